@@ -3,13 +3,20 @@ use std::{cmp::Reverse, convert::TryInto};
 use itertools::Itertools;
 use language::Language;
 use status::Status;
+use thiserror::Error;
 
 use crate::{
-    easter_in_year, feasts::KalendarEntry, holy_day::HolyDayId, liturgical_day::LiturgicalDayId,
+    feasts::KalendarEntry, holy_day::HolyDayId, liturgical_day::LiturgicalDayId,
     liturgical_week::Cycle, propers::calculate_proper, DailyOfficeYear, Date, Feast, LiturgicalDay,
     LiturgicalWeek, Proper, RCLYear, Rank, Season, Time, Weekday,
 };
 
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarError {
+    #[error("{0} falls before the adoption of the Gregorian calendar on October 15, 1582")]
+    BeforeGregorianReform(Date),
+}
+
 /// The settings for a particular calendar. Different calendars vary slightly
 /// in the way their liturgical cycles are set up relative to Christmas and Easter.
 /// Based on this structure, we can generate a [LiturgicalWeek](LiturgicalWeek)
@@ -23,7 +30,7 @@ pub struct Calendar {
     pub has_propers: bool,
     /// Maps nth week of cycle onto the liturgical week identifier
     /// i.e., in the Episcopal Church calendar the 1st week of the Christmas cycle is Christ the King
-    // TODO: benchmark against a HashMap — I'm making the assumption that O(n) for small n is better than O(1) given the cost of hashing
+    // (these static tables are indexed into HashMaps the first time a calendar is used; see `year_table.rs`)
     pub weeks: &'static [(Cycle, u8, LiturgicalWeek)],
     /// All holy days in the calendar
     pub holy_days: &'static [KalendarEntry],
@@ -49,6 +56,10 @@ pub struct Calendar {
 impl Calendar {
     /// The [LiturgicalDay](LiturgicalDay) that is observed on a given date,
     /// including any feasts or special observances and any transferred feasts.
+    ///
+    /// Dates before the adoption of the Gregorian calendar are calculated as proleptic Gregorian dates,
+    /// and the resulting day is marked as [proleptic](LiturgicalDay::proleptic); use
+    /// [try_liturgical_day](Calendar::try_liturgical_day) to refuse them instead.
     /// ```
     /// # use crate::calendar::{BCP1979_CALENDAR, Date, Weekday, LiturgicalWeek, DailyOfficeYear, RCLYear, Feast, LiturgicalDayId};
     /// let date = Date::from_ymd(2020, 5, 21);
@@ -65,7 +76,9 @@ impl Calendar {
         let mut original = self.liturgical_day_without_transferred_feasts(date, evening);
 
         // modify if feasts should be transferred
-        let transferred = self.transferred_feast(&original);
+        let transferred = self
+            .year_table(date.year())
+            .transferred_feast(self, date, evening);
         if let Some(transferred) = transferred {
             let alternate = std::mem::replace(
                 &mut original.observed,
@@ -80,8 +93,9 @@ impl Calendar {
         // - black-letter days if it's a Sunday or a holy day
         let observed = original.observed;
         let weekday = original.weekday;
+        let tables = self.tables();
         original.holy_days.retain(|feast| {
-            let feast_rank = self.feast_day_rank(feast);
+            let feast_rank = tables.rank(feast);
             match observed {
                 LiturgicalDayId::Feast(o_feast) => {
                     o_feast != *feast
@@ -103,6 +117,29 @@ impl Calendar {
         original
     }
 
+    /// The [LiturgicalDay](LiturgicalDay) that is observed on a given date, refusing any date
+    /// before the adoption of the Gregorian calendar, for which a Gregorian liturgical day is meaningless.
+    /// (Use [julian_easter_in_year](crate::julian_easter_in_year) and [Date::from_julian_ymd] for historical dates.)
+    /// ```
+    /// # use crate::calendar::{BCP1979_CALENDAR, CalendarError, Date, Feast, LiturgicalDayId};
+    /// let christmas = BCP1979_CALENDAR.try_liturgical_day(Date::from_ymd(1582, 12, 25), false);
+    /// assert_eq!(christmas.unwrap().observed, LiturgicalDayId::Feast(Feast::ChristmasDay));
+    /// let date = Date::from_ymd(1500, 12, 25);
+    /// let christmas = BCP1979_CALENDAR.try_liturgical_day(date, false);
+    /// assert_eq!(christmas, Err(CalendarError::BeforeGregorianReform(date)));
+    /// ```
+    pub fn try_liturgical_day(
+        &self,
+        date: Date,
+        evening: bool,
+    ) -> Result<LiturgicalDay, CalendarError> {
+        if date.is_gregorian() {
+            Ok(self.liturgical_day(date, evening))
+        } else {
+            Err(CalendarError::BeforeGregorianReform(date))
+        }
+    }
+
     /// The [LiturgicalDay](LiturgicalDay) that is observed on a given date,
     /// without transferring any feasts.
     /// ```
@@ -125,9 +162,16 @@ impl Calendar {
         let weekday = date.weekday();
         let week = self.liturgical_week(date);
         let proper = self.proper(date, week);
-        let holy_days = self.holy_days(date, week, evening, false);
-        let fallback_holy_days = self.holy_days_fallback.map(|fallback| {
-            Calendar::filter_holy_days(date, week, evening, false, fallback.holy_days)
+        let year_table = self.year_table(date.year());
+        let holy_days = Calendar::filter_holy_days(
+            date,
+            week,
+            evening,
+            false,
+            year_table.holy_days.candidates(date, week),
+        );
+        let fallback_holy_days = year_table.fallback_holy_days.as_ref().map(|fallback| {
+            Calendar::filter_holy_days(date, week, evening, false, fallback.candidates(date, week))
         });
         let holy_days = if let Some(fallback) = fallback_holy_days {
             holy_days.chain(fallback).unique().collect::<Vec<_>>()
@@ -154,6 +198,8 @@ impl Calendar {
             observed,
             alternate,
             alternative_services,
+            orthodox_pascha: date == year_table.orthodox_pascha,
+            proleptic: !date.is_gregorian(),
         }
     }
 
@@ -284,11 +330,7 @@ impl Calendar {
     /// ignoring any feasts or special observances.
     fn liturgical_week(&self, date: Date) -> LiturgicalWeek {
         let index = self.liturgical_week_index(date);
-        self.weeks
-            .iter()
-            .find(|(cycle, offset, _)| index.cycle == *cycle && index.week == *offset)
-            .map(|(_, _, week)| *week)
-            .unwrap_or(LiturgicalWeek::None)
+        self.tables().week(index.cycle, index.week)
     }
 
     /// For calendars that use the Proper ____ system, gives the [Proper](Proper)
@@ -303,20 +345,7 @@ impl Calendar {
 
     /// The rank of the given feast day in this calendar
     pub fn feast_day_rank(&self, feast: &Feast) -> Rank {
-        if let Some(fallback) = self.holy_days_fallback {
-            self.holy_day_ranks
-                .iter()
-                .chain(fallback.holy_day_ranks.iter())
-                .find(|(search_feast, _)| search_feast == feast)
-                .map(|(_, rank)| *rank)
-                .unwrap_or(Rank::OptionalObservance)
-        } else {
-            self.holy_day_ranks
-                .iter()
-                .find(|(search_feast, _)| search_feast == feast)
-                .map(|(_, rank)| *rank)
-                .unwrap_or(Rank::OptionalObservance)
-        }
+        self.tables().rank(feast)
     }
 
     /// Whether the given feast is the "Eve of ___"
@@ -372,88 +401,92 @@ impl Calendar {
         evening: bool,
         ignore_evening: bool,
     ) -> impl Iterator<Item = Feast> {
-        Calendar::filter_holy_days(date, week, evening, ignore_evening, self.holy_days)
+        let year_table = self.year_table(date.year());
+        Calendar::filter_holy_days(
+            date,
+            week,
+            evening,
+            ignore_evening,
+            year_table.holy_days.candidates(date, week),
+        )
     }
 
-    fn filter_holy_days(
+    /// Filters the given calendar entries down to the feasts that actually fall on this date.
+    pub(crate) fn filter_holy_days(
         date: Date,
         week: LiturgicalWeek,
         evening: bool,
         ignore_evening: bool,
-        holy_days: &[KalendarEntry],
-    ) -> impl Iterator<Item = Feast> + '_ {
+        holy_days: impl Iterator<Item = &'static KalendarEntry>,
+    ) -> impl Iterator<Item = Feast> {
         let today_month = date.month();
         let today_day = date.day();
         let today_weekday = date.weekday();
         let today_year = date.year();
-        holy_days
-            .iter()
-            .filter_map(move |(id, feast, f_time, f_stops_at_sunday)| {
-                let has_stopped = if let Some(stopping_week) = f_stops_at_sunday {
-                    week >= *stopping_week
-                } else {
-                    false
-                };
-                let time_ok = (!matches!(*f_time, Time::EveningOnly(_))
-                    || (!ignore_evening && matches!(*f_time, Time::EveningOnly(_)) && evening))
-                    && (*f_time != Time::MorningOnly || !evening);
-                match id {
-                    HolyDayId::Date(f_month, f_day) => {
-                        if *f_month == today_month && *f_day == today_day && time_ok && !has_stopped
-                        {
-                            Some(*feast)
-                        } else {
-                            None
-                        }
+        holy_days.filter_map(move |(id, feast, f_time, f_stops_at_sunday)| {
+            let has_stopped = if let Some(stopping_week) = f_stops_at_sunday {
+                week >= *stopping_week
+            } else {
+                false
+            };
+            let time_ok = (!matches!(*f_time, Time::EveningOnly(_))
+                || (!ignore_evening && matches!(*f_time, Time::EveningOnly(_)) && evening))
+                && (*f_time != Time::MorningOnly || !evening);
+            match id {
+                HolyDayId::Date(f_month, f_day) => {
+                    if *f_month == today_month && *f_day == today_day && time_ok && !has_stopped {
+                        Some(*feast)
+                    } else {
+                        None
                     }
-                    HolyDayId::SpecialDay(f_week, f_weekday) => {
-                        if *f_week == week && *f_weekday == today_weekday && time_ok && !has_stopped
-                        {
-                            Some(*feast)
-                        } else {
-                            None
-                        }
+                }
+                HolyDayId::SpecialDay(f_week, f_weekday) => {
+                    if *f_week == week && *f_weekday == today_weekday && time_ok && !has_stopped {
+                        Some(*feast)
+                    } else {
+                        None
                     }
-                    HolyDayId::DayOfMonth { month, week, day } => {
-                        // divide date by 7 and round up => nth instance of a day of week
-                        if *month == today_month
-                            && *day == today_weekday
-                            && date.nth_instance_in_month() == *week
-                            && time_ok
-                            && !has_stopped
-                        {
-                            Some(*feast)
-                        } else {
-                            None
-                        }
+                }
+                HolyDayId::DayOfMonth { month, week, day } => {
+                    // divide date by 7 and round up => nth instance of a day of week
+                    if *month == today_month
+                        && *day == today_weekday
+                        && date.nth_instance_in_month() == *week
+                        && time_ok
+                        && !has_stopped
+                    {
+                        Some(*feast)
+                    } else {
+                        None
                     }
-                    HolyDayId::WeekdayAfterDate {
-                        month,
-                        day,
-                        starting_weekday,
-                        weekday,
-                    } => {
-                        let starting_date = Date::from_ymd(today_year, *month, *day);
-                        let starting_date = if let Some(starting_weekday) = starting_weekday {
-                            if starting_date.weekday() == *starting_weekday {
-                                starting_date.add_days(7)
-                            } else {
-                                starting_date
-                            }
+                }
+                HolyDayId::WeekdayAfterDate {
+                    month,
+                    day,
+                    starting_weekday,
+                    weekday,
+                } => {
+                    let starting_date = Date::from_ymd(today_year, *month, *day);
+                    let starting_date = if let Some(starting_weekday) = starting_weekday {
+                        if starting_date.weekday() == *starting_weekday {
+                            starting_date.add_days(7)
                         } else {
                             starting_date
-                        };
+                        }
+                    } else {
+                        starting_date
+                    };
 
-                        let distance_in_days = (date - starting_date).num_days();
+                    let distance_in_days = (date - starting_date).num_days();
 
-                        if (0..7).contains(&distance_in_days) && weekday == &date.weekday() {
-                            Some(*feast)
-                        } else {
-                            None
-                        }
+                    if (0..7).contains(&distance_in_days) && weekday == &date.weekday() {
+                        Some(*feast)
+                    } else {
+                        None
                     }
                 }
-            })
+            }
+        })
     }
 
     fn observed_day(
@@ -470,10 +503,11 @@ impl Calendar {
             )
         } else {
             // include all eligible feasts
+            let tables = self.tables();
             let mut observable_feasts = holy_days
                 .iter()
                 .filter(|feast| {
-                    let rank = self.feast_day_rank(feast);
+                    let rank = tables.rank(feast);
                     // only include if rank is higher than a black-letter day
                     rank >= Rank::PrecedenceOverWeekday
                     // if, if today is a Sunday, if rank is above a Sunday
@@ -483,13 +517,11 @@ impl Calendar {
                 .collect::<Vec<_>>();
 
             // sort in reverse order, i.e., from highest-ranking feast to lowest
-            observable_feasts.sort_by_cached_key(|feast| Reverse(self.feast_day_rank(feast)));
+            observable_feasts.sort_by_cached_key(|feast| Reverse(tables.rank(feast)));
             let highest_ranking_feast = observable_feasts.get(0);
 
             if let Some(highest_ranking_feast) = highest_ranking_feast {
-                if weekday == Weekday::Sun
-                    && self.feast_day_rank(highest_ranking_feast) > Rank::Sunday
-                {
+                if weekday == Weekday::Sun && tables.rank(highest_ranking_feast) > Rank::Sunday {
                     (
                         LiturgicalDayId::Feast(**highest_ranking_feast),
                         Some(self.observed_day_from_week_or_proper(week, proper, weekday)),
@@ -499,7 +531,7 @@ impl Calendar {
                         LiturgicalDayId::Feast(**highest_ranking_feast),
                         observable_feasts
                             .iter()
-                            .filter(|feast| self.feast_day_rank(feast) >= Rank::SpecialDevotion)
+                            .filter(|feast| tables.rank(feast) >= Rank::SpecialDevotion)
                             .nth(1)
                             .copied()
                             .copied()
@@ -516,7 +548,7 @@ impl Calendar {
             {
                 let alternate_feast = holy_days
                     .iter()
-                    .find(|feast| self.feast_day_rank(feast) > Rank::OptionalObservance)
+                    .find(|feast| tables.rank(feast) > Rank::OptionalObservance)
                     .copied()
                     .map(LiturgicalDayId::Feast);
 
@@ -547,18 +579,11 @@ impl Calendar {
     }
 
     fn liturgical_week_index(&self, date: Date) -> LiturgicalWeekIndex {
-        let year = date.year();
-        let easter = easter_in_year(year.into());
-        let christmas_eve = Date::from_ymd(year, 12, 24);
-        let last_epiphany = easter.subtract_weeks(self.easter_cycle_begins);
-        let fourth_advent = christmas_eve.sunday_before();
-        let last_pentecost = fourth_advent
-            .sunday_before()
-            .subtract_weeks(self.christmas_cycle_begins);
-        if date >= last_pentecost || date < last_epiphany {
+        let year_table = self.year_table(date.year());
+        if date >= year_table.last_pentecost || date < year_table.last_epiphany {
             self.christmas_cycle_week(date)
         } else {
-            self.easter_cycle_week(date, easter)
+            self.easter_cycle_week(date, year_table.easter)
         }
     }
 
@@ -723,4 +748,23 @@ mod tests {
         let day = BCP1979_CALENDAR.liturgical_day(date, false);
         assert!(day.holy_days.contains(&Feast::EmberDay));
     }
+
+    #[test]
+    fn marks_proleptic_days() {
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(1500, 12, 25), false);
+        assert!(day.proleptic);
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(1582, 10, 15), false);
+        assert!(!day.proleptic);
+    }
+
+    #[test]
+    fn marks_orthodox_pascha() {
+        let date = Date::from_ymd(2024, 5, 5);
+        let day = BCP1979_CALENDAR.liturgical_day(date, false);
+        assert!(day.orthodox_pascha);
+
+        let date = Date::from_ymd(2024, 3, 31);
+        let day = BCP1979_CALENDAR.liturgical_day(date, false);
+        assert!(!day.orthodox_pascha);
+    }
 }
@@ -13,6 +13,13 @@ pub enum DateError {
     Parse,
 }
 
+/// The first day on which the Gregorian calendar was in use (October 15, 1582).
+/// [Date]s before this are "proleptic" Gregorian dates, which did not exist in the calendar of the time.
+pub const GREGORIAN_REFORM: (u16, u8, u8) = (1582, 10, 15);
+
+// Julian Day Number of 0001-01-01 (proleptic Gregorian), which chrono numbers as day 1 of the common era
+const JULIAN_DAY_NUMBER_OF_CE: i64 = 1_721_425;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Date {
     pub(crate) naive_date: chrono::NaiveDate,
@@ -56,6 +63,83 @@ impl Date {
         Self { naive_date }
     }
 
+    /// Creates a Date from a year, month, and day in the Julian calendar, or `None` if there is no such day.
+    /// ```
+    /// # use calendar::Date;
+    /// // the day before the Gregorian reform was Thursday, October 4, 1582 (Julian)
+    /// let last_julian_day = Date::from_julian_ymd(1582, 10, 4);
+    /// assert_eq!(last_julian_day, Some(Date::from_ymd(1582, 10, 14)));
+    /// // Julian Christmas falls on January 7 in the twentieth and twenty-first centuries
+    /// assert_eq!(Date::from_julian_ymd(2021, 12, 25), Some(Date::from_ymd(2022, 1, 7)));
+    /// // every fourth year is a leap year in the Julian calendar
+    /// assert_eq!(Date::from_julian_ymd(1900, 2, 29), Some(Date::from_ymd(1900, 3, 13)));
+    /// assert_eq!(Date::from_julian_ymd(1901, 2, 29), None);
+    /// assert_eq!(Date::from_julian_ymd(2021, 13, 1), None);
+    /// ```
+    pub fn from_julian_ymd(year: u16, month: u8, day: u8) -> Option<Date> {
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year.is_multiple_of(4) => 29,
+            2 => 28,
+            _ => return None,
+        };
+        if day == 0 || day > days_in_month {
+            return None;
+        }
+
+        // see Richards, "Calendars," in the Explanatory Supplement to the Astronomical Almanac
+        let (year, month, day) = (i64::from(year), i64::from(month), i64::from(day));
+        let a = (14 - month) / 12;
+        let y = year + 4800 - a;
+        let m = month + 12 * a - 3;
+        let julian_day_number = day + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083;
+        let naive_date = NaiveDate::from_num_days_from_ce_opt(
+            (julian_day_number - JULIAN_DAY_NUMBER_OF_CE)
+                .try_into()
+                .ok()?,
+        )?;
+        Some(Self { naive_date })
+    }
+
+    /// The year, month, and day of this date in the Julian calendar, or `None` if it
+    /// falls before the year 0 in the Julian calendar (as a date converted from a [chrono::NaiveDate] may).
+    /// ```
+    /// # use calendar::Date;
+    /// assert_eq!(Date::from_ymd(2022, 1, 7).to_julian_ymd(), Some((2021, 12, 25)));
+    /// assert_eq!(Date::from_ymd(0, 1, 1).to_julian_ymd(), Some((0, 1, 3)));
+    /// let before_year_zero = Date::from(chrono::NaiveDate::from_ymd_opt(-1, 6, 1).unwrap());
+    /// assert_eq!(before_year_zero.to_julian_ymd(), None);
+    /// ```
+    pub fn to_julian_ymd(&self) -> Option<(u16, u8, u8)> {
+        let julian_day_number =
+            i64::from(self.naive_date.num_days_from_ce()) + JULIAN_DAY_NUMBER_OF_CE;
+        let c = julian_day_number + 32082;
+        let d = (4 * c + 3) / 1461;
+        let e = c - (1461 * d) / 4;
+        let m = (5 * e + 2) / 153;
+        let day = e - (153 * m + 2) / 5 + 1;
+        let month = m + 3 - 12 * (m / 10);
+        let year = d - 4800 + m / 10;
+        Some((
+            year.try_into().ok()?,
+            month.try_into().ok()?,
+            day.try_into().ok()?,
+        ))
+    }
+
+    /// Whether this date falls on or after the adoption of the Gregorian calendar,
+    /// i.e., whether it is a date that was actually in use rather than a proleptic one.
+    /// ```
+    /// # use calendar::Date;
+    /// assert!(Date::from_ymd(1582, 10, 15).is_gregorian());
+    /// assert!(!Date::from_ymd(1582, 10, 14).is_gregorian());
+    /// ```
+    pub fn is_gregorian(&self) -> bool {
+        let (year, month, day) = GREGORIAN_REFORM;
+        *self >= Date::from_ymd(year, month, day)
+    }
+
     /// Creates Date from a year, month, and day.
     pub fn parse_from_str(s: &str, fmt: &str) -> Result<Date, DateError> {
         let naive_date = chrono::NaiveDate::parse_from_str(s, fmt).map_err(|_| DateError::Parse)?;
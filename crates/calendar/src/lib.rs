@@ -11,6 +11,8 @@ pub mod lff2018;
 mod liturgical_color;
 mod liturgical_day;
 mod liturgical_week;
+mod occurrences;
+mod precedence;
 pub mod propers;
 mod rank;
 mod sanctoral;
@@ -19,7 +21,8 @@ mod transferred_feast;
 mod various_occasions;
 mod weekday;
 mod year;
-pub use self::calendar::Calendar;
+mod year_table;
+pub use self::calendar::{Calendar, CalendarError};
 pub use bcp1979::BCP1979_CALENDAR;
 pub use date::*;
 pub use feasts::*;
@@ -28,13 +31,15 @@ pub use lff2018::LFF2018_CALENDAR;
 pub use liturgical_color::Color;
 pub use liturgical_day::{LiturgicalDay, LiturgicalDayId};
 pub use liturgical_week::{Cycle, LiturgicalWeek};
+pub use occurrences::{Observance, Occurrence, YearlyOccurrences};
+pub use precedence::{Precedence, PrecedenceCandidate, PrecedenceOutcome, PrecedenceRule};
 pub use propers::Proper;
 pub use rank::Rank;
 pub use sanctoral::Sanctoral;
 pub use season::Season;
 pub use various_occasions::*;
 pub use weekday::Weekday;
-pub use year::{DailyOfficeYear, RCLYear, Year, YearType};
+pub use year::{DailyOfficeYear, RCLYear, WeekdayEucharistYear, Year, YearType};
 
 /// Calculates the date of Easter as a [Date](Date) in any given year.
 /// ```
@@ -77,13 +82,48 @@ pub fn easter_in_year(year: u32) -> Date {
     )
 }
 
+/// Calculates the date of Easter according to the Julian computus, used by the Eastern Orthodox churches
+/// to determine the date of Pascha. The result is given as a (Gregorian) [Date](Date), so that it can be
+/// marked alongside the Western calendar; use [Date::to_julian_ymd](Date::to_julian_ymd) to get the date
+/// as it would have been reckoned in the Julian calendar.
+/// ```
+/// # use calendar::{easter_in_year, julian_easter_in_year, Date};
+/// // Orthodox Pascha 2023: April 16 (April 3, Julian)
+/// assert_eq!(julian_easter_in_year(2023), Date::from_ymd(2023, 4, 16));
+/// assert_eq!(julian_easter_in_year(2023).to_julian_ymd(), Some((2023, 4, 3)));
+/// // Orthodox Pascha 2024: May 5
+/// assert_eq!(julian_easter_in_year(2024), Date::from_ymd(2024, 5, 5));
+/// // In 2025, Easter was celebrated on the same day in East and West
+/// assert_eq!(julian_easter_in_year(2025), easter_in_year(2025));
+/// // every year that can be given has a date of Pascha
+/// assert!(matches!(julian_easter_in_year(u16::MAX).to_julian_ymd(), Some((u16::MAX, 3 | 4, _))));
+/// ```
+#[allow(clippy::many_single_char_names)]
+pub fn julian_easter_in_year(year: u16) -> Date {
+    // Computus: Meeus Julian algorithm
+    let a = year % 4;
+    let b = year % 7;
+    let c = year % 19;
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b + 34 - d) % 7;
+    let month = (d + e + 114) / 31;
+    let day = ((d + e + 114) % 31) + 1;
+    // the computus always gives a day in March or April
+    Date::from_julian_ymd(
+        year,
+        month.try_into().unwrap(),
+        day.try_into().unwrap(),
+    )
+    .unwrap()
+}
+
 // Crate-wide benchmark
 extern crate test;
 #[cfg(test)]
 mod tests {
     use test::{black_box, Bencher};
 
-    use crate::{Date, BCP1979_CALENDAR};
+    use crate::{Calendar, Date, BCP1979_CALENDAR};
 
     #[bench]
     fn bench_liturgical_day(b: &mut Bencher) {
@@ -95,6 +135,31 @@ mod tests {
             }
         });
     }
+
+    // every day in a decade, once the year tables have been built
+    #[bench]
+    fn bench_liturgical_day_decade(b: &mut Bencher) {
+        b.iter(|| {
+            let mut date = Date::from_ymd(2020, 1, 1);
+            while date.year() < 2030 {
+                black_box(BCP1979_CALENDAR.liturgical_day(date, false));
+                date = date.add_days(1);
+            }
+        });
+    }
+
+    // every day in a decade, rebuilding the year tables from scratch each time
+    #[bench]
+    fn bench_liturgical_day_decade_uncached(b: &mut Bencher) {
+        b.iter(|| {
+            Calendar::clear_tables();
+            let mut date = Date::from_ymd(2020, 1, 1);
+            while date.year() < 2030 {
+                black_box(BCP1979_CALENDAR.liturgical_day(date, false));
+                date = date.add_days(1);
+            }
+        });
+    }
 }
 
 // Convert string slugs to calendars
@@ -17,6 +17,14 @@ pub struct LiturgicalDay {
     pub observed: LiturgicalDayId,
     pub alternate: Option<LiturgicalDayId>,
     pub alternative_services: Vec<Feast>,
+    /// Whether this is the day on which the Eastern Orthodox churches celebrate Pascha
+    /// (see [julian_easter_in_year](crate::julian_easter_in_year))
+    #[serde(default)]
+    pub orthodox_pascha: bool,
+    /// Whether the date falls before the adoption of the Gregorian calendar (see [Date::is_gregorian]),
+    /// so that the day has been calculated for a proleptic Gregorian date that was never actually observed
+    #[serde(default)]
+    pub proleptic: bool,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
@@ -0,0 +1,257 @@
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Calendar, Date, Feast, LiturgicalDay, LiturgicalDayId, LiturgicalWeek, PrecedenceOutcome,
+    PrecedenceRule, Proper, Rank, Weekday,
+};
+
+/// Anything that can be observed on a particular date: a [Feast], the Sunday of a [LiturgicalWeek],
+/// or the Sunday of a [Proper].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Observance {
+    Feast(Feast),
+    Week(LiturgicalWeek),
+    Proper(Proper),
+}
+
+impl From<Feast> for Observance {
+    fn from(feast: Feast) -> Self {
+        Self::Feast(feast)
+    }
+}
+
+impl From<LiturgicalWeek> for Observance {
+    fn from(week: LiturgicalWeek) -> Self {
+        Self::Week(week)
+    }
+}
+
+impl From<Proper> for Observance {
+    fn from(proper: Proper) -> Self {
+        Self::Proper(proper)
+    }
+}
+
+/// All the occurrences of an [Observance] within a single calendar year
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct YearlyOccurrences {
+    pub year: u16,
+    /// Empty if the observance does not fall in this year at all (e.g., an Epiphany 8 in a year with an early Easter)
+    pub occurrences: Vec<Occurrence>,
+}
+
+/// A single instance of an [Observance]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Occurrence {
+    /// The date on which the observance would fall, absent any rules of precedence
+    pub original_date: Date,
+    /// The date on which the observance is actually kept, or `None` if it is suppressed this year
+    pub observed_date: Option<Date>,
+    /// Why the observance is kept on `observed_date` (see [Calendar::precedence])
+    pub outcome: PrecedenceOutcome,
+}
+
+impl Calendar {
+    /// Finds every date on which an [Observance] is kept in each of the given years,
+    /// including any transfers or suppressions.
+    /// ```
+    /// # use crate::calendar::{BCP1979_CALENDAR, Date, Feast, PrecedenceOutcome, PrecedenceRule};
+    /// // St. Joseph's Day falls on a Sunday in 2028
+    /// let years = BCP1979_CALENDAR.occurrences(Feast::Joseph, 2027..=2028);
+    /// assert_eq!(years.len(), 2);
+    /// assert_eq!(years[0].occurrences[0].observed_date, Some(Date::from_ymd(2027, 3, 19)));
+    /// assert_eq!(years[1].occurrences[0].original_date, Date::from_ymd(2028, 3, 19));
+    /// assert_eq!(years[1].occurrences[0].observed_date, Some(Date::from_ymd(2028, 3, 20)));
+    /// assert_eq!(
+    ///     years[1].occurrences[0].outcome,
+    ///     PrecedenceOutcome::Transferred {
+    ///         to: Date::from_ymd(2028, 3, 20),
+    ///         rule: PrecedenceRule::TransferredFromSunday
+    ///     }
+    /// );
+    /// ```
+    pub fn occurrences(
+        &self,
+        observance: impl Into<Observance>,
+        years: RangeInclusive<u16>,
+    ) -> Vec<YearlyOccurrences> {
+        let observance = observance.into();
+        years
+            .map(|year| YearlyOccurrences {
+                year,
+                occurrences: match observance {
+                    Observance::Feast(feast) => self.feast_occurrences(feast, year),
+                    Observance::Week(week) => self.sunday_occurrences(year, |day| day.week == week),
+                    Observance::Proper(proper) => {
+                        self.sunday_occurrences(year, |day| day.proper == Some(proper))
+                    }
+                },
+            })
+            .collect()
+    }
+
+    fn feast_occurrences(&self, feast: Feast, year: u16) -> Vec<Occurrence> {
+        let mut occurrences: Vec<Occurrence> = Vec::new();
+        let mut transferred_here = Vec::new();
+
+        for date in days_in_year(year) {
+            let original = self.liturgical_day_without_transferred_feasts(date, false);
+            let transferred =
+                self.year_table(year).transferred_feast(self, date, false) == Some(feast);
+            if !original.holy_days.contains(&feast) && !transferred {
+                continue;
+            }
+
+            let candidate = self
+                .precedence(date, false)
+                .candidates
+                .into_iter()
+                .find(|candidate| candidate.feast == feast);
+            if let Some(candidate) = candidate {
+                match candidate.outcome {
+                    PrecedenceOutcome::TransferredHere { .. } => {
+                        transferred_here.push((date, candidate.outcome))
+                    }
+                    outcome => {
+                        let observed_date = match outcome {
+                            PrecedenceOutcome::Transferred { to, .. } => Some(to),
+                            PrecedenceOutcome::Displaced { .. } => None,
+                            _ => Some(date),
+                        };
+                        occurrences.push(Occurrence {
+                            original_date: date,
+                            observed_date,
+                            outcome,
+                        });
+                    }
+                }
+            }
+        }
+
+        // a transfer is already reported from its original date, unless that date was in a previous year
+        // or the feast is kept on both dates (e.g., All Saints’ Sunday)
+        for (date, outcome) in transferred_here {
+            let already_reported = occurrences
+                .iter()
+                .any(|occurrence| occurrence.observed_date == Some(date));
+            if !already_reported {
+                let original_date = match outcome {
+                    PrecedenceOutcome::TransferredHere { from, .. } => from,
+                    _ => date,
+                };
+                occurrences.push(Occurrence {
+                    original_date,
+                    observed_date: Some(date),
+                    outcome,
+                });
+            }
+        }
+
+        occurrences.sort_by_key(|occurrence| occurrence.original_date);
+        occurrences
+    }
+
+    /// Occurrences of a week or proper, which are kept on the Sunday that begins them
+    fn sunday_occurrences(
+        &self,
+        year: u16,
+        matches: impl Fn(&LiturgicalDay) -> bool,
+    ) -> Vec<Occurrence> {
+        days_in_year(year)
+            .filter(|date| date.weekday() == Weekday::Sun)
+            .map(|date| self.liturgical_day(date, false))
+            .filter(|day| matches(day))
+            .map(|day| {
+                let base = if let Some(proper) = day.proper {
+                    LiturgicalDayId::ProperAndDay(proper, Weekday::Sun)
+                } else {
+                    LiturgicalDayId::WeekAndDay(day.week, Weekday::Sun)
+                };
+
+                if day.observed == base {
+                    Occurrence {
+                        original_date: day.date,
+                        observed_date: Some(day.date),
+                        outcome: PrecedenceOutcome::Observed,
+                    }
+                } else {
+                    let rule = match day.observed {
+                        LiturgicalDayId::TransferredFeast(Feast::AllSaintsDay) => {
+                            PrecedenceRule::AllSaintsSunday
+                        }
+                        LiturgicalDayId::Feast(feast)
+                            if self.feast_day_rank(&feast) == Rank::PrincipalFeast =>
+                        {
+                            PrecedenceRule::PrincipalFeast
+                        }
+                        _ => PrecedenceRule::SundayPrecedence,
+                    };
+                    Occurrence {
+                        original_date: day.date,
+                        observed_date: None,
+                        outcome: PrecedenceOutcome::Displaced {
+                            by: day.observed,
+                            rule,
+                        },
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+fn days_in_year(year: u16) -> impl Iterator<Item = Date> {
+    let first = Date::from_ymd(year, 1, 1);
+    (0..366)
+        .map(move |delta| first.add_days(delta))
+        .take_while(move |date| date.year() == year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BCP1979_CALENDAR;
+
+    #[test]
+    fn proper_displaced_by_all_saints_sunday() {
+        // All Saints’ Sunday 2021 was November 7, which would otherwise have been Proper 27
+        let years = BCP1979_CALENDAR.occurrences(Proper::Proper27, 2021..=2021);
+        assert_eq!(
+            years[0].occurrences,
+            vec![Occurrence {
+                original_date: Date::from_ymd(2021, 11, 7),
+                observed_date: None,
+                outcome: PrecedenceOutcome::Displaced {
+                    by: LiturgicalDayId::TransferredFeast(Feast::AllSaintsDay),
+                    rule: PrecedenceRule::AllSaintsSunday
+                }
+            }]
+        );
+    }
+
+    #[test]
+    fn all_saints_observed_on_date_and_sunday() {
+        let years = BCP1979_CALENDAR.occurrences(Feast::AllSaintsDay, 2021..=2021);
+        let observed_dates = years[0]
+            .occurrences
+            .iter()
+            .map(|occurrence| occurrence.observed_date)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            observed_dates,
+            vec![
+                Some(Date::from_ymd(2021, 11, 1)),
+                Some(Date::from_ymd(2021, 11, 7))
+            ]
+        );
+    }
+
+    #[test]
+    fn week_that_does_not_occur() {
+        // Easter 2024 was March 31, so there was no Eighth Sunday after the Epiphany
+        let years = BCP1979_CALENDAR.occurrences(LiturgicalWeek::Epiphany8, 2024..=2024);
+        assert!(years[0].occurrences.is_empty());
+    }
+}
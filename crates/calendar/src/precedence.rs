@@ -0,0 +1,290 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Calendar, Date, Feast, LiturgicalDay, LiturgicalDayId, LiturgicalWeek, Rank, Weekday};
+
+/// How far (in days) to look for the date to or from which a feast has been transferred.
+/// Feasts that fall in Holy Week can be moved as far as the week after the Second Sunday of Easter.
+const TRANSFER_SEARCH_WINDOW: u8 = 21;
+
+/// A full account of how the [LiturgicalDay] for a date was chosen: every feast that was a candidate
+/// for that date, its [Rank], and what happened to it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Precedence {
+    /// The [LiturgicalDay] that is actually observed, as given by [Calendar::liturgical_day]
+    pub day: LiturgicalDay,
+    /// Every feast that falls on (or was transferred to) this date
+    pub candidates: Vec<PrecedenceCandidate>,
+}
+
+/// A feast that could have been observed on a date, and what became of it
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrecedenceCandidate {
+    /// The feast that falls on (or was transferred to) the date
+    pub feast: Feast,
+    /// The [Rank] of the feast in the [Calendar], which determines its precedence
+    pub rank: Rank,
+    /// Whether the feast is observed on the date, and, if not, which rule displaced it
+    pub outcome: PrecedenceOutcome,
+}
+
+/// What happened to a candidate feast when precedence was applied
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PrecedenceOutcome {
+    /// This feast is the observance of the day
+    Observed,
+    /// This feast was moved here from another date
+    TransferredHere { from: Date, rule: PrecedenceRule },
+    /// This feast may be observed in place of the day's observance, but isn't by default
+    Alternate,
+    /// This feast is not the principal observance, but is still commemorated (e.g., a black-letter day on a weekday)
+    Commemorated,
+    /// This feast was displaced, and is instead observed on another date
+    Transferred { to: Date, rule: PrecedenceRule },
+    /// This feast was displaced by a higher observance and is not observed this year
+    Displaced {
+        by: LiturgicalDayId,
+        rule: PrecedenceRule,
+    },
+}
+
+/// The rules of precedence from the Calendar of the Church Year (BCP pp. 15–18)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PrecedenceRule {
+    /// "The Principal Feasts... take precedence of any other day or observance." (BCP p. 15)
+    PrincipalFeast,
+    /// "All Sundays of the year are feasts of our Lord Jesus Christ. In addition to the dates listed above,
+    /// only the following feasts, appointed on fixed days, take precedence of a Sunday:
+    /// The Holy Name, The Presentation, The Transfiguration." (BCP p. 16)
+    SundayPrecedence,
+    /// A holy day of higher rank takes precedence over one of lower rank. (BCP pp. 16–17)
+    HigherRank,
+    /// "Feasts of our Lord, and all other Major Feasts appointed on fixed days in the Calendar,
+    /// when they occur on a Sunday, are normally transferred to the first convenient open day within the week." (BCP p. 16)
+    TransferredFromSunday,
+    /// "Fixed Holy Days... occurring in Holy Week or Easter Week are transferred to the week
+    /// following the Second Sunday of Easter, in the order of their occurrence." (BCP p. 17)
+    TransferredFromHolyWeekOrEasterWeek,
+    /// A Major Feast that coincides with a Principal Feast or other day of higher rank
+    /// is transferred to the next open day. (BCP p. 16)
+    TransferredFromHigherFeast,
+    /// "All Saints’ Day, November 1, may always be observed on the Sunday following November 1,
+    /// in addition to its observance on the fixed date." (BCP p. 15)
+    AllSaintsSunday,
+    /// Days of optional observance are not observed on Sundays, Principal Feasts,
+    /// or other days that take precedence over them. (BCP pp. 17–18)
+    LesserFeastNotObserved,
+}
+
+impl PrecedenceRule {
+    /// The page(s) of the Book of Common Prayer on which this rule is found
+    pub fn citation(&self) -> &'static str {
+        match self {
+            PrecedenceRule::PrincipalFeast => "BCP p. 15",
+            PrecedenceRule::SundayPrecedence => "BCP p. 16",
+            PrecedenceRule::HigherRank => "BCP pp. 16–17",
+            PrecedenceRule::TransferredFromSunday => "BCP p. 16",
+            PrecedenceRule::TransferredFromHolyWeekOrEasterWeek => "BCP p. 17",
+            PrecedenceRule::TransferredFromHigherFeast => "BCP p. 16",
+            PrecedenceRule::AllSaintsSunday => "BCP p. 15",
+            PrecedenceRule::LesserFeastNotObserved => "BCP pp. 17–18",
+        }
+    }
+}
+
+impl Calendar {
+    /// Explains how the observance for a given date was chosen, listing every candidate feast,
+    /// its [Rank], and which rule of precedence caused it to be observed, transferred, or displaced.
+    /// ```
+    /// # use crate::calendar::{BCP1979_CALENDAR, Date, Feast, PrecedenceOutcome, PrecedenceRule};
+    /// // in 2008, St. Joseph's Day fell on the Wednesday in Holy Week
+    /// let precedence = BCP1979_CALENDAR.precedence(Date::from_ymd(2008, 3, 19), false);
+    /// let joseph = precedence.candidates.iter().find(|candidate| candidate.feast == Feast::Joseph).unwrap();
+    /// assert_eq!(
+    ///     joseph.outcome,
+    ///     PrecedenceOutcome::Transferred {
+    ///         to: Date::from_ymd(2008, 3, 31),
+    ///         rule: PrecedenceRule::TransferredFromHolyWeekOrEasterWeek
+    ///     }
+    /// );
+    /// ```
+    pub fn precedence(&self, date: Date, evening: bool) -> Precedence {
+        let original = self.liturgical_day_without_transferred_feasts(date, evening);
+        let day = self.liturgical_day(date, evening);
+
+        let transferred_here = match day.observed {
+            LiturgicalDayId::TransferredFeast(feast) => Some(feast),
+            _ => None,
+        }
+        .map(|feast| {
+            let outcome = match self.transferred_from(feast, date) {
+                Some(from) => PrecedenceOutcome::TransferredHere {
+                    from,
+                    rule: self.transfer_rule(feast, from, date),
+                },
+                None => PrecedenceOutcome::Observed,
+            };
+            PrecedenceCandidate {
+                feast,
+                rank: self.feast_day_rank(&feast),
+                outcome,
+            }
+        });
+
+        let transferred_feast = transferred_here.as_ref().map(|candidate| candidate.feast);
+        let candidates = transferred_here
+            .into_iter()
+            .chain(
+                original
+                    .holy_days
+                    .iter()
+                    .filter(|feast| Some(**feast) != transferred_feast)
+                    .map(|feast| PrecedenceCandidate {
+                        feast: *feast,
+                        rank: self.feast_day_rank(feast),
+                        outcome: self.precedence_outcome(*feast, &day),
+                    }),
+            )
+            .collect();
+
+        Precedence { day, candidates }
+    }
+
+    fn precedence_outcome(&self, feast: Feast, day: &LiturgicalDay) -> PrecedenceOutcome {
+        let rank = self.feast_day_rank(&feast);
+
+        if day.observed == LiturgicalDayId::Feast(feast) {
+            PrecedenceOutcome::Observed
+        } else if let Some(to) = self.transferred_to(feast, day.date) {
+            // a transferred feast may also be listed as the alternate, but the transfer is more useful to report
+            PrecedenceOutcome::Transferred {
+                to,
+                rule: self.transfer_rule(feast, day.date, to),
+            }
+        } else if day.alternate == Some(LiturgicalDayId::Feast(feast)) {
+            PrecedenceOutcome::Alternate
+        } else if day.holy_days.contains(&feast) {
+            PrecedenceOutcome::Commemorated
+        } else {
+            let observed_rank = match day.observed {
+                LiturgicalDayId::Feast(observed) | LiturgicalDayId::TransferredFeast(observed) => {
+                    Some(self.feast_day_rank(&observed))
+                }
+                _ => None,
+            };
+            let rule = if rank < Rank::PrecedenceOverWeekday {
+                PrecedenceRule::LesserFeastNotObserved
+            } else if observed_rank == Some(Rank::PrincipalFeast) {
+                PrecedenceRule::PrincipalFeast
+            } else if day.weekday == Weekday::Sun && rank < Rank::Sunday {
+                PrecedenceRule::SundayPrecedence
+            } else {
+                PrecedenceRule::HigherRank
+            };
+            PrecedenceOutcome::Displaced {
+                by: day.observed,
+                rule,
+            }
+        }
+    }
+
+    /// The rule under which a feast was moved from one date to another
+    fn transfer_rule(&self, feast: Feast, from: Date, to: Date) -> PrecedenceRule {
+        let origin = self.liturgical_day_without_transferred_feasts(from, false);
+        if feast == Feast::AllSaintsDay && to.weekday() == Weekday::Sun {
+            PrecedenceRule::AllSaintsSunday
+        } else if origin.week == LiturgicalWeek::HolyWeek || origin.week == LiturgicalWeek::Easter {
+            PrecedenceRule::TransferredFromHolyWeekOrEasterWeek
+        } else if origin.weekday == Weekday::Sun {
+            PrecedenceRule::TransferredFromSunday
+        } else {
+            PrecedenceRule::TransferredFromHigherFeast
+        }
+    }
+
+    /// The next date after `date` to which the given feast has been transferred, if any
+    fn transferred_to(&self, feast: Feast, date: Date) -> Option<Date> {
+        (1..=TRANSFER_SEARCH_WINDOW)
+            .map(|delta| date.add_days(delta))
+            .find(|candidate| {
+                self.year_table(candidate.year())
+                    .transferred_feast(self, *candidate, false)
+                    == Some(feast)
+            })
+    }
+
+    /// The most recent date before `date` on which the given feast originally fell, if any
+    fn transferred_from(&self, feast: Feast, date: Date) -> Option<Date> {
+        (1..=TRANSFER_SEARCH_WINDOW)
+            .map(|delta| date.subtract_days(delta))
+            .find(|candidate| {
+                self.liturgical_day_without_transferred_feasts(*candidate, false)
+                    .holy_days
+                    .contains(&feast)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BCP1979_CALENDAR;
+
+    fn outcome_for(date: Date, feast: Feast) -> Option<PrecedenceOutcome> {
+        BCP1979_CALENDAR
+            .precedence(date, false)
+            .candidates
+            .into_iter()
+            .find(|candidate| candidate.feast == feast)
+            .map(|candidate| candidate.outcome)
+    }
+
+    #[test]
+    fn explains_transfer_from_sunday() {
+        // 4/25/2021 is a Sunday, so St. Mark transfers to Monday
+        assert_eq!(
+            outcome_for(Date::from_ymd(2021, 4, 25), Feast::Mark),
+            Some(PrecedenceOutcome::Transferred {
+                to: Date::from_ymd(2021, 4, 26),
+                rule: PrecedenceRule::TransferredFromSunday
+            })
+        );
+        assert_eq!(
+            outcome_for(Date::from_ymd(2021, 4, 26), Feast::Mark),
+            Some(PrecedenceOutcome::TransferredHere {
+                from: Date::from_ymd(2021, 4, 25),
+                rule: PrecedenceRule::TransferredFromSunday
+            })
+        );
+    }
+
+    #[test]
+    fn explains_transfer_from_principal_feast() {
+        // Ascension Day 2008 displaced Ss. Philip and James to the next day
+        assert_eq!(
+            outcome_for(Date::from_ymd(2008, 5, 1), Feast::PhilipAndJames),
+            Some(PrecedenceOutcome::Transferred {
+                to: Date::from_ymd(2008, 5, 2),
+                rule: PrecedenceRule::TransferredFromHigherFeast
+            })
+        );
+    }
+
+    #[test]
+    fn explains_all_saints_sunday() {
+        assert_eq!(
+            outcome_for(Date::from_ymd(2021, 11, 7), Feast::AllSaintsDay),
+            Some(PrecedenceOutcome::TransferredHere {
+                from: Date::from_ymd(2021, 11, 1),
+                rule: PrecedenceRule::AllSaintsSunday
+            })
+        );
+    }
+
+    #[test]
+    fn observed_feast_is_marked_observed() {
+        assert_eq!(
+            outcome_for(Date::from_ymd(2020, 8, 6), Feast::TheTransfiguration),
+            Some(PrecedenceOutcome::Observed)
+        );
+    }
+}
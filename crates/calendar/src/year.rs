@@ -8,6 +8,7 @@ use crate::{Date, LiturgicalWeek};
 pub enum YearType {
     Rcl,
     DailyOffice,
+    WeekdayEucharist,
     None,
 }
 
@@ -16,6 +17,7 @@ pub enum YearType {
 pub enum Year {
     DailyOffice(DailyOfficeYear),
     Rcl(RCLYear),
+    WeekdayEucharist(WeekdayEucharistYear),
     Any,
 }
 
@@ -89,6 +91,38 @@ impl RCLYear {
     }
 }
 
+/// The year in a two-year cycle of weekday Eucharistic readings. Year One is used in liturgical years
+/// that end in odd-numbered years (i.e., beginning with the First Sunday of Advent in an even-numbered year).
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Deserialize, Serialize)]
+pub enum WeekdayEucharistYear {
+    One,
+    Two,
+}
+
+impl WeekdayEucharistYear {
+    /// Calculates the year in the two-year weekday Eucharistic cycle.
+    /// ```
+    /// # use crate::calendar::{Date, LiturgicalWeek, WeekdayEucharistYear};
+    /// let lent_2023 = Date::from_ymd(2023, 3, 7);
+    /// assert_eq!(WeekdayEucharistYear::new(lent_2023, LiturgicalWeek::Lent2), WeekdayEucharistYear::One);
+    /// let advent_2023 = Date::from_ymd(2023, 12, 5);
+    /// assert_eq!(WeekdayEucharistYear::new(advent_2023, LiturgicalWeek::Advent1), WeekdayEucharistYear::Two);
+    /// ```
+    pub fn new(date: Date, week: LiturgicalWeek) -> Self {
+        let ending_year = if is_advent(week) || date.month() == 12 {
+            date.year() + 1
+        } else {
+            date.year()
+        };
+
+        if ending_year.is_multiple_of(2) {
+            WeekdayEucharistYear::Two
+        } else {
+            WeekdayEucharistYear::One
+        }
+    }
+}
+
 fn is_advent(week: LiturgicalWeek) -> bool {
     matches!(
         week,
@@ -0,0 +1,400 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use lazy_static::lazy_static;
+
+use crate::{
+    easter_in_year, feasts::KalendarEntry, holy_day::HolyDayId, julian_easter_in_year,
+    liturgical_week::Cycle, Calendar, Date, Feast, LiturgicalWeek, Rank, Weekday,
+};
+
+/// How many [YearTable]s are kept for each calendar; once the cache is full, the year
+/// farthest from the one being requested is dropped to make room for it.
+const MAX_CACHED_YEARS: usize = 64;
+
+lazy_static! {
+    /// Cached tables for each calendar, keyed by [Calendar::cache_key]
+    static ref CALENDAR_TABLES: Mutex<HashMap<CalendarKey, Arc<CalendarTables>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Identifies a [Calendar] by its settings and the addresses and lengths of its static tables.
+type CalendarKey = [usize; 22];
+
+/// Indexed versions of a [Calendar]'s lookup tables, plus a [YearTable] for each year that has been requested.
+pub(crate) struct CalendarTables {
+    weeks: HashMap<(Cycle, u8), LiturgicalWeek>,
+    ranks: HashMap<Feast, Rank>,
+    years: Mutex<HashMap<u16, Arc<YearTable>>>,
+}
+
+impl CalendarTables {
+    fn new(calendar: &Calendar) -> Self {
+        let mut weeks = HashMap::new();
+        for (cycle, offset, week) in calendar.weeks {
+            weeks.entry((*cycle, *offset)).or_insert(*week);
+        }
+
+        // the first entry for a feast wins, and the calendar's own ranks take precedence over its fallback's
+        let mut ranks = HashMap::new();
+        let fallback_ranks = calendar
+            .holy_days_fallback
+            .map(|fallback| fallback.holy_day_ranks)
+            .unwrap_or_default();
+        for (feast, rank) in calendar.holy_day_ranks.iter().chain(fallback_ranks.iter()) {
+            ranks.entry(*feast).or_insert(*rank);
+        }
+
+        Self {
+            weeks,
+            ranks,
+            years: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The [LiturgicalWeek] found at the given offset within a [Cycle]
+    pub(crate) fn week(&self, cycle: Cycle, offset: u8) -> LiturgicalWeek {
+        self.weeks
+            .get(&(cycle, offset))
+            .copied()
+            .unwrap_or(LiturgicalWeek::None)
+    }
+
+    /// The [Rank] of the given feast, or [Rank::OptionalObservance] if it has no explicit rank
+    pub(crate) fn rank(&self, feast: &Feast) -> Rank {
+        self.ranks
+            .get(feast)
+            .copied()
+            .unwrap_or(Rank::OptionalObservance)
+    }
+}
+
+/// Everything about a single calendar year that [Calendar::liturgical_day] would otherwise
+/// recalculate on every call: the date of Easter, the boundaries of the Christmas and Easter cycles,
+/// an index of holy days by date and week, and (lazily) every transferred feast in the year,
+/// for the morning and for the evening.
+pub(crate) struct YearTable {
+    pub(crate) easter: Date,
+    pub(crate) orthodox_pascha: Date,
+    pub(crate) last_epiphany: Date,
+    pub(crate) last_pentecost: Date,
+    pub(crate) holy_days: HolyDayIndex,
+    pub(crate) fallback_holy_days: Option<HolyDayIndex>,
+    transfers: Mutex<HashMap<bool, Arc<HashMap<Date, Feast>>>>,
+}
+
+impl YearTable {
+    fn new(calendar: &Calendar, year: u16) -> Self {
+        let easter = easter_in_year(year.into());
+        let christmas_eve = Date::from_ymd(year, 12, 24);
+        let last_epiphany = easter.subtract_weeks(calendar.easter_cycle_begins);
+        let fourth_advent = christmas_eve.sunday_before();
+        let last_pentecost = fourth_advent
+            .sunday_before()
+            .subtract_weeks(calendar.christmas_cycle_begins);
+
+        Self {
+            easter,
+            orthodox_pascha: julian_easter_in_year(year),
+            last_epiphany,
+            last_pentecost,
+            holy_days: HolyDayIndex::new(calendar.holy_days, year),
+            fallback_holy_days: calendar
+                .holy_days_fallback
+                .map(|fallback| HolyDayIndex::new(fallback.holy_days, year)),
+            transfers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The feast, if any, that has been transferred to the given date, in the morning or the evening.
+    /// The full set of transfers for the year is resolved the first time this is called for each.
+    pub(crate) fn transferred_feast(
+        &self,
+        calendar: &Calendar,
+        date: Date,
+        evening: bool,
+    ) -> Option<Feast> {
+        if let Some(transfers) = self.transfers.lock().unwrap().get(&evening) {
+            return transfers.get(&date).copied();
+        }
+
+        // resolve outside the lock: finding a transfer calculates days that use this table
+        let year = date.year();
+        let mut current = Date::from_ymd(year, 1, 1);
+        let mut transfers = HashMap::new();
+        while current.year() == year {
+            let day = calendar.liturgical_day_without_transferred_feasts(current, evening);
+            if let Some(feast) = calendar.transferred_feast(&day) {
+                transfers.insert(current, feast);
+            }
+            current = current.add_days(1);
+        }
+        let transfers = Arc::clone(
+            self.transfers
+                .lock()
+                .unwrap()
+                .entry(evening)
+                .or_insert_with(|| Arc::new(transfers)),
+        );
+        transfers.get(&date).copied()
+    }
+}
+
+/// Indexes the entries of a calendar's holy days by the dates and weeks on which they can fall in a given year.
+pub(crate) struct HolyDayIndex {
+    pub(crate) entries: &'static [KalendarEntry],
+    by_date: HashMap<(u8, u8), Vec<usize>>,
+    by_week: HashMap<(LiturgicalWeek, Weekday), Vec<usize>>,
+}
+
+impl HolyDayIndex {
+    fn new(entries: &'static [KalendarEntry], year: u16) -> Self {
+        let mut by_date: HashMap<(u8, u8), Vec<usize>> = HashMap::new();
+        let mut by_week: HashMap<(LiturgicalWeek, Weekday), Vec<usize>> = HashMap::new();
+
+        for (idx, (id, _, _, _)) in entries.iter().enumerate() {
+            match id {
+                HolyDayId::Date(month, day) => by_date.entry((*month, *day)).or_default().push(idx),
+                HolyDayId::SpecialDay(week, weekday) => {
+                    by_week.entry((*week, *weekday)).or_default().push(idx)
+                }
+                HolyDayId::DayOfMonth { month, week, day } => {
+                    // the nth instance of a given weekday always falls in the 7 days starting on day 7(n-1)+1
+                    let first_candidate = 7 * (*week as u32).saturating_sub(1) + 1;
+                    for candidate in first_candidate..first_candidate + 7 {
+                        if let Some(date) =
+                            chrono::NaiveDate::from_ymd_opt(year.into(), (*month).into(), candidate)
+                        {
+                            let date = Date::from(date);
+                            if date.weekday() == *day && date.nth_instance_in_month() == *week {
+                                by_date
+                                    .entry((date.month(), date.day()))
+                                    .or_default()
+                                    .push(idx);
+                            }
+                        }
+                    }
+                }
+                HolyDayId::WeekdayAfterDate {
+                    month,
+                    day,
+                    starting_weekday,
+                    weekday,
+                } => {
+                    let starting_date = Date::from_ymd(year, *month, *day);
+                    let starting_date = match starting_weekday {
+                        Some(starting_weekday) if starting_date.weekday() == *starting_weekday => {
+                            starting_date.add_days(7)
+                        }
+                        _ => starting_date,
+                    };
+                    // only dates within the same year match, just as when calculating day-by-day
+                    if let Some(date) = (0..7)
+                        .map(|delta| starting_date.add_days(delta))
+                        .find(|date| date.weekday() == *weekday && date.year() == year)
+                    {
+                        by_date
+                            .entry((date.month(), date.day()))
+                            .or_default()
+                            .push(idx);
+                    }
+                }
+            }
+        }
+
+        Self {
+            entries,
+            by_date,
+            by_week,
+        }
+    }
+
+    /// The entries that could possibly fall on this date, in the order they appear in the calendar.
+    pub(crate) fn candidates(
+        &self,
+        date: Date,
+        week: LiturgicalWeek,
+    ) -> impl Iterator<Item = &'static KalendarEntry> {
+        let by_date = self.by_date.get(&(date.month(), date.day()));
+        let by_week = self.by_week.get(&(week, date.weekday()));
+        let mut indices = by_date
+            .into_iter()
+            .chain(by_week)
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        let entries = self.entries;
+        indices.into_iter().map(move |idx| &entries[idx])
+    }
+}
+
+impl Calendar {
+    /// The key under which this calendar's tables are cached. Calendars are `const` values that are copied
+    /// freely, so they have no stable identity of their own; but two calendars whose tables live at the same
+    /// addresses must have the same tables. (A calendar whose tables are duplicated in memory is simply
+    /// cached more than once.)
+    fn cache_key(&self) -> CalendarKey {
+        fn table<T>(table: &'static [T]) -> [usize; 2] {
+            [table.as_ptr() as usize, table.len()]
+        }
+
+        let [weeks, weeks_len] = table(self.weeks);
+        let [holy_days, holy_days_len] = table(self.holy_days);
+        let [ranks, ranks_len] = table(self.holy_day_ranks);
+        let [feast_seasons, feast_seasons_len] = table(self.feast_seasons);
+        let [week_seasons, week_seasons_len] = table(self.week_seasons);
+        let [feast_names, feast_names_len] = table(self.feast_names);
+        let [week_names, week_names_len] = table(self.week_names);
+        let [proper_names, proper_names_len] = table(self.proper_names);
+        let [alternatives, alternatives_len] = table(self.major_day_alternatives);
+        [
+            self.easter_cycle_begins.into(),
+            self.christmas_cycle_begins.into(),
+            self.has_propers.into(),
+            self.holy_days_fallback
+                .map_or(0, |fallback| fallback as *const Calendar as usize),
+            weeks,
+            weeks_len,
+            holy_days,
+            holy_days_len,
+            ranks,
+            ranks_len,
+            feast_seasons,
+            feast_seasons_len,
+            week_seasons,
+            week_seasons_len,
+            feast_names,
+            feast_names_len,
+            week_names,
+            week_names_len,
+            proper_names,
+            proper_names_len,
+            alternatives,
+            alternatives_len,
+        ]
+    }
+
+    /// The cached, indexed tables for this calendar, built the first time they're requested.
+    pub(crate) fn tables(&self) -> Arc<CalendarTables> {
+        let key = self.cache_key();
+        if let Some(tables) = CALENDAR_TABLES.lock().unwrap().get(&key) {
+            return Arc::clone(tables);
+        }
+
+        let tables = Arc::new(CalendarTables::new(self));
+        Arc::clone(CALENDAR_TABLES.lock().unwrap().entry(key).or_insert(tables))
+    }
+
+    /// The cached [YearTable] for the given year, built the first time it's requested.
+    pub(crate) fn year_table(&self, year: u16) -> Arc<YearTable> {
+        let tables = self.tables();
+        if let Some(table) = tables.years.lock().unwrap().get(&year) {
+            return Arc::clone(table);
+        }
+
+        // build outside the lock: building a table may require tables for other years
+        let table = Arc::new(YearTable::new(self, year));
+        let mut years = tables.years.lock().unwrap();
+        if years.len() >= MAX_CACHED_YEARS && !years.contains_key(&year) {
+            if let Some(farthest) = years
+                .keys()
+                .copied()
+                .max_by_key(|cached| (i32::from(*cached) - i32::from(year)).abs())
+            {
+                years.remove(&farthest);
+            }
+        }
+        Arc::clone(years.entry(year).or_insert(table))
+    }
+
+    /// Drops every cached table, so the next calculation starts from scratch.
+    #[cfg(test)]
+    pub(crate) fn clear_tables() {
+        CALENDAR_TABLES.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BCP1979_CALENDAR, LFF2018_CALENDAR};
+
+    #[test]
+    fn cached_tables_agree_with_the_calendar() {
+        for calendar in [BCP1979_CALENDAR, LFF2018_CALENDAR] {
+            let tables = calendar.tables();
+            for (cycle, offset, _) in calendar.weeks {
+                let linear = calendar
+                    .weeks
+                    .iter()
+                    .find(|(s_cycle, s_offset, _)| s_cycle == cycle && s_offset == offset)
+                    .map(|(_, _, week)| *week)
+                    .unwrap();
+                assert_eq!(tables.week(*cycle, *offset), linear);
+            }
+            let fallback_ranks = calendar
+                .holy_days_fallback
+                .map(|fallback| fallback.holy_day_ranks)
+                .unwrap_or_default();
+            for (_, feast, _, _) in calendar.holy_days {
+                let linear = calendar
+                    .holy_day_ranks
+                    .iter()
+                    .chain(fallback_ranks.iter())
+                    .find(|(s_feast, _)| s_feast == feast)
+                    .map(|(_, rank)| *rank)
+                    .unwrap_or(Rank::OptionalObservance);
+                assert_eq!(tables.rank(feast), linear);
+            }
+
+            let mut date = Date::from_ymd(2019, 1, 1);
+            while date.year() < 2031 {
+                let year_table = calendar.year_table(date.year());
+                assert_eq!(year_table.easter, easter_in_year(date.year().into()));
+
+                let day = calendar.liturgical_day_without_transferred_feasts(date, false);
+                for evening in [false, true] {
+                    let evening_day =
+                        calendar.liturgical_day_without_transferred_feasts(date, evening);
+                    assert_eq!(
+                        year_table.transferred_feast(&calendar, date, evening),
+                        calendar.transferred_feast(&evening_day),
+                        "transferred feast on {} (evening: {})",
+                        date,
+                        evening
+                    );
+
+                    let indexed = Calendar::filter_holy_days(
+                        date,
+                        day.week,
+                        evening,
+                        false,
+                        year_table.holy_days.candidates(date, day.week),
+                    )
+                    .collect::<Vec<_>>();
+                    let linear = Calendar::filter_holy_days(
+                        date,
+                        day.week,
+                        evening,
+                        false,
+                        calendar.holy_days.iter(),
+                    )
+                    .collect::<Vec<_>>();
+                    assert_eq!(indexed, linear, "holy days on {}", date);
+                }
+                date = date.add_days(1);
+            }
+        }
+    }
+
+    #[test]
+    fn year_cache_is_bounded() {
+        for year in 1900..1900 + 2 * MAX_CACHED_YEARS as u16 {
+            BCP1979_CALENDAR.year_table(year);
+        }
+        assert!(BCP1979_CALENDAR.tables().years.lock().unwrap().len() <= MAX_CACHED_YEARS);
+    }
+}
@@ -23,6 +23,19 @@ where
 }
 
 impl CanticleTable {
+    /// Every canticle that appears anywhere in the table, in order of first appearance.
+    pub fn canticles(&self) -> impl Iterator<Item = CanticleId> + '_ {
+        let mut seen = Vec::new();
+        self.0.iter().filter_map(move |entry| {
+            if seen.contains(&entry.canticle) {
+                None
+            } else {
+                seen.push(entry.canticle);
+                Some(entry.canticle)
+            }
+        })
+    }
+
     pub fn find(
         &self,
         calendar: &Calendar,
@@ -5,6 +5,7 @@ use docx_rs::{
     AlignmentType, BreakType, DocumentChild, Docx, PageMargin, Paragraph, Run, Table, TableCell,
     TableRow,
 };
+use language::Language;
 use liturgy::*;
 
 mod styles;
@@ -109,6 +110,12 @@ fn add_content(docx: Docx, doc: &Document) -> Docx {
         Content::CollectOfTheDay { allow_multiple: _ } => {
             docx.add_paragraph(paragraph_with_text("The Collect of the Day").style(HEADING_3))
         }
+        Content::ProperPreface => {
+            docx.add_paragraph(paragraph_with_text("The Proper Preface").style(RUBRIC))
+        }
+        Content::CycleOfPrayer(lookup) => {
+            docx.add_paragraph(paragraph_with_text(&lookup.template).style(RUBRIC))
+        }
         Content::DocumentLink { .. } => docx,
         Content::Empty => docx,
         Content::Error(content) => content.add_to_docx(docx),
@@ -134,21 +141,199 @@ fn add_content(docx: Docx, doc: &Document) -> Docx {
     }
 }
 
+// Sizes in half-points, relative to the default size of 24
+const SMALL_CAPS_SIZE: usize = 19;
+const VERSE_NUMBER_SIZE: usize = 16;
+
 fn paragraph_with_text(text: impl std::fmt::Display) -> Paragraph {
-    let para = Paragraph::new();
-    let text = text.to_string();
-    let lines = text.split('\n').collect::<Vec<_>>();
-    let lines_len = lines.len();
-
-    lines.iter().enumerate().fold(para, |para, (idx, text)| {
-        let run = Run::new().add_text(*text);
-        if idx == lines_len - 1 {
-            para.add_run(run)
+    add_inline(
+        Paragraph::new(),
+        InlineText::from(text.to_string()).as_slice(),
+        RunFormat::default(),
+    )
+}
+
+/// How the runs added for a piece of [InlineText] are formatted
+#[derive(Clone, Copy, Default)]
+struct RunFormat<'a> {
+    bold: bool,
+    italic: bool,
+    /// Added at the start of each new line
+    indent: &'a str,
+}
+
+impl<'a> RunFormat<'a> {
+    fn run(&self, text: &str) -> Run {
+        let run = Run::new().add_text(text);
+        let run = if self.bold { run.bold() } else { run };
+        if self.italic {
+            run.italic()
         } else {
-            para.add_run(run.add_break(BreakType::TextWrapping))
+            run
+        }
+    }
+}
+
+/// Adds [InlineText] to the paragraph as runs. Italics within italics are set in roman, small capitals are
+/// imitated with smaller capitals after the first letter, and the brackets around optional words are kept.
+fn add_inline(para: Paragraph, inline: &[Inline], format: RunFormat) -> Paragraph {
+    inline.iter().fold(para, |para, piece| match piece {
+        Inline::Text(text) => para.add_run(format.run(text)),
+        Inline::Italic(children) => add_inline(
+            para,
+            children,
+            RunFormat {
+                italic: !format.italic,
+                ..format
+            },
+        ),
+        Inline::SmallCaps(word) => {
+            let first_len = word.chars().next().map(char::len_utf8).unwrap_or(0);
+            para.add_run(format.run(&word[..first_len]))
+                .add_run(format.run(&word[first_len..]).size(SMALL_CAPS_SIZE))
         }
+        Inline::Optional(children) => {
+            add_inline(para.add_run(format.run("[")), children, format).add_run(format.run("]"))
+        }
+        Inline::LineBreak => para.add_run(
+            Run::new()
+                .add_break(BreakType::TextWrapping)
+                .add_text(format.indent),
+        ),
+        Inline::Placeholder(placeholder) => para.add_run(
+            RunFormat {
+                italic: true,
+                ..format
+            }
+            .run(placeholder.trim_matches('*')),
+        ),
+        Inline::VerseNumber(number) => para.add_run(
+            Run::new()
+                .add_text(format!("{} ", number))
+                .size(VERSE_NUMBER_SIZE),
+        ),
     })
 }
+
+/// Adds the sections of a psalm or canticle, each as a paragraph. If it is to be recited in a particular way,
+/// whoever says each verse is named when it changes, and any refrain is inserted after the appropriate verses.
+fn add_psalm_sections(
+    docx: Docx,
+    sections: Vec<Vec<(String, String, Option<Pointing>)>>,
+    recitation: Option<&Recitation>,
+    refrain: Option<Antiphon>,
+) -> Docx {
+    let count = sections.iter().map(Vec::len).sum();
+    let mut nth = 0;
+    let mut previous_speaker = None;
+    sections
+        .into_iter()
+        .enumerate()
+        .fold(docx, |docx, (section_idx, verses)| {
+            let mut para = Paragraph::new().style(PSALM_OR_CANTICLE);
+            if let (0, Some(refrain)) = (section_idx, &refrain) {
+                para = add_refrain(para, refrain, &mut previous_speaker);
+            }
+            for (a, b, pointing) in verses {
+                let speakers = recitation.map(|recitation| recitation.speakers(nth));
+                para = add_verse(
+                    para,
+                    &a,
+                    &b,
+                    pointing.as_ref(),
+                    speakers,
+                    &mut previous_speaker,
+                );
+                if let (Some(recitation), Some(refrain)) = (recitation, &refrain) {
+                    if recitation.refrain_after(nth, count) {
+                        para = add_refrain(para, refrain, &mut previous_speaker);
+                    }
+                }
+                nth += 1;
+            }
+            docx.add_paragraph(para)
+        })
+}
+
+fn add_speaker(
+    para: Paragraph,
+    speaker: Speaker,
+    previous_speaker: &mut Option<Speaker>,
+) -> Paragraph {
+    if *previous_speaker == Some(speaker) {
+        para
+    } else {
+        *previous_speaker = Some(speaker);
+        para.add_run(
+            Run::new()
+                .add_text(format!("{}: ", speaker.label(Language::default())))
+                .italic(),
+        )
+    }
+}
+
+fn add_refrain(
+    para: Paragraph,
+    refrain: &Antiphon,
+    previous_speaker: &mut Option<Speaker>,
+) -> Paragraph {
+    add_speaker(para, Speaker::People, previous_speaker).add_run(
+        Run::new()
+            .add_text(refrain.to_string())
+            .bold()
+            .add_break(BreakType::TextWrapping),
+    )
+}
+
+/// Adds a verse of a psalm or canticle to the paragraph, one line at a time, with the second half indented.
+/// If the verse has been pointed, accented syllables are bold, and bar lines and flexes are marked with | and †.
+fn add_verse(
+    para: Paragraph,
+    a: &str,
+    b: &str,
+    pointing: Option<&Pointing>,
+    speakers: Option<(Speaker, Speaker)>,
+    previous_speaker: &mut Option<Speaker>,
+) -> Paragraph {
+    let no_marks = Pointing::default();
+    let pointing = pointing.unwrap_or(&no_marks);
+    let (a_speaker, b_speaker) = speakers.unzip();
+    [
+        (a, &pointing.a, "", a_speaker),
+        (b, &pointing.b, "\t", b_speaker),
+    ]
+    .into_iter()
+    .fold(para, |para, (text, marks, indent, speaker)| {
+        let para = para.add_run(Run::new().add_text(indent));
+        let para = match speaker {
+            Some(speaker) if !text.is_empty() => add_speaker(para, speaker, previous_speaker),
+            _ => para,
+        };
+        let mut after_space = true;
+        let para = Pointing::apply(text, marks)
+            .into_iter()
+            .fold(para, |para, piece| match piece {
+                PointedText::Text(text) | PointedText::Accented(text) => {
+                    after_space = text.ends_with(char::is_whitespace);
+                    add_inline(
+                        para,
+                        InlineText::from(text).as_slice(),
+                        RunFormat {
+                            bold: matches!(piece, PointedText::Accented(_)),
+                            italic: false,
+                            indent,
+                        },
+                    )
+                }
+                PointedText::Bar => {
+                    para.add_run(Run::new().add_text(if after_space { "| " } else { "|" }))
+                }
+                PointedText::Flex => para.add_run(Run::new().add_text(" †")),
+            });
+        para.add_run(Run::new().add_break(BreakType::TextWrapping))
+    })
+}
+
 trait AddToDocx {
     fn add_to_docx(&self, docx: Docx) -> Docx;
 }
@@ -217,24 +402,21 @@ impl AddToDocx for Canticle {
 
         let docx = docx.add_paragraph(header);
 
-        self.sections.iter().fold(docx, |docx, section| {
-            let paragraph = section.verses.iter().fold(
-                Paragraph::new().style(PSALM_OR_CANTICLE),
-                |para, verse| {
-                    verse
-                        .a
-                        .split('\n')
-                        .map(String::from)
-                        .chain(verse.b.split('\n').map(|b| format!("\t{b}")))
-                        .fold(para, |para, line| {
-                            para.add_run(
-                                Run::new().add_text(line).add_break(BreakType::TextWrapping),
-                            )
-                        })
-                },
-            );
-            docx.add_paragraph(paragraph)
-        })
+        add_psalm_sections(
+            docx,
+            self.sections
+                .iter()
+                .map(|section| {
+                    section
+                        .verses
+                        .iter()
+                        .map(|verse| (verse.a.clone(), verse.b.clone(), verse.pointing.clone()))
+                        .collect()
+                })
+                .collect(),
+            self.recitation.as_ref(),
+            self.refrain(),
+        )
     }
 }
 
@@ -360,9 +542,9 @@ impl AddToDocx for LectionaryReading {
 
 impl AddToDocx for Litany {
     fn add_to_docx(&self, docx: Docx) -> Docx {
-        self.iter().fold(docx, |docx, line| {
+        self.inline().into_iter().fold(docx, |docx, line| {
             docx.add_paragraph(
-                Paragraph::new().add_run(Run::new().add_text(line)).add_run(
+                add_inline(Paragraph::new(), line.as_slice(), RunFormat::default()).add_run(
                     Run::new()
                         .add_text(self.response.clone())
                         .bold()
@@ -409,25 +591,22 @@ impl AddToDocx for Psalm {
 
         let docx = docx.add_paragraph(header);
 
-        self.filtered_sections().iter().fold(docx, |docx, section| {
-            let paragraph = section.verses.iter().fold(
-                // TODO add psalm local name/Latin name as well
-                Paragraph::new().style(PSALM_OR_CANTICLE),
-                |para, verse| {
-                    verse
-                        .a
-                        .split('\n')
-                        .map(String::from)
-                        .chain(verse.b.split('\n').map(|b| format!("\t{b}")))
-                        .fold(para, |para, line| {
-                            para.add_run(
-                                Run::new().add_text(line).add_break(BreakType::TextWrapping),
-                            )
-                        })
-                },
-            );
-            docx.add_paragraph(paragraph)
-        })
+        // TODO add psalm local name/Latin name as well
+        add_psalm_sections(
+            docx,
+            self.filtered_sections()
+                .into_iter()
+                .map(|section| {
+                    section
+                        .verses
+                        .into_iter()
+                        .map(|verse| (verse.a, verse.b, verse.pointing))
+                        .collect()
+                })
+                .collect(),
+            self.recitation.as_ref(),
+            self.refrain(),
+        )
     }
 }
 
@@ -443,21 +622,12 @@ impl AddToDocx for ResponsivePrayer {
             .iter()
             .enumerate()
             .fold(Paragraph::new(), |paragraph, (idx, line)| {
-                if idx % 2 == 1 {
-                    line.split('\n').fold(paragraph, |paragraph, line| {
-                        paragraph.add_run(
-                            Run::new()
-                                .add_text(line)
-                                .bold()
-                                .add_break(BreakType::TextWrapping),
-                        )
-                    })
-                } else {
-                    line.split('\n').fold(paragraph, |paragraph, line| {
-                        paragraph
-                            .add_run(Run::new().add_text(line).add_break(BreakType::TextWrapping))
-                    })
-                }
+                let format = RunFormat {
+                    bold: idx % 2 == 1,
+                    ..RunFormat::default()
+                };
+                add_inline(paragraph, InlineText::from(line).as_slice(), format)
+                    .add_run(Run::new().add_break(BreakType::TextWrapping))
             });
         docx.add_paragraph(paragraph)
     }
@@ -504,7 +674,11 @@ impl AddToDocx for Sentence {
 
 impl AddToDocx for Text {
     fn add_to_docx(&self, docx: Docx) -> Docx {
-        let para = paragraph_with_text(&self.text);
+        let para = add_inline(
+            Paragraph::new(),
+            self.inline().as_slice(),
+            RunFormat::default(),
+        );
 
         let para = if let Some(response) = &self.response {
             para.add_run(Run::new().add_text(" "))
@@ -26,6 +26,10 @@ impl From<Document> for LdfJson {
         map.insert("citation".to_string(), doc.to_citation().into_value());
 
         map.insert("metadata".to_string(), doc.as_metadata());
+        // the text with its inline markup, alongside the plain text in `value`
+        if let Some(html) = doc.as_html() {
+            map.insert("html".to_string(), html);
+        }
 
         map.insert(
             "label".to_string(),
@@ -55,14 +59,16 @@ impl ConvertableDocument {
                 Some("responsive")
             }
             Content::Rubric(_) => Some("rubric"),
-            Content::Text(_) | Content::CollectOfTheDay { .. } => Some("text"),
+            Content::Text(_) | Content::CollectOfTheDay { .. } | Content::ProperPreface => {
+                Some("text")
+            }
             _ => None,
         }
     }
 
     fn as_ldf_style(&self) -> Option<&'static str> {
         match &self.0.content {
-            Content::CollectOfTheDay { .. } => Some("prayer"),
+            Content::CollectOfTheDay { .. } | Content::ProperPreface => Some("prayer"),
             Content::Antiphon(_) => Some("antiphon"),
             Content::BiblicalCitation(_) => Some("long"),
             Content::BiblicalReading(_) => Some("long"),
@@ -106,6 +112,15 @@ impl ConvertableDocument {
         }
     }
 
+    fn as_html(&self) -> Option<Value> {
+        match &self.0.content {
+            Content::Text(text) => Some(Value::Array(
+                text.text.split("\n\n").map(html_value).collect(),
+            )),
+            _ => None,
+        }
+    }
+
     fn as_metadata(&self) -> Value {
         match &self.0.content {
             Content::Choice(choice) => {
@@ -294,29 +309,23 @@ impl IntoValue for BiblicalReading {
 
 impl IntoValue for Canticle {
     fn into_value(self) -> Value {
-        Value::Array(
+        let refrain = self.refrain();
+        psalm_sections(
             self.sections
                 .into_iter()
                 .map(|section| {
-                    let mut map = Map::new();
-                    map.insert(
-                        "type".to_string(),
-                        Value::String("psalm-section".to_string()),
-                    );
-                    map.insert("label".to_string(), section.title.into_value());
-                    map.insert(
-                        "value".to_string(),
-                        Value::Array(
-                            section
-                                .verses
-                                .into_iter()
-                                .map(|verse| psalm_verse(None as Option<u16>, verse.a, verse.b))
-                                .collect(),
-                        ),
-                    );
-                    Value::Object(map)
+                    (
+                        section.title.into_value(),
+                        section
+                            .verses
+                            .into_iter()
+                            .map(|verse| (None, verse.a, verse.b))
+                            .collect(),
+                    )
                 })
                 .collect(),
+            self.recitation.as_ref(),
+            refrain,
         )
     }
 }
@@ -368,7 +377,9 @@ impl IntoValue for Invitatory {
                             section
                                 .verses
                                 .into_iter()
-                                .map(|verse| psalm_verse(None as Option<u16>, verse.a, verse.b))
+                                .map(|verse| {
+                                    psalm_verse(None as Option<u16>, verse.a, verse.b, None)
+                                })
                                 .collect(),
                         ),
                     );
@@ -386,6 +397,7 @@ impl IntoValue for Litany {
                 .into_iter()
                 .map(|line| {
                     let mut m = Map::new();
+                    m.insert("html".to_string(), html_value(&line));
                     m.insert("text".to_string(), Value::from(line));
                     Value::Object(m)
                 })
@@ -402,6 +414,7 @@ impl IntoValue for Preces {
                 .map(|(label, text)| {
                     let mut m = Map::new();
                     m.insert("label".to_string(), Value::from(label));
+                    m.insert("html".to_string(), html_value(&text));
                     m.insert("text".to_string(), Value::from(text));
                     Value::Object(m)
                 })
@@ -421,6 +434,8 @@ impl IntoValue for ResponsivePrayer {
                     let mut m = Map::new();
                     let text = n.next().unwrap_or_default();
                     let response = n.next().unwrap_or_default();
+                    m.insert("text_html".to_string(), html_value(&text));
+                    m.insert("response_html".to_string(), html_value(&response));
                     m.insert("text".to_string(), Value::from(text));
                     m.insert("response".to_string(), Value::from(response));
                     Value::Object(m)
@@ -430,40 +445,111 @@ impl IntoValue for ResponsivePrayer {
     }
 }
 
-fn psalm_verse<V: std::fmt::Display>(number: Option<V>, a: String, b: String) -> Value {
+fn psalm_verse<V: std::fmt::Display>(
+    number: Option<V>,
+    a: String,
+    b: String,
+    speakers: Option<(Speaker, Speaker)>,
+) -> Value {
     let mut map = Map::new();
     map.insert("number".to_string(), number.into_value());
+    map.insert("verse_html".to_string(), html_value(&a));
+    map.insert("halfverse_html".to_string(), html_value(&b));
     map.insert("verse".to_string(), Value::String(a));
     map.insert("halfverse".to_string(), Value::String(b));
+    if let Some((a_speaker, b_speaker)) = speakers {
+        map.insert("speaker".to_string(), speaker_value(a_speaker));
+        map.insert("halfverse_speaker".to_string(), speaker_value(b_speaker));
+    }
     Value::Object(map)
 }
 
+fn speaker_value(speaker: Speaker) -> Value {
+    Value::String(
+        match speaker {
+            Speaker::All => "all",
+            Speaker::Leader => "leader",
+            Speaker::People => "people",
+            Speaker::Cantor => "cantor",
+            Speaker::SideA => "side-a",
+            Speaker::SideB => "side-b",
+        }
+        .to_string(),
+    )
+}
+
+fn refrain_value(refrain: &Antiphon) -> Value {
+    let mut map = Map::new();
+    map.insert("type".to_string(), Value::String("refrain".to_string()));
+    map.insert("text".to_string(), Value::String(refrain.to_string()));
+    map.insert("speaker".to_string(), speaker_value(Speaker::People));
+    Value::Object(map)
+}
+
+/// A section of a psalm or canticle, as its label and its verses' (number, a, b)
+type PsalmSectionValues = (Value, Vec<(Option<u8>, String, String)>);
+
+/// Converts the sections of a psalm or canticle, marking who says each verse and inserting the refrain if it is recited responsorially
+fn psalm_sections(
+    sections: Vec<PsalmSectionValues>,
+    recitation: Option<&Recitation>,
+    refrain: Option<Antiphon>,
+) -> Value {
+    let count = sections.iter().map(|(_, verses)| verses.len()).sum();
+    let mut nth = 0;
+    Value::Array(
+        sections
+            .into_iter()
+            .enumerate()
+            .map(|(section_idx, (label, verses))| {
+                let mut values = Vec::new();
+                if let (0, Some(refrain)) = (section_idx, &refrain) {
+                    values.push(refrain_value(refrain));
+                }
+                for (number, a, b) in verses {
+                    let speakers = recitation.map(|recitation| recitation.speakers(nth));
+                    values.push(psalm_verse(number, a, b, speakers));
+                    if let (Some(recitation), Some(refrain)) = (recitation, &refrain) {
+                        if recitation.refrain_after(nth, count) {
+                            values.push(refrain_value(refrain));
+                        }
+                    }
+                    nth += 1;
+                }
+
+                let mut map = Map::new();
+                map.insert(
+                    "type".to_string(),
+                    Value::String("psalm-section".to_string()),
+                );
+                map.insert("label".to_string(), label);
+                map.insert("value".to_string(), Value::Array(values));
+                Value::Object(map)
+            })
+            .collect(),
+    )
+}
+
 #[cfg(any(feature = "browser", feature = "server"))]
 impl IntoValue for Psalm {
     fn into_value(self) -> Value {
-        Value::Array(
+        let refrain = self.refrain();
+        psalm_sections(
             self.filtered_sections()
                 .into_iter()
                 .map(|section| {
-                    let mut map = Map::new();
-                    map.insert(
-                        "type".to_string(),
-                        Value::String("psalm-section".to_string()),
-                    );
-                    map.insert("label".to_string(), Value::String(section.local_name));
-                    map.insert(
-                        "value".to_string(),
-                        Value::Array(
-                            section
-                                .verses
-                                .into_iter()
-                                .map(|verse| psalm_verse(Some(verse.number), verse.a, verse.b))
-                                .collect(),
-                        ),
-                    );
-                    Value::Object(map)
+                    (
+                        Value::String(section.local_name),
+                        section
+                            .verses
+                            .into_iter()
+                            .map(|verse| (Some(verse.number), verse.a, verse.b))
+                            .collect(),
+                    )
                 })
                 .collect(),
+            self.recitation.as_ref(),
+            refrain,
         )
     }
 }
@@ -489,6 +575,62 @@ impl IntoValue for Text {
     }
 }
 
+/// Text written with the library's inline conventions, as HTML. This is given in fields of its own
+/// (e.g., `html` alongside `text`), so that the plain-text fields are unchanged.
+fn html_value(text: &str) -> Value {
+    let mut html = String::new();
+    write_html(&mut html, InlineText::from(text).as_slice());
+    Value::String(html)
+}
+
+fn write_html(html: &mut String, inline: &[Inline]) {
+    for piece in inline {
+        match piece {
+            Inline::Text(text) => html.push_str(&escape_html(text)),
+            Inline::Italic(children) => {
+                html.push_str("<em>");
+                write_html(html, children);
+                html.push_str("</em>");
+            }
+            Inline::SmallCaps(word) => {
+                // small caps are set from lowercase letters, so that the text is still legible without styles
+                html.push_str(&format!(
+                    "<span class=\"small-caps\">{}</span>",
+                    escape_html(&capitalized(word))
+                ));
+            }
+            Inline::Optional(children) => {
+                html.push_str("<span class=\"optional\">[");
+                write_html(html, children);
+                html.push_str("]</span>");
+            }
+            Inline::LineBreak => html.push_str("<br>"),
+            Inline::Placeholder(placeholder) => html.push_str(&format!(
+                "<span class=\"placeholder\">{}</span>",
+                escape_html(placeholder.trim_matches('*'))
+            )),
+            Inline::VerseNumber(number) => {
+                html.push_str(&format!("<sup class=\"verse-number\">{}</sup>", number))
+            }
+        }
+    }
+}
+
+fn capitalized(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .into_iter()
+        .chain(chars.flat_map(char::to_lowercase))
+        .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 impl IntoValue for Rubric {
     fn into_value(self) -> Value {
         Value::Array(
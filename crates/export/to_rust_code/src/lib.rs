@@ -174,7 +174,21 @@ impl ToRustCode for Content {
                 } else {
                     ""
                 };
-                format!("Choice::from(vec![\n{children}\n]){selected}{should_rotate}")
+                let rotation = if content.rotation == Rotation::default() {
+                    String::new()
+                } else {
+                    let Rotation {
+                        basis,
+                        evening_offset,
+                        weights,
+                        no_repeat_within,
+                    } = &content.rotation;
+                    format!(
+                        ".rotation(Rotation {{ basis: RotationBasis::{:?}, evening_offset: {}, weights: vec!{:?}, no_repeat_within: {:?} }})",
+                        basis, evening_offset, weights, no_repeat_within
+                    )
+                };
+                format!("Choice::from(vec![\n{children}\n]){selected}{should_rotate}{rotation}")
             }
             Content::Parallel(content) => {
                 let children: String = content
@@ -244,6 +258,11 @@ impl ToRustCode for Content {
                 "Content::CollectOfTheDay {{ allow_multiple: {} }}",
                 allow_multiple
             ),
+            Content::ProperPreface => "Content::ProperPreface".to_string(),
+            Content::CycleOfPrayer(lookup) => format!(
+                "CycleOfPrayerLookup::new(CycleOfPrayerId::{:?}, {:?})",
+                lookup.cycle, lookup.template
+            ),
             Content::Empty => "Content::Empty".to_string(),
             Content::Error(content) => {
                 format!("Content::Error(DocumentError::from({:?}))", content)
@@ -336,7 +355,8 @@ impl ToRustCode for Content {
 
 impl ToRustCode for Condition {
     #![allow(clippy::tabs_in_doc_comments)]
-    /// Converts a [Condition](liturgy::Condition) into a `String` consisting of compileable Rust code
+    /// Converts a [Condition](liturgy::Condition) into a `String` consisting of compileable Rust code.
+    /// To convert a condition written in its text syntax, use [condition_text_to_rust_code].
     /// ```
     /// # use liturgy::Condition;
     /// # use calendar::Season;
@@ -449,16 +469,81 @@ impl ToRustCode for PreferenceKey {
 impl ToRustCode for PreferenceValue {
     fn to_rust_code(&self, _start_tabs: usize) -> String {
         match self {
-            PreferenceValue::Language(val) => format!("PreferenceValue::Language({val})"),
-            PreferenceValue::Version(val) => format!("PreferenceValue::Version({val})"),
-            PreferenceValue::Lectionary(val) => format!("PreferenceValue::Lectionary({val})"),
-            PreferenceValue::CanticleTable(val) => format!("PreferenceValue::CanticleTable({val})"),
-            PreferenceValue::ReadingType(val) => format!("PreferenceValue::ReadingType({val})"),
+            PreferenceValue::Language(val) => {
+                format!("PreferenceValue::Language(Language::{val})")
+            }
+            PreferenceValue::Version(val) => format!("PreferenceValue::Version(Version::{val:?})"),
+            PreferenceValue::Lectionary(val) => {
+                format!("PreferenceValue::Lectionary(Lectionaries::{val})")
+            }
+            PreferenceValue::WeekdayCycle(val) => {
+                format!("PreferenceValue::WeekdayCycle(WeekdayCycle::{val})")
+            }
+            PreferenceValue::CanticleTable(val) => {
+                format!("PreferenceValue::CanticleTable(CanticleTables::{val})")
+            }
+            PreferenceValue::ReadingType(val) => {
+                format!("PreferenceValue::ReadingType(ReadingType::{val})")
+            }
+            PreferenceValue::PsalmRecitation(val) => {
+                format!("PreferenceValue::PsalmRecitation(PsalmRecitation::{val:?})")
+            }
+            PreferenceValue::ChantTone(val) => format!(
+                "PreferenceValue::ChantTone(ChantTone {{ style: ChantStyle::{:?}, mediation: {}, termination: {}, flex_after: {:?} }})",
+                val.style, val.mediation, val.termination, val.flex_after
+            ),
+            PreferenceValue::Names(val) => format!(
+                "PreferenceValue::Names(Names {{ names: vec![{}], pronouns: Pronouns::{:?} }})",
+                val.names
+                    .iter()
+                    .map(|name| format!("{name:?}.to_string()"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                val.pronouns
+            ),
+            PreferenceValue::CycleOfPrayer(val) => {
+                format!("PreferenceValue::CycleOfPrayer({:?}.to_string())", val)
+            }
             PreferenceValue::Local(val) => format!("PreferenceValue::Local({:?}.to_string())", val),
             PreferenceValue::Bool(val) => format!("PreferenceValue::Bool({val})"),
             PreferenceValue::Canticle(val) => {
-                format!("PreferenceValue::Canticle({val})")
+                format!("PreferenceValue::Canticle({})", val.to_rust_code(0))
             }
         }
     }
 }
+
+impl ToRustCode for SlugPath {
+    fn to_rust_code(&self, _start_tabs: usize) -> String {
+        let slugs = self
+            .as_slice()
+            .iter()
+            .map(|slug| match slug {
+                Slug::Version(version) => format!("Slug::Version(Version::{version:?})"),
+                Slug::Canticle(id) => format!("Slug::Canticle(CanticleId::{id:?})"),
+                _ => format!("Slug::{slug:?}"),
+            })
+            .intersperse_with(|| ", ".to_string())
+            .collect::<String>();
+        format!("SlugPath::from([{slugs}])")
+    }
+}
+
+/// Parses a [Condition](liturgy::Condition) written in its text syntax (see the `FromStr` implementation for [Condition](liturgy::Condition))
+/// and converts it into a `String` consisting of compileable Rust code, so that conditions authored in
+/// configuration files can be compiled into the library.
+/// ```
+/// # use crate::to_rust_code::condition_text_to_rust_code;
+/// assert_eq!(
+///     condition_text_to_rust_code("season(Lent) and not weekday(Sun)", 0).unwrap(),
+///     "Condition::And(Box::new(Condition::Season(Season::Lent)), Box::new(Condition::Not(Box::new(Condition::Weekday(Weekday::Sun)))))"
+/// );
+/// assert!(condition_text_to_rust_code("season(Lent) and", 0).is_err());
+/// ```
+pub fn condition_text_to_rust_code(
+    text: &str,
+    start_tabs: usize,
+) -> Result<String, ConditionParseError> {
+    text.parse::<Condition>()
+        .map(|condition| condition.to_rust_code(start_tabs))
+}
@@ -5,6 +5,7 @@ mod lff2018;
 mod rcl;
 mod rcl1;
 mod rcl2;
+mod weekday_eucharist;
 
 pub use bcp1979_30_day_psalter::BCP1979_30_DAY_PSALTER;
 pub use bcp1979_daily_office_psalter::BCP1979_DAILY_OFFICE_PSALTER;
@@ -13,6 +14,9 @@ pub use lff2018::LFF2018_LECTIONARY;
 pub use rcl::{rcl_readings, RCLTrack, RCL};
 pub use rcl1::RCL_TRACK_1;
 pub use rcl2::RCL_TRACK_2;
+pub use weekday_eucharist::{
+    sunday_propers_for_weekday, weekday_eucharist_readings, WeekdayCycle, WEEKDAY_EUCHARIST,
+};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,182 @@
+use calendar::{
+    DailyOfficeYear, LiturgicalDay, LiturgicalDayId, Weekday, WeekdayEucharistYear, Year, YearType,
+};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+use crate::{
+    rcl_readings, Lectionary, RCLTrack, Reading, ReadingType, BCP1979_DAILY_OFFICE_LECTIONARY,
+    BCP1979_DAILY_OFFICE_PSALTER,
+};
+
+/// How readings are chosen for the Eucharist on a weekday without a proper of its own
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    EnumString,
+    Display,
+    Default,
+)]
+pub enum WeekdayCycle {
+    /// "The Proper appointed for the Sunday is also used at celebrations of the Eucharist
+    /// on the weekdays following, unless otherwise appointed." (BCP p. 158)
+    SundayPropers,
+    /// A two-year cycle of weekday readings (see [WEEKDAY_EUCHARIST])
+    #[default]
+    TwoYear,
+}
+
+lazy_static! {
+    static ref WEEKDAY_EUCHARIST_READINGS: Vec<(LiturgicalDayId, Year, ReadingType, &'static str)> = {
+        let lessons = BCP1979_DAILY_OFFICE_LECTIONARY
+            .readings
+            .iter()
+            .filter(|(_, _, reading_type, _)| {
+                matches!(
+                    reading_type,
+                    ReadingType::FirstReading | ReadingType::SecondReading | ReadingType::Gospel
+                )
+            })
+            .copied();
+        let psalms = BCP1979_DAILY_OFFICE_PSALTER
+            .readings
+            .iter()
+            .filter(|(_, _, reading_type, _)| *reading_type == ReadingType::MorningPsalm)
+            .map(|(id, year, _, citation)| (*id, *year, ReadingType::Psalm, *citation));
+        lessons
+            .chain(psalms)
+            .filter(|(id, _, _, _)| is_ferial(id))
+            .map(|(id, year, reading_type, citation)| {
+                let year = match year {
+                    Year::DailyOffice(DailyOfficeYear::One) => {
+                        Year::WeekdayEucharist(WeekdayEucharistYear::One)
+                    }
+                    Year::DailyOffice(DailyOfficeYear::Two) => {
+                        Year::WeekdayEucharist(WeekdayEucharistYear::Two)
+                    }
+                    year => year,
+                };
+                (id, year, reading_type, citation)
+            })
+            .collect()
+    };
+
+    /// A two-year cycle of weekday Eucharistic readings. This library has no separate table of weekday
+    /// Eucharistic readings, so the lessons and morning psalms are drawn from the weekday Daily Office
+    /// Lectionary (BCP pp. 936–1001), whose two years fall in step with the [WeekdayEucharistYear].
+    /// Sundays and holy days are not included; use the [RCL](crate::RCL) for those.
+    pub static ref WEEKDAY_EUCHARIST: Lectionary = Lectionary {
+        year_type: YearType::WeekdayEucharist,
+        readings: &WEEKDAY_EUCHARIST_READINGS,
+    };
+}
+
+/// Whether the given day is a weekday without propers of its own (i.e., not a Sunday or a feast)
+fn is_ferial(id: &LiturgicalDayId) -> bool {
+    matches!(
+        id,
+        LiturgicalDayId::WeekAndDay(_, weekday) | LiturgicalDayId::ProperAndDay(_, weekday)
+            if *weekday != Weekday::Sun
+    )
+}
+
+/// For a weekday without propers of its own, the Sunday whose propers it follows.
+/// ```
+/// # use calendar::{LiturgicalDayId, LiturgicalWeek, Proper, Weekday};
+/// # use lectionary::sunday_propers_for_weekday;
+/// assert_eq!(
+///     sunday_propers_for_weekday(&LiturgicalDayId::ProperAndDay(Proper::Proper12, Weekday::Tue)),
+///     Some(LiturgicalDayId::ProperAndDay(Proper::Proper12, Weekday::Sun))
+/// );
+/// assert_eq!(
+///     sunday_propers_for_weekday(&LiturgicalDayId::WeekAndDay(LiturgicalWeek::Lent3, Weekday::Sun)),
+///     None
+/// );
+/// ```
+pub fn sunday_propers_for_weekday(observed: &LiturgicalDayId) -> Option<LiturgicalDayId> {
+    match observed {
+        LiturgicalDayId::WeekAndDay(week, weekday) if *weekday != Weekday::Sun => {
+            Some(LiturgicalDayId::WeekAndDay(*week, Weekday::Sun))
+        }
+        LiturgicalDayId::ProperAndDay(proper, weekday) if *weekday != Weekday::Sun => {
+            Some(LiturgicalDayId::ProperAndDay(*proper, Weekday::Sun))
+        }
+        _ => None,
+    }
+}
+
+/// The Eucharistic readings for any day, using the given [WeekdayCycle] on weekdays without propers of their own.
+/// On Sundays and holy days, these are simply the [rcl_readings].
+/// If the two-year cycle has no readings for a particular weekday, the Sunday propers are used instead.
+pub fn weekday_eucharist_readings(
+    observed: &LiturgicalDayId,
+    day: &LiturgicalDay,
+    cycle: WeekdayCycle,
+    track_if_needed: RCLTrack,
+) -> Box<dyn Iterator<Item = Reading>> {
+    match (sunday_propers_for_weekday(observed), cycle) {
+        (Some(sunday), WeekdayCycle::SundayPropers) => {
+            Box::new(rcl_readings(&sunday, day, track_if_needed))
+        }
+        (Some(sunday), WeekdayCycle::TwoYear) => {
+            let mut readings = WEEKDAY_EUCHARIST.readings_by_day(observed, day).peekable();
+            if readings.peek().is_some() {
+                Box::new(readings)
+            } else {
+                Box::new(rcl_readings(&sunday, day, track_if_needed))
+            }
+        }
+        (None, _) => Box::new(rcl_readings(observed, day, track_if_needed)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calendar::{Date, BCP1979_CALENDAR};
+
+    use super::*;
+
+    #[test]
+    fn two_year_cycle_covers_ordinary_weekdays() {
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2021, 7, 13), false);
+        let readings = WEEKDAY_EUCHARIST
+            .readings_by_day(&day.observed, &day)
+            .collect::<Vec<_>>();
+        for reading_type in [
+            ReadingType::FirstReading,
+            ReadingType::Psalm,
+            ReadingType::SecondReading,
+            ReadingType::Gospel,
+        ] {
+            assert!(readings
+                .iter()
+                .any(|reading| reading.reading_type == reading_type));
+        }
+    }
+
+    #[test]
+    fn sunday_propers_on_weekdays() {
+        let tuesday = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2021, 7, 13), false);
+        let sunday = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2021, 7, 11), false);
+        let weekday_readings = weekday_eucharist_readings(
+            &tuesday.observed,
+            &tuesday,
+            WeekdayCycle::SundayPropers,
+            RCLTrack::Two,
+        )
+        .collect::<Vec<_>>();
+        let sunday_readings =
+            rcl_readings(&sunday.observed, &sunday, RCLTrack::Two).collect::<Vec<_>>();
+        assert!(!weekday_readings.is_empty());
+        assert_eq!(weekday_readings, sunday_readings);
+    }
+}
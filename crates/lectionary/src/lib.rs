@@ -6,7 +6,9 @@ pub use lectionaries::*;
 pub use reading::Reading;
 pub use reading_type::*;
 
-use calendar::{DailyOfficeYear, LiturgicalDay, LiturgicalDayId, Year, YearType};
+use calendar::{
+    DailyOfficeYear, LiturgicalDay, LiturgicalDayId, WeekdayEucharistYear, Year, YearType,
+};
 use serde::Serialize;
 
 /// Represents a given lectionary cycle of readings, e.g., the Revised Common Lectionary
@@ -30,6 +32,9 @@ impl Lectionary {
         let year = match self.year_type {
             YearType::Rcl => Year::Rcl(day.rcl_year),
             YearType::DailyOffice => Year::DailyOffice(day.daily_office_year),
+            YearType::WeekdayEucharist => {
+                Year::WeekdayEucharist(WeekdayEucharistYear::new(day.date, day.week))
+            }
             YearType::None => Year::Any,
         };
 
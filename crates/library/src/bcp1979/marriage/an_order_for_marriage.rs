@@ -13,7 +13,7 @@ lazy_static! {
             Document::from(Rubric::from("If it is desired to celebrate a marriage otherwise than as provided on page 423 of this Book [The Celebration and Blessing of a Marriage], this Order is used.")),
             Document::from(Content::DocumentLink {
                 label: "Marriage Service".into(),
-                path: SlugPath::from([Slug::Marriage, Slug::CelebrationAndBlessing]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Marriage, Slug::CelebrationAndBlessing]),
                 rotate: false,
                 link_only: false
             }),
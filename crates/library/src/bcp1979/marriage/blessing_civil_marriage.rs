@@ -17,7 +17,7 @@ lazy_static! {
             }),
             Document::from(Content::DocumentLink {
                 label: "Marriage Service".into(),
-                path: SlugPath::from([Slug::Marriage, Slug::CelebrationAndBlessing]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Marriage, Slug::CelebrationAndBlessing]),
                 rotate: false,
                 link_only: false
             }),
@@ -53,7 +53,7 @@ lazy_static! {
             Document::from(Rubric::from("The service continues with The Prayers on page 428 [in the marriage service].")),
             Document::from(Content::DocumentLink {
                 label: "Marriage Service".into(),
-                path: SlugPath::from([Slug::Marriage, Slug::CelebrationAndBlessing]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Marriage, Slug::CelebrationAndBlessing]),
                 rotate: false,
                 link_only: false
             }),
@@ -134,7 +134,7 @@ lazy_static! {
             Document::from(Rubric::from("If there is to be a presentation or a giving in marriage, it takes place at this time.")).tags([PRESENTATION_RUBRIC]),
             Document::from(Content::DocumentLink {
                 label: "Additional Directions".into(),
-                path: SlugPath::from([Slug::Marriage, Slug::AdditionalDirections]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Marriage, Slug::AdditionalDirections]),
                 rotate: false,
                 link_only: false
             }).tags([ADDITIONAL_DIRECTIONS_PARALLEL]),
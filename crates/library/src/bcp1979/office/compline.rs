@@ -131,7 +131,17 @@ lazy_static! {
         Document::from(Rubric::from("The Officiant concludes")),
         Document::from(Text::from("The almighty and merciful Lord, Father, Son, and Holy Spirit, bless us and keep us.").response("Amen."))
     ]))
-    .evening(true))
+    .evening(true)
+    .preferences([
+        LiturgyPreference::from((
+            PreferenceKey::from(GlobalPref::InsertGloria),
+            "Gloria Patri after Each Psalm",
+            [
+                LiturgyPreferenceOption::from(("No", PreferenceValue::Bool(false))),
+                LiturgyPreferenceOption::from(("Yes", PreferenceValue::Bool(true))),
+            ]
+        )).category("Liturgy")
+    ]))
     .version(Version::BCP1979)
     .page(127)
     .label("Compline");
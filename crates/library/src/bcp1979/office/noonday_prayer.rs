@@ -103,6 +103,16 @@ lazy_static! {
                     ("Officiant", "Let us bless the Lord."),
                     ("People", "Thanks be to God.")
                 ]))
-            ]))))
+            ]))
+            .preferences([
+                LiturgyPreference::from((
+                    PreferenceKey::from(GlobalPref::InsertGloria),
+                    "Gloria Patri after Each Psalm",
+                    [
+                        LiturgyPreferenceOption::from(("No", PreferenceValue::Bool(false))),
+                        LiturgyPreferenceOption::from(("Yes", PreferenceValue::Bool(true))),
+                    ]
+                )).category("Liturgy")
+            ])))
             .version(Version::BCP1979);
 }
@@ -48,7 +48,7 @@ lazy_static! {
        Document::from(Text::from("Almighty God, we thank you for making the earth fruitful, so that it might produce what is needed for life: Bless those who work in the fields; give us seasonable weather; and grant that we may all share the fruits of the earth, rejoicing in your goodness; through Jesus Christ our Lord.").response("Amen.")).label("29. For Agriculture").page(824).tags(["Prayers", "Prayers for the Social Order"]),
        Document::from(Text::from("Heavenly Father, we remember before you those who suffer want and anxiety from lack of work. Guide the people of this land so to use our public and private wealth that all may find suitable and fulfilling employment, and receive just payment for their labor; through Jesus Christ our Lord.").response("Amen.")).label("30. For the Unemployed").page(824).tags(["Prayers", "Prayers for the Social Order"]),
        Document::from(Text::from("O Eternal God, bless all schools, colleges, and universities [and especially _________ ], that they may be lively centers for sound learning, new discovery, and the pursuit of wisdom; and grant that those who teach and those who learn may find you to be the source of all truth; through Jesus Christ our Lord.").response("Amen.")).label("31. For Schools and Colleges").page(824).tags(["Prayers", "Prayers for the Social Order"]),
-       Document::from(Text::from("O God, in the course of this busy life, give us times of refreshment and peace; and grant that we may so use our leisure to rebuild our bodies and renew our minds, that our spirits may be opened to the goodness of your creation; through Jesus Christ our Lord.").response("Amen.")).label("For the Good Use of Leisure").page(825).tags(["Prayers", "Prayers for the Social Order"]),
+       Document::from(Text::from("O God, in the course of this busy life, give us times of refreshment and peace; and grant that we may so use our leisure to rebuild our bodies and renew our minds, that our spirits may be opened to the goodness of your creation; through Jesus Christ our Lord.").response("Amen.")).label("32. For the Good Use of Leisure").page(825).tags(["Prayers", "Prayers for the Social Order"]),
        Document::from(Text::from("Heavenly Father, in your Word you have given us a vision of that holy City to which the nations of the world bring their glory: Behold and visit, we pray, the cities of the earth. Renew the ties of mutual regard which form our civic life. Send us honest and able leaders. Enable us to eliminate poverty, prejudice, and oppression, that peace may prevail with righteousness, and justice with order, and that men and women from different cultures and with differing talents may find with one another the fulfillment of their humanity; through Jesus Christ our Lord.").response("Amen.")).label("33. For Cities").page(825).tags(["Prayers", "Prayers for the Social Order"]),
        Document::from(Text::from("Lord Christ, when you came among us, you proclaimed the kingdom of God in villages, towns, and lonely places: Grant that your presence and power may be known throughout this land. Have mercy upon all of us who live and work in rural areas [especially _________ ]; and grant that all the people of our nation may give thanks to you for food and drink and all other bodily necessities of life, respect those who labor to produce them, and honor the land and the water from which these good things come. All this we ask in your holy Name.").response("Amen.")).label("34. For Towns and Rural Areas").page(825).tags(["Prayers", "Prayers for the Social Order"]),
        Document::from(Text::from("Almighty and most merciful God, we remember before you all poor and neglected persons whom it would be easy for us to forget: the homeless and the destitute, the old and the sick, and all who have none to care for them. Help us to heal those who are broken in body or spirit, and to turn their sorrow into joy. Grant this, Father, for the love of your Son, who for our sake became poor, Jesus Christ our Lord.").response("Amen.")).label("35. For the Poor and the Neglected").page(826).tags(["Prayers", "Prayers for the Social Order"]),
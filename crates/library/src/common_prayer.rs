@@ -1,14 +1,16 @@
 use canticle_table::{CanticleId, CanticleTable};
 use lectionary::Lectionary;
 use liturgy::{
-    parallel_table::build_parallel_table, CanticleTables, Document, Lectionaries, SlugPath, Version,
+    parallel_table::build_parallel_table, CanticleTables, Document,
+    Lectionaries, SlugPath, Version,
 };
 use psalter::{bcp1979::BCP1979_PSALTER, Psalter};
 
 use crate::{
     bcp1979, bos, eow, loc,
     marriage_alternatives::{self, parallels::MARRIAGE_PARALLEL_TAGS},
-    rite1, rite2, Contents, Library, Section, Slug, TableOfContents,
+    rite1, rite2, Contents, Library, PrefaceId, Section, Slug,
+    TableOfContents,
 };
 
 pub struct CommonPrayer {}
@@ -25,6 +27,7 @@ impl Library for CommonPrayer {
             Lectionaries::BCP1979ThirtyDayPsalms => &lectionary::BCP1979_30_DAY_PSALTER,
             Lectionaries::RCLTrack1 => &lectionary::RCL_TRACK_1,
             Lectionaries::RCLTrack2 => &lectionary::RCL_TRACK_2,
+            Lectionaries::WeekdayEucharist => &lectionary::WEEKDAY_EUCHARIST,
         }
     }
 
@@ -539,11 +542,48 @@ impl Library for CommonPrayer {
                                 ),
                                 (
                                     Slug::ProperPrefaces,
-                                    Contents::MultiDocument { 
+                                    Contents::Category {
                                         label: "Proper Prefaces".into(),
-                                        documents: vec![
-                                        ],
-                                        hidden_in_toc: false
+                                        contents: vec![
+                                            (
+                                                Slug::Version(Version::RiteI),
+                                                Contents::MultiDocument {
+                                                    label: "Rite I".into(),
+                                                    documents: rite1::eucharist::PROPER_PREFACES_I.iter().map(|(_, doc)| doc.clone()).collect(),
+                                                    hidden_in_toc: false,
+                                                }
+                                            ),
+                                            (
+                                                Slug::Version(Version::RiteII),
+                                                Contents::MultiDocument {
+                                                    label: "Rite II".into(),
+                                                    documents: rite2::eucharist::PROPER_PREFACES_II.iter().map(|(_, doc)| doc.clone()).collect(),
+                                                    hidden_in_toc: false,
+                                                }
+                                            ),
+                                            (
+                                                Slug::Burial,
+                                                Contents::ByVersion {
+                                                    label: "Preface of the Commemoration of the Dead".into(),
+                                                    documents: rite1::eucharist::PROPER_PREFACES_I.iter()
+                                                        .chain(rite2::eucharist::PROPER_PREFACES_II.iter())
+                                                        .filter(|(id, _)| *id == PrefaceId::CommemorationOfTheDead)
+                                                        .map(|(_, doc)| doc)
+                                                        .collect()
+                                                }
+                                            ),
+                                            (
+                                                // the marriage rites are in contemporary language
+                                                Slug::Marriage,
+                                                Contents::ByVersion {
+                                                    label: "Preface of Marriage".into(),
+                                                    documents: rite2::eucharist::PROPER_PREFACES_II.iter()
+                                                        .filter(|(id, _)| *id == PrefaceId::Marriage)
+                                                        .map(|(_, doc)| doc)
+                                                        .collect()
+                                                }
+                                            )
+                                        ]
                                     }
                                 )
                             ]
@@ -848,3 +888,332 @@ impl Library for CommonPrayer {
         ])
     }
 }
+
+#[cfg(all(test, any(feature = "browser", feature = "server")))]
+mod tests {
+    use std::collections::HashMap;
+
+    use calendar::{Date, BCP1979_CALENDAR};
+    use language::Language;
+    use liturgy::{
+        Antiphon, ChantTone, ConditionProblem, Content, GlobalPref, LiturgyPreferences, Names,
+        PlaceholderContext, PlaceholderRole, PreferenceKey, PreferenceValue, Pronouns, PsalmCitation, PsalmRecitation,
+        Recitation, Series, Simplified,
+    };
+    use psalter::loc::LOC_PSALTER;
+
+    use super::*;
+
+    #[test]
+    fn every_condition_can_be_met() {
+        let problems = CommonPrayer::contents()
+            .flatten()
+            .flat_map(|(path, contents)| {
+                contents
+                    .as_documents()
+                    .flat_map(|doc| doc.analyze_conditions())
+                    .filter(|diagnostic| diagnostic.problem != ConditionProblem::AlwaysTrue)
+                    .map(|diagnostic| {
+                        format!(
+                            "{} {:?}: {:?} {}",
+                            path, diagnostic.path, diagnostic.problem, diagnostic.condition
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert!(problems.is_empty(), "{}", problems.join("\n"));
+    }
+
+    #[test]
+    fn every_text_round_trips_through_inline_markup() {
+        let mismatches = CommonPrayer::contents()
+            .flatten()
+            .flat_map(|(path, contents)| {
+                contents
+                    .as_documents()
+                    .flat_map(|doc| doc.flatten())
+                    .flat_map(|doc| match &doc.content {
+                        Content::Text(text) => vec![(text.text.clone(), text.inline())],
+                        Content::Litany(litany) => {
+                            litany.lines.iter().cloned().zip(litany.inline()).collect()
+                        }
+                        Content::Preces(preces) => preces
+                            .iter()
+                            .map(|(_, text)| text.clone())
+                            .zip(preces.inline().into_iter().map(|(_, inline)| inline))
+                            .collect(),
+                        _ => Vec::new(),
+                    })
+                    .filter(|(source, inline)| *source != inline.to_string())
+                    .map(|(source, _)| format!("{}: {:?}", path, source))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+    }
+
+    #[test]
+    fn static_analysis_agrees_with_date_coverage() {
+        let prefs: [(PreferenceKey, PreferenceValue); 0] = [];
+        let liturgy_prefs = LiturgyPreferences::default();
+        for (path, contents) in CommonPrayer::contents().flatten() {
+            for doc in contents.as_documents() {
+                for coverage in doc.date_coverage(&BCP1979_CALENDAR, 2023, &prefs, &liturgy_prefs) {
+                    if !coverage.dates.is_empty() {
+                        assert_ne!(
+                            coverage.condition.simplify(),
+                            Simplified::Never,
+                            "{} {:?} was included on {} days but was marked unsatisfiable",
+                            path,
+                            coverage.path,
+                            coverage.dates.len()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn psalms_are_marked_with_the_preferred_recitation() {
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 7, 9), false);
+        let prefs = HashMap::from([(
+            PreferenceKey::from(GlobalPref::PsalmRecitation),
+            PreferenceValue::from(PsalmRecitation::Responsorial(3)),
+        )]);
+        let doc = Document::from(Series::from(vec![
+            Document::from(Antiphon::from("The Lord is my shepherd.")),
+            Document::from(PsalmCitation::from("Psalm 23")),
+            Document::from(PsalmCitation::from("Psalm 100")),
+        ]));
+        let compiled = CommonPrayer::compile(
+            doc,
+            &BCP1979_CALENDAR,
+            &day,
+            &day.observed,
+            &prefs,
+            &LiturgyPreferences::default(),
+        )
+        .unwrap();
+        let psalms = compiled
+            .flatten()
+            .into_iter()
+            .filter_map(|doc| match &doc.content {
+                Content::Psalm(psalm) => Some(psalm),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(psalms.len(), 2);
+        // the antiphon before the psalm is its refrain
+        assert_eq!(
+            psalms[0].refrain(),
+            Some(Antiphon::from("The Lord is my shepherd."))
+        );
+        // otherwise, the first verse is used
+        assert_eq!(
+            psalms[1].refrain(),
+            Some(Antiphon::from(
+                "Be joyful in the LORD, all you lands; serve the LORD with gladness and come before his presence with a song."
+            ))
+        );
+        assert_eq!(
+            psalms[1].recitation,
+            Some(Recitation::from(PsalmRecitation::Responsorial(3)))
+        );
+    }
+
+    #[test]
+    fn every_psalm_in_a_citation_is_marked_with_the_preferred_recitation() {
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 7, 9), false);
+        let prefs = HashMap::from([(
+            PreferenceKey::from(GlobalPref::PsalmRecitation),
+            PreferenceValue::from(PsalmRecitation::Antiphonal),
+        )]);
+        let compiled = CommonPrayer::compile(
+            Document::from(PsalmCitation::from("Psalms 1, 2")),
+            &BCP1979_CALENDAR,
+            &day,
+            &day.observed,
+            &prefs,
+            &LiturgyPreferences::default(),
+        )
+        .unwrap();
+        let recitations = compiled
+            .flatten()
+            .into_iter()
+            .filter_map(|doc| match &doc.content {
+                Content::Psalm(psalm) => Some(psalm.recitation.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            recitations,
+            vec![Some(Recitation::from(PsalmRecitation::Antiphonal)); 2]
+        );
+    }
+
+    #[test]
+    fn english_psalms_are_pointed_for_the_preferred_tone() {
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 7, 9), false);
+        let prefs = HashMap::from([(
+            PreferenceKey::from(GlobalPref::ChantTone),
+            PreferenceValue::from(ChantTone::ANGLICAN_SINGLE),
+        )]);
+        let doc = Document::from(Series::from(vec![
+            Document::from(PsalmCitation::from("Psalm 1")),
+            Document::from(LOC_PSALTER.psalm_by_number(1).unwrap().clone())
+                .language(Language::Es)
+                .version(Version::LibroDeOracionComun),
+        ]));
+        let compiled = CommonPrayer::compile(
+            doc,
+            &BCP1979_CALENDAR,
+            &day,
+            &day.observed,
+            &prefs,
+            &LiturgyPreferences::default(),
+        )
+        .unwrap();
+        let pointed = compiled
+            .flatten()
+            .into_iter()
+            .filter_map(|doc| match &doc.content {
+                Content::Psalm(psalm) => Some((
+                    doc.language,
+                    psalm
+                        .sections
+                        .iter()
+                        .flat_map(|section| section.verses.iter())
+                        .all(|verse| verse.pointing.is_some()),
+                )),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // the pointing heuristics are for English, so the Spanish psalm is left as it is
+        assert_eq!(pointed, vec![(Language::En, true), (Language::Es, false)]);
+    }
+
+    #[test]
+    fn placeholders_are_filled_from_preferences() {
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 7, 11), false);
+        let prefs = HashMap::from([(
+            PreferenceKey::from(GlobalPref::DeceasedName),
+            PreferenceValue::from(Names::new(["Margaret"], Pronouns::She)),
+        )]);
+        let compiled = CommonPrayer::compile(
+            rite2::burial::BURIAL_RITE_II.clone(),
+            &BCP1979_CALENDAR,
+            &day,
+            &day.observed,
+            &prefs,
+            &LiturgyPreferences::default(),
+        )
+        .unwrap();
+        assert!(compiled.contains("we remember before you this day our sister Margaret We thank you for giving her to us, her family"));
+        assert!(!compiled.contains("brother (sister)"));
+        assert!(compiled
+            .unfilled_placeholders()
+            .iter()
+            .all(|placeholder| placeholder.role != Some(PlaceholderRole::Deceased)));
+    }
+
+    #[test]
+    fn placeholders_are_filled_from_context_and_unfilled_ones_are_reported() {
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 7, 11), false);
+        let compile = |placeholders: &PlaceholderContext| {
+            CommonPrayer::compile_with_placeholders(
+                rite2::burial::BURIAL_RITE_II.clone(),
+                &BCP1979_CALENDAR,
+                &day,
+                &day.observed,
+                &HashMap::new(),
+                &LiturgyPreferences::default(),
+                placeholders,
+            )
+            .unwrap()
+        };
+
+        let (_, unfilled) = compile(&PlaceholderContext::new());
+        assert!(unfilled
+            .iter()
+            .any(|placeholder| placeholder.placeholder == "*N.*"));
+
+        let context = PlaceholderContext::new().with(
+            PlaceholderRole::Deceased,
+            Names::new(["Margaret"], Pronouns::She),
+        );
+        let (compiled, unfilled) = compile(&context);
+        assert!(compiled.contains("our sister Margaret"));
+        assert_eq!(unfilled, compiled.unfilled_placeholders());
+        assert!(unfilled
+            .iter()
+            .all(|placeholder| placeholder.role != Some(PlaceholderRole::Deceased)));
+    }
+
+    #[test]
+    fn weekday_lectionary_follows_cycle_and_track_preferences() {
+        use lectionary::{rcl_readings, RCLTrack, ReadingType, WeekdayCycle, WEEKDAY_EUCHARIST};
+        use liturgy::Lectionaries;
+
+        // Tuesday after Proper 10, Year B
+        let tuesday = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2021, 7, 13), false);
+        let sunday = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2021, 7, 11), false);
+        fn first_reading(mut readings: impl Iterator<Item = lectionary::Reading>) -> String {
+            readings
+                .find(|reading| reading.reading_type == ReadingType::FirstReading)
+                .unwrap()
+                .citation
+        }
+        let eucharist = rite2::eucharist::HOLY_EUCHARIST_II.clone();
+        let liturgy_prefs = match &eucharist.content {
+            Content::Liturgy(liturgy) => liturgy.preferences.clone(),
+            _ => LiturgyPreferences::default(),
+        };
+        let compile = |cycle: WeekdayCycle, track: Lectionaries| {
+            let mut prefs = HashMap::new();
+            prefs.insert(
+                PreferenceKey::from(GlobalPref::Lectionary),
+                PreferenceValue::from(track),
+            );
+            prefs.insert(
+                PreferenceKey::from(GlobalPref::WeekdayCycle),
+                PreferenceValue::from(cycle),
+            );
+            CommonPrayer::compile(
+                eucharist.clone(),
+                &BCP1979_CALENDAR,
+                &tuesday,
+                &tuesday.observed,
+                &prefs,
+                &liturgy_prefs,
+            )
+            .unwrap()
+        };
+
+        // `Document::contains` does not search the citations of biblical readings
+        fn cites(document: &Document, citation: &str) -> bool {
+            serde_json::to_string(document)
+                .unwrap()
+                .contains(&format!("\"citation\":\"{}\"", citation))
+        }
+
+        let weekday =
+            first_reading(WEEKDAY_EUCHARIST.readings_by_day(&tuesday.observed, &tuesday));
+        let track_one = first_reading(rcl_readings(&sunday.observed, &sunday, RCLTrack::One));
+        let track_two = first_reading(rcl_readings(&sunday.observed, &sunday, RCLTrack::Two));
+        assert_ne!(track_one, track_two);
+
+        let two_year = compile(WeekdayCycle::TwoYear, Lectionaries::RCLTrack1);
+        assert!(cites(&two_year, &weekday));
+        assert!(!cites(&two_year, &track_one));
+
+        let sunday_track_one = compile(WeekdayCycle::SundayPropers, Lectionaries::RCLTrack1);
+        assert!(cites(&sunday_track_one, &track_one));
+        assert!(!cites(&sunday_track_one, &weekday));
+
+        let sunday_track_two = compile(WeekdayCycle::SundayPropers, Lectionaries::RCLTrack2);
+        assert!(cites(&sunday_track_two, &track_two));
+        assert!(!cites(&sunday_track_two, &track_one));
+    }
+}
@@ -0,0 +1,29 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use lazy_static::lazy_static;
+use liturgy::CycleOfPrayer;
+
+lazy_static! {
+    /// Cycles of prayer given to [register_cycle_of_prayer], keyed by their identifiers
+    static ref CYCLES_OF_PRAYER: Mutex<HashMap<String, Arc<CycleOfPrayer>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Makes a cycle of prayer available to [Library::cycle_of_prayer](crate::Library::cycle_of_prayer)
+/// under the identifier that clients give in the [GlobalPref::AnglicanCycleOfPrayer](liturgy::GlobalPref::AnglicanCycleOfPrayer)
+/// or [GlobalPref::DiocesanCycleOfPrayer](liturgy::GlobalPref::DiocesanCycleOfPrayer) preference,
+/// replacing any cycle already registered under it.
+pub fn register_cycle_of_prayer(id: impl ToString, cycle: CycleOfPrayer) {
+    CYCLES_OF_PRAYER
+        .lock()
+        .unwrap()
+        .insert(id.to_string(), Arc::new(cycle));
+}
+
+/// The cycle of prayer registered under the given identifier, if any
+pub fn registered_cycle_of_prayer(id: &str) -> Option<Arc<CycleOfPrayer>> {
+    CYCLES_OF_PRAYER.lock().unwrap().get(id).map(Arc::clone)
+}
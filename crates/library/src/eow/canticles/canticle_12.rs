@@ -57,7 +57,8 @@ lazy_static! {
                     CanticleVerse::from(("Let us glorify the Lord:Father, Son, and Holy Spirit; *", "sing praise and give honor for ever.")),
                     CanticleVerse::from(("In the firmament of his power, glorify the Lord, *", "sing praise and give honor for ever."))
                 ]
-            }]
+            }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference { source: Source::EOW1, page: 25 });
@@ -48,7 +48,8 @@ and holy is your Name."
                     "to Abraham and his children for ever."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .version_label("EOW")
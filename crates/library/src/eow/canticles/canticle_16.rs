@@ -57,7 +57,8 @@ and the shadow of death, *",
                     "and to guide our feet into the way of peace."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -50,7 +50,8 @@ lazy_static! {
                     ))
                 ]
             }
-        ]
+        ],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
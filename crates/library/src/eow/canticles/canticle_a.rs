@@ -46,7 +46,8 @@ lazy_static! {
                     "and gave speech to the tongues of a new-born people."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
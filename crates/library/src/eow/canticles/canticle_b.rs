@@ -51,7 +51,8 @@ even while I was very young, *",
                     "and with it will I offer praise to God."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -58,7 +58,8 @@ lazy_static! {
                     "on which the whole earth is founded."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -58,7 +58,8 @@ lazy_static! {
                     "and sorrow and sighing shall flee away."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -43,7 +43,8 @@ the wealth of nations like an overflowing stream."
                     "you shall flourish like the grass of the fields.”"
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -46,7 +46,8 @@ lazy_static! {
                     "for the coming of God’s salvation."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -35,7 +35,8 @@ lazy_static! {
                 )),
                 CanticleVerse::from(("You shall be my people, *", "and I will be your God."))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
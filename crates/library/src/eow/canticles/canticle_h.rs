@@ -34,7 +34,8 @@ lazy_static! {
                     "like spring rains that water the earth."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
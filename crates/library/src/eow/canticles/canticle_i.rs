@@ -43,7 +43,8 @@ all your waves and billows passed over me."
                     "what I have vowed I will pay, for deliverance belongs to the Lord!"
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -38,7 +38,8 @@ lazy_static! {
                     "but whoever fears the Lord shall stand in your sight for ever."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -51,7 +51,8 @@ with every spiritual blessing in the heavenly places."
                     "things in heaven and things on earth."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -38,7 +38,8 @@ lazy_static! {
                     "to the glory of God the Father."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
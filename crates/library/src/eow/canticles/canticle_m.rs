@@ -34,7 +34,8 @@ lazy_static! {
                     "so that we might have faith and hope in God."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -36,7 +36,8 @@ so that we might live through Jesus Christ."
                     "and God’s love will be perfected in us."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -43,7 +43,8 @@ and all servants give worship there; *",
                     "there they will see God’s face, whose Name shall be on their foreheads."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -44,7 +44,8 @@ that they may have the right to the tree of life, *",
                     "let those who desire take the water of life as a gift."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -46,7 +46,8 @@ lazy_static! {
                     "for the beauty of heaven, may your love prepare us."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
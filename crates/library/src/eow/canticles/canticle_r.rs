@@ -44,7 +44,8 @@ most humbly and most pure, in the Virgin’s womb."
 Christ is the one whom we love."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
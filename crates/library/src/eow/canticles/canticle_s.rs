@@ -44,7 +44,8 @@ for the honor and nobility of all, *",
                     "and for the joy and bliss of salvation."
                 ))
             ]
-        }]
+        }],
+        ..Default::default()
     })
     .version(Version::EOW)
     .source(Reference {
@@ -2,11 +2,15 @@ pub mod canticles;
 
 pub use canticles::*;
 pub mod eucharist;
+pub mod office;
 pub mod volume_2;
-use liturgy::Text;
+use liturgy::GloriaPatri;
 
 lazy_static! {
-    pub static ref GLORIA_PATRI: Text = Text::from(
-        "Praise to the holy and undivided Trinity, one God: as it was in the beginning, is now, and will be for ever. Amen."
-    );
+    pub static ref GLORIA_PATRI: GloriaPatri = GloriaPatri::from((
+        "Praise to the holy and undivided Trinity, ",
+        "one God: ",
+        "as it was in the beginning, is now, ",
+        "and will be for ever. Amen. "
+    ));
 }
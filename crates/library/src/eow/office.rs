@@ -0,0 +1,18 @@
+use liturgy::{DisplayFormat, Document, Reference, Source, Text, Version};
+
+lazy_static! {
+    pub static ref CONFESSION: Document = Document::from(
+        Text::from("God of all mercy,\nwe confess that we have sinned against you,\nopposing your will in our lives.\nWe have denied your goodness in each other,\nin ourselves, and in the world you have created.\nWe repent of the evil that enslaves us,\nthe evil we have done,\nand the evil done on our behalf.\nForgive, restore, and strengthen us\nthrough our Savior Jesus Christ,\nthat we may abide in your love\nand serve only your will.")
+            .response("Amen.")
+            .display_format(DisplayFormat::Unison)
+    )
+    .version(Version::EOW)
+    .source(Reference { source: Source::EOW1, page: 20 });
+
+    pub static ref ABSOLUTION: Document = Document::from(
+        Text::from("Almighty God have mercy on you, forgive you all your sins through the grace of Jesus Christ, strengthen you in all goodness, and by the power of the Holy Spirit keep you in eternal life.")
+            .response("Amen.")
+    )
+    .version(Version::EOW)
+    .source(Reference { source: Source::EOW1, page: 20 });
+}
@@ -0,0 +1,225 @@
+use canticle_table::CanticleId;
+use liturgy::{Content, Document, Reference, Slug, SlugPath, Source, Version};
+
+use crate::{eow, rite2};
+
+/// The versions whose texts are preferred when compiling with [GlobalPref::ExpansiveLanguage](liturgy::GlobalPref::ExpansiveLanguage),
+/// in order of preference
+pub const EXPANSIVE_VERSIONS: [Version; 2] = [Version::EOW, Version::Expansive];
+
+lazy_static! {
+    /// The links for which an expansive-language alternative is authorized, as pairs of
+    /// (original path, alternative path). Paths are listed explicitly rather than inferred from
+    /// the table of contents, because a document in one of the [EXPANSIVE_VERSIONS] is not
+    /// necessarily an alternative to the document with the same path in another version
+    /// (e.g., the EOW burial rite is the burial of a child, not an alternative to Rite II).
+    pub static ref EXPANSIVE_SUBSTITUTIONS: Vec<(SlugPath, SlugPath)> = {
+        let canticles = [
+            CanticleId::Canticle12,
+            CanticleId::Canticle15,
+            CanticleId::Canticle16,
+            CanticleId::Canticle18,
+            CanticleId::Canticle21,
+        ]
+        .iter()
+        .map(|&id| {
+            (
+                SlugPath::from([Slug::Office, Slug::Canticles, Slug::Canticle(id), Slug::Version(Version::RiteII)]),
+                SlugPath::from([Slug::Office, Slug::Canticles, Slug::Canticle(id), Slug::Version(Version::EOW)]),
+            )
+        });
+
+        canticles
+            .chain([(
+                SlugPath::from([Slug::Eucharist, Slug::GreatThanksgiving, Slug::Version(Version::RiteII)]),
+                SlugPath::from([Slug::Eucharist, Slug::GreatThanksgiving, Slug::Version(Version::EOW)]),
+            )])
+            .collect()
+    };
+}
+
+/// The expansive-language alternative to the document, if there is one. This is used by
+/// [Library::compile](crate::Library::compile) for each English document in a Rite II liturgy when the
+/// [GlobalPref::ExpansiveLanguage](liturgy::GlobalPref::ExpansiveLanguage) preference is set, and covers only
+/// 1. the Gloria Patri;
+/// 2. the Rite II confession of sin and absolution ([CONFESSION_OF_SIN](crate::rite2::CONFESSION_OF_SIN)
+///    and [ABSOLUTION](crate::rite2::ABSOLUTION));
+/// 3. links listed in [EXPANSIVE_SUBSTITUTIONS].
+///
+/// (Canticles are substituted when the canticle table is compiled.)
+///
+/// The opening sentences, the invitatory and its antiphons, the collects, and the dismissal are not
+/// covered: the library does not contain expansive-language versions of them, so they are left as they are.
+///
+/// The alternative takes the place of the original in the liturgy, keeping its condition, display, and tags,
+/// but it keeps its own version, [Reference](liturgy::Reference), and [Status](liturgy::Status), so that readers can
+/// see what has been changed; the source of the original is kept as an alternate source.
+pub fn expansive_alternative(document: &Document) -> Option<Document> {
+    let alternative = match &document.content {
+        Content::GloriaPatri(_) => Document::from(eow::GLORIA_PATRI.clone())
+            .version(Version::EOW)
+            .source(Reference {
+                source: Source::EOW1,
+                page: 20,
+            }),
+        Content::Text(text) if *text == *rite2::CONFESSION_OF_SIN => {
+            eow::office::CONFESSION.clone()
+        }
+        Content::Text(text) if *text == *rite2::ABSOLUTION => eow::office::ABSOLUTION.clone(),
+        Content::DocumentLink {
+            label,
+            path,
+            rotate,
+            link_only,
+        } => {
+            let (_, path) = EXPANSIVE_SUBSTITUTIONS
+                .iter()
+                .find(|(original, _)| original == path)?;
+            Document::from(Content::DocumentLink {
+                label: label.clone(),
+                path: path.clone(),
+                rotate: *rotate,
+                link_only: *link_only,
+            })
+        }
+        _ => return None,
+    };
+
+    Some(Document {
+        condition: document.condition.clone(),
+        display: document.display,
+        tags: document.tags.clone(),
+        label: alternative.label.clone().or_else(|| document.label.clone()),
+        version_label: alternative
+            .version_label
+            .clone()
+            .or_else(|| document.version_label.clone()),
+        language: document.language,
+        alternate_sources: document
+            .source
+            .iter()
+            .chain(document.alternate_sources.iter())
+            .copied()
+            .collect(),
+        ..alternative
+    })
+}
+
+#[cfg(all(test, any(feature = "browser", feature = "server")))]
+mod tests {
+    use std::collections::HashMap;
+
+    use calendar::{Date, BCP1979_CALENDAR};
+    use language::Language;
+    use liturgy::{GlobalPref, LiturgyPreferences, PreferenceKey, PreferenceValue};
+
+    use super::*;
+    use crate::{CommonPrayer, Library};
+
+    #[test]
+    fn expansive_language_prefers_eow_alternatives() {
+        fn find<'a>(doc: &'a Document, text: &str) -> Option<&'a Document> {
+            match &doc.content {
+                Content::Liturgy(liturgy) => {
+                    liturgy.body.iter().find_map(|child| find(child, text))
+                }
+                Content::Series(series) => series.iter().find_map(|child| find(child, text)),
+                Content::Choice(choice) => {
+                    choice.options.iter().find_map(|child| find(child, text))
+                }
+                _ if doc.contains(text) => Some(doc),
+                _ => None,
+            }
+        }
+
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 2, 13), false);
+        let compile = |expansive: bool| {
+            let mut prefs = HashMap::new();
+            prefs.insert(
+                PreferenceKey::from(GlobalPref::ExpansiveLanguage),
+                PreferenceValue::Bool(expansive),
+            );
+            CommonPrayer::compile(
+                rite2::office::MORNING_PRAYER_II.clone(),
+                &BCP1979_CALENDAR,
+                &day,
+                &day.observed,
+                &prefs,
+                &LiturgyPreferences::default(),
+            )
+            .unwrap()
+        };
+
+        let bcp = compile(false);
+        assert!(bcp.contains("Most merciful God"));
+        assert!(!bcp.contains("God of all mercy"));
+
+        let expansive = compile(true);
+        assert!(!expansive.contains("Most merciful God"));
+        assert!(!expansive.contains("through our Lord Jesus Christ, strengthen you"));
+        assert!(expansive.contains("through the grace of Jesus Christ"));
+        assert!(expansive.contains("Praise to the holy and undivided Trinity"));
+
+        // substituted texts keep their own version and source, so readers can see what has changed
+        let confession = find(&expansive, "God of all mercy").unwrap();
+        assert_eq!(confession.version, Version::EOW);
+        assert_eq!(
+            confession.source.map(|reference| reference.source),
+            Some(Source::EOW1)
+        );
+    }
+
+    #[test]
+    fn expansive_rite_ii_burial_keeps_its_own_additional_prayers() {
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 2, 13), false);
+        let mut prefs = HashMap::new();
+        prefs.insert(
+            PreferenceKey::from(GlobalPref::ExpansiveLanguage),
+            PreferenceValue::Bool(true),
+        );
+        let burial = CommonPrayer::compile(
+            rite2::burial::BURIAL_RITE_II.clone(),
+            &BCP1979_CALENDAR,
+            &day,
+            &day.observed,
+            &prefs,
+            &LiturgyPreferences::default(),
+        )
+        .unwrap();
+
+        // the EOW burial rite is the burial of a child, not an expansive version of Rite II
+        let json = serde_json::to_string(&burial).unwrap();
+        assert!(json.contains(
+            "Almighty God, with whom still live the spirits of those who die in the Lord"
+        ));
+        assert!(!json.contains("you called into being this fragile life"));
+    }
+
+    #[test]
+    fn expansive_language_is_only_used_in_english_rite_ii() {
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 2, 13), false);
+        // an office in the Libro de Oración Común, with the structure of Rite II
+        let office = rite2::office::MORNING_PRAYER_II
+            .clone()
+            .version(Version::LibroDeOracionComun)
+            .language(Language::Es);
+        let compile = |expansive: bool| {
+            let prefs = HashMap::from([(
+                PreferenceKey::from(GlobalPref::ExpansiveLanguage),
+                PreferenceValue::Bool(expansive),
+            )]);
+            CommonPrayer::compile(
+                office.clone(),
+                &BCP1979_CALENDAR,
+                &day,
+                &day.observed,
+                &prefs,
+                &LiturgyPreferences::default(),
+            )
+            .unwrap()
+        };
+        let expansive = compile(true);
+        assert!(!expansive.contains("God of all mercy"));
+        assert_eq!(expansive, compile(false));
+    }
+}
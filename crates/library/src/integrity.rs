@@ -0,0 +1,340 @@
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+};
+
+use canticle_table::CanticleId;
+use liturgy::{
+    CanticleTableChoice, CanticleTables, Condition, Content, Document, Lectionaries,
+    LectionaryTableChoice, LiturgyPreferences, PreferenceKey, PreferenceValue, SlugPath, Version,
+};
+use reference_parser::BibleReference;
+use serde::{Deserialize, Serialize};
+
+use crate::{Contents, Library, TableOfContents};
+
+/// A broken reference found somewhere in a [Library]'s table of contents.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityDiagnostic {
+    /// The path to the table-of-contents entry that contains the problem
+    pub slug_path: SlugPath,
+    /// The path to the problematic sub-document within that entry, as used by [Document::at_path].
+    /// For entries that contain several documents, the first index selects the document.
+    pub path: Vec<usize>,
+    pub problem: IntegrityProblem,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntegrityProblem {
+    /// A [Content::DocumentLink] whose path doesn't lead to anything in the table of contents
+    UnresolvedDocumentLink(SlugPath),
+    /// A [Content::PsalmCitation] that doesn't include any psalms
+    EmptyPsalmCitation(String),
+    /// A citation in a lectionary that doesn't include any psalms, verses, or canticles
+    EmptyLectionaryCitation {
+        lectionary: Lectionaries,
+        citation: String,
+    },
+    /// A canticle that can be chosen from a canticle table, but isn't available in the document's version
+    MissingCanticle {
+        table: CanticleTables,
+        canticle: CanticleId,
+        version: Version,
+    },
+    /// A [Condition::Preference] with a key that isn't declared by the [Liturgy](liturgy::Liturgy) that contains it
+    UndeclaredPreference(PreferenceKey),
+    /// A [Choice](liturgy::Choice) whose `selected` index doesn't point to one of its options
+    ChoiceSelectionOutOfRange { selected: usize, options: usize },
+}
+
+/// Checks every document in the [Library]'s table of contents for references that
+/// can't be resolved when the document is compiled.
+/// ```
+/// # use library::{check_integrity, CommonPrayer};
+/// for diagnostic in check_integrity::<CommonPrayer>() {
+///     println!("{} {:?}: {:?}", diagnostic.slug_path, diagnostic.path, diagnostic.problem);
+/// }
+/// ```
+pub fn check_integrity<L: Library>() -> Vec<IntegrityDiagnostic> {
+    let toc = L::contents();
+    let mut checker = Checker::<L> {
+        toc: &toc,
+        diagnostics: Vec::new(),
+        checked_lectionaries: HashSet::new(),
+        lectionary_problems: HashMap::new(),
+        library: std::marker::PhantomData,
+    };
+
+    // the flattened table of contents includes branches as well as leaves, and may include
+    // the same entry more than once; versioned documents are listed individually under their version
+    let mut checked = HashSet::new();
+    for (slug_path, contents) in toc.flatten() {
+        if !checked.insert(slug_path.clone()) {
+            continue;
+        }
+        match contents {
+            Contents::Document(doc) => {
+                checker.checked_lectionaries.clear();
+                checker.check(&slug_path, Vec::new(), doc, None)
+            }
+            Contents::MultiDocument { documents, .. } => {
+                for (idx, doc) in documents.iter().enumerate() {
+                    checker.checked_lectionaries.clear();
+                    checker.check(&slug_path, vec![idx], doc, None);
+                }
+            }
+            Contents::Category { .. }
+            | Contents::Sections { .. }
+            | Contents::ByVersion { .. }
+            | Contents::Parallels { .. }
+            | Contents::Page { .. } => {}
+        }
+    }
+
+    checker.diagnostics
+}
+
+struct Checker<'a, L: Library> {
+    toc: &'a TableOfContents<'static>,
+    diagnostics: Vec<IntegrityDiagnostic>,
+    /// The lectionaries already checked within the document being checked, so that each problem
+    /// is reported once for every document that uses the lectionary, rather than once for each reading
+    checked_lectionaries: HashSet<Lectionaries>,
+    /// Each lectionary's citations only need to be resolved once, no matter how many documents use it
+    lectionary_problems: HashMap<(Lectionaries, Version), Vec<IntegrityProblem>>,
+    library: std::marker::PhantomData<L>,
+}
+
+impl<L: Library> Checker<'_, L> {
+    fn report(&mut self, slug_path: &SlugPath, path: &[usize], problem: IntegrityProblem) {
+        self.diagnostics.push(IntegrityDiagnostic {
+            slug_path: slug_path.clone(),
+            path: path.to_vec(),
+            problem,
+        });
+    }
+
+    fn check(
+        &mut self,
+        slug_path: &SlugPath,
+        path: Vec<usize>,
+        doc: &Document,
+        liturgy_prefs: Option<&LiturgyPreferences>,
+    ) {
+        if let (Some(condition), Some(liturgy_prefs)) = (&doc.condition, liturgy_prefs) {
+            let mut keys = Vec::new();
+            preference_keys(condition, &mut keys);
+            for key in keys {
+                if !liturgy_prefs.iter().any(|pref| pref.key == *key) {
+                    self.report(
+                        slug_path,
+                        &path,
+                        IntegrityProblem::UndeclaredPreference(key.clone()),
+                    );
+                }
+            }
+        }
+
+        match &doc.content {
+            Content::DocumentLink { path: link, .. }
+                if self.toc.contents_at_path(link).is_none() =>
+            {
+                self.report(
+                    slug_path,
+                    &path,
+                    IntegrityProblem::UnresolvedDocumentLink(link.clone()),
+                );
+            }
+            Content::PsalmCitation(citation)
+                if L::psalter(Version::BCP1979)
+                    .psalms_by_citation(citation.as_str())
+                    .is_empty() =>
+            {
+                self.report(
+                    slug_path,
+                    &path,
+                    IntegrityProblem::EmptyPsalmCitation(citation.to_string()),
+                );
+            }
+            Content::LectionaryReading(reading) => {
+                let lectionaries = match &reading.lectionary {
+                    LectionaryTableChoice::Preference(key) => {
+                        preference_options(key, liturgy_prefs)
+                            .filter_map(|value| match value {
+                                PreferenceValue::Lectionary(lectionary) => Some(*lectionary),
+                                _ => None,
+                            })
+                            .chain(std::iter::once(Lectionaries::default()))
+                            .collect::<Vec<_>>()
+                    }
+                    LectionaryTableChoice::Selected(lectionary) => vec![*lectionary],
+                };
+                for lectionary in lectionaries {
+                    if self.checked_lectionaries.insert(lectionary) {
+                        self.check_lectionary(slug_path, &path, doc.version, lectionary);
+                    }
+                }
+            }
+            Content::CanticleTableEntry(entry) => {
+                let tables = match &entry.table {
+                    CanticleTableChoice::Preference(key) => preference_options(key, liturgy_prefs)
+                        .filter_map(|value| match value {
+                            PreferenceValue::CanticleTable(table) => Some(*table),
+                            _ => None,
+                        })
+                        .chain(std::iter::once(CanticleTables::default()))
+                        .collect::<Vec<_>>(),
+                    CanticleTableChoice::Selected(table) => vec![*table],
+                };
+                let mut checked = HashSet::new();
+                // the classical canticles are inserted directly into the liturgy, not drawn from a table
+                for table in tables
+                    .into_iter()
+                    .filter(|table| *table != CanticleTables::Classical)
+                {
+                    for canticle in L::canticle_table(table).canticles() {
+                        if checked.insert(canticle) && L::canticle(canticle, doc.version).is_none()
+                        {
+                            self.report(
+                                slug_path,
+                                &path,
+                                IntegrityProblem::MissingCanticle {
+                                    table,
+                                    canticle,
+                                    version: doc.version,
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+            Content::Choice(choice) if choice.selected >= choice.options.len() => {
+                self.report(
+                    slug_path,
+                    &path,
+                    IntegrityProblem::ChoiceSelectionOutOfRange {
+                        selected: choice.selected,
+                        options: choice.options.len(),
+                    },
+                );
+            }
+            _ => {}
+        }
+
+        // the nearest enclosing liturgy declares the preferences its children can use
+        let (children, liturgy_prefs) = match &doc.content {
+            Content::Liturgy(liturgy) => (liturgy.body.as_slice(), Some(&liturgy.preferences)),
+            Content::Series(series) => (series.as_slice(), liturgy_prefs),
+            Content::Parallel(parallel) => (parallel.as_slice(), liturgy_prefs),
+            Content::Choice(choice) => (choice.options.as_slice(), liturgy_prefs),
+            _ => (&[] as &[Document], liturgy_prefs),
+        };
+        for (idx, child) in children.iter().enumerate() {
+            let mut child_path = path.clone();
+            child_path.push(idx);
+            self.check(slug_path, child_path, child, liturgy_prefs);
+        }
+    }
+
+    fn check_lectionary(
+        &mut self,
+        slug_path: &SlugPath,
+        path: &[usize],
+        version: Version,
+        lectionary: Lectionaries,
+    ) {
+        let problems = self
+            .lectionary_problems
+            .entry((lectionary, version))
+            .or_insert_with(|| Self::lectionary_problems(version, lectionary))
+            .clone();
+        for problem in problems {
+            self.report(slug_path, path, problem);
+        }
+    }
+
+    fn lectionary_problems(version: Version, lectionary: Lectionaries) -> Vec<IntegrityProblem> {
+        let psalter = L::psalter(Version::BCP1979);
+        L::lectionary(lectionary)
+            .readings
+            .iter()
+            .filter(|(_, _, reading_type, citation)| {
+                if citation.starts_with("canticle-")
+                    || citation.starts_with("Canticle ")
+                    || citation.starts_with("Cántico")
+                {
+                    CanticleId::try_from(*citation)
+                        .ok()
+                        .and_then(|id| L::canticle(id, version))
+                        .is_none()
+                } else if reading_type.is_psalm() {
+                    psalter.psalms_by_citation(citation).is_empty()
+                } else {
+                    BibleReference::from(*citation).ranges.is_empty()
+                }
+            })
+            .map(
+                |(_, _, _, citation)| IntegrityProblem::EmptyLectionaryCitation {
+                    lectionary,
+                    citation: citation.to_string(),
+                },
+            )
+            .collect()
+    }
+}
+
+/// Every value that the [Liturgy](liturgy::Liturgy) offers for the given preference
+fn preference_options<'a>(
+    key: &'a PreferenceKey,
+    liturgy_prefs: Option<&'a LiturgyPreferences>,
+) -> impl Iterator<Item = &'a PreferenceValue> {
+    liturgy_prefs
+        .into_iter()
+        .flat_map(|prefs| prefs.iter())
+        .filter(move |pref| pref.key == *key)
+        .flat_map(|pref| {
+            pref.choices()
+                .map(|choice| &choice.value)
+                .chain(pref.default_value.as_ref())
+        })
+}
+
+/// Every local preference key tested anywhere within the condition
+fn preference_keys<'a>(condition: &'a Condition, keys: &mut Vec<&'a PreferenceKey>) {
+    match condition {
+        Condition::Preference(key, _) if !keys.contains(&key) => {
+            keys.push(key);
+        }
+        Condition::Not(a) => preference_keys(a, keys),
+        Condition::And(a, b) | Condition::Or(a, b) => {
+            preference_keys(a, keys);
+            preference_keys(b, keys);
+        }
+        Condition::Any(conditions) | Condition::All(conditions) | Condition::None(conditions) => {
+            for condition in conditions {
+                preference_keys(condition, keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CommonPrayer;
+
+    #[test]
+    fn library_has_no_broken_references() {
+        let problems = check_integrity::<CommonPrayer>()
+            .into_iter()
+            .map(|diagnostic| {
+                format!(
+                    "{} {:?}: {:?}",
+                    diagnostic.slug_path, diagnostic.path, diagnostic.problem
+                )
+            })
+            .collect::<Vec<_>>();
+        assert!(problems.is_empty(), "{}", problems.join("\n"));
+    }
+}
@@ -1,11 +1,14 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, sync::Arc};
 
 use bible::OfflineBible;
 use calendar::{Calendar, LiturgicalDay, LiturgicalDayId, Rank, Weekday};
 use canticle_table::{CanticleId, CanticleNumber, CanticleTable};
 use itertools::Itertools;
 use language::Language;
-use lectionary::{rcl_readings, Lectionary, RCLTrack, Reading, ReadingType};
+use lectionary::{
+    rcl_readings, weekday_eucharist_readings, Lectionary, RCLTrack, Reading, ReadingType,
+    WeekdayCycle,
+};
 use liturgy::*;
 use loc::collects::COLECTAS;
 use psalter::Psalter;
@@ -27,15 +30,37 @@ pub mod collect;
 mod common_prayer;
 pub use common_prayer::*;
 pub mod conditions;
+mod cycle_of_prayer;
+pub use cycle_of_prayer::*;
 pub mod eow;
+mod expansive;
+pub use expansive::*;
+#[cfg(any(feature = "browser", feature = "server"))]
+mod integrity;
 pub mod lff2018;
 pub mod loc;
 pub mod marriage_alternatives;
+mod prayer_catalog;
+pub use prayer_catalog::*;
+mod prayers_of_the_people;
+pub use prayers_of_the_people::*;
+mod preface;
+mod preference_schemas;
+pub use preference_schemas::*;
+pub use preface::*;
 pub mod rite1;
 pub mod rite2;
+mod service_plan;
+pub use service_plan::*;
+#[cfg(any(feature = "browser", feature = "server"))]
+mod search;
 pub mod summary;
 mod table_of_contents;
 pub use collect::*;
+#[cfg(any(feature = "browser", feature = "server"))]
+pub use integrity::*;
+#[cfg(any(feature = "browser", feature = "server"))]
+pub use search::*;
 pub use table_of_contents::*;
 
 pub trait Library {
@@ -49,14 +74,83 @@ pub trait Library {
 
     fn contents<'a>() -> TableOfContents<'a>;
 
+    /// The expansive-language alternative to an English document in a Rite II liturgy, used in its place
+    /// when compiling with the [GlobalPref::ExpansiveLanguage] preference; by default, the alternatives given by
+    /// [expansive_alternative](crate::expansive_alternative)
+    fn expansive_alternative(document: &Document) -> Option<Document> {
+        crate::expansive_alternative(document)
+    }
+
+    /// The cycle of prayer with the identifier given in a client’s preferences (see [CycleOfPrayerId]);
+    /// by default, the cycle given to [register_cycle_of_prayer](crate::register_cycle_of_prayer)
+    fn cycle_of_prayer(id: &str) -> Option<Arc<CycleOfPrayer>> {
+        crate::registered_cycle_of_prayer(id)
+    }
+
     #[cfg(any(feature = "browser", feature = "server"))]
     fn compile(
+        document: Document,
+        calendar: &Calendar,
+        day: &LiturgicalDay,
+        observed: &LiturgicalDayId,
+        prefs: &impl ClientPreferences,
+        liturgy_prefs: &LiturgyPreferences,
+    ) -> Option<Document> {
+        let rite = document.version;
+        Self::compile_in_context(
+            document,
+            calendar,
+            day,
+            observed,
+            prefs,
+            liturgy_prefs,
+            &PlaceholderContext::from_preferences(prefs),
+            rite,
+        )
+    }
+
+    /// Compiles the document as [Library::compile] does, filling in the names given in `placeholders`
+    /// (which may begin with [PlaceholderContext::from_preferences]) rather than only those in the preferences.
+    /// Returns the compiled document along with any placeholders that were left unfilled.
+    #[cfg(any(feature = "browser", feature = "server"))]
+    fn compile_with_placeholders(
+        document: Document,
+        calendar: &Calendar,
+        day: &LiturgicalDay,
+        observed: &LiturgicalDayId,
+        prefs: &impl ClientPreferences,
+        liturgy_prefs: &LiturgyPreferences,
+        placeholders: &PlaceholderContext,
+    ) -> Option<(Document, Vec<UnfilledPlaceholder>)> {
+        let rite = document.version;
+        let document = Self::compile_in_context(
+            document,
+            calendar,
+            day,
+            observed,
+            prefs,
+            liturgy_prefs,
+            placeholders,
+            rite,
+        )?;
+        let unfilled = document.unfilled_placeholders();
+        Some((document, unfilled))
+    }
+
+    /// Compiles a document and each of the documents nested within it, with the names
+    /// for its placeholders gathered once for the whole document. `rite` is the version of
+    /// the liturgy that contains the document, or of the document itself if it is not in one.
+    #[cfg(any(feature = "browser", feature = "server"))]
+    #[allow(clippy::too_many_arguments)]
+    fn compile_in_context(
         mut document: Document,
         calendar: &Calendar,
         day: &LiturgicalDay,
         observed: &LiturgicalDayId,
         prefs: &impl ClientPreferences,
         liturgy_prefs: &LiturgyPreferences,
+        placeholders: &PlaceholderContext,
+        rite: Version,
     ) -> Option<Document> {
         document.is_compiled = true;
 
@@ -72,7 +166,29 @@ pub trait Library {
         if !include {
             None
         } else {
-            match &document.content {
+            let recitation = match preference_value_for_key(&PreferenceKey::from(
+                GlobalPref::PsalmRecitation,
+            )) {
+                Some(PreferenceValue::PsalmRecitation(recitation)) => Some(*recitation),
+                _ => None,
+            };
+            let tone = match preference_value_for_key(&PreferenceKey::from(GlobalPref::ChantTone)) {
+                Some(PreferenceValue::ChantTone(tone)) => Some(*tone),
+                _ => None,
+            };
+
+            // the expansive-language alternatives are only for the English texts of Rite II
+            let expansive = rite == Version::RiteII
+                && document.language == Language::En
+                && preference_value_for_key(&PreferenceKey::from(GlobalPref::ExpansiveLanguage))
+                    == Some(&PreferenceValue::Bool(true));
+            let mut document = if expansive {
+                Self::expansive_alternative(&document).unwrap_or(document)
+            } else {
+                document
+            };
+
+            let compiled = match &document.content {
                 // Document Link Lookup
                 Content::DocumentLink {
                     path,
@@ -89,12 +205,22 @@ pub trait Library {
 
                         Document::choice_or_document(&mut docs)
                             .and_then(|docs| {
-                                Self::compile(docs, calendar, day, observed, prefs, liturgy_prefs)
+                                Self::compile_in_context(
+                                    docs,
+                                    calendar,
+                                    day,
+                                    observed,
+                                    prefs,
+                                    liturgy_prefs,
+                                    placeholders,
+                                    rite,
+                                )
                             })
                             .map(|mut doc| {
+                                // a link to a single Choice keeps its own Rotation; otherwise, the default is used
                                 if let Content::Choice(ref mut choice) = doc.content {
                                     if *rotate {
-                                        choice.rotate(&day.date);
+                                        choice.rotate_on(calendar, day);
                                     }
                                 }
                                 doc
@@ -127,16 +253,34 @@ pub trait Library {
                     if let Some(reading_type) = reading_type {
                         let readings = if chosen_lectionary == Lectionaries::RCLTrack1
                             || chosen_lectionary == Lectionaries::RCLTrack2
+                            || chosen_lectionary == Lectionaries::WeekdayEucharist
                         {
                             let track = if chosen_lectionary == Lectionaries::RCLTrack1 {
                                 RCLTrack::One
                             } else {
                                 RCLTrack::Two
                             };
-                            Box::new(
-                                rcl_readings(observed, day, track)
-                                    .filter(|reading| reading.reading_type == reading_type),
-                            ) as Box<dyn Iterator<Item = Reading>>
+                            // on ferial weekdays, the RCL gives way to the preferred weekday cycle, if any
+                            let cycle = match preference_value_for_key(&PreferenceKey::from(
+                                GlobalPref::WeekdayCycle,
+                            )) {
+                                Some(PreferenceValue::WeekdayCycle(cycle)) => Some(*cycle),
+                                _ if chosen_lectionary == Lectionaries::WeekdayEucharist => {
+                                    Some(WeekdayCycle::default())
+                                }
+                                _ => None,
+                            };
+                            match cycle {
+                                Some(cycle) => Box::new(
+                                    weekday_eucharist_readings(observed, day, cycle, track)
+                                        .filter(move |reading| reading.reading_type == reading_type),
+                                )
+                                    as Box<dyn Iterator<Item = Reading>>,
+                                None => Box::new(
+                                    rcl_readings(observed, day, track)
+                                        .filter(move |reading| reading.reading_type == reading_type),
+                                ),
+                            }
                         } else {
                             Box::new(lectionary.reading_by_type_with_override(
                                 observed,
@@ -157,13 +301,15 @@ pub trait Library {
                                         Document::from(DocumentError::from(reading.citation))
                                     })
                             } else if reading_type.is_psalm() {
-                                Self::compile(
+                                Self::compile_in_context(
                                     Document::from(PsalmCitation::from(reading.citation)),
                                     calendar,
                                     day,
                                     observed,
                                     prefs,
                                     liturgy_prefs,
+                                    placeholders,
+                                    rite,
                                 )
                                 .unwrap()
                             } else {
@@ -240,6 +386,12 @@ pub trait Library {
                         Some(specific_canticle)
                     } else {
                         let chosen_table = match &entry.table {
+                            // unless the user has chosen a table, expansive language prefers the EOW table
+                            CanticleTableChoice::Preference(key)
+                                if expansive && prefs.value(key).is_none() =>
+                            {
+                                CanticleTables::EOW
+                            }
                             CanticleTableChoice::Preference(key) => {
                                 match preference_value_for_key(key) {
                                     Some(PreferenceValue::CanticleTable(table)) => *table,
@@ -254,7 +406,12 @@ pub trait Library {
                         let entries = table.find(calendar, day, entry.nth, None, false);
 
                         let mut docs = entries.iter().map(|id| {
-                            let mut canticle = Self::canticle(*id, document.version)
+                            let version = if expansive {
+                                Version::EOW
+                            } else {
+                                document.version
+                            };
+                            let mut canticle = Self::canticle(*id, version)
                                 .unwrap_or_else(|| {
                                     Document::from(DocumentError::from(format!(
                                         "{:#?} not available in {:#?}",
@@ -267,8 +424,15 @@ pub trait Library {
                                 canticle.changeable = Some(entry.nth);
                             }
 
-                            // Switch between contemporary and traditional Gloria Patri depending on preference
-                            if prefs.value(&PreferenceKey::from(GlobalPref::GloriaPatriTraditional))
+                            // Switch between contemporary, traditional, and expansive Gloria Patri depending on preference
+                            if expansive {
+                                if let Content::Canticle(ref mut canticle) = &mut canticle.content {
+                                    if canticle.gloria_patri.is_some() {
+                                        canticle.gloria_patri =
+                                            Some(eow::GLORIA_PATRI.clone());
+                                    }
+                                }
+                            } else if prefs.value(&PreferenceKey::from(GlobalPref::GloriaPatriTraditional))
                                 == Some(&PreferenceValue::Bool(true))
                             {
                                 if let Content::Canticle(ref mut canticle) = &mut canticle.content {
@@ -286,8 +450,9 @@ pub trait Library {
 
                 // Switch between contemporary and traditional Gloria Patri depending on preference
                 Content::GloriaPatri(_) => {
-                    if prefs.value(&PreferenceKey::from(GlobalPref::GloriaPatriTraditional))
-                        == Some(&PreferenceValue::Bool(true))
+                    if !expansive
+                        && prefs.value(&PreferenceKey::from(GlobalPref::GloriaPatriTraditional))
+                            == Some(&PreferenceValue::Bool(true))
                     {
                         Some(Document {
                             content: Content::GloriaPatri(GLORIA_PATRI_TRADITIONAL.clone()),
@@ -377,7 +542,7 @@ pub trait Library {
                 // Insert seasonal antiphon for invitatories
                 Content::Invitatory(invitatory) => match invitatory.antiphon {
                     SeasonalAntiphon::Insert => {
-                        if let Some(antiphon) = Self::compile(
+                        if let Some(antiphon) = Self::compile_in_context(
                             Document::from(Content::DocumentLink {
                                 label: String::new(),
                                 path: SlugPath::from([Slug::Office, Slug::InvitatoryAntiphons]),
@@ -389,6 +554,8 @@ pub trait Library {
                             observed,
                             prefs,
                             liturgy_prefs,
+                            placeholders,
+                            rite,
                         ) {
                             match (&antiphon.content, &mut document.content) {
                                 (
@@ -436,35 +603,43 @@ pub trait Library {
                         Some(Document::from(Series::from(psalms)))
                     }
                 }
-                Content::CollectOfTheDay { allow_multiple } => {
-                    let language = document.language;
-                    let traditional_language = matches!(document.version, Version::RiteI);
-                    let use_black_letter_collects = prefs
-                        .value(&PreferenceKey::from(GlobalPref::UseBlackLetterCollects))
-                        .and_then(|value| match value {
-                            PreferenceValue::Bool(bool) => Some(*bool),
-                            _ => None,
-                        })
-                        .unwrap_or(true);
-
-                    // create collect list, based on version + calendar + black-letter collect preferences
-                    let collects: Box<dyn Iterator<Item = &(CollectId, CollectData)>> =
-                        match (language, traditional_language, use_black_letter_collects) {
-                            (Language::Es, _, _) => Box::new(COLECTAS.iter()),
-                            (_, true, true) => Box::new(
-                                COLLECTS_TRADITIONAL
-                                    .iter()
-                                    .chain(LFF_COLLECTS_TRADITIONAL.iter()),
-                            ),
-                            (_, true, false) => Box::new(COLLECTS_TRADITIONAL.iter()),
-                            (_, false, true) => Box::new(
-                                COLLECTS_CONTEMPORARY
-                                    .iter()
-                                    .chain(LFF_COLLECTS_CONTEMPORARY.iter()),
-                            ),
-                            (_, false, false) => Box::new(COLLECTS_CONTEMPORARY.iter()),
+                Content::ProperPreface => {
+                    let collects = collects_for(&document, prefs);
+                    let prefaces = prefaces_for_day(calendar, day, observed, collects);
+                    let table: Option<&[(PrefaceId, Document)]> =
+                        match (document.language, document.version) {
+                            (Language::Es, _) | (_, Version::LibroDeOracionComun) => None,
+                            (_, Version::RiteI) => Some(&rite1::eucharist::PROPER_PREFACES_I),
+                            _ => Some(&rite2::eucharist::PROPER_PREFACES_II),
                         };
-                    let collects = collects.collect::<Vec<_>>();
+
+                    let mut options = prefaces.iter().flat_map(|id| match table {
+                        Some(table) => table
+                            .iter()
+                            .filter(|(s_id, _)| s_id == id)
+                            .map(|(_, doc)| doc.clone())
+                            .collect::<Vec<_>>(),
+                        // if the text of the preface is not available in this version, its name is given instead
+                        None => vec![Document::from(Rubric::from(id.name(document.language)))
+                            .language(document.language)
+                            .version(document.version)],
+                    });
+
+                    // the first preface appointed is the default
+                    Document::choice_or_document(&mut options)
+                }
+                Content::CycleOfPrayer(lookup) => lookup
+                    .cycle
+                    .chosen_in(prefs)
+                    .and_then(Self::cycle_of_prayer)
+                    .filter(|cycle| !cycle.intercessions_for(day.date).is_empty())
+                    .map(|cycle| {
+                        let text = Text::from(lookup.fill(cycle.intercessions_for(day.date)));
+                        document.clone().content(Content::Text(text))
+                    }),
+                Content::CollectOfTheDay { allow_multiple } => {
+                    let collects = collects_for(&document, prefs);
+                    let use_black_letter_collects = use_black_letter_collects(prefs);
 
                     let day_rank = calendar.rank(day);
                     let holy_day_collect = match observed {
@@ -598,13 +773,15 @@ pub trait Library {
                                 .body
                                 .iter()
                                 .filter_map(|doc| {
-                                    Self::compile(
+                                    Self::compile_in_context(
                                         doc.clone(),
                                         calendar,
                                         day,
                                         observed,
                                         prefs,
                                         liturgy_prefs,
+                                        placeholders,
+                                        document.version,
                                     )
                                 })
                                 .collect::<Vec<_>>(),
@@ -618,13 +795,15 @@ pub trait Library {
                     content: Content::Series(Series::from(
                         sub.iter()
                             .filter_map(|doc| {
-                                Self::compile(
+                                Self::compile_in_context(
                                     doc.clone(),
                                     calendar,
                                     day,
                                     observed,
                                     prefs,
                                     liturgy_prefs,
+                                    placeholders,
+                                    rite,
                                 )
                             })
                             .collect::<Vec<_>>(),
@@ -635,13 +814,15 @@ pub trait Library {
                     content: Content::Parallel(Parallel::from(
                         sub.iter()
                             .filter_map(|doc| {
-                                Self::compile(
+                                Self::compile_in_context(
                                     doc.clone(),
                                     calendar,
                                     day,
                                     observed,
                                     prefs,
                                     liturgy_prefs,
+                                    placeholders,
+                                    rite,
                                 )
                             })
                             .collect::<Vec<_>>(),
@@ -654,28 +835,43 @@ pub trait Library {
                     let index_of_prev_selection = prev_selection
                         .and_then(|prev| sub.options.iter().position(|search| search == prev));
 
+                    // keep each option's weight in the rotation together with it, in case some are excluded
+                    let (options, weights): (Vec<_>, Vec<_>) = sub
+                        .options
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(idx, doc)| {
+                            Self::compile_in_context(
+                                doc.clone(),
+                                calendar,
+                                day,
+                                observed,
+                                prefs,
+                                liturgy_prefs,
+                                placeholders,
+                                rite,
+                            )
+                            .map(|doc| (doc, sub.rotation.weights.get(idx).copied().unwrap_or(1)))
+                        })
+                        .unzip();
+
                     let mut choice = Choice {
-                        options: sub
-                            .options
-                            .iter()
-                            .filter_map(|doc| {
-                                Self::compile(
-                                    doc.clone(),
-                                    calendar,
-                                    day,
-                                    observed,
-                                    prefs,
-                                    liturgy_prefs,
-                                )
-                            })
-                            .collect(),
+                        options,
                         selected: index_of_prev_selection.unwrap_or(0),
                         rotated: sub.rotated,
                         should_rotate: sub.should_rotate,
+                        rotation: Rotation {
+                            weights: if sub.rotation.weights.is_empty() {
+                                Vec::new()
+                            } else {
+                                weights
+                            },
+                            ..sub.rotation.clone()
+                        },
                     };
 
                     if choice.should_rotate {
-                        choice.rotate(&day.date);
+                        choice.rotate_on(calendar, day);
                     }
 
                     Some(Document {
@@ -685,11 +881,186 @@ pub trait Library {
                 }
                 // Every else just passes through as is
                 _ => Some(document),
+            };
+
+            compiled
+                .map(|doc| apply_recitation(doc, recitation))
+                .map(|doc| apply_pointing(doc, tone))
+                .map(|doc| fill_placeholders(doc, placeholders))
+        }
+    }
+}
+
+/// Marks every psalm and canticle, unless it already specifies its own recitation, with the preferred way of reciting it.
+/// This includes those nested in the [Series] or [Choice] that a citation compiles into. In a [Series] or [Liturgy],
+/// an [Antiphon] that immediately precedes a psalm or canticle is used as its refrain.
+#[cfg(any(feature = "browser", feature = "server"))]
+fn apply_recitation(mut document: Document, recitation: Option<PsalmRecitation>) -> Document {
+    if let Some(recitation) = recitation {
+        mark_recitation(&mut document, recitation);
+    }
+    document
+}
+
+#[cfg(any(feature = "browser", feature = "server"))]
+fn mark_recitation(document: &mut Document, recitation: PsalmRecitation) {
+    match &mut document.content {
+        Content::Psalm(Psalm {
+            recitation: ref mut existing @ None,
+            ..
+        })
+        | Content::Canticle(Canticle {
+            recitation: ref mut existing @ None,
+            ..
+        }) => *existing = Some(Recitation::from(recitation)),
+        Content::Series(series) => mark_series(series.as_mut_slice(), recitation),
+        Content::Liturgy(liturgy) => mark_series(liturgy.body.as_mut_slice(), recitation),
+        Content::Choice(choice) => {
+            for option in choice.options.iter_mut() {
+                mark_recitation(option, recitation);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Points every psalm and canticle for the preferred chant tone, including those nested in the [Series] or [Choice]
+/// that a citation compiles into. The pointing heuristics are for English words, so documents in other languages
+/// (and everything they contain) are left unpointed.
+#[cfg(any(feature = "browser", feature = "server"))]
+fn apply_pointing(mut document: Document, tone: Option<ChantTone>) -> Document {
+    if let Some(tone) = tone {
+        mark_pointing(&mut document, &tone);
+    }
+    document
+}
+
+#[cfg(any(feature = "browser", feature = "server"))]
+fn mark_pointing(document: &mut Document, tone: &ChantTone) {
+    if document.language != Language::En {
+        return;
+    }
+    match &mut document.content {
+        // each level of a nested document is marked as it is compiled, so anything already pointed is left as it is
+        Content::Psalm(psalm)
+            if psalm
+                .sections
+                .iter()
+                .flat_map(|section| &section.verses)
+                .any(|verse| verse.pointing.is_none()) =>
+        {
+            *psalm = psalm.clone().pointed(tone)
+        }
+        Content::Canticle(canticle)
+            if canticle
+                .sections
+                .iter()
+                .flat_map(|section| &section.verses)
+                .any(|verse| verse.pointing.is_none()) =>
+        {
+            *canticle = canticle.clone().pointed(tone)
+        }
+        Content::Series(series) => {
+            for doc in series.as_mut_slice() {
+                mark_pointing(doc, tone);
+            }
+        }
+        Content::Liturgy(liturgy) => {
+            for doc in liturgy.body.as_mut_slice() {
+                mark_pointing(doc, tone);
             }
         }
+        Content::Choice(choice) => {
+            for option in choice.options.iter_mut() {
+                mark_pointing(option, tone);
+            }
+        }
+        _ => {}
     }
 }
 
+/// Fills in the names given for the document's placeholders. Containers are skipped, because each of their children
+/// has already been compiled; any placeholders left unfilled can be found with [Document::unfilled_placeholders].
+#[cfg(any(feature = "browser", feature = "server"))]
+fn fill_placeholders(mut document: Document, placeholders: &PlaceholderContext) -> Document {
+    if !placeholders.is_empty() && document.content.is_leaf() {
+        document.fill_placeholders(placeholders);
+    }
+    document
+}
+
+#[cfg(any(feature = "browser", feature = "server"))]
+fn mark_series(docs: &mut [Document], recitation: PsalmRecitation) {
+    for doc in docs.iter_mut() {
+        mark_recitation(doc, recitation);
+    }
+    link_refrains(docs);
+}
+
+#[cfg(any(feature = "browser", feature = "server"))]
+fn link_refrains(docs: &mut [Document]) {
+    let mut antiphon = None;
+    for doc in docs {
+        match &mut doc.content {
+            Content::Antiphon(text) => antiphon = Some(text.clone()),
+            Content::Psalm(Psalm {
+                recitation: Some(recitation),
+                ..
+            })
+            | Content::Canticle(Canticle {
+                recitation: Some(recitation),
+                ..
+            }) => {
+                if recitation.has_refrain() && recitation.refrain.is_none() {
+                    recitation.refrain = antiphon.take();
+                }
+            }
+            _ => antiphon = None,
+        }
+    }
+}
+
+/// The collects used for a document, based on its version and language
+/// and on whether the client prefers to include black-letter days
+#[cfg(any(feature = "browser", feature = "server"))]
+fn collects_for(
+    document: &Document,
+    prefs: &impl ClientPreferences,
+) -> Vec<&'static (CollectId, CollectData)> {
+    let traditional_language = matches!(document.version, Version::RiteI);
+    let use_black_letter_collects = use_black_letter_collects(prefs);
+
+    // create collect list, based on version + calendar + black-letter collect preferences
+    let collects: Box<dyn Iterator<Item = &(CollectId, CollectData)>> =
+        match (document.language, traditional_language, use_black_letter_collects) {
+            (Language::Es, _, _) => Box::new(COLECTAS.iter()),
+            (_, true, true) => Box::new(
+                COLLECTS_TRADITIONAL
+                    .iter()
+                    .chain(LFF_COLLECTS_TRADITIONAL.iter()),
+            ),
+            (_, true, false) => Box::new(COLLECTS_TRADITIONAL.iter()),
+            (_, false, true) => Box::new(
+                COLLECTS_CONTEMPORARY
+                    .iter()
+                    .chain(LFF_COLLECTS_CONTEMPORARY.iter()),
+            ),
+            (_, false, false) => Box::new(COLLECTS_CONTEMPORARY.iter()),
+        };
+    collects.collect()
+}
+
+#[cfg(any(feature = "browser", feature = "server"))]
+fn use_black_letter_collects(prefs: &impl ClientPreferences) -> bool {
+    prefs
+        .value(&PreferenceKey::from(GlobalPref::UseBlackLetterCollects))
+        .and_then(|value| match value {
+            PreferenceValue::Bool(bool) => Some(*bool),
+            _ => None,
+        })
+        .unwrap_or(true)
+}
+
 fn biblical_reading(
     document: &Document,
     citation: &str,
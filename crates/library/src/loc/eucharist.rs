@@ -5,7 +5,7 @@ use crate::{
 use calendar::Season;
 use language::*;
 use lazy_static::lazy_static;
-use lectionary::ReadingType;
+use lectionary::{ReadingType, WeekdayCycle};
 use liturgy::*;
 
 lazy_static! {
@@ -456,6 +456,14 @@ lazy_static! {
                     LiturgyPreferenceOption::from(("RCL (Track 2)", PreferenceValue::from(Lectionaries::RCLTrack2)))
                 ]
             )).category("Lectionary"),
+            LiturgyPreference::from((
+                PreferenceKey::from(GlobalPref::WeekdayCycle),
+                "Weekday Readings",
+                [
+                    LiturgyPreferenceOption::from(("Two-Year Weekday Cycle", PreferenceValue::from(WeekdayCycle::TwoYear))),
+                    LiturgyPreferenceOption::from(("Propers of the Preceding Sunday", PreferenceValue::from(WeekdayCycle::SundayPropers)))
+                ]
+            )).category("Lectionary"),
 
             // Readings
             LiturgyPreference::from((
@@ -516,12 +524,7 @@ lazy_static! {
             Document::from(Rubric::from("El Celebrante continúa:")),
             Document::from(Text::from("En verdad es digno, justo y saludable, darte gracias, en todo tiempo y lugar, Padre omnipotente, Creador de cielo y tierra.")),
             Document::from(Rubric::from("Aquí, todos los domingos y en las ocasiones que se indique, se canta o dice el Prefacio Propio.")),
-            Document::from(Content::DocumentLink {
-                label: "Prefacios Propios".into(),
-                path: SlugPath::from([Slug::Eucharist, Slug::ProperPrefaces, Slug::Version(Version::LibroDeOracionComun)]),
-                rotate: true,
-                link_only: false
-            }),
+            Document::new().language(Language::Es).version(Version::LibroDeOracionComun).content(Content::ProperPreface),
             Document::from(Text::from("Por tanto te alabamos, uniendo nuestras voces con los Angeles y Arcángeles, y con todos los coros celestiales que, proclamando la gloria de tu Nombre, por siempre cantan este himno:")),
             Document::from(Rubric::from("Celebrante y Pueblo:")),
             Document::from(Text::from("Santo, santo, santo es el Señor, Dios del universo.\nLlenos están el cielo y la tierra de tu gloria.\n\tHosanna en el cielo.\nBendito el que viene en nombre del Señor.\n\tHosanna en el cielo.").display_format(DisplayFormat::Unison)),
@@ -557,12 +560,7 @@ lazy_static! {
             Document::from(Rubric::from("El Celebrante continúa:")),
             Document::from(Text::from("En verdad es digno, justo y saludable, darte gracias, en todo tiempo y lugar, Padre omnipotente, Creador de cielo y tierra.")),
             Document::from(Rubric::from("Aquí, todos los domingos y en las ocasiones que se indique, se canta o dice el Prefacio Propio.")),
-            Document::from(Content::DocumentLink {
-                label: "Prefacios Propios".into(),
-                path: SlugPath::from([Slug::Eucharist, Slug::ProperPrefaces, Slug::Version(Version::LibroDeOracionComun)]),
-                rotate: true,
-                link_only: false
-            }),
+            Document::new().language(Language::Es).version(Version::LibroDeOracionComun).content(Content::ProperPreface),
             Document::from(Text::from("Por tanto te alabamos, uniendo nuestras voces con los Angeles y Arcángeles, y con todos los coros c elestiales que, proclamando la gloria de tu Nombre, por siempre cantan este himno:")),
             Document::from(Rubric::from("Celebrante y Pueblo:")),
             Document::from(Text::from("Santo, santo, santo es el Señor, Dios del universo.\nLlenos están el cielo y la tierra de tu gloria.\n\tHosanna en el cielo.\nBendito el que viene en nombre del Señor.\n\tHosanna en el cielo.").display_format(DisplayFormat::Unison)),
@@ -605,7 +605,7 @@ lazy_static! {
             Document::from(Rubric::from("If there is to be a presentation or a giving in marriage, it takes place at this time.")).tags([PRESENTATION_RUBRIC]),
             Document::from(Content::DocumentLink {
                 label: "Additional Directions".into(),
-                path: SlugPath::from([Slug::Marriage, Slug::AdditionalDirections, Slug::Version(Version::Expansive)]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Marriage, Slug::AdditionalDirections, Slug::Version(Version::Expansive)]),
                 rotate: false,
                 link_only: false
             }).tags([ADDITIONAL_DIRECTIONS_PARALLEL]),
@@ -757,7 +757,7 @@ lazy_static! {
             }),
             Document::from(Content::DocumentLink {
                 label: "Marriage Service".into(),
-                path: SlugPath::from([Slug::Marriage, Slug::CelebrationAndBlessing, Slug::Version(Version::Expansive)]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Marriage, Slug::CelebrationAndBlessing, Slug::Version(Version::Expansive)]),
                 rotate: false,
                 link_only: false
             }),
@@ -790,7 +790,7 @@ lazy_static! {
             Document::from(Rubric::from("The service continues with The Prayers on page 104 [in the marriage service].")),
             Document::from(Content::DocumentLink {
                 label: "Marriage Service".into(),
-                path: SlugPath::from([Slug::Marriage, Slug::CelebrationAndBlessing, Slug::Version(Version::Expansive)]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Marriage, Slug::CelebrationAndBlessing, Slug::Version(Version::Expansive)]),
                 rotate: false,
                 link_only: false
             }),
@@ -810,7 +810,7 @@ lazy_static! {
             Document::from(Rubric::from("If it is desired to celebrate a marriage otherwise than as provided on pages 76-85 of “Liturgical Resources 1: The Witnessing and Blessing of a Lifelong Covenant (revised and expanded),” this Order is used.")),
             Document::from(Content::DocumentLink {
                 label: "Marriage Service".into(),
-                path: SlugPath::from([Slug::Marriage, Slug::CelebrationAndBlessing, Slug::Version(Version::Expansive)]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Marriage, Slug::CelebrationAndBlessing, Slug::Version(Version::Expansive)]),
                 rotate: false,
                 link_only: false
             }),
@@ -16,19 +16,19 @@ lazy_static! {
             Document::from(Rubric::from("If it is desired to celebrate a marriage otherwise than as provided on page 423 of The Book of Common Prayer, or in the trial-use liturgies “The Witnessing and Blessing of a Marriage” or “The Celebration and Blessing of a Marriage 2,” this Order is used.")),
             Document::from(Content::DocumentLink {
                 label: "BCP Marriage Service".into(),
-                path: SlugPath::from([Slug::Marriage, Slug::CelebrationAndBlessing]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Marriage, Slug::CelebrationAndBlessing]),
                 rotate: false,
                 link_only: false
             }),
             Document::from(Content::DocumentLink {
                 label: "The Witnessing and Blessing of a Marriage".into(),
-                path: SlugPath::from([Slug::Marriage, Slug::WitnessingAndBlessing]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Marriage, Slug::WitnessingAndBlessing]),
                 rotate: false,
                 link_only: false
             }),
             Document::from(Content::DocumentLink {
                 label: "The Celebration and Blessing of a Marriage".into(),
-                path: SlugPath::from([Slug::Marriage, Slug::CelebrationAndBlessing, Slug::Version(Version::Expansive)]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Marriage, Slug::CelebrationAndBlessing, Slug::Version(Version::Expansive)]),
                 rotate: false,
                 link_only: false
             }),
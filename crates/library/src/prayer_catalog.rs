@@ -0,0 +1,300 @@
+use calendar::{Calendar, LiturgicalDay, LiturgicalDayId, Season, VariousOccasions};
+use lazy_static::lazy_static;
+use liturgy::{Document, Reference};
+use serde::{Deserialize, Serialize};
+
+use crate::bcp1979::PRAYERS_AND_THANKSGIVINGS;
+
+/// One of the numbered Prayers and Thanksgivings (BCP pp. 814–841); the two are numbered separately
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum PrayerId {
+    Prayer(u8),
+    Thanksgiving(u8),
+}
+
+/// The headings under which the Prayers and Thanksgivings are arranged
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PrayerCategory {
+    ForTheWorld,
+    ForTheChurch,
+    ForNationalLife,
+    ForTheSocialOrder,
+    ForTheNaturalOrder,
+    ForFamilyAndPersonalLife,
+    OtherPrayers,
+    GeneralThanksgivings,
+    ThanksgivingsForTheChurch,
+    ThanksgivingsForNationalLife,
+    ThanksgivingsForTheSocialOrder,
+    ThanksgivingsForTheNaturalOrder,
+    ThanksgivingsForFamilyAndPersonalLife,
+}
+
+impl PrayerCategory {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PrayerCategory::ForTheWorld => "Prayers for the World",
+            PrayerCategory::ForTheChurch => "Prayers for the Church",
+            PrayerCategory::ForNationalLife => "Prayers for National Life",
+            PrayerCategory::ForTheSocialOrder => "Prayers for the Social Order",
+            PrayerCategory::ForTheNaturalOrder => "Prayers for the Natural Order",
+            PrayerCategory::ForFamilyAndPersonalLife => "Prayers for Family and Personal Life",
+            PrayerCategory::OtherPrayers => "Other Prayers",
+            PrayerCategory::GeneralThanksgivings => "General Thanksgivings",
+            PrayerCategory::ThanksgivingsForTheChurch => "Thanksgivings for the Church",
+            PrayerCategory::ThanksgivingsForNationalLife => "Thanksgivings for National Life",
+            PrayerCategory::ThanksgivingsForTheSocialOrder => "Thanksgiving for the Social Order",
+            PrayerCategory::ThanksgivingsForTheNaturalOrder => {
+                "Thanksgivings for the Natural Order"
+            }
+            PrayerCategory::ThanksgivingsForFamilyAndPersonalLife => {
+                "Thanksgivings for Family and Personal Life"
+            }
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "Prayers for the World" => Some(PrayerCategory::ForTheWorld),
+            "Prayers for the Church" => Some(PrayerCategory::ForTheChurch),
+            "Prayers for National Life" => Some(PrayerCategory::ForNationalLife),
+            "Prayers for the Social Order" => Some(PrayerCategory::ForTheSocialOrder),
+            "Prayers for the Natural Order" => Some(PrayerCategory::ForTheNaturalOrder),
+            "Prayers for Family and Personal Life" => {
+                Some(PrayerCategory::ForFamilyAndPersonalLife)
+            }
+            "Other Prayers" => Some(PrayerCategory::OtherPrayers),
+            "General Thanksgivings" => Some(PrayerCategory::GeneralThanksgivings),
+            "Thanksgivings for the Church" => Some(PrayerCategory::ThanksgivingsForTheChurch),
+            "Thanksgivings for National Life" => Some(PrayerCategory::ThanksgivingsForNationalLife),
+            "Thanksgiving for the Social Order" => {
+                Some(PrayerCategory::ThanksgivingsForTheSocialOrder)
+            }
+            "Thanksgivings for the Natural Order" => {
+                Some(PrayerCategory::ThanksgivingsForTheNaturalOrder)
+            }
+            "Thanksgivings for Family and Personal Life" => {
+                Some(PrayerCategory::ThanksgivingsForFamilyAndPersonalLife)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A single prayer or thanksgiving, with the information needed to find it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrayerEntry {
+    pub id: PrayerId,
+    /// The title, without its number (e.g., “For Local Government”)
+    pub title: String,
+    pub category: PrayerCategory,
+    pub reference: Reference,
+    pub tags: Vec<String>,
+    pub document: &'static Document,
+}
+
+impl PrayerEntry {
+    fn from_document(document: &'static Document) -> Option<Self> {
+        let (number, title) = document.label.as_ref()?.split_once(". ")?;
+        let number = number.parse().ok()?;
+        let id = if document.tags.iter().any(|tag| tag == "Thanksgivings") {
+            PrayerId::Thanksgiving(number)
+        } else {
+            PrayerId::Prayer(number)
+        };
+        let category = document
+            .tags
+            .iter()
+            .find_map(|tag| PrayerCategory::from_label(tag))?;
+        Some(Self {
+            id,
+            title: title.to_string(),
+            category,
+            reference: document.source?,
+            tags: document.tags.clone(),
+            document,
+        })
+    }
+}
+
+/// The Prayers and Thanksgivings, in order, each identified by its [PrayerId]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrayerCatalog {
+    entries: Vec<PrayerEntry>,
+    uncataloged: Vec<&'static Document>,
+}
+
+lazy_static! {
+    pub static ref PRAYER_CATALOG: PrayerCatalog =
+        PrayerCatalog::from_documents(PRAYERS_AND_THANKSGIVINGS.iter());
+}
+
+impl PrayerCatalog {
+    /// Catalogs documents labeled with their number and title (“23. For Local Government”), tagged with
+    /// “Prayers” or “Thanksgivings” and the label of their [PrayerCategory], and with a source.
+    /// Any other documents are kept aside, and can be found with [PrayerCatalog::uncataloged].
+    pub fn from_documents(documents: impl IntoIterator<Item = &'static Document>) -> Self {
+        let mut catalog = Self::default();
+        for document in documents {
+            match PrayerEntry::from_document(document) {
+                Some(entry) => catalog.entries.push(entry),
+                None => catalog.uncataloged.push(document),
+            }
+        }
+        catalog
+    }
+
+    /// Documents passed to [PrayerCatalog::from_documents] whose label, tags, or source could not be parsed
+    pub fn uncataloged(&self) -> &[&'static Document] {
+        &self.uncataloged
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &PrayerEntry> {
+        self.entries.iter()
+    }
+
+    pub fn get(&self, id: PrayerId) -> Option<&PrayerEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    pub fn in_category(&self, category: PrayerCategory) -> impl Iterator<Item = &PrayerEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.category == category)
+    }
+
+    /// Proposes prayers that could be added to the Prayers of the People on the given day, based on the
+    /// Various Occasion being observed or the season. If nothing in particular is suggested by the day,
+    /// the prayer for all sorts and conditions of people and the General Thanksgiving are proposed.
+    pub fn suggestions_for_day(
+        &self,
+        calendar: &Calendar,
+        day: &LiturgicalDay,
+    ) -> Vec<&PrayerEntry> {
+        let mut ids = Vec::new();
+        if let LiturgicalDayId::VariousOccasions(occasion) = day.observed {
+            ids.extend(suggested_for_occasion(occasion));
+        }
+        ids.extend(suggested_for_season(calendar.season(day)));
+        if ids.is_empty() {
+            ids.extend([PrayerId::Prayer(2), PrayerId::Thanksgiving(1)]);
+        }
+        let mut suggestions: Vec<&PrayerEntry> = Vec::new();
+        for entry in ids.into_iter().filter_map(|id| self.get(id)) {
+            if !suggestions.contains(&entry) {
+                suggestions.push(entry);
+            }
+        }
+        suggestions
+    }
+}
+
+fn suggested_for_occasion(occasion: VariousOccasions) -> &'static [PrayerId] {
+    use PrayerId::{Prayer, Thanksgiving};
+
+    match occasion {
+        VariousOccasions::AllBaptizedChristians
+        | VariousOccasions::Baptism
+        | VariousOccasions::Confirmation => &[Prayer(15)],
+        VariousOccasions::TheDeparted => &[Thanksgiving(4)],
+        VariousOccasions::Dedication => &[Prayer(11)],
+        VariousOccasions::ChurchConvention => &[Prayer(12)],
+        VariousOccasions::UnityOfTheChurch => &[Prayer(14)],
+        VariousOccasions::EmberDays => &[Prayer(13), Prayer(16)],
+        VariousOccasions::MissionOfTheChurch => &[Prayer(8), Thanksgiving(3)],
+        VariousOccasions::Nation => &[Prayer(18), Prayer(22), Thanksgiving(5)],
+        VariousOccasions::Peace => &[Prayer(4), Prayer(5)],
+        VariousOccasions::RogationDays => &[Prayer(29), Prayer(41), Prayer(42), Thanksgiving(9)],
+        VariousOccasions::Sick => &[Prayer(55)],
+        VariousOccasions::SocialJustice => &[Prayer(27), Prayer(36)],
+        VariousOccasions::SocialService => &[Prayer(35), Prayer(37)],
+        VariousOccasions::Education => &[Prayer(31)],
+        VariousOccasions::Vocation | VariousOccasions::LaborDay => &[Prayer(30), Prayer(38)],
+        VariousOccasions::HolyEucharist => &[Prayer(66), Prayer(67)],
+        VariousOccasions::HolyTrinity
+        | VariousOccasions::HolySpirit
+        | VariousOccasions::HolyAngels
+        | VariousOccasions::Incarnation
+        | VariousOccasions::HolyCross
+        | VariousOccasions::ReignOfChrist => &[],
+    }
+}
+
+fn suggested_for_season(season: Season) -> &'static [PrayerId] {
+    use PrayerId::{Prayer, Thanksgiving};
+
+    match season {
+        Season::Saints => &[Thanksgiving(4)],
+        Season::Ember => &[Prayer(13), Prayer(16)],
+        Season::National => &[Prayer(18), Thanksgiving(5)],
+        Season::Thanksgiving => &[Thanksgiving(1), Thanksgiving(9)],
+        Season::Rogation => &[Prayer(29), Prayer(42)],
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calendar::{Date, BCP1979_CALENDAR};
+    use liturgy::{Slug, SlugPath, Text};
+
+    use super::*;
+    use crate::{CommonPrayer, Contents, Library};
+
+    #[test]
+    fn every_prayer_and_thanksgiving_is_cataloged() {
+        assert_eq!(
+            PRAYER_CATALOG.iter().count(),
+            PRAYERS_AND_THANKSGIVINGS.len()
+        );
+        let prayers = (1..=70).map(PrayerId::Prayer);
+        let thanksgivings = (1..=11).map(PrayerId::Thanksgiving);
+        assert!(PRAYER_CATALOG
+            .iter()
+            .map(|entry| entry.id)
+            .eq(prayers.chain(thanksgivings)));
+
+        let local_government = PRAYER_CATALOG.get(PrayerId::Prayer(23)).unwrap();
+        assert_eq!(local_government.title, "For Local Government");
+        assert_eq!(local_government.category, PrayerCategory::ForNationalLife);
+        assert_eq!(local_government.reference.page, 822);
+        assert!(PRAYER_CATALOG.uncataloged().is_empty());
+        assert!(matches!(
+            CommonPrayer::contents().contents_at_path(&SlugPath::from([Slug::PrayersAndThanksgivings])),
+            Some(Contents::MultiDocument { documents, .. }) if documents.len() == PRAYERS_AND_THANKSGIVINGS.len()
+        ));
+    }
+
+    #[test]
+    fn unparseable_documents_are_uncataloged() {
+        let unnumbered: &'static Document = Box::leak(Box::new(
+            Document::from(Text::from("Lord, have mercy.")).label("For Mercy"),
+        ));
+        let catalog = PrayerCatalog::from_documents(
+            PRAYERS_AND_THANKSGIVINGS.iter().take(2).chain([unnumbered]),
+        );
+        assert_eq!(catalog.iter().count(), 2);
+        assert_eq!(catalog.uncataloged(), &[unnumbered]);
+    }
+
+    #[test]
+    fn prayers_are_suggested_for_the_day() {
+        let suggested = |day: &calendar::LiturgicalDay| {
+            PRAYER_CATALOG
+                .suggestions_for_day(&BCP1979_CALENDAR, day)
+                .into_iter()
+                .map(|entry| entry.id)
+                .collect::<Vec<_>>()
+        };
+        let mut day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 7, 11), false);
+        assert_eq!(
+            suggested(&day),
+            vec![PrayerId::Prayer(2), PrayerId::Thanksgiving(1)]
+        );
+        day.observed = LiturgicalDayId::VariousOccasions(VariousOccasions::Peace);
+        assert_eq!(
+            suggested(&day),
+            vec![PrayerId::Prayer(4), PrayerId::Prayer(5)]
+        );
+    }
+}
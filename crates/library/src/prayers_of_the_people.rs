@@ -0,0 +1,625 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use calendar::{Calendar, Date, LiturgicalDay, Rank};
+use liturgy::{
+    ClientPreferences, Content, CycleOfPrayerId, Document, Names, Pronouns, Series, Text,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{bcp1979::eucharist::pop, Library};
+
+/// The form of the Prayers of the People into which intercessions are inserted
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PrayersOfThePeopleForm {
+    I,
+    II,
+    III,
+    IV,
+    /// Form V, with the response “Lord, have mercy.”
+    V,
+    /// Form V, with the response “Kyrie, eleison.”
+    VKyrie,
+    VI,
+    /// A locally-written form; names are inserted using the same cues as in the Prayer Book forms
+    Custom(Box<Document>),
+}
+
+impl PrayersOfThePeopleForm {
+    pub fn document(&self) -> &Document {
+        match self {
+            PrayersOfThePeopleForm::I => &pop::FORM_I,
+            PrayersOfThePeopleForm::II => &pop::FORM_II,
+            PrayersOfThePeopleForm::III => &pop::FORM_III,
+            PrayersOfThePeopleForm::IV => &pop::FORM_IV,
+            PrayersOfThePeopleForm::V => &pop::FORM_V,
+            PrayersOfThePeopleForm::VKyrie => &pop::FORM_V_KYRIE,
+            PrayersOfThePeopleForm::VI => &pop::FORM_VI,
+            PrayersOfThePeopleForm::Custom(document) => document,
+        }
+    }
+}
+
+/// The kinds of intercession for which a parish supplies names each week
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum IntercessionCategory {
+    /// The Church throughout the world, e.g., the provinces and dioceses of the Anglican Cycle of Prayer
+    Church,
+    /// The diocese, e.g., the clergy and congregations of the diocesan cycle of prayer
+    Diocese,
+    /// Those in positions of public trust
+    Authorities,
+    /// The local community
+    Community,
+    /// The sick and those in any trouble
+    Sick,
+    /// Those who have died
+    Departed,
+    /// Thanksgivings asked for by members of the congregation
+    Thanksgivings,
+    /// The saints commemorated on the day
+    Commemorations,
+    /// Any other requests for prayer
+    Other,
+}
+
+impl IntercessionCategory {
+    /// The places in the text of a form where names in this category belong, in order of preference
+    fn cues(&self) -> &'static [Cue] {
+        use Cue::{After, Blank, Replace};
+
+        match self {
+            IntercessionCategory::Church => &[
+                After("God’s people throughout the world"),
+                After("the holy Church of God"),
+                After("your holy Catholic Church"),
+                After("all who confess your Name"),
+                After("the peace and unity of the Church of God"),
+            ],
+            IntercessionCategory::Diocese => &[
+                After("all the clergy and people"),
+                After("all ministers and people"),
+                After("all bishops and other ministers"),
+                After("all bishops, priests, and deacons"),
+            ],
+            IntercessionCategory::Authorities => &[
+                Blank("public trust [especially "),
+                After("all in authority"),
+                After("govern and hold authority in the nations of the world"),
+                After("the people of this land"),
+            ],
+            IntercessionCategory::Community => &[
+                Blank("this community [especially "),
+                After("every city and community"),
+                After("all whose lives are closely linked with ours"),
+                After("For this community"),
+            ],
+            IntercessionCategory::Sick => &[
+                After("the sick and the suffering"),
+                After("those in any need or trouble"),
+                After("those who suffer from any grief or trouble"),
+                After("all those who suffer in body, mind, or spirit"),
+                After("the sick, and all who suffer"),
+                After("all who are in danger, sorrow, or any kind of trouble"),
+            ],
+            IntercessionCategory::Departed => &[
+                Blank("the departed [especially "),
+                After("for all the departed"),
+                After("Give to the departed"),
+                After("all who have died"),
+            ],
+            IntercessionCategory::Thanksgivings => &[
+                Blank("your thanksgiving for "),
+                After("all the blessings of this life"),
+            ],
+            IntercessionCategory::Commemorations => &[
+                Blank("honored [especially "),
+                Blank("communion of ["),
+                Replace("(*blessed N.*)", "blessed "),
+                After("for your saints"),
+                After("with all your saints"),
+            ],
+            IntercessionCategory::Other => &[
+                Blank("prayers for "),
+                Blank("For "),
+                After("all who have commended themselves to our prayers"),
+                After("those of others"),
+                After("the special needs and concerns of this congregation"),
+            ],
+        }
+    }
+
+    /// A petition added to a form that has no place for names in this category
+    fn bidding(&self, names: &str) -> String {
+        match self {
+            IntercessionCategory::Thanksgivings => format!("We give thanks for {}.", names),
+            IntercessionCategory::Commemorations => {
+                format!("We give thanks for the witness of {}.", names)
+            }
+            _ => format!("We pray for {}.", names),
+        }
+    }
+}
+
+/// A place in a petition where names can be inserted
+enum Cue {
+    /// Text that is immediately followed by a blank (___________), which the names replace.
+    /// If the blank is optional, i.e., the text opens a bracket, the brackets are removed once it is filled.
+    Blank(&'static str),
+    /// Text after which the names are inserted, as “…, especially N.”
+    After(&'static str),
+    /// A placeholder that is replaced by the names, following the given prefix
+    Replace(&'static str, &'static str),
+}
+
+impl Cue {
+    fn fill(&self, text: &str, names: &str) -> Option<String> {
+        match self {
+            Cue::Blank(prefix) => text.match_indices(prefix).find_map(|(idx, _)| {
+                let end = idx + prefix.len();
+                let after = &text[end..];
+                if !after.starts_with("___") {
+                    return None;
+                }
+                let rest = after.trim_start_matches('_');
+                let rest = if rest.trim_start().starts_with(']') {
+                    rest.trim_start()
+                } else {
+                    rest
+                };
+                let separator = if rest.starts_with(char::is_alphanumeric) {
+                    " "
+                } else {
+                    ""
+                };
+                match prefix.rfind('[') {
+                    Some(bracket) => Some(format!(
+                        "{}{}{}{}{}",
+                        &text[..idx + bracket],
+                        &prefix[bracket + 1..],
+                        names,
+                        separator,
+                        rest.replacen(']', "", 1)
+                    )),
+                    None => Some(format!("{}{}{}{}", &text[..end], names, separator, rest)),
+                }
+            }),
+            Cue::After(cue) => {
+                let end = text.find(cue)? + cue.len();
+                let rest = &text[end..];
+                let closing = if rest.is_empty() || rest.starts_with(&[',', ';', '.', ':'][..]) {
+                    ""
+                } else {
+                    ","
+                };
+                Some(format!(
+                    "{}, especially {}{}{}",
+                    &text[..end],
+                    names,
+                    closing,
+                    rest
+                ))
+            }
+            Cue::Replace(placeholder, prefix) => {
+                if text.contains(placeholder) {
+                    Some(text.replacen(placeholder, &format!("{}{}", prefix, names), 1))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Builds the Prayers of the People for a particular service, inserting the names for which the congregation
+/// prays into the appropriate petitions of the chosen form
+/// ```
+/// # use library::{IntercessionCategory, PrayersOfThePeople, PrayersOfThePeopleForm};
+/// # use liturgy::{Content, Litany};
+/// let prayers = PrayersOfThePeople::new(PrayersOfThePeopleForm::I)
+///     .intercession(IntercessionCategory::Sick, "Walter")
+///     .intercession(IntercessionCategory::Other, "the people of Haiti")
+///     .build();
+/// let Content::Series(series) = &prayers.content else {
+///     panic!("expected a series")
+/// };
+/// let Content::Litany(Litany { lines, .. }) = &series.as_slice()[2].content else {
+///     panic!("expected a litany")
+/// };
+/// assert_eq!(lines[8], "For the aged and infirm, for the widowed and orphans, and for the sick and the suffering, especially Walter, let us pray to the Lord.");
+/// assert_eq!(lines[9], "For the people of Haiti, let us pray to the Lord.");
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PrayersOfThePeople {
+    form: PrayersOfThePeopleForm,
+    intercessions: BTreeMap<IntercessionCategory, Vec<String>>,
+}
+
+impl PrayersOfThePeople {
+    pub fn new(form: PrayersOfThePeopleForm) -> Self {
+        Self {
+            form,
+            intercessions: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a name to be prayed for in the given category
+    pub fn intercession(mut self, category: IntercessionCategory, name: impl Display) -> Self {
+        self.intercessions
+            .entry(category)
+            .or_default()
+            .push(name.to_string());
+        self
+    }
+
+    /// Adds several names to be prayed for in the given category
+    pub fn intercessions<T: Display>(
+        self,
+        category: IntercessionCategory,
+        names: impl IntoIterator<Item = T>,
+    ) -> Self {
+        names
+            .into_iter()
+            .fold(self, |builder, name| builder.intercession(category, name))
+    }
+
+    /// Adds the saints commemorated in the calendar on the given day (its holy days and lesser feasts,
+    /// not principal feasts, Sundays, or eves) as [IntercessionCategory::Commemorations]
+    pub fn commemorations(self, calendar: &Calendar, day: &LiturgicalDay) -> Self {
+        let language = self.form.document().language;
+        let names = day
+            .holy_days
+            .iter()
+            .filter(|feast| {
+                !calendar.feast_is_eve(feast)
+                    && matches!(
+                        calendar.feast_day_rank(feast),
+                        Rank::HolyDay | Rank::OptionalObservance
+                    )
+            })
+            .filter_map(|feast| calendar.feast_name(*feast, language))
+            .map(|name| commemoration_name(&name))
+            .collect::<Vec<_>>();
+        self.intercessions(IntercessionCategory::Commemorations, names)
+    }
+
+    /// Adds the intercessions appointed for the given date in the Anglican and diocesan cycles of prayer
+    /// chosen in the client’s preferences, as the library `L` resolves them, as [IntercessionCategory::Church]
+    /// and [IntercessionCategory::Diocese]
+    pub fn cycles_of_prayer<L: Library>(self, prefs: &impl ClientPreferences, date: Date) -> Self {
+        [
+            (CycleOfPrayerId::Anglican, IntercessionCategory::Church),
+            (CycleOfPrayerId::Diocesan, IntercessionCategory::Diocese),
+        ]
+        .iter()
+        .fold(self, |builder, &(cycle, category)| {
+            match cycle.chosen_in(prefs).and_then(L::cycle_of_prayer) {
+                Some(cycle) => builder.intercessions(category, cycle.intercessions_for(date)),
+                None => builder,
+            }
+        })
+    }
+
+    /// The chosen form, with each category of names in its appropriate petition. Names for which the form
+    /// has no particular place are added to its general petition (e.g., “For ___________, let us pray
+    /// to the Lord.”) or, if it has none, in a petition added before its concluding rubric.
+    pub fn build(&self) -> Document {
+        let mut document = self.form.document().clone();
+        place(&mut document, &self.intercessions);
+        document
+    }
+}
+
+/// The name of a commemoration without the dates that follow it in the calendar (“Absalom Jones, Priest”)
+fn commemoration_name(name: &str) -> String {
+    let name = name.trim_start_matches('[').trim_end_matches(']');
+    let mut parts = name.split(", ").collect::<Vec<_>>();
+    while parts.len() > 1
+        && parts
+            .last()
+            .map(|part| part.starts_with(|c: char| c.is_ascii_digit()) || part.starts_with("c. "))
+            .unwrap_or(false)
+    {
+        parts.pop();
+    }
+    parts.join(", ")
+}
+
+fn place(document: &mut Document, intercessions: &BTreeMap<IntercessionCategory, Vec<String>>) {
+    // each option of a choice is a complete form in its own right
+    if let Content::Choice(choice) = &mut document.content {
+        for option in choice.options.iter_mut() {
+            place(option, intercessions);
+        }
+        return;
+    }
+
+    let mut petitions = petitions(document);
+    let mut biddings = Vec::new();
+    let mut requests = intercessions
+        .get(&IntercessionCategory::Other)
+        .cloned()
+        .unwrap_or_default();
+    for (category, names) in intercessions
+        .iter()
+        .filter(|(category, names)| **category != IntercessionCategory::Other && !names.is_empty())
+    {
+        if !insert(&mut petitions, category.cues(), names) {
+            match category {
+                IntercessionCategory::Thanksgivings | IntercessionCategory::Commemorations => {
+                    biddings.push(category.bidding(&joined(names)))
+                }
+                _ => requests.extend(names.iter().cloned()),
+            }
+        }
+    }
+    if !requests.is_empty()
+        && !insert(
+            &mut petitions,
+            IntercessionCategory::Other.cues(),
+            &requests,
+        )
+    {
+        biddings.push(IntercessionCategory::Other.bidding(&joined(&requests)));
+    }
+
+    if !biddings.is_empty() {
+        let biddings = biddings
+            .into_iter()
+            .map(|bidding| Document::from(Text::from(bidding)));
+        match &mut document.content {
+            Content::Series(series) => {
+                // before the rubrics that conclude the form (“Silence… The Celebrant adds a concluding Collect.”)
+                let docs = series.as_slice();
+                let index = match docs
+                    .iter()
+                    .rposition(|doc| matches!(doc.content, Content::Rubric(_)))
+                {
+                    Some(last) => docs[..last]
+                        .iter()
+                        .rposition(|doc| !matches!(doc.content, Content::Rubric(_)))
+                        .map(|idx| idx + 1)
+                        .unwrap_or(last),
+                    None => docs.len(),
+                };
+                for (offset, bidding) in biddings.enumerate() {
+                    series.insert_at(index + offset, bidding);
+                }
+            }
+            content => {
+                let original = Document::from(content.clone());
+                *content = Content::Series(Series::from(
+                    std::iter::once(original)
+                        .chain(biddings)
+                        .collect::<Vec<_>>(),
+                ));
+            }
+        }
+    }
+}
+
+/// The text of each petition in the form, in order, excluding rubrics
+fn petitions(document: &mut Document) -> Vec<&mut String> {
+    match &mut document.content {
+        Content::Series(series) => series
+            .as_mut_slice()
+            .iter_mut()
+            .flat_map(petitions)
+            .collect(),
+        Content::Liturgy(liturgy) => liturgy
+            .body
+            .as_mut_slice()
+            .iter_mut()
+            .flat_map(petitions)
+            .collect(),
+        Content::Text(text) => vec![&mut text.text],
+        Content::Litany(litany) => litany.lines.iter_mut().collect(),
+        Content::ResponsivePrayer(prayer) => prayer.iter_mut().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Inserts the names at the first cue found in any petition, returning whether a place was found
+fn insert(petitions: &mut [&mut String], cues: &[Cue], names: &[String]) -> bool {
+    let names = joined(names);
+    for cue in cues {
+        for petition in petitions.iter_mut() {
+            if let Some(filled) = cue.fill(petition, &names) {
+                **petition = filled;
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn joined(names: &[String]) -> String {
+    Names::new(names, Pronouns::They).joined()
+}
+
+#[cfg(all(test, any(feature = "browser", feature = "server")))]
+mod tests {
+    use std::collections::HashMap;
+
+    use calendar::BCP1979_CALENDAR;
+    use liturgy::{CycleOfPrayer, LiturgyPreferences, PreferenceKey, PreferenceValue};
+
+    use super::*;
+    use crate::{register_cycle_of_prayer, rite2, CommonPrayer};
+
+    #[test]
+    fn intercessions_are_inserted_into_prayers_of_the_people() {
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 2, 13), false);
+        let lines = |document: Document| match document.content {
+            Content::Series(series) => series
+                .into_vec()
+                .into_iter()
+                .flat_map(|doc| match doc.content {
+                    Content::Text(text) => vec![text.text],
+                    Content::Litany(litany) => litany.into_vec(),
+                    Content::ResponsivePrayer(prayer) => prayer.into_vec(),
+                    _ => Vec::new(),
+                })
+                .collect::<Vec<_>>(),
+            _ => panic!("Prayers of the People should be a Series"),
+        };
+        let petitions = |form: PrayersOfThePeopleForm| {
+            lines(
+                PrayersOfThePeople::new(form)
+                    .intercession(IntercessionCategory::Church, "the Church of Uganda")
+                    .intercession(IntercessionCategory::Diocese, "St. Paul’s, Oakland")
+                    .intercessions(IntercessionCategory::Sick, ["Walter", "Joan"])
+                    .intercession(IntercessionCategory::Departed, "Margaret")
+                    .intercession(IntercessionCategory::Thanksgivings, "the birth of Eli")
+                    .commemorations(&BCP1979_CALENDAR, &day)
+                    .build(),
+            )
+        };
+        assert_eq!(
+            petitions(PrayersOfThePeopleForm::II)[..5],
+            [
+                "I ask your prayers for God’s people throughout the world, especially the Church of Uganda; for our Bishop(s) ___________ ; for this gathering; and for all ministers and people, especially St. Paul’s, Oakland.\nPray for the Church.",
+                "I ask your prayers for peace; for goodwill among nations; and for the well-being of all people.\nPray for justice and peace.",
+                "I ask your prayers for the poor, the sick, the hungry, the oppressed, and those in prison.\nPray for those in any need or trouble, especially Walter and Joan.",
+                "I ask your prayers for all who seek God, or a deeper knowledge of him.\nPray that they may find and be found by him.",
+                "I ask your prayers for the departed especially Margaret.\nPray for those who have died.",
+            ]
+        );
+        assert_eq!(
+            petitions(PrayersOfThePeopleForm::II)[5],
+            "Praise God for those in every generation in whom Christ has been honored especially Absalom Jones, Priest whom we remember today.\nPray that we may have grace to glorify Christ in our own day."
+        );
+
+        // optional blanks lose their brackets once they are filled
+        let form_v = lines(
+            PrayersOfThePeople::new(PrayersOfThePeopleForm::V)
+                .intercession(
+                    IntercessionCategory::Community,
+                    "the students of Central High",
+                )
+                .build(),
+        );
+        assert_eq!(
+            form_v
+                .iter()
+                .find(|line| line.contains("this community"))
+                .map(String::as_str),
+            Some("| For all who live and work in this community especially the students of Central High, we pray to you, O Lord.")
+        );
+
+        // forms without blanks keep their structure, with names added to the matching petitions
+        let form_iv = petitions(PrayersOfThePeopleForm::IV);
+        assert_eq!(
+            form_iv
+                .iter()
+                .filter(|line| *line == "Hear our prayer.")
+                .count(),
+            6
+        );
+        assert_eq!(
+            form_iv[13],
+            "Comfort and heal all those who suffer in body, mind, or spirit, especially Walter and Joan; give them courage and hope in their troubles, and bring them the joy of your salvation."
+        );
+        assert_eq!(
+            form_iv[16],
+            "We commend to your mercy all who have died, especially Margaret, that your will for them may be fulfilled; and we pray that we may share with all your saints, especially Absalom Jones, Priest, in your eternal kingdom."
+        );
+        assert_eq!(
+            form_iv[19..],
+            [
+                "We give thanks for the birth of Eli.",
+                "We pray for St. Paul’s, Oakland."
+            ]
+        );
+    }
+
+    #[test]
+    fn cycles_of_prayer_are_inserted_by_date() {
+        let cycle: CycleOfPrayer = serde_json::from_str(
+            r#"{
+                "name": "Diocesan Cycle of Prayer",
+                "entries": [
+                    { "key": { "Date": { "month": 7, "day": 11 } }, "intercessions": ["St. Benedict’s, Los Osos"] },
+                    { "key": { "Index": 0 }, "intercessions": ["Grace Church, Madison"] },
+                    { "key": { "Index": 1 }, "intercessions": ["Christ Church, Ridgewood", "St. Paul’s, Chatham"] }
+                ]
+            }"#,
+        )
+        .unwrap();
+        register_cycle_of_prayer("diocese-of-el-camino-real", cycle);
+        let prefs = HashMap::from([(
+            PreferenceKey::from(CycleOfPrayerId::Diocesan.preference()),
+            PreferenceValue::CycleOfPrayer("diocese-of-el-camino-real".into()),
+        )]);
+
+        let compile_office_on = |office: &Document, date: Date| {
+            let day = BCP1979_CALENDAR.liturgical_day(date, false);
+            CommonPrayer::compile(
+                office.clone(),
+                &BCP1979_CALENDAR,
+                &day,
+                &day.observed,
+                &prefs,
+                &LiturgyPreferences::default(),
+            )
+            .unwrap()
+        };
+        let compile_on = |date: Date| compile_office_on(&rite2::office::MORNING_PRAYER_II, date);
+        assert!(compile_on(Date::from_ymd(2023, 7, 11))
+            .contains("In the diocesan cycle of prayer, we pray for St. Benedict’s, Los Osos."));
+        let (on_even_day, on_odd_day) = (
+            compile_on(Date::from_ymd(2023, 7, 12)),
+            compile_on(Date::from_ymd(2023, 7, 13)),
+        );
+        assert_ne!(
+            on_even_day.contains("we pray for Grace Church, Madison."),
+            on_odd_day.contains("we pray for Grace Church, Madison.")
+        );
+        assert_ne!(
+            on_even_day.contains("we pray for Christ Church, Ridgewood and St. Paul’s, Chatham."),
+            on_odd_day.contains("we pray for Christ Church, Ridgewood and St. Paul’s, Chatham.")
+        );
+        // no Anglican cycle has been given, so nothing is inserted for it
+        assert!(!on_even_day.contains("Anglican Cycle of Prayer"));
+        // Evening Prayer includes the cycles as well
+        assert!(compile_office_on(
+            &rite2::office::EVENING_PRAYER_II,
+            Date::from_ymd(2023, 7, 11)
+        )
+        .contains("In the diocesan cycle of prayer, we pray for St. Benedict’s, Los Osos."));
+        // without the cycle in the preferences, nothing is inserted
+        let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2023, 7, 11), false);
+        assert!(!CommonPrayer::compile(
+            rite2::office::MORNING_PRAYER_II.clone(),
+            &BCP1979_CALENDAR,
+            &day,
+            &day.observed,
+            &HashMap::new(),
+            &LiturgyPreferences::default(),
+        )
+        .unwrap()
+        .contains("diocesan cycle of prayer"));
+        // nor if no cycle has been registered under the identifier
+        let unregistered = HashMap::from([(
+            PreferenceKey::from(CycleOfPrayerId::Diocesan.preference()),
+            PreferenceValue::CycleOfPrayer("diocese-of-nowhere".into()),
+        )]);
+        assert!(!CommonPrayer::compile(
+            rite2::office::MORNING_PRAYER_II.clone(),
+            &BCP1979_CALENDAR,
+            &day,
+            &day.observed,
+            &unregistered,
+            &LiturgyPreferences::default(),
+        )
+        .unwrap()
+        .contains("diocesan cycle of prayer"));
+
+        let prayers = PrayersOfThePeople::new(PrayersOfThePeopleForm::III)
+            .cycles_of_prayer::<CommonPrayer>(&prefs, Date::from_ymd(2023, 7, 11))
+            .build();
+        assert!(prayers.contains(
+            "We pray for all bishops, priests, and deacons, especially St. Benedict’s, Los Osos;"
+        ));
+    }
+}
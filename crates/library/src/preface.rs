@@ -0,0 +1,392 @@
+use calendar::{Calendar, Date, LiturgicalDay, LiturgicalDayId, Rank, Season, Weekday};
+use language::Language;
+use serde::{Deserialize, Serialize};
+
+use crate::{CollectData, CollectId, COLLECT_LINKS};
+
+/// The Proper Prefaces that may be inserted into the Great Thanksgiving
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PrefaceId {
+    LordsDay,
+    Advent,
+    Incarnation,
+    Epiphany,
+    Lent,
+    HolyWeek,
+    Easter,
+    Ascension,
+    Pentecost,
+    TrinitySunday,
+    AllSaints,
+    ASaint,
+    Apostles,
+    DedicationOfAChurch,
+    Baptism,
+    Marriage,
+    CommemorationOfTheDead,
+}
+
+/// A preface named in the rubric attached to a collect
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AppointedPreface {
+    /// “The Preface of the Season,” which depends on the day it is used
+    Season,
+    Preface(PrefaceId),
+}
+
+/// The words that identify each preface in a rubric, in the order they should be checked
+/// (e.g., “All Saints” before “a Saint,” and the first preface named in “Preface of the Incarnation The Epiphany”)
+const PREFACE_NAMES: [(&str, AppointedPreface); 36] = [
+    ("lord’s day", AppointedPreface::Preface(PrefaceId::LordsDay)),
+    (
+        "día del señor",
+        AppointedPreface::Preface(PrefaceId::LordsDay),
+    ),
+    ("season", AppointedPreface::Season),
+    ("estación", AppointedPreface::Season),
+    ("advent", AppointedPreface::Preface(PrefaceId::Advent)),
+    ("adviento", AppointedPreface::Preface(PrefaceId::Advent)),
+    (
+        "incarnation",
+        AppointedPreface::Preface(PrefaceId::Incarnation),
+    ),
+    (
+        "encarnación",
+        AppointedPreface::Preface(PrefaceId::Incarnation),
+    ),
+    ("epiphany", AppointedPreface::Preface(PrefaceId::Epiphany)),
+    ("epifanía", AppointedPreface::Preface(PrefaceId::Epiphany)),
+    ("lent", AppointedPreface::Preface(PrefaceId::Lent)),
+    ("cuaresma", AppointedPreface::Preface(PrefaceId::Lent)),
+    ("holy week", AppointedPreface::Preface(PrefaceId::HolyWeek)),
+    (
+        "semana santa",
+        AppointedPreface::Preface(PrefaceId::HolyWeek),
+    ),
+    ("easter", AppointedPreface::Preface(PrefaceId::Easter)),
+    ("pascua", AppointedPreface::Preface(PrefaceId::Easter)),
+    ("ascension", AppointedPreface::Preface(PrefaceId::Ascension)),
+    ("ascensión", AppointedPreface::Preface(PrefaceId::Ascension)),
+    ("pentecost", AppointedPreface::Preface(PrefaceId::Pentecost)),
+    (
+        "pentecostés",
+        AppointedPreface::Preface(PrefaceId::Pentecost),
+    ),
+    (
+        "trinity",
+        AppointedPreface::Preface(PrefaceId::TrinitySunday),
+    ),
+    (
+        "trinidad",
+        AppointedPreface::Preface(PrefaceId::TrinitySunday),
+    ),
+    (
+        "all saints",
+        AppointedPreface::Preface(PrefaceId::AllSaints),
+    ),
+    (
+        "todos los santos",
+        AppointedPreface::Preface(PrefaceId::AllSaints),
+    ),
+    ("apostles", AppointedPreface::Preface(PrefaceId::Apostles)),
+    ("apóstoles", AppointedPreface::Preface(PrefaceId::Apostles)),
+    ("saint", AppointedPreface::Preface(PrefaceId::ASaint)),
+    ("santo", AppointedPreface::Preface(PrefaceId::ASaint)),
+    (
+        "dedication",
+        AppointedPreface::Preface(PrefaceId::DedicationOfAChurch),
+    ),
+    (
+        "dedicación",
+        AppointedPreface::Preface(PrefaceId::DedicationOfAChurch),
+    ),
+    ("baptism", AppointedPreface::Preface(PrefaceId::Baptism)),
+    ("bautismo", AppointedPreface::Preface(PrefaceId::Baptism)),
+    ("marriage", AppointedPreface::Preface(PrefaceId::Marriage)),
+    ("matrimonio", AppointedPreface::Preface(PrefaceId::Marriage)),
+    (
+        "dead",
+        AppointedPreface::Preface(PrefaceId::CommemorationOfTheDead),
+    ),
+    (
+        "difuntos",
+        AppointedPreface::Preface(PrefaceId::CommemorationOfTheDead),
+    ),
+];
+
+impl PrefaceId {
+    /// The title of the preface, e.g., for a rubric when its text is not available
+    pub fn name(&self, language: Language) -> &'static str {
+        match (language, self) {
+            (Language::Es, PrefaceId::LordsDay) => "Prefacio del Día del Señor",
+            (Language::Es, PrefaceId::Advent) => "Prefacio de Adviento",
+            (Language::Es, PrefaceId::Incarnation) => "Prefacio de la Encarnación",
+            (Language::Es, PrefaceId::Epiphany) => "Prefacio de la Epifanía",
+            (Language::Es, PrefaceId::Lent) => "Prefacio de Cuaresma",
+            (Language::Es, PrefaceId::HolyWeek) => "Prefacio de Semana Santa",
+            (Language::Es, PrefaceId::Easter) => "Prefacio de Pascua",
+            (Language::Es, PrefaceId::Ascension) => "Prefacio de la Ascensión",
+            (Language::Es, PrefaceId::Pentecost) => "Prefacio de Pentecostés",
+            (Language::Es, PrefaceId::TrinitySunday) => "Prefacio del Domingo de Trinidad",
+            (Language::Es, PrefaceId::AllSaints) => "Prefacio de Todos los Santos",
+            (Language::Es, PrefaceId::ASaint) => "Prefacio de un Santo",
+            (Language::Es, PrefaceId::Apostles) => "Prefacio de Apóstoles",
+            (Language::Es, PrefaceId::DedicationOfAChurch) => {
+                "Prefacio de la Dedicación de una Iglesia"
+            }
+            (Language::Es, PrefaceId::Baptism) => "Prefacio del Bautismo",
+            (Language::Es, PrefaceId::Marriage) => "Prefacio del Matrimonio",
+            (Language::Es, PrefaceId::CommemorationOfTheDead) => {
+                "Prefacio de la Conmemoración de los Fieles Difuntos"
+            }
+            (_, PrefaceId::LordsDay) => "Preface of the Lord’s Day",
+            (_, PrefaceId::Advent) => "Preface of Advent",
+            (_, PrefaceId::Incarnation) => "Preface of the Incarnation",
+            (_, PrefaceId::Epiphany) => "Preface of the Epiphany",
+            (_, PrefaceId::Lent) => "Preface of Lent",
+            (_, PrefaceId::HolyWeek) => "Preface of Holy Week",
+            (_, PrefaceId::Easter) => "Preface of Easter",
+            (_, PrefaceId::Ascension) => "Preface of the Ascension",
+            (_, PrefaceId::Pentecost) => "Preface of Pentecost",
+            (_, PrefaceId::TrinitySunday) => "Preface of Trinity Sunday",
+            (_, PrefaceId::AllSaints) => "Preface of All Saints",
+            (_, PrefaceId::ASaint) => "Preface of a Saint",
+            (_, PrefaceId::Apostles) => "Preface of Apostles and Ordinations",
+            (_, PrefaceId::DedicationOfAChurch) => "Preface of the Dedication of a Church",
+            (_, PrefaceId::Baptism) => "Preface of Baptism",
+            (_, PrefaceId::Marriage) => "Preface of Marriage",
+            (_, PrefaceId::CommemorationOfTheDead) => "Preface of the Commemoration of the Dead",
+        }
+    }
+
+    /// The preface used during a season, on days that do not have a preface of their own.
+    /// The Prefaces of the Incarnation and of the Epiphany are not used throughout their
+    /// seasons, but only during their octaves (see [PrefaceId::of_octave]) and on the
+    /// feasts that appoint them.
+    pub fn of_season(season: Season) -> Option<Self> {
+        match season {
+            Season::Advent => Some(PrefaceId::Advent),
+            Season::Incarnation => Some(PrefaceId::Incarnation),
+            Season::Lent => Some(PrefaceId::Lent),
+            Season::HolyWeek => Some(PrefaceId::HolyWeek),
+            Season::Easter => Some(PrefaceId::Easter),
+            Season::Ascension => Some(PrefaceId::Ascension),
+            Season::Pentecost => Some(PrefaceId::Pentecost),
+            Season::Trinity => Some(PrefaceId::TrinitySunday),
+            Season::Saints => Some(PrefaceId::ASaint),
+            _ => None,
+        }
+    }
+
+    /// The preface appointed for Christmas Day or the Epiphany and the seven days after.
+    /// ```
+    /// # use calendar::Date;
+    /// # use library::PrefaceId;
+    /// assert_eq!(PrefaceId::of_octave(Date::from_ymd(2023, 1, 1)), Some(PrefaceId::Incarnation));
+    /// assert_eq!(PrefaceId::of_octave(Date::from_ymd(2023, 1, 13)), Some(PrefaceId::Epiphany));
+    /// assert_eq!(PrefaceId::of_octave(Date::from_ymd(2023, 1, 24)), None);
+    /// ```
+    pub fn of_octave(date: Date) -> Option<Self> {
+        match (date.month(), date.day()) {
+            (12, 25..=31) | (1, 1) => Some(PrefaceId::Incarnation),
+            (1, 6..=13) => Some(PrefaceId::Epiphany),
+            _ => None,
+        }
+    }
+}
+
+impl CollectData {
+    /// The prefaces named in the collect’s `preface` rubric, in the order given.
+    /// Returns `None` if the rubric directs that no Proper Preface be used.
+    /// ```
+    /// # use library::{AppointedPreface, CollectData, PrefaceId};
+    /// # use liturgy::Document;
+    /// let data = CollectData {
+    ///     preface: "Preface of Baptism, or of the Season".into(),
+    ///     ..CollectData::from(Document::new())
+    /// };
+    /// assert_eq!(
+    ///     data.appointed_prefaces(),
+    ///     Some(vec![AppointedPreface::Preface(PrefaceId::Baptism), AppointedPreface::Season])
+    /// );
+    /// let datos = CollectData {
+    ///     preface: "Prefacio de Epifanía o del Día del Señor".into(),
+    ///     ..CollectData::from(Document::new())
+    /// };
+    /// assert_eq!(
+    ///     datos.appointed_prefaces(),
+    ///     Some(vec![
+    ///         AppointedPreface::Preface(PrefaceId::Epiphany),
+    ///         AppointedPreface::Preface(PrefaceId::LordsDay)
+    ///     ])
+    /// );
+    /// ```
+    pub fn appointed_prefaces(&self) -> Option<Vec<AppointedPreface>> {
+        let rubric = self.preface.to_lowercase();
+        if rubric.contains("no proper preface") || rubric.contains("no se usa") {
+            return None;
+        }
+        Some(
+            rubric
+                .split(" or ")
+                .flat_map(|part| part.split(" o "))
+                .filter_map(|part| {
+                    PREFACE_NAMES
+                        .iter()
+                        .find(|(name, _)| part.contains(name))
+                        .map(|(_, preface)| *preface)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The Proper Prefaces appointed for the day, in the order they are listed.
+///
+/// On a holy day that takes precedence over the weekday, or on a Sunday, the preface named
+/// with its collect is used; otherwise, the preface of the season (if any). Sundays without
+/// a preface of their own use the Preface of the Lord’s Day. Outside their octaves, weekdays
+/// in Christmastide and after the Epiphany have no Proper Preface.
+pub fn prefaces_for_day<'a>(
+    calendar: &Calendar,
+    day: &LiturgicalDay,
+    observed: &LiturgicalDayId,
+    collects: impl IntoIterator<Item = &'a (CollectId, CollectData)>,
+) -> Vec<PrefaceId> {
+    let collects = collects.into_iter().collect::<Vec<_>>();
+    let is_sunday = day.weekday == Weekday::Sun;
+
+    let collect_id = match observed {
+        LiturgicalDayId::Feast(feast) | LiturgicalDayId::TransferredFeast(feast)
+            if calendar.rank(day) >= Rank::PrecedenceOverWeekday =>
+        {
+            let feast = calendar.feast_eve_following_day(feast).unwrap_or(*feast);
+            Some(COLLECT_LINKS.linked_id(&CollectId::Feast(feast)))
+        }
+        _ if is_sunday => Some(COLLECT_LINKS.linked_id(&match day.proper {
+            Some(proper) => CollectId::Proper(proper),
+            None => CollectId::Week(day.week),
+        })),
+        _ => None,
+    };
+    let appointed = collect_id
+        .and_then(|id| collects.iter().find(|(s_id, _)| *s_id == id))
+        .map(|(_, data)| data.appointed_prefaces())
+        .unwrap_or_else(|| Some(Vec::new()));
+
+    let of_season = PrefaceId::of_octave(day.date)
+        .or_else(|| PrefaceId::of_season(calendar.season(day)))
+        .or_else(|| PrefaceId::of_season(calendar.base_season(day)));
+
+    let mut prefaces = match appointed {
+        None => return Vec::new(),
+        Some(appointed) => appointed
+            .into_iter()
+            .filter_map(|preface| match preface {
+                AppointedPreface::Season => of_season,
+                AppointedPreface::Preface(id) => Some(id),
+            })
+            .collect::<Vec<_>>(),
+    };
+    if prefaces.is_empty() {
+        prefaces.extend(of_season);
+    }
+    if prefaces.is_empty() && is_sunday {
+        prefaces.push(PrefaceId::LordsDay);
+    }
+    prefaces.dedup();
+    prefaces
+}
+
+#[cfg(all(test, any(feature = "browser", feature = "server")))]
+mod tests {
+    use calendar::BCP1979_CALENDAR;
+    use liturgy::{Content, Document, LiturgyPreferences, PreferenceKey, PreferenceValue, Version};
+
+    use super::*;
+    use crate::{CommonPrayer, Library};
+
+    fn compiled_preface(date: Date, language: Language, version: Version) -> Option<Document> {
+        let prefs: [(PreferenceKey, PreferenceValue); 0] = [];
+        let day = BCP1979_CALENDAR.liturgical_day(date, false);
+        let doc = Document::new()
+            .language(language)
+            .version(version)
+            .content(Content::ProperPreface);
+        CommonPrayer::compile(
+            doc,
+            &BCP1979_CALENDAR,
+            &day,
+            &day.observed,
+            &prefs,
+            &LiturgyPreferences::default(),
+        )
+    }
+
+    #[test]
+    fn proper_preface_of_the_day() {
+        // Easter Day
+        let easter =
+            compiled_preface(Date::from_ymd(2023, 4, 9), Language::En, Version::RiteII).unwrap();
+        assert_eq!(easter.label.as_deref(), Some("Preface of Easter"));
+        // a Sunday after Pentecost offers all three prefaces of the Lord’s Day
+        match compiled_preface(Date::from_ymd(2023, 7, 9), Language::En, Version::RiteI)
+            .map(|doc| doc.content)
+        {
+            Some(Content::Choice(choice)) => {
+                assert_eq!(choice.options.len(), 3);
+                assert!(choice
+                    .options
+                    .iter()
+                    .all(|doc| doc.version == Version::RiteI));
+            }
+            other => panic!("expected a choice of prefaces, found {:?}", other),
+        }
+        // a weekday in Lent uses the preface of the season
+        let lent =
+            compiled_preface(Date::from_ymd(2023, 3, 7), Language::En, Version::RiteII).unwrap();
+        assert!(matches!(lent.content, Content::Choice(_)));
+        // an ordinary weekday has no Proper Preface
+        assert_eq!(
+            compiled_preface(Date::from_ymd(2023, 7, 11), Language::En, Version::RiteII),
+            None
+        );
+        // the Preface of the Epiphany is used during its octave, but not on later weekdays
+        let octave =
+            compiled_preface(Date::from_ymd(2023, 1, 10), Language::En, Version::RiteII).unwrap();
+        assert!(octave
+            .label
+            .as_deref()
+            .unwrap_or_default()
+            .contains("Epiphany"));
+        assert_eq!(
+            compiled_preface(Date::from_ymd(2023, 1, 24), Language::En, Version::RiteII),
+            None
+        );
+        assert_eq!(
+            compiled_preface(Date::from_ymd(2023, 2, 14), Language::En, Version::RiteII),
+            None
+        );
+        // a Sunday after the Epiphany defaults to the first preface appointed
+        match compiled_preface(Date::from_ymd(2023, 1, 22), Language::En, Version::RiteII)
+            .map(|doc| doc.content)
+        {
+            Some(Content::Choice(choice)) => {
+                assert_eq!(choice.selected, 0);
+                assert_eq!(
+                    choice.options[0].label.as_deref(),
+                    Some("Preface of the Epiphany")
+                );
+            }
+            other => panic!("expected a choice of prefaces, found {:?}", other),
+        }
+        // the name of the preface is given when its text is not available
+        let pascua = compiled_preface(
+            Date::from_ymd(2023, 4, 9),
+            Language::Es,
+            Version::LibroDeOracionComun,
+        )
+        .unwrap();
+        assert_eq!(pascua.as_text(), "Prefacio de Pascua");
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use liturgy::{Content, SlugPath};
+use serde::{Deserialize, Serialize};
+
+use crate::{Contents, Library};
+
+/// A description of the preferences of one liturgy in a [Library], from which a front end can build its settings
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiturgyPreferenceSchema {
+    pub slug_path: SlugPath,
+    pub label: String,
+    /// See [LiturgyPreferences::json_schema](liturgy::LiturgyPreferences::json_schema)
+    pub json_schema: serde_json::Value,
+    /// See [LiturgyPreferences::typescript](liturgy::LiturgyPreferences::typescript)
+    pub typescript: String,
+}
+
+/// Describes the preferences of every liturgy in the [Library]'s table of contents that has any
+pub fn preference_schemas<L: Library>() -> Vec<LiturgyPreferenceSchema> {
+    // the flattened table of contents includes branches as well as leaves, and may include the same entry more than once
+    let mut checked = HashSet::new();
+    L::contents()
+        .flatten()
+        .filter(|(slug_path, _)| checked.insert(slug_path.clone()))
+        .filter_map(|(slug_path, contents)| match contents {
+            Contents::Document(doc) => match &doc.content {
+                Content::Liturgy(liturgy) if !liturgy.preferences.is_empty() => {
+                    let label = doc.label.clone().unwrap_or_else(|| slug_path.to_string());
+                    Some(LiturgyPreferenceSchema {
+                        json_schema: liturgy.preferences.json_schema(&label),
+                        typescript: liturgy.preferences.typescript(&interface_name(&slug_path)),
+                        slug_path,
+                        label,
+                    })
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// A TypeScript identifier for the preferences of the liturgy at the path,
+/// e.g., `OfficeMorningPrayerRiteIIPreferences`
+fn interface_name(slug_path: &SlugPath) -> String {
+    slug_path
+        .as_slice()
+        .iter()
+        .flat_map(|slug| {
+            slug.slugify()
+                .split(|c: char| !c.is_alphanumeric())
+                .map(|part| {
+                    let mut chars = part.chars();
+                    chars
+                        .next()
+                        .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>()
+        })
+        .chain(std::iter::once(String::from("Preferences")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use liturgy::{Document, Slug, Version};
+
+    use super::*;
+    use crate::CommonPrayer;
+
+    #[test]
+    fn default_preferences_are_valid() {
+        for (slug_path, contents) in CommonPrayer::contents().flatten() {
+            let liturgy = match contents {
+                Contents::Document(Document {
+                    content: Content::Liturgy(liturgy),
+                    ..
+                }) => liturgy,
+                _ => continue,
+            };
+            let defaults = liturgy
+                .preferences
+                .iter()
+                .filter_map(|pref| {
+                    liturgy
+                        .preferences
+                        .default_value_for_key(&pref.key)
+                        .map(|value| (pref.key.clone(), value.clone()))
+                })
+                .collect::<HashMap<_, _>>();
+            assert_eq!(
+                liturgy.preferences.validate(&defaults),
+                [],
+                "default preferences of {}",
+                slug_path
+            );
+        }
+
+        let schemas = preference_schemas::<CommonPrayer>();
+        let morning_prayer = schemas
+            .iter()
+            .find(|schema| {
+                schema.slug_path
+                    == SlugPath::from([
+                        Slug::Office,
+                        Slug::MorningPrayer,
+                        Slug::Version(Version::RiteII),
+                    ])
+            })
+            .unwrap();
+        assert!(morning_prayer
+            .typescript
+            .starts_with("export interface OfficeMorningPrayerRiteIIPreferences {"));
+        assert!(morning_prayer.json_schema["properties"]["Lectionary"]["oneOf"].is_array());
+    }
+}
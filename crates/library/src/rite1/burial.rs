@@ -56,40 +56,48 @@ lazy_static! {
                             PsalmVerse {
                                 number: 1,
                                 a: "Like as the hart desireth the water-brooks, *".into(),
-                                b: "so longeth my soul after thee, O God.".into()
+                                b: "so longeth my soul after thee, O God.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 2,
                                 a: "My soul is athirst for God, yea, even for the living God; *".into(),
-                                b: "when shall I come to appear before the presence of God?".into()
+                                b: "when shall I come to appear before the presence of God?".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 3,
                                 a: "My tears have been my meat day and night, *".into(),
-                                b: "while they daily say unto me, Where is now thy God?".into()
+                                b: "while they daily say unto me, Where is now thy God?".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 4,
                                 a: "Now when I think thereupon, I pour out my heart by myself; *".into(),
-                                b: "for I went with the multitude, and brought them forth into the house of God".into()
+                                b: "for I went with the multitude, and brought them forth into the house of God".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 5,
                                 a: "In the voice of praise and thanksgiving, *".into(),
-                                b: "among such as keep holy-day.".into()
+                                b: "among such as keep holy-day.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 6,
                                 a: "Why art thou so full of heaviness, O my soul? *".into(),
-                                b: "and why art thou so disquieted within me?".into()
+                                b: "and why art thou so disquieted within me?".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 7,
                                 a: "O put thy trust in God; *".into(),
-                                b: "for I will yet thank him, which is the help of my\ncountenance, and my God.".into()
+                                b: "for I will yet thank him, which is the help of my\ncountenance, and my God.".into(),
+                                ..Default::default()
                             }
                         ]
-                    }]
+                    }],
+                    ..Default::default()
                 }),
                 Document::from(Psalm {
                     citation: None,
@@ -105,40 +113,48 @@ lazy_static! {
                             PsalmVerse {
                                 number: 1,
                                 a: "God is our hope and strength, *".into(),
-                                b: "a very present help in trouble.".into()
+                                b: "a very present help in trouble.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 2,
                                 a: "Therefore will we not fear, though the earth be moved, *".into(),
-                                b: "and though the hills be carried into the midst of the sea;".into()
+                                b: "and though the hills be carried into the midst of the sea;".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 3,
                                 a: "Though the waters thereof rage and swell, *".into(),
-                                b: "and though the mountains shake at the tempest of the same.".into()
+                                b: "and though the mountains shake at the tempest of the same.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 4,
                                 a: "There is a river, the streams whereof make glad the city of God, *".into(),
-                                b: "the holy place of the tabernacle of the Most Highest.".into()
+                                b: "the holy place of the tabernacle of the Most Highest.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 5,
                                 a: "God is in the midst of her,\ntherefore shall she not be removed; *".into(),
-                                b: "God shall help her, and that right early.".into()
+                                b: "God shall help her, and that right early.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 6,
                                 a: "Be still then, and know that I am God; *".into(),
-                                b: "I will be exalted among the nations,\nand I will be exalted in the earth.".into()
+                                b: "I will be exalted among the nations,\nand I will be exalted in the earth.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 7,
                                 a: "The LORD of hosts is with us; *".into(),
-                                b: "the God of Jacob is our refuge.".into()
+                                b: "the God of Jacob is our refuge.".into(),
+                                ..Default::default()
                             },
                         ]
-                    }]
+                    }],
+                    ..Default::default()
                 }),
                 Document::from(Psalm {
                     citation: None,
@@ -154,60 +170,72 @@ lazy_static! {
                             PsalmVerse {
                                 number: 1,
                                 a: "LORD, thou hast been our refuge, *".into(),
-                                b: "from one generation to another.".into()
+                                b: "from one generation to another.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 2,
                                 a: "Before the mountains were brought forth,\nor ever the earth and the world were made, *".into(),
-                                b: "thou art God from everlasting, and world without end.".into()
+                                b: "thou art God from everlasting, and world without end.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 3,
                                 a: "Thou turnest man to destruction; *".into(),
-                                b: "again thou sayest, Come again, ye children of men.".into()
+                                b: "again thou sayest, Come again, ye children of men.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 4,
                                 a: "For a thousand years in thy sight are but as yesterday when it is past, *".into(),
-                                b: "and as a watch in the night.".into()
+                                b: "and as a watch in the night.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 5,
                                 a: "As soon as thou scatterest them they are even as a sleep, *".into(),
-                                b: "and fade away suddenly like the grass.".into()
+                                b: "and fade away suddenly like the grass.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 6,
                                 a: "In the morning it is green, and groweth up; *".into(),
-                                b: "but in the evening it is cut down, dried up, and withered.".into()
+                                b: "but in the evening it is cut down, dried up, and withered.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 7,
                                 a: "For we consume away in thy displeasure, *".into(),
-                                b: "and are afraid at thy wrathful indignation.".into()
+                                b: "and are afraid at thy wrathful indignation.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 8,
                                 a: "Thou hast set our misdeeds before thee, *".into(),
-                                b: "and our secret sins in the light of thy countenance.".into()
+                                b: "and our secret sins in the light of thy countenance.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 9,
                                 a: "For when thou art angry all our days are gone; *".into(),
-                                b: "we bring our years to an end, as it were a tale that is told.".into()
+                                b: "we bring our years to an end, as it were a tale that is told.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 10,
                                 a: "The days of our age are threescore years and ten;\nand though men be so strong that they come to fourscore years, *".into(),
-                                b: "yet is their strength then but labor and sorrow,\nso soon passeth it away, and we are gone.".into()
+                                b: "yet is their strength then but labor and sorrow,\nso soon passeth it away, and we are gone.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 11,
                                 a: "So teach us to number our days, *".into(),
-                                b: "that we may apply our hearts unto wisdom.".into()
+                                b: "that we may apply our hearts unto wisdom.".into(),
+                                ..Default::default()
                             },
                         ]
-                    }]
+                    }],
+                    ..Default::default()
                 }),
                 Document::from(Psalm {
                     citation: None,
@@ -223,45 +251,54 @@ lazy_static! {
                             PsalmVerse {
                                 number: 1,
                                 a: "I will lift up mine eyes unto the hills; *".into(),
-                                b: "from whence cometh my help?".into()
+                                b: "from whence cometh my help?".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 2,
                                 a: "My help cometh even from the LORD".into(),
-                                b: "who hath made heaven and earth.".into()
+                                b: "who hath made heaven and earth.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 3,
                                 a: "He will not suffer thy foot to be moved, *".into(),
-                                b: "and he that keepeth thee will not sleep.".into()
+                                b: "and he that keepeth thee will not sleep.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 4,
                                 a: "Behold, he that keepeth Israel *".into(),
-                                b: "shall neither slumber nor sleep.".into()
+                                b: "shall neither slumber nor sleep.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 5,
                                 a: "The LORD himself is thy keeper; *".into(),
-                                b: "the LORD is thy defence upon thy right hand;".into()
+                                b: "the LORD is thy defence upon thy right hand;".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 6,
                                 a: "So that the sun shall not burn thee by day, *".into(),
-                                b: "neither the moon by night.".into()
+                                b: "neither the moon by night.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 7,
                                 a: "The LORD shall preserve thee from all evil; *".into(),
-                                b: "yea, it is even he that shall keep thy soul.".into()
+                                b: "yea, it is even he that shall keep thy soul.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 8,
                                 a: "The LORD shall preserve thy going out, and thy coming in, *".into(),
-                                b: "from this time forth for evermore.".into()
+                                b: "from this time forth for evermore.".into(),
+                                ..Default::default()
                             },
                         ]
-                    }]
+                    }],
+                    ..Default::default()
                 }),
                 Document::from(Psalm {
                     citation: None,
@@ -277,50 +314,60 @@ lazy_static! {
                             PsalmVerse {
                                 number: 1,
                                 a: "Out of the deep have I called unto thee, O LORD; *".into(),
-                                b: "Lord, hear my voice.".into()
+                                b: "Lord, hear my voice.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 2,
                                 a: "O let thine ears consider well *".into(),
-                                b: "the voice of my complaint.".into()
+                                b: "the voice of my complaint.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 3,
                                 a: "If thou LORD, wilt be extreme to mark what is done amiss, *".into(),
-                                b: "O Lord, who may abide it?".into()
+                                b: "O Lord, who may abide it?".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 4,
                                 a: "For there is mercy with thee, *".into(),
-                                b: "therefore shalt thou be feared.".into()
+                                b: "therefore shalt thou be feared.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 5,
                                 a: "I look for the LORD; my soul doth wait for him; ".into(),
-                                b: "in his word is my trust.".into()
+                                b: "in his word is my trust.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 6,
                                 a: "".into(),
-                                b: "".into()
+                                b: "".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 7,
                                 a: "My soul fleeth unto the Lord before the morning watch; *".into(),
-                                b: "I say, before the morning watch.".into()
+                                b: "I say, before the morning watch.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 8,
                                 a: "O Israel, trust in the LORD,\nfor with the LORD there is mercy, *".into(),
-                                b: "and with him is plenteous redemption.".into()
+                                b: "and with him is plenteous redemption.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 9,
                                 a: "And he shall redeem Israel *".into(),
-                                b: "from all his sins.".into()
+                                b: "from all his sins.".into(),
+                                ..Default::default()
                             }
                         ]
-                    }]
+                    }],
+                    ..Default::default()
                 }),
                 Document::from(Psalm {
                     citation: None,
@@ -336,60 +383,72 @@ lazy_static! {
                             PsalmVerse {
                                 number: 1,
                                 a: "O LORD, thou hast searched me out, and known me. *".into(),
-                                b: "Thou knowest my down-sitting and mine up-rising;\nthou understandest my thoughts long before.".into()
+                                b: "Thou knowest my down-sitting and mine up-rising;\nthou understandest my thoughts long before.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 2,
                                 a: "Thou art about my path, and about my bed, *".into(),
-                                b: "and art acquainted with all my ways.".into()
+                                b: "and art acquainted with all my ways.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 3,
                                 a: "For lo, there is not a word in my tongue, *".into(),
-                                b: "But thou, O LORD, knowest it altogether.".into()
+                                b: "But thou, O LORD, knowest it altogether.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 4,
                                 a: "Thou hast beset me behind and before, *".into(),
-                                b: "and laid thine hand upon me.".into()
+                                b: "and laid thine hand upon me.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 5,
                                 a: "Such knowledge is too wonderful and excellent for me; *".into(),
-                                b: "I cannot attain unto it.".into()
+                                b: "I cannot attain unto it.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 6,
                                 a: "Whither shall I go then from thy Spirit? *".into(),
-                                b: "or whither shall I go then from thy presence?".into()
+                                b: "or whither shall I go then from thy presence?".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 7,
                                 a: "If I climb up into heaven, thou art there; *".into(),
-                                b: "if I go down to hell, thou art there also.".into()
+                                b: "if I go down to hell, thou art there also.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 8,
                                 a: "If I take the wings of the morning, *".into(),
-                                b: "and remain in the uttermost parts of the sea;".into()
+                                b: "and remain in the uttermost parts of the sea;".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 9,
                                 a: "Even there also shall thy hand lead me, *".into(),
-                                b: "and thy right hand shall hold me.".into()
+                                b: "and thy right hand shall hold me.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 10,
                                 a: "If I say, Peradventure the darkness shall cover me, *".into(),
-                                b: "then shall my night be turned to day.".into()
+                                b: "then shall my night be turned to day.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 11,
                                 a: "Yea, the darkness is no darkness with thee,\nbut the night is as clear as day; *".into(),
-                                b: "the darkness and light to thee are both alike.".into()
+                                b: "the darkness and light to thee are both alike.".into(),
+                                ..Default::default()
                             },
                         ]
-                    }]
+                    }],
+                    ..Default::default()
                 })
             ])).tags([PSALM]),
 
@@ -418,35 +477,42 @@ lazy_static! {
                             PsalmVerse {
                                 number: 1,
                                 a: "The LORD is my shepherd; *".into(),
-                                b: "therefore can I lack nothing.".into()
+                                b: "therefore can I lack nothing.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 2,
                                 a: "He shall feed me in a green pasture, *".into(),
-                                b: "and lead me forth beside the waters of comfort.".into()
+                                b: "and lead me forth beside the waters of comfort.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 3,
                                 a: "He shall convert my soul, *".into(),
-                                b: "and bring me forth in the paths of righteousness for his Name’s sake.".into()
+                                b: "and bring me forth in the paths of righteousness for his Name’s sake.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 4,
                                 a: "Yea, though I walk through the valley of the shadow of death,\nI will fear no evil; *".into(),
-                                b: "for thou art with me;\nthy rod and thy staff comfort me.".into()
+                                b: "for thou art with me;\nthy rod and thy staff comfort me.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 5,
                                 a: "Thou shalt prepare a table before me in the presence of them that trouble me; *".into(),
-                                b: "thou hast anointed my head with oil,\nand my cup shall be full.".into()
+                                b: "thou hast anointed my head with oil,\nand my cup shall be full.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 6,
                                 a: "Surely thy loving-kindness and mercy shall follow me all the days of my life; *".into(),
-                                b: "and I will dwell in the house of the LORD for ever.".into()
+                                b: "and I will dwell in the house of the LORD for ever.".into(),
+                                ..Default::default()
                             },
                         ]
-                    }]
+                    }],
+                    ..Default::default()
                 }),
                 Document::from(Psalm {
                     citation: None,
@@ -462,35 +528,42 @@ lazy_static! {
                             PsalmVerse {
                                 number: 1,
                                 a: "The LORD is my shepherd; *".into(),
-                                b: "I shall not want.".into()
+                                b: "I shall not want.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 2,
                                 a: "He maketh me to lie down in green pastures; *".into(),
-                                b: "he leadeth me beside the still waters.".into()
+                                b: "he leadeth me beside the still waters.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 3,
                                 a: "He restoreth my soul; *".into(),
-                                b: "He leadeth me in the paths of righteousness for his Name’s sake.".into()
+                                b: "He leadeth me in the paths of righteousness for his Name’s sake.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 4,
                                 a: "Yea, though I walk through the valley of the shadow of death,\nI will fear no evil; *".into(),
-                                b: "for thou art with me;\nthy rod and thy staff, they comfort me.".into()
+                                b: "for thou art with me;\nthy rod and thy staff, they comfort me.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 5,
                                 a: "Thou preparest a table before me in the presence of mine enemies; *".into(),
-                                b: "thou anointest my head with oil;\nmy cup runneth over.".into()
+                                b: "thou anointest my head with oil;\nmy cup runneth over.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 6,
                                 a: "Surely goodness and mercy shall follow me all the days of my life, *".into(),
-                                b: "and I will dwell in the house of the LORD for ever.".into()
+                                b: "and I will dwell in the house of the LORD for ever.".into(),
+                                ..Default::default()
                             },
                         ]
-                    }]
+                    }],
+                    ..Default::default()
                 }),
                 Document::from(Psalm {
                     number: 27,
@@ -506,55 +579,66 @@ lazy_static! {
                             PsalmVerse {
                                 number: 1,
                                 a: "The Lord is my light and my salvation;\nwhom then shall I fear? *".into(),
-                                b: "the LORD is the strength of my life;\nof whom then shall I be afraid?".into()
+                                b: "the LORD is the strength of my life;\nof whom then shall I be afraid?".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 2,
                                 a: "One thing have I desired of the LORD, which I will require, *".into(),
-                                b: "even that I may dwell in the house of the LORD all the days of my life,\nto behold the fair beauty of the LORD, and to visit his temple.".into()
+                                b: "even that I may dwell in the house of the LORD all the days of my life,\nto behold the fair beauty of the LORD, and to visit his temple.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 3,
                                 a: "For in the time of trouble he shall hide me in his tabernacle; *".into(),
-                                b: "yea, in the secret place of his dwelling shall he hide me,\nand set me up upon a rock of stone.".into()
+                                b: "yea, in the secret place of his dwelling shall he hide me,\nand set me up upon a rock of stone.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 4,
                                 a: "And now shall he lift up mine head *".into(),
-                                b: "above mine enemies round about me.".into()
+                                b: "above mine enemies round about me.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 5,
                                 a: "Therefore will I offer in his dwelling an oblation with great gladness; *".into(),
-                                b: "I will sing and speak praises unto the LORD.".into()
+                                b: "I will sing and speak praises unto the LORD.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 6,
                                 a: "Hearken unto my voice, O LORD, when I cry unto thee; *".into(),
-                                b: "have mercy upon me, and hear me.".into()
+                                b: "have mercy upon me, and hear me.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 7,
                                 a: "My heart hath talked of thee, Seek ye my face. *".into(),
-                                b: "Thy face, LORD, will I seek.".into()
+                                b: "Thy face, LORD, will I seek.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 8,
                                 a: "O hide not thou thy face from me, *".into(),
-                                b: "nor cast thy servant away in displeasure.".into()
+                                b: "nor cast thy servant away in displeasure.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 9,
                                 a: "I should utterly have fainted, *".into(),
-                                b: "but that I believe verily to see the goodness of the LORD in the land of the living.".into()
+                                b: "but that I believe verily to see the goodness of the LORD in the land of the living.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 10,
                                 a: "O tarry thou the LORD’s leisure; *".into(),
-                                b: "be strong, and he shall comfort thine heart;\nand put thou thy trust in the LORD.".into()
+                                b: "be strong, and he shall comfort thine heart;\nand put thou thy trust in the LORD.".into(),
+                                ..Default::default()
                             },
                         ]
-                    }]
+                    }],
+                    ..Default::default()
                 }),
                 Document::from(Psalm {
                     number: 106,
@@ -570,30 +654,36 @@ lazy_static! {
                             PsalmVerse {
                                 number: 1,
                                 a: "O give thanks unto the LORD, for he is gracious, *".into(),
-                                b: "and his mercy endureth for ever.".into()
+                                b: "and his mercy endureth for ever.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 2,
                                 a: "Who can express the noble acts of the LORD, *".into(),
-                                b: "or show forth all his praise?".into()
+                                b: "or show forth all his praise?".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 3,
                                 a: "Blessed are they that alway keep judgment, *".into(),
-                                b: "and do righteousness.".into()
+                                b: "and do righteousness.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 4,
                                 a: "Remember me, O LORD, according to the favor that thou bearest unto thy people; *".into(),
-                                b: "O visit me with thy salvation;".into()
+                                b: "O visit me with thy salvation;".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 5,
                                 a: "That I may see the felicity of thy chosen, *".into(),
-                                b: "and rejoice in the gladness of thy people,\nand give thanks with thine inheritance.".into()
+                                b: "and rejoice in the gladness of thy people,\nand give thanks with thine inheritance.".into(),
+                                ..Default::default()
                             },
                         ]
-                    }]
+                    }],
+                    ..Default::default()
                 }),
                 Document::from(Psalm {
                     number: 116,
@@ -609,55 +699,66 @@ lazy_static! {
                             PsalmVerse {
                                 number: 1,
                                 a: "My delight is in the LORD, *".into(),
-                                b: "because he hath heard the voice of my prayer;".into()
+                                b: "because he hath heard the voice of my prayer;".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 2,
                                 a: "Because he hath inclined his ear unto me, *".into(),
-                                b: "therefore will I call upon him as long as I live.".into()
+                                b: "therefore will I call upon him as long as I live.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 3,
                                 a: "The snares of death compassed me round about, *".into(),
-                                b: "and the pains of hell gat hold upon me.".into()
+                                b: "and the pains of hell gat hold upon me.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 4,
                                 a: "I found trouble and heaviness;\nthen called I upon the Name of the LORD; *".into(),
-                                b: "O LORD, I beseech thee, deliver my soul.".into()
+                                b: "O LORD, I beseech thee, deliver my soul.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 5,
                                 a: "Gracious is the LORD, and righteous; *".into(),
-                                b: "yea, our God is merciful.".into()
+                                b: "yea, our God is merciful.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 6,
                                 a: "The LORD preserveth the simple; *".into(),
-                                b: "I was in misery, and he helped me.".into()
+                                b: "I was in misery, and he helped me.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 7,
                                 a: "Turn again then unto thy rest, O my soul, *".into(),
-                                b: "for the LORD hath rewarded thee.".into()
+                                b: "for the LORD hath rewarded thee.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 8,
                                 a: "And why? thou hast delivered my soul from death, *".into(),
-                                b: "mine eyes from tears, and my feet from falling.".into()
+                                b: "mine eyes from tears, and my feet from falling.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 9,
                                 a: "I will walk before the LORD *".into(),
-                                b: "in the land of the living.".into()
+                                b: "in the land of the living.".into(),
+                                ..Default::default()
                             },
                             PsalmVerse {
                                 number: 10,
                                 a: "I will pay my vows now in the presence of all his people; *".into(),
-                                b: "right dear in the sight of the LORD is the death of his saints.".into()
+                                b: "right dear in the sight of the LORD is the death of his saints.".into(),
+                                ..Default::default()
                             },
                         ]
-                    }]
+                    }],
+                    ..Default::default()
                 }),
             ])).tags([PSALM_2]),
 
@@ -730,7 +831,7 @@ lazy_static! {
                 Document::from(Rubric::from("The Apostles’ Creed may be said, all standing.")),
                 Document::from(Content::DocumentLink{
                     label: "Apostles’ Creed".into(),
-                    path: SlugPath::from([Slug::ApostlesCreed, Slug::Version(Version::RiteI)]),
+                    path: SlugPath::from([Slug::Creeds, Slug::ApostlesCreed, Slug::Version(Version::RiteI)]),
                     rotate: false,
                     link_only: false
                 }).display(Show::TemplateOnly)
@@ -741,7 +842,7 @@ lazy_static! {
             Document::from(Rubric::from("If there is not to be a Communion, the Lord’s Prayer is said here, and the service continues with the following prayer of intercession, or with one or more suitable prayers (see pages 487-489).\n\nWhen there is a Communion, the following serves for the Prayers of the People.")).tags([RUBRIC_BEFORE_PRAYERS]),
             Document::from(Content::DocumentLink {
                 label: "Additional Prayers".into(),
-                path: SlugPath::from([Slug::Burial, Slug::AdditionalPrayers, Slug::Version(Version::RiteI)]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Burial, Slug::Version(Version::RiteI)]),
                 rotate: false,
                 link_only: false
             }).version(Version::RiteI).tags([RUBRIC_BEFORE_PRAYERS]),
@@ -832,7 +933,7 @@ lazy_static! {
             Document::from(Rubric::from("In place of this prayer, or in addition to it, the Celebrant may use any of the Additional Prayers.")).tags([COMMITTAL_PRAYERS_2]),
             Document::from(Content::DocumentLink {
                 label: "Additional Prayers".into(),
-                path: SlugPath::from([Slug::Burial, Slug::AdditionalPrayers, Slug::Version(Version::RiteI)]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Burial, Slug::Version(Version::RiteI)]),
                 rotate: false,
                 link_only: false
             }).version(Version::RiteI).tags([COMMITTAL_PRAYERS_2]),
@@ -856,7 +957,7 @@ lazy_static! {
                 Document::from(Heading::from((HeadingLevel::Heading2, "Additional Prayers"))),
                 Document::from(Content::DocumentLink {
                     label: "Additional Prayers".into(),
-                    path: SlugPath::from([Slug::Burial, Slug::AdditionalPrayers, Slug::Version(Version::RiteI)]),
+                    path: SlugPath::from([Slug::PastoralOffices, Slug::Burial, Slug::Version(Version::RiteI)]),
                     rotate: false,
                     link_only: false
                 }).version(Version::RiteI)
@@ -126,7 +126,8 @@ lazy_static! {
                 ]
             }
         ],
-        gloria_patri: None
+        gloria_patri: None,
+        ..Default::default()
     })
     .version(Version::RiteI)
     .page(47);
@@ -43,7 +43,8 @@ and dwellest between the Cherubim; *",
                 ))
             ]
         }],
-        gloria_patri: None
+        gloria_patri: None,
+        ..Default::default()
     })
     .version(Version::RiteI)
     .page(49);
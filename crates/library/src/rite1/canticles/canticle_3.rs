@@ -50,7 +50,8 @@ Abraham and his seed for ever."
                 ))
             ]
         }],
-        gloria_patri: Some(GLORIA_PATRI.clone())
+        gloria_patri: Some(GLORIA_PATRI.clone()),
+        ..Default::default()
     })
     .version(Version::RiteI)
     .page(50);
@@ -72,7 +72,8 @@ and in the shadow of death, *",
                 ]
             }
         ],
-        gloria_patri: Some(GLORIA_PATRI.clone())
+        gloria_patri: Some(GLORIA_PATRI.clone()),
+        ..Default::default()
     })
     .version(Version::RiteI)
     .page(50);
@@ -28,7 +28,8 @@ lazy_static! {
                 ))
             ]
         }],
-        gloria_patri: Some(GLORIA_PATRI.clone())
+        gloria_patri: Some(GLORIA_PATRI.clone()),
+        ..Default::default()
     })
     .version(Version::RiteI)
     .page(51);
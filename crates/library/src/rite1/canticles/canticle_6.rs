@@ -38,7 +38,8 @@ thou only, O Christ,
                 "undefined"
             ))]
         }],
-        gloria_patri: None
+        gloria_patri: None,
+        ..Default::default()
     })
     .version(Version::RiteI)
     .page(52);
@@ -1,5 +1,7 @@
 use lazy_static::lazy_static;
 use liturgy::*;
+mod proper_prefaces;
+pub use proper_prefaces::*;
 
 lazy_static! {
     pub static ref PRAYER_I: Document = Document::new()
@@ -20,12 +22,7 @@ lazy_static! {
             Document::from(Rubric::from("Then, facing the Holy Table, the Celebrant proceeds")),
             Document::from(Text::from("It is very meet, right, and our bounden duty, that we should at all times, and in all places, give thanks unto thee, O Lord, holy Father, almighty, everlasting God.")),
             Document::from(Rubric::from("Here a Proper Preface is sung or said on all Sundays, and on other occasions as appointed.")),
-            Document::from(Content::DocumentLink {
-                label: "Proper Prefaces".into(),
-                path: SlugPath::from([Slug::Eucharist, Slug::ProperPrefaces, Slug::Version(Version::RiteI)]),
-                rotate: true,
-                link_only: false
-            }),
+            Document::new().version(Version::RiteI).content(Content::ProperPreface),
             Document::from(Text::from("Therefore with Angels and Archangels, and with all the company of heaven, we laud and magnify thy glorious Name; evermore praising thee, and saying,")),
             Document::from(Rubric::from("Celebrant and People")),
             Document::from(Text::from("Holy, holy, holy, Lord God of Hosts:\nHeaven and earth are full of thy glory.\nGlory be to thee, O Lord Most High.").display_format(DisplayFormat::Unison)),
@@ -55,12 +52,7 @@ lazy_static! {
             Document::from(Rubric::from("Then, facing the Holy Table, the Celebrant proceeds")),
             Document::from(Text::from("It is very meet, right, and our bounden duty, that we should at all times, and in all places, give thanks unto thee, O Lord, holy Father, almighty, everlasting God.")),
             Document::from(Rubric::from("Here a Proper Preface is sung or said on all Sundays, and on other occasions as appointed.")),
-            Document::from(Content::DocumentLink {
-                label: "Proper Prefaces".into(),
-                path: SlugPath::from([Slug::Eucharist, Slug::ProperPrefaces, Slug::Version(Version::RiteI)]),
-                rotate: true,
-                link_only: false
-            }),
+            Document::new().version(Version::RiteI).content(Content::ProperPreface),
             Document::from(Text::from("Therefore with Angels and Archangels, and with all the company of heaven, we laud and magnify thy glorious Name; evermore praising thee, and saying,")),
             Document::from(Rubric::from("Celebrant and People")),
             Document::from(Text::from("Holy, holy, holy, Lord God of Hosts:\nHeaven and earth are full of thy glory.\nGlory be to thee, O Lord Most High.").display_format(DisplayFormat::Unison)),
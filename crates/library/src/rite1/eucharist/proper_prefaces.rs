@@ -0,0 +1,29 @@
+use lazy_static::lazy_static;
+use liturgy::*;
+
+use crate::PrefaceId;
+
+lazy_static! {
+    pub static ref PROPER_PREFACES_I: Vec<(PrefaceId, Document)> = vec![
+        (PrefaceId::LordsDay, Document::from(Text::from("For thou art the source of light and life; thou madest us in thine image, and didst call us to new life in Jesus Christ our Lord.")).label("Preface of the Lord’s Day").subtitle("1. Of God the Father").version(Version::RiteI).page(344)),
+        (PrefaceId::LordsDay, Document::from(Text::from("Through Jesus Christ our Lord; who on the first day of the week overcame death and the grave, and by his glorious resurrection opened to us the way of everlasting life.")).label("Preface of the Lord’s Day").subtitle("2. Of God the Son").version(Version::RiteI).page(344)),
+        (PrefaceId::LordsDay, Document::from(Text::from("Who by water and the Holy Spirit hast made us a new people in Jesus Christ our Lord, to show forth thy glory in all the world.")).label("Preface of the Lord’s Day").subtitle("3. Of God the Holy Spirit").version(Version::RiteI).page(345)),
+        (PrefaceId::Advent, Document::from(Text::from("Because thou didst send thy beloved Son to redeem us from sin and death, and to make us heirs in him of everlasting life; that when he shall come again in power and great triumph to judge the world, we may without shame or fear rejoice to behold his appearing.")).label("Preface of Advent").version(Version::RiteI).page(345)),
+        (PrefaceId::Incarnation, Document::from(Text::from("Because thou didst give Jesus Christ, thine only Son, to be born for us; who, by the mighty power of the Holy Ghost, was made very Man of the substance of the Virgin Mary his mother; that we might be delivered from the bondage of sin, and receive power to become thy children.")).label("Preface of the Incarnation").version(Version::RiteI).page(345)),
+        (PrefaceId::Epiphany, Document::from(Text::from("Because in the mystery of the Word made flesh, thou hast caused a new light to shine in our hearts, to give the knowledge of thy glory in the face of thy Son Jesus Christ our Lord.")).label("Preface of the Epiphany").version(Version::RiteI).page(345)),
+        (PrefaceId::Lent, Document::from(Text::from("Through Jesus Christ our Lord; who was in every way tempted as we are, yet did not sin; by whose grace we are able to triumph over every evil, and to live no longer unto ourselves, but unto him who died for us and rose again.")).label("Preface of Lent").version(Version::RiteI).page(346)),
+        (PrefaceId::Lent, Document::from(Text::from("Who dost bid thy faithful people cleanse their hearts, and prepare with joy for the Paschal feast; that, fervent in prayer and in works of mercy, and renewed by thy Word and Sacraments, they may come to the fullness of grace which thou hast prepared for those who love thee.")).label("Preface of Lent").version(Version::RiteI).page(346)),
+        (PrefaceId::HolyWeek, Document::from(Text::from("Through Jesus Christ our Lord; who for our sins was lifted high upon the cross, that he might draw the whole world unto himself; who by his suffering and death became the author of eternal salvation for all who put their trust in him.")).label("Preface of Holy Week").version(Version::RiteI).page(346)),
+        (PrefaceId::Easter, Document::from(Text::from("But chiefly are we bound to praise thee for the glorious resurrection of thy Son Jesus Christ our Lord; for he is the very Paschal Lamb, who was sacrificed for us, and hath taken away the sin of the world; who by his death hath destroyed death, and by his rising to life again hath won for us everlasting life.")).label("Preface of Easter").version(Version::RiteI).page(346)),
+        (PrefaceId::Ascension, Document::from(Text::from("Through thy dearly beloved Son Jesus Christ our Lord; who after his glorious resurrection manifestly appeared to his disciples, and in their sight ascended up into heaven, to prepare a place for us; that where he is, there we might also be, and reign with him in glory.")).label("Preface of the Ascension").version(Version::RiteI).page(347)),
+        (PrefaceId::Pentecost, Document::from(Text::from("Through Jesus Christ our Lord; according to whose true promise the Holy Ghost came down on this day from heaven, lighting upon the disciples, to teach them and to lead them into all truth; uniting peoples of many tongues in the confession of one faith, and giving to thy Church the power to serve thee as a royal priesthood, and to preach the Gospel unto all nations.")).label("Preface of Pentecost").version(Version::RiteI).page(347)),
+        (PrefaceId::TrinitySunday, Document::from(Text::from("Who with thy co-eternal Son and Holy Spirit art one God, one Lord, in Trinity of Persons and in Unity of Substance; for that which we believe of thy glory, O Father, we believe the same of the Son, and of the Holy Ghost, without any difference or inequality.")).label("Preface of Trinity Sunday").version(Version::RiteI).page(347)),
+        (PrefaceId::AllSaints, Document::from(Text::from("Who, in the multitude of thy saints, hast compassed us about with so great a cloud of witnesses that we, rejoicing in their fellowship, may run with patience the race that is set before us, and, together with them, may receive the crown of glory that fadeth not away.")).label("Preface of All Saints").version(Version::RiteI).page(347)),
+        (PrefaceId::ASaint, Document::from(Text::from("For the wonderful grace and virtue declared in all thy saints, who have been the choice vessels of thy grace, and the lights of the world in their several generations.")).label("Preface of a Saint").version(Version::RiteI).page(348)),
+        (PrefaceId::Apostles, Document::from(Text::from("Through the great shepherd of thy flock, Jesus Christ our Lord; who after his resurrection sent forth his apostles to preach the Gospel and to teach all nations; and promised to be with them always, even unto the end of the ages.")).label("Preface of Apostles and Ordinations").version(Version::RiteI).page(348)),
+        (PrefaceId::DedicationOfAChurch, Document::from(Text::from("Through Jesus Christ our great High Priest; in whom we are built up as living stones of a holy temple, that we might offer before thee a sacrifice of praise and prayer which is holy and pleasing in thy sight.")).label("Preface of the Dedication of a Church").version(Version::RiteI).page(348)),
+        (PrefaceId::Baptism, Document::from(Text::from("Because in Jesus Christ our Lord thou hast received us as thy sons and daughters, made us citizens of thy kingdom, and given us the Holy Spirit to guide us into all truth.")).label("Preface of Baptism").version(Version::RiteI).page(348)),
+        (PrefaceId::Marriage, Document::from(Text::from("Because in the love of wife and husband, thou hast given us an image of the heavenly Jerusalem, adorned as a bride for her bridegroom, thy Son Jesus Christ our Lord; who loveth her and gave himself for her, that he might make the whole creation new.")).label("Preface of Marriage").version(Version::RiteI).page(349)),
+        (PrefaceId::CommemorationOfTheDead, Document::from(Text::from("Through Jesus Christ our Lord; who rose victorious from the dead, and doth comfort us with the blessed hope of everlasting life; for to thy faithful people, O Lord, life is changed, not ended; and when our mortal body doth lie in death, there is prepared for us a dwelling place eternal in the heavens.")).label("Preface of the Commemoration of the Dead").version(Version::RiteI).page(349)),
+    ];
+}
@@ -167,7 +167,7 @@ lazy_static! {
             Document::from(Rubric::from("If there is not to be a Communion, the Lord’s Prayer is said here, and the service continues with the Prayers of the People, or with one or more suitable prayers (see pages 503-505).\n\nWhen there is a Communion, the following form of the Prayers of the People is used, or else the form on page 465 or 480.")).tags([RUBRIC_BEFORE_PRAYERS]),
             Document::from(Content::DocumentLink {
                 label: "Additional Prayers".into(),
-                path: SlugPath::from([Slug::Burial, Slug::AdditionalPrayers, Slug::Version(Version::RiteII)]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Burial, Slug::Version(Version::RiteII)]),
                 rotate: false,
                 link_only: false
             }).version(Version::RiteII).tags([RUBRIC_BEFORE_PRAYERS]),
@@ -256,7 +256,7 @@ lazy_static! {
             Document::from(Rubric::from("Other prayers may be added.")).tags([COMMITTAL_PRAYERS_2]),
             Document::from(Content::DocumentLink {
                 label: "Additional Prayers".into(),
-                path: SlugPath::from([Slug::Burial, Slug::AdditionalPrayers, Slug::Version(Version::RiteII)]),
+                path: SlugPath::from([Slug::PastoralOffices, Slug::Burial, Slug::Version(Version::RiteII)]),
                 rotate: false,
                 link_only: false
             }).version(Version::RiteII).tags([COMMITTAL_PRAYERS_2]),
@@ -287,7 +287,7 @@ lazy_static! {
                 Document::from(Heading::from((HeadingLevel::Heading2, "Additional Prayers"))),
                 Document::from(Content::DocumentLink {
                     label: "Additional Prayers".into(),
-                    path: SlugPath::from([Slug::Burial, Slug::AdditionalPrayers, Slug::Version(Version::RiteII)]),
+                    path: SlugPath::from([Slug::PastoralOffices, Slug::Burial, Slug::Version(Version::RiteII)]),
                     rotate: false,
                     link_only: false
                 }).version(Version::RiteII)
@@ -54,6 +54,7 @@ and my thoughts than your thoughts."
             ]
         }],
         gloria_patri: Some(GLORIA_PATRI.clone()),
+        ..Default::default()
     })
     .version(Version::RiteII)
     .page(86);
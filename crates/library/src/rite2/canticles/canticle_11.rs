@@ -57,6 +57,7 @@ lazy_static! {
             ]
         }],
         gloria_patri: Some(GLORIA_PATRI.clone()),
+        ..Default::default()
     })
     .version(Version::RiteII)
     .page(87);
@@ -125,7 +125,8 @@ and all that grows upon the earth, *",
                 ]
             }
         ],
-        gloria_patri: None
+        gloria_patri: None,
+        ..Default::default()
     })
     .version(Version::RiteII).page(88);
 }
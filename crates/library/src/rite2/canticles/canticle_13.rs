@@ -39,6 +39,7 @@ lazy_static! {
             ]
         }],
         gloria_patri: None,
+        ..Default::default()
     })
     .version(Version::RiteII)
     .page(90);
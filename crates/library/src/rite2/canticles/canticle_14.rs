@@ -75,7 +75,8 @@ in accordance with your great mercy, *",
                 ))
             ]
         }],
-        gloria_patri: None
+        gloria_patri: None,
+        ..Default::default()
     })
     .version(Version::RiteII)
     .page(90);
@@ -51,6 +51,7 @@ and holy is his Name."
             ]
         }],
         gloria_patri: Some(GLORIA_PATRI.clone()),
+        ..Default::default()
     })
     .version(Version::RiteII)
     .page(91);
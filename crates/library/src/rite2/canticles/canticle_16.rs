@@ -64,7 +64,8 @@ all the days of our life."
                 ]
             }
         ],
-        gloria_patri: Some(GLORIA_PATRI.clone())
+        gloria_patri: Some(GLORIA_PATRI.clone()),
+        ..Default::default()
     })
     .version(Version::RiteII)
     .page(92);
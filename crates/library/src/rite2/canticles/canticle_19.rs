@@ -42,6 +42,7 @@ and sing the praises of your Name? *",
             }
         ],
         gloria_patri: Some(GLORIA_PATRI.clone()),
+        ..Default::default()
     })
     .version(Version::RiteII)
     .page(94);
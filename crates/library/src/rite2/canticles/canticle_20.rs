@@ -46,6 +46,7 @@ in the glory of God the Father. Amen."
             ]
         }],
         gloria_patri: None,
+        ..Default::default()
     })
     .version(Version::RiteII)
     .page(94);
@@ -19,6 +19,7 @@ lazy_static! {
             ]
         }],
         gloria_patri: None,
+        ..Default::default()
     })
     .version(Version::RiteII)
     .page(95);
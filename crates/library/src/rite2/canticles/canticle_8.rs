@@ -63,7 +63,8 @@ awesome in renown, and worker of wonders?"
                 CanticleVerse::from(("The Lord shall reign *", "for ever and for ever."))
             ]
         }],
-        gloria_patri: Some(GLORIA_PATRI.clone())
+        gloria_patri: Some(GLORIA_PATRI.clone()),
+        ..Default::default()
     })
     .version(Version::RiteII)
     .page(85);
@@ -44,7 +44,8 @@ lazy_static! {
                 ))
             ]
         }],
-        gloria_patri: Some(GLORIA_PATRI.clone())
+        gloria_patri: Some(GLORIA_PATRI.clone()),
+        ..Default::default()
     })
     .version(Version::RiteII)
     .page(86);
@@ -1,11 +1,14 @@
 use calendar::Season;
 use lazy_static::lazy_static;
-use lectionary::ReadingType;
+use lectionary::{ReadingType, WeekdayCycle};
 use liturgy::*;
 pub mod parallel;
 use parallel::*;
+mod proper_prefaces;
+pub use proper_prefaces::*;
 
 use crate::bcp1979;
+use crate::rite2::{ABSOLUTION, CONFESSION_OF_SIN};
 
 lazy_static! {
     pub static ref GLORIA_IN_EXCELSIS: Text = Text::from("Glory to God in the highest,\n\tand peace to his people on earth. \n\nLord God, heavenly King,\nalmighty God and Father,\n\twe worship you, we give you thanks,\n\twe praise you for your glory. \n\nLord Jesus Christ, only Son of the Father,\nLord God, Lamb of God,\nyou take away the sin of the world:\n\thave mercy on us;\nyou are seated at the right hand of the Father:\n\treceive our prayer. \n\nFor you alone are the Holy One,\nyou alone are the Lord,\nyou alone are the Most High,\n\tJesus Christ,\n\twith the Holy Spirit,\n\tin the glory of God the Father. Amen.");
@@ -235,9 +238,9 @@ lazy_static! {
             Document::from(Text::from("Let us confess our sins against God and our neighbor.")).tags([CONFESSION]),
             Document::from(Rubric::from("Silence may be kept.")).tags([CONFESSION]),
             Document::from(Rubric::from("Minister and People")).tags([CONFESSION]),
-            Document::from(Text::from("Most merciful God,\nwe confess that we have sinned against you\nin thought, word, and deed,\nby what we have done,\nand by what we have left undone.\nWe have not loved you with our whole heart;\nwe have not loved our neighbors as ourselves.\nWe are truly sorry and we humbly repent.\nFor the sake of your Son Jesus Christ,\nhave mercy on us and forgive us;\nthat we may delight in your will,\nand walk in your ways,\nto the glory of your Name. Amen.").display_format(DisplayFormat::Unison)).tags([CONFESSION]),
+            Document::from(CONFESSION_OF_SIN.clone()).tags([CONFESSION]),
             Document::from(Rubric::from("The Bishop when present, or the Priest, stands and says")).tags([CONFESSION]),
-            Document::from(Text::from("Almighty God have mercy on you, forgive you all your sins through our Lord Jesus Christ, strengthen you in all goodness, and by the power of the Holy Spirit keep you in eternal life.").response("Amen.")).tags([CONFESSION]),
+            Document::from(ABSOLUTION.clone()).tags([CONFESSION]),
             Document::from(Heading::from((HeadingLevel::Heading3, "The Peace"))).tags([THE_PEACE]),
             Document::from(Rubric::from("All stand. The Celebrant says to the people")).tags([THE_PEACE]),
             Document::from(Preces::from([
@@ -432,6 +435,14 @@ lazy_static! {
                     LiturgyPreferenceOption::from(("RCL (Track 2)", PreferenceValue::from(Lectionaries::RCLTrack2)))
                 ]
             )).category("Lectionary"),
+            LiturgyPreference::from((
+                PreferenceKey::from(GlobalPref::WeekdayCycle),
+                "Weekday Readings",
+                [
+                    LiturgyPreferenceOption::from(("Two-Year Weekday Cycle", PreferenceValue::from(WeekdayCycle::TwoYear))),
+                    LiturgyPreferenceOption::from(("Propers of the Preceding Sunday", PreferenceValue::from(WeekdayCycle::SundayPropers)))
+                ]
+            )).category("Lectionary"),
 
             // Readings
             LiturgyPreference::from((
@@ -488,12 +499,7 @@ lazy_static! {
             Document::from(Rubric::from("Then, facing the Holy Table, the Celebrant proceeds")),
             Document::from(Text::from("It is right, and a good and joyful thing, always and everywhere to give thanks to you, Father Almighty, Creator of heaven and earth.")),
             Document::from(Rubric::from("Here a Proper Preface is sung or said on all Sundays, and on other occasions as appointed.")),
-            Document::from(Content::DocumentLink {
-                label: "Proper Prefaces".into(),
-                path: SlugPath::from([Slug::Eucharist, Slug::ProperPrefaces, Slug::Version(Version::RiteII)]),
-                rotate: true,
-                link_only: false
-            }),
+            Document::new().version(Version::RiteII).content(Content::ProperPreface),
             Document::from(Text::from("Therefore we praise you, joining our voices with Angels and Archangels and with all the company of heaven, who for ever sing this hymn to proclaim the glory of your Name:")),
             Document::from(Rubric::from("Celebrant and People")),
             Document::from(Text::from("Holy, holy, holy Lord, God of power and might,\nheaven and earth are full of your glory.\n\tHosanna in the highest.\nBlessed is he who comes in the name of the Lord.\n\tHosanna in the highest. ").display_format(DisplayFormat::Unison)),
@@ -524,12 +530,7 @@ lazy_static! {
             Document::from(Rubric::from("Then, facing the Holy Table, the Celebrant proceeds")),
             Document::from(Text::from("It is right, and a good and joyful thing, always and everywhere to give thanks to you, Father Almighty, Creator of heaven and earth.")),
             Document::from(Rubric::from("Here a Proper Preface is sung or said on all Sundays, and on other occasions as appointed.")),
-            Document::from(Content::DocumentLink {
-                label: "Proper Prefaces".into(),
-                path: SlugPath::from([Slug::Eucharist, Slug::ProperPrefaces, Slug::Version(Version::RiteII)]),
-                rotate: true,
-                link_only: false
-            }),
+            Document::new().version(Version::RiteII).content(Content::ProperPreface),
             Document::from(Text::from("Therefore we praise you, joining our voices with Angels and Archangels and with all the company of heaven, who for ever sing this hymn to proclaim the glory of your Name:")),
             Document::from(Rubric::from("Celebrant and People")),
             Document::from(Text::from("Holy, holy, holy Lord, God of power and might,\nheaven and earth are full of your glory.\n\tHosanna in the highest.\nBlessed is he who comes in the name of the Lord.\n\tHosanna in the highest. ").display_format(DisplayFormat::Unison)),
@@ -0,0 +1,31 @@
+use lazy_static::lazy_static;
+use liturgy::*;
+
+use crate::PrefaceId;
+
+lazy_static! {
+    pub static ref PROPER_PREFACES_II: Vec<(PrefaceId, Document)> = vec![
+        (PrefaceId::LordsDay, Document::from(Text::from("For you are the source of light and life; you made us in your image, and called us to new life in Jesus Christ our Lord.")).label("Preface of the Lord’s Day").subtitle("1. Of God the Father").version(Version::RiteII).page(377)),
+        (PrefaceId::LordsDay, Document::from(Text::from("Through Jesus Christ our Lord; who on the first day of the week overcame death and the grave, and by his glorious resurrection opened to us the way of everlasting life.")).label("Preface of the Lord’s Day").subtitle("2. Of God the Son").version(Version::RiteII).page(377)),
+        (PrefaceId::LordsDay, Document::from(Text::from("For by water and the Holy Spirit you have made us a new people in Jesus Christ our Lord, to show forth your glory in all the world.")).label("Preface of the Lord’s Day").subtitle("3. Of God the Holy Spirit").version(Version::RiteII).page(378)),
+        (PrefaceId::Advent, Document::from(Text::from("Because you sent your beloved Son to redeem us from sin and death, and to make us heirs in him of everlasting life; that when he shall come again in power and great triumph to judge the world, we may without shame or fear rejoice to behold his appearing.")).label("Preface of Advent").version(Version::RiteII).page(378)),
+        (PrefaceId::Incarnation, Document::from(Text::from("Because you gave Jesus Christ, your only Son, to be born for us; who, by the mighty power of the Holy Spirit, was made perfect Man of the flesh of the Virgin Mary his mother; so that we might be delivered from the bondage of sin, and receive power to become your children.")).label("Preface of the Incarnation").version(Version::RiteII).page(378)),
+        (PrefaceId::Epiphany, Document::from(Text::from("Because in the mystery of the Word made flesh, you have caused a new light to shine in our hearts, to give the knowledge of your glory in the face of your Son Jesus Christ our Lord.")).label("Preface of the Epiphany").version(Version::RiteII).page(378)),
+        (PrefaceId::Lent, Document::from(Text::from("Through Jesus Christ our Lord, who was tempted in every way as we are, yet did not sin. By his grace we are able to triumph over every evil, and to live no longer for ourselves alone, but for him who died for us and rose again.")).label("Preface of Lent").version(Version::RiteII).page(379)),
+        (PrefaceId::Lent, Document::from(Text::from("You bid your faithful people cleanse their hearts, and prepare with joy for the Paschal feast; that, fervent in prayer and in works of mercy, and renewed by your Word and Sacraments, they may come to the fullness of grace which you have prepared for those who love you.")).label("Preface of Lent").version(Version::RiteII).page(379)),
+        (PrefaceId::HolyWeek, Document::from(Text::from("Through Jesus Christ our Lord. For our sins he was lifted high upon the cross, that he might draw the whole world to himself; and, by his suffering and death, he became the source of eternal salvation for all who put their trust in him.")).label("Preface of Holy Week").version(Version::RiteII).page(379)),
+        (PrefaceId::Easter, Document::from(Text::from("But chiefly are we bound to praise you for the glorious resurrection of your Son Jesus Christ our Lord; for he is the true Paschal Lamb, who was sacrificed for us, and has taken away the sin of the world. By his death he has destroyed death, and by his rising to life again he has won for us everlasting life.")).label("Preface of Easter").version(Version::RiteII).page(379)),
+        (PrefaceId::Ascension, Document::from(Text::from("Through your dearly beloved Son Jesus Christ our Lord. After his glorious resurrection he openly appeared to his disciples, and in their sight was taken up into heaven, to prepare a place for us; that where he is, there we might also be, and reign with him in glory.")).label("Preface of the Ascension").version(Version::RiteII).page(379)),
+        (PrefaceId::Pentecost, Document::from(Text::from("Through Jesus Christ our Lord. In fulfillment of his true promise, the Holy Spirit came down on this day from heaven, lighting upon the disciples, to teach them and to lead them into all truth; uniting peoples of many tongues in the confession of one faith, and giving to your Church the power to serve you as a royal priesthood, and to preach the Gospel to all nations.")).label("Preface of Pentecost").version(Version::RiteII).page(380)),
+        (PrefaceId::TrinitySunday, Document::from(Text::from("For with your co-eternal Son and Holy Spirit, you are one God, one Lord, in Trinity of Persons and in Unity of Being; and we celebrate the one and equal glory of you, O Father, and of the Son, and of the Holy Spirit.")).label("Preface of Trinity Sunday").version(Version::RiteII).page(380)),
+        (PrefaceId::AllSaints, Document::from(Text::from("For in the multitude of your saints, you have surrounded us with a great cloud of witnesses, that we might rejoice in their fellowship, and run with endurance the race that is set before us; and, together with them, receive the crown of glory that never fades away.")).label("Preface of All Saints").version(Version::RiteII).page(380)),
+        (PrefaceId::ASaint, Document::from(Text::from("For the wonderful grace and virtue declared in all your saints, who have been the chosen vessels of your grace, and the lights of the world in their generations.")).label("Preface of a Saint").version(Version::RiteII).page(380)),
+        (PrefaceId::ASaint, Document::from(Text::from("Because in the obedience of your saints you have given us an example of righteousness, and in their eternal joy a glorious pledge of the hope of our calling.")).label("Preface of a Saint").version(Version::RiteII).page(381)),
+        (PrefaceId::ASaint, Document::from(Text::from("Because you are greatly glorified in the assembly of your saints. All your creatures praise you, and your faithful servants bless you, confessing before the rulers of this world the great Name of your only Son.")).label("Preface of a Saint").version(Version::RiteII).page(381)),
+        (PrefaceId::Apostles, Document::from(Text::from("Through the great shepherd of your flock, Jesus Christ our Lord; who after his resurrection sent forth his apostles to preach the Gospel and to teach all nations; and promised to be with them always, even to the end of the ages.")).label("Preface of Apostles and Ordinations").version(Version::RiteII).page(381)),
+        (PrefaceId::DedicationOfAChurch, Document::from(Text::from("Through Jesus Christ our great High Priest, in whom we are built up as living stones of a holy temple, that we might offer before you a sacrifice of praise and prayer which is holy and pleasing in your sight.")).label("Preface of the Dedication of a Church").version(Version::RiteII).page(381)),
+        (PrefaceId::Baptism, Document::from(Text::from("Because in Jesus Christ our Lord you have received us as your sons and daughters, made us citizens of your kingdom, and given us the Holy Spirit to guide us into all truth.")).label("Preface of Baptism").version(Version::RiteII).page(381)),
+        (PrefaceId::Marriage, Document::from(Text::from("Because in the love of wife and husband, you have given us an image of the heavenly Jerusalem, adorned as a bride for her bridegroom, your Son Jesus Christ our Lord; who loves her and gave himself for her, that he might make the whole creation new.")).label("Preface of Marriage").version(Version::RiteII).page(381)),
+        (PrefaceId::CommemorationOfTheDead, Document::from(Text::from("Through Jesus Christ our Lord; who rose victorious from the dead, and comforts us with the blessed hope of everlasting life. For to your faithful people, O Lord, life is changed, not ended; and when our mortal body lies in death, there is prepared for us a dwelling place eternal in the heavens.")).label("Preface of the Commemoration of the Dead").version(Version::RiteII).page(382)),
+    ];
+}
@@ -38,6 +38,13 @@ lazy_static! {
       .response("Amen.")
       .display_format(DisplayFormat::Unison);
 
+    pub static ref CONFESSION_OF_SIN: Text = Text::from("Most merciful God,\nwe confess that we have sinned against you\nin thought, word, and deed,\nby what we have done,\nand by what we have left undone.\nWe have not loved you with our whole heart;\nwe have not loved our neighbors as ourselves.\nWe are truly sorry and we humbly repent.\nFor the sake of your Son Jesus Christ,\nhave mercy on us and forgive us;\nthat we may delight in your will,\nand walk in your ways,\nto the glory of your Name.")
+      .response("Amen.")
+      .display_format(DisplayFormat::Unison);
+
+    pub static ref ABSOLUTION: Text = Text::from("Almighty God have mercy on you, forgive you all your sins through our Lord Jesus Christ, strengthen you in all goodness, and by the power of the Holy Spirit keep you in eternal life.")
+      .response("Amen.");
+
     pub static ref PASCHA_NOSTRUM: Invitatory = Invitatory {
         local_name: String::from("Christ our Passover"),
         citation: Some(String::from("1 Corinthians 5:7-8; Romans 6:9-11; 1 Corinthians 15:20-22 ")),
@@ -1,7 +1,8 @@
 use crate::bcp1979::AN_ORDER_OF_WORSHIP_FOR_EVENING;
 use crate::conditions::{EASTER_SEASON, NOT_INSERT_GLORIA, NOT_LENT};
 use crate::rite2::{
-    APOSTLES_CREED, GLORIA_PATRI, LORDS_PRAYER_CONTEMPORARY_AND_TRADITIONAL, WORD_OF_THE_LORD,
+    ABSOLUTION, APOSTLES_CREED, CONFESSION_OF_SIN, GLORIA_PATRI,
+    LORDS_PRAYER_CONTEMPORARY_AND_TRADITIONAL, WORD_OF_THE_LORD,
 };
 use calendar::Weekday;
 use canticle_table::CanticleNumber;
@@ -76,12 +77,9 @@ lazy_static! {
                   Document::from("Let us confess our sins against God and our neighbor.").version_label("Short")
                 ]).selected(1)).page(116),
             Document::from(Rubric::from("Silence may be kept.\n\nOfficiant and People together, all kneeling")),
-            Document::from(Text::from("Most merciful God,\nwe confess that we have sinned against you\nin thought, word, and deed,\nby what we have done,\nand by what we have left undone.\nWe have not loved you with our whole heart;\nwe have not loved our neighbors as ourselves.\nWe are truly sorry and we humbly repent.\nFor the sake of your Son Jesus Christ,\nhave mercy on us and forgive us;\nthat we may delight in your will,\nand walk in your ways,\nto the glory of your Name.")
-                .response("Amen.")
-                .display_format(DisplayFormat::Unison)),
+            Document::from(CONFESSION_OF_SIN.clone()),
             Document::from(Rubric::from("The Priest alone stands and says")),
-            Document::from(Text::from("Almighty God have mercy on you, forgive you all your sins through our Lord Jesus Christ, strengthen you in all goodness, and by the power of the Holy Spirit keep you in eternal life.")
-                .response("Amen."))
+            Document::from(ABSOLUTION.clone())
                 .version_label("Priest"),
             Document::from(Rubric::from("A deacon or lay person using the preceding form remains kneeling, and substitutes “us” for “you” and “our” for “your.”"))
         ])).condition(
@@ -295,6 +293,10 @@ lazy_static! {
         Document::from(HymnLink::Tag("Evening".into())),
 
         Document::from(Rubric::from("Authorized intercessions and thanksgivings may follow.")),
+        Document::from(CycleOfPrayerLookup::new(CycleOfPrayerId::Anglican, "In the Anglican Cycle of Prayer, we pray for ___________."))
+            .display(Show::CompiledOnly),
+        Document::from(CycleOfPrayerLookup::new(CycleOfPrayerId::Diocesan, "In the diocesan cycle of prayer, we pray for ___________."))
+            .display(Show::CompiledOnly),
 
         Document::from(Content::DocumentLink { label: "Prayers and Thanksgivings".into(), path: SlugPath::from([Slug::PrayersAndThanksgivings]), rotate: false, link_only: true }),
 
@@ -339,6 +341,15 @@ lazy_static! {
           ]
         )).category("Liturgy"),
 
+        LiturgyPreference::from((
+          PreferenceKey::from(GlobalPref::InsertGloria),
+          "Gloria Patri after Each Psalm",
+          [
+            LiturgyPreferenceOption::from(("No", PreferenceValue::Bool(false))),
+            LiturgyPreferenceOption::from(("Yes", PreferenceValue::Bool(true))),
+          ]
+        )).category("Liturgy"),
+
         LiturgyPreference::from((
           PreferenceKey::from("ServiceOfLight"),
           "An Order of Worship for the Evening",
@@ -0,0 +1,506 @@
+use std::collections::{BTreeMap, HashSet};
+
+use language::Language;
+use liturgy::{Document, Psalm, SlugPath, Version};
+use psalter::{bcp1979::BCP1979_PSALTER, loc::LOC_PSALTER, Psalter};
+use rust_stemmers::{Algorithm, Stemmer};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    lff2018::collects::{LFF_COLLECTS_CONTEMPORARY, LFF_COLLECTS_TRADITIONAL},
+    CollectId, Contents, Library,
+};
+
+/// BM25 parameters: how quickly repeated terms stop adding to the score, and how much long texts are penalized
+const K1: f32 = 1.2;
+const B: f32 = 0.75;
+/// Each term in a label counts as this many terms in the text, so titles outrank passing mentions
+const LABEL_WEIGHT: u32 = 3;
+/// How many words of context are shown in a snippet, and how many of them precede the first match
+const SNIPPET_WORDS: usize = 24;
+const SNIPPET_LEAD: usize = 8;
+
+/// A full-text index of every document in a [Library], along with its collects and psalters.
+///
+/// The index can be built once (e.g., at compile time for the website) and then serialized,
+/// so that a browser can load it without building it again.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SearchIndex {
+    entries: Vec<SearchEntry>,
+    languages: Vec<LanguageIndex>,
+}
+
+/// Where a [SearchResult] can be found
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SearchSource {
+    /// A document in the table of contents, with the path to the sub-document as used by [Document::at_path].
+    /// For entries that contain several documents, the first index selects the document.
+    Contents {
+        slug_path: SlugPath,
+        path: Vec<usize>,
+    },
+    /// A collect that is not listed in the table of contents (e.g., from *Lesser Feasts and Fasts*)
+    Collect { id: CollectId, version: Version },
+    /// A psalm from the psalter in the given version
+    Psalm { version: Version, number: u8 },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub source: SearchSource,
+    pub label: Option<String>,
+    pub score: f32,
+    /// The passage around the first match, with each matching word highlighted
+    pub snippet: Vec<SnippetSpan>,
+}
+
+/// A run of text in a [SearchResult]'s snippet
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnippetSpan {
+    pub text: String,
+    pub highlighted: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct SearchEntry {
+    source: SearchSource,
+    label: Option<String>,
+    language: Language,
+    text: String,
+    /// The weighted number of terms in the label and text, used to normalize scores by length
+    length: u32,
+}
+
+/// The stemmed terms in one language, with the entries that contain them
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct LanguageIndex {
+    language: Language,
+    terms: BTreeMap<String, Vec<Posting>>,
+    /// The number of entries that contain any terms, and their total length, from which scores are normalized
+    entries: u32,
+    total_length: u64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Posting {
+    entry: usize,
+    count: u32,
+}
+
+impl SearchIndex {
+    /// Indexes every document in the [Library]'s table of contents, along with the collects
+    /// and psalters that are used when compiling them.
+    pub fn build<L: Library>() -> Self {
+        let mut index = Self::default();
+
+        // the flattened table of contents includes branches as well as leaves, and may include the same entry more than once
+        let mut checked = HashSet::new();
+        for (slug_path, contents) in L::contents().flatten() {
+            if !checked.insert(slug_path.clone()) {
+                continue;
+            }
+            match contents {
+                Contents::Document(doc) => index.add_document(&slug_path, Vec::new(), doc),
+                Contents::MultiDocument { documents, .. } => {
+                    for (idx, doc) in documents.iter().enumerate() {
+                        index.add_document(&slug_path, vec![idx], doc);
+                    }
+                }
+                Contents::Category { .. }
+                | Contents::Sections { .. }
+                | Contents::ByVersion { .. }
+                | Contents::Parallels { .. }
+                | Contents::Page { .. } => {}
+            }
+        }
+
+        for (id, data) in LFF_COLLECTS_TRADITIONAL
+            .iter()
+            .chain(LFF_COLLECTS_CONTEMPORARY.iter())
+        {
+            let doc = &data.document;
+            let label = doc.best_label().filter(|label| !label.is_empty());
+            index.add_entry(
+                SearchSource::Collect {
+                    id: *id,
+                    version: doc.version,
+                },
+                label.clone(),
+                label.as_deref(),
+                doc.language,
+                doc.as_text(),
+            );
+        }
+
+        for (psalter, version, language) in [
+            (&*BCP1979_PSALTER, Version::BCP1979, Language::En),
+            (&*LOC_PSALTER, Version::LibroDeOracionComun, Language::Es),
+        ] {
+            index.add_psalter(psalter, version, language);
+        }
+
+        index
+    }
+
+    fn add_document(&mut self, slug_path: &SlugPath, path: Vec<usize>, doc: &Document) {
+        for (sub_path, leaf) in doc.flatten_with_path(false) {
+            // only a sub-document's own label is indexed, but the whole document's label is shown if it has none
+            let own_label = leaf.best_label().filter(|label| !label.is_empty());
+            let text = [leaf.as_metadata_text(), leaf.as_text()]
+                .iter()
+                .filter(|text| !text.is_empty())
+                .cloned()
+                .collect::<Vec<_>>()
+                .join("\n");
+            let mut path = path.clone();
+            path.extend(sub_path);
+            self.add_entry(
+                SearchSource::Contents {
+                    slug_path: slug_path.clone(),
+                    path,
+                },
+                own_label
+                    .clone()
+                    .or_else(|| doc.best_label().filter(|label| !label.is_empty())),
+                own_label.as_deref(),
+                leaf.language,
+                text,
+            );
+        }
+    }
+
+    fn add_psalter(&mut self, psalter: &Psalter, version: Version, language: Language) {
+        for (number, psalm) in &psalter.psalms {
+            let label = match language {
+                Language::Es => format!("Salmo {}", number),
+                _ => format!("Psalm {}", number),
+            };
+            self.add_entry(
+                SearchSource::Psalm {
+                    version,
+                    number: *number,
+                },
+                Some(label.clone()),
+                Some(&label),
+                language,
+                psalm_text(psalm),
+            );
+        }
+    }
+
+    fn add_entry(
+        &mut self,
+        source: SearchSource,
+        label: Option<String>,
+        indexed_label: Option<&str>,
+        language: Language,
+        text: String,
+    ) {
+        if text.trim().is_empty() {
+            return;
+        }
+
+        let entry = self.entries.len();
+        let mut counts: BTreeMap<String, u32> = BTreeMap::new();
+        for token in tokenize(&text, language) {
+            *counts.entry(token.term).or_default() += 1;
+        }
+        for token in tokenize(indexed_label.unwrap_or_default(), language) {
+            *counts.entry(token.term).or_default() += LABEL_WEIGHT;
+        }
+
+        let index = match self
+            .languages
+            .iter()
+            .position(|search| search.language == language)
+        {
+            Some(idx) => &mut self.languages[idx],
+            None => {
+                self.languages.push(LanguageIndex {
+                    language,
+                    terms: BTreeMap::new(),
+                    entries: 0,
+                    total_length: 0,
+                });
+                self.languages.last_mut().unwrap()
+            }
+        };
+        for (term, count) in &counts {
+            index.terms.entry(term.clone()).or_default().push(Posting {
+                entry,
+                count: *count,
+            });
+        }
+        let length = counts.values().sum();
+        if !counts.is_empty() {
+            index.entries += 1;
+            index.total_length += u64::from(length);
+        }
+
+        self.entries.push(SearchEntry {
+            source,
+            label,
+            language,
+            text,
+            length,
+        });
+    }
+
+    /// The number of searchable passages in the index
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Searches the documents in the given language, returning at most `limit` results with the best matches first.
+    /// Words are compared by their stems, so that a search for “praying” also finds “prayer.”
+    /// ```
+    /// # use library::{CommonPrayer, SearchIndex};
+    /// # use language::Language;
+    /// let index = SearchIndex::build::<CommonPrayer>();
+    /// let results = index.search("good shepherd", Language::En, 10);
+    /// assert_eq!(results[0].label, Some("Fourth Sunday of Easter".to_string()));
+    /// assert!(results.iter().all(|result| result.snippet.iter().any(|span| span.highlighted)));
+    /// ```
+    pub fn search(&self, query: &str, language: Language, limit: usize) -> Vec<SearchResult> {
+        let index = match self
+            .languages
+            .iter()
+            .find(|search| search.language == language)
+        {
+            Some(index) => index,
+            None => return Vec::new(),
+        };
+        let terms = &index.terms;
+        let query_terms = tokenize(query, language)
+            .map(|token| token.term)
+            .collect::<HashSet<_>>();
+
+        let n = index.entries as f32;
+        let avg_length = index.total_length as f32 / n.max(1.0);
+
+        let mut scores: BTreeMap<usize, (f32, usize)> = BTreeMap::new();
+        for term in &query_terms {
+            if let Some(postings) = terms.get(term) {
+                let df = postings.len() as f32;
+                let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+                for posting in postings {
+                    let tf = posting.count as f32;
+                    let length = self.entries[posting.entry].length as f32;
+                    let score =
+                        idf * tf * (K1 + 1.0) / (tf + K1 * (1.0 - B + B * length / avg_length));
+                    let (total, matched) = scores.entry(posting.entry).or_default();
+                    *total += score;
+                    *matched += 1;
+                }
+            }
+        }
+
+        // passages that contain more of the search terms are preferred to those that repeat only a few of them
+        let mut ranked = scores
+            .into_iter()
+            .map(|(entry, (score, matched))| {
+                (entry, score * matched as f32 / query_terms.len() as f32)
+            })
+            .collect::<Vec<_>>();
+        ranked.sort_by(|(a_entry, a), (b_entry, b)| {
+            b.partial_cmp(a)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a_entry.cmp(b_entry))
+        });
+
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(entry, score)| {
+                let entry = &self.entries[entry];
+                SearchResult {
+                    source: entry.source.clone(),
+                    label: entry.label.clone(),
+                    score,
+                    snippet: snippet(&entry.text, entry.language, &query_terms),
+                }
+            })
+            .collect()
+    }
+}
+
+/// The verses of a psalm, as they are found in the psalter
+fn psalm_text(psalm: &Psalm) -> String {
+    psalm
+        .sections
+        .iter()
+        .flat_map(|section| section.verses.iter())
+        .flat_map(|verse| [verse.a.as_str(), verse.b.as_str()])
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A word in a text, with its byte range and the term under which it is indexed
+struct Token {
+    start: usize,
+    end: usize,
+    term: String,
+}
+
+/// Splits the text into words, and reduces each to its stem in the given language
+fn tokenize(text: &str, language: Language) -> impl Iterator<Item = Token> + '_ {
+    let stemmer = match language {
+        Language::En => Some(Stemmer::create(Algorithm::English)),
+        Language::Es => Some(Stemmer::create(Algorithm::Spanish)),
+        Language::Fr => Some(Stemmer::create(Algorithm::French)),
+        Language::Ht => None,
+    };
+
+    let mut words = Vec::new();
+    let mut start = None;
+    for (idx, ch) in text
+        .char_indices()
+        .chain(std::iter::once((text.len(), ' ')))
+    {
+        match (start, ch.is_alphanumeric()) {
+            (None, true) => start = Some(idx),
+            (Some(word_start), false) => {
+                words.push((word_start, idx));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    words.into_iter().map(move |(start, end)| {
+        let word = text[start..end].to_lowercase();
+        let stem = match &stemmer {
+            Some(stemmer) => stemmer.stem(&word).to_string(),
+            None => word,
+        };
+        Token {
+            start,
+            end,
+            term: fold_accents(&stem),
+        }
+    })
+}
+
+/// Removes diacritics, so that a search typed without accents (e.g., “oracion”) still matches
+fn fold_accents(word: &str) -> String {
+    word.chars()
+        .map(|ch| match ch {
+            'á' | 'à' | 'â' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            _ => ch,
+        })
+        .collect()
+}
+
+/// The words around the first match in the text, with every matching word highlighted
+fn snippet(text: &str, language: Language, query_terms: &HashSet<String>) -> Vec<SnippetSpan> {
+    let tokens = tokenize(text, language).collect::<Vec<_>>();
+    let first_match = tokens
+        .iter()
+        .position(|token| query_terms.contains(&token.term))
+        .unwrap_or(0);
+    let first = first_match.saturating_sub(SNIPPET_LEAD);
+    let last = (first + SNIPPET_WORDS).min(tokens.len());
+    let window = &tokens[first..last];
+
+    let (start, end) = match (window.first(), window.last()) {
+        (Some(first_token), Some(last_token)) => (
+            first_token.start,
+            if last == tokens.len() {
+                text.len()
+            } else {
+                last_token.end
+            },
+        ),
+        _ => (0, text.len()),
+    };
+
+    let mut spans: Vec<SnippetSpan> = Vec::new();
+    let mut push = |text: &str, highlighted: bool| match spans.last_mut() {
+        Some(last) if last.highlighted == highlighted => last.text.push_str(text),
+        _ => spans.push(SnippetSpan {
+            text: text.to_string(),
+            highlighted,
+        }),
+    };
+
+    if first > 0 {
+        push("…", false);
+    }
+    let mut position = start;
+    for token in window {
+        push(&text[position..token.start], false);
+        push(
+            &text[token.start..token.end],
+            query_terms.contains(&token.term),
+        );
+        position = token.end;
+    }
+    push(&text[position..end], false);
+    if last < tokens.len() {
+        push("…", false);
+    }
+
+    // line breaks between verses read as spaces in a snippet
+    for span in spans.iter_mut() {
+        span.text = span.text.replace('\n', " ");
+    }
+    spans.retain(|span| !span.text.is_empty());
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CommonPrayer;
+
+    #[test]
+    fn search_ranks_titles_and_matches_stems() {
+        let index = SearchIndex::build::<CommonPrayer>();
+        let results = index.search("a collect for peace", Language::En, 5);
+        assert_eq!(results[0].label.as_deref(), Some("A Collect for Peace"));
+
+        // “delivered” is found as “deliver,” “delivers,” etc.
+        let results = index.search("delivered", Language::En, 5);
+        assert!(results.iter().any(|result| result
+            .snippet
+            .iter()
+            .any(|span| span.highlighted && span.text.to_lowercase() == "deliver")));
+    }
+
+    #[test]
+    fn search_is_language_aware() {
+        let index = SearchIndex::build::<CommonPrayer>();
+        let results = index.search("pastor", Language::Es, 10);
+        assert!(results.iter().any(|result| result.source
+            == SearchSource::Psalm {
+                version: Version::LibroDeOracionComun,
+                number: 23
+            }));
+        // accents may be left out of the search
+        assert_eq!(
+            index.search("oracion", Language::Es, 10),
+            index.search("oración", Language::Es, 10)
+        );
+        assert!(index.search("pastor", Language::Ht, 10).is_empty());
+    }
+
+    #[test]
+    fn search_index_can_be_serialized() {
+        let index = SearchIndex::build::<CommonPrayer>();
+        let serialized = serde_json::to_string(&index).unwrap();
+        let deserialized: SearchIndex = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(
+            deserialized.search("shepherd", Language::En, 5),
+            index.search("shepherd", Language::En, 5)
+        );
+    }
+}
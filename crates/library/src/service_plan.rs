@@ -0,0 +1,420 @@
+use std::collections::BTreeMap;
+#[cfg(any(feature = "browser", feature = "server"))]
+use std::collections::HashMap;
+
+#[cfg(any(feature = "browser", feature = "server"))]
+use calendar::Calendar;
+use calendar::Date;
+use hymnal::{HymnNumber, Hymnals};
+use language::Language;
+use liturgy::{
+    Content, Document, Heading, HeadingLevel, HymnLink, Lectionaries, PreferenceKey,
+    PreferenceValue, Selections, Series, Slug, SlugPath, Text, Version,
+};
+#[cfg(any(feature = "browser", feature = "server"))]
+use liturgy::{GlobalPref, LiturgyPreferences};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    rite2::eucharist::parallel::{
+        COMMUNION_HYMN, DISMISSAL_RUBRIC, EUCHARISTIC_PRAYERS, GOSPEL, OFFERTORY_HYMN,
+        OPENING_HYMN, POP_FORMS, THE_PEACE,
+    },
+    Library, PrayersOfThePeople,
+};
+
+/// The versions of the Eucharist that can be planned: Rite II and its Spanish translation, which are
+/// marked with the points at which planned choices are inserted and include Eucharistic Prayers A–D
+pub const PLANNED_RITES: [Version; 2] = [Version::RiteII, Version::LibroDeOracionComun];
+
+/// The points in the Eucharist at which hymns can be planned
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum HymnSlot {
+    /// At the entrance, before the opening acclamation
+    Opening,
+    /// Between the lessons and the Gospel
+    Sequence,
+    Offertory,
+    Communion,
+    /// At the end of the service, before the dismissal
+    Closing,
+}
+
+/// The Eucharistic Prayers of Rite Two (see [PLANNED_RITES]), in the order in which they are printed
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum EucharisticPrayer {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl EucharisticPrayer {
+    fn index(&self) -> usize {
+        match self {
+            EucharisticPrayer::A => 0,
+            EucharisticPrayer::B => 1,
+            EucharisticPrayer::C => 2,
+            EucharisticPrayer::D => 3,
+        }
+    }
+}
+
+/// Why a [ServicePlan] could not be made into a service
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ServicePlanProblem {
+    /// A rite other than those in [PLANNED_RITES], for which the plan's choices (including its
+    /// [EucharisticPrayer]) can't be inserted
+    UnsupportedRite(Version),
+    /// A rite for which the library has no Eucharist
+    MissingEucharist(Version),
+    /// The library could not compile the service for its day
+    NotCompiled,
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Announcement {
+    pub title: String,
+    pub text: String,
+}
+
+/// The choices made in planning a Sunday or other Eucharist, which can be saved, shared, and compiled
+/// into a complete service (e.g., for a bulletin) for its day
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServicePlan {
+    pub date: Date,
+    /// The version of the Eucharist used, one of [PLANNED_RITES]
+    pub rite: Version,
+    /// If `None`, the Eucharistic Prayers are left as a choice
+    pub eucharistic_prayer: Option<EucharisticPrayer>,
+    /// The lectionary, e.g., [Lectionaries::RCLTrack1] or [Lectionaries::RCLTrack2]
+    pub track: Lectionaries,
+    pub hymns: BTreeMap<HymnSlot, Vec<(Hymnals, HymnNumber)>>,
+    /// If `None`, the forms of the Prayers of the People are left as a choice
+    pub prayers_of_the_people: Option<PrayersOfThePeople>,
+    pub announcements: Vec<Announcement>,
+    /// Any other preferences for the service, as they would be chosen by a client
+    pub preferences: Vec<(PreferenceKey, PreferenceValue)>,
+}
+
+/// A [ServicePlan] compiled for its day, with the option selected at each [Choice](liturgy::Choice)
+/// (see [Document::selections])
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompiledService {
+    pub document: Document,
+    pub selections: Selections,
+}
+
+impl ServicePlan {
+    pub fn new(date: Date) -> Self {
+        Self {
+            date,
+            rite: Version::RiteII,
+            eucharistic_prayer: None,
+            track: Lectionaries::RCLTrack2,
+            hymns: BTreeMap::new(),
+            prayers_of_the_people: None,
+            announcements: Vec::new(),
+            preferences: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn rite(mut self, rite: Version) -> Self {
+        self.rite = rite;
+        self
+    }
+
+    #[must_use]
+    pub fn eucharistic_prayer(mut self, prayer: EucharisticPrayer) -> Self {
+        self.eucharistic_prayer = Some(prayer);
+        self
+    }
+
+    #[must_use]
+    pub fn track(mut self, track: Lectionaries) -> Self {
+        self.track = track;
+        self
+    }
+
+    /// Adds a hymn at the given point in the service, after any hymns already planned there
+    #[must_use]
+    pub fn hymn(mut self, slot: HymnSlot, hymnal: Hymnals, number: HymnNumber) -> Self {
+        self.hymns.entry(slot).or_default().push((hymnal, number));
+        self
+    }
+
+    #[must_use]
+    pub fn prayers_of_the_people(mut self, prayers: PrayersOfThePeople) -> Self {
+        self.prayers_of_the_people = Some(prayers);
+        self
+    }
+
+    #[must_use]
+    pub fn announcement(mut self, title: impl ToString, text: impl ToString) -> Self {
+        self.announcements.push(Announcement {
+            title: title.to_string(),
+            text: text.to_string(),
+        });
+        self
+    }
+
+    #[must_use]
+    pub fn preference(
+        mut self,
+        key: impl Into<PreferenceKey>,
+        value: impl Into<PreferenceValue>,
+    ) -> Self {
+        self.preferences.push((key.into(), value.into()));
+        self
+    }
+
+    /// The Eucharist for this plan's rite in the given library, with the planned choices inserted into it
+    pub fn template<L: Library>(&self) -> Result<Document, ServicePlanProblem> {
+        if !PLANNED_RITES.contains(&self.rite) {
+            return Err(ServicePlanProblem::UnsupportedRite(self.rite));
+        }
+        let mut document = L::contents()
+            .contents_at_path(&SlugPath::from([
+                Slug::Eucharist,
+                Slug::Eucharist,
+                Slug::Version(self.rite),
+            ]))
+            .and_then(|contents| contents.as_documents().next().cloned())
+            .ok_or(ServicePlanProblem::MissingEucharist(self.rite))?;
+        let language = document.language;
+        match &mut document.content {
+            Content::Liturgy(liturgy) => {
+                liturgy.body = Series::from(self.plan(liturgy.body.clone().into_vec(), language))
+            }
+            Content::Series(series) => {
+                *series = Series::from(self.plan(series.clone().into_vec(), language))
+            }
+            _ => {}
+        }
+        Ok(document)
+    }
+
+    /// Compiles the planned service for its day, using the given library and calendar
+    #[cfg(any(feature = "browser", feature = "server"))]
+    pub fn compile<L: Library>(
+        &self,
+        calendar: &Calendar,
+    ) -> Result<CompiledService, ServicePlanProblem> {
+        let document = self.template::<L>()?;
+        let day = calendar.liturgical_day(self.date, false);
+        let liturgy_prefs = match &document.content {
+            Content::Liturgy(liturgy) => liturgy.preferences.clone(),
+            _ => LiturgyPreferences::default(),
+        };
+        let prefs = self
+            .preferences
+            .iter()
+            .cloned()
+            .chain(std::iter::once((
+                PreferenceKey::from(GlobalPref::Lectionary),
+                PreferenceValue::from(self.track),
+            )))
+            .collect::<HashMap<_, _>>();
+        let document = L::compile(
+            document,
+            calendar,
+            &day,
+            &day.observed,
+            &prefs,
+            &liturgy_prefs,
+        )
+        .ok_or(ServicePlanProblem::NotCompiled)?;
+        let selections = document.selections();
+        Ok(CompiledService {
+            document,
+            selections,
+        })
+    }
+
+    fn plan(&self, documents: Vec<Document>, language: Language) -> Vec<Document> {
+        let mut planned = Vec::new();
+        let mut documents = documents.into_iter().peekable();
+        while let Some(mut document) = documents.next() {
+            let has_tag = |tag: &str| document.tags.iter().any(|s_tag| s_tag == tag);
+
+            // hymns inserted before a point in the service
+            if has_tag(GOSPEL) {
+                planned.extend(self.hymns_for(HymnSlot::Sequence));
+            }
+            if has_tag(DISMISSAL_RUBRIC)
+                && !planned
+                    .iter()
+                    .any(|doc: &Document| doc.tags.iter().any(|tag| tag == DISMISSAL_RUBRIC))
+            {
+                planned.extend(self.hymns_for(HymnSlot::Closing));
+            }
+
+            if has_tag(POP_FORMS) {
+                if let Some(prayers) = &self.prayers_of_the_people {
+                    planned.push(prayers.build().tags([POP_FORMS]));
+                    // the forms themselves may follow the link as a choice
+                    if matches!(
+                        documents.peek().map(|doc| &doc.content),
+                        Some(Content::Choice(_))
+                    ) {
+                        documents.next();
+                    }
+                    continue;
+                }
+            }
+
+            let is_peace = has_tag(THE_PEACE);
+            let slot = if has_tag(OPENING_HYMN) {
+                Some(HymnSlot::Opening)
+            } else if has_tag(OFFERTORY_HYMN) {
+                Some(HymnSlot::Offertory)
+            } else if has_tag(COMMUNION_HYMN) {
+                Some(HymnSlot::Communion)
+            } else {
+                None
+            };
+            let is_eucharistic_prayers = has_tag(EUCHARISTIC_PRAYERS);
+
+            match (&mut document.content, slot) {
+                (Content::HymnLink(_), Some(slot)) if self.hymns.contains_key(&slot) => {
+                    planned.extend(self.hymns_for(slot));
+                }
+                (Content::Choice(choice), _) if is_eucharistic_prayers => {
+                    if let Some(prayer) = self.eucharistic_prayer {
+                        if prayer.index() < choice.options.len() {
+                            choice.selected = prayer.index();
+                        }
+                    }
+                    planned.push(document);
+                }
+                (Content::Series(series), _) => {
+                    *series = Series::from(self.plan(series.clone().into_vec(), language));
+                    planned.push(document);
+                }
+                _ => planned.push(document),
+            }
+
+            // announcements follow the Peace
+            let peace_ends = is_peace
+                && !documents
+                    .peek()
+                    .map(|doc| doc.tags.iter().any(|tag| tag == THE_PEACE))
+                    .unwrap_or(false);
+            if peace_ends && !self.announcements.is_empty() {
+                planned.push(Document::from(Heading::from((
+                    HeadingLevel::Heading3,
+                    match language {
+                        Language::Es => "Anuncios",
+                        _ => "Announcements",
+                    },
+                ))));
+                planned.extend(self.announcements.iter().map(|announcement| {
+                    Document::from(Text::from(announcement.text.clone())).label(&announcement.title)
+                }));
+            }
+        }
+        planned
+    }
+
+    fn hymns_for(&self, slot: HymnSlot) -> Vec<Document> {
+        self.hymns
+            .get(&slot)
+            .into_iter()
+            .flatten()
+            .map(|(hymnal, number)| Document::from(HymnLink::Hymn(*hymnal, *number)))
+            .collect()
+    }
+}
+
+#[cfg(all(test, any(feature = "browser", feature = "server")))]
+mod tests {
+    use calendar::BCP1979_CALENDAR;
+
+    use super::*;
+    use crate::{CommonPrayer, IntercessionCategory, PrayersOfThePeopleForm};
+
+    #[test]
+    fn service_plans_compile_for_their_day() {
+        let plan = ServicePlan::new(Date::from_ymd(2023, 2, 19))
+            .track(Lectionaries::RCLTrack1)
+            .eucharistic_prayer(EucharisticPrayer::C)
+            .hymn(HymnSlot::Opening, Hymnals::Hymnal1982, HymnNumber::H(135))
+            .hymn(HymnSlot::Sequence, Hymnals::Hymnal1982, HymnNumber::H(129))
+            .hymn(HymnSlot::Closing, Hymnals::Hymnal1982, HymnNumber::H(137))
+            .prayers_of_the_people(
+                PrayersOfThePeople::new(PrayersOfThePeopleForm::III)
+                    .intercession(IntercessionCategory::Sick, "Margaret"),
+            )
+            .announcement(
+                "Shrove Tuesday",
+                "Pancakes will be served in the parish hall.",
+            );
+
+        // a plan can be saved and restored
+        let json = serde_json::to_string(&plan).unwrap();
+        assert_eq!(serde_json::from_str::<ServicePlan>(&json).unwrap(), plan);
+
+        let compiled = plan.compile::<CommonPrayer>(&BCP1979_CALENDAR).unwrap();
+        assert!(compiled
+            .document
+            .contains("Pancakes will be served in the parish hall."));
+        assert!(compiled.document.contains("Margaret"));
+        // only the chosen form of the Prayers of the People is included
+        assert!(!compiled
+            .document
+            .contains("With all our heart and with all our mind"));
+
+        fn hymns(doc: &Document) -> Vec<HymnNumber> {
+            match &doc.content {
+                Content::HymnLink(HymnLink::Hymn(_, number)) => vec![*number],
+                Content::Liturgy(liturgy) => liturgy.body.iter().flat_map(hymns).collect(),
+                Content::Series(series) => series.iter().flat_map(hymns).collect(),
+                _ => Vec::new(),
+            }
+        }
+        assert_eq!(
+            hymns(&compiled.document),
+            [HymnNumber::H(135), HymnNumber::H(129), HymnNumber::H(137)]
+        );
+
+        let prayers = compiled
+            .selections
+            .selections
+            .iter()
+            .find(|selection| {
+                compiled
+                    .document
+                    .at_path(selection.path.clone())
+                    .map(|doc| doc.tags.iter().any(|tag| tag == EUCHARISTIC_PRAYERS))
+                    .unwrap_or(false)
+            })
+            .unwrap();
+        assert_eq!(prayers.option.index, 2);
+        match &compiled
+            .document
+            .at_path(prayers.path.clone())
+            .unwrap()
+            .content
+        {
+            Content::Choice(choice) => assert_eq!(choice.selected, 2),
+            _ => panic!("expected the Eucharistic Prayers to be a choice"),
+        }
+    }
+
+    #[test]
+    fn only_rite_ii_services_can_be_planned() {
+        let plan =
+            ServicePlan::new(Date::from_ymd(2023, 2, 19)).eucharistic_prayer(EucharisticPrayer::B);
+        assert!(plan
+            .clone()
+            .rite(Version::LibroDeOracionComun)
+            .compile::<CommonPrayer>(&BCP1979_CALENDAR)
+            .is_ok());
+        assert_eq!(
+            plan.rite(Version::RiteI)
+                .compile::<CommonPrayer>(&BCP1979_CALENDAR),
+            Err(ServicePlanProblem::UnsupportedRite(Version::RiteI))
+        );
+    }
+}
@@ -14,8 +14,9 @@ use psalter::{bcp1979::BCP1979_PSALTER, Psalter};
 
 use language::Language;
 use lectionary::{
-    Lectionary, ReadingType, BCP1979_30_DAY_PSALTER, BCP1979_DAILY_OFFICE_LECTIONARY,
-    BCP1979_DAILY_OFFICE_PSALTER, RCL, RCL_TRACK_1, RCL_TRACK_2, VIGIL_READING_TYPES,
+    sunday_propers_for_weekday, Lectionary, ReadingType, BCP1979_30_DAY_PSALTER,
+    BCP1979_DAILY_OFFICE_LECTIONARY, BCP1979_DAILY_OFFICE_PSALTER, RCL, RCL_TRACK_1, RCL_TRACK_2,
+    VIGIL_READING_TYPES, WEEKDAY_EUCHARIST,
 };
 
 use crate::{CommonPrayer, Library};
@@ -51,7 +52,7 @@ impl CommonPrayer {
         language: Language,
     ) -> EucharisticLectionarySummary {
         let psalter = &BCP1979_PSALTER;
-        let alternates = day
+        let mut alternates = day
             .alternative_services
             .iter()
             .map(|alternate| {
@@ -64,6 +65,18 @@ impl CommonPrayer {
             })
             .collect::<Vec<_>>();
 
+        // on a ferial weekday, the propers of the preceding Sunday may be used instead (BCP p. 158)
+        if let Some(sunday) = sunday_propers_for_weekday(&day.observed) {
+            let sunday_day = LiturgicalDay {
+                weekday: Weekday::Sun,
+                ..day.clone()
+            };
+            let mut summary = summarize_eucharistic_observance(&day, &sunday, language, psalter);
+            summary.localized_name =
+                localize_day_name(&sunday_day, &sunday, &BCP1979_CALENDAR, language);
+            alternates.push(summary);
+        }
+
         let observed = summarize_eucharistic_observance(&day, &day.observed, language, psalter);
         EucharisticLectionarySummary {
             day,
@@ -115,7 +128,15 @@ fn summarize_eucharistic_observance(
 
     let vigil_readings = vigil_readings(observance, day, &RCL, psalter);
 
-    let tracked_readings = if let LiturgicalDayId::ProperAndDay(..) = observance {
+    // ferial weekdays have no propers in the RCL, so use the weekday lectionary instead
+    let is_ferial = sunday_propers_for_weekday(observance).is_some();
+    let lectionary: &'static Lectionary = if is_ferial { &WEEKDAY_EUCHARIST } else { &RCL };
+
+    let tracked_readings = if is_ferial {
+        TrackedReadings::Any(Box::new(tracked_readings(
+            observance, day, lectionary, psalter,
+        )))
+    } else if let LiturgicalDayId::ProperAndDay(..) = observance {
         let track_one = Box::new(tracked_readings(observance, day, &RCL_TRACK_1, psalter));
         let track_two = Box::new(tracked_readings(observance, day, &RCL_TRACK_2, psalter));
         TrackedReadings::Tracked {
@@ -126,12 +147,12 @@ fn summarize_eucharistic_observance(
         TrackedReadings::Any(Box::new(tracked_readings(observance, day, &RCL, psalter)))
     };
 
-    let epistle = RCL
+    let epistle = lectionary
         .reading_by_type(observance, day, ReadingType::SecondReading)
         .map(|reading| reading.citation)
         .collect();
 
-    let gospel = RCL
+    let gospel = lectionary
         .reading_by_type(observance, day, ReadingType::Gospel)
         .map(|reading| reading.citation)
         .collect();
@@ -2,7 +2,10 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{CanticleTables, GloriaPatri, PreferenceKey};
+use crate::{
+    recitation::verse_as_refrain, Antiphon, CanticleTables, ChantTone, GloriaPatri, InlineText,
+    Pointing, PreferenceKey, Recitation,
+};
 use canticle_table::{CanticleId, CanticleNumber};
 
 /// An entry that can be looked up from a [CanticleTable](canticle_table::CanticleTable).
@@ -21,7 +24,7 @@ pub enum CanticleTableChoice {
 }
 
 /// A Canticle (i.e., a psalm-like text not found in the Book of Psalms, and used liturgically)
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Canticle {
     /// Unique identifier for the canticle; may be shared between different versions or translations
     pub number: CanticleId,
@@ -39,6 +42,33 @@ pub struct Canticle {
     pub sections: Vec<CanticleSection>,
     /// The text of the Gloria Patri, to be included (or not) at the end of the canticle
     pub gloria_patri: Option<GloriaPatri>,
+    /// How the canticle is to be recited, if specified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recitation: Option<Recitation>,
+}
+
+impl Canticle {
+    /// The refrain to be said by the people, if the canticle is recited responsorially
+    pub fn refrain(&self) -> Option<Antiphon> {
+        let recitation = self.recitation.as_ref().filter(|r| r.has_refrain())?;
+        recitation.refrain.clone().or_else(|| {
+            let verse = self.sections.first()?.verses.first()?;
+            Some(verse_as_refrain(&verse.a, &verse.b))
+        })
+    }
+
+    /// Points every verse of the canticle for the given tone (see [Pointing::new]).
+    #[must_use]
+    pub fn pointed(mut self, tone: &ChantTone) -> Self {
+        for verse in self
+            .sections
+            .iter_mut()
+            .flat_map(|section| section.verses.iter_mut())
+        {
+            verse.pointing = Some(Pointing::new(&verse.a, &verse.b, tone));
+        }
+        self
+    }
 }
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -55,6 +85,16 @@ pub struct CanticleVerse {
     pub a: String,
     /// Text of the second half of the verse, after the asterisk
     pub b: String,
+    /// Marks for singing the verse to a chant tone, if it has been pointed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pointing: Option<Pointing>,
+}
+
+impl CanticleVerse {
+    /// Both halves of the verse, parsed into [Inline](crate::Inline) pieces
+    pub fn inline(&self) -> (InlineText, InlineText) {
+        (InlineText::from(&self.a), InlineText::from(&self.b))
+    }
 }
 
 impl<A, B> From<(A, B)> for CanticleVerse
@@ -66,6 +106,7 @@ where
         Self {
             a: a.to_string(),
             b: b.to_string(),
+            pointing: None,
         }
     }
 }
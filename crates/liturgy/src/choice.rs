@@ -1,8 +1,10 @@
+use std::convert::TryFrom;
+
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
 use crate::{Content, Document, Liturgy, Parallel, Series};
-use calendar::Date;
+use calendar::{Calendar, Date, LiturgicalDay};
 
 /// Multiple [Document](crate::Document)s that are displayed one at a time, with a menu to choose between them.
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -11,6 +13,138 @@ pub struct Choice {
     pub selected: usize,
     pub rotated: bool,
     pub should_rotate: bool,
+    /// How the selection is made if the compiler rotates this choice
+    #[serde(default)]
+    pub rotation: Rotation,
+}
+
+/// How a [Choice] chooses its selection when it is rotated, so that the same day always gives the same selection.
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Rotation {
+    pub basis: RotationBasis,
+    /// Added to the position in the rotation at evening services, so that the morning and evening selections differ
+    #[serde(default)]
+    pub evening_offset: usize,
+    /// The number of turns each option receives in each cycle of the rotation, by index.
+    /// Options without a weight receive one turn.
+    #[serde(default)]
+    pub weights: Vec<usize>,
+    /// If set, the turns in each cycle are arranged so that no option is repeated within this many turns
+    /// (i.e., days, or weeks for [RotationBasis::WeekOfSeason]), where the weights allow it.
+    #[serde(default)]
+    pub no_repeat_within: Option<usize>,
+}
+
+/// What counts as the next turn in a [Rotation]
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RotationBasis {
+    /// Each day of the calendar year, beginning again on January 1
+    #[default]
+    DayOfYear,
+    /// Each week of the liturgical season, beginning again when the season changes
+    WeekOfSeason,
+    /// Each day since the First Sunday of Advent
+    DaySinceAdvent,
+}
+
+impl Rotation {
+    /// The position in the rotation on the given day, before weights are applied
+    fn position(&self, calendar: &Calendar, day: &LiturgicalDay) -> usize {
+        let position = match self.basis {
+            RotationBasis::DayOfYear => day.date.day_in_year().into(),
+            RotationBasis::DaySinceAdvent => {
+                let advent_1 = |year: u16| {
+                    Date::from_ymd(year, 12, 24)
+                        .sunday_before()
+                        .subtract_days(21)
+                };
+                let this_year = advent_1(day.date.year());
+                let advent_1 = if day.date >= this_year {
+                    this_year
+                } else {
+                    advent_1(day.date.year() - 1)
+                };
+                usize::try_from((day.date - advent_1).num_days()).unwrap_or(0)
+            }
+            RotationBasis::WeekOfSeason => {
+                // feasts like Ash Wednesday begin a season; other feasts belong to the season of their week
+                let season_of = |day: &LiturgicalDay| {
+                    let season = calendar.season(day);
+                    if season.is_true_season() {
+                        season
+                    } else {
+                        calendar.base_season(day)
+                    }
+                };
+                let season = season_of(day);
+                let mut sunday = day.date.sunday_before();
+                let mut weeks = 0;
+                // count back each week whose last day was in the same season
+                // (no season lasts longer than a year)
+                while weeks < 53 {
+                    let saturday = calendar.liturgical_day(sunday.subtract_days(1), false);
+                    if season_of(&saturday) != season {
+                        break;
+                    }
+                    sunday = sunday.subtract_days(7);
+                    weeks += 1;
+                }
+                weeks
+            }
+        };
+        if day.evening {
+            position + self.evening_offset
+        } else {
+            position
+        }
+    }
+
+    /// The order in which options are selected over one cycle of the rotation, spreading out
+    /// the turns of each option as evenly as possible (a smooth weighted round-robin)
+    fn schedule(&self, n_options: usize) -> Vec<usize> {
+        let weights = (0..n_options)
+            .map(|idx| self.weights.get(idx).copied().unwrap_or(1))
+            .collect::<Vec<_>>();
+        let total: usize = weights.iter().sum();
+        // if every option has been given no weight, treat them equally
+        let (weights, total) = if total == 0 {
+            (vec![1; n_options], n_options)
+        } else {
+            (weights, total)
+        };
+
+        let mut current = vec![0_isize; n_options];
+        let mut schedule: Vec<usize> = Vec::with_capacity(total);
+        for _ in 0..total {
+            for (current, weight) in current.iter_mut().zip(weights.iter()) {
+                *current += *weight as isize;
+            }
+            let recent = match self.no_repeat_within {
+                Some(n) => &schedule[schedule.len().saturating_sub(n.saturating_sub(1))..],
+                None => &[],
+            };
+            let best = |eligible: &dyn Fn(usize) -> bool| {
+                (0..n_options)
+                    .filter(|idx| weights[*idx] > 0 && eligible(*idx))
+                    .max_by_key(|idx| (current[*idx], std::cmp::Reverse(*idx)))
+            };
+            let next = best(&|idx| !recent.contains(&idx))
+                .or_else(|| best(&|_| true))
+                .unwrap_or(0);
+            current[next] -= total as isize;
+            schedule.push(next);
+        }
+        schedule
+    }
+
+    /// The index of the option selected on the given day, from a [Choice] with the given number of options
+    pub fn select(&self, calendar: &Calendar, day: &LiturgicalDay, n_options: usize) -> usize {
+        if n_options == 0 {
+            return 0;
+        }
+        let schedule = self.schedule(n_options);
+        schedule[self.position(calendar, day) % schedule.len()]
+    }
 }
 
 impl<T> From<T> for Choice
@@ -23,6 +157,7 @@ where
             options: options.into_iter().collect(),
             rotated: false,
             should_rotate: false,
+            rotation: Rotation::default(),
         }
     }
 }
@@ -67,6 +202,32 @@ impl Choice {
         self.selected = nth_day % self.options.len();
     }
 
+    /// Sets the `selected` item on the `Choice` for the given day, according to its [Rotation].
+    /// ```
+    /// # use crate::liturgy::{Choice, Document, Rotation, RotationBasis, Text};
+    /// # use calendar::{Date, BCP1979_CALENDAR};
+    /// let mut choice = Choice::from([
+    ///     Document::from(Text::from("A")),
+    ///     Document::from(Text::from("B")),
+    /// ])
+    /// .rotation(Rotation {
+    ///     basis: RotationBasis::DaySinceAdvent,
+    ///     evening_offset: 1,
+    ///     ..Default::default()
+    /// });
+    /// // Advent 1
+    /// let morning = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2022, 11, 27), false);
+    /// choice.rotate_on(&BCP1979_CALENDAR, &morning);
+    /// assert_eq!(choice.selected, 0);
+    /// let evening = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(2022, 11, 27), true);
+    /// choice.rotate_on(&BCP1979_CALENDAR, &evening);
+    /// assert_eq!(choice.selected, 1);
+    /// ```
+    pub fn rotate_on(&mut self, calendar: &Calendar, day: &LiturgicalDay) {
+        self.rotated = true;
+        self.selected = self.rotation.select(calendar, day, self.options.len());
+    }
+
     /// Instruct the compiler to rotate
     #[must_use]
     pub fn should_rotate(mut self) -> Self {
@@ -74,6 +235,13 @@ impl Choice {
         self
     }
 
+    /// Set the [Rotation] used when the compiler rotates this choice
+    #[must_use]
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
     /// Set the default selection
     #[must_use]
     pub fn selected(mut self, idx: usize) -> Self {
@@ -269,6 +437,7 @@ mod tests {
                 latin_name: Some(String::from("Benedicite, omnia opera Domini")),
                 rubric: None,
                 gloria_patri: None,
+                ..Default::default()
             })
             .version(Version::RiteI),
             Document::from(Canticle {
@@ -280,6 +449,7 @@ mod tests {
                 latin_name: Some(String::from("Benedictus es, Domine")),
                 rubric: None,
                 gloria_patri: None,
+                ..Default::default()
             })
             .version(Version::RiteI),
         ]);
@@ -305,6 +475,7 @@ mod tests {
                 latin_name: Some(String::from("Benedicite, omnia opera Domini")),
                 rubric: None,
                 gloria_patri: None,
+                ..Default::default()
             })
             .version(Version::RiteI),
             Document::from(Canticle {
@@ -316,6 +487,7 @@ mod tests {
                 latin_name: Some(String::from("Benedicite, omnia opera Domini")),
                 rubric: None,
                 gloria_patri: None,
+                ..Default::default()
             })
             .version(Version::RiteII),
         ]);
@@ -335,6 +507,7 @@ mod tests {
                 latin_name: Some(String::from("Benedicite, omnia opera Domini")),
                 rubric: None,
                 gloria_patri: None,
+                ..Default::default()
             })
             .version(Version::RiteII),
             Document::from(Canticle {
@@ -346,6 +519,7 @@ mod tests {
                 latin_name: Some(String::from("Benedicite, omnia opera Domini")),
                 rubric: None,
                 gloria_patri: None,
+                ..Default::default()
             })
             .version(Version::EOW),
         ]);
@@ -365,6 +539,7 @@ mod tests {
                 latin_name: Some(String::from("Benedicite, omnia opera Domini")),
                 rubric: None,
                 gloria_patri: None,
+                ..Default::default()
             })
             .version(Version::RiteI),
             Document::from(Canticle {
@@ -376,6 +551,7 @@ mod tests {
                 latin_name: Some(String::from("Benedicite, omnia opera Domini")),
                 rubric: None,
                 gloria_patri: None,
+                ..Default::default()
             })
             .version(Version::RiteII),
             Document::from(Canticle {
@@ -387,6 +563,7 @@ mod tests {
                 latin_name: Some(String::from("Benedictus es, Domine")),
                 rubric: None,
                 gloria_patri: None,
+                ..Default::default()
             })
             .version(Version::RiteI),
         ]);
@@ -549,4 +726,40 @@ mod tests {
             "John 1:2 (“He was in the beginning with God.”)"
         );
     }
+
+    // Rotation tests
+    #[test]
+    fn weighted_rotation_spreads_out_turns() {
+        let rotation = Rotation {
+            weights: vec![2, 1, 1],
+            no_repeat_within: Some(2),
+            ..Default::default()
+        };
+        let schedule = rotation.schedule(3);
+        assert_eq!(schedule.len(), 4);
+        assert_eq!(schedule.iter().filter(|idx| **idx == 0).count(), 2);
+        assert!(schedule.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn rotation_by_week_of_season() {
+        use calendar::{Date, BCP1979_CALENDAR};
+
+        let rotation = Rotation {
+            basis: RotationBasis::WeekOfSeason,
+            ..Default::default()
+        };
+        let select = |y, m, d| {
+            let day = BCP1979_CALENDAR.liturgical_day(Date::from_ymd(y, m, d), false);
+            rotation.select(&BCP1979_CALENDAR, &day, 3)
+        };
+        // Lent 2023 began on Ash Wednesday, February 22
+        assert_eq!(select(2023, 2, 23), 0);
+        assert_eq!(select(2023, 2, 26), 1);
+        assert_eq!(select(2023, 3, 1), 1);
+        assert_eq!(select(2023, 3, 5), 2);
+        assert_eq!(select(2023, 3, 12), 0);
+        // the rotation begins again in Holy Week
+        assert_eq!(select(2023, 4, 2), 0);
+    }
 }
@@ -0,0 +1,535 @@
+use std::collections::BTreeSet;
+
+use calendar::{Calendar, Date, LiturgicalDay, LiturgicalDayId};
+use serde::{Deserialize, Serialize};
+
+use crate::{ClientPreferences, Condition, Content, Document, LiturgyPreferences};
+
+/// Days before the first of each month in a leap year, used to compare (month, day) pairs
+/// for [Condition::DateLt] and friends without reference to a particular year
+const DAYS_BEFORE_MONTH: [u16; 12] = [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335];
+const DAYS_IN_LEAP_YEAR: u16 = 366;
+
+/// The result of statically simplifying a [Condition] with [Condition::simplify]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Simplified {
+    /// The condition is met on every day, whatever preferences are chosen
+    Always,
+    /// The condition can never be met
+    Never,
+    /// The condition depends on the day or on preferences; contains an equivalent, simpler [Condition]
+    Sometimes(Condition),
+}
+
+/// A problem found in the [Condition]s of a [Document] by [Document::analyze_conditions]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConditionDiagnostic {
+    /// The path to the sub-document, as used by [Document::at_path]
+    pub path: Vec<usize>,
+    pub condition: Condition,
+    pub problem: ConditionProblem,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ConditionProblem {
+    /// The condition can never be met, so the sub-document will never be included
+    Unsatisfiable,
+    /// The condition is always met, so it has no effect
+    AlwaysTrue,
+    /// The condition can be met on its own, but never together with the conditions
+    /// of the documents that contain it, so the sub-document will never be included
+    ContradictsParent,
+}
+
+/// The dates in a year on which a conditioned sub-document would be included, from [Document::date_coverage]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DateCoverage {
+    /// The path to the sub-document, as used by [Document::at_path]
+    pub path: Vec<usize>,
+    /// The sub-document's own condition; the dates also take into account the conditions of the documents that contain it
+    pub condition: Condition,
+    pub dates: BTreeSet<Date>,
+}
+
+impl Condition {
+    /// Simplifies a [Condition] by flattening nested logic, removing duplicates and terms that are always met,
+    /// and detecting combinations that can never be met (e.g., two different seasons) or are always met (e.g., `x or not x`).
+    /// ```
+    /// # use liturgy::{Condition, Simplified};
+    /// # use calendar::{Season, Weekday};
+    /// let lent_and_easter = Condition::And(
+    ///     Box::new(Condition::Season(Season::Lent)),
+    ///     Box::new(Condition::Season(Season::Easter)),
+    /// );
+    /// assert_eq!(lent_and_easter.simplify(), Simplified::Never);
+    ///
+    /// let sunday_or_not = Condition::Or(
+    ///     Box::new(Condition::Weekday(Weekday::Sun)),
+    ///     Box::new(Condition::Not(Box::new(Condition::Weekday(Weekday::Sun)))),
+    /// );
+    /// assert_eq!(sunday_or_not.simplify(), Simplified::Always);
+    ///
+    /// let nested = Condition::All(vec![
+    ///     Condition::Not(Box::new(Condition::Not(Box::new(Condition::Season(Season::Lent))))),
+    ///     Condition::Any(vec![]),
+    /// ]);
+    /// assert_eq!(nested.simplify(), Simplified::Sometimes(Condition::Season(Season::Lent)));
+    /// ```
+    pub fn simplify(&self) -> Simplified {
+        match self {
+            Condition::Not(cond) => negate(cond.simplify()),
+            Condition::And(a, b) => conjunction([a.as_ref(), b.as_ref()]),
+            Condition::All(conds) => conjunction(conds),
+            Condition::Or(a, b) => disjunction([a.as_ref(), b.as_ref()]),
+            // `Condition::include` treats empty lists as always included
+            Condition::Any(conds) if conds.is_empty() => Simplified::Always,
+            Condition::Any(conds) => disjunction(conds),
+            Condition::None(conds) if conds.is_empty() => Simplified::Always,
+            Condition::None(conds) => negate(disjunction(conds)),
+            Condition::DateLt(1, 1) | Condition::DateGt(12, 31) => Simplified::Never,
+            Condition::DateGte(1, 1) | Condition::DateLte(12, 31) => Simplified::Always,
+            Condition::DayOfMonth(day) if *day == 0 || *day > 31 => Simplified::Never,
+            _ => Simplified::Sometimes(self.clone()),
+        }
+    }
+}
+
+fn negate(simplified: Simplified) -> Simplified {
+    match simplified {
+        Simplified::Always => Simplified::Never,
+        Simplified::Never => Simplified::Always,
+        Simplified::Sometimes(cond) => Simplified::Sometimes(negate_condition(cond)),
+    }
+}
+
+/// Negates an already-simplified condition, preferring a positive form where one exists
+fn negate_condition(cond: Condition) -> Condition {
+    match cond {
+        Condition::Not(cond) => *cond,
+        Condition::DateLt(m, d) => Condition::DateGte(m, d),
+        Condition::DateLte(m, d) => Condition::DateGt(m, d),
+        Condition::DateGt(m, d) => Condition::DateLte(m, d),
+        Condition::DateGte(m, d) => Condition::DateLt(m, d),
+        cond => Condition::Not(Box::new(cond)),
+    }
+}
+
+fn conjunction<'a>(conds: impl IntoIterator<Item = &'a Condition>) -> Simplified {
+    let mut terms = Vec::new();
+    for cond in conds {
+        match cond.simplify() {
+            Simplified::Never => return Simplified::Never,
+            Simplified::Always => {}
+            Simplified::Sometimes(Condition::And(a, b)) => {
+                push_unique(&mut terms, *a);
+                push_unique(&mut terms, *b);
+            }
+            Simplified::Sometimes(Condition::All(conds)) => {
+                for cond in conds {
+                    push_unique(&mut terms, cond);
+                }
+            }
+            Simplified::Sometimes(cond) => push_unique(&mut terms, cond),
+        }
+    }
+
+    if is_contradictory(&terms) {
+        return Simplified::Never;
+    }
+
+    match terms.len() {
+        0 => Simplified::Always,
+        1 => Simplified::Sometimes(terms.remove(0)),
+        2 => {
+            let b = terms.remove(1);
+            let a = terms.remove(0);
+            Simplified::Sometimes(Condition::And(Box::new(a), Box::new(b)))
+        }
+        _ => Simplified::Sometimes(Condition::All(terms)),
+    }
+}
+
+fn disjunction<'a>(conds: impl IntoIterator<Item = &'a Condition>) -> Simplified {
+    let mut terms = Vec::new();
+    for cond in conds {
+        match cond.simplify() {
+            Simplified::Always => return Simplified::Always,
+            Simplified::Never => {}
+            Simplified::Sometimes(Condition::Or(a, b)) => {
+                push_unique(&mut terms, *a);
+                push_unique(&mut terms, *b);
+            }
+            Simplified::Sometimes(Condition::Any(conds)) => {
+                for cond in conds {
+                    push_unique(&mut terms, cond);
+                }
+            }
+            Simplified::Sometimes(cond) => push_unique(&mut terms, cond),
+        }
+    }
+
+    let is_tautology = terms
+        .iter()
+        .any(|term| terms.contains(&negate_condition(term.clone())));
+    if is_tautology {
+        return Simplified::Always;
+    }
+
+    match terms.len() {
+        0 => Simplified::Never,
+        1 => Simplified::Sometimes(terms.remove(0)),
+        2 => {
+            let b = terms.remove(1);
+            let a = terms.remove(0);
+            Simplified::Sometimes(Condition::Or(Box::new(a), Box::new(b)))
+        }
+        _ => Simplified::Sometimes(Condition::Any(terms)),
+    }
+}
+
+fn push_unique(terms: &mut Vec<Condition>, cond: Condition) {
+    if !terms.contains(&cond) {
+        terms.push(cond);
+    }
+}
+
+/// Whether a set of simplified conditions can never all be met at once
+fn is_contradictory(terms: &[Condition]) -> bool {
+    let pairwise = terms.iter().enumerate().any(|(idx, a)| {
+        terms[idx + 1..]
+            .iter()
+            .any(|b| conflicts(a, b) || conflicts(b, a))
+    });
+    pairwise || date_range_is_empty(terms)
+}
+
+/// Whether two conditions can never both be met. Not symmetric; check both orders.
+fn conflicts(a: &Condition, b: &Condition) -> bool {
+    match (a, b) {
+        (cond, Condition::Not(negated)) => cond == negated.as_ref(),
+        (Condition::Day(a), Condition::Day(b)) => a != b,
+        (Condition::Feast(a), Condition::Feast(b)) => a != b,
+        (Condition::Day(id), Condition::Feast(feast)) => match id {
+            LiturgicalDayId::Feast(observed) | LiturgicalDayId::TransferredFeast(observed) => {
+                observed != feast
+            }
+            _ => true,
+        },
+        (Condition::Week(a), Condition::Week(b)) => a != b,
+        (Condition::Weekday(a), Condition::Weekday(b)) => a != b,
+        (Condition::DayOfMonth(a), Condition::DayOfMonth(b)) => a != b,
+        (Condition::ObservedSeason(a), Condition::ObservedSeason(b)) => a != b,
+        // `Season` matches either the observed or the base season, but if the observed season is a "true" season,
+        // it matches only that season (see `Condition::include`)
+        (Condition::Season(a), Condition::Season(b)) => {
+            a != b && a.is_true_season() && b.is_true_season()
+        }
+        (Condition::ObservedSeason(observed), Condition::Season(season)) => {
+            observed != season && observed.is_true_season()
+        }
+        (Condition::Preference(a_key, a_value), Condition::Preference(b_key, b_value)) => {
+            a_key == b_key && a_value != b_value
+        }
+        _ => false,
+    }
+}
+
+/// Whether the date bounds among a set of conditions leave no possible date
+fn date_range_is_empty(terms: &[Condition]) -> bool {
+    let mut earliest = 1;
+    let mut latest = DAYS_IN_LEAP_YEAR;
+    for term in terms {
+        match term {
+            Condition::DateLt(m, d) => latest = latest.min(ordinal(*m, *d).saturating_sub(1)),
+            Condition::DateLte(m, d) => latest = latest.min(ordinal(*m, *d)),
+            Condition::DateGt(m, d) => earliest = earliest.max(ordinal(*m, *d) + 1),
+            Condition::DateGte(m, d) => earliest = earliest.max(ordinal(*m, *d)),
+            _ => {}
+        }
+    }
+    earliest > latest
+}
+
+fn ordinal(month: u8, day: u8) -> u16 {
+    let month = (month.clamp(1, 12) - 1) as usize;
+    DAYS_BEFORE_MONTH[month] + day as u16
+}
+
+impl Document {
+    /// Finds every sub-document whose [Condition] can never be met, is always met,
+    /// or can never be met together with the conditions of the documents that contain it.
+    /// ```
+    /// # use liturgy::{Condition, ConditionProblem, Document, Series, Text};
+    /// # use calendar::Season;
+    /// let doc = Document::from(Series::from([
+    ///     Document::from(Series::from([
+    ///         Document::from(Text::from("Alleluia")).condition(Condition::Season(Season::Lent))
+    ///     ]))
+    ///     .condition(Condition::Season(Season::Easter)),
+    /// ]));
+    /// let diagnostics = doc.analyze_conditions();
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].path, vec![0, 0]);
+    /// assert_eq!(diagnostics[0].problem, ConditionProblem::ContradictsParent);
+    /// ```
+    pub fn analyze_conditions(&self) -> Vec<ConditionDiagnostic> {
+        self.conditioned_subdocuments()
+            .into_iter()
+            .filter_map(|(path, ancestors, condition)| {
+                let problem = match condition.simplify() {
+                    Simplified::Never => Some(ConditionProblem::Unsatisfiable),
+                    Simplified::Always => Some(ConditionProblem::AlwaysTrue),
+                    Simplified::Sometimes(_) if !ancestors.is_empty() => {
+                        let combined = Condition::All(
+                            ancestors
+                                .into_iter()
+                                .chain(std::iter::once(condition))
+                                .cloned()
+                                .collect(),
+                        );
+                        (combined.simplify() == Simplified::Never)
+                            .then_some(ConditionProblem::ContradictsParent)
+                    }
+                    Simplified::Sometimes(_) => None,
+                };
+                problem.map(|problem| ConditionDiagnostic {
+                    path,
+                    condition: condition.clone(),
+                    problem,
+                })
+            })
+            .collect()
+    }
+
+    /// For each sub-document that has a [Condition], finds the dates in the given year on which it would be included
+    /// (at either a morning or an evening service), taking into account the conditions of the documents that contain it.
+    /// ```
+    /// # use liturgy::{Condition, Document, LiturgyPreferences, PreferenceKey, PreferenceValue, Series, Text};
+    /// # use calendar::{Date, Feast, BCP1979_CALENDAR};
+    /// # let prefs : [(PreferenceKey, PreferenceValue); 0] = [];
+    /// let doc = Document::from(Series::from([
+    ///     Document::from(Text::from("Arise, shine, for your light has come.")).condition(Condition::Feast(Feast::Epiphany)),
+    /// ]));
+    /// let coverage = doc.date_coverage(&BCP1979_CALENDAR, 2023, &prefs, &LiturgyPreferences::default());
+    /// assert_eq!(coverage[0].path, vec![0]);
+    /// assert_eq!(coverage[0].dates.iter().collect::<Vec<_>>(), vec![&Date::from_ymd(2023, 1, 6)]);
+    /// ```
+    pub fn date_coverage(
+        &self,
+        calendar: &Calendar,
+        year: u16,
+        prefs: &impl ClientPreferences,
+        liturgy_prefs: &LiturgyPreferences,
+    ) -> Vec<DateCoverage> {
+        let first = Date::from_ymd(year, 1, 1);
+        let days = (0..DAYS_IN_LEAP_YEAR)
+            .map(|delta| first.add_days(delta))
+            .take_while(|date| date.year() == year)
+            .map(|date| {
+                [
+                    calendar.liturgical_day(date, false),
+                    calendar.liturgical_day(date, true),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let included = |conditions: &[&Condition], day: &LiturgicalDay| {
+            conditions
+                .iter()
+                .all(|condition| condition.include(calendar, day, prefs, liturgy_prefs))
+        };
+
+        self.conditioned_subdocuments()
+            .into_iter()
+            .map(|(path, mut conditions, condition)| {
+                conditions.push(condition);
+                let dates = days
+                    .iter()
+                    .filter(|times| times.iter().any(|day| included(&conditions, day)))
+                    .map(|times| times[0].date)
+                    .collect();
+                DateCoverage {
+                    path,
+                    condition: condition.clone(),
+                    dates,
+                }
+            })
+            .collect()
+    }
+
+    /// Every sub-document (including this one) that has a condition, with its path,
+    /// the conditions of the documents that contain it, and its own condition
+    fn conditioned_subdocuments(&self) -> Vec<(Vec<usize>, Vec<&Condition>, &Condition)> {
+        fn visit<'a>(
+            doc: &'a Document,
+            path: Vec<usize>,
+            ancestors: Vec<&'a Condition>,
+            found: &mut Vec<(Vec<usize>, Vec<&'a Condition>, &'a Condition)>,
+        ) {
+            let mut ancestors_of_children = ancestors.clone();
+            if let Some(condition) = &doc.condition {
+                found.push((path.clone(), ancestors, condition));
+                ancestors_of_children.push(condition);
+            }
+
+            let children = match &doc.content {
+                Content::Liturgy(liturgy) => liturgy.body.as_slice(),
+                Content::Series(series) => series.as_slice(),
+                Content::Parallel(parallel) => parallel.as_slice(),
+                Content::Choice(choice) => choice.options.as_slice(),
+                _ => &[],
+            };
+            for (idx, child) in children.iter().enumerate() {
+                let mut child_path = path.clone();
+                child_path.push(idx);
+                visit(child, child_path, ancestors_of_children.clone(), found);
+            }
+        }
+
+        let mut found = Vec::new();
+        visit(self, Vec::new(), Vec::new(), &mut found);
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calendar::{Feast, LiturgicalWeek, Season, Weekday};
+
+    use super::*;
+    use crate::{GlobalPref, PreferenceKey, PreferenceValue};
+
+    fn and(a: Condition, b: Condition) -> Condition {
+        Condition::And(Box::new(a), Box::new(b))
+    }
+
+    fn not(a: Condition) -> Condition {
+        Condition::Not(Box::new(a))
+    }
+
+    #[test]
+    fn detects_contradictions() {
+        assert_eq!(
+            and(
+                Condition::Season(Season::Lent),
+                Condition::Season(Season::Easter)
+            )
+            .simplify(),
+            Simplified::Never
+        );
+        assert_eq!(
+            and(
+                Condition::Weekday(Weekday::Sun),
+                Condition::Weekday(Weekday::Mon)
+            )
+            .simplify(),
+            Simplified::Never
+        );
+        assert_eq!(
+            and(Condition::Evening, not(Condition::Evening)).simplify(),
+            Simplified::Never
+        );
+        assert_eq!(
+            and(
+                Condition::Feast(Feast::Epiphany),
+                Condition::Day(LiturgicalDayId::WeekAndDay(
+                    LiturgicalWeek::Lent1,
+                    Weekday::Sun
+                ))
+            )
+            .simplify(),
+            Simplified::Never
+        );
+        assert_eq!(
+            and(
+                Condition::Preference(
+                    PreferenceKey::from(GlobalPref::OmitForeOffice),
+                    PreferenceValue::Bool(true)
+                ),
+                Condition::Preference(
+                    PreferenceKey::from(GlobalPref::OmitForeOffice),
+                    PreferenceValue::Bool(false)
+                )
+            )
+            .simplify(),
+            Simplified::Never
+        );
+        // 2/1 < date < 2/2 has no days in it
+        assert_eq!(
+            and(Condition::DateGt(2, 1), Condition::DateLt(2, 2)).simplify(),
+            Simplified::Never
+        );
+        // ...but 2/1 <= date < 2/2 does
+        assert_eq!(
+            and(Condition::DateGte(2, 1), Condition::DateLt(2, 2)).simplify(),
+            Simplified::Sometimes(and(Condition::DateGte(2, 1), Condition::DateLt(2, 2)))
+        );
+    }
+
+    #[test]
+    fn seasons_that_can_overlap_are_not_contradictions() {
+        // a saint's day in Lent matches both
+        let saint_in_lent = and(
+            Condition::Season(Season::Saints),
+            Condition::Season(Season::Lent),
+        );
+        assert_eq!(
+            saint_in_lent.simplify(),
+            Simplified::Sometimes(saint_in_lent.clone())
+        );
+    }
+
+    #[test]
+    fn simplifies_nested_logic() {
+        let cond = Condition::Any(vec![
+            not(not(Condition::Season(Season::Lent))),
+            Condition::Or(
+                Box::new(Condition::Season(Season::Lent)),
+                Box::new(Condition::Weekday(Weekday::Fri)),
+            ),
+            and(Condition::Evening, not(Condition::Evening)),
+        ]);
+        assert_eq!(
+            cond.simplify(),
+            Simplified::Sometimes(Condition::Or(
+                Box::new(Condition::Season(Season::Lent)),
+                Box::new(Condition::Weekday(Weekday::Fri))
+            ))
+        );
+        assert_eq!(
+            not(Condition::DateLt(3, 1)).simplify(),
+            Simplified::Sometimes(Condition::DateGte(3, 1))
+        );
+        assert_eq!(
+            Condition::None(vec![Condition::DateLt(1, 1)]).simplify(),
+            Simplified::Always
+        );
+    }
+
+    #[test]
+    fn date_coverage_includes_parent_conditions() {
+        let prefs: [(PreferenceKey, PreferenceValue); 0] = [];
+        let doc = Document::from(crate::Series::from([Document::from(crate::Series::from(
+            [Document::from(crate::Text::from("Friday in Lent"))
+                .condition(Condition::Weekday(Weekday::Fri))],
+        ))
+        .condition(Condition::Season(Season::Lent))]));
+        let coverage = doc.date_coverage(
+            &calendar::BCP1979_CALENDAR,
+            2023,
+            &prefs,
+            &LiturgyPreferences::default(),
+        );
+        assert_eq!(coverage.len(), 2);
+        // the First Sunday in Lent 2023 was February 26
+        assert_eq!(
+            coverage[1].dates.iter().next(),
+            Some(&Date::from_ymd(2023, 3, 3))
+        );
+        assert!(coverage[1]
+            .dates
+            .iter()
+            .all(|date| date.weekday() == Weekday::Fri));
+        assert_eq!(coverage[1].dates.len(), 5);
+    }
+}
@@ -0,0 +1,1014 @@
+use std::{fmt::Display, str::FromStr};
+
+use calendar::{LiturgicalDayId, LiturgicalWeek, Proper, VariousOccasions, Weekday};
+use language::Language;
+use lectionary::{ReadingType, WeekdayCycle};
+use thiserror::Error;
+
+use crate::{
+    CanticleTables, ChantStyle, ChantTone, Condition, GlobalPref, Lectionaries, Names,
+    PreferenceKey, PreferenceValue, Pronouns, PsalmRecitation, SlugPath, Version,
+};
+
+/// An error encountered while parsing the text syntax for a [Condition].
+/// Columns are counted in characters, starting from 1.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConditionParseError {
+    #[error("unexpected character `{found}` at column {column}")]
+    UnexpectedCharacter { found: char, column: usize },
+    #[error("unterminated string starting at column {column}")]
+    UnterminatedString { column: usize },
+    #[error("expected {expected} at column {column}, but found {found}")]
+    Expected {
+        expected: &'static str,
+        found: String,
+        column: usize,
+    },
+    #[error("unknown condition `{name}` at column {column}")]
+    UnknownCondition { name: String, column: usize },
+    #[error("`{value}` is not a valid {kind} (at column {column})")]
+    InvalidValue {
+        kind: &'static str,
+        value: String,
+        column: usize,
+    },
+}
+
+impl ConditionParseError {
+    /// The column at which the error occurred
+    pub fn column(&self) -> usize {
+        match self {
+            ConditionParseError::UnexpectedCharacter { column, .. }
+            | ConditionParseError::UnterminatedString { column }
+            | ConditionParseError::Expected { column, .. }
+            | ConditionParseError::UnknownCondition { column, .. }
+            | ConditionParseError::InvalidValue { column, .. } => *column,
+        }
+    }
+}
+
+/// Parses the compact text syntax for [Condition]s, so they can be written by hand and stored in configuration files.
+/// [Condition]'s `Display` implementation prints the same syntax.
+///
+/// Conditions are combined with `and`, `or`, and `not` (in increasing order of precedence), and can be grouped
+/// with parentheses. `all(…)`, `any(…)`, and `none(…)` take a comma-separated list of conditions.
+///
+/// | Syntax                                   | Condition                        |
+/// |------------------------------------------|----------------------------------|
+/// | `day(Feast(GoodFriday))`                 | [Condition::Day]                 |
+/// | `day(WeekAndDay(Lent1, Sun))`            | [Condition::Day]                 |
+/// | `feast(GoodFriday)`                      | [Condition::Feast]               |
+/// | `season(Lent)`                           | [Condition::Season]              |
+/// | `observed_season(Lent)`                  | [Condition::ObservedSeason]      |
+/// | `week(HolyWeek)`                         | [Condition::Week]                |
+/// | `weekday(Sun)`                           | [Condition::Weekday]             |
+/// | `evening`                                | [Condition::Evening]             |
+/// | `rank_gte(HolyDay)`                      | [Condition::RankGte]             |
+/// | `date < 2/2`, `date <= 2/2`, `date > 2/2`, `date >= 2/2` | [Condition::DateLt] etc. |
+/// | `day_of_month(25)`                       | [Condition::DayOfMonth]          |
+/// | `pref(OmitForeOffice=true)`              | [Condition::Preference]          |
+/// | `pref("local-key"="value")`              | [Condition::Preference]          |
+/// | `pref(Lectionary=Lectionary(RCLTrack1))` | [Condition::Preference]          |
+///
+/// ```
+/// # use liturgy::{Condition, PreferenceKey, PreferenceValue, GlobalPref};
+/// # use calendar::{Season, Weekday};
+/// let condition: Condition = "season(Lent) and not weekday(Sun) or pref(OmitForeOffice=true)"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(
+///     condition,
+///     Condition::Or(
+///         Box::new(Condition::And(
+///             Box::new(Condition::Season(Season::Lent)),
+///             Box::new(Condition::Not(Box::new(Condition::Weekday(Weekday::Sun))))
+///         )),
+///         Box::new(Condition::Preference(
+///             PreferenceKey::from(GlobalPref::OmitForeOffice),
+///             PreferenceValue::Bool(true)
+///         ))
+///     )
+/// );
+/// assert_eq!(
+///     condition.to_string(),
+///     "season(Lent) and not weekday(Sun) or pref(OmitForeOffice=true)"
+/// );
+/// ```
+impl FromStr for Condition {
+    type Err = ConditionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            pos: 0,
+        };
+        let condition = parser.expression()?;
+        parser.expect_end()?;
+        Ok(condition)
+    }
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_text(f, Precedence::Or)
+    }
+}
+
+/// How tightly an operator binds; a child expression must be parenthesized if it binds more loosely than its position requires
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Or,
+    And,
+    Unary,
+}
+
+impl Condition {
+    fn precedence(&self) -> Precedence {
+        match self {
+            Condition::Or(_, _) => Precedence::Or,
+            Condition::And(_, _) => Precedence::And,
+            _ => Precedence::Unary,
+        }
+    }
+
+    fn write_text(&self, f: &mut std::fmt::Formatter<'_>, min: Precedence) -> std::fmt::Result {
+        let parenthesize = self.precedence() < min;
+        if parenthesize {
+            write!(f, "(")?;
+        }
+        match self {
+            Condition::Day(id) => {
+                write!(f, "day(")?;
+                write_day_id(f, id)?;
+                write!(f, ")")?;
+            }
+            Condition::Feast(feast) => write!(f, "feast({})", feast)?,
+            Condition::Season(season) => write!(f, "season({})", season)?,
+            Condition::ObservedSeason(season) => write!(f, "observed_season({})", season)?,
+            Condition::Week(week) => write!(f, "week({})", week)?,
+            Condition::Weekday(weekday) => write!(f, "weekday({})", weekday)?,
+            Condition::Evening => write!(f, "evening")?,
+            Condition::RankGte(rank) => write!(f, "rank_gte({})", rank)?,
+            Condition::DateLt(m, d) => write!(f, "date < {}/{}", m, d)?,
+            Condition::DateLte(m, d) => write!(f, "date <= {}/{}", m, d)?,
+            Condition::DateGt(m, d) => write!(f, "date > {}/{}", m, d)?,
+            Condition::DateGte(m, d) => write!(f, "date >= {}/{}", m, d)?,
+            Condition::DayOfMonth(d) => write!(f, "day_of_month({})", d)?,
+            Condition::Preference(key, value) => {
+                write!(f, "pref(")?;
+                match key {
+                    PreferenceKey::Global(key) => write!(f, "{}", key.as_ref())?,
+                    PreferenceKey::Local(key) => write_quoted(f, key)?,
+                }
+                write!(f, "=")?;
+                write_preference_value(f, value)?;
+                write!(f, ")")?;
+            }
+            Condition::Not(cond) => {
+                write!(f, "not ")?;
+                cond.write_text(f, Precedence::Unary)?;
+            }
+            // both operators are left-associative, so only a right-hand child of the same kind needs parentheses
+            Condition::And(a, b) => {
+                a.write_text(f, Precedence::And)?;
+                write!(f, " and ")?;
+                b.write_text(f, Precedence::Unary)?;
+            }
+            Condition::Or(a, b) => {
+                a.write_text(f, Precedence::Or)?;
+                write!(f, " or ")?;
+                b.write_text(f, Precedence::And)?;
+            }
+            Condition::Any(conds) => write_list(f, "any", conds)?,
+            Condition::All(conds) => write_list(f, "all", conds)?,
+            Condition::None(conds) => write_list(f, "none", conds)?,
+        }
+        if parenthesize {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+fn write_list(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    conds: &[Condition],
+) -> std::fmt::Result {
+    write!(f, "{}(", name)?;
+    for (idx, cond) in conds.iter().enumerate() {
+        if idx > 0 {
+            write!(f, ", ")?;
+        }
+        cond.write_text(f, Precedence::Or)?;
+    }
+    write!(f, ")")
+}
+
+fn write_day_id(f: &mut std::fmt::Formatter<'_>, id: &LiturgicalDayId) -> std::fmt::Result {
+    match id {
+        LiturgicalDayId::Feast(feast) => write!(f, "Feast({})", feast),
+        LiturgicalDayId::WeekAndDay(week, weekday) => {
+            write!(f, "WeekAndDay({}, {})", week, weekday)
+        }
+        LiturgicalDayId::ProperAndDay(proper, weekday) => {
+            write!(f, "ProperAndDay({}, {})", proper, weekday)
+        }
+        LiturgicalDayId::TransferredFeast(feast) => write!(f, "TransferredFeast({})", feast),
+        LiturgicalDayId::DayOfMonth(d) => write!(f, "DayOfMonth({})", d),
+        LiturgicalDayId::VariousOccasions(occasion) => write!(f, "VariousOccasions({})", occasion),
+    }
+}
+
+fn write_preference_value(
+    f: &mut std::fmt::Formatter<'_>,
+    value: &PreferenceValue,
+) -> std::fmt::Result {
+    match value {
+        PreferenceValue::Bool(value) => write!(f, "{}", value),
+        PreferenceValue::Local(value) => write_quoted(f, value),
+        PreferenceValue::Language(language) => write!(f, "Language({})", language),
+        // `Version`'s `Display` impl gives a human-readable label, so use the variant name instead
+        PreferenceValue::Version(version) => write!(f, "Version({:?})", version),
+        PreferenceValue::Lectionary(lectionary) => write!(f, "Lectionary({})", lectionary),
+        PreferenceValue::WeekdayCycle(cycle) => write!(f, "WeekdayCycle({})", cycle),
+        PreferenceValue::CanticleTable(table) => write!(f, "CanticleTable({})", table),
+        PreferenceValue::ReadingType(reading_type) => write!(f, "ReadingType({})", reading_type),
+        PreferenceValue::PsalmRecitation(PsalmRecitation::Responsorial(every)) => {
+            write!(f, "PsalmRecitation(Responsorial({}))", every)
+        }
+        PreferenceValue::PsalmRecitation(recitation) => {
+            write!(f, "PsalmRecitation({:?})", recitation)
+        }
+        PreferenceValue::ChantTone(tone) => {
+            write!(
+                f,
+                "ChantTone({:?}, {}, {}",
+                tone.style, tone.mediation, tone.termination
+            )?;
+            if let Some(flex_after) = tone.flex_after {
+                write!(f, ", {}", flex_after)?;
+            }
+            write!(f, ")")
+        }
+        PreferenceValue::Names(Names { names, pronouns }) => {
+            write!(f, "Names({:?}", pronouns)?;
+            for name in names {
+                write!(f, ", ")?;
+                write_quoted(f, name)?;
+            }
+            write!(f, ")")
+        }
+        PreferenceValue::Canticle(path) => {
+            write!(f, "Canticle(")?;
+            write_quoted(f, &path.to_string())?;
+            write!(f, ")")
+        }
+        PreferenceValue::CycleOfPrayer(id) => {
+            write!(f, "CycleOfPrayer(")?;
+            write_quoted(f, id)?;
+            write!(f, ")")
+        }
+    }
+}
+
+fn write_quoted(f: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+    write!(f, "\"")?;
+    for ch in s.chars() {
+        if ch == '"' || ch == '\\' {
+            write!(f, "\\")?;
+        }
+        write!(f, "{}", ch)?;
+    }
+    write!(f, "\"")
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    Number(u32),
+    Str(String),
+    LParen,
+    RParen,
+    Comma,
+    Equals,
+    Slash,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    End,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Ident(ident) => format!("`{}`", ident),
+            Token::Number(n) => format!("`{}`", n),
+            Token::Str(s) => format!("string {:?}", s),
+            Token::LParen => "`(`".into(),
+            Token::RParen => "`)`".into(),
+            Token::Comma => "`,`".into(),
+            Token::Equals => "`=`".into(),
+            Token::Slash => "`/`".into(),
+            Token::Lt => "`<`".into(),
+            Token::Lte => "`<=`".into(),
+            Token::Gt => "`>`".into(),
+            Token::Gte => "`>=`".into(),
+            Token::End => "end of input".into(),
+        }
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<(Token, usize)>, ConditionParseError> {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let ch = chars[idx];
+        let column = idx + 1;
+        if ch.is_whitespace() {
+            idx += 1;
+            continue;
+        }
+
+        let token = match ch {
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            '=' => Token::Equals,
+            '/' => Token::Slash,
+            '<' | '>' => {
+                let or_equal = chars.get(idx + 1) == Some(&'=');
+                if or_equal {
+                    idx += 1;
+                }
+                match (ch, or_equal) {
+                    ('<', false) => Token::Lt,
+                    ('<', true) => Token::Lte,
+                    ('>', false) => Token::Gt,
+                    _ => Token::Gte,
+                }
+            }
+            '"' => {
+                let mut value = String::new();
+                let mut closed = false;
+                idx += 1;
+                while idx < chars.len() {
+                    match chars[idx] {
+                        '"' => {
+                            closed = true;
+                            break;
+                        }
+                        '\\' if idx + 1 < chars.len() => {
+                            value.push(chars[idx + 1]);
+                            idx += 2;
+                        }
+                        other => {
+                            value.push(other);
+                            idx += 1;
+                        }
+                    }
+                }
+                if !closed {
+                    return Err(ConditionParseError::UnterminatedString { column });
+                }
+                Token::Str(value)
+            }
+            _ if ch.is_ascii_digit() => {
+                let start = idx;
+                while idx + 1 < chars.len() && chars[idx + 1].is_ascii_digit() {
+                    idx += 1;
+                }
+                let digits = chars[start..=idx].iter().collect::<String>();
+                let n = digits
+                    .parse()
+                    .map_err(|_| ConditionParseError::InvalidValue {
+                        kind: "number",
+                        value: digits.clone(),
+                        column,
+                    })?;
+                Token::Number(n)
+            }
+            _ if ch.is_alphabetic() || ch == '_' => {
+                let start = idx;
+                while idx + 1 < chars.len()
+                    && (chars[idx + 1].is_alphanumeric() || chars[idx + 1] == '_')
+                {
+                    idx += 1;
+                }
+                Token::Ident(chars[start..=idx].iter().collect())
+            }
+            _ => return Err(ConditionParseError::UnexpectedCharacter { found: ch, column }),
+        };
+        tokens.push((token, column));
+        idx += 1;
+    }
+
+    tokens.push((Token::End, chars.len() + 1));
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &(Token, usize) {
+        &self.tokens[self.pos.min(self.tokens.len() - 1)]
+    }
+
+    fn next(&mut self) -> (Token, usize) {
+        let token = self.peek().clone();
+        if self.pos < self.tokens.len() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(&self.peek().0, Token::Ident(ident) if ident == keyword)
+    }
+
+    fn error(expected: &'static str, (found, column): &(Token, usize)) -> ConditionParseError {
+        ConditionParseError::Expected {
+            expected,
+            found: found.describe(),
+            column: *column,
+        }
+    }
+
+    fn expect(&mut self, token: Token, expected: &'static str) -> Result<(), ConditionParseError> {
+        let next = self.next();
+        if next.0 == token {
+            Ok(())
+        } else {
+            Err(Self::error(expected, &next))
+        }
+    }
+
+    fn expect_end(&mut self) -> Result<(), ConditionParseError> {
+        self.expect(Token::End, "`and`, `or`, or end of input")
+    }
+
+    fn expression(&mut self) -> Result<Condition, ConditionParseError> {
+        let mut condition = self.and()?;
+        while self.peek_keyword("or") {
+            self.next();
+            let rhs = self.and()?;
+            condition = Condition::Or(Box::new(condition), Box::new(rhs));
+        }
+        Ok(condition)
+    }
+
+    fn and(&mut self) -> Result<Condition, ConditionParseError> {
+        let mut condition = self.unary()?;
+        while self.peek_keyword("and") {
+            self.next();
+            let rhs = self.unary()?;
+            condition = Condition::And(Box::new(condition), Box::new(rhs));
+        }
+        Ok(condition)
+    }
+
+    fn unary(&mut self) -> Result<Condition, ConditionParseError> {
+        if self.peek_keyword("not") {
+            self.next();
+            Ok(Condition::Not(Box::new(self.unary()?)))
+        } else {
+            self.primary()
+        }
+    }
+
+    fn primary(&mut self) -> Result<Condition, ConditionParseError> {
+        let next = self.next();
+        let (name, column) = match next {
+            (Token::LParen, _) => {
+                let condition = self.expression()?;
+                self.expect(Token::RParen, "`)`")?;
+                return Ok(condition);
+            }
+            (Token::Ident(ref name), column) if !["and", "or", "not"].contains(&name.as_str()) => {
+                (name.clone(), column)
+            }
+            _ => return Err(Self::error("a condition", &next)),
+        };
+
+        let condition = match name.as_str() {
+            "evening" => Condition::Evening,
+            "date" => {
+                let comparison = self.next();
+                let (month, day) = self.month_and_day()?;
+                match comparison.0 {
+                    Token::Lt => Condition::DateLt(month, day),
+                    Token::Lte => Condition::DateLte(month, day),
+                    Token::Gt => Condition::DateGt(month, day),
+                    Token::Gte => Condition::DateGte(month, day),
+                    _ => return Err(Self::error("`<`, `<=`, `>`, or `>=`", &comparison)),
+                }
+            }
+            "any" | "all" | "none" => {
+                self.expect(Token::LParen, "`(`")?;
+                let mut conds = Vec::new();
+                if self.peek().0 == Token::RParen {
+                    self.next();
+                } else {
+                    loop {
+                        conds.push(self.expression()?);
+                        let next = self.next();
+                        match next.0 {
+                            Token::Comma => continue,
+                            Token::RParen => break,
+                            _ => return Err(Self::error("`,` or `)`", &next)),
+                        }
+                    }
+                }
+                match name.as_str() {
+                    "any" => Condition::Any(conds),
+                    "all" => Condition::All(conds),
+                    _ => Condition::None(conds),
+                }
+            }
+            "day" => self.parenthesized(|parser| parser.day_id().map(Condition::Day))?,
+            "feast" => self.parenthesized(|parser| parser.value("feast").map(Condition::Feast))?,
+            "season" => {
+                self.parenthesized(|parser| parser.value("season").map(Condition::Season))?
+            }
+            "observed_season" => {
+                self.parenthesized(|parser| parser.value("season").map(Condition::ObservedSeason))?
+            }
+            "week" => self.parenthesized(|parser| parser.value("week").map(Condition::Week))?,
+            "weekday" => {
+                self.parenthesized(|parser| parser.value("weekday").map(Condition::Weekday))?
+            }
+            "rank_gte" => {
+                self.parenthesized(|parser| parser.value("rank").map(Condition::RankGte))?
+            }
+            "day_of_month" => self.parenthesized(|parser| {
+                parser
+                    .number_in_range("day of the month", 1..=31)
+                    .map(Condition::DayOfMonth)
+            })?,
+            "pref" => self.parenthesized(|parser| {
+                let key = parser.preference_key()?;
+                parser.expect(Token::Equals, "`=`")?;
+                let value = parser.preference_value()?;
+                Ok(Condition::Preference(key, value))
+            })?,
+            _ => return Err(ConditionParseError::UnknownCondition { name, column }),
+        };
+        Ok(condition)
+    }
+
+    fn parenthesized<T>(
+        &mut self,
+        inner: impl FnOnce(&mut Self) -> Result<T, ConditionParseError>,
+    ) -> Result<T, ConditionParseError> {
+        self.expect(Token::LParen, "`(`")?;
+        let value = inner(self)?;
+        self.expect(Token::RParen, "`)`")?;
+        Ok(value)
+    }
+
+    fn ident(&mut self, expected: &'static str) -> Result<(String, usize), ConditionParseError> {
+        match self.next() {
+            (Token::Ident(ident), column) => Ok((ident, column)),
+            other => Err(Self::error(expected, &other)),
+        }
+    }
+
+    fn string(&mut self, expected: &'static str) -> Result<(String, usize), ConditionParseError> {
+        match self.next() {
+            (Token::Str(s), column) => Ok((s, column)),
+            other => Err(Self::error(expected, &other)),
+        }
+    }
+
+    /// Parses a bare identifier as any type that can be parsed from its variant name
+    fn value<T: FromStr>(&mut self, kind: &'static str) -> Result<T, ConditionParseError> {
+        let (ident, column) = self.ident(kind)?;
+        T::from_str(&ident).map_err(|_| ConditionParseError::InvalidValue {
+            kind,
+            value: ident,
+            column,
+        })
+    }
+
+    fn number_in_range(
+        &mut self,
+        kind: &'static str,
+        range: std::ops::RangeInclusive<u32>,
+    ) -> Result<u8, ConditionParseError> {
+        match self.next() {
+            (Token::Number(n), _) if range.contains(&n) => Ok(n as u8),
+            (Token::Number(n), column) => Err(ConditionParseError::InvalidValue {
+                kind,
+                value: n.to_string(),
+                column,
+            }),
+            other => Err(Self::error(kind, &other)),
+        }
+    }
+
+    fn month_and_day(&mut self) -> Result<(u8, u8), ConditionParseError> {
+        let month = self.number_in_range("month", 1..=12)?;
+        self.expect(Token::Slash, "`/`")?;
+        let day = self.number_in_range("day of the month", 1..=31)?;
+        Ok((month, day))
+    }
+
+    fn day_id(&mut self) -> Result<LiturgicalDayId, ConditionParseError> {
+        let (variant, column) = self.ident("a liturgical day")?;
+        match variant.as_str() {
+            "Feast" => {
+                self.parenthesized(|parser| parser.value("feast").map(LiturgicalDayId::Feast))
+            }
+            "TransferredFeast" => self.parenthesized(|parser| {
+                parser.value("feast").map(LiturgicalDayId::TransferredFeast)
+            }),
+            "WeekAndDay" => self.parenthesized(|parser| {
+                let week: LiturgicalWeek = parser.value("week")?;
+                parser.expect(Token::Comma, "`,`")?;
+                let weekday: Weekday = parser.value("weekday")?;
+                Ok(LiturgicalDayId::WeekAndDay(week, weekday))
+            }),
+            "ProperAndDay" => self.parenthesized(|parser| {
+                let proper: Proper = parser.value("proper")?;
+                parser.expect(Token::Comma, "`,`")?;
+                let weekday: Weekday = parser.value("weekday")?;
+                Ok(LiturgicalDayId::ProperAndDay(proper, weekday))
+            }),
+            "DayOfMonth" => self.parenthesized(|parser| {
+                parser
+                    .number_in_range("day of the month", 1..=31)
+                    .map(LiturgicalDayId::DayOfMonth)
+            }),
+            "VariousOccasions" => self.parenthesized(|parser| {
+                parser
+                    .value::<VariousOccasions>("occasion")
+                    .map(LiturgicalDayId::VariousOccasions)
+            }),
+            _ => Err(ConditionParseError::InvalidValue {
+                kind: "liturgical day",
+                value: variant,
+                column,
+            }),
+        }
+    }
+
+    fn preference_key(&mut self) -> Result<PreferenceKey, ConditionParseError> {
+        match self.next() {
+            (Token::Str(key), _) => Ok(PreferenceKey::Local(key)),
+            (Token::Ident(key), column) => GlobalPref::from_str(&key)
+                .map(PreferenceKey::Global)
+                .map_err(|_| ConditionParseError::InvalidValue {
+                    kind: "global preference (local preference keys must be quoted)",
+                    value: key,
+                    column,
+                }),
+            other => Err(Self::error("a preference key", &other)),
+        }
+    }
+
+    fn preference_value(&mut self) -> Result<PreferenceValue, ConditionParseError> {
+        let (kind, column) = match self.next() {
+            (Token::Str(value), _) => return Ok(PreferenceValue::Local(value)),
+            (Token::Ident(kind), column) => (kind, column),
+            other => return Err(Self::error("a preference value", &other)),
+        };
+
+        match kind.as_str() {
+            "true" => Ok(PreferenceValue::Bool(true)),
+            "false" => Ok(PreferenceValue::Bool(false)),
+            "Bool" => self.parenthesized(|parser| match parser.ident("`true` or `false`")? {
+                (value, _) if value == "true" => Ok(PreferenceValue::Bool(true)),
+                (value, _) if value == "false" => Ok(PreferenceValue::Bool(false)),
+                (value, column) => Err(ConditionParseError::InvalidValue {
+                    kind: "boolean",
+                    value,
+                    column,
+                }),
+            }),
+            "Local" => self.parenthesized(|parser| {
+                parser
+                    .string("a quoted string")
+                    .map(|(value, _)| PreferenceValue::Local(value))
+            }),
+            "Language" => self.parenthesized(|parser| {
+                parser
+                    .value::<Language>("language")
+                    .map(PreferenceValue::Language)
+            }),
+            "Version" => self.parenthesized(|parser| {
+                parser
+                    .value::<Version>("version")
+                    .map(PreferenceValue::Version)
+            }),
+            "Lectionary" => self.parenthesized(|parser| {
+                parser
+                    .value::<Lectionaries>("lectionary")
+                    .map(PreferenceValue::Lectionary)
+            }),
+            "WeekdayCycle" => self.parenthesized(|parser| {
+                parser
+                    .value::<WeekdayCycle>("weekday cycle")
+                    .map(PreferenceValue::WeekdayCycle)
+            }),
+            "CanticleTable" => self.parenthesized(|parser| {
+                parser
+                    .value::<CanticleTables>("canticle table")
+                    .map(PreferenceValue::CanticleTable)
+            }),
+            "ReadingType" => self.parenthesized(|parser| {
+                parser
+                    .value::<ReadingType>("reading type")
+                    .map(PreferenceValue::ReadingType)
+            }),
+            "PsalmRecitation" => self.parenthesized(|parser| {
+                let (mode, column) = parser.ident("psalm recitation")?;
+                match mode.as_str() {
+                    "Unison" => Ok(PsalmRecitation::Unison),
+                    "ResponsiveByVerse" => Ok(PsalmRecitation::ResponsiveByVerse),
+                    "ResponsiveByHalfVerse" => Ok(PsalmRecitation::ResponsiveByHalfVerse),
+                    "Antiphonal" => Ok(PsalmRecitation::Antiphonal),
+                    "Responsorial" => parser
+                        .parenthesized(|parser| parser.number_in_range("number of verses", 1..=255))
+                        .map(PsalmRecitation::Responsorial),
+                    _ => Err(ConditionParseError::InvalidValue {
+                        kind: "psalm recitation",
+                        value: mode,
+                        column,
+                    }),
+                }
+                .map(PreferenceValue::PsalmRecitation)
+            }),
+            "ChantTone" => self.parenthesized(|parser| {
+                let (style, column) = parser.ident("chant style")?;
+                let style = match style.as_str() {
+                    "Anglican" => ChantStyle::Anglican,
+                    "Plainsong" => ChantStyle::Plainsong,
+                    _ => {
+                        return Err(ConditionParseError::InvalidValue {
+                            kind: "chant style",
+                            value: style,
+                            column,
+                        })
+                    }
+                };
+                parser.expect(Token::Comma, "`,`")?;
+                let mediation = parser.number_in_range("number of accents", 1..=255)?;
+                parser.expect(Token::Comma, "`,`")?;
+                let termination = parser.number_in_range("number of accents", 1..=255)?;
+                let flex_after = if parser.peek().0 == Token::Comma {
+                    parser.next();
+                    Some(parser.number_in_range("number of syllables", 1..=255)?)
+                } else {
+                    None
+                };
+                Ok(PreferenceValue::ChantTone(ChantTone {
+                    style,
+                    mediation: mediation.into(),
+                    termination: termination.into(),
+                    flex_after: flex_after.map(usize::from),
+                }))
+            }),
+            "Names" => self.parenthesized(|parser| {
+                let (pronouns, column) = parser.ident("pronouns")?;
+                let pronouns = match pronouns.as_str() {
+                    "She" => Pronouns::She,
+                    "He" => Pronouns::He,
+                    "They" => Pronouns::They,
+                    _ => {
+                        return Err(ConditionParseError::InvalidValue {
+                            kind: "pronouns",
+                            value: pronouns,
+                            column,
+                        })
+                    }
+                };
+                let mut names = Vec::new();
+                while parser.peek().0 == Token::Comma {
+                    parser.next();
+                    names.push(parser.string("a quoted name")?.0);
+                }
+                Ok(PreferenceValue::Names(Names { names, pronouns }))
+            }),
+            "Canticle" => self.parenthesized(|parser| {
+                let (path, column) = parser.string("a quoted slug path")?;
+                SlugPath::from_str(&path)
+                    .map(PreferenceValue::Canticle)
+                    .map_err(|_| ConditionParseError::InvalidValue {
+                        kind: "slug path",
+                        value: path,
+                        column,
+                    })
+            }),
+            "CycleOfPrayer" => self.parenthesized(|parser| {
+                let (id, _) = parser.string("a quoted cycle of prayer identifier")?;
+                Ok(PreferenceValue::CycleOfPrayer(id))
+            }),
+            _ => Err(ConditionParseError::InvalidValue {
+                kind: "preference value",
+                value: kind,
+                column,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calendar::{Feast, Rank, Season};
+
+    use super::*;
+
+    fn round_trip(condition: Condition) {
+        let text = condition.to_string();
+        assert_eq!(
+            text.parse::<Condition>(),
+            Ok(condition),
+            "failed to round-trip {}",
+            text
+        );
+    }
+
+    #[test]
+    fn round_trips_simple_conditions() {
+        round_trip(Condition::Day(LiturgicalDayId::Feast(Feast::GoodFriday)));
+        round_trip(Condition::Day(LiturgicalDayId::WeekAndDay(
+            LiturgicalWeek::Lent1,
+            Weekday::Sun,
+        )));
+        round_trip(Condition::Day(LiturgicalDayId::ProperAndDay(
+            Proper::Proper12,
+            Weekday::Wed,
+        )));
+        round_trip(Condition::Day(LiturgicalDayId::TransferredFeast(
+            Feast::AllSaintsDay,
+        )));
+        round_trip(Condition::Day(LiturgicalDayId::DayOfMonth(3)));
+        round_trip(Condition::Feast(Feast::Annunciation));
+        round_trip(Condition::Season(Season::Lent));
+        round_trip(Condition::ObservedSeason(Season::Saints));
+        round_trip(Condition::Week(LiturgicalWeek::HolyWeek));
+        round_trip(Condition::Weekday(Weekday::Sat));
+        round_trip(Condition::Evening);
+        round_trip(Condition::RankGte(Rank::HolyDay));
+        round_trip(Condition::DateLt(2, 2));
+        round_trip(Condition::DateLte(12, 24));
+        round_trip(Condition::DateGt(1, 6));
+        round_trip(Condition::DateGte(11, 1));
+        round_trip(Condition::DayOfMonth(31));
+    }
+
+    #[test]
+    fn round_trips_preferences() {
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::OmitForeOffice),
+            PreferenceValue::Bool(false),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::Lectionary),
+            PreferenceValue::from(Lectionaries::RCLTrack1),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::Version),
+            PreferenceValue::from(Version::RiteII),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::Language),
+            PreferenceValue::from(Language::Es),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::CanticleTable),
+            PreferenceValue::from(CanticleTables::EOW),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::ReadingA),
+            PreferenceValue::from(ReadingType::FirstReading),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::PsalmRecitation),
+            PreferenceValue::from(PsalmRecitation::Antiphonal),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::PsalmRecitation),
+            PreferenceValue::from(PsalmRecitation::Responsorial(4)),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::ChantTone),
+            PreferenceValue::from(ChantTone::ANGLICAN_SINGLE),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::ChantTone),
+            PreferenceValue::from(ChantTone::PLAINSONG_DOUBLE_ACCENT),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::CoupleNames),
+            PreferenceValue::from(Names::new(["Mary \"Molly\" Jones", "John"], Pronouns::They)),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::BishopName),
+            PreferenceValue::from(Names::new(Vec::<String>::new(), Pronouns::He)),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from(GlobalPref::DiocesanCycleOfPrayer),
+            PreferenceValue::CycleOfPrayer("diocese-of-california".into()),
+        ));
+        round_trip(Condition::Preference(
+            PreferenceKey::from("say-the-\"quoted\" \\ thing"),
+            PreferenceValue::Local("a \"quoted\" value".into()),
+        ));
+    }
+
+    #[test]
+    fn round_trips_nested_conditions() {
+        let a = || Box::new(Condition::Season(Season::Lent));
+        let b = || Box::new(Condition::Weekday(Weekday::Sun));
+        let c = || Box::new(Condition::Evening);
+        round_trip(Condition::And(Box::new(Condition::And(a(), b())), c()));
+        round_trip(Condition::And(a(), Box::new(Condition::And(b(), c()))));
+        round_trip(Condition::Or(a(), Box::new(Condition::Or(b(), c()))));
+        round_trip(Condition::And(Box::new(Condition::Or(a(), b())), c()));
+        round_trip(Condition::Or(Box::new(Condition::And(a(), b())), c()));
+        round_trip(Condition::Not(Box::new(Condition::Or(a(), b()))));
+        round_trip(Condition::Not(Box::new(Condition::Not(a()))));
+        round_trip(Condition::Any(vec![]));
+        round_trip(Condition::All(vec![*a(), Condition::Or(b(), c())]));
+        round_trip(Condition::None(vec![*a(), Condition::Not(b())]));
+    }
+
+    #[test]
+    fn prints_minimal_parentheses() {
+        let condition: Condition = "(season(Lent) and (weekday(Sun))) or (evening)"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            condition.to_string(),
+            "season(Lent) and weekday(Sun) or evening"
+        );
+        let condition: Condition = "season(Lent) and (weekday(Sun) or evening)"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            condition.to_string(),
+            "season(Lent) and (weekday(Sun) or evening)"
+        );
+    }
+
+    #[test]
+    fn reports_errors_with_columns() {
+        assert_eq!(
+            "season(Lnet)".parse::<Condition>(),
+            Err(ConditionParseError::InvalidValue {
+                kind: "season",
+                value: "Lnet".into(),
+                column: 8
+            })
+        );
+        assert_eq!(
+            "season(Lent) and".parse::<Condition>(),
+            Err(ConditionParseError::Expected {
+                expected: "a condition",
+                found: "end of input".into(),
+                column: 17
+            })
+        );
+        assert_eq!(
+            "seasn(Lent)".parse::<Condition>(),
+            Err(ConditionParseError::UnknownCondition {
+                name: "seasn".into(),
+                column: 1
+            })
+        );
+        assert_eq!(
+            "date < 13/1".parse::<Condition>(),
+            Err(ConditionParseError::InvalidValue {
+                kind: "month",
+                value: "13".into(),
+                column: 8
+            })
+        );
+        assert_eq!(
+            "pref(\"unclosed=true)".parse::<Condition>(),
+            Err(ConditionParseError::UnterminatedString { column: 6 })
+        );
+        assert_eq!(
+            "evening evening".parse::<Condition>(),
+            Err(ConditionParseError::Expected {
+                expected: "`and`, `or`, or end of input",
+                found: "`evening`".into(),
+                column: 9
+            })
+        );
+        assert_eq!(
+            "pref(NotAPref=true)"
+                .parse::<Condition>()
+                .unwrap_err()
+                .to_string(),
+            "`NotAPref` is not a valid global preference (local preference keys must be quoted) (at column 6)"
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use calendar::Date;
+use serde::{Deserialize, Serialize};
+
+use crate::{ClientPreferences, GlobalPref, PreferenceKey, PreferenceValue};
+
+/// The cycles in which the Church prays daily for part of the Anglican Communion or of the local diocese
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum CycleOfPrayerId {
+    /// The Anglican Cycle of Prayer, in which the provinces and dioceses of the Anglican Communion are remembered
+    Anglican,
+    /// A diocesan cycle of prayer, in which the congregations and ministries of the local diocese are remembered
+    Diocesan,
+}
+
+impl CycleOfPrayerId {
+    /// The [GlobalPref] in which a client supplies this cycle
+    pub fn preference(&self) -> GlobalPref {
+        match self {
+            CycleOfPrayerId::Anglican => GlobalPref::AnglicanCycleOfPrayer,
+            CycleOfPrayerId::Diocesan => GlobalPref::DiocesanCycleOfPrayer,
+        }
+    }
+
+    /// The identifier of the cycle chosen in the client’s preferences, if any, which the
+    /// [Library](library::Library) resolves to its [CycleOfPrayer]
+    pub fn chosen_in<'a>(&self, prefs: &'a impl ClientPreferences) -> Option<&'a str> {
+        match prefs.value(&PreferenceKey::from(self.preference())) {
+            Some(PreferenceValue::CycleOfPrayer(id)) => Some(id),
+            _ => None,
+        }
+    }
+}
+
+/// A cycle of prayer, in which particular churches, dioceses, or ministries are prayed for on particular days
+/// ```
+/// # use calendar::Date;
+/// # use liturgy::{CycleEntry, CycleKey, CycleOfPrayer, CycleRotation};
+/// let entry = |key, intercessions: &[&str]| CycleEntry {
+///     key,
+///     intercessions: intercessions.iter().map(|name| name.to_string()).collect(),
+/// };
+/// let cycle = CycleOfPrayer {
+///     name: "Diocese of Newark Cycle of Prayer".into(),
+///     entries: vec![
+///         entry(CycleKey::Date { month: 12, day: 25 }, &["the Cathedral"]),
+///         entry(CycleKey::Week(1), &["Christ Church, Ridgewood"]),
+///         entry(CycleKey::Index(0), &["All Saints’, Hoboken"]),
+///         entry(CycleKey::Index(1), &["Grace Church, Madison", "St. Paul’s, Chatham"]),
+///     ],
+///     rotation: Some(CycleRotation { start: Date::from_ymd(2023, 1, 1), days: 7 }),
+/// };
+/// assert_eq!(cycle.intercessions_for(Date::from_ymd(2023, 12, 25)), ["the Cathedral"]);
+/// assert_eq!(cycle.intercessions_for(Date::from_ymd(2023, 1, 3)), ["Christ Church, Ridgewood"]);
+/// assert_eq!(cycle.intercessions_for(Date::from_ymd(2023, 1, 10)), ["Grace Church, Madison", "St. Paul’s, Chatham"]);
+/// assert_eq!(cycle.intercessions_for(Date::from_ymd(2023, 1, 17)), ["All Saints’, Hoboken"]);
+/// ```
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CycleOfPrayer {
+    pub name: String,
+    pub entries: Vec<CycleEntry>,
+    /// How entries keyed by [CycleKey::Index] rotate; if `None`, they advance daily from January 1, 2000
+    #[serde(default)]
+    pub rotation: Option<CycleRotation>,
+}
+
+/// The churches, dioceses, or ministries prayed for on the days identified by the key
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CycleEntry {
+    pub key: CycleKey,
+    pub intercessions: Vec<String>,
+}
+
+/// The days on which a [CycleEntry] is used
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CycleKey {
+    /// The same day each year
+    Date { month: u8, day: u8 },
+    /// Each day of the same week each year, counting from January 1 (1–53)
+    Week(u8),
+    /// A position in a sequence that repeats without regard to the calendar year; see [CycleRotation]
+    Index(u16),
+}
+
+/// The pace at which the entries of a cycle keyed by [CycleKey::Index] advance
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CycleRotation {
+    /// The day on which index 0 is used
+    pub start: Date,
+    /// The number of days for which each index is used
+    pub days: u16,
+}
+
+impl Default for CycleRotation {
+    fn default() -> Self {
+        Self {
+            start: Date::from_ymd(2000, 1, 1),
+            days: 1,
+        }
+    }
+}
+
+impl CycleOfPrayer {
+    /// The intercessions for the given day. An entry for the particular date is used in preference
+    /// to one for its week, which is used in preference to the rotating sequence.
+    pub fn intercessions_for(&self, date: Date) -> &[String] {
+        self.entry_for(date)
+            .map(|entry| entry.intercessions.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn entry_for(&self, date: Date) -> Option<&CycleEntry> {
+        let week = (date.day_in_year() / 7 + 1) as u8;
+        self.entries
+            .iter()
+            .find(|entry| {
+                entry.key
+                    == CycleKey::Date {
+                        month: date.month(),
+                        day: date.day(),
+                    }
+            })
+            .or_else(|| {
+                self.entries
+                    .iter()
+                    .find(|entry| entry.key == CycleKey::Week(week))
+            })
+            .or_else(|| {
+                let indices = self
+                    .entries
+                    .iter()
+                    .filter_map(|entry| match entry.key {
+                        CycleKey::Index(index) => Some(index),
+                        _ => None,
+                    })
+                    .max()?;
+                let rotation = self.rotation.unwrap_or_default();
+                let elapsed = (date - rotation.start).num_days() / i64::from(rotation.days.max(1));
+                let index = elapsed.rem_euclid(i64::from(indices) + 1) as u16;
+                self.entries
+                    .iter()
+                    .find(|entry| entry.key == CycleKey::Index(index))
+            })
+    }
+}
+
+/// A lookup for the intercessions appointed for the day in a [CycleOfPrayer], which the
+/// [Library](library::Library) will compile into [Text](crate::Text) using the cycle chosen in
+/// the preference for [CycleOfPrayerId::preference]
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CycleOfPrayerLookup {
+    pub cycle: CycleOfPrayerId,
+    /// The text into which the intercessions are inserted, in place of its blank (___________)
+    pub template: String,
+}
+
+impl CycleOfPrayerLookup {
+    pub fn new(cycle: CycleOfPrayerId, template: impl ToString) -> Self {
+        Self {
+            cycle,
+            template: template.to_string(),
+        }
+    }
+
+    /// The template, with its blank replaced by the given intercessions
+    /// ```
+    /// # use liturgy::{CycleOfPrayerId, CycleOfPrayerLookup};
+    /// let lookup = CycleOfPrayerLookup::new(CycleOfPrayerId::Anglican, "In the Anglican Cycle of Prayer, we pray for ___________.");
+    /// assert_eq!(
+    ///     lookup.fill(&["the Diocese of Polynesia".to_string()]),
+    ///     "In the Anglican Cycle of Prayer, we pray for the Diocese of Polynesia."
+    /// );
+    /// ```
+    pub fn fill(&self, intercessions: &[String]) -> String {
+        let intercessions = crate::Names::new(intercessions, crate::Pronouns::They).joined();
+        match self.template.find("___") {
+            Some(start) => {
+                let rest = self.template[start..].trim_start_matches('_');
+                format!("{}{}{}", &self.template[..start], intercessions, rest)
+            }
+            None => format!("{} {}", self.template, intercessions),
+        }
+    }
+}
@@ -0,0 +1,499 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{Content, Document};
+
+/// A single change to a [Document] tree, addressed by path (as in [Document::at_path]).
+/// Paths refer to the state of the document after every previous edit in the [DocumentPatch] has been applied.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum DocumentEdit {
+    /// Insert the document so that it is found at the given path.
+    Insert {
+        path: Vec<usize>,
+        document: Document,
+    },
+    /// Remove the document found at the given path.
+    Delete {
+        path: Vec<usize>,
+        document: Document,
+    },
+    /// Move a document within the same parent, so that it is found at `to` instead of `from`.
+    Move {
+        from: Vec<usize>,
+        to: Vec<usize>,
+        document: Document,
+    },
+    /// Replace the document found at the given path.
+    Update {
+        path: Vec<usize>,
+        old: Box<Document>,
+        new: Box<Document>,
+    },
+}
+
+/// An ordered list of [DocumentEdit]s that transform one [Document] into another,
+/// generated by [Document::diff] and applied with [Document::apply_patch].
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DocumentPatch {
+    pub edits: Vec<DocumentEdit>,
+}
+
+impl DocumentPatch {
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+}
+
+/// A [DocumentEdit] that could not be applied, identified by its index in the [DocumentPatch].
+#[derive(Error, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PatchConflict {
+    #[error("edit {edit}: there is no place for a document at {path:?}")]
+    PathNotFound { edit: usize, path: Vec<usize> },
+    #[error("edit {edit}: the document at {path:?} is not the one the patch expected")]
+    DocumentChanged { edit: usize, path: Vec<usize> },
+}
+
+impl Document {
+    /// Generates a [DocumentPatch] that, when applied to this document, produces the other.
+    /// Children of [Liturgy](crate::Liturgy), [Series](crate::Series), [Parallel](crate::Parallel),
+    /// and [Choice](crate::Choice) are compared one by one, so that a change deep within
+    /// the tree only replaces the smallest document that contains it.
+    /// ```
+    /// # use crate::liturgy::*;
+    /// let original = Document::from(Series::from([
+    ///     Document::from(Text::from("A")),
+    ///     Document::from(Text::from("B")),
+    ///     Document::from(Text::from("C")),
+    /// ]));
+    /// let customized = Document::from(Series::from([
+    ///     Document::from(Text::from("C")),
+    ///     Document::from(Text::from("A")),
+    ///     Document::from(Text::from("B2")),
+    /// ]));
+    /// let patch = original.diff(&customized);
+    /// let mut patched = original.clone();
+    /// assert!(patched.apply_patch(&patch).is_empty());
+    /// assert_eq!(patched, customized);
+    /// ```
+    pub fn diff(&self, other: &Document) -> DocumentPatch {
+        let mut edits = Vec::new();
+        diff_helper(self, other, &[], &mut edits);
+        DocumentPatch { edits }
+    }
+
+    /// Applies each edit in the [DocumentPatch] in order, returning any that conflict with this document.
+    ///
+    /// Before deleting, moving, or updating a document, the patch checks that it is the document it expects;
+    /// if it is not, but exactly one document elsewhere in the tree is, the edit is applied there instead.
+    /// This allows a patch to be reapplied to a newer version of the document it was created from.
+    /// (Insertions are always made at the path given.) Edits that conflict are skipped, and the rest are applied.
+    pub fn apply_patch(&mut self, patch: &DocumentPatch) -> Vec<PatchConflict> {
+        patch
+            .edits
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, edit)| self.apply_edit(idx, edit).err())
+            .collect()
+    }
+
+    fn apply_edit(&mut self, edit_idx: usize, edit: &DocumentEdit) -> Result<(), PatchConflict> {
+        match edit {
+            DocumentEdit::Insert { path, document } => {
+                let (idx, parent_path) = split_path(edit_idx, path)?;
+                let parent = self
+                    .at_path_mut(parent_path.iter().copied())
+                    .map_err(|_| not_found(edit_idx, path))?;
+                match children(parent) {
+                    Some(children) if idx <= children.len() => {
+                        parent
+                            .insert_at(idx, document.clone())
+                            .map_err(|_| not_found(edit_idx, path))?;
+                        Ok(())
+                    }
+                    _ => Err(not_found(edit_idx, path)),
+                }
+            }
+            DocumentEdit::Delete { path, document } => {
+                let path = self.locate(edit_idx, path, document)?;
+                self.remove_at_path(&path)
+                    .map_err(|_| not_found(edit_idx, &path))?;
+                Ok(())
+            }
+            DocumentEdit::Move { from, to, document } => {
+                let from = self.locate(edit_idx, from, document)?;
+                let (_, parent_path) = split_path(edit_idx, &from)?;
+                let (to_idx, _) = split_path(edit_idx, to)?;
+                // the document is moved within its parent, wherever that parent is now found
+                let mut to = parent_path.to_vec();
+                to.push(to_idx);
+                let siblings = self
+                    .at_path(parent_path.iter().copied())
+                    .ok()
+                    .and_then(children)
+                    .map(|children| children.len())
+                    .unwrap_or_default();
+                if to_idx >= siblings {
+                    return Err(not_found(edit_idx, &to));
+                }
+                self.move_subdocument(&from, &to)
+                    .map_err(|_| not_found(edit_idx, &to))
+            }
+            DocumentEdit::Update { path, old, new } => {
+                // this edit has already been made
+                if self.at_path(path.iter().copied()).ok() == Some(&**new) {
+                    return Ok(());
+                }
+                let path = self.locate(edit_idx, path, old)?;
+                let doc = self
+                    .at_path_mut(path.iter().copied())
+                    .map_err(|_| not_found(edit_idx, &path))?;
+                *doc = (**new).clone();
+                Ok(())
+            }
+        }
+    }
+
+    /// The path of the expected document: either the given path, or the only path in the tree at which it is found.
+    fn locate(
+        &self,
+        edit_idx: usize,
+        path: &[usize],
+        expected: &Document,
+    ) -> Result<Vec<usize>, PatchConflict> {
+        match self.at_path(path.iter().copied()) {
+            Ok(found) if found == expected => return Ok(path.to_vec()),
+            Ok(_) if path.is_empty() => {
+                return Err(PatchConflict::DocumentChanged {
+                    edit: edit_idx,
+                    path: Vec::new(),
+                })
+            }
+            Ok(_) => {}
+            Err(_) if path.is_empty() => return Err(not_found(edit_idx, path)),
+            Err(_) => {}
+        }
+
+        let mut matches = Vec::new();
+        find_paths(self, expected, &mut Vec::new(), &mut matches);
+        if matches.len() == 1 {
+            Ok(matches.remove(0))
+        } else {
+            Err(PatchConflict::DocumentChanged {
+                edit: edit_idx,
+                path: path.to_vec(),
+            })
+        }
+    }
+}
+
+/// Every path within the document at which the expected document is found
+fn find_paths(
+    doc: &Document,
+    expected: &Document,
+    path: &mut Vec<usize>,
+    found: &mut Vec<Vec<usize>>,
+) {
+    if doc == expected {
+        found.push(path.clone());
+    } else if let Some(children) = children(doc) {
+        for (idx, child) in children.iter().enumerate() {
+            path.push(idx);
+            find_paths(child, expected, path, found);
+            path.pop();
+        }
+    }
+}
+
+fn not_found(edit: usize, path: &[usize]) -> PatchConflict {
+    PatchConflict::PathNotFound {
+        edit,
+        path: path.to_vec(),
+    }
+}
+
+/// Splits a path into the index of the last segment and the path to its parent
+fn split_path(edit: usize, path: &[usize]) -> Result<(usize, &[usize]), PatchConflict> {
+    path.split_last()
+        .map(|(idx, parent)| (*idx, parent))
+        .ok_or_else(|| not_found(edit, path))
+}
+
+fn children(doc: &Document) -> Option<&[Document]> {
+    match &doc.content {
+        Content::Liturgy(liturgy) => Some(liturgy.body.as_slice()),
+        Content::Series(series) => Some(series.as_slice()),
+        Content::Parallel(parallel) => Some(parallel.as_slice()),
+        Content::Choice(choice) => Some(choice.options.as_slice()),
+        _ => None,
+    }
+}
+
+/// Whether two documents are the same kind of container, with the same fields, ignoring their children
+fn same_container(a: &Document, b: &Document) -> bool {
+    let same_content = match (&a.content, &b.content) {
+        (Content::Liturgy(a), Content::Liturgy(b)) => {
+            a.preferences == b.preferences && a.evening == b.evening
+        }
+        (Content::Series(a), Content::Series(b)) => a.is_indivisible() == b.is_indivisible(),
+        (Content::Parallel(_), Content::Parallel(_)) => true,
+        (Content::Choice(a), Content::Choice(b)) => {
+            a.selected == b.selected
+                && a.rotated == b.rotated
+                && a.should_rotate == b.should_rotate
+                && a.rotation == b.rotation
+        }
+        _ => false,
+    };
+    same_content
+        && Document {
+            content: Content::Empty,
+            ..a.clone()
+        } == Document {
+            content: Content::Empty,
+            ..b.clone()
+        }
+}
+
+fn diff_helper(old: &Document, new: &Document, path: &[usize], edits: &mut Vec<DocumentEdit>) {
+    if old == new {
+        return;
+    }
+    match (children(old), children(new)) {
+        (Some(old_children), Some(new_children)) if same_container(old, new) => {
+            diff_children(old_children, new_children, path, edits)
+        }
+        _ => edits.push(DocumentEdit::Update {
+            path: path.to_vec(),
+            old: Box::new(old.clone()),
+            new: Box::new(new.clone()),
+        }),
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Child {
+    Old(usize),
+    New(usize),
+}
+
+fn diff_children(
+    old: &[Document],
+    new: &[Document],
+    path: &[usize],
+    edits: &mut Vec<DocumentEdit>,
+) {
+    // for each new child, the old child it corresponds to
+    let mut source: Vec<Option<usize>> = vec![None; new.len()];
+    let mut used = vec![false; old.len()];
+
+    // 1) children that are unchanged and in the same order
+    let anchors = longest_common_subsequence(old, new);
+    for (old_idx, new_idx) in &anchors {
+        source[*new_idx] = Some(*old_idx);
+        used[*old_idx] = true;
+    }
+
+    // 2) children that are unchanged but have been moved
+    for (new_idx, new_child) in new.iter().enumerate() {
+        if source[new_idx].is_none() {
+            if let Some(old_idx) = (0..old.len()).find(|idx| !used[*idx] && old[*idx] == *new_child)
+            {
+                source[new_idx] = Some(old_idx);
+                used[old_idx] = true;
+            }
+        }
+    }
+
+    // 3) children that have been changed in place: between each pair of anchors,
+    //    pair up the remaining old and new children of the same kind, in order
+    let mut changed = Vec::new();
+    let mut bounds = anchors.clone();
+    bounds.push((old.len(), new.len()));
+    let (mut old_start, mut new_start) = (0, 0);
+    for (old_end, new_end) in bounds {
+        let mut next_old = old_start;
+        for new_idx in new_start..new_end {
+            if source[new_idx].is_some() {
+                continue;
+            }
+            let same_kind = (next_old..old_end).find(|old_idx| {
+                !used[*old_idx]
+                    && std::mem::discriminant(&old[*old_idx].content)
+                        == std::mem::discriminant(&new[new_idx].content)
+            });
+            if let Some(old_idx) = same_kind {
+                source[new_idx] = Some(old_idx);
+                used[old_idx] = true;
+                changed.push(new_idx);
+                next_old = old_idx + 1;
+            }
+        }
+        old_start = old_end + 1;
+        new_start = new_end + 1;
+    }
+
+    let child_path = |idx: usize| {
+        let mut path = path.to_vec();
+        path.push(idx);
+        path
+    };
+
+    // remove deleted children, from last to first so that the indices are still valid
+    let mut current = (0..old.len()).map(Child::Old).collect::<Vec<_>>();
+    for old_idx in (0..old.len()).rev().filter(|idx| !used[*idx]) {
+        edits.push(DocumentEdit::Delete {
+            path: child_path(old_idx),
+            document: old[old_idx].clone(),
+        });
+        current.remove(old_idx);
+    }
+
+    // move and insert children until the order matches the new document
+    for (new_idx, source) in source.iter().enumerate() {
+        let target = match source {
+            Some(old_idx) => Child::Old(*old_idx),
+            None => Child::New(new_idx),
+        };
+        if current.get(new_idx) == Some(&target) {
+            continue;
+        }
+        match target {
+            Child::New(_) => {
+                edits.push(DocumentEdit::Insert {
+                    path: child_path(new_idx),
+                    document: new[new_idx].clone(),
+                });
+                current.insert(new_idx, target);
+            }
+            Child::Old(old_idx) => {
+                // everything before `new_idx` is already in place, so the child must come later
+                let current_idx = current
+                    .iter()
+                    .position(|child| *child == target)
+                    .expect("every remaining old child is in the current list");
+                edits.push(DocumentEdit::Move {
+                    from: child_path(current_idx),
+                    to: child_path(new_idx),
+                    document: old[old_idx].clone(),
+                });
+                current.remove(current_idx);
+                current.insert(new_idx, target);
+            }
+        }
+    }
+
+    // now that every child is in its final position, update the ones that have changed
+    for new_idx in changed {
+        if let Some(old_idx) = source[new_idx] {
+            diff_helper(&old[old_idx], &new[new_idx], &child_path(new_idx), edits);
+        }
+    }
+}
+
+/// Pairs of indices of equal documents that appear in the same order in both lists
+fn longest_common_subsequence(old: &[Document], new: &[Document]) -> Vec<(usize, usize)> {
+    let mut lengths = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Choice, Heading, HeadingLevel, Liturgy, Series, Text};
+
+    use super::*;
+
+    fn text(text: &str) -> Document {
+        Document::from(Text::from(text))
+    }
+
+    fn liturgy() -> Document {
+        Document::from(Liturgy::from(Series::from([
+            Document::from(Heading::Text(HeadingLevel::Heading1, "Title".into())),
+            Document::from(Choice::from([text("A"), text("B"), text("C")])),
+            Document::from(Series::from([text("1"), text("2"), text("3")])),
+            text("Amen."),
+        ])))
+    }
+
+    #[test]
+    fn diff_round_trips() {
+        let original = liturgy();
+        let mut customized = liturgy();
+        customized.remove_at_path(&[1, 2]).unwrap();
+        customized
+            .at_path_mut([1])
+            .unwrap()
+            .insert_at(0, text("Z"))
+            .unwrap();
+        customized.move_subdocument(&[2, 0], &[2, 2]).unwrap();
+        *customized.at_path_mut([2, 1]).unwrap() = text("two");
+        customized.insert_at(4, text("Alleluia.")).unwrap();
+
+        let patch = original.diff(&customized);
+        let mut patched = original.clone();
+        assert_eq!(patched.apply_patch(&patch), vec![]);
+        assert_eq!(patched, customized);
+
+        // edits are addressed precisely, rather than replacing the whole liturgy
+        assert!(patch.edits.iter().all(|edit| !matches!(
+            edit,
+            DocumentEdit::Update { path, .. } if path.len() < 2
+        )));
+    }
+
+    #[test]
+    fn patch_reapplies_to_updated_document() {
+        let original = liturgy();
+        let mut customized = liturgy();
+        *customized.at_path_mut([2, 1]).unwrap() = text("two");
+        let patch = original.diff(&customized);
+
+        // the library inserts something before the series we customized
+        let mut updated = liturgy();
+        updated.insert_at(1, text("New rubric")).unwrap();
+        assert_eq!(updated.apply_patch(&patch), vec![]);
+        assert_eq!(updated.at_path([3, 1]).unwrap(), &text("two"));
+    }
+
+    #[test]
+    fn reports_conflicts() {
+        let original = liturgy();
+        let mut customized = liturgy();
+        *customized.at_path_mut([3]).unwrap() = text("Thanks be to God.");
+        customized.remove_at_path(&[2, 0]).unwrap();
+        let patch = original.diff(&customized);
+
+        // the library changed both documents we customized
+        let mut updated = liturgy();
+        *updated.at_path_mut([3]).unwrap() = text("Amen. Amen.");
+        *updated.at_path_mut([2, 0]).unwrap() = text("One");
+        let conflicts = updated.apply_patch(&patch);
+        assert_eq!(conflicts.len(), 2);
+        assert!(conflicts
+            .iter()
+            .all(|conflict| matches!(conflict, PatchConflict::DocumentChanged { .. })));
+    }
+}
@@ -201,6 +201,8 @@ impl Document {
             Content::Choice(choice) => choice.options.iter().any(|doc| doc.has_date_condition()),
             Content::Liturgy(liturgy) => liturgy.body.iter().any(|doc| doc.has_date_condition()),
             Content::CollectOfTheDay { allow_multiple: _ } => true,
+            Content::ProperPreface => true,
+            Content::CycleOfPrayer(_) => true,
             _ => false
         };
         has_own_date_condition || has_child_date_condition
@@ -608,6 +610,10 @@ pub enum Content {
     /// # Lookup Fields
     /// Inserts the Collect of the Day
     CollectOfTheDay { allow_multiple: bool },
+    /// Inserts the Proper Preface appointed for the day, if any
+    ProperPreface,
+    /// Inserts the intercessions appointed for the day in a cycle of prayer, if one is available
+    CycleOfPrayer(CycleOfPrayerLookup),
     /// # Content Variants
     /// A document with no contents
     Empty,
@@ -676,6 +682,8 @@ impl Content {
             Content::Parallel(docs) => docs.iter().any(|doc| doc.contains(text)),
             Content::Choice(docs) => docs.options.iter().any(|doc| doc.contains(text)),
             Content::CollectOfTheDay { allow_multiple: _ } => false,
+            Content::ProperPreface => false,
+            Content::CycleOfPrayer(_) => false,
             Content::Empty => false,
             Content::Error(_) => false,
             Content::Antiphon(antiphon) => antiphon.to_string().contains(text),
@@ -738,6 +746,8 @@ impl Content {
             Content::Parallel(docs) => docs.iter().any(|doc| doc.contains_case_insensitive(text)),
             Content::Choice(docs) => docs.options.iter().any(|doc| doc.contains_case_insensitive(text)),
             Content::CollectOfTheDay { allow_multiple: _ } => false,
+            Content::ProperPreface => false,
+            Content::CycleOfPrayer(_) => false,
             Content::Empty => false,
             Content::Error(_) => false,
             Content::Antiphon(antiphon) => antiphon.to_string().to_lowercase().contains(text),
@@ -897,6 +907,12 @@ impl From<CanticleTableEntry> for Document {
     }
 }
 
+impl From<CycleOfPrayerLookup> for Document {
+    fn from(content: CycleOfPrayerLookup) -> Self {
+        Self::from(Content::CycleOfPrayer(content))
+    }
+}
+
 impl From<Choice> for Document {
     fn from(content: Choice) -> Self {
         Self::from(Content::Choice(content))
@@ -1059,6 +1075,12 @@ impl From<CanticleTableEntry> for Content {
     }
 }
 
+impl From<CycleOfPrayerLookup> for Content {
+	fn from(content: CycleOfPrayerLookup) -> Self {
+        Self::CycleOfPrayer(content)
+    }
+}
+
 impl From<GloriaPatri> for Content {
 	fn from(content: GloriaPatri) -> Self {
         Self::GloriaPatri(content)
@@ -0,0 +1,253 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+use crate::placeholder::NAME_PLACEHOLDERS;
+
+/// Words printed in small capitals, as the divine name is in the psalms
+const SMALL_CAPS: [&str; 2] = ["LORD", "GOD"];
+
+/// A piece of liturgical text, parsed from the conventions used in the strings of the library
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Inline {
+    /// Text without any formatting
+    Text(String),
+    /// Text in italics, usually an alternative like *thy*; written between asterisks
+    Italic(Vec<Inline>),
+    /// A word printed in small capitals, like LORD; written in capitals
+    SmallCaps(String),
+    /// Words that may be omitted; written between square brackets
+    Optional(Vec<Inline>),
+    /// A line break; written as a newline
+    LineBreak,
+    /// A name or blank to be filled in, like *N.* or ___________, as written
+    Placeholder(String),
+    /// The number of a verse, which is not written in the text itself
+    VerseNumber(u8),
+}
+
+/// Text parsed into [Inline] pieces, which can be converted back to exactly the string it was parsed from
+/// ```
+/// # use liturgy::{Inline, InlineText};
+/// let source = "Bless the LORD, [O] *N.*,\nfor *thou art* good.";
+/// let inline = InlineText::from(source);
+/// assert_eq!(
+///     inline.as_slice(),
+///     &[
+///         Inline::Text("Bless the ".into()),
+///         Inline::SmallCaps("LORD".into()),
+///         Inline::Text(", ".into()),
+///         Inline::Optional(vec![Inline::Text("O".into())]),
+///         Inline::Text(" ".into()),
+///         Inline::Placeholder("*N.*".into()),
+///         Inline::Text(",".into()),
+///         Inline::LineBreak,
+///         Inline::Text("for ".into()),
+///         Inline::Italic(vec![Inline::Text("thou art".into())]),
+///         Inline::Text(" good.".into()),
+///     ]
+/// );
+/// assert_eq!(inline.to_string(), source);
+/// ```
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct InlineText(Vec<Inline>);
+
+impl InlineText {
+    pub fn iter(&self) -> impl Iterator<Item = &Inline> {
+        self.0.iter()
+    }
+
+    pub fn as_slice(&self) -> &[Inline] {
+        &self.0
+    }
+
+    pub fn into_vec(self) -> Vec<Inline> {
+        self.0
+    }
+
+    /// Prefixes the text with a verse number
+    #[must_use]
+    pub fn with_verse_number(mut self, number: u8) -> Self {
+        self.0.insert(0, Inline::VerseNumber(number));
+        self
+    }
+}
+
+impl From<Vec<Inline>> for InlineText {
+    fn from(inline: Vec<Inline>) -> Self {
+        Self(inline)
+    }
+}
+
+impl From<&str> for InlineText {
+    fn from(text: &str) -> Self {
+        Self(parse(text))
+    }
+}
+
+impl From<&String> for InlineText {
+    fn from(text: &String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl From<String> for InlineText {
+    fn from(text: String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl Display for InlineText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|inline| write!(f, "{}", inline))
+    }
+}
+
+impl Display for Inline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Inline::Text(text) | Inline::SmallCaps(text) | Inline::Placeholder(text) => {
+                write!(f, "{}", text)
+            }
+            Inline::Italic(children) => write_wrapped(f, '*', children, '*'),
+            Inline::Optional(children) => write_wrapped(f, '[', children, ']'),
+            Inline::LineBreak => writeln!(f),
+            // verse numbers are kept separately from the verse's text
+            Inline::VerseNumber(_) => Ok(()),
+        }
+    }
+}
+
+fn write_wrapped(
+    f: &mut std::fmt::Formatter<'_>,
+    open: char,
+    children: &[Inline],
+    close: char,
+) -> std::fmt::Result {
+    write!(f, "{}", open)?;
+    children
+        .iter()
+        .try_for_each(|inline| write!(f, "{}", inline))?;
+    write!(f, "{}", close)
+}
+
+fn parse(text: &str) -> Vec<Inline> {
+    let mut inline = Vec::new();
+    let mut plain = String::new();
+    let mut idx = 0;
+    while let Some(c) = text[idx..].chars().next() {
+        let rest = &text[idx..];
+        let at_word_start = !text[..idx].ends_with(char::is_alphanumeric);
+
+        let parsed = match c {
+            '*' => italic(rest),
+            '[' => optional(rest),
+            '\n' => Some((Inline::LineBreak, 1)),
+            '_' if rest.starts_with("___") => {
+                let len = rest.find(|c| c != '_').unwrap_or(rest.len());
+                Some((Inline::Placeholder(rest[..len].to_string()), len))
+            }
+            _ if at_word_start => small_caps(rest),
+            _ => None,
+        };
+
+        match parsed {
+            Some((piece, len)) => {
+                if !plain.is_empty() {
+                    inline.push(Inline::Text(std::mem::take(&mut plain)));
+                }
+                inline.push(piece);
+                idx += len;
+            }
+            None => {
+                plain.push(c);
+                idx += c.len_utf8();
+            }
+        }
+    }
+    if !plain.is_empty() {
+        inline.push(Inline::Text(plain));
+    }
+    inline
+}
+
+/// Text between asterisks on a single line, as long as it doesn't begin or end with a space
+fn italic(rest: &str) -> Option<(Inline, usize)> {
+    let len = rest[1..].find('*')? + 2;
+    let inner = &rest[1..len - 1];
+    if inner.is_empty()
+        || inner.contains('\n')
+        || inner.starts_with(char::is_whitespace)
+        || inner.ends_with(char::is_whitespace)
+    {
+        None
+    } else if NAME_PLACEHOLDERS.contains(&inner) {
+        Some((Inline::Placeholder(rest[..len].to_string()), len))
+    } else {
+        Some((Inline::Italic(parse(inner)), len))
+    }
+}
+
+/// Text between matching square brackets
+fn optional(rest: &str) -> Option<(Inline, usize)> {
+    let mut depth = 0;
+    for (idx, c) in rest.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((Inline::Optional(parse(&rest[1..idx])), idx + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn small_caps(rest: &str) -> Option<(Inline, usize)> {
+    SMALL_CAPS.iter().find_map(|word| {
+        let after = rest.strip_prefix(word)?;
+        if after.starts_with(char::is_alphanumeric) {
+            None
+        } else {
+            Some((Inline::SmallCaps(word.to_string()), word.len()))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unmatched_markers_are_left_as_text() {
+        for source in [
+            "The LORD is my shepherd; *",
+            "O God, make speed to save us. [",
+            "* not italic * but [optional *and italic*]",
+            "LORDS, GODLY, and the LORD’s house",
+            "",
+        ] {
+            assert_eq!(InlineText::from(source).to_string(), source);
+        }
+        assert_eq!(
+            InlineText::from("LORDS and the LORD’s").as_slice(),
+            &[
+                Inline::Text("LORDS and the ".into()),
+                Inline::SmallCaps("LORD".into()),
+                Inline::Text("’s".into())
+            ]
+        );
+        assert_eq!(
+            InlineText::from("[ *N.*, ___________ ]").as_slice(),
+            &[Inline::Optional(vec![
+                Inline::Text(" ".into()),
+                Inline::Placeholder("*N.*".into()),
+                Inline::Text(", ".into()),
+                Inline::Placeholder("___________".into()),
+                Inline::Text(" ".into()),
+            ])]
+        );
+    }
+}
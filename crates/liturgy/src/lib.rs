@@ -6,12 +6,17 @@ mod biblical_reading;
 mod canticle;
 mod choice;
 mod condition;
+mod condition_analysis;
+mod condition_text;
+mod cycle_of_prayer;
+mod diff;
 mod display_format;
 mod document;
 mod document_error;
 mod gloria_patri;
 mod heading;
 mod hymn_link;
+mod inline;
 mod invitatory;
 mod lectionary_reading;
 mod litany;
@@ -19,13 +24,19 @@ mod liturgy;
 mod parallel;
 pub mod parallel_table;
 mod path;
+mod placeholder;
+mod pointing;
 mod preces;
 mod preference;
+mod preference_layers;
+mod preference_schema;
 mod psalm;
 mod psalm_citation;
+mod recitation;
 mod reference;
 mod responsive_prayer;
 mod rubric;
+mod selections;
 mod sentence;
 mod series;
 mod show;
@@ -40,24 +51,35 @@ pub use biblical_reading::*;
 pub use canticle::*;
 pub use choice::*;
 pub use condition::Condition;
+pub use condition_analysis::{ConditionDiagnostic, ConditionProblem, DateCoverage, Simplified};
+pub use condition_text::ConditionParseError;
+pub use cycle_of_prayer::*;
+pub use diff::*;
 pub use display_format::DisplayFormat;
 pub use document::{Content, Document};
 pub use document_error::*;
 pub use gloria_patri::*;
 pub use heading::*;
 pub use hymn_link::*;
+pub use inline::{Inline, InlineText};
 pub use invitatory::*;
 pub use lectionary_reading::*;
 pub use litany::*;
 pub use parallel::*;
 pub use path::*;
+pub use placeholder::*;
+pub use pointing::*;
 pub use preces::*;
 pub use preference::*;
+pub use preference_layers::*;
+pub use preference_schema::*;
 pub use psalm::*;
 pub use psalm_citation::*;
+pub use recitation::{PsalmRecitation, Recitation, Speaker};
 pub use reference::*;
 pub use responsive_prayer::*;
 pub use rubric::*;
+pub use selections::*;
 pub use sentence::*;
 pub use series::*;
 pub use show::*;
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
-use crate::{Content, Preces, ResponsivePrayer, Text};
+use crate::{Content, InlineText, Preces, ResponsivePrayer, Text};
 
 /// A responsive prayer in which the same response is given to every petition
 #[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -26,6 +26,11 @@ impl Litany {
     pub fn remove_at_index(&mut self, index: usize) -> String {
         self.lines.remove(index)
     }
+
+    /// Each petition, parsed into its italics, small capitals, optional words, and line breaks
+    pub fn inline(&self) -> Vec<InlineText> {
+        self.lines.iter().map(InlineText::from).collect()
+    }
 }
 
 impl<R, T, A> From<(R, T)> for Litany
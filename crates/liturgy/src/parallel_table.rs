@@ -1,15 +1,47 @@
+#[cfg(any(feature = "browser", feature = "server"))]
+use std::collections::HashMap;
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
 use crate::{Content, Document, Reference, SlugPath};
 
+/// How similar two chunks must be for the differences between them to be marked word by word
+#[cfg(any(feature = "browser", feature = "server"))]
+const SIMILARITY_THRESHOLD: f32 = 0.4;
+
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum ParallelDocument {
     Source(Option<Reference>),
-    Link { label: String, slug: SlugPath },
+    Link {
+        label: String,
+        slug: SlugPath,
+    },
     Explainer(Option<String>),
     Document(Box<Document>),
+    /// A document whose text is similar to, but not the same as, its neighbor in the row.
+    /// The spans mark the words it shares with its neighbor, and those that differ.
+    Compared {
+        document: Box<Document>,
+        spans: Vec<DiffSpan>,
+    },
+}
+
+/// A run of words in a [ParallelDocument::Compared] cell
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DiffSpan {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DiffKind {
+    /// Found in both this cell and its neighbor
+    Common,
+    /// Found only in this cell
+    Inserted,
+    /// Found only in the neighboring cell
+    Deleted,
 }
 
 pub fn build_parallel_table<T, U>(
@@ -68,27 +100,9 @@ where
             );
         }
 
-        // rearrange from a list of columns (by doc) to a list of rows (by chunk)
-        let mut chunked_rows = Vec::new();
-
-        let max_len = parallel_tagged_docs
-            .iter()
-            .map(|column| column.len())
-            .max()
-            .unwrap_or(0);
-        for row_idx in 0..max_len {
-            chunked_rows.push(
-                parallel_tagged_docs
-                    .iter()
-                    .map(move |column| {
-                        column
-                            .get(row_idx)
-                            .cloned()
-                            .unwrap_or_else(|| Document::from(Content::Empty))
-                    })
-                    .collect::<Vec<_>>(),
-            );
-        }
+        // rearrange from a list of columns (by doc) to a list of rows (by chunk),
+        // lining up similar chunks so that an extra chunk in one column doesn't shift every later row
+        let chunked_rows = align_columns(parallel_tagged_docs);
 
         // deduplicate/expand width of columns
         for row in chunked_rows {
@@ -116,8 +130,18 @@ where
                             break;
                         }
                     }
-                    parallels_for_this_row
-                        .push((ParallelDocument::Document(Box::new(column.clone())), width));
+
+                    // compare to the cell on the left, or (for the first cell) the cell on the right
+                    let neighbor = prev_child.or_else(|| row.get(column_id + width));
+                    let spans = neighbor.and_then(|neighbor| compare(neighbor, column));
+                    let cell = match spans {
+                        Some(spans) => ParallelDocument::Compared {
+                            document: Box::new(column.clone()),
+                            spans,
+                        },
+                        None => ParallelDocument::Document(Box::new(column.clone())),
+                    };
+                    parallels_for_this_row.push((cell, width));
                 }
             }
 
@@ -127,3 +151,283 @@ where
 
     parallels
 }
+
+/// Arranges columns of chunks into rows, pairing up the chunks in each column that are most similar
+/// to those already placed in the row, in order. Gaps are filled with [Content::Empty].
+#[cfg(any(feature = "browser", feature = "server"))]
+fn align_columns(columns: Vec<Vec<Document>>) -> Vec<Vec<Document>> {
+    let mut columns = columns.into_iter();
+    let mut rows: Vec<Vec<Option<Document>>> = match columns.next() {
+        Some(first) => first.into_iter().map(|doc| vec![Some(doc)]).collect(),
+        None => return Vec::new(),
+    };
+    for (n_columns, column) in (1..).zip(columns) {
+        let texts = column.iter().map(words).collect::<Vec<_>>();
+        let row_texts = rows
+            .iter()
+            .map(|row| row.iter().flatten().map(words).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        // scores[i][j] is the similarity of chunk j to the most similar chunk already in row i
+        let scores = rows
+            .iter()
+            .zip(row_texts.iter())
+            .map(|(row, row_words)| {
+                column
+                    .iter()
+                    .zip(texts.iter())
+                    .map(|(chunk, chunk_words)| {
+                        row.iter()
+                            .zip(row_words.iter())
+                            .filter_map(|(doc, words)| doc.as_ref().map(|doc| (doc, words)))
+                            .map(|(doc, words)| similarity(doc, words, chunk, chunk_words))
+                            .fold(0.0_f32, f32::max)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // best[i][j] is the best total similarity of aligning rows[i..] with column[j..]
+        let (n_rows, n_chunks) = (rows.len(), column.len());
+        let mut best = vec![vec![0.0_f32; n_chunks + 1]; n_rows + 1];
+        for i in (0..n_rows).rev() {
+            for j in (0..n_chunks).rev() {
+                best[i][j] = (best[i + 1][j + 1] + scores[i][j])
+                    .max(best[i + 1][j])
+                    .max(best[i][j + 1]);
+            }
+        }
+
+        let mut aligned = Vec::new();
+        let mut column = column.into_iter().map(Some).collect::<Vec<_>>();
+        let (mut i, mut j) = (0, 0);
+        while i < n_rows || j < n_chunks {
+            let pair = i < n_rows && j < n_chunks;
+            // pairing is preferred when there's a tie, so that dissimilar chunks still line up by position
+            if pair && best[i][j] <= best[i + 1][j + 1] + scores[i][j] {
+                let mut row = std::mem::take(&mut rows[i]);
+                row.push(column[j].take());
+                aligned.push(row);
+                i += 1;
+                j += 1;
+            } else if j >= n_chunks || (i < n_rows && best[i][j] <= best[i + 1][j]) {
+                let mut row = std::mem::take(&mut rows[i]);
+                row.push(None);
+                aligned.push(row);
+                i += 1;
+            } else {
+                let mut row = vec![None; n_columns];
+                row.push(column[j].take());
+                aligned.push(row);
+                j += 1;
+            }
+        }
+        rows = aligned;
+    }
+
+    rows.into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|doc| doc.unwrap_or_else(|| Document::from(Content::Empty)))
+                .collect()
+        })
+        .collect()
+}
+
+/// Arranges columns of chunks into rows by position. Chunks can only be compared once they can be
+/// rendered as text, so without the `browser` or `server` feature they are not aligned by similarity.
+#[cfg(not(any(feature = "browser", feature = "server")))]
+fn align_columns(columns: Vec<Vec<Document>>) -> Vec<Vec<Document>> {
+    let max_len = columns.iter().map(Vec::len).max().unwrap_or(0);
+    (0..max_len)
+        .map(|row_idx| {
+            columns
+                .iter()
+                .map(|column| {
+                    column
+                        .get(row_idx)
+                        .cloned()
+                        .unwrap_or_else(|| Document::from(Content::Empty))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The text of a chunk, divided into words that keep their trailing whitespace
+#[cfg(any(feature = "browser", feature = "server"))]
+fn words(doc: &Document) -> Vec<String> {
+    let text = chunk_text(doc);
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for ch in text.chars() {
+        if !ch.is_whitespace() && current.ends_with(char::is_whitespace) {
+            words.push(std::mem::take(&mut current));
+        }
+        current.push(ch);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+#[cfg(any(feature = "browser", feature = "server"))]
+fn chunk_text(doc: &Document) -> String {
+    doc.as_text()
+}
+
+/// A score from 0 (nothing in common) to 1 (identical), based on the words two chunks share
+#[cfg(any(feature = "browser", feature = "server"))]
+fn similarity(a: &Document, a_words: &[String], b: &Document, b_words: &[String]) -> f32 {
+    if a.content == b.content {
+        return 1.0;
+    }
+    if a_words.is_empty() || b_words.is_empty() {
+        return 0.0;
+    }
+    let mut counts: HashMap<&str, isize> = HashMap::new();
+    for word in a_words {
+        *counts.entry(word.trim_end()).or_default() += 1;
+    }
+    let mut shared = 0;
+    for word in b_words {
+        if let Some(count) = counts.get_mut(word.trim_end()) {
+            if *count > 0 {
+                *count -= 1;
+                shared += 1;
+            }
+        }
+    }
+    (2 * shared) as f32 / (a_words.len() + b_words.len()) as f32
+}
+
+/// Marks the differences between a cell and its neighbor, if they're different but similar enough to be worth comparing
+#[cfg(any(feature = "browser", feature = "server"))]
+fn compare(neighbor: &Document, doc: &Document) -> Option<Vec<DiffSpan>> {
+    let (neighbor_words, doc_words) = (words(neighbor), words(doc));
+    let similarity = similarity(neighbor, &neighbor_words, doc, &doc_words);
+    if neighbor_words == doc_words || similarity < SIMILARITY_THRESHOLD {
+        None
+    } else {
+        Some(word_diff(&neighbor_words, &doc_words))
+    }
+}
+
+/// Word-level differences can only be marked once documents can be rendered as text,
+/// so without the `browser` or `server` feature every cell is left as a plain [ParallelDocument::Document]
+#[cfg(not(any(feature = "browser", feature = "server")))]
+fn compare(_neighbor: &Document, _doc: &Document) -> Option<Vec<DiffSpan>> {
+    None
+}
+
+/// The words of `new`, along with those only found in `old`, grouped into runs of [DiffSpan]s
+#[cfg(any(feature = "browser", feature = "server"))]
+fn word_diff(old: &[String], new: &[String]) -> Vec<DiffSpan> {
+    // lengths[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lengths = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i].trim_end() == new[j].trim_end() {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut spans: Vec<DiffSpan> = Vec::new();
+    let mut push = |kind: DiffKind, word: &str| match spans.last_mut() {
+        Some(span) if span.kind == kind => span.text.push_str(word),
+        _ => spans.push(DiffSpan {
+            kind,
+            text: word.to_string(),
+        }),
+    };
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i].trim_end() == new[j].trim_end() {
+            push(DiffKind::Common, &new[j]);
+            i += 1;
+            j += 1;
+        } else if j >= new.len() || (i < old.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            push(DiffKind::Deleted, &old[i]);
+            i += 1;
+        } else {
+            push(DiffKind::Inserted, &new[j]);
+            j += 1;
+        }
+    }
+    spans
+}
+
+#[cfg(all(test, any(feature = "browser", feature = "server")))]
+mod tests {
+    use crate::{Rubric, Series, Text};
+
+    use super::*;
+
+    fn text(text: &str) -> Document {
+        Document::from(Text::from(text))
+    }
+
+    fn cells(row: &[(ParallelDocument, usize)]) -> Vec<&ParallelDocument> {
+        row.iter().map(|(cell, _)| cell).collect()
+    }
+
+    #[test]
+    fn marks_word_differences() {
+        let old = words(&text("The Lord be with you."));
+        let new = words(&text("The Lord be with thee."));
+        assert_eq!(
+            word_diff(&old, &new),
+            vec![
+                DiffSpan {
+                    kind: DiffKind::Common,
+                    text: "The Lord be with ".into()
+                },
+                DiffSpan {
+                    kind: DiffKind::Deleted,
+                    text: "you.".into()
+                },
+                DiffSpan {
+                    kind: DiffKind::Inserted,
+                    text: "thee.".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn aligns_chunks_by_similarity() {
+        let rite_i = Document::from(Series::from([
+            text("Almighty God, unto whom all hearts be open, all desires known, and from whom no secrets are hid."),
+            text("Lord, have mercy upon us."),
+        ]))
+        .tags(["tag"]);
+        let rite_ii = Document::from(Series::from([
+            Document::from(Rubric::from("The Celebrant may say")),
+            text("Almighty God, to you all hearts are open, all desires known, and from you no secrets are hid."),
+            text("Lord, have mercy upon us."),
+        ]))
+        .tags(["tag"]);
+        let (a, b) = (SlugPath::default(), SlugPath::default());
+        let table = build_parallel_table(["tag"], &[(&a, &rite_i), (&b, &rite_ii)]);
+        // skip the rows of sources and links
+        let rows = &table[2..];
+        assert_eq!(rows.len(), 3);
+        assert!(matches!(
+            cells(&rows[0])[..],
+            [ParallelDocument::Document(_), ParallelDocument::Document(_)]
+        ));
+        assert!(matches!(
+            cells(&rows[1])[..],
+            [
+                ParallelDocument::Compared { .. },
+                ParallelDocument::Compared { .. }
+            ]
+        ));
+        // identical chunks are merged into a single cell
+        assert_eq!(rows[2].len(), 1);
+        assert_eq!(rows[2][0].1, 2);
+    }
+}
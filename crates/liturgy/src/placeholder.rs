@@ -0,0 +1,675 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use crate::{
+    ClientPreferences, Content, Document, GlobalPref, Litany, Preces, PreferenceKey,
+    PreferenceValue, ResponsivePrayer,
+};
+
+/// The people or places whose names are left blank in a liturgical text (*N.*, *N.N.*, ___________)
+#[derive(
+    Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, EnumIter,
+)]
+pub enum PlaceholderRole {
+    /// The person who has died, in the Burial of the Dead and in prayers for the departed
+    Deceased,
+    /// The two people being married or blessed
+    Couple,
+    /// Those being baptized, confirmed, received, reaffirmed, or ordained
+    Candidates,
+    /// The bishop or bishops of the diocese
+    Bishop,
+    /// The parish or congregation
+    Parish,
+}
+
+impl PlaceholderRole {
+    /// The [GlobalPref] in which a client supplies the names for this role
+    pub fn preference(&self) -> GlobalPref {
+        match self {
+            PlaceholderRole::Deceased => GlobalPref::DeceasedName,
+            PlaceholderRole::Couple => GlobalPref::CoupleNames,
+            PlaceholderRole::Candidates => GlobalPref::CandidateNames,
+            PlaceholderRole::Bishop => GlobalPref::BishopName,
+            PlaceholderRole::Parish => GlobalPref::ParishName,
+        }
+    }
+
+    /// Whether this role is the focus of a rite, and so is the one meant by an unqualified *N.* or *him/her*
+    fn is_subject(&self) -> bool {
+        matches!(
+            self,
+            PlaceholderRole::Deceased | PlaceholderRole::Couple | PlaceholderRole::Candidates
+        )
+    }
+}
+
+/// The pronouns used for a person, which determine the choice among alternatives like *her/him*
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Pronouns {
+    She,
+    He,
+    #[default]
+    They,
+}
+
+impl Pronouns {
+    fn index(&self) -> usize {
+        match self {
+            Pronouns::She => 0,
+            Pronouns::He => 1,
+            Pronouns::They => 2,
+        }
+    }
+}
+
+/// The name or names, and pronouns, that fill a [PlaceholderRole]
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Names {
+    pub names: Vec<String>,
+    pub pronouns: Pronouns,
+}
+
+impl Names {
+    pub fn new<T: Display>(names: impl IntoIterator<Item = T>, pronouns: Pronouns) -> Self {
+        Self {
+            names: names.into_iter().map(|name| name.to_string()).collect(),
+            pronouns,
+        }
+    }
+
+    /// The pronouns for these names; more than one person is always referred to as “they”
+    pub fn pronouns(&self) -> Pronouns {
+        if self.names.len() > 1 {
+            Pronouns::They
+        } else {
+            self.pronouns
+        }
+    }
+
+    /// All the names, joined in a list
+    /// ```
+    /// # use liturgy::{Names, Pronouns};
+    /// assert_eq!(Names::new(["Anne"], Pronouns::She).joined(), "Anne");
+    /// assert_eq!(Names::new(["Anne", "Bo"], Pronouns::They).joined(), "Anne and Bo");
+    /// assert_eq!(Names::new(["Anne", "Bo", "Cy"], Pronouns::They).joined(), "Anne, Bo, and Cy");
+    /// ```
+    pub fn joined(&self) -> String {
+        match self.names.as_slice() {
+            [] => String::new(),
+            [one] => one.clone(),
+            [a, b] => format!("{} and {}", a, b),
+            [rest @ .., last] => format!("{}, and {}", rest.join(", "), last),
+        }
+    }
+}
+
+/// The names that fill each [PlaceholderRole] in a document
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PlaceholderContext(BTreeMap<PlaceholderRole, Names>);
+
+impl PlaceholderContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes the names for each role from its [GlobalPref], if the client has set it
+    pub fn from_preferences(prefs: &impl ClientPreferences) -> Self {
+        Self(
+            PlaceholderRole::iter()
+                .filter_map(
+                    |role| match prefs.value(&PreferenceKey::from(role.preference())) {
+                        Some(PreferenceValue::Names(names)) if !names.names.is_empty() => {
+                            Some((role, names.clone()))
+                        }
+                        _ => None,
+                    },
+                )
+                .collect(),
+        )
+    }
+
+    #[must_use]
+    pub fn with(mut self, role: PlaceholderRole, names: Names) -> Self {
+        self.insert(role, names);
+        self
+    }
+
+    pub fn insert(&mut self, role: PlaceholderRole, names: Names) {
+        self.0.insert(role, names);
+    }
+
+    pub fn get(&self, role: PlaceholderRole) -> Option<&Names> {
+        self.0.get(&role).filter(|names| !names.names.is_empty())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The role meant by an unqualified *N.*, if only one subject role has been given names
+    fn subject(&self) -> Option<PlaceholderRole> {
+        let mut subjects = self.0.keys().filter(|role| role.is_subject());
+        match (subjects.next(), subjects.next()) {
+            (Some(role), None) => Some(*role),
+            _ => None,
+        }
+    }
+}
+
+/// A placeholder that was left in a text because no name, or no single form, could be chosen for it
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UnfilledPlaceholder {
+    /// The placeholder as it appears in the text, e.g., `*N.*` or `*her/him*`
+    pub placeholder: String,
+    /// The role it refers to, if that could be determined from the text and the context
+    pub role: Option<PlaceholderRole>,
+}
+
+impl Document {
+    /// Replaces the name placeholders in this document and all its children with the names in `context`,
+    /// choosing among pronoun and gendered alternatives (*her/him*, brother (sister)) to match.
+    /// Returns any placeholders that could not be filled.
+    /// ```
+    /// # use liturgy::{Content, Document, Names, PlaceholderContext, PlaceholderRole, Pronouns, Text};
+    /// let mut doc = Document::from("We commend to you our brother (sister) *N.*; receive *her/him* into your mercy.");
+    /// let context = PlaceholderContext::new()
+    ///     .with(PlaceholderRole::Deceased, Names::new(["Agnes"], Pronouns::She));
+    /// assert!(doc.fill_placeholders(&context).is_empty());
+    /// assert_eq!(
+    ///     doc.content,
+    ///     Content::Text(Text::from("We commend to you our sister Agnes; receive her into your mercy."))
+    /// );
+    /// ```
+    pub fn fill_placeholders(&mut self, context: &PlaceholderContext) -> Vec<UnfilledPlaceholder> {
+        let mut filler = Filler {
+            context,
+            unfilled: Vec::new(),
+        };
+        filler.document(self);
+        filler.unfilled
+    }
+
+    /// The placeholders in this document that are still waiting to be filled
+    pub fn unfilled_placeholders(&self) -> Vec<UnfilledPlaceholder> {
+        self.clone()
+            .fill_placeholders(&PlaceholderContext::default())
+    }
+}
+
+/// The names that may be written in italics, e.g., *N.*, to show where a name belongs
+pub(crate) const NAME_PLACEHOLDERS: [&str; 6] = ["N.", "N.’s", "N.'s", "N. N.", "N.N.", "NN."];
+
+/// Possessive pronouns that stand alone, as in “the victory is his,” and so are never followed by a noun
+const INDEPENDENT_POSSESSIVES: [&str; 3] = ["hers", "his", "theirs"];
+
+/// Words that vary with a person's pronouns, in the order she, he, they
+const GENDERED: [[&str; 3]; 9] = [
+    ["she", "he", "they"],
+    ["her", "him", "them"],
+    ["her", "his", "their"],
+    INDEPENDENT_POSSESSIVES,
+    ["sister", "brother", "sibling"],
+    ["woman", "man", "person"],
+    ["wife", "husband", "spouse"],
+    ["daughter", "son", "child"],
+    ["mother", "father", "parent"],
+];
+
+/// The sets of [GENDERED] words that include all of the given alternatives. A single word may belong
+/// to more than one set, as *her* may stand for either “him” or “his.”
+fn gendered_sets<'a>(
+    alternatives: &'a [&'a str],
+) -> impl Iterator<Item = &'static [&'static str; 3]> + 'a {
+    GENDERED.iter().filter(move |set| {
+        alternatives
+            .iter()
+            .all(|alt| set.contains(&alt.to_lowercase().as_str()))
+    })
+}
+
+fn is_gendered(alternatives: &[&str]) -> bool {
+    gendered_sets(alternatives).next().is_some()
+}
+
+fn capitalized_like(word: &str, model: &str) -> String {
+    match (model.chars().next(), word.chars().next()) {
+        (Some(m), Some(w)) if m.is_uppercase() => {
+            w.to_uppercase().chain(word.chars().skip(1)).collect()
+        }
+        _ => word.to_string(),
+    }
+}
+
+enum NameForm {
+    /// A single name, like *N.*
+    One,
+    /// A single name in the possessive, like *N.’s*
+    Possessive,
+    /// Every name for the role, like *N.N.* or *N.* and *N.*
+    All,
+}
+
+struct Filler<'a> {
+    context: &'a PlaceholderContext,
+    unfilled: Vec<UnfilledPlaceholder>,
+}
+
+impl<'a> Filler<'a> {
+    fn document(&mut self, doc: &mut Document) {
+        match &mut doc.content {
+            Content::Series(series) => series
+                .as_mut_slice()
+                .iter_mut()
+                .for_each(|doc| self.document(doc)),
+            Content::Parallel(parallel) => parallel
+                .as_mut_slice()
+                .iter_mut()
+                .for_each(|doc| self.document(doc)),
+            Content::Choice(choice) => choice.options.iter_mut().for_each(|doc| self.document(doc)),
+            Content::Liturgy(liturgy) => liturgy
+                .body
+                .as_mut_slice()
+                .iter_mut()
+                .for_each(|doc| self.document(doc)),
+            Content::Text(text) => {
+                text.text = self.fill(&text.text);
+                text.response = text.response.as_ref().map(|response| self.fill(response));
+            }
+            Content::Rubric(rubric) => rubric.text = self.fill(&rubric.text),
+            Content::Litany(Litany {
+                lines, response, ..
+            }) => {
+                *lines = lines.iter().map(|line| self.fill(line)).collect();
+                *response = self.fill(response);
+            }
+            Content::ResponsivePrayer(prayer) => {
+                *prayer = ResponsivePrayer::from(
+                    prayer
+                        .iter()
+                        .map(|line| self.fill(line))
+                        .collect::<Vec<_>>(),
+                );
+            }
+            Content::Preces(preces) => {
+                *preces = Preces::from(
+                    preces
+                        .iter()
+                        .map(|(label, text)| (label.clone(), self.fill(text)))
+                        .collect::<Vec<_>>(),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn fill(&mut self, text: &str) -> String {
+        let mut filled = String::with_capacity(text.len());
+        let mut idx = 0;
+        while let Some(c) = text[idx..].chars().next() {
+            let preceding = &text[..idx];
+            let rest = &text[idx..];
+            let at_word_start = !preceding.ends_with(char::is_alphanumeric);
+
+            let replaced = if c == '*' {
+                self.italic(preceding, rest)
+            } else if rest.starts_with("___") {
+                self.blank(preceding, rest)
+            } else if rest.starts_with("Bishop(s)") {
+                self.bishops()
+            } else if at_word_start && rest.starts_with("N.") {
+                self.bishop_names(preceding, rest)
+            } else if at_word_start && c.is_alphabetic() {
+                self.parenthetical_alternative(preceding, rest)
+            } else {
+                None
+            };
+
+            match replaced {
+                Some((replacement, len)) => {
+                    filled.push_str(&replacement);
+                    idx += len;
+                }
+                None => {
+                    filled.push(c);
+                    idx += c.len_utf8();
+                }
+            }
+        }
+        filled
+    }
+
+    /// Italicized placeholders: *N.*, *N.N.*, *N.’s*, *him*, *her/him*, *wife/husband/spouse*
+    fn italic(&mut self, preceding: &str, rest: &str) -> Option<(String, usize)> {
+        let len = rest[1..].find('*')? + 2;
+        let inner = &rest[1..len - 1];
+        if inner.is_empty()
+            || inner.contains('\n')
+            || inner.starts_with(' ')
+            || inner.ends_with(' ')
+        {
+            return None;
+        }
+        let placeholder = &rest[..len];
+        let role = self.role_for(preceding);
+
+        let filled = match inner {
+            "N." => {
+                // *N.* and *N.* names two people at once
+                if let Some(pair) = rest[len..].strip_prefix(" and *N.*") {
+                    let len = rest.len() - pair.len();
+                    return Some((
+                        self.names(&rest[..len], role, NameForm::All)
+                            .unwrap_or_else(|| rest[..len].to_string()),
+                        len,
+                    ));
+                }
+                self.names(placeholder, role, NameForm::One)
+            }
+            "N.’s" | "N.'s" => self.names(placeholder, role, NameForm::Possessive),
+            "N. N." | "N.N." | "NN." => self.names(placeholder, role, NameForm::All),
+            _ => {
+                let alternatives = inner.split('/').collect::<Vec<_>>();
+                if !is_gendered(&alternatives) {
+                    return Some((placeholder.to_string(), len));
+                }
+                let followed_by_word = rest[len..]
+                    .strip_prefix(' ')
+                    .is_some_and(|after| after.starts_with(char::is_alphabetic));
+                self.gendered(placeholder, role, &alternatives, followed_by_word)
+            }
+        };
+        Some((filled.unwrap_or_else(|| placeholder.to_string()), len))
+    }
+
+    /// Blanks (___________) are only filled where the text shows whose name belongs there
+    fn blank(&mut self, preceding: &str, rest: &str) -> Option<(String, usize)> {
+        let len = rest.find(|c| c != '_').unwrap_or(rest.len());
+        let placeholder = &rest[..len];
+        let role = match self.role_for(preceding) {
+            Some(role @ (PlaceholderRole::Bishop | PlaceholderRole::Parish)) => role,
+            _ => return Some((placeholder.to_string(), len)),
+        };
+        let filled = self.names(placeholder, Some(role), NameForm::All);
+        Some((filled.unwrap_or_else(|| placeholder.to_string()), len))
+    }
+
+    /// “Bishop(s)” becomes “Bishop” or “Bishops” once the bishops' names are known
+    fn bishops(&mut self) -> Option<(String, usize)> {
+        let bishops = self.context.get(PlaceholderRole::Bishop)?;
+        let title = if bishops.names.len() > 1 {
+            "Bishops"
+        } else {
+            "Bishop"
+        };
+        Some((title.to_string(), "Bishop(s)".len()))
+    }
+
+    /// Unitalicized names only follow a bishop's title, as in “our Bishop(s) N. [and N.]”;
+    /// elsewhere, a plain N. names a commemorated saint and is left alone
+    fn bishop_names(&mut self, preceding: &str, rest: &str) -> Option<(String, usize)> {
+        if !Self::follows_bishop(preceding) {
+            return None;
+        }
+        let placeholder = ["N. [and N.]", "N. and N.", "N."]
+            .iter()
+            .find(|placeholder| rest.starts_with(**placeholder))?;
+        let filled = self.names(placeholder, Some(PlaceholderRole::Bishop), NameForm::All);
+        Some((
+            filled.unwrap_or_else(|| placeholder.to_string()),
+            placeholder.len(),
+        ))
+    }
+
+    /// Alternatives in parentheses, as in “our brother (sister) *N.*”
+    fn parenthetical_alternative(
+        &mut self,
+        preceding: &str,
+        rest: &str,
+    ) -> Option<(String, usize)> {
+        let word_len = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        let after = rest[word_len..].strip_prefix(" (")?;
+        let alt_len = after.find(')')?;
+        let alternatives = [&rest[..word_len], &after[..alt_len]];
+        if alternatives[0].eq_ignore_ascii_case(alternatives[1]) || !is_gendered(&alternatives) {
+            return None;
+        }
+        let len = word_len + 2 + alt_len + 1;
+        let placeholder = &rest[..len];
+        let filled = self.gendered(placeholder, self.role_for(preceding), &alternatives, false);
+        Some((filled.unwrap_or_else(|| placeholder.to_string()), len))
+    }
+
+    fn follows_bishop(preceding: &str) -> bool {
+        let preceding = preceding.trim_end();
+        ["Bishop", "Bishops", "Bishop(s)", "bishop", "bishops"]
+            .iter()
+            .any(|title| preceding.ends_with(title))
+    }
+
+    fn follows_parish(preceding: &str) -> bool {
+        let preceding = preceding.trim_end().to_lowercase();
+        ["parish", "congregation"]
+            .iter()
+            .any(|word| preceding.ends_with(word))
+    }
+
+    fn role_for(&self, preceding: &str) -> Option<PlaceholderRole> {
+        if Self::follows_bishop(preceding) {
+            Some(PlaceholderRole::Bishop)
+        } else if Self::follows_parish(preceding) {
+            Some(PlaceholderRole::Parish)
+        } else {
+            self.context.subject()
+        }
+    }
+
+    fn report(&mut self, placeholder: &str, role: Option<PlaceholderRole>) {
+        self.unfilled.push(UnfilledPlaceholder {
+            placeholder: placeholder.to_string(),
+            role,
+        });
+    }
+
+    fn names(
+        &mut self,
+        placeholder: &str,
+        role: Option<PlaceholderRole>,
+        form: NameForm,
+    ) -> Option<String> {
+        let names = role.and_then(|role| self.context.get(role));
+        let filled = match (names, form) {
+            (Some(names), NameForm::All) => Some(names.joined()),
+            (Some(Names { names, .. }), NameForm::One) if names.len() == 1 => {
+                Some(names[0].clone())
+            }
+            (Some(Names { names, .. }), NameForm::Possessive) if names.len() == 1 => {
+                Some(format!("{}’s", names[0]))
+            }
+            _ => None,
+        };
+        if filled.is_none() {
+            self.report(placeholder, role);
+        }
+        filled
+    }
+
+    /// Chooses among alternatives like *her/him* or *their/her/his*. A single italicized pronoun (*him*) may
+    /// become “them” for several people, but alternatives that don't include that form refer to only one person.
+    /// A word whose form is ambiguous (*her*, which may stand for “him” or “his”) is only filled if every form
+    /// it may stand for gives the same word; one followed by another word is not an independent possessive.
+    fn gendered(
+        &mut self,
+        placeholder: &str,
+        role: Option<PlaceholderRole>,
+        alternatives: &[&str],
+        followed_by_word: bool,
+    ) -> Option<String> {
+        let names = role.and_then(|role| self.context.get(role));
+        let word = names.and_then(|names| {
+            let index = names.pronouns().index();
+            let mut words = gendered_sets(alternatives)
+                .filter(|set| !(followed_by_word && **set == INDEPENDENT_POSSESSIVES))
+                .map(|set| set[index]);
+            let word = words.next()?;
+            words.all(|other| other == word).then_some(word)
+        });
+        let filled = match (names, word) {
+            (Some(names), Some(word)) => {
+                let offered = alternatives.len() == 1
+                    || alternatives
+                        .iter()
+                        .any(|alt| alt.eq_ignore_ascii_case(word));
+                if names.names.len() > 1 && !offered {
+                    None
+                } else {
+                    Some(capitalized_like(word, alternatives[0]))
+                }
+            }
+            _ => None,
+        };
+        if filled.is_none() {
+            self.report(placeholder, role);
+        }
+        filled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(text: &str, context: &PlaceholderContext) -> (String, Vec<UnfilledPlaceholder>) {
+        let mut doc = Document::from(text);
+        let unfilled = doc.fill_placeholders(context);
+        match doc.content {
+            Content::Text(text) => (text.text, unfilled),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn fills_names_and_pronouns_for_the_subject() {
+        let context = PlaceholderContext::new().with(
+            PlaceholderRole::Deceased,
+            Names::new(["Thomas"], Pronouns::He),
+        );
+        let (text, unfilled) = fill(
+            "Into your hands we commend your servant *N.*, that *he* may rest with *their/her/his* fathers. Remember *N.’s* kindness.",
+            &context,
+        );
+        assert_eq!(
+            text,
+            "Into your hands we commend your servant Thomas, that he may rest with his fathers. Remember Thomas’s kindness."
+        );
+        assert!(unfilled.is_empty());
+    }
+
+    #[test]
+    fn uses_plural_forms_for_several_people() {
+        let context = PlaceholderContext::new().with(
+            PlaceholderRole::Candidates,
+            Names::new(["Ruth", "Eli", "Sam"], Pronouns::She),
+        );
+        let (text, unfilled) = fill(
+            "Sustain *N.N.*, and strengthen *them* in *their/her/his* life. Receive *N.* into your kingdom; bless *her/him*.",
+            &context,
+        );
+        assert_eq!(
+            text,
+            "Sustain Ruth, Eli, and Sam, and strengthen them in their life. Receive *N.* into your kingdom; bless *her/him*."
+        );
+        assert_eq!(
+            unfilled,
+            vec![
+                UnfilledPlaceholder {
+                    placeholder: "*N.*".into(),
+                    role: Some(PlaceholderRole::Candidates)
+                },
+                UnfilledPlaceholder {
+                    placeholder: "*her/him*".into(),
+                    role: Some(PlaceholderRole::Candidates)
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn leaves_ambiguous_pronouns_unless_their_form_is_known() {
+        let text = "Give *her* rest, and remember *her/him* in your kingdom.";
+        let he = PlaceholderContext::new().with(
+            PlaceholderRole::Deceased,
+            Names::new(["Thomas"], Pronouns::He),
+        );
+        // *her* may stand for “him” or for “his”
+        assert_eq!(
+            fill(text, &he),
+            (
+                "Give *her* rest, and remember him in your kingdom.".to_string(),
+                vec![UnfilledPlaceholder {
+                    placeholder: "*her*".into(),
+                    role: Some(PlaceholderRole::Deceased)
+                }]
+            )
+        );
+        let she = PlaceholderContext::new().with(
+            PlaceholderRole::Deceased,
+            Names::new(["Agnes"], Pronouns::She),
+        );
+        assert_eq!(
+            fill(text, &she),
+            (
+                "Give her rest, and remember her in your kingdom.".to_string(),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn fills_bishops_from_textual_cues() {
+        let context = PlaceholderContext::new()
+            .with(
+                PlaceholderRole::Bishop,
+                Names::new(["Mariann", "Carlye"], Pronouns::She),
+            )
+            .with(
+                PlaceholderRole::Deceased,
+                Names::new(["Ana"], Pronouns::She),
+            );
+        let (text, unfilled) = fill(
+            "Bless our Bishop(s) N. [and N.], and other clergy. Pray for our Bishop(s) ___________ ; and for *N.*. O Holy N., pray for us.",
+            &context,
+        );
+        assert_eq!(
+            text,
+            "Bless our Bishops Mariann and Carlye, and other clergy. Pray for our Bishops Mariann and Carlye ; and for Ana. O Holy N., pray for us."
+        );
+        assert!(unfilled.is_empty());
+    }
+
+    #[test]
+    fn reports_placeholders_without_names() {
+        let doc = Document::from(
+            "The Lord be with *N.* and *N.*; keep *them* in peace. For ___________, let us pray.",
+        );
+        assert_eq!(
+            doc.unfilled_placeholders(),
+            vec![
+                UnfilledPlaceholder {
+                    placeholder: "*N.* and *N.*".into(),
+                    role: None
+                },
+                UnfilledPlaceholder {
+                    placeholder: "*them*".into(),
+                    role: None
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,482 @@
+use serde::{Deserialize, Serialize};
+
+/// The tradition of chant for which a psalm or canticle is pointed
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ChantStyle {
+    /// Anglican chant, in which bar lines divide the end of each half-verse into the measures of the chant
+    Anglican,
+    /// Plainsong, in which the accented syllables of the mediation and termination are marked,
+    /// and a long first half-verse may be broken by a flex
+    Plainsong,
+}
+
+/// A simple chant tone, described by the number of accented syllables in the cadence
+/// at the end of each half of the verse
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ChantTone {
+    pub style: ChantStyle,
+    /// Number of accents in the mediation, at the end of the first half-verse
+    pub mediation: usize,
+    /// Number of accents in the termination, at the end of the second half-verse
+    pub termination: usize,
+    /// If the first half-verse has more than this number of syllables, it is broken by a flex where possible
+    pub flex_after: Option<usize>,
+}
+
+impl ChantTone {
+    /// A single Anglican chant: a mediation of two measures and a termination of three
+    pub const ANGLICAN_SINGLE: ChantTone = ChantTone {
+        style: ChantStyle::Anglican,
+        mediation: 2,
+        termination: 3,
+        flex_after: None,
+    };
+
+    /// A plainsong tone with a single accent in both the mediation and the termination (e.g., Tones 2 and 8)
+    pub const PLAINSONG_SINGLE_ACCENT: ChantTone = ChantTone {
+        style: ChantStyle::Plainsong,
+        mediation: 1,
+        termination: 1,
+        flex_after: Some(16),
+    };
+
+    /// A plainsong tone with two accents in both the mediation and the termination (e.g., Tone 7)
+    pub const PLAINSONG_DOUBLE_ACCENT: ChantTone = ChantTone {
+        style: ChantStyle::Plainsong,
+        mediation: 2,
+        termination: 2,
+        flex_after: Some(16),
+    };
+}
+
+impl Default for ChantTone {
+    /// A single Anglican chant
+    fn default() -> Self {
+        Self::ANGLICAN_SINGLE
+    }
+}
+
+/// Pointing marks for each half of a verse of a psalm or canticle
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Pointing {
+    /// Marks for the first half of the verse, up to the asterisk
+    pub a: Vec<PointingMark>,
+    /// Marks for the second half of the verse, after the asterisk
+    pub b: Vec<PointingMark>,
+}
+
+/// A single pointing mark. Positions are byte offsets into the text of the half-verse.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PointingMark {
+    /// A bar line, placed before the syllable that begins at this position (Anglican chant)
+    Bar(usize),
+    /// An accented syllable, spanning these positions (plainsong)
+    Accent(usize, usize),
+    /// A flex, placed at this position (plainsong)
+    Flex(usize),
+}
+
+impl PointingMark {
+    fn position(&self) -> usize {
+        match self {
+            PointingMark::Bar(position) => *position,
+            PointingMark::Accent(start, _) => *start,
+            PointingMark::Flex(position) => *position,
+        }
+    }
+}
+
+/// A piece of a half-verse with its pointing applied, as generated by [Pointing::apply].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum PointedText<'a> {
+    Text(&'a str),
+    Accented(&'a str),
+    Bar,
+    Flex,
+}
+
+impl Pointing {
+    /// Points a verse for the given tone, using heuristics for the syllables and stresses of English words.
+    /// ```
+    /// # use liturgy::{ChantTone, Pointing, PointingMark, PointedText};
+    /// let a = "Their delight is in the law of the LORD, *";
+    /// let b = "and they meditate on his law day and night.";
+    ///
+    /// let anglican = Pointing::new(a, b, &ChantTone::ANGLICAN_SINGLE);
+    /// assert_eq!(anglican.a, vec![PointingMark::Bar(24), PointingMark::Bar(35)]);
+    /// assert_eq!(
+    ///     Pointing::apply(a, &anglican.a),
+    ///     vec![
+    ///         PointedText::Text("Their delight is in the "),
+    ///         PointedText::Bar,
+    ///         PointedText::Text("law of the "),
+    ///         PointedText::Bar,
+    ///         PointedText::Text("LORD, *")
+    ///     ]
+    /// );
+    ///
+    /// let plainsong = Pointing::new(a, b, &ChantTone::PLAINSONG_SINGLE_ACCENT);
+    /// assert_eq!(
+    ///     Pointing::apply(b, &plainsong.b),
+    ///     vec![
+    ///         PointedText::Text("and they meditate on his law day and "),
+    ///         PointedText::Accented("night"),
+    ///         PointedText::Text(".")
+    ///     ]
+    /// );
+    /// ```
+    pub fn new(a: &str, b: &str, tone: &ChantTone) -> Self {
+        let mut a_marks = point_half_verse(a, tone.mediation, tone.style);
+        if let Some(flex_after) = tone.flex_after {
+            if let Some(flex) = flex(a, &a_marks, flex_after) {
+                a_marks.insert(0, PointingMark::Flex(flex));
+            }
+        }
+        Self {
+            a: a_marks,
+            b: point_half_verse(b, tone.termination, tone.style),
+        }
+    }
+
+    /// Divides the text of a half-verse into pieces according to its pointing marks.
+    /// Marks that do not fall within the text are ignored.
+    pub fn apply<'a>(text: &'a str, marks: &[PointingMark]) -> Vec<PointedText<'a>> {
+        let mut marks = marks.to_vec();
+        marks.sort_by_key(PointingMark::position);
+
+        let mut pieces = Vec::new();
+        let mut position = 0;
+        for mark in marks {
+            let before = match text.get(position..mark.position()) {
+                Some(before) => before,
+                None => continue,
+            };
+            if !before.is_empty() {
+                pieces.push(PointedText::Text(before));
+            }
+            position = mark.position();
+            match mark {
+                PointingMark::Bar(_) => pieces.push(PointedText::Bar),
+                PointingMark::Flex(_) => pieces.push(PointedText::Flex),
+                PointingMark::Accent(start, end) => {
+                    if let Some(syllable) = text.get(start..end) {
+                        pieces.push(PointedText::Accented(syllable));
+                        position = end;
+                    }
+                }
+            }
+        }
+        if let Some(rest) = text.get(position..) {
+            if !rest.is_empty() {
+                pieces.push(PointedText::Text(rest));
+            }
+        }
+        pieces
+    }
+}
+
+/// Monosyllables that are not normally stressed in speech
+const UNSTRESSED_WORDS: [&str; 61] = [
+    "a", "an", "the", "and", "or", "but", "nor", "of", "to", "in", "on", "at", "by", "for", "from",
+    "with", "as", "is", "are", "was", "were", "be", "been", "am", "art", "i", "me", "my", "mine",
+    "thee", "thy", "thou", "you", "your", "ye", "he", "him", "his", "she", "her", "it", "its",
+    "we", "us", "our", "they", "them", "their", "who", "whom", "whose", "which", "that", "shall",
+    "will", "hath", "doth", "so", "if", "than", "let",
+];
+
+/// Words ending in -ed in which the ending is pronounced as its own syllable
+const SOUNDED_ED: [&str; 8] = [
+    "wicked", "blessed", "naked", "beloved", "crooked", "learned", "ragged", "wretched",
+];
+
+/// Words of two syllables that are stressed on the second, without beginning with a common prefix
+const STRESSED_ON_SECOND: [&str; 5] = ["upon", "amen", "alone", "among", "above"];
+
+/// Prefixes that are not normally stressed
+const UNSTRESSED_PREFIXES: [&str; 14] = [
+    "a", "be", "de", "re", "un", "in", "con", "com", "dis", "ex", "for", "per", "pre", "mis",
+];
+
+/// Pairs of consonants that begin a syllable together
+const CONSONANT_CLUSTERS: [&str; 20] = [
+    "th", "sh", "ch", "ph", "wh", "bl", "br", "cl", "cr", "dr", "fl", "fr", "gl", "gr", "pl", "pr",
+    "tr", "sc", "sp", "st",
+];
+
+struct Syllable {
+    start: usize,
+    end: usize,
+    stressed: bool,
+}
+
+fn point_half_verse(text: &str, accents: usize, style: ChantStyle) -> Vec<PointingMark> {
+    let syllables = words(text)
+        .into_iter()
+        .flat_map(|(start, word)| syllables(start, word))
+        .collect::<Vec<_>>();
+
+    // choose the last stressed syllables, filling in from the end of the text if there are too few
+    let mut chosen = syllables
+        .iter()
+        .rev()
+        .filter(|syllable| syllable.stressed)
+        .take(accents)
+        .collect::<Vec<_>>();
+    if chosen.len() < accents {
+        let earliest = chosen.last().map_or(text.len(), |syllable| syllable.start);
+        chosen.extend(
+            syllables
+                .iter()
+                .rev()
+                .filter(|syllable| syllable.start < earliest && !syllable.stressed)
+                .take(accents - chosen.len()),
+        );
+        chosen.sort_by_key(|syllable| std::cmp::Reverse(syllable.start));
+    }
+
+    chosen
+        .into_iter()
+        .rev()
+        .map(|syllable| match style {
+            ChantStyle::Anglican => PointingMark::Bar(syllable.start),
+            ChantStyle::Plainsong => PointingMark::Accent(syllable.start, syllable.end),
+        })
+        .collect()
+}
+
+/// Finds the position of a flex in a long first half-verse: after the last pause (a comma,
+/// semicolon, colon, or line break) that comes before the mediation and after at least three syllables
+fn flex(text: &str, marks: &[PointingMark], flex_after: usize) -> Option<usize> {
+    let syllables = words(text)
+        .into_iter()
+        .flat_map(|(start, word)| syllables(start, word))
+        .collect::<Vec<_>>();
+    if syllables.len() <= flex_after {
+        return None;
+    }
+    let mediation = marks.iter().map(PointingMark::position).min()?;
+    text.char_indices()
+        .filter_map(|(idx, ch)| match ch {
+            ',' | ';' | ':' => Some(idx + ch.len_utf8()),
+            '\n' => Some(idx),
+            _ => None,
+        })
+        .filter(|&position| position < mediation)
+        .rfind(|&position| {
+            syllables
+                .iter()
+                .filter(|syllable| syllable.end <= position)
+                .count()
+                >= 3
+        })
+}
+
+/// Splits text into words, with the byte offset at which each begins
+fn words(text: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (idx, ch) in text.char_indices() {
+        let in_word = ch.is_alphabetic() || (start.is_some() && (ch == '\'' || ch == '’'));
+        match (in_word, start) {
+            (true, None) => start = Some(idx),
+            (false, Some(word_start)) => {
+                words.push((word_start, &text[word_start..idx]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(word_start) = start {
+        words.push((word_start, &text[word_start..]));
+    }
+    words
+}
+
+fn is_vowel(ch: char, first: bool) -> bool {
+    matches!(
+        ch.to_lowercase().next().unwrap_or(ch),
+        'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'í' | 'ó' | 'ú' | 'ü'
+    ) || (!first && matches!(ch, 'y' | 'Y'))
+}
+
+/// Divides a word, beginning at the byte offset `offset`, into syllables, and guesses which is stressed
+fn syllables(offset: usize, word: &str) -> Vec<Syllable> {
+    let lower = word.to_lowercase();
+    let chars = word.char_indices().collect::<Vec<_>>();
+    let lower_chars = lower.chars().collect::<Vec<_>>();
+    if chars.len() != lower_chars.len() {
+        return vec![Syllable {
+            start: offset,
+            end: offset + word.len(),
+            stressed: true,
+        }];
+    }
+
+    // groups of vowels, as (first char index, last char index + 1)
+    let mut groups: Vec<(usize, usize)> = Vec::new();
+    for (idx, &ch) in lower_chars.iter().enumerate() {
+        if is_vowel(ch, idx == 0) {
+            match groups.last_mut() {
+                Some((_, end)) if *end == idx => *end = idx + 1,
+                _ => groups.push((idx, idx + 1)),
+            }
+        }
+    }
+
+    // silent final -e, -es, and -ed
+    let len = lower_chars.len();
+    if groups.len() > 1 {
+        let (last_start, last_end) = groups[groups.len() - 1];
+        let single_e = last_end == last_start + 1 && lower_chars[last_start] == 'e';
+        let before = |n: usize| last_start.checked_sub(n).map(|idx| lower_chars[idx]);
+        let silent = single_e
+            && if last_start == len - 1 {
+                !(before(1) == Some('l') && before(2).is_some_and(|ch| !is_vowel(ch, false)))
+            } else if last_start == len - 2 && lower_chars[len - 1] == 's' {
+                !matches!(before(1), Some('s' | 'x' | 'z' | 'c' | 'g' | 'h'))
+            } else if last_start == len - 2 && lower_chars[len - 1] == 'd' {
+                !matches!(before(1), Some('t' | 'd')) && !SOUNDED_ED.contains(&lower.as_str())
+            } else {
+                false
+            };
+        if silent {
+            groups.pop();
+        }
+    }
+
+    // each syllable begins with the consonants that can begin it together
+    let mut starts = vec![0];
+    for pair in groups.windows(2) {
+        let (_, previous_end) = pair[0];
+        let (next_start, _) = pair[1];
+        let consonants = next_start - previous_end;
+        let start = match consonants {
+            0 | 1 => next_start - consonants,
+            _ => {
+                let cluster = lower_chars[next_start - 2..next_start]
+                    .iter()
+                    .collect::<String>();
+                if CONSONANT_CLUSTERS.contains(&cluster.as_str()) {
+                    next_start - 2
+                } else {
+                    next_start - 1
+                }
+            }
+        };
+        starts.push(start);
+    }
+    if groups.is_empty() {
+        starts.truncate(1);
+    }
+
+    let stressed = stressed_syllable(&lower, &lower_chars, &starts);
+    let byte = |char_idx: usize| chars.get(char_idx).map_or(word.len(), |(byte, _)| *byte);
+    starts
+        .iter()
+        .enumerate()
+        .map(|(idx, &start)| Syllable {
+            start: offset + byte(start),
+            end: offset + starts.get(idx + 1).map_or(word.len(), |&end| byte(end)),
+            stressed: Some(idx) == stressed,
+        })
+        .collect()
+}
+
+/// Guesses which syllable of a word is stressed, if any
+fn stressed_syllable(word: &str, chars: &[char], starts: &[usize]) -> Option<usize> {
+    let count = starts.len();
+    let first_syllable = chars[..starts.get(1).copied().unwrap_or(chars.len())]
+        .iter()
+        .collect::<String>();
+    let has_prefix = UNSTRESSED_PREFIXES.contains(&first_syllable.as_str());
+    match count {
+        1 => (!UNSTRESSED_WORDS.contains(&word)).then_some(0),
+        2 if STRESSED_ON_SECOND.contains(&word) || has_prefix => Some(1),
+        2 => Some(0),
+        _ => {
+            if ["tion", "sion", "cian", "cious", "tious", "ic"]
+                .iter()
+                .any(|ending| word.ends_with(ending))
+            {
+                Some(count - 2)
+            } else if ["ity", "ical", "ify", "ogy", "ety"]
+                .iter()
+                .any(|ending| word.ends_with(ending))
+            {
+                Some(count - 3)
+            } else if has_prefix {
+                Some(1)
+            } else {
+                Some(0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn syllable_texts(word: &str) -> Vec<(&str, bool)> {
+        syllables(0, word)
+            .into_iter()
+            .map(|syllable| (&word[syllable.start..syllable.end], syllable.stressed))
+            .collect()
+    }
+
+    #[test]
+    fn divides_words_into_syllables() {
+        assert_eq!(syllable_texts("LORD"), vec![("LORD", true)]);
+        assert_eq!(syllable_texts("the"), vec![("the", false)]);
+        assert_eq!(
+            syllable_texts("delight"),
+            vec![("de", false), ("light", true)]
+        );
+        assert_eq!(
+            syllable_texts("children"),
+            vec![("chil", true), ("dren", false)]
+        );
+        assert_eq!(
+            syllable_texts("lingered"),
+            vec![("lin", true), ("gered", false)]
+        );
+        assert_eq!(
+            syllable_texts("wicked"),
+            vec![("wic", true), ("ked", false)]
+        );
+        assert_eq!(
+            syllable_texts("salvation"),
+            vec![("sal", false), ("va", true), ("tion", false)]
+        );
+        assert_eq!(
+            syllable_texts("temple"),
+            vec![("tem", true), ("ple", false)]
+        );
+    }
+
+    #[test]
+    fn flexes_long_first_half_verses() {
+        let a = "They are like trees planted by streams of water,\nbearing fruit in due season, with leaves that do not wither; *";
+        let b = "everything they do shall prosper.";
+        let pointing = Pointing::new(a, b, &ChantTone::PLAINSONG_SINGLE_ACCENT);
+        let flex = a.find("season,").unwrap() + "season,".len();
+        assert_eq!(pointing.a[0], PointingMark::Flex(flex));
+        assert_eq!(
+            Pointing::apply(a, &pointing.a).last(),
+            Some(&PointedText::Text("ther; *"))
+        );
+
+        let anglican = Pointing::new(a, b, &ChantTone::ANGLICAN_SINGLE);
+        assert!(!anglican
+            .a
+            .iter()
+            .any(|mark| matches!(mark, PointingMark::Flex(_))));
+    }
+
+    #[test]
+    fn points_short_half_verses_as_far_as_possible() {
+        let pointing = Pointing::new("O God, *", "", &ChantTone::ANGLICAN_SINGLE);
+        assert_eq!(pointing.a, vec![PointingMark::Bar(0), PointingMark::Bar(2)]);
+        assert!(pointing.b.is_empty());
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{Content, Litany, ResponsivePrayer, Text};
+use crate::{Content, InlineText, Litany, ResponsivePrayer, Text};
 
 /// An explanatory sentence or direction for the liturgy
 #[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -20,6 +20,14 @@ impl Preces {
     pub fn push(&mut self, line: (String, String)) {
         self.0.push(line);
     }
+
+    /// Each line's label, and its text parsed into italics, small capitals, optional words, and line breaks
+    pub fn inline(&self) -> Vec<(String, InlineText)> {
+        self.0
+            .iter()
+            .map(|(label, text)| (label.clone(), InlineText::from(text)))
+            .collect()
+    }
 }
 
 impl<T, A, B> From<T> for Preces
@@ -4,8 +4,8 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use strum_macros::{AsRefStr, Display, EnumIter, EnumString, IntoStaticStr};
 
-use crate::{Version, SlugPath};
-use lectionary::ReadingType;
+use crate::{ChantTone, Names, PsalmRecitation, SlugPath, Version};
+use lectionary::{ReadingType, WeekdayCycle};
 
 /// An explanatory sentence or direction for the liturgy
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -35,6 +35,10 @@ pub enum GlobalPref {
     BibleVersion,
     PsalterVersion,
     Lectionary,
+    /// How readings are chosen for the Eucharist on weekdays without propers of their own
+    /// (see [WeekdayCycle](lectionary::WeekdayCycle)); if this is set, ferial weekdays follow it
+    /// instead of the RCL [Lectionary](GlobalPref::Lectionary), which is still used on Sundays and holy days
+    WeekdayCycle,
     PsalmCycle,
     CanticleTable,
     OmitForeOffice,
@@ -47,6 +51,80 @@ pub enum GlobalPref {
     /// Whether to insert the Gloria Patri after each psalm in the Daily Office, or only at the end of the psalms
     InsertGloria,
     GloriaPatriTraditional,
+    /// How psalms and canticles are to be recited (see [PsalmRecitation](crate::PsalmRecitation))
+    PsalmRecitation,
+    /// The chant tone for which psalms and canticles in English are pointed (see [ChantTone](crate::ChantTone));
+    /// if it is not set, they are not pointed
+    ChantTone,
+    /// The name and pronouns of the person who has died (see [PlaceholderRole](crate::PlaceholderRole))
+    DeceasedName,
+    /// The names of the two people being married or blessed
+    CoupleNames,
+    /// The names of those being baptized, confirmed, received, or ordained
+    CandidateNames,
+    /// The name of the bishop, or names of the bishops, of the diocese
+    BishopName,
+    /// The name of the parish or congregation
+    ParishName,
+    /// Whether to use the expansive-language alternatives from *Enriching Our Worship* in English Rite II
+    /// liturgies. Only the Gloria Patri, the confession and absolution, the canticles, and the Eucharistic
+    /// Prayers are replaced; the opening sentences, the invitatory, the collects, and the dismissal are not.
+    ExpansiveLanguage,
+    /// The identifier of the Anglican Cycle of Prayer, from which intercessions are inserted into the liturgy
+    /// (see [CycleOfPrayerId](crate::CycleOfPrayerId))
+    AnglicanCycleOfPrayer,
+    /// The identifier of the cycle of prayer of the diocese, from which intercessions are inserted into the liturgy
+    DiocesanCycleOfPrayer,
+}
+
+impl PreferenceKey {
+    /// The name of the preference, as it is written in a [Condition](crate::Condition)
+    pub fn name(&self) -> &str {
+        match self {
+            PreferenceKey::Global(key) => key.as_ref(),
+            PreferenceKey::Local(key) => key,
+        }
+    }
+}
+
+impl GlobalPref {
+    /// Whether the value is of the kind that the compiler expects for this preference
+    /// (e.g., a [Lectionaries] for [GlobalPref::Lectionary])
+    pub fn accepts(&self, value: &PreferenceValue) -> bool {
+        match self {
+            GlobalPref::Language => matches!(value, PreferenceValue::Language(_)),
+            GlobalPref::Calendar => matches!(value, PreferenceValue::Local(_)),
+            GlobalPref::Version | GlobalPref::BibleVersion | GlobalPref::PsalterVersion => {
+                matches!(value, PreferenceValue::Version(_))
+            }
+            GlobalPref::Lectionary | GlobalPref::PsalmCycle => {
+                matches!(value, PreferenceValue::Lectionary(_))
+            }
+            GlobalPref::WeekdayCycle => matches!(value, PreferenceValue::WeekdayCycle(_)),
+            GlobalPref::CanticleTable => matches!(value, PreferenceValue::CanticleTable(_)),
+            GlobalPref::OmitForeOffice
+            | GlobalPref::UseBlackLetterCollects
+            | GlobalPref::InsertGloria
+            | GlobalPref::GloriaPatriTraditional
+            | GlobalPref::ExpansiveLanguage => matches!(value, PreferenceValue::Bool(_)),
+            GlobalPref::ReadingA | GlobalPref::ReadingB | GlobalPref::ReadingC => {
+                matches!(value, PreferenceValue::ReadingType(_))
+            }
+            GlobalPref::CanticleOne | GlobalPref::CanticleTwo => {
+                matches!(value, PreferenceValue::Canticle(_))
+            }
+            GlobalPref::PsalmRecitation => matches!(value, PreferenceValue::PsalmRecitation(_)),
+            GlobalPref::ChantTone => matches!(value, PreferenceValue::ChantTone(_)),
+            GlobalPref::DeceasedName
+            | GlobalPref::CoupleNames
+            | GlobalPref::CandidateNames
+            | GlobalPref::BishopName
+            | GlobalPref::ParishName => matches!(value, PreferenceValue::Names(_)),
+            GlobalPref::AnglicanCycleOfPrayer | GlobalPref::DiocesanCycleOfPrayer => {
+                matches!(value, PreferenceValue::CycleOfPrayer(_))
+            }
+        }
+    }
 }
 
 impl<T> From<T> for PreferenceKey
@@ -71,9 +149,15 @@ pub enum PreferenceValue {
     Language(Language),
     Version(Version),
     Lectionary(Lectionaries),
+    WeekdayCycle(WeekdayCycle),
     CanticleTable(CanticleTables),
     Canticle(SlugPath),
     ReadingType(ReadingType),
+    PsalmRecitation(PsalmRecitation),
+    ChantTone(ChantTone),
+    Names(Names),
+    /// The identifier of a [CycleOfPrayer](crate::CycleOfPrayer), which the library resolves to its entries
+    CycleOfPrayer(String),
     Local(String),
     Bool(bool),
 }
@@ -96,6 +180,12 @@ impl From<Lectionaries> for PreferenceValue {
     }
 }
 
+impl From<WeekdayCycle> for PreferenceValue {
+    fn from(cycle: WeekdayCycle) -> Self {
+        Self::WeekdayCycle(cycle)
+    }
+}
+
 impl From<CanticleTables> for PreferenceValue {
     fn from(table: CanticleTables) -> Self {
         Self::CanticleTable(table)
@@ -108,6 +198,24 @@ impl From<ReadingType> for PreferenceValue {
     }
 }
 
+impl From<PsalmRecitation> for PreferenceValue {
+    fn from(recitation: PsalmRecitation) -> Self {
+        Self::PsalmRecitation(recitation)
+    }
+}
+
+impl From<ChantTone> for PreferenceValue {
+    fn from(tone: ChantTone) -> Self {
+        Self::ChantTone(tone)
+    }
+}
+
+impl From<Names> for PreferenceValue {
+    fn from(names: Names) -> Self {
+        Self::Names(names)
+    }
+}
+
 impl From<bool> for PreferenceValue {
     fn from(value: bool) -> Self {
         Self::Bool(value)
@@ -122,6 +230,12 @@ impl Default for PreferenceValue {
 
 pub trait ClientPreferences {
     fn value(&self, key: &PreferenceKey) -> Option<&PreferenceValue>;
+
+    /// The key of each preference for which [ClientPreferences::value] gives a value. By default, none are
+    /// listed, so [LiturgyPreferences::validate] has nothing to check.
+    fn preference_keys(&self) -> Vec<&PreferenceKey> {
+        Vec::new()
+    }
 }
 
 impl ClientPreferences for [(PreferenceKey, PreferenceValue); 0] {
@@ -134,6 +248,10 @@ impl ClientPreferences for HashMap<PreferenceKey, PreferenceValue> {
     fn value(&self, key: &PreferenceKey) -> Option<&PreferenceValue> {
         self.get(key)
     }
+
+    fn preference_keys(&self) -> Vec<&PreferenceKey> {
+        self.keys().collect()
+    }
 }
 
 #[derive(
@@ -156,6 +274,8 @@ pub enum Lectionaries {
     BCP1979ThirtyDayPsalms,
     RCLTrack1,
     RCLTrack2,
+    /// Readings for weekday celebrations of the Eucharist; see `lectionary::WEEKDAY_EUCHARIST`
+    WeekdayEucharist,
 }
 
 impl Default for Lectionaries {
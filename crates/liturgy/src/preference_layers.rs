@@ -0,0 +1,451 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ClientPreferences, LiturgyPreferences, PreferenceKey, PreferenceValue, SlugPath};
+
+/// The layers of a [LayeredPreferences], in order of precedence: a value set in a later layer
+/// overrides a value set in an earlier one, unless the earlier layer has locked it.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum PreferenceLayerId {
+    /// Defaults for every user of the application
+    Global,
+    Diocese,
+    Parish,
+    /// The individual user's own choices
+    User,
+}
+
+/// A value set for a preference in one layer of a [LayeredPreferences]
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LayeredPreference {
+    pub key: PreferenceKey,
+    pub value: PreferenceValue,
+    /// If set, the value only applies to liturgies whose path begins with this one
+    /// (e.g., `Office` for every office, or `Office/MorningPrayer` for Morning Prayer)
+    pub scope: Option<SlugPath>,
+    /// Whether later layers are prevented from overriding this value
+    pub locked: bool,
+}
+
+/// The preferences set by one authority, e.g., the defaults of a parish
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PreferenceLayer {
+    pub preferences: Vec<LayeredPreference>,
+}
+
+impl PreferenceLayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the preference for every liturgy
+    #[must_use]
+    pub fn set(self, key: impl Into<PreferenceKey>, value: impl Into<PreferenceValue>) -> Self {
+        self.insert(key.into(), value.into(), None, false)
+    }
+
+    /// Sets the preference for liturgies whose path begins with the given path
+    #[must_use]
+    pub fn set_for(
+        self,
+        scope: SlugPath,
+        key: impl Into<PreferenceKey>,
+        value: impl Into<PreferenceValue>,
+    ) -> Self {
+        self.insert(key.into(), value.into(), Some(scope), false)
+    }
+
+    /// Sets the preference for every liturgy, and prevents later layers from overriding it
+    #[must_use]
+    pub fn lock(self, key: impl Into<PreferenceKey>, value: impl Into<PreferenceValue>) -> Self {
+        self.insert(key.into(), value.into(), None, true)
+    }
+
+    /// Sets the preference for liturgies whose path begins with the given path, and prevents later layers from overriding it
+    #[must_use]
+    pub fn lock_for(
+        self,
+        scope: SlugPath,
+        key: impl Into<PreferenceKey>,
+        value: impl Into<PreferenceValue>,
+    ) -> Self {
+        self.insert(key.into(), value.into(), Some(scope), true)
+    }
+
+    fn insert(
+        mut self,
+        key: PreferenceKey,
+        value: PreferenceValue,
+        scope: Option<SlugPath>,
+        locked: bool,
+    ) -> Self {
+        self.preferences
+            .retain(|pref| !(pref.key == key && pref.scope == scope));
+        self.preferences.push(LayeredPreference {
+            key,
+            value,
+            scope,
+            locked,
+        });
+        self
+    }
+
+    /// The value of this layer that applies to the liturgy: a locked value if there is one, so that a
+    /// lock cannot be bypassed by an unlocked value scoped to the liturgy, and otherwise (or among
+    /// locked values) the one with the most specific scope
+    fn preference_for(
+        &self,
+        liturgy: Option<&SlugPath>,
+        key: &PreferenceKey,
+    ) -> Option<&LayeredPreference> {
+        self.preferences
+            .iter()
+            .filter(|pref| pref.key == *key)
+            .filter(|pref| match (&pref.scope, liturgy) {
+                (None, _) => true,
+                (Some(scope), Some(liturgy)) => liturgy.as_slice().starts_with(scope.as_slice()),
+                (Some(_), None) => false,
+            })
+            .max_by_key(|pref| {
+                (
+                    pref.locked,
+                    pref.scope.as_ref().map(|scope| scope.as_slice().len()),
+                )
+            })
+    }
+}
+
+/// [ClientPreferences] built up from several [PreferenceLayer]s, so that a parish can set defaults
+/// that its members may override, and a diocese can lock preferences that its parishes may not.
+///
+/// For each preference, the value that applies to the liturgy in each layer is found (preferring a locked
+/// value, and then a value scoped to the liturgy over one set for every liturgy). If any layer has locked its value, the earliest
+/// such layer's value is used; otherwise, the latest layer's value is used. Preferences set in no layer
+/// fall back to the liturgy's own defaults when it is compiled.
+/// ```
+/// # use liturgy::*;
+/// let prefs = LayeredPreferences::new()
+///     .layer(PreferenceLayerId::Diocese, PreferenceLayer::new().lock(GlobalPref::BibleVersion, Version::NRSV))
+///     .layer(
+///         PreferenceLayerId::Parish,
+///         PreferenceLayer::new()
+///             .set(GlobalPref::InsertGloria, true)
+///             .set_for(SlugPath::from([Slug::Office]), GlobalPref::CanticleTable, CanticleTables::EOW),
+///     )
+///     .layer(
+///         PreferenceLayerId::User,
+///         PreferenceLayer::new()
+///             .set(GlobalPref::InsertGloria, false)
+///             .set(GlobalPref::BibleVersion, Version::KJV),
+///     );
+///
+/// let morning_prayer = prefs.for_liturgy(SlugPath::from([Slug::Office, Slug::MorningPrayer]));
+/// assert_eq!(morning_prayer.value(&PreferenceKey::from(GlobalPref::InsertGloria)), Some(&PreferenceValue::Bool(false)));
+/// assert_eq!(morning_prayer.value(&PreferenceKey::from(GlobalPref::BibleVersion)), Some(&PreferenceValue::Version(Version::NRSV)));
+/// assert_eq!(morning_prayer.value(&PreferenceKey::from(GlobalPref::CanticleTable)), Some(&PreferenceValue::CanticleTable(CanticleTables::EOW)));
+///
+/// let eucharist = prefs.for_liturgy(SlugPath::from([Slug::Eucharist]));
+/// assert_eq!(eucharist.value(&PreferenceKey::from(GlobalPref::CanticleTable)), None);
+/// ```
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LayeredPreferences {
+    pub layers: BTreeMap<PreferenceLayerId, PreferenceLayer>,
+}
+
+/// A [LayeredPreferences] as it applies to a particular liturgy, which can be passed to the compiler
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ScopedPreferences<'a> {
+    preferences: &'a LayeredPreferences,
+    liturgy: SlugPath,
+}
+
+/// Where the value of a preference came from, as given by [LayeredPreferences::explain]
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PreferenceExplanation {
+    pub key: PreferenceKey,
+    pub value: PreferenceValue,
+    /// The layer that supplied the value, or `None` if it is the liturgy's default
+    pub layer: Option<PreferenceLayerId>,
+    /// The scope of the value in its layer, if it was not set for every liturgy
+    pub scope: Option<SlugPath>,
+    /// Whether the value was locked by its layer
+    pub locked: bool,
+    /// The values that other layers set for this preference, which were not used
+    pub overridden: Vec<(PreferenceLayerId, PreferenceValue)>,
+}
+
+impl LayeredPreferences {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the layer, replacing any layer with the same identifier
+    #[must_use]
+    pub fn layer(mut self, id: PreferenceLayerId, layer: PreferenceLayer) -> Self {
+        self.layers.insert(id, layer);
+        self
+    }
+
+    /// The preferences as they apply to the liturgy found at the given path in a library's table of contents
+    pub fn for_liturgy(&self, liturgy: SlugPath) -> ScopedPreferences<'_> {
+        ScopedPreferences {
+            preferences: self,
+            liturgy,
+        }
+    }
+
+    /// Explains where the value of the preference comes from for the given liturgy (or, if `None`,
+    /// from the preferences set for every liturgy), falling back to the liturgy's own default.
+    /// ```
+    /// # use liturgy::*;
+    /// let prefs = LayeredPreferences::new()
+    ///     .layer(PreferenceLayerId::Diocese, PreferenceLayer::new().lock(GlobalPref::BibleVersion, Version::NRSV))
+    ///     .layer(PreferenceLayerId::User, PreferenceLayer::new().set(GlobalPref::BibleVersion, Version::KJV));
+    /// let explanation = prefs
+    ///     .explain(None, &PreferenceKey::from(GlobalPref::BibleVersion), &LiturgyPreferences::default())
+    ///     .unwrap();
+    /// assert_eq!(explanation.layer, Some(PreferenceLayerId::Diocese));
+    /// assert!(explanation.locked);
+    /// assert_eq!(explanation.overridden, [(PreferenceLayerId::User, PreferenceValue::from(Version::KJV))]);
+    /// ```
+    pub fn explain(
+        &self,
+        liturgy: Option<&SlugPath>,
+        key: &PreferenceKey,
+        liturgy_prefs: &LiturgyPreferences,
+    ) -> Option<PreferenceExplanation> {
+        let candidates = self.candidates(liturgy, key);
+        match self.resolve(&candidates) {
+            Some(idx) => {
+                let (layer, pref) = candidates[idx];
+                Some(PreferenceExplanation {
+                    key: key.clone(),
+                    value: pref.value.clone(),
+                    layer: Some(layer),
+                    scope: pref.scope.clone(),
+                    locked: pref.locked,
+                    overridden: candidates
+                        .iter()
+                        .enumerate()
+                        .filter(|(c_idx, _)| *c_idx != idx)
+                        .map(|(_, (layer, pref))| (*layer, pref.value.clone()))
+                        .collect(),
+                })
+            }
+            None => liturgy_prefs
+                .default_value_for_key(key)
+                .map(|value| PreferenceExplanation {
+                    key: key.clone(),
+                    value: value.clone(),
+                    layer: None,
+                    scope: None,
+                    locked: false,
+                    overridden: Vec::new(),
+                }),
+        }
+    }
+
+    /// Explains each of the liturgy's preferences, and any other preference set in any layer, in that order
+    pub fn explain_all(
+        &self,
+        liturgy: Option<&SlugPath>,
+        liturgy_prefs: &LiturgyPreferences,
+    ) -> Vec<PreferenceExplanation> {
+        let mut keys: Vec<&PreferenceKey> = liturgy_prefs.iter().map(|pref| &pref.key).collect();
+        for pref in self.layers.values().flat_map(|layer| &layer.preferences) {
+            if !keys.contains(&&pref.key) {
+                keys.push(&pref.key);
+            }
+        }
+        keys.into_iter()
+            .filter_map(|key| self.explain(liturgy, key, liturgy_prefs))
+            .collect()
+    }
+
+    /// The value that applies to the liturgy in each layer that sets one, in order of precedence
+    fn candidates(
+        &self,
+        liturgy: Option<&SlugPath>,
+        key: &PreferenceKey,
+    ) -> Vec<(PreferenceLayerId, &LayeredPreference)> {
+        self.layers
+            .iter()
+            .filter_map(|(id, layer)| layer.preference_for(liturgy, key).map(|pref| (*id, pref)))
+            .collect()
+    }
+
+    /// The index of the candidate that is used: the earliest that is locked, or else the latest
+    fn resolve(&self, candidates: &[(PreferenceLayerId, &LayeredPreference)]) -> Option<usize> {
+        candidates
+            .iter()
+            .position(|(_, pref)| pref.locked)
+            .or_else(|| candidates.len().checked_sub(1))
+    }
+
+    fn value_for(
+        &self,
+        liturgy: Option<&SlugPath>,
+        key: &PreferenceKey,
+    ) -> Option<&PreferenceValue> {
+        let candidates = self.candidates(liturgy, key);
+        self.resolve(&candidates)
+            .map(|idx| &candidates[idx].1.value)
+    }
+
+    /// The key of each preference set in any layer that applies to the liturgy
+    fn keys_for(&self, liturgy: Option<&SlugPath>) -> Vec<&PreferenceKey> {
+        let mut keys: Vec<&PreferenceKey> = Vec::new();
+        for pref in self.layers.values().flat_map(|layer| &layer.preferences) {
+            if !keys.contains(&&pref.key) && self.value_for(liturgy, &pref.key).is_some() {
+                keys.push(&pref.key);
+            }
+        }
+        keys
+    }
+}
+
+/// Without a liturgy, only the preferences that are set for every liturgy apply
+impl ClientPreferences for LayeredPreferences {
+    fn value(&self, key: &PreferenceKey) -> Option<&PreferenceValue> {
+        self.value_for(None, key)
+    }
+
+    fn preference_keys(&self) -> Vec<&PreferenceKey> {
+        self.keys_for(None)
+    }
+}
+
+impl<'a> ClientPreferences for ScopedPreferences<'a> {
+    fn value(&self, key: &PreferenceKey) -> Option<&PreferenceValue> {
+        self.preferences.value_for(Some(&self.liturgy), key)
+    }
+
+    fn preference_keys(&self) -> Vec<&PreferenceKey> {
+        self.preferences.keys_for(Some(&self.liturgy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        CanticleTables, ClientPreferences, GlobalPref, LayeredPreferences, Lectionaries,
+        PreferenceKey, PreferenceLayer, PreferenceLayerId, PreferenceValue, Slug, SlugPath,
+        Version,
+    };
+
+    #[test]
+    fn scoped_values_are_preferred_within_a_layer_but_not_across_layers() {
+        let prefs = LayeredPreferences::new()
+            .layer(
+                PreferenceLayerId::Parish,
+                PreferenceLayer::new()
+                    .set(GlobalPref::CanticleTable, CanticleTables::BCP1979RiteII)
+                    .set_for(
+                        SlugPath::from([Slug::Office]),
+                        GlobalPref::CanticleTable,
+                        CanticleTables::EOW,
+                    )
+                    .set_for(
+                        SlugPath::from([Slug::Office, Slug::EveningPrayer]),
+                        GlobalPref::CanticleTable,
+                        CanticleTables::Classical,
+                    )
+                    .set_for(
+                        SlugPath::from([Slug::Office, Slug::MorningPrayer]),
+                        GlobalPref::PsalmCycle,
+                        Lectionaries::BCP1979ThirtyDayPsalms,
+                    ),
+            )
+            .layer(
+                PreferenceLayerId::User,
+                PreferenceLayer::new().set(
+                    GlobalPref::PsalmCycle,
+                    Lectionaries::BCP1979DailyOfficePsalms,
+                ),
+            );
+        let canticle_table = PreferenceKey::from(GlobalPref::CanticleTable);
+        let psalm_cycle = PreferenceKey::from(GlobalPref::PsalmCycle);
+
+        let morning_prayer = prefs.for_liturgy(SlugPath::from([
+            Slug::Office,
+            Slug::MorningPrayer,
+            Slug::Version(Version::RiteII),
+        ]));
+        let evening_prayer = prefs.for_liturgy(SlugPath::from([Slug::Office, Slug::EveningPrayer]));
+        assert_eq!(
+            morning_prayer.value(&canticle_table),
+            Some(&PreferenceValue::from(CanticleTables::EOW))
+        );
+        assert_eq!(
+            evening_prayer.value(&canticle_table),
+            Some(&PreferenceValue::from(CanticleTables::Classical))
+        );
+        assert_eq!(
+            prefs.value(&canticle_table),
+            Some(&PreferenceValue::from(CanticleTables::BCP1979RiteII))
+        );
+        // the user's own choice overrides the parish's, even though the parish's is specific to the liturgy
+        assert_eq!(
+            morning_prayer.value(&psalm_cycle),
+            Some(&PreferenceValue::from(
+                Lectionaries::BCP1979DailyOfficePsalms
+            ))
+        );
+    }
+
+    #[test]
+    fn earliest_lock_wins() {
+        let prefs = LayeredPreferences::new()
+            .layer(
+                PreferenceLayerId::Global,
+                PreferenceLayer::new().set(GlobalPref::BibleVersion, Version::ESV),
+            )
+            .layer(
+                PreferenceLayerId::Diocese,
+                PreferenceLayer::new().lock(GlobalPref::BibleVersion, Version::NRSV),
+            )
+            .layer(
+                PreferenceLayerId::Parish,
+                PreferenceLayer::new().lock(GlobalPref::BibleVersion, Version::KJV),
+            )
+            .layer(
+                PreferenceLayerId::User,
+                PreferenceLayer::new().set(GlobalPref::BibleVersion, Version::CEB),
+            );
+        let explanation = prefs
+            .explain_all(None, &Default::default())
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_eq!(explanation.value, PreferenceValue::from(Version::NRSV));
+        assert_eq!(explanation.layer, Some(PreferenceLayerId::Diocese));
+        assert_eq!(explanation.overridden.len(), 3);
+    }
+
+    #[test]
+    fn unlocked_scoped_value_does_not_bypass_lock_in_same_layer() {
+        let office = SlugPath::from([Slug::Office]);
+        let prefs = LayeredPreferences::new()
+            .layer(
+                PreferenceLayerId::Diocese,
+                PreferenceLayer::new()
+                    .lock(GlobalPref::BibleVersion, Version::NRSV)
+                    .set_for(office.clone(), GlobalPref::BibleVersion, Version::KJV),
+            )
+            .layer(
+                PreferenceLayerId::User,
+                PreferenceLayer::new().set(GlobalPref::BibleVersion, Version::CEB),
+            );
+        let bible_version = PreferenceKey::from(GlobalPref::BibleVersion);
+        assert_eq!(
+            prefs.for_liturgy(office.clone()).value(&bible_version),
+            Some(&PreferenceValue::from(Version::NRSV))
+        );
+        let explanation = prefs
+            .explain(Some(&office), &bible_version, &Default::default())
+            .unwrap();
+        assert!(explanation.locked);
+        assert_eq!(explanation.scope, None);
+    }
+}
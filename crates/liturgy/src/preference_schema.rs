@@ -0,0 +1,221 @@
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "json-schema")]
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+
+use crate::{ClientPreferences, LiturgyPreferences, PreferenceKey, PreferenceValue};
+
+/// A client preference that does not fit the [LiturgyPreferences] of the liturgy for which it was given
+#[derive(Error, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PreferenceError {
+    #[error("{} is not a preference of this liturgy", .0.name())]
+    UnknownKey(PreferenceKey),
+    #[error("{value:?} is not a valid value for {}", .key.name())]
+    InvalidValue {
+        key: PreferenceKey,
+        value: PreferenceValue,
+        /// The values that may be given for this preference, if it is one of the liturgy's declared preferences
+        expected: Vec<PreferenceValue>,
+    },
+}
+
+impl PreferenceError {
+    pub fn key(&self) -> &PreferenceKey {
+        match self {
+            PreferenceError::UnknownKey(key) => key,
+            PreferenceError::InvalidValue { key, .. } => key,
+        }
+    }
+}
+
+impl LiturgyPreferences {
+    /// Checks each of the client's preferences against the preferences declared for this liturgy, so that
+    /// a mistyped key or value is reported rather than silently ignored by the compiler.
+    ///
+    /// A declared preference must have the value of one of its options. A [GlobalPref](crate::GlobalPref)
+    /// that is not declared (e.g., [GlobalPref::ParishName](crate::GlobalPref::ParishName)) is used by the compiler
+    /// in any liturgy, and only needs to have the kind of value the compiler expects; a local preference
+    /// that is not declared is unknown.
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use liturgy::*;
+    /// let preferences = LiturgyPreferences::from([LiturgyPreference::from((
+    ///     PreferenceKey::from("sermon"),
+    ///     "Sermon",
+    ///     [
+    ///         LiturgyPreferenceOption::from(("After the Readings", PreferenceValue::Local("after_readings".into()))),
+    ///         LiturgyPreferenceOption::from(("None", PreferenceValue::Local("none".into()))),
+    ///     ],
+    /// ))]);
+    ///
+    /// let mut prefs = HashMap::new();
+    /// prefs.insert(PreferenceKey::from("sermon"), PreferenceValue::Local("after_readings".into()));
+    /// prefs.insert(PreferenceKey::from(GlobalPref::Lectionary), PreferenceValue::from(Lectionaries::RCLTrack1));
+    /// assert!(preferences.validate(&prefs).is_empty());
+    ///
+    /// prefs.insert(PreferenceKey::from("sermon"), PreferenceValue::Local("after_reading".into()));
+    /// prefs.insert(PreferenceKey::from("sermn"), PreferenceValue::Local("none".into()));
+    /// let errors = preferences.validate(&prefs);
+    /// assert_eq!(errors[0], PreferenceError::UnknownKey(PreferenceKey::from("sermn")));
+    /// assert!(matches!(errors[1], PreferenceError::InvalidValue { .. }));
+    ///
+    /// // layered preferences are checked as they apply to the liturgy
+    /// let layered = LayeredPreferences::new().layer(
+    ///     PreferenceLayerId::Parish,
+    ///     PreferenceLayer::new().set_for(SlugPath::from([Slug::Eucharist]), PreferenceKey::from("sermon"), PreferenceValue::Local("later".into())),
+    /// );
+    /// assert!(preferences.validate(&layered).is_empty());
+    /// assert_eq!(preferences.validate(&layered.for_liturgy(SlugPath::from([Slug::Eucharist]))).len(), 1);
+    /// ```
+    pub fn validate(&self, prefs: &impl ClientPreferences) -> Vec<PreferenceError> {
+        let mut errors = prefs
+            .preference_keys()
+            .into_iter()
+            .filter_map(|key| prefs.value(key).map(|value| (key, value)))
+            .filter_map(
+                |(key, value)| match (self.iter().find(|pref| pref.key == *key), key) {
+                    (Some(pref), _) => {
+                        if pref.choices().any(|option| option.value == *value) {
+                            None
+                        } else {
+                            Some(PreferenceError::InvalidValue {
+                                key: key.clone(),
+                                value: value.clone(),
+                                expected: pref
+                                    .choices()
+                                    .map(|option| option.value.clone())
+                                    .collect(),
+                            })
+                        }
+                    }
+                    (None, PreferenceKey::Global(global)) => {
+                        if global.accepts(value) {
+                            None
+                        } else {
+                            Some(PreferenceError::InvalidValue {
+                                key: key.clone(),
+                                value: value.clone(),
+                                expected: Vec::new(),
+                            })
+                        }
+                    }
+                    (None, PreferenceKey::Local(_)) => {
+                        Some(PreferenceError::UnknownKey(key.clone()))
+                    }
+                },
+            )
+            .collect::<Vec<_>>();
+        errors.sort_by(|a, b| a.key().name().cmp(b.key().name()));
+        errors
+    }
+
+    /// Describes the preferences as a [JSON Schema](https://json-schema.org/) for an object with one property for
+    /// each preference, named as in [PreferenceKey::name]. Each value is the serialized [PreferenceValue]
+    /// of one of its options. The serialized [PreferenceKey] and the category of each preference are given
+    /// as `x-key` and `x-category`, so that a front end can build its settings from the schema.
+    /// Requires the `json-schema` feature.
+    /// ```
+    /// # use liturgy::*;
+    /// # use serde_json::json;
+    /// let preferences = LiturgyPreferences::from([LiturgyPreference::from((
+    ///     PreferenceKey::from(GlobalPref::Lectionary),
+    ///     "Readings",
+    ///     [
+    ///         LiturgyPreferenceOption::from(Lectionaries::RCLTrack1),
+    ///         LiturgyPreferenceOption::from(Lectionaries::RCLTrack2),
+    ///     ],
+    /// ))
+    /// .category("Lectionary")]);
+    /// let schema = preferences.json_schema("Eucharist");
+    /// assert_eq!(schema["title"], "Eucharist");
+    /// assert_eq!(
+    ///     schema["properties"]["Lectionary"],
+    ///     json!({
+    ///         "title": "Readings",
+    ///         "x-key": { "Global": "Lectionary" },
+    ///         "x-category": "Lectionary",
+    ///         "default": { "Lectionary": "RCLTrack1" },
+    ///         "oneOf": [
+    ///             { "const": { "Lectionary": "RCLTrack1" }, "title": "RCLTrack1" },
+    ///             { "const": { "Lectionary": "RCLTrack2" }, "title": "RCLTrack2" }
+    ///         ]
+    ///     })
+    /// );
+    /// ```
+    #[cfg(feature = "json-schema")]
+    pub fn json_schema(&self, title: &str) -> Value {
+        let properties = self
+            .iter()
+            .map(|pref| {
+                let mut property = Map::new();
+                property.insert("title".into(), json!(pref.label));
+                if let Some(description) = &pref.description {
+                    property.insert("description".into(), json!(description));
+                }
+                property.insert("x-key".into(), json!(pref.key));
+                if let Some(category) = &pref.category {
+                    property.insert("x-category".into(), json!(category));
+                }
+                property.insert(
+                    "default".into(),
+                    json!(self.default_value_for_key(&pref.key)),
+                );
+                property.insert(
+                    "oneOf".into(),
+                    pref.choices()
+                        .map(|option| json!({ "const": option.value, "title": option.label }))
+                        .collect(),
+                );
+                (pref.key.name().to_string(), Value::Object(property))
+            })
+            .collect::<Map<_, _>>();
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": title,
+            "type": "object",
+            "properties": properties
+        })
+    }
+
+    /// Describes the preferences as a TypeScript interface with the given name, in which each property is
+    /// a union of the serialized values of its options. Requires the `json-schema` feature.
+    /// ```
+    /// # use liturgy::*;
+    /// let preferences = LiturgyPreferences::from([LiturgyPreference::from((
+    ///     PreferenceKey::from(GlobalPref::Lectionary),
+    ///     "Readings",
+    ///     [
+    ///         LiturgyPreferenceOption::from(Lectionaries::RCLTrack1),
+    ///         LiturgyPreferenceOption::from(Lectionaries::RCLTrack2),
+    ///     ],
+    /// ))]);
+    /// assert_eq!(
+    ///     preferences.typescript("EucharistPreferences"),
+    ///     "export interface EucharistPreferences {\n  /** Readings */\n  \"Lectionary\"?: {\"Lectionary\":\"RCLTrack1\"} | {\"Lectionary\":\"RCLTrack2\"};\n}\n"
+    /// );
+    /// ```
+    #[cfg(feature = "json-schema")]
+    pub fn typescript(&self, name: &str) -> String {
+        let properties = self
+            .iter()
+            .map(|pref| {
+                let comment = match &pref.description {
+                    Some(description) => format!("{}: {}", pref.label, description),
+                    None => pref.label.clone(),
+                };
+                let values = pref
+                    .choices()
+                    .map(|option| json!(option.value).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" | ");
+                format!(
+                    "  /** {} */\n  {}?: {};\n",
+                    comment.replace("*/", "*\\/"),
+                    json!(pref.key.name()),
+                    values
+                )
+            })
+            .collect::<String>();
+        format!("export interface {} {{\n{}}}\n", name, properties)
+    }
+}
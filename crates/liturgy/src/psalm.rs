@@ -1,10 +1,12 @@
 use reference_parser::{BibleReference, BibleVerse, BibleVersePart, Book};
 use serde::{Deserialize, Serialize};
 
-use crate::Reference;
+use crate::{
+    recitation::verse_as_refrain, Antiphon, ChantTone, InlineText, Pointing, Recitation, Reference,
+};
 
 /// Represents an entire psalm
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Psalm {
     /// The psalm number (e.g., 8 for Psalm 8)
     pub number: u8,
@@ -12,6 +14,9 @@ pub struct Psalm {
     pub citation: Option<String>,
     /// The content of the psalm, by section
     pub sections: Vec<PsalmSection>,
+    /// How the psalm is to be recited, if specified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recitation: Option<Recitation>,
 }
 
 impl Psalm {
@@ -21,6 +26,28 @@ impl Psalm {
         self
     }
 
+    /// The refrain to be said by the people, if the psalm is recited responsorially
+    pub fn refrain(&self) -> Option<Antiphon> {
+        let recitation = self.recitation.as_ref().filter(|r| r.has_refrain())?;
+        recitation.refrain.clone().or_else(|| {
+            let verse = self.sections.first()?.verses.first()?;
+            Some(verse_as_refrain(&verse.a, &verse.b))
+        })
+    }
+
+    /// Points every verse of the psalm for the given tone (see [Pointing::new]).
+    #[must_use]
+    pub fn pointed(mut self, tone: &ChantTone) -> Self {
+        for verse in self
+            .sections
+            .iter_mut()
+            .flat_map(|section| section.verses.iter_mut())
+        {
+            verse.pointing = Some(Pointing::new(&verse.a, &verse.b, tone));
+        }
+        self
+    }
+
     /// Returns only the verses and sections of a psalm that are included in its citation.
     /// ```
     /// # use psalter::bcp1979::*;
@@ -73,20 +100,24 @@ impl Psalm {
                             });
 
                             match (contains_a, contains_b) {
-                                (true, true) => Some(PsalmVerse {
-                                    number: verse.number,
-                                    a: verse.a.clone(),
-                                    b: verse.b.clone(),
-                                }),
+                                (true, true) => Some(verse.clone()),
                                 (true, false) => Some(PsalmVerse {
                                     number: verse.number,
                                     a: verse.a.clone(),
                                     b: "".into(),
+                                    pointing: verse.pointing.as_ref().map(|pointing| Pointing {
+                                        a: pointing.a.clone(),
+                                        b: Vec::new(),
+                                    }),
                                 }),
                                 (false, true) => Some(PsalmVerse {
                                     number: verse.number,
                                     a: "".into(),
                                     b: verse.b.clone(),
+                                    pointing: verse.pointing.as_ref().map(|pointing| Pointing {
+                                        a: Vec::new(),
+                                        b: pointing.b.clone(),
+                                    }),
                                 }),
                                 (false, false) => None,
                             }
@@ -157,7 +188,7 @@ impl PsalmSection {
     }
 }
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PsalmVerse {
     /// Verse number
     pub number: u8,
@@ -165,4 +196,17 @@ pub struct PsalmVerse {
     pub a: String,
     /// Text of the second half of the verse, after the asterisk
     pub b: String,
+    /// Marks for singing the verse to a chant tone, if it has been pointed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pointing: Option<Pointing>,
+}
+
+impl PsalmVerse {
+    /// Both halves of the verse, parsed into [Inline](crate::Inline) pieces, with the verse number before the first
+    pub fn inline(&self) -> (InlineText, InlineText) {
+        (
+            InlineText::from(&self.a).with_verse_number(self.number),
+            InlineText::from(&self.b),
+        )
+    }
 }
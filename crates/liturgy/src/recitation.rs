@@ -0,0 +1,133 @@
+use language::Language;
+use serde::{Deserialize, Serialize};
+
+use crate::Antiphon;
+
+/// The ways in which a psalm or canticle may be recited by a congregation
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PsalmRecitation {
+    /// Everyone reads every verse together
+    #[default]
+    Unison,
+    /// The leader and people alternate by whole verse
+    ResponsiveByVerse,
+    /// The leader reads the first half of each verse, and the people the second
+    ResponsiveByHalfVerse,
+    /// Two sides of the congregation alternate by whole verse
+    Antiphonal,
+    /// A cantor reads the verses, and the people respond with a refrain after every _n_ verses
+    Responsorial(u8),
+}
+
+/// Whoever is to say a particular verse or half-verse of a psalm or canticle
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Speaker {
+    All,
+    Leader,
+    People,
+    Cantor,
+    SideA,
+    SideB,
+}
+
+impl Speaker {
+    pub fn label(&self, language: Language) -> &'static str {
+        match (language, self) {
+            (Language::Es, Speaker::All) => "Todos",
+            (Language::Es, Speaker::Leader) => "Oficiante",
+            (Language::Es, Speaker::People) => "Pueblo",
+            (Language::Es, Speaker::Cantor) => "Cantor",
+            (Language::Es, Speaker::SideA) => "Lado A",
+            (Language::Es, Speaker::SideB) => "Lado B",
+            (_, Speaker::All) => "All",
+            (_, Speaker::Leader) => "Leader",
+            (_, Speaker::People) => "People",
+            (_, Speaker::Cantor) => "Cantor",
+            (_, Speaker::SideA) => "Side A",
+            (_, Speaker::SideB) => "Side B",
+        }
+    }
+
+    /// Whether this part is said by the congregation, rather than by a single voice
+    pub fn is_congregation(&self) -> bool {
+        matches!(self, Speaker::All | Speaker::People | Speaker::SideB)
+    }
+}
+
+/// How a particular [Psalm](crate::Psalm) or [Canticle](crate::Canticle) is to be recited
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Recitation {
+    pub mode: PsalmRecitation,
+    /// The refrain for responsorial recitation; if `None`, the first verse of the text is used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refrain: Option<Antiphon>,
+}
+
+impl From<PsalmRecitation> for Recitation {
+    fn from(mode: PsalmRecitation) -> Self {
+        Self {
+            mode,
+            refrain: None,
+        }
+    }
+}
+
+impl Recitation {
+    /// Who says each half of the _nth_ verse, counting from zero across all the sections of the text.
+    /// ```
+    /// # use liturgy::{PsalmRecitation, Recitation, Speaker};
+    /// let responsive = Recitation::from(PsalmRecitation::ResponsiveByVerse);
+    /// assert_eq!(responsive.speakers(0), (Speaker::Leader, Speaker::Leader));
+    /// assert_eq!(responsive.speakers(1), (Speaker::People, Speaker::People));
+    ///
+    /// let antiphonal = Recitation::from(PsalmRecitation::Antiphonal);
+    /// assert_eq!(antiphonal.speakers(2), (Speaker::SideA, Speaker::SideA));
+    /// assert_eq!(antiphonal.speakers(3), (Speaker::SideB, Speaker::SideB));
+    ///
+    /// let half_verse = Recitation::from(PsalmRecitation::ResponsiveByHalfVerse);
+    /// assert_eq!(half_verse.speakers(3), (Speaker::Leader, Speaker::People));
+    /// ```
+    pub fn speakers(&self, nth: usize) -> (Speaker, Speaker) {
+        let even = nth.is_multiple_of(2);
+        match self.mode {
+            PsalmRecitation::Unison => (Speaker::All, Speaker::All),
+            PsalmRecitation::ResponsiveByVerse if even => (Speaker::Leader, Speaker::Leader),
+            PsalmRecitation::ResponsiveByVerse => (Speaker::People, Speaker::People),
+            PsalmRecitation::ResponsiveByHalfVerse => (Speaker::Leader, Speaker::People),
+            PsalmRecitation::Antiphonal if even => (Speaker::SideA, Speaker::SideA),
+            PsalmRecitation::Antiphonal => (Speaker::SideB, Speaker::SideB),
+            PsalmRecitation::Responsorial(_) => (Speaker::Cantor, Speaker::Cantor),
+        }
+    }
+
+    /// Whether the people should say the refrain after the _nth_ of `count` verses.
+    /// In responsorial recitation, the refrain is also said before the first verse.
+    /// ```
+    /// # use liturgy::{PsalmRecitation, Recitation};
+    /// let responsorial = Recitation::from(PsalmRecitation::Responsorial(3));
+    /// let refrains = (0..8)
+    ///     .filter(|nth| responsorial.refrain_after(*nth, 8))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(refrains, vec![2, 5, 7]);
+    /// ```
+    pub fn refrain_after(&self, nth: usize, count: usize) -> bool {
+        match self.mode {
+            PsalmRecitation::Responsorial(every) => {
+                let every = usize::from(every.max(1));
+                (nth + 1).is_multiple_of(every) || nth + 1 == count
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether this recitation includes a refrain at all
+    pub fn has_refrain(&self) -> bool {
+        matches!(self.mode, PsalmRecitation::Responsorial(_))
+    }
+}
+
+/// The text of a verse, with the asterisk removed, for use as a refrain
+pub(crate) fn verse_as_refrain(a: &str, b: &str) -> Antiphon {
+    let a = a.trim_end().trim_end_matches('*').trim_end();
+    Antiphon::from(format!("{} {}", a, b.trim()).replace('\n', " "))
+}
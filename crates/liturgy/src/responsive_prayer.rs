@@ -12,6 +12,10 @@ impl ResponsivePrayer {
         self.0.iter()
     }
 
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut String> {
+        self.0.iter_mut()
+    }
+
     pub fn into_vec(self) -> Vec<String> {
         self.0
     }
@@ -0,0 +1,437 @@
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{Choice, Content, Document};
+
+/// The option selected in each [Choice] of a compiled document, saved so that the selections can be
+/// restored later, shared with someone else, or reapplied to another day's service,
+/// generated by [Document::selections] and applied with [Document::apply_selections].
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Selections {
+    pub selections: Vec<Selection>,
+}
+
+/// The option selected in a single [Choice], found at the given path (as in [Document::at_path]).
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Selection {
+    pub path: Vec<usize>,
+    pub option: OptionKey,
+}
+
+/// Identifies an option of a [Choice] by what it is, and not only by where it is found in the list of options,
+/// so that it can be found again if the options are added to, removed, or reordered.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct OptionKey {
+    pub index: usize,
+    /// The label of the option in the menu of choices (see [Choice::option_label]),
+    /// unless it is only the generic label for its index (“Option 2”)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub label: Option<String>,
+    /// A hash of the option's content, which identifies it even if its label is not unique
+    pub fingerprint: String,
+}
+
+/// A [Selection] that could not be applied, identified by its index in the [Selections].
+/// The [Choice] keeps the option it had already selected.
+#[derive(Error, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SelectionConflict {
+    #[error("selection {selection}: there is no choice at {path:?}")]
+    ChoiceNotFound { selection: usize, path: Vec<usize> },
+    #[error(
+        "selection {selection}: the choice at {path:?} no longer has the option that was selected"
+    )]
+    OptionNotFound { selection: usize, path: Vec<usize> },
+}
+
+impl Selections {
+    pub fn is_empty(&self) -> bool {
+        self.selections.is_empty()
+    }
+}
+
+impl OptionKey {
+    fn new(choice: &Choice, index: usize) -> Option<Self> {
+        let option = choice.options.get(index)?;
+        let label = choice.option_label(option, index);
+        Some(Self {
+            index,
+            label: if label == format!("Option {}", index + 1) {
+                None
+            } else {
+                Some(label)
+            },
+            fingerprint: fingerprint(option),
+        })
+    }
+
+    /// The index of this option within the choice: the option with the same content if there is one,
+    /// or else the only option with the same label. `fingerprints` are those of the choice's options.
+    fn find_in(&self, choice: &Choice, fingerprints: &[String]) -> Option<usize> {
+        let by_fingerprint = fingerprints
+            .iter()
+            .position(|fingerprint| *fingerprint == self.fingerprint);
+        by_fingerprint.or_else(|| {
+            let label = self.label.as_ref()?;
+            let mut same_label = choice
+                .option_labels()
+                .enumerate()
+                .filter(|(_, option_label)| option_label == label)
+                .map(|(idx, _)| idx);
+            match (same_label.next(), same_label.next()) {
+                (Some(idx), None) => Some(idx),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl Document {
+    /// Records the option selected in each [Choice] in the document, including choices nested within other options.
+    /// ```
+    /// # use crate::liturgy::*;
+    /// let compiled = Document::from(Series::from([
+    ///     Document::from(Text::from("Opening")),
+    ///     Document::from(Choice::from([
+    ///         Document::from(Text::from("A")).label("First"),
+    ///         Document::from(Text::from("B")).label("Second"),
+    ///     ]).selected(1)),
+    /// ]));
+    /// let selections = compiled.selections();
+    /// assert_eq!(selections.selections[0].path, vec![1]);
+    /// assert_eq!(selections.selections[0].option.label, Some("Second".to_string()));
+    ///
+    /// // the next time it is compiled, the options are in a different order
+    /// let mut recompiled = Document::from(Series::from([
+    ///     Document::from(Text::from("Opening")),
+    ///     Document::from(Choice::from([
+    ///         Document::from(Text::from("B")).label("Second"),
+    ///         Document::from(Text::from("A")).label("First"),
+    ///     ])),
+    /// ]));
+    /// assert!(recompiled.apply_selections(&selections).is_empty());
+    /// match &recompiled.at_path([1]).unwrap().content {
+    ///     Content::Choice(choice) => assert_eq!(choice.selected, 0),
+    ///     _ => unreachable!()
+    /// }
+    /// ```
+    pub fn selections(&self) -> Selections {
+        let mut selections = Vec::new();
+        selections_helper(self, &mut Vec::new(), &mut selections);
+        Selections { selections }
+    }
+
+    /// Selects the saved option in each [Choice], returning any selections that could not be applied.
+    ///
+    /// If there is no longer a choice at the saved path, but exactly one choice elsewhere in the tree has the
+    /// selected option, the selection is applied there instead. An option is found by its content, or else by
+    /// its label if only one option has that label. If the option no longer exists, the choice keeps the option
+    /// it had already selected (e.g., its default, or the option selected by rotation), and a
+    /// [SelectionConflict] is returned. The rest of the selections are still applied.
+    pub fn apply_selections(&mut self, selections: &Selections) -> Vec<SelectionConflict> {
+        // selecting an option does not change any fingerprint, so they are computed only once
+        let mut fingerprints = BTreeMap::new();
+        fingerprints_helper(self, &mut Vec::new(), &mut fingerprints);
+        selections
+            .selections
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, selection)| {
+                self.apply_selection(idx, selection, &fingerprints).err()
+            })
+            .collect()
+    }
+
+    fn apply_selection(
+        &mut self,
+        selection_idx: usize,
+        selection: &Selection,
+        fingerprints: &BTreeMap<Vec<usize>, Vec<String>>,
+    ) -> Result<(), SelectionConflict> {
+        let option_at = |doc: &Document| match (&doc.content, fingerprints.get(&selection.path)) {
+            (Content::Choice(choice), Some(fingerprints)) => {
+                Some(selection.option.find_in(choice, fingerprints))
+            }
+            _ => None,
+        };
+
+        let (path, option) = match self
+            .at_path(selection.path.iter().copied())
+            .ok()
+            .and_then(option_at)
+        {
+            Some(Some(option)) => (selection.path.clone(), option),
+            found => {
+                // the path of every choice within the document that has the option, with the option's index
+                let mut matches = fingerprints
+                    .iter()
+                    .filter_map(|(path, fingerprints)| {
+                        fingerprints
+                            .iter()
+                            .position(|fingerprint| *fingerprint == selection.option.fingerprint)
+                            .map(|idx| (path.clone(), idx))
+                    })
+                    .collect::<Vec<_>>();
+                match (matches.len(), found) {
+                    (1, _) => matches.remove(0),
+                    (_, Some(None)) => {
+                        return Err(SelectionConflict::OptionNotFound {
+                            selection: selection_idx,
+                            path: selection.path.clone(),
+                        })
+                    }
+                    _ => {
+                        return Err(SelectionConflict::ChoiceNotFound {
+                            selection: selection_idx,
+                            path: selection.path.clone(),
+                        })
+                    }
+                }
+            }
+        };
+
+        match self
+            .at_path_mut(path.iter().copied())
+            .map(|doc| &mut doc.content)
+        {
+            Ok(Content::Choice(choice)) => {
+                choice.selected = option;
+                Ok(())
+            }
+            _ => Err(SelectionConflict::ChoiceNotFound {
+                selection: selection_idx,
+                path,
+            }),
+        }
+    }
+}
+
+fn selections_helper(doc: &Document, path: &mut Vec<usize>, selections: &mut Vec<Selection>) {
+    let children = match &doc.content {
+        Content::Liturgy(liturgy) => liturgy.body.as_slice(),
+        Content::Series(series) => series.as_slice(),
+        Content::Parallel(parallel) => parallel.as_slice(),
+        Content::Choice(choice) => {
+            if let Some(option) = OptionKey::new(choice, choice.selected) {
+                selections.push(Selection {
+                    path: path.clone(),
+                    option,
+                });
+            }
+            choice.options.as_slice()
+        }
+        _ => &[],
+    };
+    for (idx, child) in children.iter().enumerate() {
+        path.push(idx);
+        selections_helper(child, path, selections);
+        path.pop();
+    }
+}
+
+/// The fingerprints of the options of every [Choice] within the document, by the path to the choice
+fn fingerprints_helper(
+    doc: &Document,
+    path: &mut Vec<usize>,
+    fingerprints: &mut BTreeMap<Vec<usize>, Vec<String>>,
+) {
+    let children = match &doc.content {
+        Content::Liturgy(liturgy) => liturgy.body.as_slice(),
+        Content::Series(series) => series.as_slice(),
+        Content::Parallel(parallel) => parallel.as_slice(),
+        Content::Choice(choice) => {
+            fingerprints.insert(
+                path.clone(),
+                choice.options.iter().map(fingerprint).collect(),
+            );
+            choice.options.as_slice()
+        }
+        _ => &[],
+    };
+    for (idx, child) in children.iter().enumerate() {
+        path.push(idx);
+        fingerprints_helper(child, path, fingerprints);
+        path.pop();
+    }
+}
+
+/// A 64-bit FNV-1a hash of the document, which (unlike the [DefaultHasher](std::collections::hash_map::DefaultHasher))
+/// depends neither on the platform nor on the standard library's choice of algorithm. The selections of any choices within the
+/// document are ignored, so that an option is recognized whichever of its own options is selected.
+fn fingerprint(doc: &Document) -> String {
+    fn unselect(doc: &mut Document) {
+        let children = match &mut doc.content {
+            Content::Liturgy(liturgy) => liturgy.body.as_mut_slice(),
+            Content::Series(series) => series.as_mut_slice(),
+            Content::Parallel(parallel) => parallel.as_mut_slice(),
+            Content::Choice(choice) => {
+                choice.selected = 0;
+                choice.rotated = false;
+                choice.options.as_mut_slice()
+            }
+            _ => &mut [],
+        };
+        children.iter_mut().for_each(unselect);
+    }
+
+    let mut doc = doc.clone();
+    unselect(&mut doc);
+    let mut hasher = Fnv1a(0xcbf29ce484222325);
+    doc.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Writes every integer as little-endian bytes, and every `usize` as eight of them,
+/// so that a value hashes the same way on 32- and 64-bit platforms.
+struct Fnv1a(u64);
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fingerprint;
+    use crate::{Choice, Content, Document, Selections, Series, Text};
+
+    fn selected(doc: &Document, path: &[usize]) -> usize {
+        match &doc.at_path(path.iter().copied()).unwrap().content {
+            Content::Choice(choice) => choice.selected,
+            _ => panic!("expected a choice at {:?}", path),
+        }
+    }
+
+    #[test]
+    fn selections_survive_serialization_and_moved_choices() {
+        let compiled = Document::from(Series::from([
+            Document::from(
+                Choice::from([
+                    Document::from(Text::from("Venite")),
+                    Document::from(Text::from("Jubilate")),
+                ])
+                .selected(1),
+            ),
+            Document::from(Choice::from([
+                Document::from(Series::from([
+                    Document::from(Text::from("Nested")),
+                    Document::from(
+                        Choice::from([
+                            Document::from(Text::from("x")),
+                            Document::from(Text::from("y")),
+                        ])
+                        .selected(1),
+                    ),
+                ])),
+                Document::from(Text::from("Other")),
+            ])),
+        ]));
+        let selections = compiled.selections();
+        assert_eq!(selections.selections.len(), 3);
+        let json = serde_json::to_string(&selections).unwrap();
+        let selections: Selections = serde_json::from_str(&json).unwrap();
+
+        // a new document has been inserted before the choices
+        let mut recompiled = Document::from(Series::from([
+            Document::from(Text::from("A new opening sentence")),
+            Document::from(Choice::from([
+                Document::from(Text::from("Venite")),
+                Document::from(Text::from("Jubilate")),
+            ])),
+            Document::from(Choice::from([
+                Document::from(Series::from([
+                    Document::from(Text::from("Nested")),
+                    Document::from(Choice::from([
+                        Document::from(Text::from("x")),
+                        Document::from(Text::from("y")),
+                    ])),
+                ])),
+                Document::from(Text::from("Other")),
+            ])),
+        ]));
+        assert!(recompiled.apply_selections(&selections).is_empty());
+        assert_eq!(selected(&recompiled, &[1]), 1);
+        assert_eq!(selected(&recompiled, &[2]), 0);
+        assert_eq!(selected(&recompiled, &[2, 0, 1]), 1);
+    }
+
+    #[test]
+    fn choices_keep_their_selection_if_the_option_is_gone() {
+        let compiled = Document::from(
+            Choice::from([
+                Document::from(Text::from("A")).label("First"),
+                Document::from(Text::from("B")).label("Second"),
+            ])
+            .selected(1),
+        );
+        let selections = compiled.selections();
+
+        // a changed option is still found by its label
+        let mut changed = Document::from(
+            Choice::from([
+                Document::from(Text::from("B, revised")).label("Second"),
+                Document::from(Text::from("A")).label("First"),
+            ])
+            .selected(1),
+        );
+        assert!(changed.apply_selections(&selections).is_empty());
+        assert_eq!(selected(&changed, &[]), 0);
+
+        let mut removed = Document::from(
+            Choice::from([
+                Document::from(Text::from("A")).label("First"),
+                Document::from(Text::from("C")).label("Third"),
+            ])
+            .selected(1),
+        );
+        assert_eq!(removed.apply_selections(&selections).len(), 1);
+        assert_eq!(selected(&removed, &[]), 1);
+    }
+
+    #[test]
+    fn fingerprints_are_stable() {
+        // saved selections are matched by fingerprint, so this value must not change
+        assert_eq!(
+            fingerprint(&Document::from(Text::from("Venite"))),
+            "fc2c5d6e5ec79050"
+        );
+        assert_eq!(
+            fingerprint(&Document::from(Choice::from([
+                Document::from(Text::from("x")),
+                Document::from(Text::from("y")),
+            ]))),
+            fingerprint(&Document::from(
+                Choice::from([
+                    Document::from(Text::from("x")),
+                    Document::from(Text::from("y")),
+                ])
+                .selected(1)
+            ))
+        );
+    }
+}
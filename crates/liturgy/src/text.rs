@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
-use crate::{Antiphon, Content, DisplayFormat, Heading, Preces, Rubric, Sentence};
+use crate::{Antiphon, Content, DisplayFormat, Heading, InlineText, Preces, Rubric, Sentence};
 
 /// Text, without any additional styling or semantics
 #[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -26,6 +26,11 @@ impl Text {
         self.response = Some(response.to_string());
         self
     }
+
+    /// The text, parsed into its italics, small capitals, optional words, and line breaks
+    pub fn inline(&self) -> InlineText {
+        InlineText::from(&self.text)
+    }
 }
 
 impl From<String> for Text {
@@ -14,34 +14,41 @@ lazy_static! {
               PsalmVerse {
                             number: 1,
                             a: String::from("Happy are they who have not walked in the counsel of the wicked, *"),
-                            b: String::from("nor lingered in the way of sinners,\nnor sat in the seats of the scornful!")
+                            b: String::from("nor lingered in the way of sinners,\nnor sat in the seats of the scornful!"),
+                            ..Default::default()
                         },
                       PsalmVerse {
                             number: 2,
                             a: String::from("Their delight is in the law of the LORD, *"),
-                            b: String::from("and they meditate on his law day and night.")
+                            b: String::from("and they meditate on his law day and night."),
+                            ..Default::default()
                         },
                       PsalmVerse {
                             number: 3,
                             a: String::from("They are like trees planted by streams of water,\nbearing fruit in due season, with leaves that do not wither; *"),
-                            b: String::from("everything they do shall prosper.")
+                            b: String::from("everything they do shall prosper."),
+                            ..Default::default()
                         },
                       PsalmVerse {
                             number: 4,
                             a: String::from("It is not so with the wicked; *"),
-                            b: String::from("they are like chaff which the wind blows away.")
+                            b: String::from("they are like chaff which the wind blows away."),
+                            ..Default::default()
                         },
                       PsalmVerse {
                             number: 5,
                             a: String::from("Therefore the wicked shall not stand upright when judgment comes, *"),
-                            b: String::from("nor the sinner in the council of the righteous.")
+                            b: String::from("nor the sinner in the council of the righteous."),
+                            ..Default::default()
                         },
                       PsalmVerse {
                             number: 6,
                             a: String::from("For the LORD knows the way of the righteous, *"),
-                            b: String::from("but the way of the wicked is doomed.")
+                            b: String::from("but the way of the wicked is doomed."),
+                            ..Default::default()
                         },
                         ]
-        }]
+        }],
+        ..Default::default()
     };
 }
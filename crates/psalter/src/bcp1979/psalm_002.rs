@@ -14,69 +14,83 @@ lazy_static! {
               PsalmVerse {
         number: 1,
         a: String::from("Why are the nations in an uproar? *"),
-        b: String::from("Why do the peoples mutter empty threats?")
+        b: String::from("Why do the peoples mutter empty threats?"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 2,
         a: String::from("Why do the kings of the earth rise up in revolt,\nand the princes plot together, *"),
-        b: String::from("against the LORD and against his Anointed?")
+        b: String::from("against the LORD and against his Anointed?"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 3,
         a: String::from("“Let us break their yoke,” they say; *"),
-        b: String::from("“let us cast off their bonds from us.”")
+        b: String::from("“let us cast off their bonds from us.”"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 4,
         a: String::from("He whose throne is in heaven is laughing; *"),
-        b: String::from("the Lord has them in derision.")
+        b: String::from("the Lord has them in derision."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 5,
         a: String::from("Then he speaks to them in his wrath, *"),
-        b: String::from("and his rage fills them with terror.")
+        b: String::from("and his rage fills them with terror."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 6,
         a: String::from("“I myself have set my king *"),
-        b: String::from("upon my holy hill of Zion.”")
+        b: String::from("upon my holy hill of Zion.”"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 7,
         a: String::from("Let me announce the decree of the LORD: *"),
-        b: String::from("he said to me, “You are my Son;\nthis day have I begotten you.")
+        b: String::from("he said to me, “You are my Son;\nthis day have I begotten you."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 8,
         a: String::from("Ask of me, and I will give you the nations for your inheritance *"),
-        b: String::from("and the ends of the earth for your possession.")
+        b: String::from("and the ends of the earth for your possession."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 9,
         a: String::from("You shall crush them with an iron rod *"),
-        b: String::from("and shatter them like a piece of pottery.”")
+        b: String::from("and shatter them like a piece of pottery.”"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 10,
         a: String::from("And now, you kings, be wise; *"),
-        b: String::from("be warned, you rulers of the earth.")
+        b: String::from("be warned, you rulers of the earth."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 11,
         a: String::from("Submit to the LORD with fear, *"),
-        b: String::from("and with trembling bow before him;")
+        b: String::from("and with trembling bow before him;"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 12,
         a: String::from("Lest he be angry and you perish; *"),
-        b: String::from("for his wrath is quickly kindled.")
+        b: String::from("for his wrath is quickly kindled."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 13,
         a: String::from("Happy are they all *"),
-        b: String::from("who take refuge in him!")
+        b: String::from("who take refuge in him!"),
+        ..Default::default()
     },
 ]
-        }]
+        }],
+        ..Default::default()
     };
 }
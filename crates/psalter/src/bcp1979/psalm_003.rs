@@ -14,44 +14,53 @@ lazy_static! {
               PsalmVerse {
         number: 1,
         a: String::from("LORD, how many adversaries I have! *"),
-        b: String::from("how many there are who rise up against me!")
+        b: String::from("how many there are who rise up against me!"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 2,
         a: String::from("How many there are who say of me, *"),
-        b: String::from("“There is no help for him in his God.”")
+        b: String::from("“There is no help for him in his God.”"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 3,
         a: String::from("But you, O LORD, are a shield about me; *"),
-        b: String::from("you are my glory, the one who lifts up my head.")
+        b: String::from("you are my glory, the one who lifts up my head."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 4,
         a: String::from("I call aloud upon the LORD, *"),
-        b: String::from("and he answers me from his holy hill;")
+        b: String::from("and he answers me from his holy hill;"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 5,
         a: String::from("I lie down and go to sleep; *"),
-        b: String::from("I wake again, because the LORD sustains me.")
+        b: String::from("I wake again, because the LORD sustains me."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 6,
         a: String::from("I do not fear the multitudes of people *"),
-        b: String::from("who set themselves against me all around.")
+        b: String::from("who set themselves against me all around."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 7,
         a: String::from("Rise up, O LORD; set me free, O my God; *"),
-        b: String::from("surely, you will strike all my enemies across the face,\nyou will break the teeth of the wicked.")
+        b: String::from("surely, you will strike all my enemies across the face,\nyou will break the teeth of the wicked."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 8,
         a: String::from("Deliverance belongs to the LORD. *"),
-        b: String::from("Your blessing be upon your people!")
+        b: String::from("Your blessing be upon your people!"),
+        ..Default::default()
     },
 ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,44 +14,53 @@ lazy_static! {
               PsalmVerse {
         number: 1,
         a: String::from("Answer me when I call, O God, defender of my cause; *"),
-        b: String::from("you set me free when I am hard-pressed;\nhave mercy on me and hear my prayer.")
+        b: String::from("you set me free when I am hard-pressed;\nhave mercy on me and hear my prayer."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 2,
         a: String::from("“You mortals, how long will you dishonor my glory; *"),
-        b: String::from("how long will you worship dumb idols\nand run after false gods?”")
+        b: String::from("how long will you worship dumb idols\nand run after false gods?”"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 3,
         a: String::from("Know that the LORD does wonders for the faithful; *"),
-        b: String::from("when I call upon the LORD, he will hear me.")
+        b: String::from("when I call upon the LORD, he will hear me."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 4,
         a: String::from("Tremble, then, and do not sin; *"),
-        b: String::from("speak to your heart in silence upon your bed.")
+        b: String::from("speak to your heart in silence upon your bed."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 5,
         a: String::from("Offer the appointed sacrifices *"),
-        b: String::from("and put your trust in the LORD.")
+        b: String::from("and put your trust in the LORD."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 6,
         a: String::from("Many are saying,\n“Oh, that we might see better times!” *"),
-        b: String::from("Lift up the light of your countenance upon us, O LORD.")
+        b: String::from("Lift up the light of your countenance upon us, O LORD."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 7,
         a: String::from("You have put gladness in my heart, *"),
-        b: String::from("more than when grain and wine and oil increase.")
+        b: String::from("more than when grain and wine and oil increase."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 8,
         a: String::from("I lie down in peace; at once I fall asleep; *"),
-        b: String::from("for only you, LORD, make me dwell in safety.")
+        b: String::from("for only you, LORD, make me dwell in safety."),
+        ..Default::default()
     },
 ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,54 +14,65 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("LORD, do not rebuke me in your anger; *"),
-                    b: String::from("do not punish me in your wrath.")
+                    b: String::from("do not punish me in your wrath."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Have pity on me, LORD, for I am weak; *"),
-                    b: String::from("heal me, LORD, for my bones are racked.")
+                    b: String::from("heal me, LORD, for my bones are racked."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("My spirit shakes with terror; *"),
-                    b: String::from("how long, O LORD, how long?")
+                    b: String::from("how long, O LORD, how long?"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("Turn, O LORD, and deliver me; *"),
-                    b: String::from("save me for your mercy’s sake.")
+                    b: String::from("save me for your mercy’s sake."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("For in death no one remembers you; *"),
-                    b: String::from("and who will give you thanks in the grave?")
+                    b: String::from("and who will give you thanks in the grave?"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("I grow weary because of my groaning; *"),
-                    b: String::from("every night I drench my bed\nand flood my couch with tears.")
+                    b: String::from("every night I drench my bed\nand flood my couch with tears."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("My eyes are wasted with grief *"),
-                    b: String::from("and worn away because of all my enemies.")
+                    b: String::from("and worn away because of all my enemies."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("Depart from me, all evildoers, *"),
-                    b: String::from("for the LORD has heard the sound of my weeping.")
+                    b: String::from("for the LORD has heard the sound of my weeping."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("The LORD has heard my supplication; *"),
-                    b: String::from("the LORD accepts my prayer.")
+                    b: String::from("the LORD accepts my prayer."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 10,
                     a: String::from("All my enemies shall be confounded and quake with fear; *"),
-                    b: String::from("they shall turn back and suddenly be put to shame.")
+                    b: String::from("they shall turn back and suddenly be put to shame."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,94 +14,113 @@ lazy_static! {
               PsalmVerse {
         number: 1,
         a: String::from("O LORD my God, I take refuge in you; *"),
-        b: String::from("save and deliver me from all who pursue me;")
+        b: String::from("save and deliver me from all who pursue me;"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 2,
         a: String::from("Lest like a lion they tear me in pieces *"),
-        b: String::from("and snatch me away with none to deliver me.")
+        b: String::from("and snatch me away with none to deliver me."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 3,
         a: String::from("O LORD my God, if I have done these things: *"),
-        b: String::from("if there is any wickedness in my hands,")
+        b: String::from("if there is any wickedness in my hands,"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 4,
         a: String::from("If I have repaid my friend with evil, *"),
-        b: String::from("or plundered him who without cause is my enemy;")
+        b: String::from("or plundered him who without cause is my enemy;"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 5,
         a: String::from("Then let my enemy pursue and overtake me, *"),
-        b: String::from("trample my life into the ground,\nand lay my honor in the dust.")
+        b: String::from("trample my life into the ground,\nand lay my honor in the dust."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 6,
         a: String::from("Stand up, O LORD, in your wrath; *"),
-        b: String::from("rise up against the fury of my enemies.")
+        b: String::from("rise up against the fury of my enemies."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 7,
         a: String::from("Awake, O my God, decree justice; *"),
-        b: String::from("let the assembly of the peoples gather round you.")
+        b: String::from("let the assembly of the peoples gather round you."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 8,
         a: String::from("Be seated on your lofty throne, O Most High; *"),
-        b: String::from("O LORD, judge the nations.")
+        b: String::from("O LORD, judge the nations."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 9,
         a: String::from("Give judgment for me according to my righteousness, O LORD, *"),
-        b: String::from("and according to my innocence, O Most High.")
+        b: String::from("and according to my innocence, O Most High."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 10,
         a: String::from("Let the malice of the wicked come to an end,\nbut establish the righteous; *"),
-        b: String::from("for you test the mind and heart, O righteous God.")
+        b: String::from("for you test the mind and heart, O righteous God."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 11,
         a: String::from("God is my shield and defense; *"),
-        b: String::from("he is the savior of the true in heart.")
+        b: String::from("he is the savior of the true in heart."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 12,
         a: String::from("God is a righteous judge; *"),
-        b: String::from("God sits in judgment every day.")
+        b: String::from("God sits in judgment every day."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 13,
         a: String::from("If they will not repent, God will whet his sword; *"),
-        b: String::from("he will bend his bow and make it ready.")
+        b: String::from("he will bend his bow and make it ready."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 14,
         a: String::from("He has prepared his weapons of death; *"),
-        b: String::from("he makes his arrows shafts of fire.")
+        b: String::from("he makes his arrows shafts of fire."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 15,
         a: String::from("Look at those who are in labor with wickedness, *"),
-        b: String::from("who conceive evil, and give birth to a lie.")
+        b: String::from("who conceive evil, and give birth to a lie."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 16,
         a: String::from("They dig a pit and make it deep *"),
-        b: String::from("and fall into the hole that they have made.")
+        b: String::from("and fall into the hole that they have made."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 17,
         a: String::from("Their malice turns back upon their own head; *"),
-        b: String::from("their violence falls on their own scalp.")
+        b: String::from("their violence falls on their own scalp."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 18,
         a: String::from("I will bear witness that the LORD is righteous; *"),
-        b: String::from("I will praise the Name of the LORD Most High.")
+        b: String::from("I will praise the Name of the LORD Most High."),
+        ..Default::default()
     },
 ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,54 +14,65 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("O LORD our Governor, *"),
-                    b: String::from("how exalted is your Name in all the world!")
+                    b: String::from("how exalted is your Name in all the world!"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Out of the mouths of infants and children *"),
-                    b: String::from("your majesty is praised above the heavens.")
+                    b: String::from("your majesty is praised above the heavens."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("You have set up a stronghold against your adversaries, *"),
-                    b: String::from("to quell the enemy and the avenger.")
+                    b: String::from("to quell the enemy and the avenger."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("When I consider your heavens, the work of your fingers, *"),
-                    b: String::from("the moon and the stars you have set in their courses,")
+                    b: String::from("the moon and the stars you have set in their courses,"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("What is man that you should be mindful of him? *"),
-                    b: String::from("the son of man that you should seek him out?")
+                    b: String::from("the son of man that you should seek him out?"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("You have made him but little lower than the angels; *"),
-                    b: String::from("you adorn him with glory and honor;")
+                    b: String::from("you adorn him with glory and honor;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("You give him mastery over the works of your hands; *"),
-                    b: String::from("you put all things under his feet:")
+                    b: String::from("you put all things under his feet:"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("All sheep and oxen, *"),
-                    b: String::from("even the wild beasts of the field,")
+                    b: String::from("even the wild beasts of the field,"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("The birds of the air, the fish of the sea, *"),
-                    b: String::from("and whatsoever walks in the paths of the sea.")
+                    b: String::from("and whatsoever walks in the paths of the sea."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 10,
                     a: String::from("O LORD our Governor, *"),
-                    b: String::from("how exalted is you Name in all the world!")
+                    b: String::from("how exalted is you Name in all the world!"),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
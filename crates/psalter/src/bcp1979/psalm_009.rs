@@ -14,104 +14,125 @@ lazy_static! {
               PsalmVerse {
         number: 1,
         a: String::from("I will give thanks to you, O LORD, with my whole heart; *"),
-        b: String::from("I will tell of all your marvelous works.")
+        b: String::from("I will tell of all your marvelous works."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 2,
         a: String::from("I will be glad and rejoice in you; *"),
-        b: String::from("I will sing to your Name, O Most High.")
+        b: String::from("I will sing to your Name, O Most High."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 3,
         a: String::from("When my enemies are driven back, *"),
-        b: String::from("they will stumble and perish at your presence.")
+        b: String::from("they will stumble and perish at your presence."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 4,
         a: String::from("For you have maintained my right and my cause; *"),
-        b: String::from("you sit upon your throne judging right.")
+        b: String::from("you sit upon your throne judging right."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 5,
         a: String::from("You have rebuked the ungodly and destroyed the wicked; *"),
-        b: String::from("you have blotted out their name for ever and ever.")
+        b: String::from("you have blotted out their name for ever and ever."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 6,
         a: String::from("As for the enemy, they are finished, in perpetual ruin, *"),
-        b: String::from("their cities plowed under, the memory of them perished;")
+        b: String::from("their cities plowed under, the memory of them perished;"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 7,
         a: String::from("But the LORD is enthroned for ever; *"),
-        b: String::from("he has set up this throne for judgment.")
+        b: String::from("he has set up this throne for judgment."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 8,
         a: String::from("It is he who rules the world with righteousness; *"),
-        b: String::from("he judges the peoples with equity.")
+        b: String::from("he judges the peoples with equity."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 9,
         a: String::from("The LORD will be a refuge for the oppressed, *"),
-        b: String::from("a refuge in time of trouble.")
+        b: String::from("a refuge in time of trouble."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 10,
         a: String::from("Those who know your Name will put their trust in you, *"),
-        b: String::from("for you never forsake those who seek you, O LORD.")
+        b: String::from("for you never forsake those who seek you, O LORD."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 11,
         a: String::from("Sing praise to the LORD who dwells in Zion; *"),
-        b: String::from("proclaim to the peoples the things he has done.")
+        b: String::from("proclaim to the peoples the things he has done."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 12,
         a: String::from("The Avenger of blood will remember them; *"),
-        b: String::from("he will not forget the cry of the afflicted.")
+        b: String::from("he will not forget the cry of the afflicted."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 13,
         a: String::from("Have pity on me, O LORD; *"),
-        b: String::from("see the misery I suffer from those who hate me,\nO you who lift me up from the gate of death;")
+        b: String::from("see the misery I suffer from those who hate me,\nO you who lift me up from the gate of death;"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 14,
         a: String::from("So that I may tell of all your praises\nand rejoice in your salvation *"),
-        b: String::from("in the gates of the city of Zion.")
+        b: String::from("in the gates of the city of Zion."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 15,
         a: String::from("The ungodly have fallen into the pit they dug, *"),
-        b: String::from("and in the snare they set is their own foot caught.")
+        b: String::from("and in the snare they set is their own foot caught."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 16,
         a: String::from("The LORD is known by his acts of justice; *"),
-        b: String::from("the wicked are trapped in the works of their own hands.")
+        b: String::from("the wicked are trapped in the works of their own hands."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 17,
         a: String::from("The wicked shall be given over to the grave, *"),
-        b: String::from("and also all the people that forget God.")
+        b: String::from("and also all the people that forget God."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 18,
         a: String::from("For the needy shall not always be forgotten, *"),
-        b: String::from("and the hope of the poor shall not perish for ever.")
+        b: String::from("and the hope of the poor shall not perish for ever."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 19,
         a: String::from("Rise up, O LORD, let not the ungodly have the upper hand; *"),
-        b: String::from("let them be judged before you.")
+        b: String::from("let them be judged before you."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 20,
         a: String::from("Put fear upon them, O LORD; *"),
-        b: String::from("let the ungodly know they are but mortal.")
+        b: String::from("let the ungodly know they are but mortal."),
+        ..Default::default()
     },
 ]
-        }]
+        }],
+        ..Default::default()
     };
 }
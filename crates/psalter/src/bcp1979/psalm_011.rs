@@ -14,44 +14,53 @@ lazy_static! {
               PsalmVerse {
         number: 1,
         a: String::from("In the LORD have I taken refuge; *"),
-        b: String::from("how then can you say to me,\n“Fly away like a bird to the hilltop;")
+        b: String::from("how then can you say to me,\n“Fly away like a bird to the hilltop;"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 2,
         a: String::from("For see how the wicked bend the bow\nand fit their arrows to the string, *"),
-        b: String::from("to shoot from ambush at the true of heart.")
+        b: String::from("to shoot from ambush at the true of heart."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 3,
         a: String::from("When the foundations are being destroyed, *"),
-        b: String::from("what can the righteous do?”")
+        b: String::from("what can the righteous do?”"),
+        ..Default::default()
     },
   PsalmVerse {
         number: 4,
         a: String::from("The LORD is in his holy temple; *"),
-        b: String::from("the LORD’s throne is in heaven.")
+        b: String::from("the LORD’s throne is in heaven."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 5,
         a: String::from("His eyes behold the inhabited world; *"),
-        b: String::from("his piercing eye weighs our worth.")
+        b: String::from("his piercing eye weighs our worth."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 6,
         a: String::from("The LORD weighs the righteous as well as the wicked, *"),
-        b: String::from("but those who delight in violence he abhors.")
+        b: String::from("but those who delight in violence he abhors."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 7,
         a: String::from("Upon the wicked he shall rain coals of fire and burning sulphur; *"),
-        b: String::from("a scorching wind shall be their lot.")
+        b: String::from("a scorching wind shall be their lot."),
+        ..Default::default()
     },
   PsalmVerse {
         number: 8,
         a: String::from("For the LORD is righteous;\nhe delights in righteous deeds; *"),
-        b: String::from("and the just shall see his face.")
+        b: String::from("and the just shall see his face."),
+        ..Default::default()
     },
 ]
-        }]
+        }],
+        ..Default::default()
     };
 }
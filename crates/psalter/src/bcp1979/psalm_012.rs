@@ -14,22 +14,26 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Help me, LORD, for there is no godly one left; *"),
-                    b: String::from("the faithful have vanished from among us.")
+                    b: String::from("the faithful have vanished from among us."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Everyone speaks falsely with his neighbor; *"),
-                    b: String::from("with a smooth tongue they speak from a double heart.")
+                    b: String::from("with a smooth tongue they speak from a double heart."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("Oh, that the LORD would cut off all smooth tongues, *"),
-                    b: String::from("and close the lips that utter proud boasts!")
+                    b: String::from("and close the lips that utter proud boasts!"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("Those who say, “With our tongue will we prevail; *"),
-                    b: String::from("our lips are our own; who is lord over us?”")
+                    b: String::from("our lips are our own; who is lord over us?”"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
@@ -38,26 +42,31 @@ lazy_static! {
                     ),
                     b: String::from(
                         "I will rise up,” says the LORD,\n“and give them the help they long for.”"
-                    )
+                    ),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("The words of the LORD are pure words, *"),
                     b: String::from(
                         "like silver refined from ore\nand purified seven times in the fire."
-                    )
+                    ),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("O LORD, watch over us *"),
-                    b: String::from("and save us from this generation for ever.")
+                    b: String::from("and save us from this generation for ever."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("The wicked prowl on every side, *"),
-                    b: String::from("and that which is worthless is highly prized by everyone.")
+                    b: String::from("and that which is worthless is highly prized by everyone."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
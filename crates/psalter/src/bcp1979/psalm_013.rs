@@ -15,35 +15,42 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("How long, O LORD?\nwill you forget me for ever? *"),
-                      b: String::from("how long will you hide your face from me?")
+                      b: String::from("how long will you hide your face from me?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("How long shall I have perplexity in my mind,\nand grief in my heart, day after day? *"),
-                      b: String::from("how long shall my enemy triumph over me?")
+                      b: String::from("how long shall my enemy triumph over me?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Look upon me and answer me, O LORD my God; *"),
-                      b: String::from("give light to my eyes, lest I sleep in death;")
+                      b: String::from("give light to my eyes, lest I sleep in death;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Lest my enemy say, “I have prevailed over him,” *"),
-                      b: String::from("and my foes rejoice that I have fallen.")
+                      b: String::from("and my foes rejoice that I have fallen."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("But I put my trust in your mercy; *"),
-                      b: String::from("my heart is joyful because of your saving help.")
+                      b: String::from("my heart is joyful because of your saving help."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("I will sing to the LORD, for he has dealt with me richly; *"),
-                      b: String::from("I will praise the Name of the Lord Most High.")
+                      b: String::from("I will praise the Name of the Lord Most High."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,40 +15,48 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("The fool has said in his heart, “There is no God.” *"),
-                      b: String::from("All are corrupt and commit abominable acts;\nthere is none who does any good.")
+                      b: String::from("All are corrupt and commit abominable acts;\nthere is none who does any good."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("The LORD looks down from heaven upon us all, *"),
-                      b: String::from("to see if there is any who is wise,\nif there is one who seeks after God.")
+                      b: String::from("to see if there is any who is wise,\nif there is one who seeks after God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Every one has proved faithless;\nall alike have turned bad; *"),
-                      b: String::from("there is none who does good; no, not one.")
+                      b: String::from("there is none who does good; no, not one."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Have they no knowledge, all those evildoers *"),
-                      b: String::from("who eat up my people like bread\nand do not call upon the LORD?")
+                      b: String::from("who eat up my people like bread\nand do not call upon the LORD?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("See how they tremble with fear, *"),
-                      b: String::from("because God is in the company of the righteous.")
+                      b: String::from("because God is in the company of the righteous."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Their aim is to confound the plans of the afflicted, *"),
-                      b: String::from("but the LORD is their refuge.")
+                      b: String::from("but the LORD is their refuge."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Oh, that Israel’s deliverance would come out of Zion! *"),
-                      b: String::from("when the LORD restores the fortunes of his people,\nJacob will rejoice and Israel be glad.")
+                      b: String::from("when the LORD restores the fortunes of his people,\nJacob will rejoice and Israel be glad."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -14,41 +14,49 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("LORD, who may dwell in your tabernacle? *"),
-                    b: String::from("who may abide upon your holy hill?")
+                    b: String::from("who may abide upon your holy hill?"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Whoever leads a blameless life and does what is right, *"),
-                    b: String::from("who speaks the truth from his heart.")
+                    b: String::from("who speaks the truth from his heart."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from(
                         "There is no guile upon his tongue;\nhe does no evil to his friend; *"
                     ),
-                    b: String::from("he does not heap contempt upon his neighbor.")
+                    b: String::from("he does not heap contempt upon his neighbor."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("In his sight the wicked is rejected, *"),
-                    b: String::from("but he honors those who fear the LORD.")
+                    b: String::from("but he honors those who fear the LORD."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("He has sworn to do no wrong *"),
-                    b: String::from("and does not take back his word.")
+                    b: String::from("and does not take back his word."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("He does not give his money in hope of gain, *"),
-                    b: String::from("nor does he take a bribe against the innocent.")
+                    b: String::from("nor does he take a bribe against the innocent."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("Whoever does these things *"),
-                    b: String::from("shall never be overthrown.")
+                    b: String::from("shall never be overthrown."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
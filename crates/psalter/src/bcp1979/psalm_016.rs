@@ -15,60 +15,72 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Protect me, O God, for I take refuge in you; *"),
-                      b: String::from("I have said to the LORD, “You are my Lord,\nmy good above all other.”")
+                      b: String::from("I have said to the LORD, “You are my Lord,\nmy good above all other.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("All my delight is upon the godly that are in the land, *"),
-                      b: String::from("upon those who are noble among the people.")
+                      b: String::from("upon those who are noble among the people."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("But those who run after other gods *"),
-                      b: String::from("shall have their troubles multiplied.")
+                      b: String::from("shall have their troubles multiplied."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Their libations of blood I will not offer, *"),
-                      b: String::from("nor take the names of their gods upon my lips.")
+                      b: String::from("nor take the names of their gods upon my lips."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("O LORD, you are my portion and my cup; *"),
-                      b: String::from("it is you who uphold my lot.")
+                      b: String::from("it is you who uphold my lot."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("My boundaries enclose a pleasant land; *"),
-                      b: String::from("indeed, I have a goodly heritage.")
+                      b: String::from("indeed, I have a goodly heritage."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("I will bless the LORD who gives me counsel; *"),
-                      b: String::from("my heart teaches me, night after night.")
+                      b: String::from("my heart teaches me, night after night."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("I have set the LORD always before me; *"),
-                      b: String::from("because he is at my right hand I shall not fall.")
+                      b: String::from("because he is at my right hand I shall not fall."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("My heart, therefore, is glad, and my spirit rejoices; *"),
-                      b: String::from("my body also shall rest in hope.")
+                      b: String::from("my body also shall rest in hope."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("For you will not abandon me to the grave, *"),
-                      b: String::from("nor let your holy one see the Pit.")
+                      b: String::from("nor let your holy one see the Pit."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("You will show me the path of life; *"),
-                      b: String::from("in your presence there is fullness of joy,\nand in your right hand are pleasures for evermore.")
+                      b: String::from("in your presence there is fullness of joy,\nand in your right hand are pleasures for evermore."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
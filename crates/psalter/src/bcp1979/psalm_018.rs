@@ -14,102 +14,122 @@ lazy_static! {
                           PsalmVerse {
                     number: 1,
                     a: String::from("I love you, O LORD my strength, *"),
-                    b: String::from("O LORD my stronghold, my crag, and my haven.")
+                    b: String::from("O LORD my stronghold, my crag, and my haven."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 2,
                     a: String::from("My God, my rock in whom I put my trust, *"),
-                    b: String::from("my shield, the horn of my salvation, and my refuge;\nyou are worthy of praise.")
+                    b: String::from("my shield, the horn of my salvation, and my refuge;\nyou are worthy of praise."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 3,
                     a: String::from("I will call upon the LORD, *"),
-                    b: String::from("and so shall I be saved from my enemies.")
+                    b: String::from("and so shall I be saved from my enemies."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 4,
                     a: String::from("The breakers of death rolled over me, *"),
-                    b: String::from("and the torrents of oblivion made me afraid.")
+                    b: String::from("and the torrents of oblivion made me afraid."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 5,
                     a: String::from("The cords of hell entangled me, *"),
-                    b: String::from("and the snares of death were set for me.")
+                    b: String::from("and the snares of death were set for me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 6,
                     a: String::from("I called upon the LORD in my distress *"),
-                    b: String::from("and cried out to my God for help.")
+                    b: String::from("and cried out to my God for help."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 7,
                     a: String::from("He heard my voice from his heavenly dwelling; *"),
-                    b: String::from("my cry of anguish came to his ears.")
+                    b: String::from("my cry of anguish came to his ears."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 8,
                     a: String::from("The earth reeled and rocked; *"),
-                    b: String::from("the roots of the mountains shook;\nthey reeled because of his anger.")
+                    b: String::from("the roots of the mountains shook;\nthey reeled because of his anger."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 9,
                     a: String::from("Smoke rose from his nostrils\nand a consuming fire out of his mouth; *"),
-                    b: String::from("hot burning coals blazed forth from him.")
+                    b: String::from("hot burning coals blazed forth from him."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 10,
                     a: String::from("He parted the heavens and came down *"),
-                    b: String::from("with a storm cloud under his feet.")
+                    b: String::from("with a storm cloud under his feet."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 11,
                     a: String::from("He mounted on cherubim and flew; *"),
-                    b: String::from("he swooped on the wings of the wind.")
+                    b: String::from("he swooped on the wings of the wind."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 12,
                     a: String::from("He wrapped darkness about him; *"),
-                    b: String::from("he made dark waters and thick clouds his pavilion.")
+                    b: String::from("he made dark waters and thick clouds his pavilion."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 13,
                     a: String::from("From the brightness of his presence, through the clouds, *"),
-                    b: String::from("burst hailstones and coals of fire.")
+                    b: String::from("burst hailstones and coals of fire."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 14,
                     a: String::from("The LORD thundered out of heaven; *"),
-                    b: String::from("the Most High uttered his voice.")
+                    b: String::from("the Most High uttered his voice."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 15,
                     a: String::from("He loosed his arrows and scattered them; *"),
-                    b: String::from("he hurled thunderbolts and routed them.")
+                    b: String::from("he hurled thunderbolts and routed them."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 16,
                     a: String::from("The beds of the seas were uncovered,\nand the foundations of the world laid bare, *"),
-                    b: String::from("at your battle cry, O LORD,\nat the blast of the breath of your nostrils.")
+                    b: String::from("at your battle cry, O LORD,\nat the blast of the breath of your nostrils."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 17,
                     a: String::from("He reached down from on high and grasped me; *"),
-                    b: String::from("he drew me out of great waters.")
+                    b: String::from("he drew me out of great waters."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 18,
                     a: String::from("He delivered me from my strong enemies\nand from those who hated me; *"),
-                    b: String::from("for they were too mighty for me.")
+                    b: String::from("for they were too mighty for me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 19,
                     a: String::from("They confronted me in the day of my disaster; *"),
-                    b: String::from("but the LORD was my support.")
+                    b: String::from("but the LORD was my support."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 20,
                     a: String::from("He brought me out into an open place; *"),
-                    b: String::from("he rescued me because he delighted in me.")
+                    b: String::from("he rescued me because he delighted in me."),
+                    ..Default::default()
                 },
             ]
         },
@@ -124,154 +144,185 @@ lazy_static! {
                         PsalmVerse {
                   number: 21,
                   a: String::from("The LORD rewarded me because of my righteous dealing; *"),
-                  b: String::from("because my hands were clean he rewarded me;")
+                  b: String::from("because my hands were clean he rewarded me;"),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 22,
                   a: String::from("For I have kept the ways of the LORD *"),
-                  b: String::from("and have not offended against my God;")
+                  b: String::from("and have not offended against my God;"),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 23,
                   a: String::from("For all his judgments are before my eyes, *"),
-                  b: String::from("and his decrees I have not put away from me;")
+                  b: String::from("and his decrees I have not put away from me;"),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 24,
                   a: String::from("For I have been blameless with him *"),
-                  b: String::from("and have kept myself from iniquity;")
+                  b: String::from("and have kept myself from iniquity;"),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 25,
                   a: String::from("Therefore the LORD rewarded me according to my righteous dealing, *"),
-                  b: String::from("because of the cleanness of my hands in his sight.")
+                  b: String::from("because of the cleanness of my hands in his sight."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 26,
                   a: String::from("With the faithful you show yourself faithful, O God; *"),
-                  b: String::from("with the forthright you show yourself forthright.")
+                  b: String::from("with the forthright you show yourself forthright."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 27,
                   a: String::from("With the pure you show yourself pure, *"),
-                  b: String::from("but with the crooked you are wily.")
+                  b: String::from("but with the crooked you are wily."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 28,
                   a: String::from("You will save a lowly people, *"),
-                  b: String::from("but you will humble the haughty eyes.")
+                  b: String::from("but you will humble the haughty eyes."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 29,
                   a: String::from("You, O LORD, are my lamp; *"),
-                  b: String::from("my God, you make my darkness bright.")
+                  b: String::from("my God, you make my darkness bright."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 30,
                   a: String::from("With you I will break down an enclosure; *"),
-                  b: String::from("with the help of my God I will scale any wall.")
+                  b: String::from("with the help of my God I will scale any wall."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 31,
                   a: String::from("As for God, his ways are perfect;\nthe words of the LORD are tried in the fire; *"),
-                  b: String::from("he is a shield to all who trust in him.")
+                  b: String::from("he is a shield to all who trust in him."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 32,
                   a: String::from("For who is God, but the LORD? *"),
-                  b: String::from("who is the Rock, except our God?")
+                  b: String::from("who is the Rock, except our God?"),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 33,
                   a: String::from("It is God who girds me about with strength *"),
-                  b: String::from("and makes my way secure.")
+                  b: String::from("and makes my way secure."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 34,
                   a: String::from("He makes me sure-footed like a deer *"),
-                  b: String::from("and lets me stand firm on the heights.")
+                  b: String::from("and lets me stand firm on the heights."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 35,
                   a: String::from("He trains my hands for battle *"),
-                  b: String::from("and my arms for bending even a bow of bronze.")
+                  b: String::from("and my arms for bending even a bow of bronze."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 36,
                   a: String::from("You have given me your shield of victory; *"),
-                  b: String::from("your right hand also sustains me;\nyour loving care makes me great.")
+                  b: String::from("your right hand also sustains me;\nyour loving care makes me great."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 37,
                   a: String::from("You lengthen my stride beneath me, *"),
-                  b: String::from("and my ankles do not give way.")
+                  b: String::from("and my ankles do not give way."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 38,
                   a: String::from("I pursue my enemies and overtake them; *"),
-                  b: String::from("I will not turn back till I have destroyed them.")
+                  b: String::from("I will not turn back till I have destroyed them."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 39,
                   a: String::from("I strike them down, and they cannot rise; *"),
-                  b: String::from("they fall defeated at my feet.")
+                  b: String::from("they fall defeated at my feet."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 40,
                   a: String::from("You have girded me with strength for the battle; *"),
-                  b: String::from("you have cast down my adversaries beneath me;\nyou have put my enemies to flight.")
+                  b: String::from("you have cast down my adversaries beneath me;\nyou have put my enemies to flight."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 41,
                   a: String::from("I destroy those who hate me;\nthey cry out, but there is none to help them; *"),
-                  b: String::from("they cry to the LORD, but he does not answer.")
+                  b: String::from("they cry to the LORD, but he does not answer."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 42,
                   a: String::from("I beat them small like dust before the wind; *"),
-                  b: String::from("I trample them like mud in the streets.")
+                  b: String::from("I trample them like mud in the streets."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 43,
                   a: String::from("You deliver me from the strife of the peoples; *"),
-                  b: String::from("you put me at the head of the nations.")
+                  b: String::from("you put me at the head of the nations."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 44,
                   a: String::from("A people I have not known shall serve me;\nno sooner shall they hear than they shall obey me; *"),
-                  b: String::from("strangers will cringe before me.")
+                  b: String::from("strangers will cringe before me."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 45,
                   a: String::from("The foreign peoples will lose heart; *"),
-                  b: String::from("they shall come trembling out of their strongholds.")
+                  b: String::from("they shall come trembling out of their strongholds."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 46,
                   a: String::from("The LORD lives!  Blessed is my Rock! *"),
-                  b: String::from("Exalted is the God of my salvation!")
+                  b: String::from("Exalted is the God of my salvation!"),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 47,
                   a: String::from("He is the God who gave me victory *"),
-                  b: String::from("and cast down the peoples beneath me.")
+                  b: String::from("and cast down the peoples beneath me."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 48,
                   a: String::from("You rescued me from the fury of my enemies;\nyou exalted me above those who rose against me; *"),
-                  b: String::from("you saved me from my deadly foe.")
+                  b: String::from("you saved me from my deadly foe."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 49,
                   a: String::from("Therefore will I extol you among the nations, O LORD, *"),
-                  b: String::from("and sing praises to your Name.")
+                  b: String::from("and sing praises to your Name."),
+                  ..Default::default()
               },
             PsalmVerse {
                   number: 50,
                   a: String::from("He multiplies the victories of his king; *"),
-                  b: String::from("he shows loving-kindness to his anointed,\nto David and his descendants for ever.")
+                  b: String::from("he shows loving-kindness to his anointed,\nto David and his descendants for ever."),
+                  ..Default::default()
               },
           ]
-        }]
+        }],
+        ..Default::default()
      };
 }
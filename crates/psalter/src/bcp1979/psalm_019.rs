@@ -15,75 +15,90 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("The heavens declare the glory of God, *"),
-                      b: String::from("and the firmament shows his handiwork.")
+                      b: String::from("and the firmament shows his handiwork."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("One day tells its tale to another, *"),
-                      b: String::from("and one night imparts knowledge to another.")
+                      b: String::from("and one night imparts knowledge to another."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Although they have no words or language, *"),
-                      b: String::from("and their voices are not heard,")
+                      b: String::from("and their voices are not heard,"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Their sound has gone out into all lands, *"),
-                      b: String::from("and their message to the ends of the world.")
+                      b: String::from("and their message to the ends of the world."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("In the deep has he set a pavilion for the sun; *"),
-                      b: String::from("it comes forth like a bridegroom out of his chamber;\nit rejoices like a champion to run its course.")
+                      b: String::from("it comes forth like a bridegroom out of his chamber;\nit rejoices like a champion to run its course."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("It goes forth from the uttermost edge of the heavens\nand runs about to the end of it again; *"),
-                      b: String::from("nothing is hidden from its burning heat.")
+                      b: String::from("nothing is hidden from its burning heat."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("The law of the LORD is perfect and revives the soul; *"),
-                      b: String::from("the testimony of the LORD is sure and gives wisdom to the innocent.")
+                      b: String::from("the testimony of the LORD is sure and gives wisdom to the innocent."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("The statutes of the LORD are just and rejoice the heart; *"),
-                      b: String::from("the commandment of the LORD is clear and gives light to the eyes.")
+                      b: String::from("the commandment of the LORD is clear and gives light to the eyes."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("The fear of the LORD is clean and endures for ever; *"),
-                      b: String::from("the judgments of the LORD are true and righteous altogether.")
+                      b: String::from("the judgments of the LORD are true and righteous altogether."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("More to be desired are they than gold, more than much fine gold, *"),
-                      b: String::from("sweeter far than honey, than honey in the comb.")
+                      b: String::from("sweeter far than honey, than honey in the comb."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("By them also is your servant enlightened, *"),
-                      b: String::from("and in keeping them there is great reward.")
+                      b: String::from("and in keeping them there is great reward."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("Who can tell how often he offends? *"),
-                      b: String::from("cleanse me from my secret faults.")
+                      b: String::from("cleanse me from my secret faults."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("Above all, keep your servant from presumptuous sins;\nlet them not get dominion over me; *"),
-                      b: String::from("then shall I be whole and sound,\nand innocent of a great offense.")
+                      b: String::from("then shall I be whole and sound,\nand innocent of a great offense."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("Let the words of my mouth and the meditation of my heart be acceptable in your sight, *"),
-                      b: String::from("O LORD, my strength and my redeemer.")
+                      b: String::from("O LORD, my strength and my redeemer."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
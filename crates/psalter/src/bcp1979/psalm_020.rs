@@ -15,50 +15,60 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("May the LORD answer you in the day of trouble, *"),
-                      b: String::from("the Name of the God of Jacob defend you;")
+                      b: String::from("the Name of the God of Jacob defend you;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Send you help from his holy place *"),
-                      b: String::from("and strengthen you out of Zion;")
+                      b: String::from("and strengthen you out of Zion;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Remember all your offerings *"),
-                      b: String::from("and accept your burnt sacrifice;")
+                      b: String::from("and accept your burnt sacrifice;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Grant you your heart’s desire *"),
-                      b: String::from("and prosper all your plans.")
+                      b: String::from("and prosper all your plans."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("We will shout for joy at your victory\nand triumph in the Name of our God; *"),
-                      b: String::from("may the LORD grant all your requests.")
+                      b: String::from("may the LORD grant all your requests."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Now I know that the LORD gives victory to his anointed; *"),
-                      b: String::from("he will answer him out of his holy heaven,\nwith the victorious strength of his right hand.")
+                      b: String::from("he will answer him out of his holy heaven,\nwith the victorious strength of his right hand."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Some put their trust in chariots and some in horses, *"),
-                      b: String::from("but we will call upon the Name of the LORD our God.")
+                      b: String::from("but we will call upon the Name of the LORD our God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("They collapse and fall down, *"),
-                      b: String::from("but we will arise and stand upright.")
+                      b: String::from("but we will arise and stand upright."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("O LORD, give victory to the king *"),
-                      b: String::from("and answer us when we call.")
+                      b: String::from("and answer us when we call."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
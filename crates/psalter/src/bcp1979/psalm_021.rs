@@ -14,78 +14,93 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("The king rejoices in your strength, O LORD; *"),
-                    b: String::from("how greatly he exults in your victory!")
+                    b: String::from("how greatly he exults in your victory!"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("You have given him his heart’s desire; *"),
-                    b: String::from("you have not denied him the request of his lips.")
+                    b: String::from("you have not denied him the request of his lips."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("For you meet him with blessings of prosperity, *"),
-                    b: String::from("and set a crown of fine gold upon his head.")
+                    b: String::from("and set a crown of fine gold upon his head."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("He asked you for life, and you gave it to him: *"),
-                    b: String::from("length of days, for ever and ever.")
+                    b: String::from("length of days, for ever and ever."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("His honor is great, because of your victory; *"),
-                    b: String::from("splendor and majesty have you bestowed upon him.")
+                    b: String::from("splendor and majesty have you bestowed upon him."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("For you will give him everlasting felicity *"),
-                    b: String::from("and will make him glad with the joy of your presence.")
+                    b: String::from("and will make him glad with the joy of your presence."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("For the king puts his trust in the LORD; *"),
                     b: String::from(
                         "because of the loving-kindness of the Most High, he will not fall."
-                    )
+                    ),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("Your hand will lay hold upon all your enemies; *"),
-                    b: String::from("your right hand will seize all those who hate you.")
+                    b: String::from("your right hand will seize all those who hate you."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("You will make them like a fiery furnace *"),
-                    b: String::from("at the time of your appearing, O LORD;")
+                    b: String::from("at the time of your appearing, O LORD;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 10,
                     a: String::from("You will swallow them up in your wrath, *"),
-                    b: String::from("and fire shall consume them.")
+                    b: String::from("and fire shall consume them."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 11,
                     a: String::from("You will destroy their offspring from the land *"),
-                    b: String::from("and their descendants from among the peoples of the earth.")
+                    b: String::from("and their descendants from among the peoples of the earth."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 12,
                     a: String::from(
                         "Though they intend evil against you\nand devise wicked schemes, *"
                     ),
-                    b: String::from("yet they shall not prevail.")
+                    b: String::from("yet they shall not prevail."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 13,
                     a: String::from("For you will put them to flight *"),
-                    b: String::from("and aim your arrows at them.")
+                    b: String::from("and aim your arrows at them."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 14,
                     a: String::from("Be exalted, O LORD, in your might; *"),
-                    b: String::from("we will sing and praise your power.")
+                    b: String::from("we will sing and praise your power."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
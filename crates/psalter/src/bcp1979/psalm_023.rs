@@ -15,35 +15,42 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("The LORD is my shepherd; *"),
-                      b: String::from("I shall not be in want.")
+                      b: String::from("I shall not be in want."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("He makes me lie down in green pastures *"),
-                      b: String::from("and leads me beside still waters.")
+                      b: String::from("and leads me beside still waters."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("He revives my soul *"),
-                      b: String::from("and guides me along right pathways for his Name’s sake.")
+                      b: String::from("and guides me along right pathways for his Name’s sake."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Though I walk through the valley of the shadow of death,\nI shall fear no evil; *"),
-                      b: String::from("for you are with me;\nyour rod and your staff, they comfort me.")
+                      b: String::from("for you are with me;\nyour rod and your staff, they comfort me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("You spread a table before me in the presence of those who trouble me; *"),
-                      b: String::from("you have anointed my head with oil,\nand my cup is running over.")
+                      b: String::from("you have anointed my head with oil,\nand my cup is running over."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Surely your goodness and mercy shall follow me all the days of my life, *"),
-                      b: String::from("and I will dwell in the house of the LORD for ever.")
+                      b: String::from("and I will dwell in the house of the LORD for ever."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,55 +15,66 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("The earth is the LORD’s and all that is in it, *"),
-                      b: String::from("the world and all who dwell therein.")
+                      b: String::from("the world and all who dwell therein."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("For it is he who founded it upon the seas *"),
-                      b: String::from("and made it firm upon the rivers of the deep.")
+                      b: String::from("and made it firm upon the rivers of the deep."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("“Who can ascend the hill of the LORD? *"),
-                      b: String::from("and who can stand in his holy place?”")
+                      b: String::from("and who can stand in his holy place?”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("“Those who have clean hands and a pure heart, *"),
-                      b: String::from("who have not pledged themselves to falsehood,\nnor sworn by what is a fraud.")
+                      b: String::from("who have not pledged themselves to falsehood,\nnor sworn by what is a fraud."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("They shall receive a blessing from the LORD *"),
-                      b: String::from("and a just reward from the God of their salvation.”")
+                      b: String::from("and a just reward from the God of their salvation.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Such is the generation of those who seek him, *"),
-                      b: String::from("of those who seek your face, O God of Jacob.")
+                      b: String::from("of those who seek your face, O God of Jacob."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Lift up your heads, O gates;\nlift them high, O everlasting doors; *"),
-                      b: String::from("and the King of glory shall come in.")
+                      b: String::from("and the King of glory shall come in."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("“Who is this King of glory?” *"),
-                      b: String::from("“The LORD, strong and mighty,\nthe LORD, mighty in battle.”")
+                      b: String::from("“The LORD, strong and mighty,\nthe LORD, mighty in battle.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Lift up your heads, O gates;\nlift them high, O everlasting doors; *"),
-                      b: String::from("and the King of glory shall come in.")
+                      b: String::from("and the King of glory shall come in."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("“Who is he, this King of glory?” *"),
-                      b: String::from("“The LORD of hosts,\nhe is the King of glory.”")
+                      b: String::from("“The LORD of hosts,\nhe is the King of glory.”"),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -16,64 +16,77 @@ lazy_static! {
                     a: String::from(
                         "Give judgment for me, O LORD,\nfor I have lived with integrity; *"
                     ),
-                    b: String::from("I have trusted in the Lord and have not faltered.")
+                    b: String::from("I have trusted in the Lord and have not faltered."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Test me, O LORD, and try me; *"),
-                    b: String::from("examine my heart and my mind.")
+                    b: String::from("examine my heart and my mind."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("For your love is before my eyes; *"),
-                    b: String::from("I have walked faithfully with you.")
+                    b: String::from("I have walked faithfully with you."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("I have not sat with the worthless, *"),
-                    b: String::from("nor do I consort with the deceitful.")
+                    b: String::from("nor do I consort with the deceitful."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("I have hated the company of evildoers; *"),
-                    b: String::from("I will not sit down with the wicked.")
+                    b: String::from("I will not sit down with the wicked."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("I will wash my hands in innocence, O LORD, *"),
-                    b: String::from("that I may go in procession round your altar,")
+                    b: String::from("that I may go in procession round your altar,"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("Singing aloud a song of thanksgiving *"),
-                    b: String::from("and recounting all your wonderful deeds.")
+                    b: String::from("and recounting all your wonderful deeds."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("LORD, I love the house in which you dwell *"),
-                    b: String::from("and the place where your glory abides.")
+                    b: String::from("and the place where your glory abides."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("Do not sweep me away with sinners, *"),
-                    b: String::from("nor my life with those who thirst for blood,")
+                    b: String::from("nor my life with those who thirst for blood,"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 10,
                     a: String::from("Whose hands are full of evil plots, *"),
-                    b: String::from("and their right hand full of bribes.")
+                    b: String::from("and their right hand full of bribes."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 11,
                     a: String::from("As for me, I will live with integrity; *"),
-                    b: String::from("redeem me, O LORD, and have pity on me.")
+                    b: String::from("redeem me, O LORD, and have pity on me."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 12,
                     a: String::from("My foot stands on level ground; *"),
-                    b: String::from("in the full assembly I will bless the LORD.")
+                    b: String::from("in the full assembly I will bless the LORD."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -15,95 +15,114 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("The LORD is my light and my salvation;\nwhom then shall I fear? *"),
-                      b: String::from("the LORD is the strength of my life;\nof whom then shall I be afraid?")
+                      b: String::from("the LORD is the strength of my life;\nof whom then shall I be afraid?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("When evildoers came upon me to eat up my flesh, *"),
-                      b: String::from("it was they, my foes and my adversaries, who stumbled and fell.")
+                      b: String::from("it was they, my foes and my adversaries, who stumbled and fell."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Though an army should encamp against me, *"),
-                      b: String::from("yet my heart shall not be afraid;")
+                      b: String::from("yet my heart shall not be afraid;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("And though war should rise up against me, *"),
-                      b: String::from("yet will I put my trust in him.")
+                      b: String::from("yet will I put my trust in him."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("One thing have I asked of the LORD;\none thing I seek; *"),
-                      b: String::from("that I may dwell in the house of the LORD all the days of my life;")
+                      b: String::from("that I may dwell in the house of the LORD all the days of my life;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("To behold the fair beauty of the LORD *"),
-                      b: String::from("and to seek him in his temple.")
+                      b: String::from("and to seek him in his temple."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("For in the day of trouble he shall keep me safe in his shelter; *"),
-                      b: String::from("he shall hide me in the secrecy of his dwelling\nand set me high upon a rock.")
+                      b: String::from("he shall hide me in the secrecy of his dwelling\nand set me high upon a rock."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Even now he lifts up my head *"),
-                      b: String::from("above my enemies round about me.")
+                      b: String::from("above my enemies round about me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Therefore I will offer in his dwelling an oblation\nwith sounds of great gladness; *"),
-                      b: String::from("I will sing and make music to the LORD.")
+                      b: String::from("I will sing and make music to the LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Hearken to my voice, O LORD, when I call; *"),
-                      b: String::from("have mercy on me and answer me.")
+                      b: String::from("have mercy on me and answer me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("You speak in my heart and say, “Seek my face.” *"),
-                      b: String::from("Your face, LORD, will I seek.")
+                      b: String::from("Your face, LORD, will I seek."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("Hide not your face from me, *"),
-                      b: String::from("nor turn away your servant in displeasure.")
+                      b: String::from("nor turn away your servant in displeasure."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("You have been my helper;\ncast me not away; *"),
-                      b: String::from("do not forsake me, O God of my salvation.")
+                      b: String::from("do not forsake me, O God of my salvation."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("Though my father and my mother forsake me, *"),
-                      b: String::from("the LORD will sustain me.")
+                      b: String::from("the LORD will sustain me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("Show me your way, O LORD; *"),
-                      b: String::from("lead me on a level path, because of my enemies.")
+                      b: String::from("lead me on a level path, because of my enemies."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("Deliver me not into the hand of my adversaries, *"),
-                      b: String::from("for false witnesses have risen up against me,\nand also those who speak malice.")
+                      b: String::from("for false witnesses have risen up against me,\nand also those who speak malice."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("What if I had not believed\nthat I should see the goodness of the LORD *"),
-                      b: String::from("in the land of the living!")
+                      b: String::from("in the land of the living!"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("O tarry and await the LORD’s pleasure;\nbe strong, and he shall comfort your heart; *"),
-                      b: String::from("wait patiently for the LORD.")
+                      b: String::from("wait patiently for the LORD."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
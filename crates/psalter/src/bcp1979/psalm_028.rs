@@ -15,60 +15,72 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("O LORD, I call to you;\nmy Rock, do not be deaf to my cry; *"),
-                      b: String::from("lest, if you do not hear me,\nI become like those who go down to the Pit.")
+                      b: String::from("lest, if you do not hear me,\nI become like those who go down to the Pit."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Hear the voice of my prayer when I cry out to you, *"),
-                      b: String::from("when I lift up my hands to your holy of holies.")
+                      b: String::from("when I lift up my hands to your holy of holies."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Do not snatch me away with the wicked or with the evildoers, *"),
-                      b: String::from("who speak peaceably with their neighbors,\nwhile strife is in their hearts.")
+                      b: String::from("who speak peaceably with their neighbors,\nwhile strife is in their hearts."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Repay them according to their deeds, *"),
-                      b: String::from("and according to the wickedness of their actions.")
+                      b: String::from("and according to the wickedness of their actions."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("According to the work of their hands repay them, *"),
-                      b: String::from("and give them their just deserts.")
+                      b: String::from("and give them their just deserts."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("They have no understanding of the LORD’s doings,\nnor of the works of his hands; *"),
-                      b: String::from("therefore he will break them down and not build them up.")
+                      b: String::from("therefore he will break them down and not build them up."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Blessed is the LORD! *"),
-                      b: String::from("for he has heard the voice of my prayer.")
+                      b: String::from("for he has heard the voice of my prayer."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("The LORD is my strength and my shield; *"),
-                      b: String::from("my heart trusts in him, and I have been helped;")
+                      b: String::from("my heart trusts in him, and I have been helped;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Therefore my heart dances for joy, *"),
-                      b: String::from("and in my song will I praise him.")
+                      b: String::from("and in my song will I praise him."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("The LORD is the strength of his people, *"),
-                      b: String::from("a safe refuge for his anointed.")
+                      b: String::from("a safe refuge for his anointed."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Save your people and bless your inheritance; *"),
-                      b: String::from("shepherd them and carry them for ever.")
+                      b: String::from("shepherd them and carry them for ever."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
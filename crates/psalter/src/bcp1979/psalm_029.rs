@@ -15,60 +15,72 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Ascribe to the LORD, you gods, *"),
-                      b: String::from("ascribe to the LORD glory and strength.")
+                      b: String::from("ascribe to the LORD glory and strength."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Ascribe to the LORD the glory due his Name; *"),
-                      b: String::from("worship the LORD in the beauty of holiness.")
+                      b: String::from("worship the LORD in the beauty of holiness."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("The voice of the LORD is upon the waters;\nthe God of glory thunders; *"),
-                      b: String::from("the LORD is upon the mighty waters.")
+                      b: String::from("the LORD is upon the mighty waters."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("The voice of the LORD is a powerful voice; *"),
-                      b: String::from("the voice of the LORD is a voice of splendor.")
+                      b: String::from("the voice of the LORD is a voice of splendor."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("The voice of the LORD breaks the cedar trees; *"),
-                      b: String::from("the LORD breaks the cedars of Lebanon;")
+                      b: String::from("the LORD breaks the cedars of Lebanon;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("He makes Lebanon skip like a calf, *"),
-                      b: String::from("and Mount Hermon like a young wild ox.")
+                      b: String::from("and Mount Hermon like a young wild ox."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("The voice of the LORD splits the flames of fire;\nthe voice of the LORD shakes the wilderness; *"),
-                      b: String::from("the LORD shakes the wilderness of Kadesh.")
+                      b: String::from("the LORD shakes the wilderness of Kadesh."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("The voice of the LORD makes the oak trees writhe *"),
-                      b: String::from("and strips the forests bare.")
+                      b: String::from("and strips the forests bare."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("And in the temple of the LORD *"),
-                      b: String::from("all are crying, “Glory!”")
+                      b: String::from("all are crying, “Glory!”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("The LORD sits enthroned above the flood; *"),
-                      b: String::from("the LORD sits enthroned as King for evermore.")
+                      b: String::from("the LORD sits enthroned as King for evermore."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("The LORD shall give strength to his people; *"),
-                      b: String::from("the LORD shall give his people the blessing of peace.")
+                      b: String::from("the LORD shall give his people the blessing of peace."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,125 +15,150 @@ lazy_static! {
                           PsalmVerse {
                     number: 1,
                     a: String::from("In you, O LORD, have I taken refuge;\nlet me never be put to shame; *"),
-                    b: String::from("deliver me in your righteousness.")
+                    b: String::from("deliver me in your righteousness."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 2,
                     a: String::from("Incline your ear to me; *"),
-                    b: String::from("make haste to deliver me.")
+                    b: String::from("make haste to deliver me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 3,
                     a: String::from("Be my strong rock, a castle to keep me safe,\nfor you are my crag and my stronghold; *"),
-                    b: String::from("for the sake of your Name, lead me and guide me.")
+                    b: String::from("for the sake of your Name, lead me and guide me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 4,
                     a: String::from("Take me out of the net that they have secretly set for me, *"),
-                    b: String::from("for you are my tower of strength.")
+                    b: String::from("for you are my tower of strength."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 5,
                     a: String::from("Into your hands I commend my spirit, *"),
-                    b: String::from("for you have redeemed me,\nO LORD, O God of truth.")
+                    b: String::from("for you have redeemed me,\nO LORD, O God of truth."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 6,
                     a: String::from("I hate those who cling to worthless idols, *"),
-                    b: String::from("and I put my trust in the LORD.")
+                    b: String::from("and I put my trust in the LORD."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 7,
                     a: String::from("I will rejoice and be glad because of your mercy; *"),
-                    b: String::from("for you have seen my affliction;\nyou know my distress.")
+                    b: String::from("for you have seen my affliction;\nyou know my distress."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 8,
                     a: String::from("You have not shut me up in the power of the enemy; *"),
-                    b: String::from("you have set my feet in an open place.")
+                    b: String::from("you have set my feet in an open place."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 9,
                     a: String::from("Have mercy on me, O LORD, for I am in trouble; *"),
-                    b: String::from("my eye is consumed with sorrow,\nand also my throat and my belly.")
+                    b: String::from("my eye is consumed with sorrow,\nand also my throat and my belly."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 10,
                     a: String::from("For my life is wasted with grief,\nand my years with sighing; *"),
-                    b: String::from("my strength fails me because of affliction,\nand my bones are consumed.")
+                    b: String::from("my strength fails me because of affliction,\nand my bones are consumed."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 11,
                     a: String::from("I have become a reproach to all my enemies and even to my neighbors,\na dismay to those of my acquaintance; *"),
-                    b: String::from("when they see me in the street they avoid me.")
+                    b: String::from("when they see me in the street they avoid me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 12,
                     a: String::from("I am forgotten like a dead man, out of mind; *"),
-                    b: String::from("I am as useless as a broken pot.")
+                    b: String::from("I am as useless as a broken pot."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 13,
                     a: String::from("For I have heard the whispering of the crowd;\nfear is all around; *"),
-                    b: String::from("they put their heads together against me;\nthey plot to take my life.")
+                    b: String::from("they put their heads together against me;\nthey plot to take my life."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 14,
                     a: String::from("But as for me, I have trusted in you, O LORD. *"),
-                    b: String::from("I have said, “You are my God.")
+                    b: String::from("I have said, “You are my God."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 15,
                     a: String::from("My times are in your hand; *"),
-                    b: String::from("rescue me from the hand of my enemies,\nand from those who persecute me.")
+                    b: String::from("rescue me from the hand of my enemies,\nand from those who persecute me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 16,
                     a: String::from("Make your face to shine upon your servant, *"),
-                    b: String::from("and in your loving-kindness save me.”")
+                    b: String::from("and in your loving-kindness save me.”"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 17,
                     a: String::from("LORD, let me not be ashamed for having called upon you; *"),
-                    b: String::from("rather, let the wicked be put to shame;\nlet them be silent in the grave.")
+                    b: String::from("rather, let the wicked be put to shame;\nlet them be silent in the grave."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 18,
                     a: String::from("Let the lying lips be silenced which speak against the righteous, *"),
-                    b: String::from("haughtily, disdainfully, and with contempt.")
+                    b: String::from("haughtily, disdainfully, and with contempt."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 19,
                     a: String::from("How great is your goodness, O LORD!\nwhich you have laid up for those who fear you; *"),
-                    b: String::from("which you have done in the sight of all\nfor those who put their trust in you.")
+                    b: String::from("which you have done in the sight of all\nfor those who put their trust in you."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 20,
                     a: String::from("You hide them in the covert of your presence from those who slander them; *"),
-                    b: String::from("you keep them in your shelter from the strife of tongues.")
+                    b: String::from("you keep them in your shelter from the strife of tongues."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 21,
                     a: String::from("Blessed be the LORD! *"),
-                    b: String::from("for he has shown me the wonders of his love in a besieged city.")
+                    b: String::from("for he has shown me the wonders of his love in a besieged city."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 22,
                     a: String::from("Yet I said in my alarm,\n“I have been cut off from the sight of your eyes.” *"),
-                    b: String::from("Nevertheless, you heard the sound of my entreaty\nwhen I cried out to you.")
+                    b: String::from("Nevertheless, you heard the sound of my entreaty\nwhen I cried out to you."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 23,
                     a: String::from("Love the LORD, all you who worship him; *"),
-                    b: String::from("the LORD protects the faithful,\nbut repays to the full those who act haughtily.")
+                    b: String::from("the LORD protects the faithful,\nbut repays to the full those who act haughtily."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 24,
                     a: String::from("Be strong and let your heart take courage, *"),
-                    b: String::from("all you who wait for the LORD.")
+                    b: String::from("all you who wait for the LORD."),
+                    ..Default::default()
                 },
             ]
           }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,65 +15,78 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Happy are they whose transgressions are forgiven, *"),
-                      b: String::from("and whose sin is put away!")
+                      b: String::from("and whose sin is put away!"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Happy are they to whom the LORD imputes no guilt, *"),
-                      b: String::from("and in whose spirit there is no guile!")
+                      b: String::from("and in whose spirit there is no guile!"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("While I held my tongue, my bones withered away, *"),
-                      b: String::from("because of my groaning all day long.")
+                      b: String::from("because of my groaning all day long."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("For your hand was heavy upon me day and night; *"),
-                      b: String::from("my moisture was dried up as in the heat of summer.")
+                      b: String::from("my moisture was dried up as in the heat of summer."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Then I acknowledged my sin to you, *"),
-                      b: String::from("and did not conceal my guilt.")
+                      b: String::from("and did not conceal my guilt."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("I said, “I will confess my transgressions to the LORD.” *"),
-                      b: String::from("Then you forgave me the guilt of my sin.")
+                      b: String::from("Then you forgave me the guilt of my sin."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Therefore all the faithful will make their prayers to you in time of trouble; *"),
-                      b: String::from("when the great waters overflow, they shall not reach them.")
+                      b: String::from("when the great waters overflow, they shall not reach them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("You are my hiding-place;\nyou preserve me from trouble; *"),
-                      b: String::from("you surround me with shouts of deliverance.")
+                      b: String::from("you surround me with shouts of deliverance."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("“I will instruct you and teach you in the way that you should go; *"),
-                      b: String::from("I will guide you with my eye.")
+                      b: String::from("I will guide you with my eye."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Do not be like horse or mule, which have no understanding; *"),
-                      b: String::from("who must be fitted with bit and bridle,\nor else they will not stay near you.”")
+                      b: String::from("who must be fitted with bit and bridle,\nor else they will not stay near you.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Great are the tribulations of the wicked; *"),
-                      b: String::from("but mercy embraces those who trust in the LORD.")
+                      b: String::from("but mercy embraces those who trust in the LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("Be glad, you righteous, and rejoice in the LORD; *"),
-                      b: String::from("shout for joy, all who are true of heart.")
+                      b: String::from("shout for joy, all who are true of heart."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -14,114 +14,137 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Rejoice in the LORD, you righteous; *"),
-                    b: String::from("it is good for the just to sing praises.")
+                    b: String::from("it is good for the just to sing praises."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Praise the LORD with the harp; *"),
-                    b: String::from("play to him upon the psaltery and lyre.")
+                    b: String::from("play to him upon the psaltery and lyre."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("Sing for him a new song; *"),
-                    b: String::from("sound a fanfare with all your skill upon the trumpet.")
+                    b: String::from("sound a fanfare with all your skill upon the trumpet."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("For the word of the LORD is right, *"),
-                    b: String::from("and all his works are sure.")
+                    b: String::from("and all his works are sure."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("He loves righteousness and justice; *"),
-                    b: String::from("the loving-kindness of the LORD fills the whole earth.")
+                    b: String::from("the loving-kindness of the LORD fills the whole earth."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("By the word of the LORD were the heavens made, *"),
-                    b: String::from("by the breath of his mouth all the heavenly hosts.")
+                    b: String::from("by the breath of his mouth all the heavenly hosts."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("He gathers up the waters of the ocean as in a water-skin *"),
-                    b: String::from("and stores up the depths of the sea.")
+                    b: String::from("and stores up the depths of the sea."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("Let all the earth fear the LORD; *"),
-                    b: String::from("let all who dwell in the world stand in awe of him.")
+                    b: String::from("let all who dwell in the world stand in awe of him."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("For he spoke, and it came to pass; *"),
-                    b: String::from("he commanded, and it stood fast.")
+                    b: String::from("he commanded, and it stood fast."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 10,
                     a: String::from("The LORD brings the will of the nations to naught; *"),
-                    b: String::from("he thwarts the designs of the peoples.")
+                    b: String::from("he thwarts the designs of the peoples."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 11,
                     a: String::from("But the LORD’s will stands fast for ever, *"),
-                    b: String::from("and the designs of his heart from age to age.")
+                    b: String::from("and the designs of his heart from age to age."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 12,
                     a: String::from("Happy is the nation whose God is the LORD! *"),
-                    b: String::from("happy the people he has chosen to be his own!")
+                    b: String::from("happy the people he has chosen to be his own!"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 13,
                     a: String::from("The LORD looks down from heaven, *"),
-                    b: String::from("and beholds all the people in the world.")
+                    b: String::from("and beholds all the people in the world."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 14,
                     a: String::from("From where he sits enthroned he turns his gaze *"),
-                    b: String::from("on all who dwell on the earth.")
+                    b: String::from("on all who dwell on the earth."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 15,
                     a: String::from("He fashions all the hearts of them *"),
-                    b: String::from("and understands all their works.")
+                    b: String::from("and understands all their works."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 16,
                     a: String::from("There is no king that can be saved by a mighty army; *"),
-                    b: String::from("a strong man is not delivered by his great strength.")
+                    b: String::from("a strong man is not delivered by his great strength."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 17,
                     a: String::from("The horse is a vain hope for deliverance; *"),
-                    b: String::from("for all its strength it cannot save.")
+                    b: String::from("for all its strength it cannot save."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 18,
                     a: String::from("Behold, the eye of the LORD is upon those who fear him, *"),
-                    b: String::from("on those who wait upon his love,")
+                    b: String::from("on those who wait upon his love,"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 19,
                     a: String::from("To pluck their lives from death, *"),
-                    b: String::from("and to feed them in time of famine.")
+                    b: String::from("and to feed them in time of famine."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 20,
                     a: String::from("Our soul waits for the LORD; *"),
-                    b: String::from("he is our help and our shield.")
+                    b: String::from("he is our help and our shield."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 21,
                     a: String::from("Indeed, our heart rejoices in him, *"),
-                    b: String::from("for in his holy Name we put our trust.")
+                    b: String::from("for in his holy Name we put our trust."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 22,
                     a: String::from("Let your loving-kindness, O LORD, be upon us, *"),
-                    b: String::from("as we have put our trust in you.")
+                    b: String::from("as we have put our trust in you."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -15,145 +15,174 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Fight those who fight me, O LORD; *"),
-                      b: String::from("attack those who are attacking me.")
+                      b: String::from("attack those who are attacking me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Take up shield and armor *"),
-                      b: String::from("and rise up to help me.")
+                      b: String::from("and rise up to help me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Draw the sword and bar the way against those who pursue me; *"),
-                      b: String::from("say to my soul, “I am your salvation.”")
+                      b: String::from("say to my soul, “I am your salvation.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Let those who seek after my life be shamed and humbled; *"),
-                      b: String::from("let those who plot my ruin fall back and be dismayed.")
+                      b: String::from("let those who plot my ruin fall back and be dismayed."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Let them be like chaff before the wind, *"),
-                      b: String::from("and let the angel of the LORD drive them away.")
+                      b: String::from("and let the angel of the LORD drive them away."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Let their way be dark and slippery, *"),
-                      b: String::from("and let the angel of the LORD pursue them.")
+                      b: String::from("and let the angel of the LORD pursue them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("For they have secretly spread a net for me without a cause; *"),
-                      b: String::from("without a cause they have dug a pit to take me alive.")
+                      b: String::from("without a cause they have dug a pit to take me alive."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Let ruin come upon them unawares; *"),
-                      b: String::from("let them be caught in the net they hid;\nlet them fall into the pit they dug.")
+                      b: String::from("let them be caught in the net they hid;\nlet them fall into the pit they dug."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Then I will be joyful in the LORD; *"),
-                      b: String::from("I will glory in his victory.")
+                      b: String::from("I will glory in his victory."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("My very bones will say, “LORD, who is like you? *"),
-                      b: String::from("You deliver the poor from those who are too strong for them,\nthe poor and needy from those who rob them.”")
+                      b: String::from("You deliver the poor from those who are too strong for them,\nthe poor and needy from those who rob them.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Malicious witnesses rise up against me; *"),
-                      b: String::from("they charge me with matters I know nothing about.")
+                      b: String::from("they charge me with matters I know nothing about."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("They pay me evil in exchange for good; *"),
-                      b: String::from("my soul is full of despair.")
+                      b: String::from("my soul is full of despair."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("But when they were sick I dressed in sack-cloth *"),
-                      b: String::from("and humbled myself by fasting.")
+                      b: String::from("and humbled myself by fasting."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("I prayed with my whole heart,\nas one would for a friend or a brother; *"),
-                      b: String::from("I behaved like one who mourns for his mother,\nbowed down and grieving.")
+                      b: String::from("I behaved like one who mourns for his mother,\nbowed down and grieving."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("But when I stumbled, they were glad and gathered together;\nthey gathered against me; *"),
-                      b: String::from("strangers whom I did not know tore me to pieces and would not stop.")
+                      b: String::from("strangers whom I did not know tore me to pieces and would not stop."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("They put me to the test and mocked me; *"),
-                      b: String::from("they gnashed at me with their teeth.")
+                      b: String::from("they gnashed at me with their teeth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("O Lord, how long will you look on? *"),
-                      b: String::from("rescue me from the roaring beasts,\nand my life from the young lions.")
+                      b: String::from("rescue me from the roaring beasts,\nand my life from the young lions."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("I will give you thanks in the great congregation; *"),
-                      b: String::from("I will praise you in the mighty throng.")
+                      b: String::from("I will praise you in the mighty throng."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("Do not let my treacherous foes rejoice over me, *"),
-                      b: String::from("nor let those who hate me without a cause wink at each other.")
+                      b: String::from("nor let those who hate me without a cause wink at each other."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("For they do not plan for peace, *"),
-                      b: String::from("but invent deceitful schemes against the quiet in the land.")
+                      b: String::from("but invent deceitful schemes against the quiet in the land."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 21,
                       a: String::from("They opened their mouths at me and said, *"),
-                      b: String::from("“Aha! we saw it with our own eyes.”")
+                      b: String::from("“Aha! we saw it with our own eyes.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 22,
                       a: String::from("You saw it, O LORD; do not be silent; *"),
-                      b: String::from("O Lord, be not far from me.")
+                      b: String::from("O Lord, be not far from me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 23,
                       a: String::from("Awake, arise, to my cause! *"),
-                      b: String::from("to my defense, my God and my Lord!")
+                      b: String::from("to my defense, my God and my Lord!"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 24,
                       a: String::from("Give me justice, O LORD my God,\naccording to your righteousness; *"),
-                      b: String::from("do not let them triumph over me.")
+                      b: String::from("do not let them triumph over me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 25,
                       a: String::from("Do not let them say in their hearts,\n“Aha! just what we want!” *"),
-                      b: String::from("Do not let them say, “We have swallowed him up.”")
+                      b: String::from("Do not let them say, “We have swallowed him up.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 26,
                       a: String::from("Let all who rejoice at my ruin be ashamed and disgraced; *"),
-                      b: String::from("let those who boast against me be clothed with dismay and shame.")
+                      b: String::from("let those who boast against me be clothed with dismay and shame."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 27,
                       a: String::from("Let those who favor my cause sing out with joy and be glad; *"),
-                      b: String::from("let them say always, “Great is the LORD,\nwho desires the prosperity of his servant.”")
+                      b: String::from("let them say always, “Great is the LORD,\nwho desires the prosperity of his servant.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 28,
                       a: String::from("And my tongue shall be talking of your righteousness *"),
-                      b: String::from("and of your praise all the day long.")
+                      b: String::from("and of your praise all the day long."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,65 +15,78 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("There is a voice of rebellion deep in the heart of the wicked; *"),
-                      b: String::from("there is no fear of God before his eyes.")
+                      b: String::from("there is no fear of God before his eyes."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("He flatters himself in his own eyes *"),
-                      b: String::from("that his hateful sin will not be found out.")
+                      b: String::from("that his hateful sin will not be found out."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("The words of his mouth are wicked and deceitful; *"),
-                      b: String::from("he has left off acting wisely and doing good.")
+                      b: String::from("he has left off acting wisely and doing good."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("He thinks up wickedness upon his bed\nand has set himself in no good way; *"),
-                      b: String::from("he does not abhor that which is evil.")
+                      b: String::from("he does not abhor that which is evil."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Your love, O LORD, reaches to the heavens, *"),
-                      b: String::from("and your faithfulness to the clouds.")
+                      b: String::from("and your faithfulness to the clouds."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Your righteousness is like the strong mountains,\nyour justice like the great deep; *"),
-                      b: String::from("you save both man and beast, O LORD.")
+                      b: String::from("you save both man and beast, O LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("How priceless is your love, O God! *"),
-                      b: String::from("your people take refuge under the shadow of your wings.")
+                      b: String::from("your people take refuge under the shadow of your wings."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("They feast upon the abundance of your house; *"),
-                      b: String::from("you give them drink from the river of your delights.")
+                      b: String::from("you give them drink from the river of your delights."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("For with you is the well of life, *"),
-                      b: String::from("and in your light we see light.")
+                      b: String::from("and in your light we see light."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Continue your loving-kindness to those who know you, *"),
-                      b: String::from("and your favor to those who are true of heart.")
+                      b: String::from("and your favor to those who are true of heart."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Let not the foot of the proud come near me, *"),
-                      b: String::from("nor the hand of the wicked push me aside.")
+                      b: String::from("nor the hand of the wicked push me aside."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("See how they are fallen, those who work wickedness! *"),
-                      b: String::from("they are cast down and shall not be able to rise.")
+                      b: String::from("they are cast down and shall not be able to rise."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,92 +15,110 @@ lazy_static! {
                           PsalmVerse {
                     number: 1,
                     a: String::from("Do not fret yourself because of evildoers; *"),
-                    b: String::from("do not be jealous of those who do wrong.")
+                    b: String::from("do not be jealous of those who do wrong."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 2,
                     a: String::from("For they shall soon wither like the grass, *"),
-                    b: String::from("and like the green grass fade away.")
+                    b: String::from("and like the green grass fade away."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 3,
                     a: String::from("Put your trust in the LORD and do good; *"),
-                    b: String::from("dwell in the land and feed on its riches.")
+                    b: String::from("dwell in the land and feed on its riches."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 4,
                     a: String::from("Take delight in the LORD, *"),
-                    b: String::from("and he shall give you your heart’s desire.")
+                    b: String::from("and he shall give you your heart’s desire."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 5,
                     a: String::from("Commit your way to the LORD and put your trust in him, *"),
-                    b: String::from("and he will bring it to pass.")
+                    b: String::from("and he will bring it to pass."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 6,
                     a: String::from("He will make your righteousness as clear as the light *"),
-                    b: String::from("and your just dealing as the noonday.")
+                    b: String::from("and your just dealing as the noonday."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 7,
                     a: String::from("Be still before the LORD *"),
-                    b: String::from("and wait patiently for him.")
+                    b: String::from("and wait patiently for him."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 8,
                     a: String::from("Do not fret yourself over the one who prospers, *"),
-                    b: String::from("the one who succeeds in evil schemes.")
+                    b: String::from("the one who succeeds in evil schemes."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 9,
                     a: String::from("Refrain from anger, leave rage alone; *"),
-                    b: String::from("do not fret yourself; it leads only to evil.")
+                    b: String::from("do not fret yourself; it leads only to evil."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 10,
                     a: String::from("For evildoers shall be cut off, *"),
-                    b: String::from("but those who wait upon the LORD shall possess the land.")
+                    b: String::from("but those who wait upon the LORD shall possess the land."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 11,
                     a: String::from("In a little while the wicked shall be no more; *"),
-                    b: String::from("you shall search out their place, but they will not be there.")
+                    b: String::from("you shall search out their place, but they will not be there."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 12,
                     a: String::from("But the lowly shall possess the land; *"),
-                    b: String::from("they will delight in abundance of peace.")
+                    b: String::from("they will delight in abundance of peace."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 13,
                     a: String::from("The wicked plot against the righteous *"),
-                    b: String::from("and gnash at them with their teeth.")
+                    b: String::from("and gnash at them with their teeth."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 14,
                     a: String::from("The Lord laughs at the wicked, *"),
-                    b: String::from("because he sees that their day will come.")
+                    b: String::from("because he sees that their day will come."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 15,
                     a: String::from("The wicked draw their sword and bend their bow\nto strike down the poor and needy, *"),
-                    b: String::from("to slaughter those who are upright in their ways.")
+                    b: String::from("to slaughter those who are upright in their ways."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 16,
                     a: String::from("Their sword shall go through their own heart, *"),
-                    b: String::from("and their bow shall be broken.")
+                    b: String::from("and their bow shall be broken."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 17,
                     a: String::from("The little that the righteous has *"),
-                    b: String::from("is better than great riches of the wicked.")
+                    b: String::from("is better than great riches of the wicked."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 18,
                     a: String::from("For the power of the wicked shall be broken, *"),
-                    b: String::from("but the LORD upholds the righteous.")
+                    b: String::from("but the LORD upholds the righteous."),
+                    ..Default::default()
                 },
             ]
           },
@@ -115,125 +133,150 @@ lazy_static! {
               PsalmVerse {
                     number: 19,
                     a: String::from("The LORD cares for the lives of the godly, *"),
-                    b: String::from("and their inheritance shall last for ever.")
+                    b: String::from("and their inheritance shall last for ever."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 20,
                     a: String::from("They shall not be ashamed in bad times, *"),
-                    b: String::from("and in days of famine they shall have enough.")
+                    b: String::from("and in days of famine they shall have enough."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 21,
                     a: String::from("As for the wicked, they shall perish, *"),
-                    b: String::from("and the enemies of the LORD, like the glory of the meadows, shall vanish;\nthey shall vanish like smoke.")
+                    b: String::from("and the enemies of the LORD, like the glory of the meadows, shall vanish;\nthey shall vanish like smoke."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 22,
                     a: String::from("The wicked borrow and do not repay, *"),
-                    b: String::from("but the righteous are generous in giving.")
+                    b: String::from("but the righteous are generous in giving."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 23,
                     a: String::from("Those who are blessed by God shall possess the land, *"),
-                    b: String::from("but those who are cursed by him shall be destroyed.")
+                    b: String::from("but those who are cursed by him shall be destroyed."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 24,
                     a: String::from("Our steps are directed by the LORD; *"),
-                    b: String::from("he strengthens those in whose way he delights.")
+                    b: String::from("he strengthens those in whose way he delights."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 25,
                     a: String::from("If they stumble, they shall not fall headlong, *"),
-                    b: String::from("for the LORD holds them by the hand.")
+                    b: String::from("for the LORD holds them by the hand."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 26,
                     a: String::from("I have been young and now I am old, *"),
-                    b: String::from("but never have I seen the righteous forsaken,\nor their children begging bread.")
+                    b: String::from("but never have I seen the righteous forsaken,\nor their children begging bread."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 27,
                     a: String::from("The righteous are always generous in their lending, *"),
-                    b: String::from("and their children shall be a blessing.")
+                    b: String::from("and their children shall be a blessing."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 28,
                     a: String::from("Turn from evil, and do good, *"),
-                    b: String::from("and dwell in the land for ever.")
+                    b: String::from("and dwell in the land for ever."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 29,
                     a: String::from("For the LORD loves justice; *"),
-                    b: String::from("he does not forsake his faithful ones.")
+                    b: String::from("he does not forsake his faithful ones."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 30,
                     a: String::from("They shall be kept safe for ever, *"),
-                    b: String::from("but the offspring of the wicked shall be destroyed.")
+                    b: String::from("but the offspring of the wicked shall be destroyed."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 31,
                     a: String::from("The righteous shall possess the land *"),
-                    b: String::from("and dwell in it for ever.")
+                    b: String::from("and dwell in it for ever."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 32,
                     a: String::from("The mouth of the righteous utters wisdom, *"),
-                    b: String::from("and their tongue speaks what is right.")
+                    b: String::from("and their tongue speaks what is right."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 33,
                     a: String::from("The law of their God is in their heart, *"),
-                    b: String::from("and their footsteps shall not falter.")
+                    b: String::from("and their footsteps shall not falter."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 34,
                     a: String::from("The wicked spy on the righteous *"),
-                    b: String::from("and seek occasion to kill them.")
+                    b: String::from("and seek occasion to kill them."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 35,
                     a: String::from("The LORD will not abandon them to their hand, *"),
-                    b: String::from("nor let them be found guilty when brought to trial.")
+                    b: String::from("nor let them be found guilty when brought to trial."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 36,
                     a: String::from("Wait upon the LORD and keep his way; *"),
-                    b: String::from("he will raise you up to possess the land,\nand when the wicked are cut off, you will see it.")
+                    b: String::from("he will raise you up to possess the land,\nand when the wicked are cut off, you will see it."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 37,
                     a: String::from("I have seen the wicked in their arrogance, *"),
-                    b: String::from("flourishing like a tree in full leaf.")
+                    b: String::from("flourishing like a tree in full leaf."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 38,
                     a: String::from("I went by, and behold, they were not there; *"),
-                    b: String::from("I searched for them, but they could not be found.")
+                    b: String::from("I searched for them, but they could not be found."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 39,
                     a: String::from("Mark those who are honest;\nobserve the upright; *"),
-                    b: String::from("for there is a future for the peaceable.")
+                    b: String::from("for there is a future for the peaceable."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 40,
                     a: String::from("Transgressors shall be destroyed, one and all; *"),
-                    b: String::from("the future of the wicked is cut off.")
+                    b: String::from("the future of the wicked is cut off."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 41,
                     a: String::from("But the deliverance of the righteous comes from the LORD; *"),
-                    b: String::from("he is their stronghold in time of trouble.")
+                    b: String::from("he is their stronghold in time of trouble."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 42,
                     a: String::from("The LORD will help them and rescue them; *"),
-                    b: String::from("he will rescue them from the wicked and deliver them,\nbecause they seek refuge in him.")
+                    b: String::from("he will rescue them from the wicked and deliver them,\nbecause they seek refuge in him."),
+                    ..Default::default()
                 },
             ]
           }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -14,114 +14,137 @@ lazy_static! {
               PsalmVerse {
                 number: 1,
                 a: String::from("O LORD, do not rebuke me in your anger; *"),
-                b: String::from("do not punish me in your wrath.")
+                b: String::from("do not punish me in your wrath."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 2,
                 a: String::from("For your arrows have already pierced me, *"),
-                b: String::from("and your hand presses hard upon me.")
+                b: String::from("and your hand presses hard upon me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 3,
                 a: String::from("There is no health in my flesh,\nbecause of your indignation; *"),
-                b: String::from("there is no soundness in my body, because of my sin.")
+                b: String::from("there is no soundness in my body, because of my sin."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 4,
                 a: String::from("For my iniquities overwhelm me; *"),
-                b: String::from("like a heavy burden they are too much for me to bear.")
+                b: String::from("like a heavy burden they are too much for me to bear."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 5,
                 a: String::from("My wounds stink and fester *"),
-                b: String::from("by reason of my foolishness.")
+                b: String::from("by reason of my foolishness."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 6,
                 a: String::from("I am utterly bowed down and prostrate; *"),
-                b: String::from("I go about in mourning all the day long.")
+                b: String::from("I go about in mourning all the day long."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 7,
                 a: String::from("My loins are filled with searing pain; *"),
-                b: String::from("there is no health in my body.")
+                b: String::from("there is no health in my body."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 8,
                 a: String::from("I am utterly numb and crushed; *"),
-                b: String::from("I wail, because of the groaning of my heart.")
+                b: String::from("I wail, because of the groaning of my heart."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 9,
                 a: String::from("O Lord, you know all my desires, *"),
-                b: String::from("and my sighing is not hidden from you.")
+                b: String::from("and my sighing is not hidden from you."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 10,
                 a: String::from("My heart is pounding, my strength has failed me, *"),
-                b: String::from("and the brightness of my eyes is gone from me.")
+                b: String::from("and the brightness of my eyes is gone from me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 11,
                 a: String::from("My friends and companions draw back from my affliction; *"),
-                b: String::from("my neighbors stand afar off.")
+                b: String::from("my neighbors stand afar off."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 12,
                 a: String::from("Those who seek after my life lay snares for me; *"),
-                b: String::from("those who strive to hurt me speak of my ruin\nand plot treachery all the day long.")
+                b: String::from("those who strive to hurt me speak of my ruin\nand plot treachery all the day long."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 13,
                 a: String::from("But I am like the deaf who do not hear, *"),
-                b: String::from("like those who are mute and who do not open their mouth.")
+                b: String::from("like those who are mute and who do not open their mouth."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 14,
                 a: String::from("I have become like one who does not hear *"),
-                b: String::from("and from whose mouth comes no defense.")
+                b: String::from("and from whose mouth comes no defense."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 15,
                 a: String::from("For in you, O LORD, have I fixed my hope; *"),
-                b: String::from("you will answer me, O Lord my God.")
+                b: String::from("you will answer me, O Lord my God."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 16,
                 a: String::from("For I said, “Do not let them rejoice at my expense, *"),
-                b: String::from("those who gloat over me when my foot slips.”")
+                b: String::from("those who gloat over me when my foot slips.”"),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 17,
                 a: String::from("Truly, I am on the verge of falling, *"),
-                b: String::from("and my pain is always with me.")
+                b: String::from("and my pain is always with me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 18,
                 a: String::from("I will confess my iniquity *"),
-                b: String::from("and be sorry for my sin.")
+                b: String::from("and be sorry for my sin."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 19,
                 a: String::from("Those who are my enemies without cause are mighty, *"),
-                b: String::from("and many in number are those who wrongfully hate me.")
+                b: String::from("and many in number are those who wrongfully hate me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 20,
                 a: String::from("Those who repay evil for good slander me, *"),
-                b: String::from("because I follow the course that is right.")
+                b: String::from("because I follow the course that is right."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 21,
                 a: String::from("O LORD, do not forsake me; *"),
-                b: String::from("be not far from me, O my God.")
+                b: String::from("be not far from me, O my God."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 22,
                 a: String::from("Make haste to help me, *"),
-                b: String::from("O Lord of my salvation.")
+                b: String::from("O Lord of my salvation."),
+                ..Default::default()
             },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
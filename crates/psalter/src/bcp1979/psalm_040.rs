@@ -15,100 +15,120 @@ lazy_static! {
           PsalmVerse {
                 number: 1,
                 a: String::from("I waited patiently upon the LORD; *"),
-                b: String::from("he stooped to me and heard my cry.")
+                b: String::from("he stooped to me and heard my cry."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 2,
                 a: String::from("He lifted me out of the desolate pit, out of the mire and clay; *"),
-                b: String::from("he set my feet upon a high cliff and made my footing sure.")
+                b: String::from("he set my feet upon a high cliff and made my footing sure."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 3,
                 a: String::from("He put a new song in my mouth,\na song of praise to our God; *"),
-                b: String::from("many shall see, and stand in awe,\nand put their trust in the LORD.")
+                b: String::from("many shall see, and stand in awe,\nand put their trust in the LORD."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 4,
                 a: String::from("Happy are they who trust in the LORD! *"),
-                b: String::from("they do not resort to evil spirits or turn to false gods.")
+                b: String::from("they do not resort to evil spirits or turn to false gods."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 5,
                 a: String::from("Great things are they that you have done, O LORD my God!\nhow great your wonders and your plans for us! *"),
-                b: String::from("there is none who can be compared with you.")
+                b: String::from("there is none who can be compared with you."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 6,
                 a: String::from("Oh, that I could make them known and tell them! *"),
-                b: String::from("but they are more than I can count.")
+                b: String::from("but they are more than I can count."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 7,
                 a: String::from("In sacrifice and offering you take no pleasure *"),
-                b: String::from("(you have given me ears to hear you);")
+                b: String::from("(you have given me ears to hear you);"),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 8,
                 a: String::from("Burnt-offering and sin-offering you have not required, *"),
-                b: String::from("and so I said, “Behold, I come.")
+                b: String::from("and so I said, “Behold, I come."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 9,
                 a: String::from("In the roll of the book it is written concerning me: *"),
-                b: String::from("‘I love to do your will, O my God;\nyour law is deep in my heart.’”")
+                b: String::from("‘I love to do your will, O my God;\nyour law is deep in my heart.’”"),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 10,
                 a: String::from("I proclaimed righteousness in the great congregation; *"),
-                b: String::from("behold, I did not restrain my lips;\nand that, O LORD, you know.")
+                b: String::from("behold, I did not restrain my lips;\nand that, O LORD, you know."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 11,
                 a: String::from("Your righteousness have I not hidden in my heart;\nI have spoken of your faithfulness and your deliverance; *"),
-                b: String::from("I have not concealed your love and faithfulness from the great congregation.")
+                b: String::from("I have not concealed your love and faithfulness from the great congregation."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 12,
                 a: String::from("You are the LORD;\ndo not withhold your compassion from me; *"),
-                b: String::from("let your love and your faithfulness keep me safe for ever,")
+                b: String::from("let your love and your faithfulness keep me safe for ever,"),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 13,
                 a: String::from("For innumerable troubles have crowded upon me;\nmy sins have overtaken me, and I cannot see; *"),
-                b: String::from("they are more in number than the hairs of my head,\nand my heart fails me.")
+                b: String::from("they are more in number than the hairs of my head,\nand my heart fails me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 14,
                 a: String::from("Be pleased, O LORD, to deliver me; *"),
-                b: String::from("O LORD, make haste to help me.")
+                b: String::from("O LORD, make haste to help me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 15,
                 a: String::from("Let them be ashamed and altogether dismayed\nwho seek after my life to destroy it; *"),
-                b: String::from("let them draw back and be disgraced who take pleasure in my misfortune.")
+                b: String::from("let them draw back and be disgraced who take pleasure in my misfortune."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 16,
                 a: String::from("Let those who say “Aha!” and gloat over me be confounded, *"),
-                b: String::from("because they are ashamed.")
+                b: String::from("because they are ashamed."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 17,
                 a: String::from("Let all who seek you rejoice in you and be glad; *"),
-                b: String::from("let those who love your salvation continually say,\n“Great is the LORD!”")
+                b: String::from("let those who love your salvation continually say,\n“Great is the LORD!”"),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 18,
                 a: String::from("Though I am poor and afflicted, *"),
-                b: String::from("the Lord will have regard for me.")
+                b: String::from("the Lord will have regard for me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 19,
                 a: String::from("You are my helper and my deliverer; *"),
-                b: String::from("do not tarry, O my God.")
+                b: String::from("do not tarry, O my God."),
+                ..Default::default()
             },
         ]
     }
-        ]
+        ],
+        ..Default::default()
     };
 }
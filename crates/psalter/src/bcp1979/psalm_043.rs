@@ -15,35 +15,42 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Give judgment for me, O God,\nand defend my cause against an ungodly people; *"),
-                      b: String::from("deliver me from the deceitful and the wicked.")
+                      b: String::from("deliver me from the deceitful and the wicked."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("For you are the God of my strength;\nwhy have you put me from you? *"),
-                      b: String::from("and why do I go so heavily while the enemy oppresses me?")
+                      b: String::from("and why do I go so heavily while the enemy oppresses me?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Send out your light and your truth, that they may lead me, *"),
-                      b: String::from("and bring me to your holy hill\nand to your dwelling;")
+                      b: String::from("and bring me to your holy hill\nand to your dwelling;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("That I may go to the altar of God,\nto the God of my joy and gladness; *"),
-                      b: String::from("and on the harp I will give thanks to you, O God my God.")
+                      b: String::from("and on the harp I will give thanks to you, O God my God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Why are you so full of heaviness, O my soul? *"),
-                      b: String::from("and why are you so disquieted within me?")
+                      b: String::from("and why are you so disquieted within me?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Put your trust in God; *"),
-                      b: String::from("for I will yet give thanks to him,\nwho is the help of my countenance, and my God.")
+                      b: String::from("for I will yet give thanks to him,\nwho is the help of my countenance, and my God."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,135 +15,162 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("We have heard with our ears, O God,\nour forefathers have told us, *"),
-                      b: String::from("the deeds you did in their days,\nin the days of old.")
+                      b: String::from("the deeds you did in their days,\nin the days of old."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("How with your hand you drove the peoples out\nand planted our forefathers in the land; *"),
-                      b: String::from("how you destroyed nations and made your people flourish.")
+                      b: String::from("how you destroyed nations and made your people flourish."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("For they did not take the land by their sword,\nnor did their arm win the victory for them; *"),
-                      b: String::from("but your right hand, your arm, and the light of your countenance,\nbecause you favored them.")
+                      b: String::from("but your right hand, your arm, and the light of your countenance,\nbecause you favored them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("You are my King and my God; *"),
-                      b: String::from("you command victories for Jacob.")
+                      b: String::from("you command victories for Jacob."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Through you we pushed back our adversaries; *"),
-                      b: String::from("through your Name we trampled on those who rose up against us.")
+                      b: String::from("through your Name we trampled on those who rose up against us."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("For I do not rely on my bow, *"),
-                      b: String::from("and my sword does not give me the victory.")
+                      b: String::from("and my sword does not give me the victory."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Surely, you gave us victory over our adversaries *"),
-                      b: String::from("and put those who hate us to shame.")
+                      b: String::from("and put those who hate us to shame."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Every day we gloried in God, *"),
-                      b: String::from("and we will praise your Name for ever.")
+                      b: String::from("and we will praise your Name for ever."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Nevertheless, you have rejected and humbled us *"),
-                      b: String::from("and do not go forth with our armies.")
+                      b: String::from("and do not go forth with our armies."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("You have made us fall back before our adversary, *"),
-                      b: String::from("and our enemies have plundered us.")
+                      b: String::from("and our enemies have plundered us."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("You have made us like sheep to be eaten *"),
-                      b: String::from("and have scattered us among the nations.")
+                      b: String::from("and have scattered us among the nations."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("You are selling your people for a trifle *"),
-                      b: String::from("and are making no profit on the sale of them.")
+                      b: String::from("and are making no profit on the sale of them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("You have made us the scorn of our neighbors, *"),
-                      b: String::from("a mockery and derision to those around us.")
+                      b: String::from("a mockery and derision to those around us."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("You have made us a byword among the nations, *"),
-                      b: String::from("a laughing-stock among the peoples.")
+                      b: String::from("a laughing-stock among the peoples."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("My humiliation is daily before me, *"),
-                      b: String::from("and shame has covered my face;")
+                      b: String::from("and shame has covered my face;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("Because of the taunts of the mockers and blasphemers, *"),
-                      b: String::from("because of the enemy and avenger.")
+                      b: String::from("because of the enemy and avenger."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("All this has come upon us; *"),
-                      b: String::from("yet we have not forgotten you,\nnor have we betrayed your covenant.")
+                      b: String::from("yet we have not forgotten you,\nnor have we betrayed your covenant."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("Our heart never turned back, *"),
-                      b: String::from("nor did our footsteps stray from your path;")
+                      b: String::from("nor did our footsteps stray from your path;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("Though you thrust us down into a place of misery, *"),
-                      b: String::from("and covered us over with deep darkness.")
+                      b: String::from("and covered us over with deep darkness."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("If we have forgotten the Name of our God, *"),
-                      b: String::from("or stretched out our hands to some strange god,")
+                      b: String::from("or stretched out our hands to some strange god,"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 21,
                       a: String::from("Will not God find it out? *"),
-                      b: String::from("for he knows the secrets of the heart.")
+                      b: String::from("for he knows the secrets of the heart."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 22,
                       a: String::from("Indeed, for your sake we are killed all the day long; *"),
-                      b: String::from("we are accounted as sheep for the slaughter.")
+                      b: String::from("we are accounted as sheep for the slaughter."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 23,
                       a: String::from("Awake, O Lord! why are you sleeping? *"),
-                      b: String::from("Arise! do not reject us for ever.")
+                      b: String::from("Arise! do not reject us for ever."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 24,
                       a: String::from("Why have you hidden your face *"),
-                      b: String::from("and forgotten our affliction and oppression?")
+                      b: String::from("and forgotten our affliction and oppression?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 25,
                       a: String::from("We sink down into the dust; *"),
-                      b: String::from("our body cleaves to the ground.")
+                      b: String::from("our body cleaves to the ground."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 26,
                       a: String::from("Rise up, and help us, *"),
-                      b: String::from("and save us, for the sake of your steadfast love.")
+                      b: String::from("and save us, for the sake of your steadfast love."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
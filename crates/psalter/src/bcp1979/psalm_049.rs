@@ -15,105 +15,126 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Hear this, all you peoples;\nhearken, all you who dwell in the world, *"),
-                      b: String::from("you of high degree and low, rich and poor together.")
+                      b: String::from("you of high degree and low, rich and poor together."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("My mouth shall speak of wisdom, *"),
-                      b: String::from("and my heart shall meditate on understanding.")
+                      b: String::from("and my heart shall meditate on understanding."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("I will incline my ear to a proverb *"),
-                      b: String::from("and set forth my riddle upon the harp.")
+                      b: String::from("and set forth my riddle upon the harp."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Why should I be afraid in evil days, *"),
-                      b: String::from("when the wickedness of those at my heels surrounds me,")
+                      b: String::from("when the wickedness of those at my heels surrounds me,"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("The wickedness of those who put their trust in their goods, *"),
-                      b: String::from("and boast of their great riches?")
+                      b: String::from("and boast of their great riches?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("We can never ransom ourselves, *"),
-                      b: String::from("or deliver to God the price of our life;")
+                      b: String::from("or deliver to God the price of our life;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("For the ransom of our life is so great, *"),
-                      b: String::from("that we should never have enough to pay it,")
+                      b: String::from("that we should never have enough to pay it,"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("In order to live for ever and ever, *"),
-                      b: String::from("and never see the grave.")
+                      b: String::from("and never see the grave."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("For we see that the wise die also;\nlike the dull and stupid they perish *"),
-                      b: String::from("and leave their wealth to those who come after them.")
+                      b: String::from("and leave their wealth to those who come after them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Their graves shall be their homes for ever,\ntheir dwelling places from generation to generation, *"),
-                      b: String::from("though they call the lands after their own names.")
+                      b: String::from("though they call the lands after their own names."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Even though honored, they cannot live for ever; *"),
-                      b: String::from("they are like the beasts that perish.")
+                      b: String::from("they are like the beasts that perish."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("Such is the way of those who foolishly trust in themselves, *"),
-                      b: String::from("and the end of those who delight in their own words.")
+                      b: String::from("and the end of those who delight in their own words."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("Like a flock of sheep they are destined to die;\nDeath is their shepherd; *"),
-                      b: String::from("they go down straightway to the grave.")
+                      b: String::from("they go down straightway to the grave."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("Their form shall waste away, *"),
-                      b: String::from("and the land of the dead shall be their home.")
+                      b: String::from("and the land of the dead shall be their home."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("But God will ransom my life; *"),
-                      b: String::from("he will snatch me from the grasp of death.")
+                      b: String::from("he will snatch me from the grasp of death."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("Do not be envious when some become rich, *"),
-                      b: String::from("or when the grandeur of their house increases;")
+                      b: String::from("or when the grandeur of their house increases;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("For they will carry nothing away at their death, *"),
-                      b: String::from("nor will their grandeur follow them.")
+                      b: String::from("nor will their grandeur follow them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("Though they thought highly of themselves while they lived, *"),
-                      b: String::from("and were praised for their success,")
+                      b: String::from("and were praised for their success,"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("They shall join the company of their forebears, *"),
-                      b: String::from("who will never see the light again.")
+                      b: String::from("who will never see the light again."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("Those who are honored, but have no understanding, *"),
-                      b: String::from("are like the beasts that perish.")
+                      b: String::from("are like the beasts that perish."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -16,127 +16,152 @@ lazy_static! {
                             PsalmVerse {
                       number: 1,
                       a: String::from("The LORD, the God of gods, has spoken; *"),
-                      b: String::from("he has called the earth from the rising of the sun to its setting.")
+                      b: String::from("he has called the earth from the rising of the sun to its setting."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Out of Zion, perfect in its beauty, *"),
-                      b: String::from("God reveals himself in glory.")
+                      b: String::from("God reveals himself in glory."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Our God will come and will not keep silence; *"),
-                      b: String::from("before him there is a consuming flame,\nand round about him a raging storm.")
+                      b: String::from("before him there is a consuming flame,\nand round about him a raging storm."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("He calls the heavens and the earth from above *"),
-                      b: String::from("to witness the judgment of his people.")
+                      b: String::from("to witness the judgment of his people."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("“Gather before me my loyal followers, *"),
-                      b: String::from("those who have made a covenant with me\nand sealed it with sacrifice.”")
+                      b: String::from("those who have made a covenant with me\nand sealed it with sacrifice.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Let the heavens declare the rightness of his cause; *"),
-                      b: String::from("for God himself is judge.")
+                      b: String::from("for God himself is judge."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Hear, O my people, and I will speak:\n“O Israel, I will bear witness against you; *"),
-                      b: String::from("for I am God, your God.")
+                      b: String::from("for I am God, your God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("I do not accuse you because of your sacrifices; *"),
-                      b: String::from("your offerings are always before me.")
+                      b: String::from("your offerings are always before me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("I will take no bull-calf from your stalls, *"),
-                      b: String::from("nor he-goats out of your pens;")
+                      b: String::from("nor he-goats out of your pens;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("For all the beasts of the forest are mine, *"),
-                      b: String::from("the herds in their thousands upon the hills.")
+                      b: String::from("the herds in their thousands upon the hills."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("I know every bird in the sky, *"),
-                      b: String::from("and the creatures of the fields are in my sight.")
+                      b: String::from("and the creatures of the fields are in my sight."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("If I were hungry, I would not tell you, *"),
-                      b: String::from("for the whole world is mine and all that is in it.")
+                      b: String::from("for the whole world is mine and all that is in it."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("Do you think I eat the flesh of bulls, *"),
-                      b: String::from("or drink the blood of goats?")
+                      b: String::from("or drink the blood of goats?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("Offer to God a sacrifice of thanksgiving *"),
-                      b: String::from("and make good your vows to the Most High.")
+                      b: String::from("and make good your vows to the Most High."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("Call upon me in the day of trouble; *"),
-                      b: String::from("I will deliver you, and you shall honor me.”")
+                      b: String::from("I will deliver you, and you shall honor me.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("But to the wicked God says: *"),
-                      b: String::from("“Why do you recite my statutes,\nand take my covenant upon your lips;")
+                      b: String::from("“Why do you recite my statutes,\nand take my covenant upon your lips;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("Since you refuse discipline, *"),
-                      b: String::from("and toss my words behind your back?")
+                      b: String::from("and toss my words behind your back?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("When you see a thief, you make him your friend, *"),
-                      b: String::from("and you cast in your lot with adulterers.")
+                      b: String::from("and you cast in your lot with adulterers."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("You have loosed your lips for evil, *"),
-                      b: String::from("and harnessed your tongue to a lie.")
+                      b: String::from("and harnessed your tongue to a lie."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("You are always speaking evil of your brother *"),
-                      b: String::from("and slandering your own mother’s son.")
+                      b: String::from("and slandering your own mother’s son."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 21,
                       a: String::from("These things you have done, and I kept still, *"),
-                      b: String::from("and you thought that I am like you.”")
+                      b: String::from("and you thought that I am like you.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 22,
                       a: String::from("“I have made my accusation; *"),
-                      b: String::from("I have put my case in order before your eyes.")
+                      b: String::from("I have put my case in order before your eyes."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 23,
                       a: String::from("Consider this well, you who forget God, *"),
-                      b: String::from("lest I rend you and there be none to deliver you.")
+                      b: String::from("lest I rend you and there be none to deliver you."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 24,
                       a: String::from("Whoever offers me the sacrifice of thanksgiving honors me; *"),
-                      b: String::from("but to those who keep in my way will I show the salvation of God.”")
+                      b: String::from("but to those who keep in my way will I show the salvation of God.”"),
+                      ..Default::default()
                   },
               ]
             }
 
-        ]
+        ],
+        ..Default::default()
 
     };
 }
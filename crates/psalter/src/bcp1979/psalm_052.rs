@@ -15,50 +15,60 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("You tyrant, why do you boast of wickedness *"),
-                      b: String::from("against the godly all day long?")
+                      b: String::from("against the godly all day long?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("You plot ruin;\nyour tongue is like a sharpened razor, *"),
-                      b: String::from("O worker of deception.")
+                      b: String::from("O worker of deception."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("You love evil more than good *"),
-                      b: String::from("and lying more than speaking the truth.")
+                      b: String::from("and lying more than speaking the truth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("You love all words that hurt, *"),
-                      b: String::from("O you deceitful tongue.")
+                      b: String::from("O you deceitful tongue."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Oh, that God would demolish you utterly, *"),
-                      b: String::from("topple you, and snatch you from your dwelling,\nand root you out of the land of the living!")
+                      b: String::from("topple you, and snatch you from your dwelling,\nand root you out of the land of the living!"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("The righteous shall see and tremble, *"),
-                      b: String::from("and they shall laugh at him, saying,")
+                      b: String::from("and they shall laugh at him, saying,"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("“This is the one who did not take God for a refuge, *"),
-                      b: String::from("but trusted in great wealth\nand relied upon wickedness.”")
+                      b: String::from("but trusted in great wealth\nand relied upon wickedness.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("But I am like a green olive tree in the house of God; *"),
-                      b: String::from("I trust in the mercy of God for ever and ever.")
+                      b: String::from("I trust in the mercy of God for ever and ever."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("I will give you thanks for what you have done *"),
-                      b: String::from("and declare the goodness of your Name in the presence of the godly.")
+                      b: String::from("and declare the goodness of your Name in the presence of the godly."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,35 +15,42 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("The fool has said in his heart, “There is no God.” *"),
-                      b: String::from("All are corrupt and commit abominable acts;\nthere is none who does any good.")
+                      b: String::from("All are corrupt and commit abominable acts;\nthere is none who does any good."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("God looks down from heaven upon us all, *"),
-                      b: String::from("to see if there is any who is wise,\nif there is one who seeks after God.")
+                      b: String::from("to see if there is any who is wise,\nif there is one who seeks after God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Every one has proved faithless;\nall alike have turned bad; *"),
-                      b: String::from("there is none who does good; no, not one.")
+                      b: String::from("there is none who does good; no, not one."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Have they no knowledge, those evildoers *"),
-                      b: String::from("who eat up my people like bread\nand do not call upon God?")
+                      b: String::from("who eat up my people like bread\nand do not call upon God?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("See how greatly they tremble,\nsuch trembling as never was; *"),
-                      b: String::from("for God has scattered the bones of the enemy;\nthey are put to shame, because God has rejected them.")
+                      b: String::from("for God has scattered the bones of the enemy;\nthey are put to shame, because God has rejected them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Oh, that Israel’s deliverance would come out of Zion! *"),
-                      b: String::from("when God restores the fortunes of his people\nJacob will rejoice and Israel be glad.")
+                      b: String::from("when God restores the fortunes of his people\nJacob will rejoice and Israel be glad."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
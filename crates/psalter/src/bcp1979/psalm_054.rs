@@ -15,40 +15,48 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Save me, O God, by your Name; *"),
-                      b: String::from("in your might, defend my cause.")
+                      b: String::from("in your might, defend my cause."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Hear my prayer, O God; *"),
-                      b: String::from("give ear to the words of my mouth.")
+                      b: String::from("give ear to the words of my mouth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("For the arrogant have risen up against me,\nand the ruthless have sought my life, *"),
-                      b: String::from("those who have no regard for God.")
+                      b: String::from("those who have no regard for God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Behold, God is my helper; *"),
-                      b: String::from("it is the Lord who sustains my life.")
+                      b: String::from("it is the Lord who sustains my life."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Render evil to those who spy on me; *"),
-                      b: String::from("in your faithfulness, destroy them.")
+                      b: String::from("in your faithfulness, destroy them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("I will offer you a freewill sacrifice *"),
-                      b: String::from("and praise your Name, O LORD, for it is good.")
+                      b: String::from("and praise your Name, O LORD, for it is good."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("For you have rescued me from every trouble, *"),
-                      b: String::from("and my eye has seen the ruin of my foes.")
+                      b: String::from("and my eye has seen the ruin of my foes."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
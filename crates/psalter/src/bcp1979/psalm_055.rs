@@ -15,135 +15,162 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Hear my prayer, O God; *"),
-                      b: String::from("do not hide yourself from my petition.")
+                      b: String::from("do not hide yourself from my petition."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Listen to me and answer me; *"),
-                      b: String::from("I have no peace, because of my cares.")
+                      b: String::from("I have no peace, because of my cares."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("I am shaken by the noise of the enemy *"),
-                      b: String::from("and by the pressure of the wicked;")
+                      b: String::from("and by the pressure of the wicked;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("For they have cast an evil spell upon me *"),
-                      b: String::from("and are set against me in fury.")
+                      b: String::from("and are set against me in fury."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("My heart quakes within me, *"),
-                      b: String::from("and the terrors of death have fallen upon me.")
+                      b: String::from("and the terrors of death have fallen upon me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Fear and trembling have come over me, *"),
-                      b: String::from("and horror overwhelms me.")
+                      b: String::from("and horror overwhelms me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("And I said, “Oh, that I had wings like a dove! *"),
-                      b: String::from("I would fly away and be at rest.")
+                      b: String::from("I would fly away and be at rest."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("I would flee to a far-off place *"),
-                      b: String::from("and make my lodging in the wilderness.")
+                      b: String::from("and make my lodging in the wilderness."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("I would hasten to escape *"),
-                      b: String::from("from the stormy wind and tempest.”")
+                      b: String::from("from the stormy wind and tempest.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Swallow them up, O Lord;\nconfound their speech; *"),
-                      b: String::from("for I have seen violence and strife in the city.")
+                      b: String::from("for I have seen violence and strife in the city."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Day and night the watchmen make their rounds upon her walls, *"),
-                      b: String::from("but trouble and misery are in the midst of her.")
+                      b: String::from("but trouble and misery are in the midst of her."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from(" There is corruption at her heart; *"),
-                      b: String::from("her streets are never free of oppression and deceit.")
+                      b: String::from("her streets are never free of oppression and deceit."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("For had it been an adversary who taunted me,\nthen I could have borne it; *"),
-                      b: String::from("or had it been an enemy who vaunted himself against me,\nthen I could have hidden from him.")
+                      b: String::from("or had it been an enemy who vaunted himself against me,\nthen I could have hidden from him."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("But it was you, a man after my own heart, *"),
-                      b: String::from("my companion, my own familiar friend.")
+                      b: String::from("my companion, my own familiar friend."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("We took sweet counsel together, *"),
-                      b: String::from("and walked with the throng in the house of God.")
+                      b: String::from("and walked with the throng in the house of God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("Let death come upon them suddenly;\nlet them go down alive to the grave; *"),
-                      b: String::from("for wickedness is in their dwellings, in their very midst.")
+                      b: String::from("for wickedness is in their dwellings, in their very midst."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("But I will call upon God, *"),
-                      b: String::from("and the LORD will deliver me.")
+                      b: String::from("and the LORD will deliver me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("In the evening, in the morning, and at noonday,\nI will complain and lament, *"),
-                      b: String::from("and he will hear my voice.")
+                      b: String::from("and he will hear my voice."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("He will bring me safely back from the battle waged against me; *"),
-                      b: String::from("for there are many who fight me.")
+                      b: String::from("for there are many who fight me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("God, who is enthroned of old, will hear me and bring them down; *"),
-                      b: String::from("they never change; they do not fear God.")
+                      b: String::from("they never change; they do not fear God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 21,
                       a: String::from("My companion stretched forth his hand against his comrade; *"),
-                      b: String::from("he has broken his covenant.")
+                      b: String::from("he has broken his covenant."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 22,
                       a: String::from("His speech is softer than butter, *"),
-                      b: String::from("but war is in his heart.")
+                      b: String::from("but war is in his heart."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 23,
                       a: String::from("His words are smoother than oil, *"),
-                      b: String::from("but they are drawn swords.")
+                      b: String::from("but they are drawn swords."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 24,
                       a: String::from("Cast your burden upon the LORD,\nand he will sustain you; *"),
-                      b: String::from("he will never let the righteous stumble.")
+                      b: String::from("he will never let the righteous stumble."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 25,
                       a: String::from("For you will bring the bloodthirsty and deceitful *"),
-                      b: String::from("down to the pit of destruction, O God.")
+                      b: String::from("down to the pit of destruction, O God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 26,
                       a: String::from("They shall not live out half their days, *"),
-                      b: String::from("but I will put my trust in you.")
+                      b: String::from("but I will put my trust in you."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
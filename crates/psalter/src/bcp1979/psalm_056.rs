@@ -15,65 +15,78 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Have mercy on me, O God,\nfor my enemies are hounding me; *"),
-                      b: String::from("all day long they assault and oppress me.")
+                      b: String::from("all day long they assault and oppress me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("They hound me all the day long; *"),
-                      b: String::from("truly there are many who fight against me, O Most High.")
+                      b: String::from("truly there are many who fight against me, O Most High."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Whenever I am afraid, *"),
-                      b: String::from("I will put my trust in you.")
+                      b: String::from("I will put my trust in you."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("In God, whose word I praise,\nin God I trust and will not be afraid, *"),
-                      b: String::from("for what can flesh do to me?")
+                      b: String::from("for what can flesh do to me?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("All day long they damage my cause; *"),
-                      b: String::from("their only thought is to do me evil.")
+                      b: String::from("their only thought is to do me evil."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("They band together; they lie in wait; *"),
-                      b: String::from("they spy upon my footsteps;\nbecause they seek my life.")
+                      b: String::from("they spy upon my footsteps;\nbecause they seek my life."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Shall they escape despite their wickedness? *"),
-                      b: String::from("O God, in your anger, cast down the peoples.")
+                      b: String::from("O God, in your anger, cast down the peoples."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("You have noted my lamentation;\nput my tears into your bottle; *"),
-                      b: String::from("are they not recorded in your book?")
+                      b: String::from("are they not recorded in your book?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Whenever I call upon you, my enemies will be put to flight; *"),
-                      b: String::from("this I know, for God is on my side.")
+                      b: String::from("this I know, for God is on my side."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("In God the LORD, whose word I praise,\nin God I trust and will not be afraid, *"),
-                      b: String::from("for what can mortals do to me?")
+                      b: String::from("for what can mortals do to me?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("I am bound by the vow I made to you, O God; *"),
-                      b: String::from("I will present to you thank-offerings;")
+                      b: String::from("I will present to you thank-offerings;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("For you have rescued my soul from death and my feet from stumbling, *"),
-                      b: String::from("that I may walk before God in the light of the living.")
+                      b: String::from("that I may walk before God in the light of the living."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -14,61 +14,73 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Do you indeed decree righteousness, you rulers? *"),
-                    b: String::from("do you judge the peoples with equity?")
+                    b: String::from("do you judge the peoples with equity?"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("No; you devise evil in your hearts, *"),
-                    b: String::from("and your hands deal out violence in the land.")
+                    b: String::from("and your hands deal out violence in the land."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("The wicked are perverse from the womb; *"),
-                    b: String::from("liars go astray from their birth.")
+                    b: String::from("liars go astray from their birth."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("They are as venomous as a serpent, *"),
-                    b: String::from("they are like the deaf adder which stops its ears,")
+                    b: String::from("they are like the deaf adder which stops its ears,"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("Which does not heed the voice of the charmer, *"),
-                    b: String::from("no matter how skillful his charming.")
+                    b: String::from("no matter how skillful his charming."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("O God, break their teeth in their mouths; *"),
-                    b: String::from("pull the fangs of the young lions, O LORD.")
+                    b: String::from("pull the fangs of the young lions, O LORD."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("Let them vanish like water that runs off; *"),
-                    b: String::from("let them wither like trodden grass.")
+                    b: String::from("let them wither like trodden grass."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("Let them be like the snail that melts away, *"),
-                    b: String::from("like a stillborn child that never sees the sun.")
+                    b: String::from("like a stillborn child that never sees the sun."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("Before they bear fruit, let them be cut down like a brier; *"),
-                    b: String::from("like thorns and thistles let them be swept away.")
+                    b: String::from("like thorns and thistles let them be swept away."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 10,
                     a: String::from("The righteous will be glad when they see the vengeance; *"),
-                    b: String::from("they will bathe their feet in the blood of the wicked.")
+                    b: String::from("they will bathe their feet in the blood of the wicked."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 11,
                     a: String::from(
                         "And they will say,\n“Surely, there is a reward for the righteous; *"
                     ),
-                    b: String::from("surely, there is a God who rules in the earth.”")
+                    b: String::from("surely, there is a God who rules in the earth.”"),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -15,105 +15,126 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Rescue me from my enemies, O God; *"),
-                      b: String::from("protect me from those who rise up against me.")
+                      b: String::from("protect me from those who rise up against me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Rescue me from evildoers *"),
-                      b: String::from("and save me from those who thirst for my blood.")
+                      b: String::from("and save me from those who thirst for my blood."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("See how they lie in wait for my life,\nhow the mighty gather together against me; *"),
-                      b: String::from("not for any offense or fault of mine, O LORD.")
+                      b: String::from("not for any offense or fault of mine, O LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Not because of any guilt of mine *"),
-                      b: String::from("they run and prepare themselves for battle.")
+                      b: String::from("they run and prepare themselves for battle."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Rouse yourself, come to my side, and see; *"),
-                      b: String::from("for you, LORD God of hosts, are Israel’s God.")
+                      b: String::from("for you, LORD God of hosts, are Israel’s God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Awake, and punish all the ungodly; *"),
-                      b: String::from("show no mercy to those who are faithless and evil.")
+                      b: String::from("show no mercy to those who are faithless and evil."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("They go to and fro in the evening; *"),
-                      b: String::from("they snarl like dogs and run about the city.")
+                      b: String::from("they snarl like dogs and run about the city."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Behold, they boast with their mouths,\nand taunts are on their lips; *"),
-                      b: String::from("“For who,” they say, “will hear us?”")
+                      b: String::from("“For who,” they say, “will hear us?”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("But you, O LORD, you laugh at them; *"),
-                      b: String::from("you laugh all the ungodly to scorn.")
+                      b: String::from("you laugh all the ungodly to scorn."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("My eyes are fixed on you, O my Strength; *"),
-                      b: String::from("for you, O God, are my stronghold.")
+                      b: String::from("for you, O God, are my stronghold."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("My merciful God comes to meet me; *"),
-                      b: String::from("God will let me look in triumph on my enemies.")
+                      b: String::from("God will let me look in triumph on my enemies."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("Slay them, O God, lest my people forget; *"),
-                      b: String::from("send them reeling by your might\nand put them down, O Lord our shield.")
+                      b: String::from("send them reeling by your might\nand put them down, O Lord our shield."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("For the sins of their mouths, for the words of their lips,\nfor the cursing and lies that they utter, *"),
-                      b: String::from("let them be caught in their pride.")
+                      b: String::from("let them be caught in their pride."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("Make an end of them in your wrath; *"),
-                      b: String::from("make an end of them, and they shall be no more.")
+                      b: String::from("make an end of them, and they shall be no more."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("Let everyone know that God rules in Jacob, *"),
-                      b: String::from("and to the ends of the earth.")
+                      b: String::from("and to the ends of the earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("They go to and fro in the evening; *"),
-                      b: String::from("they snarl like dogs and run about the city.")
+                      b: String::from("they snarl like dogs and run about the city."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("They forage for food, *"),
-                      b: String::from("and if they are not filled, they howl.")
+                      b: String::from("and if they are not filled, they howl."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("For my part, I will sing of your strength; *"),
-                      b: String::from("I will celebrate your love in the morning;")
+                      b: String::from("I will celebrate your love in the morning;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("For you have become my stronghold, *"),
-                      b: String::from("a refuge in the day of my trouble.")
+                      b: String::from("a refuge in the day of my trouble."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("To you, O my Strength, will I sing; *"),
-                      b: String::from("for you, O God, are my stronghold and my merciful God.")
+                      b: String::from("for you, O God, are my stronghold and my merciful God."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
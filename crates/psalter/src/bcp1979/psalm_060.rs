@@ -15,65 +15,78 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("O God, you have cast us off and broken us; *"),
-                      b: String::from("you have been angry;\noh, take us back to you again.")
+                      b: String::from("you have been angry;\noh, take us back to you again."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("You have shaken the earth and split it open; *"),
-                      b: String::from("repair the cracks in it, for it totters.")
+                      b: String::from("repair the cracks in it, for it totters."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("You have made your people know hardship; *"),
-                      b: String::from("you have given us wine that makes us stagger.")
+                      b: String::from("you have given us wine that makes us stagger."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("You have set up a banner for those who fear you, *"),
-                      b: String::from("to be a refuge from the power of the bow.")
+                      b: String::from("to be a refuge from the power of the bow."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Save us by your right hand and answer us, *"),
-                      b: String::from("that those who are dear to you may be delivered.")
+                      b: String::from("that those who are dear to you may be delivered."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("God spoke from his holy place and said: *"),
-                      b: String::from("“I will exult and parcel out Shechem;\n I will divide the valley of Succoth.")
+                      b: String::from("“I will exult and parcel out Shechem;\n I will divide the valley of Succoth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Gilead is mine and Manasseh is mine; *"),
-                      b: String::from("Ephraim is my helmet and Judah my scepter.")
+                      b: String::from("Ephraim is my helmet and Judah my scepter."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Moab is my wash-basin,\non Edom I throw down my sandal to claim it, *"),
-                      b: String::from("and over Philistia will I shout in triumph.”")
+                      b: String::from("and over Philistia will I shout in triumph.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Who will lead me into the strong city? *"),
-                      b: String::from("who will bring me to Edom?")
+                      b: String::from("who will bring me to Edom?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Have you not cast us off, O God? *"),
-                      b: String::from("you no longer go out, O God, with our armies.")
+                      b: String::from("you no longer go out, O God, with our armies."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Grant us your help against the enemy, *"),
-                      b: String::from("for vain is the help of man.")
+                      b: String::from("for vain is the help of man."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("With God we will do valiant deeds, *"),
-                      b: String::from("and he shall tread our enemies under foot.")
+                      b: String::from("and he shall tread our enemies under foot."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
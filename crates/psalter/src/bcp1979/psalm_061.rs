@@ -14,48 +14,57 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Hear my cry, O God, *"),
-                    b: String::from("and listen to my prayer.")
+                    b: String::from("and listen to my prayer."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from(
                         "I call upon you from the ends of the earth\nwith heaviness in my heart; *"
                     ),
-                    b: String::from("set me upon the rock that is higher than I.")
+                    b: String::from("set me upon the rock that is higher than I."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("For you have been my refuge, *"),
-                    b: String::from("a strong tower against the enemy.")
+                    b: String::from("a strong tower against the enemy."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("I will dwell in your house for ever; *"),
-                    b: String::from("I will take refuge under the cover of your wings.")
+                    b: String::from("I will take refuge under the cover of your wings."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("For you, O God, have heard my vows; *"),
                     b: String::from(
                         "you have granted me the heritage of those who fear your Name."
-                    )
+                    ),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("Add length of days to the king’s life; *"),
-                    b: String::from("let his years extend over many generations.")
+                    b: String::from("let his years extend over many generations."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("Let him sit enthroned before God for ever; *"),
-                    b: String::from("bid love and faithfulness watch over him.")
+                    b: String::from("bid love and faithfulness watch over him."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("So will I always sing the praise of your Name, *"),
-                    b: String::from("and day by day I will fulfill my vows.")
+                    b: String::from("and day by day I will fulfill my vows."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,78 +14,93 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("For God alone my soul in silence waits; *"),
-                    b: String::from("from him comes my salvation.")
+                    b: String::from("from him comes my salvation."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("He alone is my rock and my salvation, *"),
-                    b: String::from("my stronghold, so that I shall not be greatly shaken.")
+                    b: String::from("my stronghold, so that I shall not be greatly shaken."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from(
                         "How long will you assail me to crush me,\nall of you together, *"
                     ),
-                    b: String::from("as if you were a leaning fence, a toppling wall?")
+                    b: String::from("as if you were a leaning fence, a toppling wall?"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("They seek only to bring me down from my place of honor; *"),
-                    b: String::from("lies are their chief delight.")
+                    b: String::from("lies are their chief delight."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("They bless with their lips, *"),
-                    b: String::from("but in their hearts they curse.")
+                    b: String::from("but in their hearts they curse."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("For God alone my soul in silence waits; *"),
-                    b: String::from("truly, my hope is in him.")
+                    b: String::from("truly, my hope is in him."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("He alone is my rock and my salvation, *"),
-                    b: String::from("my stronghold, so that I shall not be shaken.")
+                    b: String::from("my stronghold, so that I shall not be shaken."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("In God is my safety and my honor; *"),
-                    b: String::from("God is my strong rock and my refuge.")
+                    b: String::from("God is my strong rock and my refuge."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("Put your trust in him always, O people, *"),
-                    b: String::from("pour out your hearts before him, for God is our refuge.")
+                    b: String::from("pour out your hearts before him, for God is our refuge."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 10,
                     a: String::from("Those of high degree are but a fleeting breath, *"),
-                    b: String::from("even those of low estate cannot be trusted.")
+                    b: String::from("even those of low estate cannot be trusted."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 11,
                     a: String::from("On the scales they are lighter than a breath, *"),
-                    b: String::from("all of them together.")
+                    b: String::from("all of them together."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 12,
                     a: String::from(
                         "Put no trust in extortion;\nin robbery take no empty pride; *"
                     ),
-                    b: String::from("though wealth increase, set not your heart upon it.")
+                    b: String::from("though wealth increase, set not your heart upon it."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 13,
                     a: String::from("God has spoken once, twice have I heard it, *"),
-                    b: String::from("that power belongs to God.")
+                    b: String::from("that power belongs to God."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 14,
                     a: String::from("Steadfast love is yours, O Lord, *"),
-                    b: String::from("for you repay everyone according to his deeds.")
+                    b: String::from("for you repay everyone according to his deeds."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -15,75 +15,90 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("You are to be praised, O God, in Zion; *"),
-                      b: String::from("to you shall vows be performed in Jerusalem.")
+                      b: String::from("to you shall vows be performed in Jerusalem."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("To you that hear prayer shall all flesh come, *"),
-                      b: String::from("because of their transgressions.")
+                      b: String::from("because of their transgressions."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Our sins are stronger than we are, *"),
-                      b: String::from("but you will blot them out.")
+                      b: String::from("but you will blot them out."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Happy are they whom you choose\nand draw to your courts to dwell there! *"),
-                      b: String::from("they will be satisfied by the beauty of your house,\nby the holiness of your temple.")
+                      b: String::from("they will be satisfied by the beauty of your house,\nby the holiness of your temple."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Awesome things will you show us in your righteousness,\nO God of our salvation, *"),
-                      b: String::from("O Hope of all the ends of the earth\nand of the seas that are far away.")
+                      b: String::from("O Hope of all the ends of the earth\nand of the seas that are far away."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("You make fast the mountains by your power; *"),
-                      b: String::from("they are girded about with might.")
+                      b: String::from("they are girded about with might."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("You still the roaring of the seas, *"),
-                      b: String::from("the roaring of their waves,\nand the clamor of the peoples.")
+                      b: String::from("the roaring of their waves,\nand the clamor of the peoples."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Those who dwell at the ends of the earth will tremble at your marvelous signs; *"),
-                      b: String::from("you make the dawn and the dusk to sing for joy.")
+                      b: String::from("you make the dawn and the dusk to sing for joy."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("You visit the earth and water it abundantly;\nyou make it very plenteous; *"),
-                      b: String::from("the river of God is full of water.")
+                      b: String::from("the river of God is full of water."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("You prepare the grain, *"),
-                      b: String::from("for so you provide for the earth.")
+                      b: String::from("for so you provide for the earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("You drench the furrows and smooth out the ridges; *"),
-                      b: String::from("with heavy rain you soften the ground and bless its increase.")
+                      b: String::from("with heavy rain you soften the ground and bless its increase."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("You crown the year with your goodness, *"),
-                      b: String::from("and your paths overflow with plenty.")
+                      b: String::from("and your paths overflow with plenty."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("May the fields of the wilderness be rich for grazing, *"),
-                      b: String::from("and the hills be clothed with joy.")
+                      b: String::from("and the hills be clothed with joy."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("May the meadows cover themselves with flocks,\nand the valleys cloak themselves with grain; *"),
-                      b: String::from("let them shout for joy and sing.")
+                      b: String::from("let them shout for joy and sing."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
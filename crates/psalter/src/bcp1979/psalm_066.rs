@@ -15,95 +15,114 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Be joyful in God, all you lands; *"),
-                      b: String::from("sing the glory of his Name;\nsing the glory of his praise.")
+                      b: String::from("sing the glory of his Name;\nsing the glory of his praise."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Say to God, “How awesome are your deeds! *"),
-                      b: String::from("because of your great strength your enemies cringe before you.")
+                      b: String::from("because of your great strength your enemies cringe before you."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("All the earth bows down before you, *"),
-                      b: String::from("sings to you, sings out your Name.”")
+                      b: String::from("sings to you, sings out your Name.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Come now and see the works of God, *"),
-                      b: String::from("how wonderful he is in his doing toward all people.")
+                      b: String::from("how wonderful he is in his doing toward all people."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("He turned the sea into dry land,\nso that they went through the water on foot, *"),
-                      b: String::from("and there we rejoiced in him.")
+                      b: String::from("and there we rejoiced in him."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("In his might he rules for ever;\nhis eyes keep watch over the nations; *"),
-                      b: String::from("let no rebel rise up against him.")
+                      b: String::from("let no rebel rise up against him."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Bless our God, you peoples; *"),
-                      b: String::from("make the voice of his praise to be heard;")
+                      b: String::from("make the voice of his praise to be heard;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Who holds our souls in life, *"),
-                      b: String::from("and will not allow our feet to slip.")
+                      b: String::from("and will not allow our feet to slip."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("For you, O God, have proved us; *"),
-                      b: String::from("you have tried us just as silver is tried.")
+                      b: String::from("you have tried us just as silver is tried."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("You brought us into the snare; *"),
-                      b: String::from("you laid heavy burdens upon our backs.")
+                      b: String::from("you laid heavy burdens upon our backs."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("You let enemies ride over our heads;\nwe went through fire and water; *"),
-                      b: String::from("but you brought us out into a place of refreshment.")
+                      b: String::from("but you brought us out into a place of refreshment."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("I will enter your house with burnt-offerings\nand will pay you my vows, *"),
-                      b: String::from("which I promised with my lips\nand spoke with my mouth when I was in trouble.")
+                      b: String::from("which I promised with my lips\nand spoke with my mouth when I was in trouble."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("I will offer you sacrifices of fat beasts\nwith the smoke of rams; *"),
-                      b: String::from("I will give you oxen and goats.")
+                      b: String::from("I will give you oxen and goats."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("Come and listen, all you who fear God, *"),
-                      b: String::from("and I will tell you what he has done for me.")
+                      b: String::from("and I will tell you what he has done for me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("I called out to him with my mouth, *"),
-                      b: String::from("and his praise was on my tongue.")
+                      b: String::from("and his praise was on my tongue."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("If I had found evil in my heart, *"),
-                      b: String::from("the Lord would not have heard me;")
+                      b: String::from("the Lord would not have heard me;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("But in truth God has heard me; *"),
-                      b: String::from("he has attended to the voice of my prayer.")
+                      b: String::from("he has attended to the voice of my prayer."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("Blessed be God, who has not rejected my prayer, *"),
-                      b: String::from("nor withheld his love from me.")
+                      b: String::from("nor withheld his love from me."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,40 +15,48 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("May God be merciful to us and bless us, *"),
-                      b: String::from("show us the light of his countenance and come to us.")
+                      b: String::from("show us the light of his countenance and come to us."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Let your ways be known upon earth, *"),
-                      b: String::from("your saving health among all nations.")
+                      b: String::from("your saving health among all nations."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Let the peoples praise you, O God; *"),
-                      b: String::from("let all the peoples praise you.")
+                      b: String::from("let all the peoples praise you."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Let the nations be glad and sing for joy, *"),
-                      b: String::from("for you judge the peoples with equity\nand guide all the nations upon earth.")
+                      b: String::from("for you judge the peoples with equity\nand guide all the nations upon earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Let the peoples praise you, O God; *"),
-                      b: String::from("let all the peoples praise you.")
+                      b: String::from("let all the peoples praise you."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("The earth has brought forth her increase; *"),
-                      b: String::from("may God, our own God, give us his blessing.")
+                      b: String::from("may God, our own God, give us his blessing."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("May God give us his blessing, *"),
-                      b: String::from("and may all the ends of the earth stand in awe of him.")
+                      b: String::from("and may all the ends of the earth stand in awe of him."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
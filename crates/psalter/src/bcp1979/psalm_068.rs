@@ -15,185 +15,222 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Let God arise, and let his enemies be scattered; *"),
-                      b: String::from("let those who hate him flee before him.")
+                      b: String::from("let those who hate him flee before him."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Let them vanish like smoke when the wind drives it away; *"),
-                      b: String::from("as the wax melts at the fire, so let the wicked perish at the presence of God.")
+                      b: String::from("as the wax melts at the fire, so let the wicked perish at the presence of God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("But let the righteous be glad and rejoice before God; *"),
-                      b: String::from("let them also be merry and joyful.")
+                      b: String::from("let them also be merry and joyful."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Sing to God, sing praises to his Name;\nexalt him who rides upon the heavens; *"),
-                      b: String::from("YAHWEH is his Name, rejoice before him!")
+                      b: String::from("YAHWEH is his Name, rejoice before him!"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Father of orphans, defender of widows, *"),
-                      b: String::from("God in his holy habitation!")
+                      b: String::from("God in his holy habitation!"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("God gives the solitary a home and brings forth prisoners into freedom; *"),
-                      b: String::from("but the rebels shall live in dry places.")
+                      b: String::from("but the rebels shall live in dry places."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("O God, when you went forth before your people, *"),
-                      b: String::from("when you marched through the wilderness,")
+                      b: String::from("when you marched through the wilderness,"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("The earth shook, and the skies poured down rain,\nat the presence of God, the God of Sinai, *"),
-                      b: String::from("at the presence of God, the God of Israel.")
+                      b: String::from("at the presence of God, the God of Israel."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("You sent a gracious rain, O God, upon your inheritance; *"),
-                      b: String::from("you refreshed the land when it was weary.")
+                      b: String::from("you refreshed the land when it was weary."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Your people found their home in it; *"),
-                      b: String::from("in your goodness, O God, you have made provision for the poor.")
+                      b: String::from("in your goodness, O God, you have made provision for the poor."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("The Lord gave the word; *"),
-                      b: String::from("great was the company of women who bore the tidings:")
+                      b: String::from("great was the company of women who bore the tidings:"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("“Kings with their armies are fleeing away; *"),
-                      b: String::from("the women at home are dividing the spoils.”")
+                      b: String::from("the women at home are dividing the spoils.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("Though you lingered among the sheepfolds, *"),
-                      b: String::from("you shall be like a dove whose wings are covered with silver,\nwhose feathers are like green gold.")
+                      b: String::from("you shall be like a dove whose wings are covered with silver,\nwhose feathers are like green gold."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("When the Almighty scattered kings, *"),
-                      b: String::from("it was like snow falling in Zalmon.")
+                      b: String::from("it was like snow falling in Zalmon."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("O mighty mountain, O hill of Bashan! *"),
-                      b: String::from("O rugged mountain, O hill of Bashan!")
+                      b: String::from("O rugged mountain, O hill of Bashan!"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("Why do you look with envy, O rugged mountain,\nat the hill which God chose for his resting place? *"),
-                      b: String::from("truly, the LORD will dwell there for ever.")
+                      b: String::from("truly, the LORD will dwell there for ever."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("The chariots of God are twenty thousand,\neven thousands of thousands; *"),
-                      b: String::from("the Lord comes in holiness from Sinai.")
+                      b: String::from("the Lord comes in holiness from Sinai."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("You have gone up on high and led captivity captive;\nyou have received gifts even from your enemies, *"),
-                      b: String::from("that the LORD God might dwell among them.")
+                      b: String::from("that the LORD God might dwell among them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("Blessed be the Lord day by day, *"),
-                      b: String::from("the God of our salvation, who bears our burdens.")
+                      b: String::from("the God of our salvation, who bears our burdens."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("He is our God, the God of our salvation; *"),
-                      b: String::from("God is the LORD, by whom we escape death.")
+                      b: String::from("God is the LORD, by whom we escape death."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 21,
                       a: String::from("God shall crush the heads of his enemies, *"),
-                      b: String::from("and the hairy scalp of those who go on still in their wickedness.")
+                      b: String::from("and the hairy scalp of those who go on still in their wickedness."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 22,
                       a: String::from("The Lord has said, “I will bring them back from Bashan; *"),
-                      b: String::from("I will bring them back from the depths of the sea;")
+                      b: String::from("I will bring them back from the depths of the sea;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 23,
                       a: String::from("That your foot may be dipped in blood, *"),
-                      b: String::from("the tongues of your dogs in the blood of your enemies.”")
+                      b: String::from("the tongues of your dogs in the blood of your enemies.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 24,
                       a: String::from("They see your procession, O God, *"),
-                      b: String::from("your procession into the sanctuary, my God and my King.")
+                      b: String::from("your procession into the sanctuary, my God and my King."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 25,
                       a: String::from("The singers go before, musicians follow after, *"),
-                      b: String::from("in the midst of maidens playing upon the hand-drums.")
+                      b: String::from("in the midst of maidens playing upon the hand-drums."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 26,
                       a: String::from("Bless God in the congregation; *"),
-                      b: String::from("bless the LORD, you that are of the fountain of Israel.")
+                      b: String::from("bless the LORD, you that are of the fountain of Israel."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 27,
                       a: String::from("There is Benjamin, least of the tribes, at the head;\nthe princes of Judah in a company; *"),
-                      b: String::from("and the princes of Zebulon and Naphtali.")
+                      b: String::from("and the princes of Zebulon and Naphtali."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 28,
                       a: String::from("Send forth your strength, O God; *"),
-                      b: String::from("establish, O God, what you have wrought for us.")
+                      b: String::from("establish, O God, what you have wrought for us."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 29,
                       a: String::from("Kings shall bring gifts to you, *"),
-                      b: String::from("for your temple’s sake at Jerusalem.")
+                      b: String::from("for your temple’s sake at Jerusalem."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 30,
                       a: String::from("Rebuke the wild beast of the reeds, *"),
-                      b: String::from("and the peoples, a herd of wild bulls with its calves.")
+                      b: String::from("and the peoples, a herd of wild bulls with its calves."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 31,
                       a: String::from("Trample down those who lust after silver; *"),
-                      b: String::from("scatter the peoples that delight in war.")
+                      b: String::from("scatter the peoples that delight in war."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 32,
                       a: String::from("Let tribute be brought out of Egypt; *"),
-                      b: String::from("let Ethiopia stretch out her hands to God.")
+                      b: String::from("let Ethiopia stretch out her hands to God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 33,
                       a: String::from("Sing to God, O kingdoms of the earth; *"),
-                      b: String::from("sing praises to the Lord.")
+                      b: String::from("sing praises to the Lord."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 34,
                       a: String::from("He rides in the heavens, the ancient heavens; *"),
-                      b: String::from("he sends forth his voice, his mighty voice.")
+                      b: String::from("he sends forth his voice, his mighty voice."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 35,
                       a: String::from("Ascribe power to God; *"),
-                      b: String::from("his majesty is over Israel;\nhis strength is in the skies.")
+                      b: String::from("his majesty is over Israel;\nhis strength is in the skies."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 36,
                       a: String::from("How wonderful is God in his holy places! *"),
-                      b: String::from("the God of Israel giving strength and power to his people!\nBlessed be God!")
+                      b: String::from("the God of Israel giving strength and power to his people!\nBlessed be God!"),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
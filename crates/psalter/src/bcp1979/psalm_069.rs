@@ -14,194 +14,233 @@ lazy_static! {
               PsalmVerse {
                 number: 1,
                 a: String::from("Save me, O God, *"),
-                b: String::from("for the waters have risen up to my neck.")
+                b: String::from("for the waters have risen up to my neck."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 2,
                 a: String::from("I am sinking in deep mire, *"),
-                b: String::from("and there is no firm ground for my feet.")
+                b: String::from("and there is no firm ground for my feet."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 3,
                 a: String::from("I have come into deep waters, *"),
-                b: String::from("and the torrent washes over me.")
+                b: String::from("and the torrent washes over me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 4,
                 a: String::from("I have grown weary with my crying;\nmy throat is inflamed; *"),
-                b: String::from("my eyes have failed from looking for my God.")
+                b: String::from("my eyes have failed from looking for my God."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 5,
                 a: String::from("Those who hate me without a cause are more than the hairs of my head;\nmy lying foes who would destroy me are mighty. *"),
-                b: String::from("Must I then give back what I never stole?")
+                b: String::from("Must I then give back what I never stole?"),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 6,
                 a: String::from("O God, you know my foolishness, *"),
-                b: String::from("and my faults are not hidden from you.")
+                b: String::from("and my faults are not hidden from you."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 7,
                 a: String::from("Let not those who hope in you be put to shame through me, Lord GOD of hosts; *"),
-                b: String::from("let not those who seek you be disgraced because of me, O God of Israel.")
+                b: String::from("let not those who seek you be disgraced because of me, O God of Israel."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 8,
                 a: String::from("Surely, for your sake have I suffered reproach, *"),
-                b: String::from("and shame has covered my face.")
+                b: String::from("and shame has covered my face."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 9,
                 a: String::from("I have become a stranger to my own kindred, *"),
-                b: String::from("an alien to my mother’s children.")
+                b: String::from("an alien to my mother’s children."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 10,
                 a: String::from("Zeal for your house has eaten me up; *"),
-                b: String::from("the scorn of those who scorn you has fallen upon me.")
+                b: String::from("the scorn of those who scorn you has fallen upon me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 11,
                 a: String::from("I humbled myself with fasting, *"),
-                b: String::from("but that was turned to my reproach.")
+                b: String::from("but that was turned to my reproach."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 12,
                 a: String::from("I put on sack-cloth also, *"),
-                b: String::from("and became a byword among them.")
+                b: String::from("and became a byword among them."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 13,
                 a: String::from("Those who sit at the gate murmur against me, *"),
-                b: String::from("and the drunkards make songs about me.")
+                b: String::from("and the drunkards make songs about me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 14,
                 a: String::from("But as for me, this is my prayer to you, *"),
-                b: String::from("at the time you have set, O LORD:")
+                b: String::from("at the time you have set, O LORD:"),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 15,
                 a: String::from("“In your great mercy, O God, *"),
-                b: String::from("answer me with your unfailing help.")
+                b: String::from("answer me with your unfailing help."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 16,
                 a: String::from("Save me from the mire; do not let me sink; *"),
-                b: String::from("let me be rescued from those who hate me\nand out of the deep waters.")
+                b: String::from("let me be rescued from those who hate me\nand out of the deep waters."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 17,
                 a: String::from("Let not the torrent of waters wash over me,\nneither let the deep swallow me up; *"),
-                b: String::from("do not let the Pit shut its mouth upon me.")
+                b: String::from("do not let the Pit shut its mouth upon me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 18,
                 a: String::from("Answer me, O LORD, for your love is kind; *"),
-                b: String::from("in your great compassion, turn to me.”")
+                b: String::from("in your great compassion, turn to me.”"),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 19,
                 a: String::from("“Hide not your face from your servant; *"),
-                b: String::from("be swift and answer me, for I am in distress.")
+                b: String::from("be swift and answer me, for I am in distress."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 20,
                 a: String::from("Draw near to me and redeem me; *"),
-                b: String::from("because of my enemies deliver me.")
+                b: String::from("because of my enemies deliver me."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 21,
                 a: String::from("You know my reproach, my shame, and my dishonor; *"),
-                b: String::from("my adversaries are all in your sight.”")
+                b: String::from("my adversaries are all in your sight.”"),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 22,
                 a: String::from("Reproach has broken my heart, and it cannot be healed; *"),
-                b: String::from("I looked for sympathy, but there was none,\nfor comforters, but I could find no one.")
+                b: String::from("I looked for sympathy, but there was none,\nfor comforters, but I could find no one."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 23,
                 a: String::from("They gave me gall to eat, *"),
-                b: String::from("and when I was thirsty, they gave me vinegar to drink.")
+                b: String::from("and when I was thirsty, they gave me vinegar to drink."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 24,
                 a: String::from("Let the table before them be a trap *"),
-                b: String::from("and their sacred feasts a snare.")
+                b: String::from("and their sacred feasts a snare."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 25,
                 a: String::from("Let their eyes be darkened, that they may not see, *"),
-                b: String::from("and give them continual trembling in their loins.")
+                b: String::from("and give them continual trembling in their loins."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 26,
                 a: String::from("Pour out your indignation upon them, *"),
-                b: String::from("and let the fierceness of your anger overtake them.")
+                b: String::from("and let the fierceness of your anger overtake them."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 27,
                 a: String::from("Let their camp be desolate, *"),
-                b: String::from("and let there be none to dwell in their tents.")
+                b: String::from("and let there be none to dwell in their tents."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 28,
                 a: String::from("For they persecute him whom you have stricken *"),
-                b: String::from("and add to the pain of those whom you have pierced.")
+                b: String::from("and add to the pain of those whom you have pierced."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 29,
                 a: String::from("Lay to their charge guilt upon guilt, *"),
-                b: String::from("and let them not receive your vindication.")
+                b: String::from("and let them not receive your vindication."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 30,
                 a: String::from("Let them be wiped out of the book of the living *"),
-                b: String::from("and not be written among the righteous.")
+                b: String::from("and not be written among the righteous."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 31,
                 a: String::from("As for me, I am afflicted and in pain; *"),
-                b: String::from("your help, O God, will lift me up on high.")
+                b: String::from("your help, O God, will lift me up on high."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 32,
                 a: String::from("I will praise the Name of God in song; *"),
-                b: String::from("I will proclaim his greatness with thanksgiving.")
+                b: String::from("I will proclaim his greatness with thanksgiving."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 33,
                 a: String::from("This will please the LORD more than an offering of oxen, *"),
-                b: String::from("more than bullocks with horns and hoofs.")
+                b: String::from("more than bullocks with horns and hoofs."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 34,
                 a: String::from("The afflicted shall see and be glad; *"),
-                b: String::from("you who seek God, your heart shall live.")
+                b: String::from("you who seek God, your heart shall live."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 35,
                 a: String::from("For the LORD listens to the needy, *"),
-                b: String::from("and his prisoners he does not despise.")
+                b: String::from("and his prisoners he does not despise."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 36,
                 a: String::from("Let the heavens and the earth praise him, *"),
-                b: String::from("the seas and all that moves in them;")
+                b: String::from("the seas and all that moves in them;"),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 37,
                 a: String::from("For God will save Zion and rebuild the cities of Judah; *"),
-                b: String::from("they shall live there and have it in possession.")
+                b: String::from("they shall live there and have it in possession."),
+                ..Default::default()
             },
           PsalmVerse {
                 number: 38,
                 a: String::from("The children of his servants will inherit it, *"),
-                b: String::from("and those who love his Name will dwell therein.")
+                b: String::from("and those who love his Name will dwell therein."),
+                ..Default::default()
             },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,7 +14,8 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Be pleased, O God, to deliver me; *"),
-                    b: String::from("O LORD, make haste to help me.")
+                    b: String::from("O LORD, make haste to help me."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
@@ -23,33 +24,39 @@ lazy_static! {
                     ),
                     b: String::from(
                         "let those who take pleasure in my misfortune\ndraw back and be disgraced."
-                    )
+                    ),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from(
                         "Let those who say to me “Aha!” and gloat over me turn back, *"
                     ),
-                    b: String::from("because they are ashamed.")
+                    b: String::from("because they are ashamed."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("Let all who seek you rejoice and be glad in you; *"),
                     b: String::from(
                         "let those who love your salvation say for ever,\n“Great is the LORD!”"
-                    )
+                    ),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("But as for me, I am poor and needy; *"),
-                    b: String::from("come to me speedily, O God.")
+                    b: String::from("come to me speedily, O God."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("You are my helper and my deliverer; *"),
-                    b: String::from("O LORD, do not tarry.")
+                    b: String::from("O LORD, do not tarry."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
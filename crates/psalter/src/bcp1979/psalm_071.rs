@@ -15,125 +15,150 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("In you, O LORD, have I taken refuge; *"),
-                      b: String::from("let me never be ashamed.")
+                      b: String::from("let me never be ashamed."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("In your righteousness, deliver me and set me free; *"),
-                      b: String::from("incline your ear to me and save me.")
+                      b: String::from("incline your ear to me and save me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Be my strong rock, a castle to keep me safe; *"),
-                      b: String::from("you are my crag and my stronghold.")
+                      b: String::from("you are my crag and my stronghold."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Deliver me, my God, from the hand of the wicked, *"),
-                      b: String::from("from the clutches of the evildoer and the oppressor.")
+                      b: String::from("from the clutches of the evildoer and the oppressor."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("For you are my hope, O LORD God, *"),
-                      b: String::from("my confidence since I was young.")
+                      b: String::from("my confidence since I was young."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("I have been sustained by you ever since I was born;\nfrom my mother’s womb you have been my strength; *"),
-                      b: String::from("my praise shall be always of you.")
+                      b: String::from("my praise shall be always of you."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("I have become a portent to many; *"),
-                      b: String::from("but you are my refuge and my strength.")
+                      b: String::from("but you are my refuge and my strength."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Let my mouth be full of your praise *"),
-                      b: String::from("and your glory all the day long.")
+                      b: String::from("and your glory all the day long."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Do not cast me off in my old age; *"),
-                      b: String::from("forsake me not when my strength fails.")
+                      b: String::from("forsake me not when my strength fails."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("For my enemies are talking against me, *"),
-                      b: String::from("and those who lie in wait for my life take counsel together.")
+                      b: String::from("and those who lie in wait for my life take counsel together."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("They say, “God has forsaken him;\ngo after him and seize him; *"),
-                      b: String::from("because there is none who will save.”")
+                      b: String::from("because there is none who will save.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("O God, be not far from me; *"),
-                      b: String::from("come quickly to help me, O my God.")
+                      b: String::from("come quickly to help me, O my God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("Let those who set themselves against me be put to shame and be disgraced; *"),
-                      b: String::from("let those who seek to do me evil be covered with scorn and reproach.")
+                      b: String::from("let those who seek to do me evil be covered with scorn and reproach."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("But I shall always wait in patience, *"),
-                      b: String::from("and shall praise you more and more.")
+                      b: String::from("and shall praise you more and more."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("My mouth shall recount your mighty acts\nand saving deeds all day long; *"),
-                      b: String::from("though I cannot know the number of them.")
+                      b: String::from("though I cannot know the number of them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("I will begin with the mighty works of the Lord GOD; *"),
-                      b: String::from("I will recall your righteousness, yours alone.")
+                      b: String::from("I will recall your righteousness, yours alone."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("O God, you have taught me since I was young, *"),
-                      b: String::from("and to this day I tell of your wonderful works.")
+                      b: String::from("and to this day I tell of your wonderful works."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("And now that I am old and gray-headed, O God, do not forsake me, *"),
-                      b: String::from("till I make known your strength to this generation\nand your power to all who are to come.")
+                      b: String::from("till I make known your strength to this generation\nand your power to all who are to come."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("Your righteousness, O God, reaches to the heavens; *"),
-                      b: String::from("you have done great things;\nwho is like you, O God?")
+                      b: String::from("you have done great things;\nwho is like you, O God?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("You have showed me great troubles and adversities, *"),
-                      b: String::from("but you will restore my life\nand bring me up again from the deep places of the earth.")
+                      b: String::from("but you will restore my life\nand bring me up again from the deep places of the earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 21,
                       a: String::from("You strengthen me more and more; *"),
-                      b: String::from("you enfold and comfort me,")
+                      b: String::from("you enfold and comfort me,"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 22,
                       a: String::from("Therefore I will praise you upon the lyre for your faithfulness, O my God; *"),
-                      b: String::from("I will sing to you with the harp, O Holy One of Israel.")
+                      b: String::from("I will sing to you with the harp, O Holy One of Israel."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 23,
                       a: String::from("My lips will sing with joy when I play to you, *"),
-                      b: String::from("and so will my soul, which you have redeemed.")
+                      b: String::from("and so will my soul, which you have redeemed."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 24,
                       a: String::from("My tongue will proclaim your righteousness all day long, *"),
-                      b: String::from("for they are ashamed and disgraced who sought to do me harm.")
+                      b: String::from("for they are ashamed and disgraced who sought to do me harm."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
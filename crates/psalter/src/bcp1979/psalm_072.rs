@@ -15,100 +15,120 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Give the King your justice, O God, *"),
-                      b: String::from("and your righteousness to the King’s son;")
+                      b: String::from("and your righteousness to the King’s son;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("That he may rule your people righteously *"),
-                      b: String::from("and the poor with justice.")
+                      b: String::from("and the poor with justice."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("That the mountains may bring prosperity to the people, *"),
-                      b: String::from("and the little hills bring righteousness.")
+                      b: String::from("and the little hills bring righteousness."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("He shall defend the needy among the people; *"),
-                      b: String::from("he shall rescue the poor and crush the oppressor.")
+                      b: String::from("he shall rescue the poor and crush the oppressor."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("He shall live as long as the sun and moon endure, *"),
-                      b: String::from("from one generation to another.")
+                      b: String::from("from one generation to another."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("He shall come down like rain upon the mown field, *"),
-                      b: String::from("like showers that water the earth.")
+                      b: String::from("like showers that water the earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("In his time shall the righteous flourish; *"),
-                      b: String::from("there shall be abundance of peace till the moon shall be no more.")
+                      b: String::from("there shall be abundance of peace till the moon shall be no more."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("He shall rule from sea to sea, *"),
-                      b: String::from("and from the River to the ends of the earth.")
+                      b: String::from("and from the River to the ends of the earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("His foes shall bow down before him, *"),
-                      b: String::from("and his enemies lick the dust.")
+                      b: String::from("and his enemies lick the dust."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("The kings of Tarshish and of the isles shall pay tribute, *"),
-                      b: String::from("and the kings of Arabia and Saba offer gifts.")
+                      b: String::from("and the kings of Arabia and Saba offer gifts."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("All kings shall bow down before him, *"),
-                      b: String::from("and all the nations do him service.")
+                      b: String::from("and all the nations do him service."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("For he shall deliver the poor who cries out in distress, *"),
-                      b: String::from("and the oppressed who has no helper.")
+                      b: String::from("and the oppressed who has no helper."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("He shall have pity on the lowly and poor; *"),
-                      b: String::from("he shall preserve the lives of the needy.")
+                      b: String::from("he shall preserve the lives of the needy."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("He shall redeem their lives from oppression and violence, *"),
-                      b: String::from("and dear shall their blood be in his sight.")
+                      b: String::from("and dear shall their blood be in his sight."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("Long may he live!\nand may there be given to him gold from Arabia; *"),
-                      b: String::from("may prayer be made for him always,\nand may they bless him all the day long.")
+                      b: String::from("may prayer be made for him always,\nand may they bless him all the day long."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("May there be abundance of grain on the earth,\ngrowing thick even on the hilltops; *"),
-                      b: String::from("may its fruit flourish like Lebanon,\nand its grain like grass upon the earth.")
+                      b: String::from("may its fruit flourish like Lebanon,\nand its grain like grass upon the earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("May his Name remain for ever\nand be established as long as the sun endures; *"),
-                      b: String::from("may all the nations bless themselves in him and call him blessed.")
+                      b: String::from("may all the nations bless themselves in him and call him blessed."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("Blessed be the Lord GOD, the God of Israel, *"),
-                      b: String::from("who alone does wondrous deeds!")
+                      b: String::from("who alone does wondrous deeds!"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("And blessed be his glorious Name for ever! *"),
-                      b: String::from("and may all the earth be filled with his glory. Amen. Amen.")
+                      b: String::from("and may all the earth be filled with his glory. Amen. Amen."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -14,149 +14,179 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Truly, God is good to Israel, *"),
-                    b: String::from("to those who are pure in heart.")
+                    b: String::from("to those who are pure in heart."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("But as for me, my feet had nearly slipped; *"),
-                    b: String::from("I had almost tripped and fallen;")
+                    b: String::from("I had almost tripped and fallen;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("Because I envied the proud *"),
-                    b: String::from("and saw the prosperity of the wicked:")
+                    b: String::from("and saw the prosperity of the wicked:"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("For they suffer no pain, *"),
-                    b: String::from("and their bodies are sleek and sound;")
+                    b: String::from("and their bodies are sleek and sound;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("In the misfortunes of others they have no share; *"),
-                    b: String::from("they are not afflicted as others are;")
+                    b: String::from("they are not afflicted as others are;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("Therefore they wear their pride like a necklace *"),
-                    b: String::from("and wrap their violence about them like a cloak.")
+                    b: String::from("and wrap their violence about them like a cloak."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("Their iniquity comes from gross minds, *"),
-                    b: String::from("and their hearts overflow with wicked thoughts.")
+                    b: String::from("and their hearts overflow with wicked thoughts."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("They scoff and speak maliciously; *"),
-                    b: String::from("out of their haughtiness they plan oppression.")
+                    b: String::from("out of their haughtiness they plan oppression."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("They set their mouths against the heavens, *"),
-                    b: String::from("and their evil speech runs through the world.")
+                    b: String::from("and their evil speech runs through the world."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 10,
                     a: String::from("And so the people turn to them *"),
-                    b: String::from("and find in them no fault.")
+                    b: String::from("and find in them no fault."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 11,
                     a: String::from("They say, “How should God know? *"),
-                    b: String::from("is there knowledge in the Most High?”")
+                    b: String::from("is there knowledge in the Most High?”"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 12,
                     a: String::from("So then, these are the wicked; *"),
-                    b: String::from("always at ease, they increase their wealth.")
+                    b: String::from("always at ease, they increase their wealth."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 13,
                     a: String::from("In vain have I kept my heart clean, *"),
-                    b: String::from("and washed my hands in innocence.")
+                    b: String::from("and washed my hands in innocence."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 14,
                     a: String::from("I have been afflicted all day long, *"),
-                    b: String::from("and punished every morning.")
+                    b: String::from("and punished every morning."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 15,
                     a: String::from("Had I gone on speaking this way, *"),
-                    b: String::from("I should have betrayed the generation of your children.")
+                    b: String::from("I should have betrayed the generation of your children."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 16,
                     a: String::from("When I tried to understand these things, *"),
-                    b: String::from("it was too hard for me;")
+                    b: String::from("it was too hard for me;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 17,
                     a: String::from("Until I entered the sanctuary of God *"),
-                    b: String::from("and discerned the end of the wicked.")
+                    b: String::from("and discerned the end of the wicked."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 18,
                     a: String::from("Surely, you set them in slippery places; *"),
-                    b: String::from("you cast them down in ruin.")
+                    b: String::from("you cast them down in ruin."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 19,
                     a: String::from("Oh, how suddenly do they come to destruction, *"),
-                    b: String::from("come to an end, and perish from terror!")
+                    b: String::from("come to an end, and perish from terror!"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 20,
                     a: String::from("Like a dream when one awakens, O Lord, *"),
-                    b: String::from("when you arise you will make their image vanish.")
+                    b: String::from("when you arise you will make their image vanish."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 21,
                     a: String::from("When my mind became embittered, *"),
-                    b: String::from("I was sorely wounded in my heart.")
+                    b: String::from("I was sorely wounded in my heart."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 22,
                     a: String::from("I was stupid and had no understanding; *"),
-                    b: String::from("I was like a brute beast in your presence.")
+                    b: String::from("I was like a brute beast in your presence."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 23,
                     a: String::from("Yet I am always with you; *"),
-                    b: String::from("you hold me by my right hand.")
+                    b: String::from("you hold me by my right hand."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 24,
                     a: String::from("You will guide me by your counsel, *"),
-                    b: String::from("and afterwards receive me with glory.")
+                    b: String::from("and afterwards receive me with glory."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 25,
                     a: String::from("Whom have I in heaven but you? *"),
-                    b: String::from("and having you I desire nothing upon earth.")
+                    b: String::from("and having you I desire nothing upon earth."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 26,
                     a: String::from("Though my flesh and my heart should waste away, *"),
-                    b: String::from("God is the strength of my heart and my portion for ever.")
+                    b: String::from("God is the strength of my heart and my portion for ever."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 27,
                     a: String::from("Truly, those who forsake you will perish; *"),
-                    b: String::from("you destroy all who are unfaithful.")
+                    b: String::from("you destroy all who are unfaithful."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 28,
                     a: String::from("But it is good for me to be near God; *"),
-                    b: String::from("I have made the Lord GOD my refuge.")
+                    b: String::from("I have made the Lord GOD my refuge."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 29,
                     a: String::from("I will speak of all your works *"),
-                    b: String::from("in the gates of the city of Zion.")
+                    b: String::from("in the gates of the city of Zion."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -15,115 +15,138 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("O God, why have you utterly cast us off? *"),
-                      b: String::from("why is your wrath so hot against the sheep of your pasture?")
+                      b: String::from("why is your wrath so hot against the sheep of your pasture?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Remember your congregation that you purchased long ago, *"),
-                      b: String::from("the tribe you redeemed to be your inheritance,\nand Mount Zion where you dwell.")
+                      b: String::from("the tribe you redeemed to be your inheritance,\nand Mount Zion where you dwell."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Turn your steps toward the endless ruins; *"),
-                      b: String::from("the enemy has laid waste everything in your sanctuary.")
+                      b: String::from("the enemy has laid waste everything in your sanctuary."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Your adversaries roared in your holy place; *"),
-                      b: String::from("they set up their banners as tokens of victory.")
+                      b: String::from("they set up their banners as tokens of victory."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("They were like men coming up with axes to a grove of trees; *"),
-                      b: String::from("they broke down all your carved work with hatchets and hammers.")
+                      b: String::from("they broke down all your carved work with hatchets and hammers."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("They set fire to your holy place; *"),
-                      b: String::from("they defiled the dwelling-place of your Name\nand razed it to the ground.")
+                      b: String::from("they defiled the dwelling-place of your Name\nand razed it to the ground."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("They said to themselves, “Let us destroy them altogether.” *"),
-                      b: String::from("They burned down all the meeting-places of God in the land.")
+                      b: String::from("They burned down all the meeting-places of God in the land."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("There are no signs for us to see;\nthere is no prophet left; *"),
-                      b: String::from("there is not one among us who knows how long.")
+                      b: String::from("there is not one among us who knows how long."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("How long, O God, will the adversary scoff? *"),
-                      b: String::from("will the enemy blaspheme your Name for ever?")
+                      b: String::from("will the enemy blaspheme your Name for ever?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Why do you draw back your hand? *"),
-                      b: String::from("why is your right hand hidden in your bosom?")
+                      b: String::from("why is your right hand hidden in your bosom?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Yet God is my King from ancient times, *"),
-                      b: String::from("victorious in the midst of the earth.")
+                      b: String::from("victorious in the midst of the earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("You divided the sea by your might *"),
-                      b: String::from("and shattered the heads of the dragons upon the waters;")
+                      b: String::from("and shattered the heads of the dragons upon the waters;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("You crushed the heads of Leviathan *"),
-                      b: String::from("and gave him to the people of the desert for food.")
+                      b: String::from("and gave him to the people of the desert for food."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("You split open spring and torrent; *"),
-                      b: String::from("you dried up ever-flowing rivers.")
+                      b: String::from("you dried up ever-flowing rivers."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("Yours is the day, yours also the night; *"),
-                      b: String::from("you established the moon and the sun.")
+                      b: String::from("you established the moon and the sun."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("You fixed all the boundaries of the earth; *"),
-                      b: String::from("you made both summer and winter.")
+                      b: String::from("you made both summer and winter."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("Remember, O LORD, how the enemy scoffed, *"),
-                      b: String::from("how a foolish people despised your Name.")
+                      b: String::from("how a foolish people despised your Name."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("Do not hand over the life of your dove to wild beasts; *"),
-                      b: String::from("never forget the lives of your poor.")
+                      b: String::from("never forget the lives of your poor."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("Look upon your covenant; *"),
-                      b: String::from("the dark places of the earth are haunts of violence.")
+                      b: String::from("the dark places of the earth are haunts of violence."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("Let not the oppressed turn away ashamed; *"),
-                      b: String::from("let the poor and needy praise your Name.")
+                      b: String::from("let the poor and needy praise your Name."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 21,
                       a: String::from("Arise, O God, maintain your cause; *"),
-                      b: String::from("remember how fools revile you all day long.")
+                      b: String::from("remember how fools revile you all day long."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 22,
                       a: String::from("Forget not the clamor of your adversaries, *"),
-                      b: String::from("the unending tumult of those who rise up against you.")
+                      b: String::from("the unending tumult of those who rise up against you."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
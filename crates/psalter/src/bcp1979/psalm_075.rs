@@ -15,55 +15,66 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("We give you thanks, O God, we give you thanks, *"),
-                      b: String::from("calling upon your Name and declaring all your wonderful deeds.")
+                      b: String::from("calling upon your Name and declaring all your wonderful deeds."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("“I will appoint a time,” says God; *"),
-                      b: String::from("“I will judge with equity.")
+                      b: String::from("“I will judge with equity."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Though the earth and all its inhabitants are quaking, *"),
-                      b: String::from("I will make its pillars fast.")
+                      b: String::from("I will make its pillars fast."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("I will say to the boasters, ‘Boast no more,’ *"),
-                      b: String::from("and to the wicked, ‘Do not toss your horns;")
+                      b: String::from("and to the wicked, ‘Do not toss your horns;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Do not toss your horns so high, *"),
-                      b: String::from("nor speak with a proud neck.’”")
+                      b: String::from("nor speak with a proud neck.’”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("For judgment is neither from the east nor from the west, *"),
-                      b: String::from("nor yet from the wilderness or the mountains.")
+                      b: String::from("nor yet from the wilderness or the mountains."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("It is God who judges; *"),
-                      b: String::from("he puts down one and lifts up another.")
+                      b: String::from("he puts down one and lifts up another."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("For in the LORD’s hand there is a cup,\nfull of spiced and foaming wine, which he pours out, *"),
-                      b: String::from("and all the wicked of the earth shall drink and drain the dregs.")
+                      b: String::from("and all the wicked of the earth shall drink and drain the dregs."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("But I will rejoice for ever; *"),
-                      b: String::from("I will sing praises to the God of Jacob.")
+                      b: String::from("I will sing praises to the God of Jacob."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("He shall break off all the horns of the wicked; *"),
-                      b: String::from("but the horns of the righteous shall be exalted.")
+                      b: String::from("but the horns of the righteous shall be exalted."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
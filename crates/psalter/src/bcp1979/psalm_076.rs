@@ -14,68 +14,81 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("In Judah is God known; *"),
-                    b: String::from("his Name is great in Israel.")
+                    b: String::from("his Name is great in Israel."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("At Salem is his tabernacle, *"),
-                    b: String::from("and his dwelling is in Zion.")
+                    b: String::from("and his dwelling is in Zion."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("There he broke the flashing arrows, *"),
-                    b: String::from("the shield, the sword, and the weapons of battle.")
+                    b: String::from("the shield, the sword, and the weapons of battle."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("How glorious you are! *"),
-                    b: String::from("more splendid than the everlasting mountains!")
+                    b: String::from("more splendid than the everlasting mountains!"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from(
                         "The strong of heart have been despoiled;\nthey sink into sleep; *"
                     ),
-                    b: String::from("none of the warriors can lift a hand.")
+                    b: String::from("none of the warriors can lift a hand."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("At your rebuke, O God of Jacob, *"),
-                    b: String::from("both horse and rider lie stunned.")
+                    b: String::from("both horse and rider lie stunned."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("What terror you inspire! *"),
-                    b: String::from("who can stand before you when you are angry?")
+                    b: String::from("who can stand before you when you are angry?"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("From heaven you pronounced judgment; *"),
-                    b: String::from("the earth was afraid and was still;")
+                    b: String::from("the earth was afraid and was still;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("When God rose up to judgment *"),
-                    b: String::from("and to save all the oppressed of the earth.")
+                    b: String::from("and to save all the oppressed of the earth."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 10,
                     a: String::from("Truly, wrathful Edom will give you thanks, *"),
-                    b: String::from("and the remnant of Hamath will keep your feasts.")
+                    b: String::from("and the remnant of Hamath will keep your feasts."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 11,
                     a: String::from("Make a vow to the LORD your God and keep it; *"),
                     b: String::from(
                         "let all around him bring gifts to him who is worthy to be feared."
-                    )
+                    ),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 12,
                     a: String::from("He breaks the spirit of princes, *"),
-                    b: String::from("and strikes terror in the kings of the earth.")
+                    b: String::from("and strikes terror in the kings of the earth."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
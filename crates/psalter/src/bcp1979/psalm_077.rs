@@ -15,105 +15,126 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("I will cry aloud to God; *"),
-                      b: String::from("I will cry aloud, and he will hear me.")
+                      b: String::from("I will cry aloud, and he will hear me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("In the day of my trouble I sought the Lord; *"),
-                      b: String::from("my hands were stretched out by night and did not tire;\nI refused to be comforted.")
+                      b: String::from("my hands were stretched out by night and did not tire;\nI refused to be comforted."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("I think of God, I am restless, *"),
-                      b: String::from("I ponder, and my spirit faints.")
+                      b: String::from("I ponder, and my spirit faints."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("You will not let my eyelids close; *"),
-                      b: String::from("I am troubled and I cannot speak.")
+                      b: String::from("I am troubled and I cannot speak."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("I consider the days of old; *"),
-                      b: String::from("I remember the years long past;")
+                      b: String::from("I remember the years long past;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("I commune with my heart in the night; *"),
-                      b: String::from("I ponder and search my mind.")
+                      b: String::from("I ponder and search my mind."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Will the Lord cast me off for ever? *"),
-                      b: String::from("will he no more show his favor?")
+                      b: String::from("will he no more show his favor?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Has his loving-kindness come to an end for ever? *"),
-                      b: String::from("has his promise failed for evermore?")
+                      b: String::from("has his promise failed for evermore?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Has God forgotten to be gracious? *"),
-                      b: String::from("has he, in his anger, withheld his compassion?")
+                      b: String::from("has he, in his anger, withheld his compassion?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("And I said, “My grief is this: *"),
-                      b: String::from("the right hand of the Most High has lost its power.”")
+                      b: String::from("the right hand of the Most High has lost its power.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("I will remember the works of the LORD, *"),
-                      b: String::from("and call to mind your wonders of old time.")
+                      b: String::from("and call to mind your wonders of old time."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("I will meditate on all your acts *"),
-                      b: String::from("and ponder your mighty deeds.")
+                      b: String::from("and ponder your mighty deeds."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("Your way, O God, is holy; *"),
-                      b: String::from("who is so great a god as our God?")
+                      b: String::from("who is so great a god as our God?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("You are the God who works wonders *"),
-                      b: String::from("and have declared your power among the peoples.")
+                      b: String::from("and have declared your power among the peoples."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("By your strength you have redeemed your people, *"),
-                      b: String::from("the children of Jacob and Joseph.")
+                      b: String::from("the children of Jacob and Joseph."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("The waters saw you, O God;\nthe waters saw you and trembled; *"),
-                      b: String::from("the very depths were shaken.")
+                      b: String::from("the very depths were shaken."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("The clouds poured out water;\nthe skies thundered; *"),
-                      b: String::from("your arrows flashed to and fro;")
+                      b: String::from("your arrows flashed to and fro;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("The sound of your thunder was in the whirlwind;\nyour lightnings lit up the world; *"),
-                      b: String::from("the earth trembled and shook.")
+                      b: String::from("the earth trembled and shook."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("Your way was in the sea,\nand your paths in the great waters, *"),
-                      b: String::from("yet your footsteps were not seen.")
+                      b: String::from("yet your footsteps were not seen."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("You led your people like a flock *"),
-                      b: String::from("by the hand of Moses and Aaron.")
+                      b: String::from("by the hand of Moses and Aaron."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
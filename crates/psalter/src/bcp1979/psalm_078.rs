@@ -15,197 +15,236 @@ lazy_static! {
                           PsalmVerse {
                     number: 1,
                     a: String::from("Hear my teaching, O my people; *"),
-                    b: String::from("incline your ears to the words of my mouth.")
+                    b: String::from("incline your ears to the words of my mouth."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 2,
                     a: String::from("I will open my mouth in a parable; *"),
-                    b: String::from("I will declare the mysteries of ancient times.")
+                    b: String::from("I will declare the mysteries of ancient times."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 3,
                     a: String::from("That which we have heard and known,\nand what our forefathers have told us, *"),
-                    b: String::from("we will not hide from their children.")
+                    b: String::from("we will not hide from their children."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 4,
                     a: String::from("We will recount to generations to come\nthe praiseworthy deeds and the power of the LORD, *"),
-                    b: String::from("and the wonderful works he has done.")
+                    b: String::from("and the wonderful works he has done."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 5,
                     a: String::from("He gave his decrees to Jacob\nand established a law for Israel, *"),
-                    b: String::from("which he commanded them to teach their children;")
+                    b: String::from("which he commanded them to teach their children;"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 6,
                     a: String::from("That the generations to come might know,\nand the children yet unborn; *"),
-                    b: String::from("that they in their turn might tell it to their children;")
+                    b: String::from("that they in their turn might tell it to their children;"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 7,
                     a: String::from("So that they might put their trust in God, *"),
-                    b: String::from("and not forget the deeds of God,\nbut keep his commandments;")
+                    b: String::from("and not forget the deeds of God,\nbut keep his commandments;"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 8,
                     a: String::from("And not be like their forefathers,\na stubborn and rebellious generation, *"),
-                    b: String::from("a generation whose heart was not steadfast,\nand whose spirit was not faithful to God.")
+                    b: String::from("a generation whose heart was not steadfast,\nand whose spirit was not faithful to God."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 9,
                     a: String::from("The people of Ephraim, armed with the bow, *"),
-                    b: String::from("turned back in the day of battle;")
+                    b: String::from("turned back in the day of battle;"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 10,
                     a: String::from("They did not keep the covenant of God, *"),
-                    b: String::from("and refused to walk in his law;")
+                    b: String::from("and refused to walk in his law;"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 11,
                     a: String::from("They forgot what he had done, *"),
-                    b: String::from("and the wonders he had shown them.")
+                    b: String::from("and the wonders he had shown them."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 12,
                     a: String::from("He worked marvels in the sight of their forefathers, *"),
-                    b: String::from("in the land of Egypt, in the field of Zoan.")
+                    b: String::from("in the land of Egypt, in the field of Zoan."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 13,
                     a: String::from("He split open the sea and let them pass through; *"),
-                    b: String::from("he made the waters stand up like walls.")
+                    b: String::from("he made the waters stand up like walls."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 14,
                     a: String::from("He led them with a cloud by day, *"),
-                    b: String::from("and all the night through with a glow of fire.")
+                    b: String::from("and all the night through with a glow of fire."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 15,
                     a: String::from("He split the hard rocks in the wilderness *"),
-                    b: String::from("and gave them drink as from the great deep.")
+                    b: String::from("and gave them drink as from the great deep."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 16,
                     a: String::from("He brought streams out of the cliff, *"),
-                    b: String::from("and the waters gushed out like rivers.")
+                    b: String::from("and the waters gushed out like rivers."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 17,
                     a: String::from("But they went on sinning against him, *"),
-                    b: String::from("rebelling in the desert against the Most High.")
+                    b: String::from("rebelling in the desert against the Most High."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 18,
                     a: String::from("They tested God in their hearts, *"),
-                    b: String::from("demanding food for their craving.")
+                    b: String::from("demanding food for their craving."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 19,
                     a: String::from("They railed against God and said, *"),
-                    b: String::from("“Can God set a table in the wilderness?")
+                    b: String::from("“Can God set a table in the wilderness?"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 20,
                     a: String::from("True, he struck the rock, the waters gushed out, and the gullies overflowed; *"),
-                    b: String::from("but is he able to give bread\nor to provide meat for his people?”")
+                    b: String::from("but is he able to give bread\nor to provide meat for his people?”"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 21,
                     a: String::from("When the LORD heard this, he was full of wrath; *"),
-                    b: String::from("a fire was kindled against Jacob,\nand his anger mounted against Israel;")
+                    b: String::from("a fire was kindled against Jacob,\nand his anger mounted against Israel;"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 22,
                     a: String::from("For they had no faith in God, *"),
-                    b: String::from("nor did they put their trust in his saving power.")
+                    b: String::from("nor did they put their trust in his saving power."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 23,
                     a: String::from("So he commanded the clouds above *"),
-                    b: String::from("and opened the doors of heaven.")
+                    b: String::from("and opened the doors of heaven."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 24,
                     a: String::from("He rained down manna upon them to eat *"),
-                    b: String::from("and gave them grain from heaven.")
+                    b: String::from("and gave them grain from heaven."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 25,
                     a: String::from("So mortals ate the bread of angels; *"),
-                    b: String::from("he provided for them food enough.")
+                    b: String::from("he provided for them food enough."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 26,
                     a: String::from("He caused the east wind to blow in the heavens *"),
-                    b: String::from("and led out the south wind by his might.")
+                    b: String::from("and led out the south wind by his might."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 27,
                     a: String::from("He rained down flesh upon them like dust *"),
-                    b: String::from("and wingèd birds like the sand of the sea.")
+                    b: String::from("and wingèd birds like the sand of the sea."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 28,
                     a: String::from("He let it fall in the midst of their camp *"),
-                    b: String::from("and round about their dwellings.")
+                    b: String::from("and round about their dwellings."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 29,
                     a: String::from("So they ate and were well filled, *"),
-                    b: String::from("for he gave them what they craved.")
+                    b: String::from("for he gave them what they craved."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 30,
                     a: String::from("But they did not stop their craving, *"),
-                    b: String::from("though the food was still in their mouths.")
+                    b: String::from("though the food was still in their mouths."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 31,
                     a: String::from("So God’s anger mounted against them; *"),
-                    b: String::from("he slew their strongest men\nand laid low the youth of Israel.")
+                    b: String::from("he slew their strongest men\nand laid low the youth of Israel."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 32,
                     a: String::from("In spite of all this, they went on sinning *"),
-                    b: String::from("and had no faith in his wonderful works.")
+                    b: String::from("and had no faith in his wonderful works."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 33,
                     a: String::from("So he brought their days to an end like a breath *"),
-                    b: String::from("and their years in sudden terror.")
+                    b: String::from("and their years in sudden terror."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 34,
                     a: String::from("Whenever he slew them, they would seek him, *"),
-                    b: String::from("and repent, and diligently search for God.")
+                    b: String::from("and repent, and diligently search for God."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 35,
                     a: String::from("They would remember that God was their rock, *"),
-                    b: String::from("and the Most High God their redeemer.")
+                    b: String::from("and the Most High God their redeemer."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 36,
                     a: String::from("But they flattered him with their mouths *"),
-                    b: String::from("and lied to him with their tongues.")
+                    b: String::from("and lied to him with their tongues."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 37,
                     a: String::from("Their heart was not steadfast toward him, *"),
-                    b: String::from("and they were not faithful to his covenant.")
+                    b: String::from("and they were not faithful to his covenant."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 38,
                     a: String::from("But he was so merciful that he forgave their sins\nand did not destroy them; *"),
-                    b: String::from("many times he held back his anger\nand did not permit his wrath to be roused.")
+                    b: String::from("many times he held back his anger\nand did not permit his wrath to be roused."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 39,
                     a: String::from("For he remembered that they were but flesh, *"),
-                    b: String::from("a breath that goes forth and does not return.")
+                    b: String::from("a breath that goes forth and does not return."),
+                    ..Default::default()
                 },
             ]
               },
@@ -220,170 +259,204 @@ lazy_static! {
                   PsalmVerse {
                         number: 40,
                         a: String::from("How often the people disobeyed him in the wilderness *"),
-                        b: String::from("and offended him in the desert!")
+                        b: String::from("and offended him in the desert!"),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 41,
                         a: String::from("Again and again they tempted God *"),
-                        b: String::from("and provoked the Holy One of Israel.")
+                        b: String::from("and provoked the Holy One of Israel."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 42,
                         a: String::from("They did not remember his power *"),
-                        b: String::from("in the day when he ransomed them from the enemy;")
+                        b: String::from("in the day when he ransomed them from the enemy;"),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 43,
                         a: String::from("How he wrought his signs in Egypt *"),
-                        b: String::from("and his omens in the field of Zoan.")
+                        b: String::from("and his omens in the field of Zoan."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 44,
                         a: String::from("He turned their rivers into blood, *"),
-                        b: String::from("so that they could not drink of their streams.")
+                        b: String::from("so that they could not drink of their streams."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 45,
                         a: String::from("He sent swarms of flies among them, which ate them up, *"),
-                        b: String::from("and frogs, which destroyed them.")
+                        b: String::from("and frogs, which destroyed them."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 46,
                         a: String::from("He gave their crops to the caterpillar, *"),
-                        b: String::from("the fruit of their toil to the locust.")
+                        b: String::from("the fruit of their toil to the locust."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 47,
                         a: String::from("He killed their vines with hail *"),
-                        b: String::from("and their sycamores with frost.")
+                        b: String::from("and their sycamores with frost."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 48,
                         a: String::from("He delivered their cattle to hailstones *"),
-                        b: String::from("and their livestock to hot thunderbolts.")
+                        b: String::from("and their livestock to hot thunderbolts."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 49,
                         a: String::from("He poured out upon them his blazing anger: *"),
-                        b: String::from("fury, indignation, and distress,\na troop of destroying angels.")
+                        b: String::from("fury, indignation, and distress,\na troop of destroying angels."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 50,
                         a: String::from("He gave full rein to his anger;\nhe did not spare their souls from death; *"),
-                        b: String::from("but delivered their lives to the plague.")
+                        b: String::from("but delivered their lives to the plague."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 51,
                         a: String::from("He struck down all the firstborn of Egypt, *"),
-                        b: String::from("the flower of manhood in the dwellings of Ham.")
+                        b: String::from("the flower of manhood in the dwellings of Ham."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 52,
                         a: String::from("He led out his people like sheep *"),
-                        b: String::from("and guided them in the wilderness like a flock.")
+                        b: String::from("and guided them in the wilderness like a flock."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 53,
                         a: String::from("He led them to safety, and they were not afraid; *"),
-                        b: String::from("but the sea overwhelmed their enemies.")
+                        b: String::from("but the sea overwhelmed their enemies."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 54,
                         a: String::from("He brought them to his holy land, *"),
-                        b: String::from("the mountain his right hand had won.")
+                        b: String::from("the mountain his right hand had won."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 55,
                         a: String::from("He drove out the Canaanites before them\nand apportioned an inheritance to them by lot; *"),
-                        b: String::from("he made the tribes of Israel to dwell in their tents.")
+                        b: String::from("he made the tribes of Israel to dwell in their tents."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 56,
                         a: String::from("But they tested the Most High God, and defied him, *"),
-                        b: String::from("and did not keep his commandments.")
+                        b: String::from("and did not keep his commandments."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 57,
                         a: String::from("They turned away and were disloyal like their fathers; *"),
-                        b: String::from("they were undependable like a warped bow.")
+                        b: String::from("they were undependable like a warped bow."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 58,
                         a: String::from("The grieved him with their hill-altars *"),
-                        b: String::from("they provoked his displeasure with their idols.")
+                        b: String::from("they provoked his displeasure with their idols."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 59,
                         a: String::from("When God heard this, he was angry *"),
-                        b: String::from("and utterly rejected Israel.")
+                        b: String::from("and utterly rejected Israel."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 60,
                         a: String::from("He forsook the shrine at Shiloh, *"),
-                        b: String::from("the tabernacle where he had lived among his people.")
+                        b: String::from("the tabernacle where he had lived among his people."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 61,
                         a: String::from("He delivered the ark into captivity, *"),
-                        b: String::from("his glory into the adversary’s hand.")
+                        b: String::from("his glory into the adversary’s hand."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 62,
                         a: String::from("He gave his people to the sword *"),
-                        b: String::from("and was angered against his inheritance.")
+                        b: String::from("and was angered against his inheritance."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 63,
                         a: String::from("The fire consumed their young men; *"),
-                        b: String::from("there were no wedding songs for their maidens.")
+                        b: String::from("there were no wedding songs for their maidens."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 64,
                         a: String::from("Their priests fell by the sword, *"),
-                        b: String::from("and their widows made no lamentation.")
+                        b: String::from("and their widows made no lamentation."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 65,
                         a: String::from("Then the LORD woke as though from sleep, *"),
-                        b: String::from("like a warrior refreshed with wine.")
+                        b: String::from("like a warrior refreshed with wine."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 66,
                         a: String::from("He struck his enemies on the backside *"),
-                        b: String::from("and put them to perpetual shame.")
+                        b: String::from("and put them to perpetual shame."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 67,
                         a: String::from("He rejected the tent of Joseph *"),
-                        b: String::from("and did not choose the tribe of Ephraim;")
+                        b: String::from("and did not choose the tribe of Ephraim;"),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 68,
                         a: String::from("He chose instead the tribe of Judah *"),
-                        b: String::from("and Mount Zion, which he loved.")
+                        b: String::from("and Mount Zion, which he loved."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 69,
                         a: String::from("He built his sanctuary like the heights of heaven, *"),
-                        b: String::from("like the earth which he founded for ever.")
+                        b: String::from("like the earth which he founded for ever."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 70,
                         a: String::from("He chose David his servant, *"),
-                        b: String::from("and took him away from the sheepfolds.")
+                        b: String::from("and took him away from the sheepfolds."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 71,
                         a: String::from("He brought him from following the ewes, *"),
-                        b: String::from("to be a shepherd over Jacob his people\nand over Israel his inheritance.")
+                        b: String::from("to be a shepherd over Jacob his people\nand over Israel his inheritance."),
+                        ..Default::default()
                     },
                   PsalmVerse {
                         number: 72,
                         a: String::from("So he shepherded them with a faithful and true heart *"),
-                        b: String::from("and guided them with the skillfulness of his hands.")
+                        b: String::from("and guided them with the skillfulness of his hands."),
+                        ..Default::default()
                     },
                 ]
               }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,45 +15,54 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("God takes his stand in the council of heaven; *"),
-                      b: String::from("he gives judgment in the midst of the gods:")
+                      b: String::from("he gives judgment in the midst of the gods:"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("“How long will you judge unjustly, *"),
-                      b: String::from("and show favor to the wicked?")
+                      b: String::from("and show favor to the wicked?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Save the weak and the orphan; *"),
-                      b: String::from("defend the humble and needy;")
+                      b: String::from("defend the humble and needy;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Rescue the weak and the poor; *"),
-                      b: String::from("deliver them from the power of the wicked.")
+                      b: String::from("deliver them from the power of the wicked."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("They do not know, neither do they understand;\nthey go about in darkness; *"),
-                      b: String::from("all the foundations of the earth are shaken.")
+                      b: String::from("all the foundations of the earth are shaken."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Now I say to you, ‘You are gods, *"),
-                      b: String::from("and all of you children of the Most High;")
+                      b: String::from("and all of you children of the Most High;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Nevertheless, you shall die like mortals, *"),
-                      b: String::from("and fall like any prince.’”")
+                      b: String::from("and fall like any prince.’”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Arise, O God, and rule the earth, *"),
-                      b: String::from("for you shall take all nations for your own.")
+                      b: String::from("for you shall take all nations for your own."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
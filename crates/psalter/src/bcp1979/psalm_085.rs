@@ -15,70 +15,84 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("You have been gracious to your land, O LORD, *"),
-                      b: String::from("you have restored the good fortune of Jacob.")
+                      b: String::from("you have restored the good fortune of Jacob."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("You have forgiven the iniquity of your people *"),
-                      b: String::from("and blotted out all their sins.")
+                      b: String::from("and blotted out all their sins."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("You have withdrawn all your fury *"),
-                      b: String::from("and turned yourself from your wrathful indignation.")
+                      b: String::from("and turned yourself from your wrathful indignation."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Restore us then, O God our Savior; *"),
-                      b: String::from("let your anger depart from us.")
+                      b: String::from("let your anger depart from us."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Will you be displeased with us for ever? *"),
-                      b: String::from("will you prolong your anger from age to age?")
+                      b: String::from("will you prolong your anger from age to age?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Will you not give us life again, *"),
-                      b: String::from("that your people may rejoice in you?")
+                      b: String::from("that your people may rejoice in you?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Show us your mercy, O LORD, *"),
-                      b: String::from("and grant us your salvation.")
+                      b: String::from("and grant us your salvation."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("I will listen to what the LORD God is saying, *"),
-                      b: String::from("for he is speaking peace to his faithful people\nand to those who turn their hearts to him.")
+                      b: String::from("for he is speaking peace to his faithful people\nand to those who turn their hearts to him."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Truly, his salvation is very near to those who fear him, *"),
-                      b: String::from("that his glory may dwell in our land.")
+                      b: String::from("that his glory may dwell in our land."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Mercy and truth have met together; *"),
-                      b: String::from("righteousness and peace have kissed each other.")
+                      b: String::from("righteousness and peace have kissed each other."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Truth shall spring up from the earth, *"),
-                      b: String::from("and righteousness shall look down from heaven.")
+                      b: String::from("and righteousness shall look down from heaven."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("The LORD will indeed grant prosperity, *"),
-                      b: String::from("and our land will yield its increase.")
+                      b: String::from("and our land will yield its increase."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("Righteousness shall go before him, *"),
-                      b: String::from("and peace shall be a pathway for his feet.")
+                      b: String::from("and peace shall be a pathway for his feet."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
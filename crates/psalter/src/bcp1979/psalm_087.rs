@@ -16,36 +16,43 @@ lazy_static! {
                     a: String::from("On the holy mountain stands the city he has founded; *"),
                     b: String::from(
                         "the LORD loves the gates of Zion\nmore than all the dwellings of Jacob."
-                    )
+                    ),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Glorious things are spoken of you, *"),
-                    b: String::from("O city of our God.")
+                    b: String::from("O city of our God."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("I count Egypt and Babylon among those who know me; *"),
                     b: String::from(
                         "behold Philistia, Tyre, and Ethiopia:\nin Zion were they born."
-                    )
+                    ),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("Of Zion it shall be said, “Everyone was born in her, *"),
-                    b: String::from("and the Most High himself shall sustain her.”")
+                    b: String::from("and the Most High himself shall sustain her.”"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("The LORD will record as he enrolls the peoples, *"),
-                    b: String::from("“These also were born there.”")
+                    b: String::from("“These also were born there.”"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("The singers and the dancers will say, *"),
-                    b: String::from("“All my fresh springs are in you.”")
+                    b: String::from("“All my fresh springs are in you.”"),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -15,85 +15,102 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("He who dwells in the shelter of the Most High, *"),
-                      b: String::from("abides under the shadow of the Almighty.")
+                      b: String::from("abides under the shadow of the Almighty."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("He shall say to the LORD,\n“You are my refuge and my stronghold, *"),
-                      b: String::from("my God in whom I put my trust.”")
+                      b: String::from("my God in whom I put my trust.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("He shall deliver you from the snare of the hunter *"),
-                      b: String::from("and from the deadly pestilence.")
+                      b: String::from("and from the deadly pestilence."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("He shall cover you with his pinions,\nand you shall find refuge under his wings; *"),
-                      b: String::from("his faithfulness shall be a shield and buckler.")
+                      b: String::from("his faithfulness shall be a shield and buckler."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("You shall not be afraid of any terror by night, *"),
-                      b: String::from("nor of the arrow that flies by day;")
+                      b: String::from("nor of the arrow that flies by day;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Of the plague that stalks in the darkness, *"),
-                      b: String::from("nor of the sickness that lays waste at mid-day.")
+                      b: String::from("nor of the sickness that lays waste at mid-day."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("A thousand shall fall at your side\nand ten thousand at your right hand, *"),
-                      b: String::from("but it shall not come near you.")
+                      b: String::from("but it shall not come near you."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Your eyes have only to behold *"),
-                      b: String::from("to see the reward of the wicked.")
+                      b: String::from("to see the reward of the wicked."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Because you have made the LORD your refuge, *"),
-                      b: String::from("and the Most High your habitation,")
+                      b: String::from("and the Most High your habitation,"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("There shall no evil happen to you, *"),
-                      b: String::from("neither shall any plague come near your dwelling.")
+                      b: String::from("neither shall any plague come near your dwelling."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("For he shall give his angels charge over you, *"),
-                      b: String::from("to keep you in all your ways.")
+                      b: String::from("to keep you in all your ways."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("They shall bear you in their hands, *"),
-                      b: String::from("lest you dash your foot against a stone.")
+                      b: String::from("lest you dash your foot against a stone."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("You shall tread upon the lion and the adder; *"),
-                      b: String::from("you shall trample the young lion and the serpent under your feet.")
+                      b: String::from("you shall trample the young lion and the serpent under your feet."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("Because he is bound to me in love,\ntherefore will I deliver him; *"),
-                      b: String::from("I will protect him, because he knows my Name.")
+                      b: String::from("I will protect him, because he knows my Name."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("He shall call upon me, and I will answer him; *"),
-                      b: String::from("I am with him in trouble;\nI will rescue him and bring him to honor.")
+                      b: String::from("I am with him in trouble;\nI will rescue him and bring him to honor."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("With long life will I satisfy him, *"),
-                      b: String::from("and show him my salvation.")
+                      b: String::from("and show him my salvation."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,75 +15,90 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("It is a good thing to give thanks to the LORD, *"),
-                      b: String::from("and to sing praises to your Name, O Most High;")
+                      b: String::from("and to sing praises to your Name, O Most High;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("To tell of your loving-kindness early in the morning *"),
-                      b: String::from("and of your faithfulness in the night season;")
+                      b: String::from("and of your faithfulness in the night season;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("On the psaltery, and on the lyre, *"),
-                      b: String::from("and to the melody of the harp.")
+                      b: String::from("and to the melody of the harp."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("For you have made me glad by your acts, O LORD; *"),
-                      b: String::from("and I shout for joy because of the works of your hands.")
+                      b: String::from("and I shout for joy because of the works of your hands."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("LORD, how great are your works! *"),
-                      b: String::from("your thoughts are very deep.")
+                      b: String::from("your thoughts are very deep."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("The dullard does not know,\nnor does the fool understand, *"),
-                      b: String::from("that though the wicked grow like weeds,\nand all the workers of iniquity flourish,")
+                      b: String::from("that though the wicked grow like weeds,\nand all the workers of iniquity flourish,"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("They flourish only to be destroyed for ever; *"),
-                      b: String::from("but you, O LORD, are exalted for evermore.")
+                      b: String::from("but you, O LORD, are exalted for evermore."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("For lo, your enemies, O LORD,\nlo, your enemies shall perish, *"),
-                      b: String::from("and all the workers of iniquity shall be scattered.")
+                      b: String::from("and all the workers of iniquity shall be scattered."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("But my horn you have exalted like the horns of wild bulls; *"),
-                      b: String::from("I am anointed with fresh oil.")
+                      b: String::from("I am anointed with fresh oil."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("My eyes also gloat over my enemies, *"),
-                      b: String::from("and my ears rejoice to hear the doom of the wicked who rise up against me.")
+                      b: String::from("and my ears rejoice to hear the doom of the wicked who rise up against me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("The righteous shall flourish like a palm tree, *"),
-                      b: String::from("and shall spread abroad like a cedar of Lebanon.")
+                      b: String::from("and shall spread abroad like a cedar of Lebanon."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("Those who are planted in the house of the LORD *"),
-                      b: String::from("shall flourish in the courts of our God;")
+                      b: String::from("shall flourish in the courts of our God;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("They shall still bear fruit in old age; *"),
-                      b: String::from("they shall be green and succulent;")
+                      b: String::from("they shall be green and succulent;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("That they may show how upright the LORD is, *"),
-                      b: String::from("my Rock, in whom there is no fault.")
+                      b: String::from("my Rock, in whom there is no fault."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,35 +15,42 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("The LORD is King;\nhe has put on splendid apparel; *"),
-                      b: String::from("the LORD has put on his apparel\nand girded himself with strength.")
+                      b: String::from("the LORD has put on his apparel\nand girded himself with strength."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("He has made the whole world so sure *"),
-                      b: String::from("that it cannot be moved;")
+                      b: String::from("that it cannot be moved;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Ever since the world began, your throne has been established; *"),
-                      b: String::from("you are from everlasting.")
+                      b: String::from("you are from everlasting."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("The waters have lifted up, O LORD,\nthe waters have lifted up their voice; *"),
-                      b: String::from("the waters have lifted up their pounding waves.")
+                      b: String::from("the waters have lifted up their pounding waves."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Mightier than the sound of many waters,\nmightier than the breakers of the sea, *"),
-                      b: String::from("mightier is the LORD who dwells on high.")
+                      b: String::from("mightier is the LORD who dwells on high."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Your testimonies are very sure, *"),
-                      b: String::from("and holiness adorns your house, O LORD,\nfor ever and for evermore.")
+                      b: String::from("and holiness adorns your house, O LORD,\nfor ever and for evermore."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
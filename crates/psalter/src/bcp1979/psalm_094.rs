@@ -15,120 +15,144 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("O LORD God of vengeance, *"),
-                      b: String::from("O God of vengeance, show yourself.")
+                      b: String::from("O God of vengeance, show yourself."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Rise up, O Judge of the world; *"),
-                      b: String::from("give the arrogant their just deserts.")
+                      b: String::from("give the arrogant their just deserts."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("How long shall the wicked, O LORD, *"),
-                      b: String::from("how long shall the wicked triumph?")
+                      b: String::from("how long shall the wicked triumph?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("They bluster in their insolence; *"),
-                      b: String::from("all evildoers are full of boasting.")
+                      b: String::from("all evildoers are full of boasting."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("They crush your people, O LORD, *"),
-                      b: String::from("and afflict your chosen nation.")
+                      b: String::from("and afflict your chosen nation."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("They murder the widow and the stranger *"),
-                      b: String::from("and put the orphans to death.")
+                      b: String::from("and put the orphans to death."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Yet they say, “The LORD does not see, *"),
-                      b: String::from("the God of Jacob takes no notice.”")
+                      b: String::from("the God of Jacob takes no notice.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Consider well, you dullards among the people; *"),
-                      b: String::from("when will you fools understand?")
+                      b: String::from("when will you fools understand?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("He that planted the ear, does he not hear? *"),
-                      b: String::from("he that formed the eye, does he not see?")
+                      b: String::from("he that formed the eye, does he not see?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("He who admonishes the nations, will he not punish? *"),
-                      b: String::from("he who teaches all the world, has he no knowledge?")
+                      b: String::from("he who teaches all the world, has he no knowledge?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("The LORD knows our human thoughts; *"),
-                      b: String::from("how like a puff of wind they are.")
+                      b: String::from("how like a puff of wind they are."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("Happy are they whom you instruct, O Lord! *"),
-                      b: String::from("whom you teach out of your law;")
+                      b: String::from("whom you teach out of your law;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("To give them rest in evil days, *"),
-                      b: String::from("until a pit is dug for the wicked.")
+                      b: String::from("until a pit is dug for the wicked."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("For the LORD will not abandon his people, *"),
-                      b: String::from("nor will he forsake his own.")
+                      b: String::from("nor will he forsake his own."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("For judgment will again be just, *"),
-                      b: String::from("and all the true of heart will follow it.")
+                      b: String::from("and all the true of heart will follow it."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("Who rose up for me against the wicked? *"),
-                      b: String::from("who took my part against the evildoers?")
+                      b: String::from("who took my part against the evildoers?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("If the LORD had not come to my help, *"),
-                      b: String::from("I should soon have dwelt in the land of silence.")
+                      b: String::from("I should soon have dwelt in the land of silence."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("As often as I said, “My foot has slipped,” *"),
-                      b: String::from("your love, O LORD, upheld me.")
+                      b: String::from("your love, O LORD, upheld me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("When many cares fill my mind, *"),
-                      b: String::from("your consolations cheer my soul.")
+                      b: String::from("your consolations cheer my soul."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("Can a corrupt tribunal have any part with you, *"),
-                      b: String::from("one which frames evil into law?")
+                      b: String::from("one which frames evil into law?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 21,
                       a: String::from("They conspire against the life of the just *"),
-                      b: String::from("and condemn the innocent to death.")
+                      b: String::from("and condemn the innocent to death."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 22,
                       a: String::from("But the LORD has become my stronghold, *"),
-                      b: String::from("and my God the rock of my trust.")
+                      b: String::from("and my God the rock of my trust."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 23,
                       a: String::from("He will turn their wickedness back upon them\nand destroy them in their own malice; *"),
-                      b: String::from("the LORD our God will destroy them.")
+                      b: String::from("the LORD our God will destroy them."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,70 +15,84 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Sing to the LORD a new song; *"),
-                      b: String::from("sing to the LORD, all the whole earth.")
+                      b: String::from("sing to the LORD, all the whole earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Sing to the LORD and bless his Name; *"),
-                      b: String::from("proclaim the good news of his salvation from day to day.")
+                      b: String::from("proclaim the good news of his salvation from day to day."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Declare his glory among the nations *"),
-                      b: String::from("and his wonders among all peoples.")
+                      b: String::from("and his wonders among all peoples."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("For great is the LORD and greatly to be praised; *"),
-                      b: String::from("he is more to be feared than all gods.")
+                      b: String::from("he is more to be feared than all gods."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("As for all the gods of the nations, they are but idols; *"),
-                      b: String::from("but it is the LORD who made the heavens.")
+                      b: String::from("but it is the LORD who made the heavens."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Oh, the majesty and magnificence of his presence! *"),
-                      b: String::from("Oh, the power and the splendor of his sanctuary!")
+                      b: String::from("Oh, the power and the splendor of his sanctuary!"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Ascribe to the LORD, you families of the peoples; *"),
-                      b: String::from("ascribe to the LORD honor and power.")
+                      b: String::from("ascribe to the LORD honor and power."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Ascribe to the LORD the honor due his Name; *"),
-                      b: String::from("bring offerings and come into his courts.")
+                      b: String::from("bring offerings and come into his courts."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Worship the LORD in the beauty of holiness; *"),
-                      b: String::from("let the whole earth tremble before him.")
+                      b: String::from("let the whole earth tremble before him."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Tell it out among the nations: “The LORD is King! *"),
-                      b: String::from("he has made the world so firm that it cannot be moved;\nhe will judge the peoples with equity.”")
+                      b: String::from("he has made the world so firm that it cannot be moved;\nhe will judge the peoples with equity.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Let the heavens rejoice, and let the earth be glad;\nlet the sea thunder and all that is in it; *"),
-                      b: String::from("let the field be joyful and all that is therein.")
+                      b: String::from("let the field be joyful and all that is therein."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("Then shall all the trees of the wood shout for joy\nbefore the LORD when he comes, *"),
-                      b: String::from("when he comes to judge the earth.")
+                      b: String::from("when he comes to judge the earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("He will judge the world with righteousness *"),
-                      b: String::from("and the peoples with his truth.")
+                      b: String::from("and the peoples with his truth."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
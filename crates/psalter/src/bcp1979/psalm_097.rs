@@ -15,65 +15,78 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("The LORD is King;\nlet the earth rejoice; *"),
-                      b: String::from("let the multitude of the isles be glad.")
+                      b: String::from("let the multitude of the isles be glad."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Clouds and darkness are round about him, *"),
-                      b: String::from("righteousness and justice are the foundations of his throne.")
+                      b: String::from("righteousness and justice are the foundations of his throne."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("A fire goes before him *"),
-                      b: String::from("and burns up his enemies on every side.")
+                      b: String::from("and burns up his enemies on every side."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("His lightnings light up the world; *"),
-                      b: String::from("the earth sees it and is afraid.")
+                      b: String::from("the earth sees it and is afraid."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("The mountains melt like wax at the presence of the LORD, *"),
-                      b: String::from("at the presence of the Lord of the whole earth.")
+                      b: String::from("at the presence of the Lord of the whole earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("The heavens declare his righteousness, *"),
-                      b: String::from("and all the peoples see his glory.")
+                      b: String::from("and all the peoples see his glory."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Confounded be all who worship carved images\nand delight in false gods! *"),
-                      b: String::from("Bow down before him, all you gods.")
+                      b: String::from("Bow down before him, all you gods."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Zion hears and is glad, and the cities of Judah rejoice, *"),
-                      b: String::from("because of your judgments, O LORD.")
+                      b: String::from("because of your judgments, O LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("For you are the LORD,\nmost high over all the earth; *"),
-                      b: String::from("you are exalted far above all gods.")
+                      b: String::from("you are exalted far above all gods."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("The LORD loves those who hate evil; *"),
-                      b: String::from("he preserves the lives of his saints\nand delivers them from the hand of the wicked.")
+                      b: String::from("he preserves the lives of his saints\nand delivers them from the hand of the wicked."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Light has sprung up for the righteous, *"),
-                      b: String::from("and joyful gladness for those who are truehearted.")
+                      b: String::from("and joyful gladness for those who are truehearted."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("Rejoice in the LORD, you righteous, *"),
-                      b: String::from("and give thanks to his holy Name.")
+                      b: String::from("and give thanks to his holy Name."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -16,57 +16,68 @@ lazy_static! {
                             PsalmVerse {
                       number: 1,
                       a: String::from("Sing to the LORD a new song, *"),
-                      b: String::from("for he has done marvelous things.")
+                      b: String::from("for he has done marvelous things."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("With his right hand and his holy arm *"),
-                      b: String::from("has he won for himself the victory.")
+                      b: String::from("has he won for himself the victory."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("The LORD has made known his victory; *"),
-                      b: String::from("his righteousness has he openly shown in the sight of the nations.")
+                      b: String::from("his righteousness has he openly shown in the sight of the nations."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("He remembers his mercy and faithfulness to the house of Israel, *"),
-                      b: String::from("and all the ends of the earth have seen the victory of our God.")
+                      b: String::from("and all the ends of the earth have seen the victory of our God."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Shout with joy to the LORD, all you lands; *"),
-                      b: String::from("lift up your voice, rejoice, and sing.")
+                      b: String::from("lift up your voice, rejoice, and sing."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Sing to the LORD with the harp, *"),
-                      b: String::from("with the harp and the voice of song.")
+                      b: String::from("with the harp and the voice of song."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("With trumpets and the sound of the horn *"),
-                      b: String::from("shout with joy before the King, the LORD.")
+                      b: String::from("shout with joy before the King, the LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Let the sea make a noise and all that is in it, *"),
-                      b: String::from("the lands and those who dwell therein.")
+                      b: String::from("the lands and those who dwell therein."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Let the rivers clap their hands, *"),
-                      b: String::from("and let the hills ring out with joy before the LORD,\nwhen he comes to judge the earth.")
+                      b: String::from("and let the hills ring out with joy before the LORD,\nwhen he comes to judge the earth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("In righteousness shall he judge the world *"),
-                      b: String::from("and the peoples with equity.")
+                      b: String::from("and the peoples with equity."),
+                      ..Default::default()
                   },
               ]
             }
 
-        ]
+        ],
+        ..Default::default()
 
     };
 }
@@ -16,52 +16,62 @@ lazy_static! {
                             PsalmVerse {
                       number: 1,
                       a: String::from("The LORD is King;\nlet the people tremble; *"),
-                      b: String::from("he is enthroned upon the cherubim;\nlet the earth shake.")
+                      b: String::from("he is enthroned upon the cherubim;\nlet the earth shake."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("The LORD is great in Zion; *"),
-                      b: String::from("he is high above all peoples.")
+                      b: String::from("he is high above all peoples."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Let them confess his Name, which is great and awesome; *"),
-                      b: String::from("he is the Holy One.")
+                      b: String::from("he is the Holy One."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("“O mighty King, lover of justice,\nyou have established equity; *"),
-                      b: String::from("you have executed justice and righteousness in Jacob.”")
+                      b: String::from("you have executed justice and righteousness in Jacob.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Proclaim the greatness of the LORD our God\nand fall down before his footstool; *"),
-                      b: String::from("he is the Holy One.")
+                      b: String::from("he is the Holy One."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Moses and Aaron among his priests,\nand Samuel among those who call upon his Name, *"),
-                      b: String::from("they called upon the LORD, and he answered them.")
+                      b: String::from("they called upon the LORD, and he answered them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("He spoke to them out of the pillar of cloud; *"),
-                      b: String::from("they kept his testimonies and the decree that he gave them.")
+                      b: String::from("they kept his testimonies and the decree that he gave them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("“O LORD our God, you answered them indeed; *"),
-                      b: String::from("you were a God who forgave them,\nyet punished them for their evil deeds.”")
+                      b: String::from("you were a God who forgave them,\nyet punished them for their evil deeds.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Proclaim the greatness of the LORD our God\nand worship him upon his holy hill; *"),
-                      b: String::from("for the LORD our God is the Holy One.")
+                      b: String::from("for the LORD our God is the Holy One."),
+                      ..Default::default()
                   },
               ]
             }
 
-        ]
+        ],
+        ..Default::default()
 
     };
 }
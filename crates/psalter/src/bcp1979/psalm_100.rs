@@ -15,25 +15,30 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Be joyful in the LORD, all you lands; *"),
-                      b: String::from("serve the LORD with gladness\nand come before his presence with a song.")
+                      b: String::from("serve the LORD with gladness\nand come before his presence with a song."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Know this: The LORD himself is God; *"),
-                      b: String::from("he himself has made us, and we are his;\nwe are his people and the sheep of his pasture.")
+                      b: String::from("he himself has made us, and we are his;\nwe are his people and the sheep of his pasture."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Enter his gates with thanksgiving;\ngo into his courts with praise; *"),
-                      b: String::from("give thanks to him and call upon his Name.")
+                      b: String::from("give thanks to him and call upon his Name."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("For the LORD is good;\nhis mercy is everlasting; *"),
-                      b: String::from("and his faithfulness endures from age to age.")
+                      b: String::from("and his faithfulness endures from age to age."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
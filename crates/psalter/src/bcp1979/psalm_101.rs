@@ -15,45 +15,54 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("I will sing of mercy and justice; *"),
-                      b: String::from("to you, O LORD, will I sing praises.")
+                      b: String::from("to you, O LORD, will I sing praises."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("I will strive to follow a blameless course;\noh, when will you come to me? *"),
-                      b: String::from("I will walk with sincerity of heart within my house.")
+                      b: String::from("I will walk with sincerity of heart within my house."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("I will set no worthless thing before my eyes; *"),
-                      b: String::from("I hate the doers of evil deeds;\nthey shall not remain with me.")
+                      b: String::from("I hate the doers of evil deeds;\nthey shall not remain with me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("A crooked heart shall be far from me; *"),
-                      b: String::from("I will not know evil.")
+                      b: String::from("I will not know evil."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Those who in secret slander their neighbors I will destroy; *"),
-                      b: String::from("those who have a haughty look and a proud heart I cannot abide.")
+                      b: String::from("those who have a haughty look and a proud heart I cannot abide."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("My eyes are upon the faithful in the land, that they may dwell with me, *"),
-                      b: String::from("and only those who lead a blameless life shall be my servants.")
+                      b: String::from("and only those who lead a blameless life shall be my servants."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Those who act deceitfully shall not dwell in my house, *"),
-                      b: String::from("and those who tell lies shall not continue in my sight.")
+                      b: String::from("and those who tell lies shall not continue in my sight."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("I will soon destroy all the wicked in the land, *"),
-                      b: String::from("that I may root out all evildoers from the city of the LORD.")
+                      b: String::from("that I may root out all evildoers from the city of the LORD."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
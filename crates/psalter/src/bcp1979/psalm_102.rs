@@ -15,145 +15,174 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("LORD, hear my prayer, and let my cry come before you; *"),
-                      b: String::from("hide not your face from me in the day of my trouble.")
+                      b: String::from("hide not your face from me in the day of my trouble."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Incline your ear to me; *"),
-                      b: String::from("when I call, make haste to answer me,")
+                      b: String::from("when I call, make haste to answer me,"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("For my days drift away like smoke, *"),
-                      b: String::from("and my bones are hot as burning coals.")
+                      b: String::from("and my bones are hot as burning coals."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("My heart is smitten like grass and withered, *"),
-                      b: String::from("so that I forget to eat my bread.")
+                      b: String::from("so that I forget to eat my bread."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Because of the voice of my groaning *"),
-                      b: String::from("I am but skin and bones.")
+                      b: String::from("I am but skin and bones."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("I have become like a vulture in the wilderness, *"),
-                      b: String::from("like an owl among the ruins.")
+                      b: String::from("like an owl among the ruins."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("I lie awake and groan; *"),
-                      b: String::from("I am like a sparrow, lonely on a house-top.")
+                      b: String::from("I am like a sparrow, lonely on a house-top."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("My enemies revile me all day long, *"),
-                      b: String::from("and those who scoff at me have taken an oath against me.")
+                      b: String::from("and those who scoff at me have taken an oath against me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("For I have eaten ashes for bread *"),
-                      b: String::from("and mingled my drink with weeping.")
+                      b: String::from("and mingled my drink with weeping."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Because of your indignation and wrath *"),
-                      b: String::from("you have lifted me up and thrown me away.")
+                      b: String::from("you have lifted me up and thrown me away."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("My days pass away like a shadow, *"),
-                      b: String::from("and I wither like the grass.")
+                      b: String::from("and I wither like the grass."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("But you, O LORD, endure for ever, *"),
-                      b: String::from("and your Name from age to age.")
+                      b: String::from("and your Name from age to age."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("You will arise and have compassion on Zion,\nfor it is time to have mercy upon her; *"),
-                      b: String::from("indeed, the appointed time has come.")
+                      b: String::from("indeed, the appointed time has come."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("For your servants love her very rubble, *"),
-                      b: String::from("and are moved to pity even for her dust.")
+                      b: String::from("and are moved to pity even for her dust."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("The nations shall fear your Name, O LORD, *"),
-                      b: String::from("and all the kings of the earth your glory.")
+                      b: String::from("and all the kings of the earth your glory."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("For the LORD will build up Zion, *"),
-                      b: String::from("and his glory will appear.")
+                      b: String::from("and his glory will appear."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("He will look with favor on the prayer of the homeless; *"),
-                      b: String::from("he will not despise their plea.")
+                      b: String::from("he will not despise their plea."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("Let this be written for a future generation, *"),
-                      b: String::from("so that a people yet unborn may praise the LORD.")
+                      b: String::from("so that a people yet unborn may praise the LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("For the LORD looked down from his holy place on high; *"),
-                      b: String::from("from the heavens he beheld the earth;")
+                      b: String::from("from the heavens he beheld the earth;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("That he might hear the groan of the captive *"),
-                      b: String::from("and set free those condemned to die;")
+                      b: String::from("and set free those condemned to die;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 21,
                       a: String::from("That they may declare in Zion the Name of the LORD, *"),
-                      b: String::from("and his praise in Jerusalem;")
+                      b: String::from("and his praise in Jerusalem;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 22,
                       a: String::from("When the peoples are gathered together, *"),
-                      b: String::from("and the kingdoms also, to serve the LORD.")
+                      b: String::from("and the kingdoms also, to serve the LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 23,
                       a: String::from("He has brought down my strength before my time; *"),
-                      b: String::from("he has shortened the number of my days;")
+                      b: String::from("he has shortened the number of my days;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 24,
                       a: String::from("And I said, “O my God,\ndo not take me away in the midst of my days; *"),
-                      b: String::from("your years endure throughout all generations.")
+                      b: String::from("your years endure throughout all generations."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 25,
                       a: String::from("In the beginning, O LORD, you laid the foundations of the earth, *"),
-                      b: String::from("and the heavens are the work of your hands;")
+                      b: String::from("and the heavens are the work of your hands;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 26,
                       a: String::from("They shall perish, but you will endure;\nthey all shall wear out like a garment; *"),
-                      b: String::from("as clothing you will change them,\nand they shall be changed;")
+                      b: String::from("as clothing you will change them,\nand they shall be changed;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 27,
                       a: String::from("But you are always the same, *"),
-                      b: String::from("and your years will never end.")
+                      b: String::from("and your years will never end."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 28,
                       a: String::from("The children of your servants shall continue, *"),
-                      b: String::from("and their offspring shall stand fast in your sight.”")
+                      b: String::from("and their offspring shall stand fast in your sight.”"),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,115 +15,138 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Bless the LORD, O my soul, *"),
-                      b: String::from("and all that is within me, bless his holy Name.")
+                      b: String::from("and all that is within me, bless his holy Name."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Bless the LORD, O my soul, *"),
-                      b: String::from("and forget not all his benefits.")
+                      b: String::from("and forget not all his benefits."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("He forgives all your sins *"),
-                      b: String::from("and heals all your infirmities;")
+                      b: String::from("and heals all your infirmities;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("He redeems your life from the grave *"),
-                      b: String::from("and crowns you with mercy and loving-kindness;")
+                      b: String::from("and crowns you with mercy and loving-kindness;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("He satisfies you with good things, *"),
-                      b: String::from("and your youth is renewed like an eagle’s.")
+                      b: String::from("and your youth is renewed like an eagle’s."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("The LORD executes righteousness *"),
-                      b: String::from("and judgment for all who are oppressed.")
+                      b: String::from("and judgment for all who are oppressed."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("He made his ways known to Moses *"),
-                      b: String::from("and his works to the children of Israel.")
+                      b: String::from("and his works to the children of Israel."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("The LORD is full of compassion and mercy, *"),
-                      b: String::from("slow to anger and of great kindness.")
+                      b: String::from("slow to anger and of great kindness."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("He will not always accuse us, *"),
-                      b: String::from("nor will he keep his anger for ever.")
+                      b: String::from("nor will he keep his anger for ever."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("He has not dealt with us according to our sins, *"),
-                      b: String::from("nor rewarded us according to our wickedness.")
+                      b: String::from("nor rewarded us according to our wickedness."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("For as the heavens are high above the earth, *"),
-                      b: String::from("so is his mercy great upon those who fear him.")
+                      b: String::from("so is his mercy great upon those who fear him."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("As far as the east is from the west, *"),
-                      b: String::from("so far has he removed our sins from us.")
+                      b: String::from("so far has he removed our sins from us."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("As a father cares for his children, *"),
-                      b: String::from("so does the LORD care for those who fear him.")
+                      b: String::from("so does the LORD care for those who fear him."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("For he himself knows whereof we are made; *"),
-                      b: String::from("he remembers that we are but dust.")
+                      b: String::from("he remembers that we are but dust."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("Our days are like the grass; *"),
-                      b: String::from("we flourish like a flower of the field;")
+                      b: String::from("we flourish like a flower of the field;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("When the wind goes over it, it is gone, *"),
-                      b: String::from("and its place shall know it no more.")
+                      b: String::from("and its place shall know it no more."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("But the merciful goodness of the LORD endures for ever on those who fear him, *"),
-                      b: String::from("and his righteousness on children’s children;")
+                      b: String::from("and his righteousness on children’s children;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("On those who keep his covenant *"),
-                      b: String::from("and remember his commandments and do them.")
+                      b: String::from("and remember his commandments and do them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("The LORD has set his throne in heaven, *"),
-                      b: String::from("and his kingship has dominion over all.")
+                      b: String::from("and his kingship has dominion over all."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("Bless the LORD, you angels of his,\nyou mighty ones who do his bidding, *"),
-                      b: String::from("and hearken to the voice of his word.")
+                      b: String::from("and hearken to the voice of his word."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 21,
                       a: String::from("Bless the LORD, all you his hosts, *"),
-                      b: String::from("you ministers of his who do his will.")
+                      b: String::from("you ministers of his who do his will."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 22,
                       a: String::from("Bless the LORD, all you works of his,\nin all places of his dominion; *"),
-                      b: String::from("bless the LORD, O my soul.")
+                      b: String::from("bless the LORD, O my soul."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
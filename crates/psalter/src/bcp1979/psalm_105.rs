@@ -15,112 +15,134 @@ lazy_static! {
                     PsalmVerse {
                         number: 1,
                         a: String::from("Give thanks to the LORD and call upon his Name; *"),
-                        b: String::from("make known his deeds among the peoples.")
+                        b: String::from("make known his deeds among the peoples."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 2,
                         a: String::from("Sing to him, sing praises to him, *"),
-                        b: String::from("and speak of all his marvelous works.")
+                        b: String::from("and speak of all his marvelous works."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 3,
                         a: String::from("Glory in his holy Name; *"),
-                        b: String::from("let the hearts of those who seek the LORD rejoice.")
+                        b: String::from("let the hearts of those who seek the LORD rejoice."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 4,
                         a: String::from("Search for the LORD and his strength; *"),
-                        b: String::from("continually seek his face.")
+                        b: String::from("continually seek his face."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 5,
                         a: String::from("Remember the marvels he has done, *"),
-                        b: String::from("his wonders and the judgments of his mouth,")
+                        b: String::from("his wonders and the judgments of his mouth,"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 6,
                         a: String::from("O offspring of Abraham his servant, *"),
-                        b: String::from("O children of Jacob his chosen.")
+                        b: String::from("O children of Jacob his chosen."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 7,
                         a: String::from("He is the LORD our God; *"),
-                        b: String::from("his judgments prevail in all the world.")
+                        b: String::from("his judgments prevail in all the world."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 8,
                         a: String::from("He has always been mindful of his covenant, *"),
-                        b: String::from("the promise he made for a thousand generations:")
+                        b: String::from("the promise he made for a thousand generations:"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 9,
                         a: String::from("The covenant he made with Abraham, *"),
-                        b: String::from("the oath that he swore to Isaac,")
+                        b: String::from("the oath that he swore to Isaac,"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 10,
                         a: String::from("Which he established as a statute for Jacob, *"),
-                        b: String::from("an everlasting covenant for Israel,")
+                        b: String::from("an everlasting covenant for Israel,"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 11,
                         a: String::from("Saying, “To you will I give the land of Canaan *"),
-                        b: String::from("to be your allotted inheritance.”")
+                        b: String::from("to be your allotted inheritance.”"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 12,
                         a: String::from("When they were few in number, *"),
-                        b: String::from("of little account, and sojourners in the land,")
+                        b: String::from("of little account, and sojourners in the land,"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 13,
                         a: String::from("Wandering from nation to nation *"),
-                        b: String::from("and from one kingdom to another,")
+                        b: String::from("and from one kingdom to another,"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 14,
                         a: String::from("He let no one oppress them *"),
-                        b: String::from("and rebuked kings for their sake,")
+                        b: String::from("and rebuked kings for their sake,"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 15,
                         a: String::from("Saying, “Do not touch my anointed *"),
-                        b: String::from("and do my prophets no harm.”")
+                        b: String::from("and do my prophets no harm.”"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 16,
                         a: String::from("Then he called for a famine in the land *"),
-                        b: String::from("and destroyed the supply of bread.")
+                        b: String::from("and destroyed the supply of bread."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 17,
                         a: String::from("He sent a man before them, *"),
-                        b: String::from("Joseph, who was sold as a slave.")
+                        b: String::from("Joseph, who was sold as a slave."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 18,
                         a: String::from("They bruised his feet in fetters; *"),
-                        b: String::from("his neck they put in an iron collar.")
+                        b: String::from("his neck they put in an iron collar."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 19,
                         a: String::from("Until his prediction came to pass, *"),
-                        b: String::from("the word of the LORD tested him.")
+                        b: String::from("the word of the LORD tested him."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 20,
                         a: String::from("The king sent and released him; *"),
-                        b: String::from("the ruler of the peoples set him free.")
+                        b: String::from("the ruler of the peoples set him free."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 21,
                         a: String::from("He set him as a master over his household, *"),
-                        b: String::from("as a ruler over all his possessions,")
+                        b: String::from("as a ruler over all his possessions,"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 22,
                         a: String::from("To instruct his princes according to his will *"),
-                        b: String::from("and to teach his elders wisdom.")
+                        b: String::from("and to teach his elders wisdom."),
+                        ..Default::default()
                     },
                 ]
             },
@@ -135,120 +157,144 @@ lazy_static! {
                     PsalmVerse {
                         number: 23,
                         a: String::from("Israel came into Egypt, *"),
-                        b: String::from("and Jacob became a sojourner in the land of Ham.")
+                        b: String::from("and Jacob became a sojourner in the land of Ham."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 24,
                         a: String::from("The LORD made his people exceedingly fruitful; *"),
-                        b: String::from("he made them stronger than their enemies;")
+                        b: String::from("he made them stronger than their enemies;"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 25,
                         a: String::from("Whose heart he turned, so that they hated his people, *"),
-                        b: String::from("and dealt unjustly with his servants.")
+                        b: String::from("and dealt unjustly with his servants."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 26,
                         a: String::from("He sent Moses his servant, *"),
-                        b: String::from("and Aaron whom he had chosen.")
+                        b: String::from("and Aaron whom he had chosen."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 27,
                         a: String::from("They worked his signs among them, *"),
-                        b: String::from("and portents in the land of Ham.")
+                        b: String::from("and portents in the land of Ham."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 28,
                         a: String::from("He sent darkness, and it grew dark; *"),
-                        b: String::from("but the Egyptians rebelled against his words.")
+                        b: String::from("but the Egyptians rebelled against his words."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 29,
                         a: String::from("He turned their waters into blood *"),
-                        b: String::from("and caused their fish to die.")
+                        b: String::from("and caused their fish to die."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 30,
                         a: String::from("Their land was overrun by frogs, *"),
-                        b: String::from("in the very chambers of their kings.")
+                        b: String::from("in the very chambers of their kings."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 31,
                         a: String::from("He spoke, and there came swarms of insects *"),
-                        b: String::from("and gnats within all their borders.")
+                        b: String::from("and gnats within all their borders."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 32,
                         a: String::from("He gave them hailstones instead of rain, *"),
-                        b: String::from("and flames of fire throughout their land.")
+                        b: String::from("and flames of fire throughout their land."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 33,
                         a: String::from("He blasted their vines and their fig trees *"),
-                        b: String::from("and shattered every tree in their country.")
+                        b: String::from("and shattered every tree in their country."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 34,
                         a: String::from("He spoke, and the locust came, *"),
-                        b: String::from("and young locusts without number,")
+                        b: String::from("and young locusts without number,"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 35,
                         a: String::from("Which ate up all the green plants in their land *"),
-                        b: String::from("and devoured the fruit of their soil.")
+                        b: String::from("and devoured the fruit of their soil."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 36,
                         a: String::from("He struck down the firstborn of their land, *"),
-                        b: String::from("the firstfruits of all their strength.")
+                        b: String::from("the firstfruits of all their strength."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 37,
                         a: String::from("He led out his people with silver and gold; *"),
-                        b: String::from("in all their tribes there was not one that stumbled.")
+                        b: String::from("in all their tribes there was not one that stumbled."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 38,
                         a: String::from("Egypt was glad of their going, *"),
-                        b: String::from("because they were afraid of them.")
+                        b: String::from("because they were afraid of them."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 39,
                         a: String::from("He spread out a cloud for a covering *"),
-                        b: String::from("and a fire to give light in the night season.")
+                        b: String::from("and a fire to give light in the night season."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 40,
                         a: String::from("They asked, and quails appeared, *"),
-                        b: String::from("and he satisfied them with bread from heaven.")
+                        b: String::from("and he satisfied them with bread from heaven."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 41,
                         a: String::from("He opened the rock, and water flowed, *"),
-                        b: String::from("so the river ran in the dry places.")
+                        b: String::from("so the river ran in the dry places."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 42,
                         a: String::from("For God remembered his holy word *"),
-                        b: String::from("and Abraham his servant.")
+                        b: String::from("and Abraham his servant."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 43,
                         a: String::from("So he led forth his people with gladness, *"),
-                        b: String::from("his chosen with shouts of joy.")
+                        b: String::from("his chosen with shouts of joy."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 44,
                         a: String::from("He gave his people the lands of the nations, *"),
-                        b: String::from("and they took the fruit of others’ toil,")
+                        b: String::from("and they took the fruit of others’ toil,"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 45,
                         a: String::from("That they might keep his statutes *"),
-                        b: String::from("and observe his laws.\nHallelujah!")
+                        b: String::from("and observe his laws.\nHallelujah!"),
+                        ..Default::default()
                     },
                 ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -15,166 +15,198 @@ lazy_static! {
                     PsalmVerse {
                         number: 1,
                         a: String::from("Give thanks to the LORD, for he is good, *"),
-                        b: String::from("and his mercy endures for ever.")
+                        b: String::from("and his mercy endures for ever."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 2,
                         a: String::from("Let all those whom the LORD has redeemed proclaim *"),
-                        b: String::from("that he redeemed them from the hand of the foe.")
+                        b: String::from("that he redeemed them from the hand of the foe."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 3,
                         a: String::from("He gathered them out of the lands; *"),
                         b: String::from(
                             "from the east and from the west,\nfrom the north and from the south."
-                        )
+                        ),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 4,
                         a: String::from("Some wandered in desert wastes; *"),
-                        b: String::from("they found no way to a city where they might dwell.")
+                        b: String::from("they found no way to a city where they might dwell."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 5,
                         a: String::from("They were hungry and thirsty; *"),
-                        b: String::from("their spirits languished within them.")
+                        b: String::from("their spirits languished within them."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 6,
                         a: String::from("Then they cried to the LORD in their trouble, *"),
-                        b: String::from("and he delivered them from their distress.")
+                        b: String::from("and he delivered them from their distress."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 7,
                         a: String::from("He put their feet on a straight path *"),
-                        b: String::from("to go to a city where they might dwell.")
+                        b: String::from("to go to a city where they might dwell."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 8,
                         a: String::from("Let them give thanks to the LORD for his mercy *"),
-                        b: String::from("and the wonders he does for his children.")
+                        b: String::from("and the wonders he does for his children."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 9,
                         a: String::from("For he satisfies the thirsty *"),
-                        b: String::from("and fills the hungry with good things.")
+                        b: String::from("and fills the hungry with good things."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 10,
                         a: String::from("Some sat in darkness and deep gloom, *"),
-                        b: String::from("bound fast in misery and iron;")
+                        b: String::from("bound fast in misery and iron;"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 11,
                         a: String::from("Because they rebelled against the words of God *"),
-                        b: String::from("and despised the counsel of the Most High.")
+                        b: String::from("and despised the counsel of the Most High."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 12,
                         a: String::from("So he humbled their spirits with hard labor; *"),
-                        b: String::from("they stumbled, and there was none to help.")
+                        b: String::from("they stumbled, and there was none to help."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 13,
                         a: String::from("Then they cried to the LORD in their trouble, *"),
-                        b: String::from("and he delivered them from their distress.")
+                        b: String::from("and he delivered them from their distress."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 14,
                         a: String::from("He led them out of darkness and deep gloom *"),
-                        b: String::from("and broke their bonds asunder.")
+                        b: String::from("and broke their bonds asunder."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 15,
                         a: String::from("Let them give thanks to the LORD for his mercy *"),
-                        b: String::from("and the wonders he does for his children.")
+                        b: String::from("and the wonders he does for his children."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 16,
                         a: String::from("For he shatters the doors of bronze *"),
-                        b: String::from("and breaks in two the iron bars.")
+                        b: String::from("and breaks in two the iron bars."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 17,
                         a: String::from("Some were fools and took to rebellious ways; *"),
-                        b: String::from("they were afflicted because of their sins.")
+                        b: String::from("they were afflicted because of their sins."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 18,
                         a: String::from("They abhorred all manner of food *"),
-                        b: String::from("and drew near to death’s door.")
+                        b: String::from("and drew near to death’s door."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 19,
                         a: String::from("Then they cried to the LORD in their trouble, *"),
-                        b: String::from("and he delivered them from their distress.")
+                        b: String::from("and he delivered them from their distress."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 20,
                         a: String::from("He sent forth his word and healed them *"),
-                        b: String::from("and saved them from the grave.")
+                        b: String::from("and saved them from the grave."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 21,
                         a: String::from("Let them give thanks to the LORD for his mercy *"),
-                        b: String::from("and the wonders he does for his children.")
+                        b: String::from("and the wonders he does for his children."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 22,
                         a: String::from("Let them offer a sacrifice of thanksgiving *"),
-                        b: String::from("and tell of his acts with shouts of joy.")
+                        b: String::from("and tell of his acts with shouts of joy."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 23,
                         a: String::from("Some went down to the sea in ships *"),
-                        b: String::from("and plied their trade in deep waters;")
+                        b: String::from("and plied their trade in deep waters;"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 24,
                         a: String::from("They beheld the works of the LORD *"),
-                        b: String::from("and his wonders in the deep.")
+                        b: String::from("and his wonders in the deep."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 25,
                         a: String::from("Then he spoke, and a stormy wind arose, *"),
-                        b: String::from("which tossed high the waves of the sea.")
+                        b: String::from("which tossed high the waves of the sea."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 26,
                         a: String::from(
                             "They mounted up to the heavens and fell back to the depths; *"
                         ),
-                        b: String::from("their hearts melted because of their peril.")
+                        b: String::from("their hearts melted because of their peril."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 27,
                         a: String::from("They reeled and staggered like drunkards *"),
-                        b: String::from("and were at their wits’ end.")
+                        b: String::from("and were at their wits’ end."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 28,
                         a: String::from("Then they cried to the LORD in their trouble, *"),
-                        b: String::from("and he delivered them from their distress.")
+                        b: String::from("and he delivered them from their distress."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 29,
                         a: String::from("He stilled the storm to a whisper *"),
-                        b: String::from("and quieted the waves of the sea.")
+                        b: String::from("and quieted the waves of the sea."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 30,
                         a: String::from("Then were they glad because of the calm, *"),
-                        b: String::from("and he brought them to the harbor they were bound for.")
+                        b: String::from("and he brought them to the harbor they were bound for."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 31,
                         a: String::from("Let them give thanks to the LORD for his mercy *"),
-                        b: String::from("and the wonders he does for his children.")
+                        b: String::from("and the wonders he does for his children."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 32,
                         a: String::from("Let them exalt him in the congregation of the people *"),
-                        b: String::from("and praise him in the council of the elders.")
+                        b: String::from("and praise him in the council of the elders."),
+                        ..Default::default()
                     },
                 ]
             },
@@ -189,60 +221,72 @@ lazy_static! {
                     PsalmVerse {
                         number: 33,
                         a: String::from("The LORD changed rivers into deserts, *"),
-                        b: String::from("and water-springs into thirsty ground,")
+                        b: String::from("and water-springs into thirsty ground,"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 34,
                         a: String::from("A fruitful land into salt flats, *"),
-                        b: String::from("because of the wickedness of those who dwell there.")
+                        b: String::from("because of the wickedness of those who dwell there."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 35,
                         a: String::from("He changed deserts into pools of water *"),
-                        b: String::from("and dry land into water-springs.")
+                        b: String::from("and dry land into water-springs."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 36,
                         a: String::from("He settled the hungry there, *"),
-                        b: String::from("and they founded a city to dwell in.")
+                        b: String::from("and they founded a city to dwell in."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 37,
                         a: String::from("They sowed fields, and planted vineyards, *"),
-                        b: String::from("and brought in a fruitful harvest.")
+                        b: String::from("and brought in a fruitful harvest."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 38,
                         a: String::from("He blessed them, so that they increased greatly; *"),
-                        b: String::from("he did not let their herds decrease.")
+                        b: String::from("he did not let their herds decrease."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 39,
                         a: String::from("Yet when they were diminished and brought low, *"),
-                        b: String::from("through stress of adversity and sorrow,")
+                        b: String::from("through stress of adversity and sorrow,"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 40,
                         a: String::from("(He pours contempt on princes *"),
-                        b: String::from("and makes them wander in trackless wastes)")
+                        b: String::from("and makes them wander in trackless wastes)"),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 41,
                         a: String::from("He lifted up the poor out of misery *"),
-                        b: String::from("and multiplied their families like flocks of sheep.")
+                        b: String::from("and multiplied their families like flocks of sheep."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 42,
                         a: String::from("The upright will see this and rejoice, *"),
-                        b: String::from("but all wickedness will shut its mouth.")
+                        b: String::from("but all wickedness will shut its mouth."),
+                        ..Default::default()
                     },
                     PsalmVerse {
                         number: 43,
                         a: String::from("Whoever is wise will ponder these things, *"),
-                        b: String::from("and consider well the mercies of the LORD.")
+                        b: String::from("and consider well the mercies of the LORD."),
+                        ..Default::default()
                     },
                 ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
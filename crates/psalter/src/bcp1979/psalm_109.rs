@@ -16,155 +16,186 @@ lazy_static! {
                           PsalmVerse {
                     number: 1,
                     a: String::from("Hold not your tongue, O God of my praise; *"),
-                    b: String::from("for the mouth of the wicked,\nthe mouth of the deceitful, is opened against me.")
+                    b: String::from("for the mouth of the wicked,\nthe mouth of the deceitful, is opened against me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 2,
                     a: String::from("They speak to me with a lying tongue; *"),
-                    b: String::from("they encompass me with hateful words\nand fight against me without a cause.")
+                    b: String::from("they encompass me with hateful words\nand fight against me without a cause."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 3,
                     a: String::from("Despite my love, they accuse me; *"),
-                    b: String::from("but as for me, I pray for them.")
+                    b: String::from("but as for me, I pray for them."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 4,
                     a: String::from("They repay evil for good, *"),
-                    b: String::from("and hatred for my love.")
+                    b: String::from("and hatred for my love."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 5,
                     a: String::from("Set a wicked man against him, *"),
-                    b: String::from("and let an accuser stand at his right hand.")
+                    b: String::from("and let an accuser stand at his right hand."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 6,
                     a: String::from("When he is judged, let him be found guilty, *"),
-                    b: String::from("and let his appeal be in vain.")
+                    b: String::from("and let his appeal be in vain."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 7,
                     a: String::from("Let his days be few, *"),
-                    b: String::from("and let another take his office.")
+                    b: String::from("and let another take his office."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 8,
                     a: String::from("Let his children be fatherless, *"),
-                    b: String::from("and his wife become a widow.")
+                    b: String::from("and his wife become a widow."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 9,
                     a: String::from("Let his children be waifs and beggars; *"),
-                    b: String::from("let them be driven from the ruins of their homes.")
+                    b: String::from("let them be driven from the ruins of their homes."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 10,
                     a: String::from("Let the creditor seize everything he has; *"),
-                    b: String::from("let strangers plunder his gains.")
+                    b: String::from("let strangers plunder his gains."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 11,
                     a: String::from("Let there be no one to show him kindness, *"),
-                    b: String::from("and none to pity his fatherless children.")
+                    b: String::from("and none to pity his fatherless children."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 12,
                     a: String::from("Let his descendants be destroyed, *"),
-                    b: String::from("and his name be blotted out in the next generation.")
+                    b: String::from("and his name be blotted out in the next generation."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 13,
                     a: String::from("Let the wickedness of his fathers be remembered before the LORD, *"),
-                    b: String::from("and his mother’s sin not be blotted out;")
+                    b: String::from("and his mother’s sin not be blotted out;"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 14,
                     a: String::from("Let their sin be always before the LORD; *"),
-                    b: String::from("but let him root out their names from the earth;")
+                    b: String::from("but let him root out their names from the earth;"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 15,
                     a: String::from("Because he did not remember to show mercy, *"),
-                    b: String::from("but persecuted the poor and needy\nand sought to kill the brokenhearted.")
+                    b: String::from("but persecuted the poor and needy\nand sought to kill the brokenhearted."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 16,
                     a: String::from("He loved cursing,\nlet it come upon him; *"),
-                    b: String::from("he took no delight in blessing,\nlet it depart from him.")
+                    b: String::from("he took no delight in blessing,\nlet it depart from him."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 17,
                     a: String::from("He put on cursing like a garment, *"),
-                    b: String::from("let it soak into his body like water\nand into his bones like oil;")
+                    b: String::from("let it soak into his body like water\nand into his bones like oil;"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 18,
                     a: String::from("Let it be to him like the cloak which he wraps around himself, *"),
-                    b: String::from("and like the belt that he wears continually.")
+                    b: String::from("and like the belt that he wears continually."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 19,
                     a: String::from("Let this be the recompense from the LORD to my accusers, *"),
-                    b: String::from("and to those who speak evil against me.")
+                    b: String::from("and to those who speak evil against me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 20,
                     a: String::from("But you, O Lord my God,\noh, deal with me according to your Name; *"),
-                    b: String::from("for your tender mercy’s sake, deliver me.")
+                    b: String::from("for your tender mercy’s sake, deliver me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 21,
                     a: String::from("For I am poor and needy, *"),
-                    b: String::from("and my heart is wounded within me.")
+                    b: String::from("and my heart is wounded within me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 22,
                     a: String::from("I have faded away like a shadow when it lengthens; *"),
-                    b: String::from("I am shaken off like a locust.")
+                    b: String::from("I am shaken off like a locust."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 23,
                     a: String::from("My knees are weak through fasting, *"),
-                    b: String::from("and my flesh is wasted and gaunt.")
+                    b: String::from("and my flesh is wasted and gaunt."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 24,
                     a: String::from("I have become a reproach to them; *"),
-                    b: String::from("they see and shake their heads.")
+                    b: String::from("they see and shake their heads."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 25,
                     a: String::from("Help me, O LORD my God; *"),
-                    b: String::from("save me for your mercy’s sake.")
+                    b: String::from("save me for your mercy’s sake."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 26,
                     a: String::from("Let them know that this is your hand, *"),
-                    b: String::from("that you, O LORD, have done it.")
+                    b: String::from("that you, O LORD, have done it."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 27,
                     a: String::from("They may curse, but you will bless; *"),
-                    b: String::from("let those who rise up against me be put to shame,\nand your servant will rejoice.")
+                    b: String::from("let those who rise up against me be put to shame,\nand your servant will rejoice."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 28,
                     a: String::from("Let my accusers be clothed with disgrace *"),
-                    b: String::from("and wrap themselves in their shame as in a cloak.")
+                    b: String::from("and wrap themselves in their shame as in a cloak."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 29,
                     a: String::from("I will give great thanks to the LORD with my mouth; *"),
-                    b: String::from("in the midst of the multitude will I praise him;")
+                    b: String::from("in the midst of the multitude will I praise him;"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 30,
                     a: String::from("Because he stands at the right hand of the needy, *"),
-                    b: String::from("to save his life from those who would condemn him.")
+                    b: String::from("to save his life from those who would condemn him."),
+                    ..Default::default()
                 },
             ]
           }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -14,44 +14,53 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Hallelujah!\nGive praise, you servants of the LORD; *"),
-                    b: String::from("praise the Name of the LORD.")
+                    b: String::from("praise the Name of the LORD."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Let the Name of the LORD be blessed, *"),
-                    b: String::from("from this time forth for evermore.")
+                    b: String::from("from this time forth for evermore."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("From the rising of the sun to its going down *"),
-                    b: String::from("let the Name of the LORD be praised.")
+                    b: String::from("let the Name of the LORD be praised."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("The LORD is high above all nations, *"),
-                    b: String::from("and his glory above the heavens.")
+                    b: String::from("and his glory above the heavens."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("Who is like the LORD our God, who sits enthroned on high, *"),
-                    b: String::from("but stoops to behold the heavens and the earth?")
+                    b: String::from("but stoops to behold the heavens and the earth?"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("He takes up the weak out of the dust *"),
-                    b: String::from("and lifts up the poor from the ashes.")
+                    b: String::from("and lifts up the poor from the ashes."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("He sets them with the princes, *"),
-                    b: String::from("with the princes of his people.")
+                    b: String::from("with the princes of his people."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("He makes the woman of a childless house *"),
-                    b: String::from("to be a joyful mother of children.")
+                    b: String::from("to be a joyful mother of children."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
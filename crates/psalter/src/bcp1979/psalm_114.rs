@@ -14,44 +14,53 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Hallelujah!\nWhen Israel came out of Egypt, *"),
-                    b: String::from("the house of Jacob from a people of strange speech,")
+                    b: String::from("the house of Jacob from a people of strange speech,"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Judah became God’s sanctuary *"),
-                    b: String::from("and Israel his dominion.")
+                    b: String::from("and Israel his dominion."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("The sea beheld it and fled; *"),
-                    b: String::from("Jordan turned and went back.")
+                    b: String::from("Jordan turned and went back."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("The mountains skipped like rams, *"),
-                    b: String::from("and the little hills like young sheep.")
+                    b: String::from("and the little hills like young sheep."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("What ailed you, O sea, that you fled? *"),
-                    b: String::from("O Jordan, that you turned back?")
+                    b: String::from("O Jordan, that you turned back?"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("You mountains, that you skipped like rams? *"),
-                    b: String::from("you little hills like young sheep?")
+                    b: String::from("you little hills like young sheep?"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("Tremble, O earth, at the presence of the Lord, *"),
-                    b: String::from("at the presence of the God of Jacob,")
+                    b: String::from("at the presence of the God of Jacob,"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("Who turned the hard rock into a pool of water *"),
-                    b: String::from("and flint-stone into a flowing spring.")
+                    b: String::from("and flint-stone into a flowing spring."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,16 +14,19 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Praise the LORD, all you nations; *"),
-                    b: String::from("laud him, all you peoples.")
+                    b: String::from("laud him, all you peoples."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("For his loving-kindness toward us is great, *"),
                     b: String::from(
                         "and the faithfulness of the LORD endures for ever.\nHallelujah!"
-                    )
+                    ),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -15,150 +15,180 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Give thanks to the LORD, for he is good; *"),
-                      b: String::from("his mercy endures for ever.")
+                      b: String::from("his mercy endures for ever."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Let Israel now proclaim, *"),
-                      b: String::from("“His mercy endures for ever.”")
+                      b: String::from("“His mercy endures for ever.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Let the house of Aaron now proclaim, *"),
-                      b: String::from("“His mercy endures for ever.”")
+                      b: String::from("“His mercy endures for ever.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Let those who fear the LORD now proclaim, *"),
-                      b: String::from("“His mercy endures for ever.”")
+                      b: String::from("“His mercy endures for ever.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("I called to the LORD in my distress; *"),
-                      b: String::from("the LORD answered by setting me free.")
+                      b: String::from("the LORD answered by setting me free."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("The LORD is at my side, therefore I will not fear; *"),
-                      b: String::from("what can anyone do to me?")
+                      b: String::from("what can anyone do to me?"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("The LORD is at my side to help me; *"),
-                      b: String::from("I will triumph over those who hate me.")
+                      b: String::from("I will triumph over those who hate me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("It is better to rely on the LORD *"),
-                      b: String::from("than to put any trust in flesh.")
+                      b: String::from("than to put any trust in flesh."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("It is better to rely on the LORD *"),
-                      b: String::from("than to put any trust in rulers.")
+                      b: String::from("than to put any trust in rulers."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("All the ungodly encompass me; *"),
-                      b: String::from("in the Name of the LORD I will repel them.")
+                      b: String::from("in the Name of the LORD I will repel them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("They hem me in, they hem me in on every side; *"),
-                      b: String::from("in the name of the LORD I will repel them.")
+                      b: String::from("in the name of the LORD I will repel them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("They swarm about me like bees;\nthey blaze like a fire of thorns; *"),
-                      b: String::from("in the name of the LORD I will repel them.")
+                      b: String::from("in the name of the LORD I will repel them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("I was pressed so hard that I almost fell, *"),
-                      b: String::from("but the LORD came to my help.")
+                      b: String::from("but the LORD came to my help."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("The LORD is my strength and my song, *"),
-                      b: String::from("and he has become my salvation.")
+                      b: String::from("and he has become my salvation."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 15,
                       a: String::from("There is a sound of exultation and victory *"),
-                      b: String::from("in the tents of the righteous:")
+                      b: String::from("in the tents of the righteous:"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 16,
                       a: String::from("“The right hand of the LORD has triumphed! *"),
-                      b: String::from("the right hand of the LORD is exalted!\nthe right hand of the LORD has triumphed!”")
+                      b: String::from("the right hand of the LORD is exalted!\nthe right hand of the LORD has triumphed!”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 17,
                       a: String::from("I shall not die, but live, *"),
-                      b: String::from("and declare the works of the LORD.")
+                      b: String::from("and declare the works of the LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 18,
                       a: String::from("The LORD has punished me sorely, *"),
-                      b: String::from("but he did not hand me over to death.")
+                      b: String::from("but he did not hand me over to death."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 19,
                       a: String::from("Open for me the gates of righteousness; *"),
-                      b: String::from("I will enter them;\nI will offer thanks to the LORD.")
+                      b: String::from("I will enter them;\nI will offer thanks to the LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 20,
                       a: String::from("“This is the gate of the LORD; *"),
-                      b: String::from("he who is righteous may enter.”")
+                      b: String::from("he who is righteous may enter.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 21,
                       a: String::from("I will give thanks to you, for you answered me *"),
-                      b: String::from("and have become my salvation.")
+                      b: String::from("and have become my salvation."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 22,
                       a: String::from("The same stone which the builders rejected *"),
-                      b: String::from("has become the chief cornerstone.")
+                      b: String::from("has become the chief cornerstone."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 23,
                       a: String::from("This is the LORD’s doing, *"),
-                      b: String::from("and it is marvelous in our eyes.")
+                      b: String::from("and it is marvelous in our eyes."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 24,
                       a: String::from("On this day the LORD has acted; *"),
-                      b: String::from("we will rejoice and be glad in it.")
+                      b: String::from("we will rejoice and be glad in it."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 25,
                       a: String::from("Hosanna, LORD, hosanna! *"),
-                      b: String::from("LORD, send us now success.")
+                      b: String::from("LORD, send us now success."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 26,
                       a: String::from("Blessed is he who comes in the name of the Lord; *"),
-                      b: String::from("we bless you from the house of the LORD.")
+                      b: String::from("we bless you from the house of the LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 27,
                       a: String::from("God is the LORD; he has shined upon us; *"),
-                      b: String::from("form a procession with branches up to the horns of the altar.")
+                      b: String::from("form a procession with branches up to the horns of the altar."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 28,
                       a: String::from("“You are my God, and I will thank you; *"),
-                      b: String::from("you are my God, and I will exalt you.”")
+                      b: String::from("you are my God, and I will exalt you.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 29,
                       a: String::from("Give thanks to the LORD, for he is good; *"),
-                      b: String::from("his mercy endures for ever.")
+                      b: String::from("his mercy endures for ever."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
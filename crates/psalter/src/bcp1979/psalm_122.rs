@@ -14,49 +14,59 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("I was glad when they said to me, *"),
-                    b: String::from("“Let us go to the house of the LORD.”")
+                    b: String::from("“Let us go to the house of the LORD.”"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Now our feet are standing *"),
-                    b: String::from("within your gates, O Jerusalem.")
+                    b: String::from("within your gates, O Jerusalem."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("Jerusalem is built as a city *"),
-                    b: String::from("that is at unity with itself;")
+                    b: String::from("that is at unity with itself;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("To which the tribes go up,\nthe tribes of the LORD, *"),
-                    b: String::from("the assembly of Israel,\nto praise the Name of the LORD.")
+                    b: String::from("the assembly of Israel,\nto praise the Name of the LORD."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("For there are the thrones of judgment, *"),
-                    b: String::from("the thrones of the house of David.")
+                    b: String::from("the thrones of the house of David."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("Pray for the peace of Jerusalem: *"),
-                    b: String::from("“May they prosper who love you.")
+                    b: String::from("“May they prosper who love you."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("Peace be within your walls *"),
-                    b: String::from("and quietness within your towers.")
+                    b: String::from("and quietness within your towers."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("For my brethren and companions’ sake, *"),
-                    b: String::from("I pray for your prosperity.")
+                    b: String::from("I pray for your prosperity."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("Because of the house of the LORD our God, *"),
-                    b: String::from("I will seek to do you good.”")
+                    b: String::from("I will seek to do you good.”"),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,29 +14,35 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("To you I lift up my eyes, *"),
-                    b: String::from("to you enthroned in the heavens.")
+                    b: String::from("to you enthroned in the heavens."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("As the eyes of servants look to the hand of their masters, *"),
-                    b: String::from("and the eyes of a maid to the hand of her mistress,")
+                    b: String::from("and the eyes of a maid to the hand of her mistress,"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("So our eyes look to the LORD our God, *"),
-                    b: String::from("until he show us his mercy.")
+                    b: String::from("until he show us his mercy."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("Have mercy upon us, O LORD, have mercy, *"),
-                    b: String::from("for we have had more than enough of contempt,")
+                    b: String::from("for we have had more than enough of contempt,"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("Too much of the scorn of the indolent rich, *"),
-                    b: String::from("and of the derision of the proud.")
+                    b: String::from("and of the derision of the proud."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,44 +14,53 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("If the LORD had not been on our side, *"),
-                    b: String::from("let Israel now say;")
+                    b: String::from("let Israel now say;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("If the LORD had not been on our side, *"),
-                    b: String::from("when enemies rose up against us;")
+                    b: String::from("when enemies rose up against us;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("Then would they have swallowed us up alive *"),
-                    b: String::from("in their fierce anger toward us;")
+                    b: String::from("in their fierce anger toward us;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("Then would the waters have overwhelmed us *"),
-                    b: String::from("and the torrent gone over us;")
+                    b: String::from("and the torrent gone over us;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("Then would the raging waters *"),
-                    b: String::from("have gone right over us.")
+                    b: String::from("have gone right over us."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("Blessed be the LORD! *"),
-                    b: String::from("he has not given us over to be a prey for their teeth.")
+                    b: String::from("he has not given us over to be a prey for their teeth."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("We have escaped like a bird from the snare of the fowler; *"),
-                    b: String::from("the snare is broken, and we have escaped.")
+                    b: String::from("the snare is broken, and we have escaped."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("Our help is in the Name of the LORD, *"),
-                    b: String::from("the maker of heaven and earth.")
+                    b: String::from("the maker of heaven and earth."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
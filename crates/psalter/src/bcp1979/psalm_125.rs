@@ -15,30 +15,36 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Those who trust in the LORD are like Mount Zion, *"),
-                      b: String::from("which cannot be moved, but stands fast for ever.")
+                      b: String::from("which cannot be moved, but stands fast for ever."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("The hills stand about Jerusalem; *"),
-                      b: String::from("so does the LORD stand round about his people,\nfrom this time forth for evermore.")
+                      b: String::from("so does the LORD stand round about his people,\nfrom this time forth for evermore."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("The scepter of the wicked shall not hold sway over the land allotted to the just, *"),
-                      b: String::from("so that the just shall not put their hands to evil.")
+                      b: String::from("so that the just shall not put their hands to evil."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Show your goodness, O LORD, to those who are good *"),
-                      b: String::from("and to those who are true of heart.")
+                      b: String::from("and to those who are true of heart."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("As for those who turn aside to crooked ways,\nthe LORD will lead them away with the evildoers; *"),
-                      b: String::from("but peace be upon Israel.")
+                      b: String::from("but peace be upon Israel."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -14,39 +14,47 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("When the LORD restored the fortunes of Zion, *"),
-                    b: String::from("then were we like those who dream.")
+                    b: String::from("then were we like those who dream."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Then was our mouth filled with laughter, *"),
-                    b: String::from("and our tongue with shouts of joy.")
+                    b: String::from("and our tongue with shouts of joy."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("Then they said among the nations, *"),
-                    b: String::from("“The LORD has done great things for them.”")
+                    b: String::from("“The LORD has done great things for them.”"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("The LORD has done great things for us, *"),
-                    b: String::from("and we are glad indeed.")
+                    b: String::from("and we are glad indeed."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("Restore our fortunes, O LORD, *"),
-                    b: String::from("like the watercourses of the Negev.")
+                    b: String::from("like the watercourses of the Negev."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("Those who sowed with tears *"),
-                    b: String::from("will reap with songs of joy.")
+                    b: String::from("will reap with songs of joy."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("Those who go out weeping, carrying the seed, *"),
-                    b: String::from("will come again with joy, shouldering their sheaves.")
+                    b: String::from("will come again with joy, shouldering their sheaves."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,36 +14,43 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Happy are they all who fear the LORD, *"),
-                    b: String::from("and who follow in his ways!")
+                    b: String::from("and who follow in his ways!"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("You shall eat the fruit of your labor; *"),
-                    b: String::from("happiness and prosperity shall be yours.")
+                    b: String::from("happiness and prosperity shall be yours."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("Your wife shall be like a fruitful vine within your house, *"),
-                    b: String::from("your children like olive shoots round about your table.")
+                    b: String::from("your children like olive shoots round about your table."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("The man who fears the LORD *"),
-                    b: String::from("shall thus indeed be blessed.")
+                    b: String::from("shall thus indeed be blessed."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("The LORD bless you from Zion, *"),
                     b: String::from(
                         "and may you see the prosperity of Jerusalem all the days of your life."
-                    )
+                    ),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("May you live to see your children’s children; *"),
-                    b: String::from("may peace be upon Israel.")
+                    b: String::from("may peace be upon Israel."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,46 +14,55 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("“Greatly have they oppressed me since my youth,” *"),
-                    b: String::from("let Israel now say;")
+                    b: String::from("let Israel now say;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("“Greatly have they oppressed me since my youth, *"),
-                    b: String::from("but they have not prevailed against me.”")
+                    b: String::from("but they have not prevailed against me.”"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("The plowmen plowed upon my back *"),
-                    b: String::from("and made their furrows long.")
+                    b: String::from("and made their furrows long."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("The LORD, the Righteous One, *"),
-                    b: String::from("has cut the cords of the wicked.")
+                    b: String::from("has cut the cords of the wicked."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("Let them be put to shame and thrown back, *"),
-                    b: String::from("all those who are enemies of Zion.")
+                    b: String::from("all those who are enemies of Zion."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("Let them be like grass upon the housetops, *"),
-                    b: String::from("which withers before it can be plucked;")
+                    b: String::from("which withers before it can be plucked;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("Which does not fill the hand of the reaper, *"),
-                    b: String::from("nor the bosom of him who binds the sheaves;")
+                    b: String::from("nor the bosom of him who binds the sheaves;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from(
                         "So that those who go by say not so much as,\n“The LORD prosper you. *"
                     ),
-                    b: String::from("We wish you well in the Name of the LORD.”")
+                    b: String::from("We wish you well in the Name of the LORD.”"),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -16,41 +16,49 @@ lazy_static! {
                     a: String::from(
                         "Out of the depths have I called to you, O LORD;\nLORD, hear my voice; *"
                     ),
-                    b: String::from("let your ears consider well the voice of my supplication.")
+                    b: String::from("let your ears consider well the voice of my supplication."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("If you, LORD, were to note what is done amiss, *"),
-                    b: String::from("O LORD, who could stand?")
+                    b: String::from("O LORD, who could stand?"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("For there is forgiveness with you; *"),
-                    b: String::from("therefore you shall be feared.")
+                    b: String::from("therefore you shall be feared."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("I wait for the LORD; my soul waits for him; *"),
-                    b: String::from("in his word is my hope.")
+                    b: String::from("in his word is my hope."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from(
                         "My soul waits for the LORD,\nmore than watchmen for the morning, *"
                     ),
-                    b: String::from("more than watchmen for the morning.")
+                    b: String::from("more than watchmen for the morning."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("O Israel, wait for the LORD, *"),
-                    b: String::from("for with the LORD there is mercy;")
+                    b: String::from("for with the LORD there is mercy;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("With him there is plenteous redemption, *"),
-                    b: String::from("and he shall redeem Israel from all their sins.")
+                    b: String::from("and he shall redeem Israel from all their sins."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -15,25 +15,30 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("O LORD, I am not proud; *"),
-                      b: String::from("I have no haughty looks.")
+                      b: String::from("I have no haughty looks."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("I do not occupy myself with great matters, *"),
-                      b: String::from("or with things that are too hard for me.")
+                      b: String::from("or with things that are too hard for me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("But I still my soul and make it quiet,\nlike a child upon its mother’s breast; *"),
-                      b: String::from("my soul is quieted within me.")
+                      b: String::from("my soul is quieted within me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("O Israel, wait upon the LORD, *"),
-                      b: String::from("from this time forth for evermore.")
+                      b: String::from("from this time forth for evermore."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
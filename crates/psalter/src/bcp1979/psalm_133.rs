@@ -14,29 +14,35 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Oh, how good and pleasant it is, *"),
-                    b: String::from("when brethren live together in unity!")
+                    b: String::from("when brethren live together in unity!"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("It is like fine oil upon the head *"),
-                    b: String::from("that runs down upon the beard,")
+                    b: String::from("that runs down upon the beard,"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("Upon the beard of Aaron, *"),
-                    b: String::from("and runs down upon the collar of his robe.")
+                    b: String::from("and runs down upon the collar of his robe."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("It is like the dew of Hermon *"),
-                    b: String::from("that falls upon the hills of Zion.")
+                    b: String::from("that falls upon the hills of Zion."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("For there the LORD has ordained the blessing: *"),
-                    b: String::from("life for evermore.")
+                    b: String::from("life for evermore."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,14 +14,17 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Behold now, bless the LORD, all you servants of the LORD, *"),
-                    b: String::from("you that stand by night in the house of the LORD.")
+                    b: String::from("you that stand by night in the house of the LORD."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Lift up your hands in the holy place and bless the LORD; *"),
-                    b: String::from("the LORD who made heaven and earth bless you out of Zion.")
+                    b: String::from("the LORD who made heaven and earth bless you out of Zion."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
@@ -14,134 +14,161 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Give thanks to the LORD, for he is good, *"),
-                    b: String::from("for his mercy endures for ever.")
+                    b: String::from("for his mercy endures for ever."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Give thanks to the God of gods, *"),
-                    b: String::from("for his mercy endures for ever.")
+                    b: String::from("for his mercy endures for ever."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("Give thanks to the Lord of lords, *"),
-                    b: String::from("for his mercy endures for ever.")
+                    b: String::from("for his mercy endures for ever."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("Who only does great wonders, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("Who by wisdom made the heavens, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("Who spread out the earth upon the waters, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("Who created great lights, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("The sun to rule the day, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("The moon and the stars to govern the night, *"),
-                    b: String::from("for his mercy endures for ever.")
+                    b: String::from("for his mercy endures for ever."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 10,
                     a: String::from("Who struck down the firstborn of Egypt, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 11,
                     a: String::from("And brought out Israel from among them, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 12,
                     a: String::from("With a mighty hand and a stretched-out arm, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 13,
                     a: String::from("Who divided the Red Sea in two, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 14,
                     a: String::from("And made Israel to pass through the midst of it, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 15,
                     a: String::from("But swept Pharaoh and his army into the Red Sea, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 16,
                     a: String::from("Who led his people through the wilderness, *"),
-                    b: String::from("for his mercy endures for ever.")
+                    b: String::from("for his mercy endures for ever."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 17,
                     a: String::from("Who struck down great kings, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 18,
                     a: String::from("And slew mighty kings, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 19,
                     a: String::from("Sihon, king of the Amorites, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 20,
                     a: String::from("And Og, the king of Bashan, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 21,
                     a: String::from("And gave away their lands for an inheritance, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 22,
                     a: String::from("An inheritance for Israel his servant, *"),
-                    b: String::from("for his mercy endures for ever.")
+                    b: String::from("for his mercy endures for ever."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 23,
                     a: String::from("Who remembered us in our low estate, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 24,
                     a: String::from("And delivered us from our enemies, *"),
-                    b: String::from("for his mercy endures for ever;")
+                    b: String::from("for his mercy endures for ever;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 25,
                     a: String::from("Who gives food to all creatures, *"),
-                    b: String::from("for his mercy endures for ever.")
+                    b: String::from("for his mercy endures for ever."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 26,
                     a: String::from("Give thanks to the God of heaven, *"),
-                    b: String::from("for his mercy endures for ever.")
+                    b: String::from("for his mercy endures for ever."),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
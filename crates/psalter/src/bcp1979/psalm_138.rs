@@ -15,50 +15,60 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("I will give thanks to you, O LORD, with my whole heart; *"),
-                      b: String::from("before the gods I will sing your praise.")
+                      b: String::from("before the gods I will sing your praise."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("I will bow down toward your holy temple\nand praise your Name, *"),
-                      b: String::from("because of your love and faithfulness;")
+                      b: String::from("because of your love and faithfulness;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("For you have glorified your Name *"),
-                      b: String::from("and your word above all things.")
+                      b: String::from("and your word above all things."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("When I called, you answered me; *"),
-                      b: String::from("you increased my strength within me.")
+                      b: String::from("you increased my strength within me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("All the kings of the earth will praise you, O LORD, *"),
-                      b: String::from("when they have heard the words of your mouth.")
+                      b: String::from("when they have heard the words of your mouth."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("They will sing of the ways of the LORD, *"),
-                      b: String::from("that great is the glory of the LORD.")
+                      b: String::from("that great is the glory of the LORD."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Though the LORD be high, he cares for the lowly; *"),
-                      b: String::from("he perceives the haughty from afar.")
+                      b: String::from("he perceives the haughty from afar."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Though I walk in the midst of trouble, you keep me safe; *"),
-                      b: String::from("you stretch forth your hand against the fury of my enemies;\nyour right hand shall save me.")
+                      b: String::from("you stretch forth your hand against the fury of my enemies;\nyour right hand shall save me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("The LORD will make good his purpose for me; *"),
-                      b: String::from("O LORD, your love endures for ever;\ndo not abandon the works of your hands.")
+                      b: String::from("O LORD, your love endures for ever;\ndo not abandon the works of your hands."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -16,122 +16,146 @@ lazy_static! {
                           PsalmVerse {
                     number: 1,
                     a: String::from("LORD, you have searched me out and known me; *"),
-                    b: String::from("you know my sitting down and my rising up;\nyou discern my thoughts from afar.")
+                    b: String::from("you know my sitting down and my rising up;\nyou discern my thoughts from afar."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 2,
                     a: String::from("You trace my journeys and my resting-places *"),
-                    b: String::from("and are acquainted with all my ways.")
+                    b: String::from("and are acquainted with all my ways."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 3,
                     a: String::from("Indeed, there is not a word on my lips, *"),
-                    b: String::from("but you, O LORD, know it altogether.")
+                    b: String::from("but you, O LORD, know it altogether."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 4,
                     a: String::from("You press upon me behind and before *"),
-                    b: String::from("and lay your hand upon me.")
+                    b: String::from("and lay your hand upon me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 5,
                     a: String::from("Such knowledge is too wonderful for me; *"),
-                    b: String::from("it is so high that I cannot attain to it.")
+                    b: String::from("it is so high that I cannot attain to it."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 6,
                     a: String::from("Where can I go then from your Spirit? *"),
-                    b: String::from("where can I flee from your presence?")
+                    b: String::from("where can I flee from your presence?"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 7,
                     a: String::from("If I climb up to heaven, you are there; *"),
-                    b: String::from("if I make the grave my bed, you are there also.")
+                    b: String::from("if I make the grave my bed, you are there also."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 8,
                     a: String::from("If I take the wings of the morning *"),
-                    b: String::from("and dwell in the uttermost parts of the sea,")
+                    b: String::from("and dwell in the uttermost parts of the sea,"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 9,
                     a: String::from("Even there your hand will lead me *"),
-                    b: String::from("and your right hand hold me fast.")
+                    b: String::from("and your right hand hold me fast."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 10,
                     a: String::from("If I say, “Surely the darkness will cover me, *"),
-                    b: String::from("and the light around me turn to night,”")
+                    b: String::from("and the light around me turn to night,”"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 11,
                     a: String::from("Darkness is not dark to you;\nthe night is as bright as the day; *"),
-                    b: String::from("darkness and light to you are both alike.")
+                    b: String::from("darkness and light to you are both alike."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 12,
                     a: String::from("For you yourself created my inmost parts; *"),
-                    b: String::from("you knit me together in my mother’s womb.")
+                    b: String::from("you knit me together in my mother’s womb."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 13,
                     a: String::from("I will thank you because I am marvelously made; *"),
-                    b: String::from("your works are wonderful, and I know it well.")
+                    b: String::from("your works are wonderful, and I know it well."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 14,
                     a: String::from("My body was not hidden from you, *"),
-                    b: String::from("while I was being made in secret\nand woven in the depths of the earth.")
+                    b: String::from("while I was being made in secret\nand woven in the depths of the earth."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 15,
                     a: String::from("Your eyes beheld my limbs, yet unfinished in the womb;\nall of them were written in your book; *"),
-                    b: String::from("they were fashioned day by day,\nwhen as yet there was none of them.")
+                    b: String::from("they were fashioned day by day,\nwhen as yet there was none of them."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 16,
                     a: String::from("How deep I find your thoughts, O God! *"),
-                    b: String::from("how great is the sum of them!")
+                    b: String::from("how great is the sum of them!"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 17,
                     a: String::from("If I were to count them, they would be more in number than the sand; *"),
-                    b: String::from("to count them all, my life span would need to be like yours.")
+                    b: String::from("to count them all, my life span would need to be like yours."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 18,
                     a: String::from("Oh, that you would slay the wicked, O God! *"),
-                    b: String::from("You that thirst for blood, depart from me.")
+                    b: String::from("You that thirst for blood, depart from me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 19,
                     a: String::from("They speak despitefully against you; *"),
-                    b: String::from("your enemies take your Name in vain.")
+                    b: String::from("your enemies take your Name in vain."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 20,
                     a: String::from("Do I not hate those, O LORD, who hate you? *"),
-                    b: String::from("and do I not loathe those who rise up against you?")
+                    b: String::from("and do I not loathe those who rise up against you?"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 21,
                     a: String::from("I hate them with a perfect hatred; *"),
-                    b: String::from("they have become my own enemies.")
+                    b: String::from("they have become my own enemies."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 22,
                     a: String::from("Search me out, O God, and know my heart; *"),
-                    b: String::from("try me and know my restless thoughts.")
+                    b: String::from("try me and know my restless thoughts."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 23,
                     a: String::from("Look well whether there be any wickedness in me *"),
-                    b: String::from("and lead me in the way that is everlasting.")
+                    b: String::from("and lead me in the way that is everlasting."),
+                    ..Default::default()
                 },
             ]
           }
 
-      ]
+      ],
+        ..Default::default()
 
     };
 }
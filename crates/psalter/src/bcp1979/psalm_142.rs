@@ -15,40 +15,48 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("I cry to the LORD with my voice; *"),
-                      b: String::from("to the LORD I make loud supplication.")
+                      b: String::from("to the LORD I make loud supplication."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("I pour out my complaint before him *"),
-                      b: String::from("and tell him all my trouble.")
+                      b: String::from("and tell him all my trouble."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("When my spirit languishes within me, you know my path; *"),
-                      b: String::from("in the way wherein I walk they have hidden a trap for me.")
+                      b: String::from("in the way wherein I walk they have hidden a trap for me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("I look to my right hand and find no one who knows me; *"),
-                      b: String::from("I have no place to flee to, and no one cares for me.")
+                      b: String::from("I have no place to flee to, and no one cares for me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("I cry out to you, O LORD; *"),
-                      b: String::from("I say, “You are my refuge,\nmy portion in the land of the living.”")
+                      b: String::from("I say, “You are my refuge,\nmy portion in the land of the living.”"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Listen to my cry for help, for I have been brought very low; *"),
-                      b: String::from("save me from those who pursue me,\nfor they are too strong for me.")
+                      b: String::from("save me from those who pursue me,\nfor they are too strong for me."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Bring me out of prison, that I may give thanks to your Name; *"),
-                      b: String::from("when you have dealt bountifully with me,\nthe righteous will gather around me.")
+                      b: String::from("when you have dealt bountifully with me,\nthe righteous will gather around me."),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
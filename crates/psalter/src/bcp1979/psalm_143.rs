@@ -15,65 +15,78 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("LORD, hear my prayer,\nand in your faithfulness heed my supplications; *"),
-                      b: String::from("answer me in your righteousness.")
+                      b: String::from("answer me in your righteousness."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Enter not into judgment with your servant, *"),
-                      b: String::from("for in your sight shall no one living be justified.")
+                      b: String::from("for in your sight shall no one living be justified."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("For my enemy has sought my life;\nhe has crushed me to the ground; *"),
-                      b: String::from("he has made me live in dark places like those who are long dead.")
+                      b: String::from("he has made me live in dark places like those who are long dead."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("My spirit faints within me; *"),
-                      b: String::from("my heart within me is desolate.")
+                      b: String::from("my heart within me is desolate."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("I remember the time past;\nI muse upon all your deeds; *"),
-                      b: String::from("I consider the works of your hands.")
+                      b: String::from("I consider the works of your hands."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("I spread out my hands to you; *"),
-                      b: String::from("my soul gasps to you like a thirsty land.")
+                      b: String::from("my soul gasps to you like a thirsty land."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("O LORD, make haste to answer me; my spirit fails me; *"),
-                      b: String::from("do not hide your face from me\nor I shall be like those who go down to the Pit.")
+                      b: String::from("do not hide your face from me\nor I shall be like those who go down to the Pit."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Let me hear of your loving-kindness in the morning,\nfor I put my trust in you; *"),
-                      b: String::from("show me the road that I must walk,\nfor I lift up my soul to you.")
+                      b: String::from("show me the road that I must walk,\nfor I lift up my soul to you."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Deliver me from my enemies, O LORD, *"),
-                      b: String::from("for I flee to you for refuge.")
+                      b: String::from("for I flee to you for refuge."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Teach me to do what pleases you, for you are my God; *"),
-                      b: String::from("let your good Spirit lead me on level ground.")
+                      b: String::from("let your good Spirit lead me on level ground."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Revive me, O LORD, for your Name’s sake; *"),
-                      b: String::from("for your righteousness’ sake, bring me out of trouble.")
+                      b: String::from("for your righteousness’ sake, bring me out of trouble."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("Of your goodness, destroy my enemies\nand bring all my foes to naught, *"),
-                      b: String::from("for truly I am your servant.")
+                      b: String::from("for truly I am your servant."),
+                      ..Default::default()
                   },
               ]
             }
-      ]
+      ],
+        ..Default::default()
     };
 }
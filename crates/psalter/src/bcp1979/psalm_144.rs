@@ -15,85 +15,102 @@ lazy_static! {
                           PsalmVerse {
                     number: 1,
                     a: String::from("Blessed be the LORD my rock! *"),
-                    b: String::from("who trains my hands to fight and my fingers to battle;")
+                    b: String::from("who trains my hands to fight and my fingers to battle;"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 2,
                     a: String::from("My help and my fortress, my stronghold and my deliverer, *"),
-                    b: String::from("my shield in whom I trust,\nwho subdues the peoples under me.")
+                    b: String::from("my shield in whom I trust,\nwho subdues the peoples under me."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 3,
                     a: String::from("O LORD, what are we that you should care for us? *"),
-                    b: String::from("mere mortals that you should think of us?")
+                    b: String::from("mere mortals that you should think of us?"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 4,
                     a: String::from("We are like a puff of wind; *"),
-                    b: String::from("our days are like a passing shadow.")
+                    b: String::from("our days are like a passing shadow."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 5,
                     a: String::from("Bow your heavens, O LORD, and come down; *"),
-                    b: String::from("touch the mountains, and they shall smoke.")
+                    b: String::from("touch the mountains, and they shall smoke."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 6,
                     a: String::from("Hurl the lightning and scatter them; *"),
-                    b: String::from("shoot out your arrows and rout them.")
+                    b: String::from("shoot out your arrows and rout them."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 7,
                     a: String::from("Stretch out your hand from on high; *"),
-                    b: String::from("rescue me and deliver me from the great waters,\nfrom the hand of foreign peoples,")
+                    b: String::from("rescue me and deliver me from the great waters,\nfrom the hand of foreign peoples,"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 8,
                     a: String::from("Whose mouths speak deceitfully *"),
-                    b: String::from("and whose right hand is raised in falsehood.")
+                    b: String::from("and whose right hand is raised in falsehood."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 9,
                     a: String::from("O God, I will sing to you a new song; *"),
-                    b: String::from("I will play to you on a ten-stringed lyre.")
+                    b: String::from("I will play to you on a ten-stringed lyre."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 10,
                     a: String::from("You give victory to kings *"),
-                    b: String::from("and have rescued David your servant.")
+                    b: String::from("and have rescued David your servant."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 11,
                     a: String::from("Rescue me from the hurtful sword *"),
-                    b: String::from("and deliver me from the hand of foreign peoples,")
+                    b: String::from("and deliver me from the hand of foreign peoples,"),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 12,
                     a: String::from("Whose mouths speak deceitfully *"),
-                    b: String::from("and whose right hand is raised in falsehood.")
+                    b: String::from("and whose right hand is raised in falsehood."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 13,
                     a: String::from("May our sons be like plants well nurtured from their youth, *"),
-                    b: String::from("and our daughters like sculptured corners of a palace.")
+                    b: String::from("and our daughters like sculptured corners of a palace."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 14,
                     a: String::from("May our barns be filled to overflowing with all manner of crops; *"),
-                    b: String::from("may the flocks in our pastures increase by thousands and tens of thousands;\nmay our cattle be fat and sleek.")
+                    b: String::from("may the flocks in our pastures increase by thousands and tens of thousands;\nmay our cattle be fat and sleek."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 15,
                     a: String::from("May there be no breaching of the walls, no going into exile, *"),
-                    b: String::from("no wailing in the public squares.")
+                    b: String::from("no wailing in the public squares."),
+                    ..Default::default()
                 },
               PsalmVerse {
                     number: 16,
                     a: String::from("Happy are the people of whom this is so! *"),
-                    b: String::from("happy are the people whose God is the LORD!")
+                    b: String::from("happy are the people whose God is the LORD!"),
+                    ..Default::default()
                 },
             ]
           }
-      ]
+      ],
+        ..Default::default()
     };
 }
@@ -15,50 +15,60 @@ lazy_static! {
                 PsalmVerse {
                       number: 1,
                       a: String::from("Hallelujah!\nPraise the LORD, O my soul! *"),
-                      b: String::from("I will praise the LORD as long as I live;\nI will sing praises to my God while I have my being.")
+                      b: String::from("I will praise the LORD as long as I live;\nI will sing praises to my God while I have my being."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Put not your trust in rulers, nor in any child of earth, *"),
-                      b: String::from("for there is no help in them.")
+                      b: String::from("for there is no help in them."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("When they breathe their last, they return to earth, *"),
-                      b: String::from("and in that day their thoughts perish.")
+                      b: String::from("and in that day their thoughts perish."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Happy are they who have the God of Jacob for their help! *"),
-                      b: String::from("whose hope is in the LORD their God;")
+                      b: String::from("whose hope is in the LORD their God;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Who made heaven and earth, the seas, and all that is in them; *"),
-                      b: String::from("who keeps his promise for ever;")
+                      b: String::from("who keeps his promise for ever;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("Who gives justice to those who are oppressed, *"),
-                      b: String::from("and food to those who hunger.")
+                      b: String::from("and food to those who hunger."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("The LORD sets the prisoners free;\nthe LORD opens the eyes of the blind; *"),
-                      b: String::from("the LORD lifts up those who are bowed down;")
+                      b: String::from("the LORD lifts up those who are bowed down;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("The LORD loves the righteous;\nthe LORD cares for the stranger; *"),
-                      b: String::from("he sustains the orphan and widow,\nbut frustrates the way of the wicked.")
+                      b: String::from("he sustains the orphan and widow,\nbut frustrates the way of the wicked."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("The LORD shall reign for ever, *"),
-                      b: String::from("your God, O Zion, throughout all generations.\nHallelujah!")
+                      b: String::from("your God, O Zion, throughout all generations.\nHallelujah!"),
+                      ..Default::default()
                   },
               ]
             }
-        ]
+        ],
+        ..Default::default()
     };
 }
@@ -16,77 +16,92 @@ lazy_static! {
                             PsalmVerse {
                       number: 1,
                       a: String::from("Hallelujah!\nPraise the LORD from the heavens; *"),
-                      b: String::from("praise him in the heights.")
+                      b: String::from("praise him in the heights."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 2,
                       a: String::from("Praise him, all you angels of his; *"),
-                      b: String::from("praise him, all his host.")
+                      b: String::from("praise him, all his host."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 3,
                       a: String::from("Praise him, sun and moon; *"),
-                      b: String::from("praise him, all you shining stars.")
+                      b: String::from("praise him, all you shining stars."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 4,
                       a: String::from("Praise him, heaven of heavens, *"),
-                      b: String::from("and you waters above the heavens.")
+                      b: String::from("and you waters above the heavens."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 5,
                       a: String::from("Let them praise the Name of the LORD; *"),
-                      b: String::from("for he commanded, and they were created.")
+                      b: String::from("for he commanded, and they were created."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 6,
                       a: String::from("He made them stand fast for ever and ever; *"),
-                      b: String::from("he gave them a law which shall not pass away.")
+                      b: String::from("he gave them a law which shall not pass away."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 7,
                       a: String::from("Praise the LORD from the earth, *"),
-                      b: String::from("you sea-monsters and all deeps;")
+                      b: String::from("you sea-monsters and all deeps;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 8,
                       a: String::from("Fire and hail, snow and fog, *"),
-                      b: String::from("tempestuous wind, doing his will;")
+                      b: String::from("tempestuous wind, doing his will;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 9,
                       a: String::from("Mountains and all hills, *"),
-                      b: String::from("fruit trees and all cedars;")
+                      b: String::from("fruit trees and all cedars;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 10,
                       a: String::from("Wild beasts and all cattle, *"),
-                      b: String::from("creeping things and wingèd birds;")
+                      b: String::from("creeping things and wingèd birds;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 11,
                       a: String::from("Kings of the earth and all peoples, *"),
-                      b: String::from("princes and all rulers of the world;")
+                      b: String::from("princes and all rulers of the world;"),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 12,
                       a: String::from("Young men and maidens, *"),
-                      b: String::from("old and young together.")
+                      b: String::from("old and young together."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 13,
                       a: String::from("Let them praise the Name of the LORD, *"),
-                      b: String::from("for his Name only is exalted,\nhis splendor is over earth and heaven.")
+                      b: String::from("for his Name only is exalted,\nhis splendor is over earth and heaven."),
+                      ..Default::default()
                   },
                 PsalmVerse {
                       number: 14,
                       a: String::from("He has raised up strength for his people\nand praise for all his loyal servants, *"),
-                      b: String::from("the children of Israel, a people who are near him.\nHallelujah!")
+                      b: String::from("the children of Israel, a people who are near him.\nHallelujah!"),
+                      ..Default::default()
                   },
               ]
             }
 
-        ]
+        ],
+        ..Default::default()
 
     };
 }
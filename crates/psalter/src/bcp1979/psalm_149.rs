@@ -14,49 +14,59 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Hallelujah!\nSing to the LORD a new song; *"),
-                    b: String::from("sing his praise in the congregation of the faithful.")
+                    b: String::from("sing his praise in the congregation of the faithful."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Let Israel rejoice in his Maker; *"),
-                    b: String::from("let the children of Zion be joyful in their King.")
+                    b: String::from("let the children of Zion be joyful in their King."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("Let them praise his Name in the dance; *"),
-                    b: String::from("let them sing praise to him with timbrel and harp.")
+                    b: String::from("let them sing praise to him with timbrel and harp."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("For the LORD takes pleasure in his people *"),
-                    b: String::from("and adorns the poor with victory.")
+                    b: String::from("and adorns the poor with victory."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("Let the faithful rejoice in triumph; *"),
-                    b: String::from("let them be joyful on their beds.")
+                    b: String::from("let them be joyful on their beds."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("Let the praises of God be in their throat *"),
-                    b: String::from("and a two-edged sword in their hand;")
+                    b: String::from("and a two-edged sword in their hand;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 7,
                     a: String::from("To wreak vengeance on the nations *"),
-                    b: String::from("and punishment on the peoples;")
+                    b: String::from("and punishment on the peoples;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 8,
                     a: String::from("To bind their kings in chains *"),
-                    b: String::from("and their nobles with links of iron;")
+                    b: String::from("and their nobles with links of iron;"),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 9,
                     a: String::from("To inflict on them the judgment decreed; *"),
-                    b: String::from("this is glory for all his faithful people.\nHallelujah!")
+                    b: String::from("this is glory for all his faithful people.\nHallelujah!"),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
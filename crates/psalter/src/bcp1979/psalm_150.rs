@@ -14,34 +14,41 @@ lazy_static! {
                 PsalmVerse {
                     number: 1,
                     a: String::from("Hallelujah!\nPraise God in his holy temple; *"),
-                    b: String::from("praise him in the firmament of his power.")
+                    b: String::from("praise him in the firmament of his power."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 2,
                     a: String::from("Praise him for his mighty acts; *"),
-                    b: String::from("praise him for his excellent greatness.")
+                    b: String::from("praise him for his excellent greatness."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 3,
                     a: String::from("Praise him with the blast of the ram’s-horn; *"),
-                    b: String::from("Praise him with lyre and harp.")
+                    b: String::from("Praise him with lyre and harp."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 4,
                     a: String::from("Praise him with timbrel and dance; *"),
-                    b: String::from("praise him with strings and pipe.")
+                    b: String::from("praise him with strings and pipe."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 5,
                     a: String::from("Praise him with resounding cymbals; *"),
-                    b: String::from("praise him with loud-clanging cymbals.")
+                    b: String::from("praise him with loud-clanging cymbals."),
+                    ..Default::default()
                 },
                 PsalmVerse {
                     number: 6,
                     a: String::from("Let everything that has breath *"),
-                    b: String::from("praise the Lord.\nHallelujah!")
+                    b: String::from("praise the Lord.\nHallelujah!"),
+                    ..Default::default()
                 },
             ]
-        }]
+        }],
+        ..Default::default()
     };
 }
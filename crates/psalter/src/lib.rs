@@ -91,6 +91,7 @@ impl<'a> Psalter<'a> {
                         // TODO correctly convert this from a section of a citation into Some(String)
                         citation: Some(citation.to_string()),
                         sections: psalm.sections.clone(),
+                        ..Default::default()
                     };
                     let filtered_sections = new_psalm.filtered_sections();
                     new_psalm.sections = filtered_sections;
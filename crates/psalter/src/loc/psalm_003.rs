@@ -15,45 +15,54 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Señor, ¡cuánto se han multiplicado mis adversarios! *"),
-						b: String::from("Muchos son los que se levantan contra mí.")
+						b: String::from("Muchos son los que se levantan contra mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Muchos son los que de mí dicen: *"),
-						b: String::from("\"No hay salvación para él en Dios\".")
+						b: String::from("\"No hay salvación para él en Dios\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Mas tu, oh Señor, eres escudo alrededor de mí; *"),
-						b: String::from("mi gloria, y el que levanta mi cabeza.")
+						b: String::from("mi gloria, y el que levanta mi cabeza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Con mi voz clamé al Señor, *"),
-						b: String::from("y él me respondió desde su santo monte.")
+						b: String::from("y él me respondió desde su santo monte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Yo me acosté y dormí, *"),
-						b: String::from("y desperté, porque el Señor me sustentaba.")
+						b: String::from("y desperté, porque el Señor me sustentaba."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("No temeré a diez millares de gente, *"),
-						b: String::from("que pusieron sitio contra mí.")
+						b: String::from("que pusieron sitio contra mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("¡Levántate, oh Señor; sálvame, oh Dios mío! *"),
-						b: String::from("Por cierto, herirás a todos mis enemigos en la quijada;\nlos dientes de los perversos quebrantarás.")
+						b: String::from("Por cierto, herirás a todos mis enemigos en la quijada;\nlos dientes de los perversos quebrantarás."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("La salvación es del Señor; *"),
-						b: String::from("sobre tu pueblo sea tu bendición.")
+						b: String::from("sobre tu pueblo sea tu bendición."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,80 +15,96 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Escucha, oh Señor, mis palabras; *"),
-						b: String::from("considera mi gemir.")
+						b: String::from("considera mi gemir."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Está atento a la voz de mi clamor, Rey mío y Dios mío, *"),
-						b: String::from("porque a ti suplico.")
+						b: String::from("porque a ti suplico."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Oh Señor, de mañana oirás mi voz; *"),
-						b: String::from("de mañana me presentaré delante de ti, y esperaré;")
+						b: String::from("de mañana me presentaré delante de ti, y esperaré;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Porque tú no eres un Dios que se complace en la maldad; *"),
-						b: String::from("el malo no habitará junto a ti.")
+						b: String::from("el malo no habitará junto a ti."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("No estarán los jactanciosos delante de tus ojos; *"),
-						b: String::from("aborreces a todos los que obran iniquidad.")
+						b: String::from("aborreces a todos los que obran iniquidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Destruirás a los que hablan mentira; *"),
-						b: String::from("al hombre sanguinario y engañador, tú abominas,\noh Señor.")
+						b: String::from("al hombre sanguinario y engañador, tú abominas,\noh Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Mas yo, por la abundancia de tu misericordia, entraré en tu casa; *"),
-						b: String::from("adoraré hacia el santo templo en tu temor.")
+						b: String::from("adoraré hacia el santo templo en tu temor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Guíame, oh Señor, en tu justicia, a causa de mis enemigos; *"),
-						b: String::from("endereza delante de mí tu camino;")
+						b: String::from("endereza delante de mí tu camino;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Porque en la boca de ellos no hay sinceridad; *"),
-						b: String::from("sus entrañas son maldad;")
+						b: String::from("sus entrañas son maldad;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Sepulcro abierto es su garganta; *"),
-						b: String::from("con su lengua hablan lisonjas.")
+						b: String::from("con su lengua hablan lisonjas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Castígalos, oh Dios; *"),
-						b: String::from("caigan por sus mismos consejos.")
+						b: String::from("caigan por sus mismos consejos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Por la multitud de sus transgresiones échalos fuera, *"),
-						b: String::from("porque se rebelaron contra ti.")
+						b: String::from("porque se rebelaron contra ti."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Pero alégrense todos los que en ti confían; *"),
-						b: String::from("den voces de júbilo para siempre;")
+						b: String::from("den voces de júbilo para siempre;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Porque tú los defiendes; *"),
-						b: String::from("en ti se regocijen los que aman tu Nombre;")
+						b: String::from("en ti se regocijen los que aman tu Nombre;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Porque tú, oh Señor, bendecirás al justo; *"),
-						b: String::from("como con un escudo lo rodearás de tu favor.")
+						b: String::from("como con un escudo lo rodearás de tu favor."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
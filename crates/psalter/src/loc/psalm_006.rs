@@ -15,55 +15,66 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Señor, no me reprendas en tu enojo, *"),
-						b: String::from("ni me castigues con tu ira.")
+						b: String::from("ni me castigues con tu ira."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Ten misericordia de mí, oh Señor, porque estoy debilitado; *"),
-						b: String::from("sáname, oh Señor, porque mis huesos se estremecen.")
+						b: String::from("sáname, oh Señor, porque mis huesos se estremecen."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Mi alma también está muy turbada; *"),
-						b: String::from("y tú, oh Señor, ¿hasta cuándo?")
+						b: String::from("y tú, oh Señor, ¿hasta cuándo?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Vuélvete, oh Señor, libra mi vida; *"),
-						b: String::from("sálvame por tu misericordia;")
+						b: String::from("sálvame por tu misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Porque en la muerte no hay memoria de ti; *"),
-						b: String::from("en el sepulcro, ¿quién te alabará?")
+						b: String::from("en el sepulcro, ¿quién te alabará?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Me he consumido a fuerza de gemir; *"),
-						b: String::from("todas las noches inundo de llanto mi lecho,\nriego mi cama con mis lágrimas.")
+						b: String::from("todas las noches inundo de llanto mi lecho,\nriego mi cama con mis lágrimas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Mis ojos están gastados de sufrir; *"),
-						b: String::from("se han envejecido a causa de todos mis angustiadores.")
+						b: String::from("se han envejecido a causa de todos mis angustiadores."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Apártense de mí, todos los hacedores de iniquidad; *"),
-						b: String::from("porque el Señor ha oído la voz de mi llanto.")
+						b: String::from("porque el Señor ha oído la voz de mi llanto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("El Señor ha oído mi ruego; *"),
-						b: String::from("ha recibido el Señor mi oración.")
+						b: String::from("ha recibido el Señor mi oración."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Se avergonzarán y se turbarán todos mis enemigos; *"),
-						b: String::from("se volverán y serán avergonzados de repente.")
+						b: String::from("se volverán y serán avergonzados de repente."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,55 +15,66 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Señor, soberano nuestro, *"),
-						b: String::from("¡cuán glorioso es tu Nombre en toda la tierra!")
+						b: String::from("¡cuán glorioso es tu Nombre en toda la tierra!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Alabadá es tu gloria sobre los cielos, *"),
-						b: String::from("por la boca de los ninos y de los que maman.")
+						b: String::from("por la boca de los ninos y de los que maman."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Has fundado la fortaleza, a causa de tus enemigos, *"),
-						b: String::from("para hacer callar al enemigo y al vengador.")
+						b: String::from("para hacer callar al enemigo y al vengador."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Cuando contemplo tus cielos, obra de tus dedos, *"),
-						b: String::from("la luna y las estrellas que tú formaste,")
+						b: String::from("la luna y las estrellas que tú formaste,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Digo: \"¿Qué es el hombre, para que tengas de él memoria, el hijo del hombre, que lo ampares?\""),
-						b: String::from("")
+						b: String::from(""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Le has hecho poco menor que los ángeles, *"),
-						b: String::from("y lo coronaste de gloria y honra.")
+						b: String::from("y lo coronaste de gloria y honra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Lo hiciste señorear sobre las obras de tus manos; *"),
-						b: String::from("todo lo pusiste debajo de sus pies:")
+						b: String::from("todo lo pusiste debajo de sus pies:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Ovejas y bueyes, todo ello, *"),
-						b: String::from("y asimismo las bestias del campo;")
+						b: String::from("y asimismo las bestias del campo;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Las aves de los cielos y los peces del mar, *"),
-						b: String::from("todo cuanto pasa por los senderos del mar.")
+						b: String::from("todo cuanto pasa por los senderos del mar."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Oh Señor, soberano nuestro, *"),
-						b: String::from("¡cuán glorioso es tu Nombre en toda la tierra!")
+						b: String::from("¡cuán glorioso es tu Nombre en toda la tierra!"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,45 +15,54 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("En el Señor he confiado; *"),
-						b: String::from("¿cómo dicen ustedes a mi alma:\n\"Escapa al monte cual ave?")
+						b: String::from("¿cómo dicen ustedes a mi alma:\n\"Escapa al monte cual ave?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Porque he aquí, los malos tienden el arco, y disponen sus saetas sobre la cuerda, *"),
-						b: String::from("para asaetar en oculto a los rectos de corazón;")
+						b: String::from("para asaetar en oculto a los rectos de corazón;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Si fueren destruidos los fundamentos, *"),
-						b: String::from("¿qué ha de hacer el justo?\"")
+						b: String::from("¿qué ha de hacer el justo?\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("El Señor está en su santo templo; *"),
-						b: String::from("el Señor tiene en el cielo su trono.")
+						b: String::from("el Señor tiene en el cielo su trono."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Sus ojos observan, sus párpados examinan *"),
-						b: String::from("a los habitantes de la tierra.")
+						b: String::from("a los habitantes de la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("El Señor examina al justo y al malo; *"),
-						b: String::from("pero al que ama la violencia lo aborrece.")
+						b: String::from("pero al que ama la violencia lo aborrece."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Sobre los malos hará llover brasas, fuego y azufre; *"),
-						b: String::from("viento abrasador será la porción de su cáliz;")
+						b: String::from("viento abrasador será la porción de su cáliz;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Porque el Señor es justo, y ama la justicia; *"),
-						b: String::from("quien es recto mirará su rostro.\nDía Segundo: oración Vespertina")
+						b: String::from("quien es recto mirará su rostro.\nDía Segundo: oración Vespertina"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
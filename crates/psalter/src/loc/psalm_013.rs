@@ -15,35 +15,42 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¿Hasta cuándo, oh Señor? ¿Me olvidarás para siempre? *"),
-						b: String::from("¿Hasta cuándo esconderás tu rostro de mí?")
+						b: String::from("¿Hasta cuándo esconderás tu rostro de mí?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("¿Hasta cuándo tendré dudas en mi mente, y tristezas en mi corazón cada día? *"),
-						b: String::from("¿Hasta cuándo triunfará mi enemigo sobre mí?")
+						b: String::from("¿Hasta cuándo triunfará mi enemigo sobre mí?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Mira, respóndeme, oh Señor Dios mío; *"),
-						b: String::from("alumbra mis ojos, para que no duerma de muerte;")
+						b: String::from("alumbra mis ojos, para que no duerma de muerte;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Para que no diga mi enemigo: \"Lo vencí\", *"),
-						b: String::from("ni se alegre mi adversario, si yo resbalare.")
+						b: String::from("ni se alegre mi adversario, si yo resbalare."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Mas yo en tu misericordia he confiado; *"),
-						b: String::from("mi corazón se alegrará en tu salvación.")
+						b: String::from("mi corazón se alegrará en tu salvación."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Cantaré al Señor, porque me ha hecho bien; *"),
-						b: String::from("alabaré el Nombre del Señor Altísimo.")
+						b: String::from("alabaré el Nombre del Señor Altísimo."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,40 +15,48 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Señor, ¿quién habitará en tu tabernáculo? *"),
-						b: String::from("¿Quién morará en tu santo monte?")
+						b: String::from("¿Quién morará en tu santo monte?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("El que anda en integridad y hace justicia, *"),
-						b: String::from("y habla verdad en su corazón.")
+						b: String::from("y habla verdad en su corazón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("El que no detrae con su lengua, ni hace mal a su prójimo, *"),
-						b: String::from("ni contra su vecino acoje oprobio alguno.")
+						b: String::from("ni contra su vecino acoje oprobio alguno."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Aquél a cuyos ojos el vil es menospreciado, *"),
-						b: String::from("pero honra a los que temen al Señor.")
+						b: String::from("pero honra a los que temen al Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("El que jurando en daño suyo, *"),
-						b: String::from("no por eso cambia.")
+						b: String::from("no por eso cambia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("El que presta, no esperando de ello nada, *"),
-						b: String::from("ni contra el inocente admite cohecho.")
+						b: String::from("ni contra el inocente admite cohecho."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("El que hace estas cosas, *"),
-						b: String::from("no resbalará para siempre.")
+						b: String::from("no resbalará para siempre."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,97 +15,116 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Te amo, oh Señor, fortaleza mía, *"),
-						b: String::from("oh Señor, castillo mío, mi risco y mi abrigo.")
+						b: String::from("oh Señor, castillo mío, mi risco y mi abrigo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Dios mío, roca mía en quien confiaré, *"),
-						b: String::from("mi escudo, el cuerno de mi salvación y mi alto refugio,\neres digno de ser alabado.")
+						b: String::from("mi escudo, el cuerno de mi salvación y mi alto refugio,\neres digno de ser alabado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Invocaré al Señor, *"),
-						b: String::from("y seré salvo de mis enemigos.")
+						b: String::from("y seré salvo de mis enemigos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Me sumergieron las olas de muerte, *"),
-						b: String::from("y torrentes del abismo me atemorizaron.")
+						b: String::from("y torrentes del abismo me atemorizaron."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Ligaduras infernales me rodearon, *"),
-						b: String::from("previniéronme lazos de muerte.")
+						b: String::from("previniéronme lazos de muerte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("En mi angustia invoqué al Señor, *"),
-						b: String::from("y clamé a mi Dios.")
+						b: String::from("y clamé a mi Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("El oyó mi voz desde su templo, *"),
-						b: String::from("y mi clamor llegó delante de él, a sus oídos.")
+						b: String::from("y mi clamor llegó delante de él, a sus oídos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Se sacudió y tembló la tierra, *"),
-						b: String::from("temblaron los cimientos de los montes,\nse sacudieron, porque él se indignó.")
+						b: String::from("temblaron los cimientos de los montes,\nse sacudieron, porque él se indignó."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Humo subió de su nariz\ny de su boca fuego consumidor; *"),
-						b: String::from("carbones fueron por él encendidos.")
+						b: String::from("carbones fueron por él encendidos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Partió los cielos, y descendió; *"),
-						b: String::from("y había densas tinieblas debajo de sus pies.")
+						b: String::from("y había densas tinieblas debajo de sus pies."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Cabalgó sobre un querubín, y voló; *"),
-						b: String::from("sobre las alas del viento se abalanzó.")
+						b: String::from("sobre las alas del viento se abalanzó."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from(". Puso tinieblas por su escondedero, su pabellón en derredor de sí; *"),
-						b: String::from("oscuridad de aguas, nubes de los cielos.")
+						b: String::from("oscuridad de aguas, nubes de los cielos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Por el resplandor de su presencia, sus nubes pasaron; *"),
-						b: String::from("granizo y carbones ardientes.")
+						b: String::from("granizo y carbones ardientes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Tronó en los cielos el Señor; *"),
-						b: String::from("el Altísimo dio su voz.")
+						b: String::from("el Altísimo dio su voz."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Envió sus saetas, y los disperso; *"),
-						b: String::from("lanzó relámpagos, y los destruyó.")
+						b: String::from("lanzó relámpagos, y los destruyó."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Aparecieron las honduras de las aguas,\ny se descubrieron los cimientos del mundo, *"),
-						b: String::from("a tu grito de guerra, oh Señor,\npor la ráfaga del aliento de tu nariz.")
+						b: String::from("a tu grito de guerra, oh Señor,\npor la ráfaga del aliento de tu nariz."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Alargó la mano desde lo alto, y me agarró; *"),
-						b: String::from("me sacó de las aguas profundas.")
+						b: String::from("me sacó de las aguas profundas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Me libró de mi poderoso enemigo, y de los que me aborrecían, *"),
-						b: String::from("pues eran más fuertes que yo.")
+						b: String::from("pues eran más fuertes que yo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Me asaltaron en el día de mi quebranto, *"),
-						b: String::from("mas el Señor fue mi apoyo.")
+						b: String::from("mas el Señor fue mi apoyo."),
+						..Default::default()
 					}
 				]
 			},
@@ -120,160 +139,192 @@ lazy_static! {
 					PsalmVerse {
 						number: 20,
 						a: String::from("Me sacó a un lugar espacioso; *"),
-						b: String::from("me libró porque se agradó de mí.")
+						b: String::from("me libró porque se agradó de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("El Señor me ha premiado conforme a mi justicia; *"),
-						b: String::from("conforme a la limpieza de mis manos me ha\nrecompensado;")
+						b: String::from("conforme a la limpieza de mis manos me ha\nrecompensado;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Porque yo he guardado los caminos del Señor, *"),
-						b: String::from("y no me aparté impíamente de mi Dios.")
+						b: String::from("y no me aparté impíamente de mi Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Pues todos sus juicios estuvieron delante de mí, *"),
-						b: String::from("y no me he apartado de sus estatutos.")
+						b: String::from("y no me he apartado de sus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Fui íntegro para con él, *"),
-						b: String::from("y me he apartado de iniquidad;")
+						b: String::from("y me he apartado de iniquidad;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("Por lo cual me ha recompensado el Señor conforme a mi justicia, *"),
-						b: String::from("conforme a la limpieza de mis manos delante de su vista.")
+						b: String::from("conforme a la limpieza de mis manos delante de su vista."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Con el fiel te mostrarás fiel, *"),
-						b: String::from("con el íntegro tú eres íntegro.")
+						b: String::from("con el íntegro tú eres íntegro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Con el sincero tú eres sincero; *"),
-						b: String::from("pero con el astuto tú eres sagaz.")
+						b: String::from("pero con el astuto tú eres sagaz."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("Tú salvarás al pueblo humilde, *"),
-						b: String::from("y humillarás los ojos altivos.")
+						b: String::from("y humillarás los ojos altivos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 29,
 						a: String::from("Oh Señor, tú eres mi lámpara; *"),
-						b: String::from("Dios mío, tú alumbras mis tinieblas.")
+						b: String::from("Dios mío, tú alumbras mis tinieblas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 30,
 						a: String::from("Contigo abatiré cualquier baluarte; *"),
-						b: String::from("con mi Dios puedo escalar cualquier muralla.")
+						b: String::from("con mi Dios puedo escalar cualquier muralla."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 31,
 						a: String::from("Dios, perfecto su camino; acrisoladas las palabras del Señor; *"),
-						b: String::from("escudo a todos los que en él esperan;")
+						b: String::from("escudo a todos los que en él esperan;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 32,
 						a: String::from("Porque ¿quién es Dios sino sólo el Señor? *"),
-						b: String::from("¿Qué Roca hay fuera de nuestro Dios?")
+						b: String::from("¿Qué Roca hay fuera de nuestro Dios?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 33,
 						a: String::from("Dios es el que me inviste de fuerza, *"),
-						b: String::from("quien hace perfecto mi camino.")
+						b: String::from("quien hace perfecto mi camino."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 34,
 						a: String::from("El hace mis pies como pies de ciervos, *"),
-						b: String::from("y me hace estar firme sobre las alturas.")
+						b: String::from("y me hace estar firme sobre las alturas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 35,
 						a: String::from("El adiestra mis manos para la batalla, *"),
-						b: String::from("y mis brazos para tensar un arco de bronce.")
+						b: String::from("y mis brazos para tensar un arco de bronce."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 36,
 						a: String::from("Me diste asimismo el escudo de tu victoria; *"),
-						b: String::from("tu diestra me sustentó,\ny tu benignidad me ha engrandecido.")
+						b: String::from("tu diestra me sustentó,\ny tu benignidad me ha engrandecido."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 37,
 						a: String::from("Ensanchaste mis pasos debajo de mí, *"),
-						b: String::from("y no flaquearon mis tobillos.")
+						b: String::from("y no flaquearon mis tobillos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 38,
 						a: String::from("Perseguí a mis enemigos, y los alcancé, *"),
-						b: String::from("y no volví hasta acabarlos.")
+						b: String::from("y no volví hasta acabarlos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 39,
 						a: String::from("Los herí de modo que no se levantasen; *"),
-						b: String::from("cayeron debajo de mis pies.")
+						b: String::from("cayeron debajo de mis pies."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 40,
 						a: String::from("Me investiste de fuerzas para la pelea; *"),
-						b: String::from("has humillado a mis enemigos debajo de mí;\nhas hecho que mis enemigos me vuelvan las espaldas.")
+						b: String::from("has humillado a mis enemigos debajo de mí;\nhas hecho que mis enemigos me vuelvan las espaldas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 41,
 						a: String::from("Destruí a los que me aborrecían; clamaron, y no hubo quien los salvase; *"),
-						b: String::from("aun al Señor, pero no los oyó.")
+						b: String::from("aun al Señor, pero no los oyó."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 42,
 						a: String::from("Los molí como polvo delante del viento; *"),
-						b: String::from("los pisoteé como lodo de las calles.")
+						b: String::from("los pisoteé como lodo de las calles."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 43,
 						a: String::from("Me has librado de las contiendas del pueblo; *"),
-						b: String::from("me has hecho cabeza de las naciones.")
+						b: String::from("me has hecho cabeza de las naciones."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 44,
 						a: String::from("Pueblo que yo no conocía me sirvió; al oír de mí, me obedecieron; *"),
-						b: String::from("extranjeros se acobardaron delante de mí.")
+						b: String::from("extranjeros se acobardaron delante de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 45,
 						a: String::from("Los extranjeros flaquearon, *"),
-						b: String::from("y salieron temblando de sus encierros.")
+						b: String::from("y salieron temblando de sus encierros."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 46,
 						a: String::from("¡Viva el Señor! ¡Bendita sea mi Roca! *"),
-						b: String::from("¡Ensalzado sea el Dios de mi salvación!")
+						b: String::from("¡Ensalzado sea el Dios de mi salvación!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 47,
 						a: String::from("Es el Dios que me dio el desquite, *"),
-						b: String::from("y sometió pueblos debajo de mí;")
+						b: String::from("y sometió pueblos debajo de mí;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 48,
 						a: String::from("El que me libra de mis enemigos,\ny aun me eleva sobre los que se levantan contra mí; *"),
-						b: String::from("me libraste del varón violento.")
+						b: String::from("me libraste del varón violento."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 49,
 						a: String::from("Por tanto yo te confesaré entre las naciones, oh Señor, *"),
-						b: String::from("y cantaré himnos a tu Nombre.")
+						b: String::from("y cantaré himnos a tu Nombre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 50,
 						a: String::from("Grandes triunfos da a su rey; *"),
-						b: String::from("hace misericordia a su ungido,\na David y a su descendencia para siempre.")
+						b: String::from("hace misericordia a su ungido,\na David y a su descendencia para siempre."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
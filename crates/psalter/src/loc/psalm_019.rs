@@ -15,75 +15,90 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Los cielos proclaman la gloria de Dios, *"),
-						b: String::from("y la bóveda celeste pregona las obras de sus manos.")
+						b: String::from("y la bóveda celeste pregona las obras de sus manos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Un día emite palabra al otro día, *"),
-						b: String::from("y una noche a la otra noche imparte sabiduría.")
+						b: String::from("y una noche a la otra noche imparte sabiduría."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Aunque no hay palabras, ni lenguaje, *"),
-						b: String::from("ni son oídas sus voces,")
+						b: String::from("ni son oídas sus voces,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Por toda la tierra salió su sonido, *"),
-						b: String::from("y hasta el extremo del mundo su mensaje.")
+						b: String::from("y hasta el extremo del mundo su mensaje."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("En el mar puso tabernáculo para el sol, *"),
-						b: String::from("y éste, como esposo que sale de su alcoba,\nse alegra cual paladín para correr su camino.")
+						b: String::from("y éste, como esposo que sale de su alcoba,\nse alegra cual paladín para correr su camino."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("De un extremo de los cielos es su salida, y su curso hasta el término de ellos; *"),
-						b: String::from("nada hay que se esconda de su calor.")
+						b: String::from("nada hay que se esconda de su calor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("La ley del Señor es perfecta,\nque aviva el alma; *"),
-						b: String::from("el testimonio del Señor es fiel,\nque hace sabio al sencillo.")
+						b: String::from("el testimonio del Señor es fiel,\nque hace sabio al sencillo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Los mandamientos del Señor son rectos,\nque alegran el corazón; *"),
-						b: String::from("el precepto del Señor es claro,\nque alumbra los ojos.")
+						b: String::from("el precepto del Señor es claro,\nque alumbra los ojos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("El temor del Señor es limpio,\nque permanece para siempre; *"),
-						b: String::from("los juicios del Señor son verdad, completamente justos.")
+						b: String::from("los juicios del Señor son verdad, completamente justos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Deseables son, más que el oro,\nmás que oro fino; *"),
-						b: String::from("dulce más que miel,\nque la que destila del panal.")
+						b: String::from("dulce más que miel,\nque la que destila del panal."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Tu siervo es además por ellos alumbrado, *"),
-						b: String::from("y al guardarlos hay grande galardón.")
+						b: String::from("y al guardarlos hay grande galardón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("¿Quién podrá entender sus propios errores? *"),
-						b: String::from("Líbrame de los que me son ocultos.")
+						b: String::from("Líbrame de los que me son ocultos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Preserva también a tu siervo de las soberbias, que no se enseñoreen de mí; *"),
-						b: String::from("entonces seré íntegro,\ny estaré limpio del gran pecado.")
+						b: String::from("entonces seré íntegro,\ny estaré limpio del gran pecado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Sean gratos los dichos de mi boca\ny la meditación de mi corazón delante de ti, *"),
-						b: String::from("oh Señor, Roca mía y Redentor mío.")
+						b: String::from("oh Señor, Roca mía y Redentor mío."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,50 +15,60 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Que Dios te escuche en el día de asedio, *"),
-						b: String::from("el Nombre del Dios de Jacob sea tu baluarte;")
+						b: String::from("el Nombre del Dios de Jacob sea tu baluarte;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Te envíe ayuda desde su santuario, *"),
-						b: String::from("y te sostenga desde Sión;")
+						b: String::from("y te sostenga desde Sión;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Haga memoria de todas tus ofrendas, *"),
-						b: String::from("y acepte tu holocausto;")
+						b: String::from("y acepte tu holocausto;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Te dé conforme al deseo de tu corazón, *"),
-						b: String::from("y cumpla todos tus designios.")
+						b: String::from("y cumpla todos tus designios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Nos alegraremos en tu victoria,\ny alzaremos pendón en Nombre de nuestro Dios; *"),
-						b: String::from("que el Señor conceda todas tus peticiones.")
+						b: String::from("que el Señor conceda todas tus peticiones."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Ahora sé que el Señor ha dado la victoria a su ungido; *"),
-						b: String::from("lo ha escuchado desde su santo cielo\ncon la fuerza victoriosa de su diestra.")
+						b: String::from("lo ha escuchado desde su santo cielo\ncon la fuerza victoriosa de su diestra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Unos confían en carros de guerra, y otros en caballos, *"),
-						b: String::from("mas nosotros invocaremos el Nombre del Señor\nnuestro Dios.")
+						b: String::from("mas nosotros invocaremos el Nombre del Señor\nnuestro Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Ellos se hunden y caen, *"),
-						b: String::from("mas nosotros nos levantamos y estamos de pie.")
+						b: String::from("mas nosotros nos levantamos y estamos de pie."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Otorga victoria al rey, oh Señor, *"),
-						b: String::from("y escúchanos cuando te invocamos.")
+						b: String::from("y escúchanos cuando te invocamos."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
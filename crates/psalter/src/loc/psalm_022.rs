@@ -15,155 +15,186 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Dios mío, Dios mío, ¿Por qué me has desamparado? *"),
-						b: String::from("¿Por qué estás lejos de mi súplica,\ny de las palabras de mi clamor?")
+						b: String::from("¿Por qué estás lejos de mi súplica,\ny de las palabras de mi clamor?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Dios mío, clamo de día, y no respondes; *"),
-						b: String::from("de noche también, y no hay para mí reposo.")
+						b: String::from("de noche también, y no hay para mí reposo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Pero tú eres el Santo, *"),
-						b: String::from("entronizado sobre las alabanzas de Israel.")
+						b: String::from("entronizado sobre las alabanzas de Israel."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("En ti esperaron nuestros antepasados; *"),
-						b: String::from("esperaron, y tú los libraste.")
+						b: String::from("esperaron, y tú los libraste."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Clamaron a ti, y fueron librados; *"),
-						b: String::from("confiaron en ti, y no fueron avergonzados.")
+						b: String::from("confiaron en ti, y no fueron avergonzados."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Mas yo soy gusano, y no hombre, *"),
-						b: String::from("oprobio de todos y desprecio del pueblo.")
+						b: String::from("oprobio de todos y desprecio del pueblo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Todos los que me ven, escarnecen de mí; *"),
-						b: String::from("estiran los labios y menean la cabeza, diciendo:")
+						b: String::from("estiran los labios y menean la cabeza, diciendo:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("\"Acudió al Señor, líbrele él; *"),
-						b: String::from("sálvele, si tanto lo quiere\".")
+						b: String::from("sálvele, si tanto lo quiere\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Pero tú eres el que me sacó del vientre, *"),
-						b: String::from("y me tenías confiado en los pechos de mi madre.")
+						b: String::from("y me tenías confiado en los pechos de mi madre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("A ti fui entregado antes de nacer, *"),
-						b: String::from("desde el vientre de mi madre, tú eres mi Dios.")
+						b: String::from("desde el vientre de mi madre, tú eres mi Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("No te alejes de mí, porque la angustia está cerca, *"),
-						b: String::from("porque no hay quien ayude.")
+						b: String::from("porque no hay quien ayude."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Me rodean muchos novillos; *"),
-						b: String::from("fuertes toros de Basán me circundan.")
+						b: String::from("fuertes toros de Basán me circundan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Abren sobre mí las bocas, *"),
-						b: String::from("como león rapante y rugiente.")
+						b: String::from("como león rapante y rugiente."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Soy derramado como aguas;\ntodos mis huesos se descoyuntan; *"),
-						b: String::from("mi corazón, como cera, se derrite en mis entrañas.")
+						b: String::from("mi corazón, como cera, se derrite en mis entrañas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Como un tiesto está seca mi boca; mi lengua se pega al paladar; *"),
-						b: String::from("y me has puesto en el polvo de la muerte;")
+						b: String::from("y me has puesto en el polvo de la muerte;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Porque jaurías de perros me rodean, y pandillas de malignos me cercan; *"),
-						b: String::from("horadan mis manos y mis pies; contar puedo todos mis huesos.")
+						b: String::from("horadan mis manos y mis pies; contar puedo todos mis huesos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Me miran de hito en hito, y con satisfacción maligna; *"),
-						b: String::from("reparten entre sí mis vestidos;\nsobre mi ropa echan suertes.")
+						b: String::from("reparten entre sí mis vestidos;\nsobre mi ropa echan suertes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Mas tú, oh Señor, no te alejes; *"),
-						b: String::from("fortaleza mía, apresúrate a socorrerme.")
+						b: String::from("fortaleza mía, apresúrate a socorrerme."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Salva de la espada mi garganta, *"),
-						b: String::from("mi faz del filo del hacha.")
+						b: String::from("mi faz del filo del hacha."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Sálvame de la boca del león, *"),
-						b: String::from("a este pobre, de los cuernos del búfalo.")
+						b: String::from("a este pobre, de los cuernos del búfalo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Proclamaré tu Nombre a mis hermanos; *"),
-						b: String::from("en medio de la congregación te alabaré.")
+						b: String::from("en medio de la congregación te alabaré."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Los que temen al Señor, alábenle; *"),
-						b: String::from("glorifíquenle, oh vástago de Jacob;\ntengan miedo de él, oh descendencia de Israel;")
+						b: String::from("glorifíquenle, oh vástago de Jacob;\ntengan miedo de él, oh descendencia de Israel;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Porque no menospreció ni abominó la aflicción de los afligidos,\nni de ellos escondió su rostro; *"),
-						b: String::from("sino que cuando clamaron a él, los oyó.")
+						b: String::from("sino que cuando clamaron a él, los oyó."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("De ti será mi alabanza en la gran congregación; *"),
-						b: String::from("mis votos pagaré delante de los que le temen.")
+						b: String::from("mis votos pagaré delante de los que le temen."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("Comerán los pobres, y serán saciados, alabarán al Señor los que le buscan: *"),
-						b: String::from("¡Viva su corazón para siempre!")
+						b: String::from("¡Viva su corazón para siempre!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Se acordarán y se volverán al Señor todos los confines de la tierra, *"),
-						b: String::from("y todas las familias de las naciones delante de ti se inclinan")
+						b: String::from("y todas las familias de las naciones delante de ti se inclinan"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Porque del Señor es el reino, *"),
-						b: String::from("y él rige las naciones.")
+						b: String::from("y él rige las naciones."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("Sólo ante él se postrarán los que duermen en la tierra; *"),
-						b: String::from("delante de él doblarán la rodilla\ntodos los que bajan al polvo.")
+						b: String::from("delante de él doblarán la rodilla\ntodos los que bajan al polvo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 29,
 						a: String::from("Me hará vivir para él;\nmi descendencia le servirá; *"),
-						b: String::from("será contada como suya para siempre.")
+						b: String::from("será contada como suya para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 30,
 						a: String::from("Vendrán y anunciarán al pueblo aún no nacido *"),
-						b: String::from("los hechos asombrosos que hizo.")
+						b: String::from("los hechos asombrosos que hizo."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
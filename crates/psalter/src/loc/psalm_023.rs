@@ -15,35 +15,42 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("El Señor es mi pastor; *"),
-						b: String::from("nada me faltará.")
+						b: String::from("nada me faltará."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("En verdes pastos me hace yacer; *"),
-						b: String::from("me conduce hacia aguas tranquilas.")
+						b: String::from("me conduce hacia aguas tranquilas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Aviva mi alma *"),
-						b: String::from("y me guía por sendas seguras por amor de su Nombre.")
+						b: String::from("y me guía por sendas seguras por amor de su Nombre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Aunque ande en valle de sombra de muerte, no temeré mal alguno; *"),
-						b: String::from("porque tú estás conmigo;\ntu vara y tu cayado me infunden aliento.")
+						b: String::from("porque tú estás conmigo;\ntu vara y tu cayado me infunden aliento."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Aderezarás mesa delante de mi\nen presencia de mis angustiadores; *"),
-						b: String::from("unges mi cabeza con óleo; mi copa está rebosando.")
+						b: String::from("unges mi cabeza con óleo; mi copa está rebosando."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Ciertamente el bien y la misericordia me seguirán todos los días de mi vida, *"),
-						b: String::from("y en la casa del Señor moraré por largos días.")
+						b: String::from("y en la casa del Señor moraré por largos días."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,65 +15,78 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Júzgame, oh Señor,\nporque en integridad he andado; *"),
-						b: String::from("he confiado asimismo en el Señor sin titubear.")
+						b: String::from("he confiado asimismo en el Señor sin titubear."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Escudríñame, oh Señor, y pruébame; *"),
-						b: String::from("examina mis pensamientos y mi corazón;")
+						b: String::from("examina mis pensamientos y mi corazón;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Porque tu amor está delante de mis ojos; *"),
-						b: String::from("he andado fielmente contigo.")
+						b: String::from("he andado fielmente contigo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("No he frecuentado personas inútiles, *"),
-						b: String::from("ni me he asociado con los engañadores.")
+						b: String::from("ni me he asociado con los engañadores."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Aborrecí la reunión de los malhechores, *"),
-						b: String::from("y con los impíos nunca me sentaré.")
+						b: String::from("y con los impíos nunca me sentaré."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Lavaré en inocencia mis manos, *"),
-						b: String::from("y así andaré alrededor de tu altar, oh Señor,")
+						b: String::from("y así andaré alrededor de tu altar, oh Señor,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Cantando himnos de alabanza, *"),
-						b: String::from("y contando todas tus obras maravillosas.")
+						b: String::from("y contando todas tus obras maravillosas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Señor, la habitación de tu casa yo amo, *"),
-						b: String::from("y el lugar de la morada de tu gloria.")
+						b: String::from("y el lugar de la morada de tu gloria."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("No arrebates mi alma con los pecadores, *"),
-						b: String::from("ni mi vida con los sanguinarios,")
+						b: String::from("ni mi vida con los sanguinarios,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Cuyas manos están llenas de tramas, *"),
-						b: String::from("y cuya diestra está llena de sobornos.")
+						b: String::from("y cuya diestra está llena de sobornos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Mas yo andaré en integridad; *"),
-						b: String::from("redímeme, oh Señor, y ten misericordia de mí.")
+						b: String::from("redímeme, oh Señor, y ten misericordia de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Mi pie se mantiene firme en medio de los justos; *"),
-						b: String::from("en las asambleas bendeciré al Señor.")
+						b: String::from("en las asambleas bendeciré al Señor."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,60 +15,72 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("A ti, oh Señor, clamo;\nRoca mía, no me desatiendas; *"),
-						b: String::from("para que no sea yo, dejándome tú, semejante a los que descienden a la fosa.")
+						b: String::from("para que no sea yo, dejándome tú, semejante a los que descienden a la fosa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Oye la voz de mis ruegos cuando clamo a ti, *"),
-						b: String::from("cuando alzo mis manos hacia tu lugar santísimo.")
+						b: String::from("cuando alzo mis manos hacia tu lugar santísimo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("No me arrebates con los malos, y con los que hacen iniquidad, *"),
-						b: String::from("los cuales hablan paz con su prójimo, pero la maldad está en su corazón.")
+						b: String::from("los cuales hablan paz con su prójimo, pero la maldad está en su corazón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Dales conforme a su obra, *"),
-						b: String::from("y conforme a la perversidad de sus hechos.")
+						b: String::from("y conforme a la perversidad de sus hechos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Dales su merecido, *"),
-						b: String::from("conforme a la obra de sus manos;")
+						b: String::from("conforme a la obra de sus manos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Porque no atendieron a las obras del Señor, ni a los hechos de sus manos, *"),
-						b: String::from("él los derribará, y no los edificará.")
+						b: String::from("él los derribará, y no los edificará."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("¡Bendito sea el Señor! *"),
-						b: String::from("porque ha oído la voz de mis ruegos.")
+						b: String::from("porque ha oído la voz de mis ruegos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("El Señor es mi fortaleza y mi escudo; *"),
-						b: String::from("en él confía mi corazón, y fui ayudado.")
+						b: String::from("en él confía mi corazón, y fui ayudado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Por ello salta mi corazón con júbilo, *"),
-						b: String::from("y con mi canción le alabaré.")
+						b: String::from("y con mi canción le alabaré."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("El Señor es la fortaleza de su pueblo, *"),
-						b: String::from("el refugio de su ungido.")
+						b: String::from("el refugio de su ungido."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Salva a tu pueblo, y bendice a tu heredad; *"),
-						b: String::from("pastoréales y susténtales para siempre.")
+						b: String::from("pastoréales y susténtales para siempre."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
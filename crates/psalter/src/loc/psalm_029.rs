@@ -15,60 +15,72 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Den al Señor, oh seres celestiales, *"),
-						b: String::from("den al Señor la gloria y la fortaleza.")
+						b: String::from("den al Señor la gloria y la fortaleza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Den al Señor la gloria debida a su Nombre; *"),
-						b: String::from("adoren al Señor en la hermosura de su santidad.")
+						b: String::from("adoren al Señor en la hermosura de su santidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("La voz del Señor sobre las aguas; truena el Dios de gloria; *"),
-						b: String::from("el Señor sobre las grandes aguas.")
+						b: String::from("el Señor sobre las grandes aguas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("La voz del Señor es voz potente; *"),
-						b: String::from("la voz del Señor es voz gloriosa.")
+						b: String::from("la voz del Señor es voz gloriosa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("La voz del Señor quebranta los cedros; *"),
-						b: String::from("el Señor quebranta los cedros del Líbano.")
+						b: String::from("el Señor quebranta los cedros del Líbano."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Hace saltar al Líbano como becerro, *"),
-						b: String::from("al Hermón como hijuelo de búfalo.")
+						b: String::from("al Hermón como hijuelo de búfalo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("La voz del Señor divide las llamas de fuego; la voz del Señor hace temblar el desierto; *"),
-						b: String::from("hace temblar el Señor el desierto de Cades.")
+						b: String::from("hace temblar el Señor el desierto de Cades."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("La voz del Señor tuerce las encinas, *"),
-						b: String::from("y desnuda los bosques.")
+						b: String::from("y desnuda los bosques."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Mientras, en el templo del Señor *"),
-						b: String::from("todo proclama su gloria.")
+						b: String::from("todo proclama su gloria."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("El Señor se sienta por encima del diluvio; *"),
-						b: String::from("el Señor se sienta como Rey por siempre jamás.")
+						b: String::from("el Señor se sienta como Rey por siempre jamás."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("El Señor dará fortaleza a su pueblo; *"),
-						b: String::from("el Señor bendecirá a su pueblo con la paz.")
+						b: String::from("el Señor bendecirá a su pueblo con la paz."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,70 +15,84 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Te ensalzaré, oh Señor, porque me has alzado, *"),
-						b: String::from("y no permitiste que mis enemigos triunfaran sobre mí.")
+						b: String::from("y no permitiste que mis enemigos triunfaran sobre mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Oh Señor Dios mío, a ti clamé, *"),
-						b: String::from("y tú me sanaste.")
+						b: String::from("y tú me sanaste."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Oh Señor, me sacaste del abismo; *"),
-						b: String::from("me hiciste revivir, para que no descendiese a la\nsepultura.")
+						b: String::from("me hiciste revivir, para que no descendiese a la\nsepultura."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Canten al Señor, ustedes sus fieles, *"),
-						b: String::from("y celebren su santo Nombre;")
+						b: String::from("y celebren su santo Nombre;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Porque sólo un momento dura su ira, *"),
-						b: String::from("pero su favor toda la vida.")
+						b: String::from("pero su favor toda la vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Aunque al anochecer nos visite el llanto, *"),
-						b: String::from("en la mañana vendrá la alegría.")
+						b: String::from("en la mañana vendrá la alegría."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Dije yo en mi comodidad, \"No seré jamás conmovido; *"),
-						b: String::from("tú, oh Señor, con tu favor\nme afirmaste como monte fuerte\".")
+						b: String::from("tú, oh Señor, con tu favor\nme afirmaste como monte fuerte\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Luego escondiste tu rostro, *"),
-						b: String::from("y fui muy turbado.")
+						b: String::from("y fui muy turbado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("A ti, oh Señor, clamé, *"),
-						b: String::from("y a mi Soberano supliqué, diciendo:")
+						b: String::from("y a mi Soberano supliqué, diciendo:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("\"¿Qué provecho hay en mi muerte, cuando yo descienda a la fosa? *"),
-						b: String::from("¿Te alabará el polvo? ¿Anunciará tu fidelidad?")
+						b: String::from("¿Te alabará el polvo? ¿Anunciará tu fidelidad?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Escucha, oh Señor, y ten misericordia de mí; oh Señor sé tú mi ayudador.\""),
-						b: String::from("")
+						b: String::from(""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Has cambiado mi lamento en danzas; *"),
-						b: String::from("me has quitado el luto, y me has vestido de fiesta.")
+						b: String::from("me has quitado el luto, y me has vestido de fiesta."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Por tanto a ti canta mi corazón, y no llora más; *"),
-						b: String::from("oh Señor Dios mío, te daré gracias para siempre.")
+						b: String::from("oh Señor Dios mío, te daré gracias para siempre."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,125 +15,150 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("En ti, oh Señor, he esperado;\nno sea yo avergonzado jamás; *"),
-						b: String::from("líbrame en tu justicia.")
+						b: String::from("líbrame en tu justicia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Inclina a mí tu oído; *"),
-						b: String::from("apresúrate a librarme.")
+						b: String::from("apresúrate a librarme."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Sé tú mi roca fuerte, y fortaleza para salvarme; porque tú eres mi risco y mi castillo; *"),
-						b: String::from("por tu Nombre me guiarás y me encaminarás.")
+						b: String::from("por tu Nombre me guiarás y me encaminarás."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Me sacarás de la red que han escondido para mí, *"),
-						b: String::from("pues tú eres mi refugio.")
+						b: String::from("pues tú eres mi refugio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("En tu mano encomiendo mi espíritu; *"),
-						b: String::from("tú me has redimido, oh Señor, Dios de verdad.")
+						b: String::from("tú me has redimido, oh Señor, Dios de verdad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Aborrezco a los que se adhieren a ídolos inútiles, *"),
-						b: String::from("y pongo mi confianza en el Señor.")
+						b: String::from("y pongo mi confianza en el Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Me gozaré y alegraré en tu misericordia; *"),
-						b: String::from("porque has visto mi aflicción;\nconoces la angustia de mi vida.")
+						b: String::from("porque has visto mi aflicción;\nconoces la angustia de mi vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("No me entregaste en mano del enemigo; *"),
-						b: String::from("pusiste mis pies en lugar espacioso.")
+						b: String::from("pusiste mis pies en lugar espacioso."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Ten misericordia de mí, oh Señor, que estoy en angustia; *"),
-						b: String::from("se han consumido de tristeza mis ojos,\nmi garganta también y mi vientre;")
+						b: String::from("se han consumido de tristeza mis ojos,\nmi garganta también y mi vientre;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Porque mi vida se va gastando de dolor, y mis años de suspirar; *"),
-						b: String::from("se agotan mis fuerzas a causa de mi aflicción, y mis huesos se han consumido.")
+						b: String::from("se agotan mis fuerzas a causa de mi aflicción, y mis huesos se han consumido."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("De todos mis enemigos he sido oprobio, y de mis vecinos mucho más,\ny pavor a mis conocidos; *"),
-						b: String::from("los que me ven fuera huyen de mí.")
+						b: String::from("los que me ven fuera huyen de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("He sido olvidado como un muerto, desechado de toda memoria; *"),
-						b: String::from("he venido a ser como un vaso quebrado.")
+						b: String::from("he venido a ser como un vaso quebrado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Porque he oído el cuchicheo de muchos; \"por todos lados hay miedo\"; *"),
-						b: String::from("consultan juntos contra mí; conspiran para quitarme la vida.")
+						b: String::from("consultan juntos contra mí; conspiran para quitarme la vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Mas yo en ti confío, oh Señor; *"),
-						b: String::from("dije: \"Tú eres mi Dios.")
+						b: String::from("dije: \"Tú eres mi Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("En tu mano está mi destino; *"),
-						b: String::from("líbrame de la mano de mis enemigos,\ny de mis perseguidores.")
+						b: String::from("líbrame de la mano de mis enemigos,\ny de mis perseguidores."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Haz resplandecer tu rostro sobre tu siervo; *"),
-						b: String::from("sálvame por tu misericordia\".")
+						b: String::from("sálvame por tu misericordia\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("No sea yo avergonzado, oh Señor, ya que te he invocado; *"),
-						b: String::from("sean avergonzados los malvados;\nestén mudos en el sepulcro.")
+						b: String::from("sean avergonzados los malvados;\nestén mudos en el sepulcro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Enmudezcan los labios mentirosos,\nque hablan insolencias contra el justo, *"),
-						b: String::from("con soberbia y menosprecio.")
+						b: String::from("con soberbia y menosprecio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("¡Cuán grande es tu bondad, oh Señor! que has guardado para los que te temen; *"),
-						b: String::from("que has mostrado, delante de todos, a los que confían en ti.")
+						b: String::from("que has mostrado, delante de todos, a los que confían en ti."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("En lo secreto de tu presencia los escondes de cuantos los calumnian; *"),
-						b: String::from("los resguardas en tu abrigo de la querella de lenguas.")
+						b: String::from("los resguardas en tu abrigo de la querella de lenguas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("¡Bendito sea el Señor! *"),
-						b: String::from("me ha demostrado la maravilla de su amor\nen ciudad sitiada.")
+						b: String::from("me ha demostrado la maravilla de su amor\nen ciudad sitiada."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Decía yo en mi desmayo,\n\"Cortado soy de delante de tus ojos\", *"),
-						b: String::from("pero tú oíste la voz de mis ruegos, cuando a ti clamaba.")
+						b: String::from("pero tú oíste la voz de mis ruegos, cuando a ti clamaba."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Amen al Señor, todos ustedes que le adoran; *"),
-						b: String::from("a los fieles guarda el Señor,\ny castiga con creces a los que obran con soberbia.")
+						b: String::from("a los fieles guarda el Señor,\ny castiga con creces a los que obran con soberbia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Fortalézcanse los que esperan en el Señor, *"),
-						b: String::from("y tome su corazón aliento.")
+						b: String::from("y tome su corazón aliento."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,115 +15,138 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Alégrense, justos, en el Señor; *"),
-						b: String::from("a los rectos es conveniente la alabanza,")
+						b: String::from("a los rectos es conveniente la alabanza,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Celebren al Señor con arpa; *"),
-						b: String::from("táñanle con salterio y decacordio.")
+						b: String::from("táñanle con salterio y decacordio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Cántenle canción nueva; *"),
-						b: String::from("toquen la trompeta con destreza;")
+						b: String::from("toquen la trompeta con destreza;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Porque recta es la palabra del Señor, *"),
-						b: String::from("y toda su obra es hecha con fidelidad.")
+						b: String::from("y toda su obra es hecha con fidelidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("El ama justicia y juicio; *"),
-						b: String::from("de la misericordia del Señor está llena la tierra")
+						b: String::from("de la misericordia del Señor está llena la tierra"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Por la palabra del Señor fueron hechos los cielos, *"),
-						b: String::from("y el ejército de los cielos por el aliento de su boca.")
+						b: String::from("y el ejército de los cielos por el aliento de su boca."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("El junta como en un odre las aguas de la mar; *"),
-						b: String::from("él pone en depósitos los abismos.")
+						b: String::from("él pone en depósitos los abismos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Tema al Señor toda la tierra; *"),
-						b: String::from("teman delante de él todos los habitantes del mundo")
+						b: String::from("teman delante de él todos los habitantes del mundo"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Porque él dijo, y fue hecho; *"),
-						b: String::from("él mandó, y existió.")
+						b: String::from("él mandó, y existió."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("El Señor hace nula la voluntad de las gentes, *"),
-						b: String::from("y frustra las maquinaciones de los pueblos.")
+						b: String::from("y frustra las maquinaciones de los pueblos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Pero la voluntad del Señor permanece para siempre, *"),
-						b: String::from("los designios de su corazón por todas las generaciones.")
+						b: String::from("los designios de su corazón por todas las generaciones."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Bienaventurada la nación cuyo Dios es el Señor; *"),
-						b: String::from("bienaventurado el pueblo que él escogió para sí.")
+						b: String::from("bienaventurado el pueblo que él escogió para sí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Desde el cielo mira el Señor, *"),
-						b: String::from("y ve a todos los seres humanos.")
+						b: String::from("y ve a todos los seres humanos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Desde el lugar de su morada observa *"),
-						b: String::from("a todos los moradores de la tierra.")
+						b: String::from("a todos los moradores de la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("El formó el corazón de todos ellos; *"),
-						b: String::from("atento está a todas sus obras.")
+						b: String::from("atento está a todas sus obras."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("El rey no se salva por la multitud del ejército, *"),
-						b: String::from("ni escapa el valiente por la mucha fuerza.")
+						b: String::from("ni escapa el valiente por la mucha fuerza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Vano para salvar es el caballo; *"),
-						b: String::from("la grandeza de su fuerza a nadie podrá librar.")
+						b: String::from("la grandeza de su fuerza a nadie podrá librar."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("He aquí el ojo del Señor sobre los que le temen, *"),
-						b: String::from("sobre los que esperan en su misericordia;")
+						b: String::from("sobre los que esperan en su misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Para arrancar sus vidas de la muerte, *"),
-						b: String::from("y para sustentarles en tiempo de hambre.")
+						b: String::from("y para sustentarles en tiempo de hambre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Nuestra alma espera al Señor; *"),
-						b: String::from("nuestra ayuda y nuestro escudo es él.")
+						b: String::from("nuestra ayuda y nuestro escudo es él."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Por tanto en él se alegra nuestro corazón, *"),
-						b: String::from("porque en su santo Nombre confiamos.")
+						b: String::from("porque en su santo Nombre confiamos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Sea tu misericordia, oh Señor, sobre nosotros, *"),
-						b: String::from("según ponemos nuestra confianza en ti.")
+						b: String::from("según ponemos nuestra confianza en ti."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
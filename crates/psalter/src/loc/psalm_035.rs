@@ -15,145 +15,174 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Contiende, oh Señor, con los que contra mí contienden; *"),
-						b: String::from("combate a los que me combaten.")
+						b: String::from("combate a los que me combaten."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Echa mano al escudo y a la armadura, *"),
-						b: String::from("y levántate en mi ayuda.")
+						b: String::from("y levántate en mi ayuda."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Saca la lanza, y cierra el paso a los que me persiguen; *"),
-						b: String::from("di a mi alma: \"Yo soy tu salvación\".")
+						b: String::from("di a mi alma: \"Yo soy tu salvación\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Sean avergonzados y humillados los que buscan mi vida; *"),
-						b: String::from("sean vueltos atrás y abochornados los que mi mal intentan.")
+						b: String::from("sean vueltos atrás y abochornados los que mi mal intentan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Sean como la paja delante del viento, *"),
-						b: String::from("y el ángel del Señor los eche fuera.")
+						b: String::from("y el ángel del Señor los eche fuera."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Sea su camino tenebroso y resbaladizo, *"),
-						b: String::from("y el ángel del Señor los persiga;")
+						b: String::from("y el ángel del Señor los persiga;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Porque sin causa me tendieron su red; *"),
-						b: String::from("sin causa cavaron hoyo para enredarme vivo.")
+						b: String::from("sin causa cavaron hoyo para enredarme vivo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Sorpréndales la ruina sin que lo sepan; *"),
-						b: String::from("y su red que escondieron los prenda;\ncaigan en el hoyo que cavaron.")
+						b: String::from("y su red que escondieron los prenda;\ncaigan en el hoyo que cavaron."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Entonces mi alma se alegrará en el Señor; *"),
-						b: String::from("se regocijará en su victoria.")
+						b: String::from("se regocijará en su victoria."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Todos mis huesos dirán: \"Señor, ¿quién como tú? *"),
-						b: String::from("tú libras al afligido del que es más fuerte,\nal pobre y menesteroso del que le despoja\".")
+						b: String::from("tú libras al afligido del que es más fuerte,\nal pobre y menesteroso del que le despoja\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Se levantan contra mí testigos malvados; *"),
-						b: String::from("de lo que no sé, me acusan.")
+						b: String::from("de lo que no sé, me acusan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Me devuelven mal por bien, *"),
-						b: String::from("abatiendo mi alma.")
+						b: String::from("abatiendo mi alma."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Mas yo, cuando ellos enfermaron, me vestí de luto, *"),
-						b: String::from("me afligí con ayuno.")
+						b: String::from("me afligí con ayuno."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Oré con todo mi corazón,\ncomo se ora por un compañero o por un hermano; *"),
-						b: String::from("como el que lleva luto por su madre, enlutado me humillaba.")
+						b: String::from("como el que lleva luto por su madre, enlutado me humillaba."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Pero ellos se alegraron cuando yo tropecé, y se juntaron; se juntaron contra mí desconocidos; *"),
-						b: String::from("los que yo no conocía me despedazaban sin cesar.")
+						b: String::from("los que yo no conocía me despedazaban sin cesar."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Me pusieron a prueba, y se burlaron, *"),
-						b: String::from("crujiendo sobre mí sus dientes.")
+						b: String::from("crujiendo sobre mí sus dientes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Oh Soberano mió, ¿hasta cuándo verás esto? *"),
-						b: String::from("Rescátame de las bestias rugientes,\nmi vida de los leoncillos.")
+						b: String::from("Rescátame de las bestias rugientes,\nmi vida de los leoncillos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Te daré gracias en la gran asamblea; *"),
-						b: String::from("te alabaré entre la multitud del pueblo.")
+						b: String::from("te alabaré entre la multitud del pueblo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("No se alegren de mí, mis enemigos traidores, *"),
-						b: String::from("ni los que me aborrecen sin causa guiñen el ojo.")
+						b: String::from("ni los que me aborrecen sin causa guiñen el ojo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Porque no hablan paz, *"),
-						b: String::from("mas contra los mansos de la tierra traman engaños.")
+						b: String::from("mas contra los mansos de la tierra traman engaños."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Abrieron su boca, y me dijeron: *"),
-						b: String::from("\"¡Ajá! nuestros ojos lo han visto\".")
+						b: String::from("\"¡Ajá! nuestros ojos lo han visto\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Tú lo has visto, oh Señor; no calles; *"),
-						b: String::from("Soberano mío, no te alejes de mí.")
+						b: String::from("Soberano mío, no te alejes de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("¡Muévete y despierta! ¡A mi causa! *"),
-						b: String::from("A mi defensa, Dios mío y Soberano mío!")
+						b: String::from("A mi defensa, Dios mío y Soberano mío!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Júzgame conforme a tu justicia, oh Señor Dios mío; *"),
-						b: String::from("no permitas que triunfen sobre mí.")
+						b: String::from("no permitas que triunfen sobre mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("No digan en su corazón: \"¡Ajá! lo que queríamos\". *"),
-						b: String::from("No digan: \"Le hemos devorado\".")
+						b: String::from("No digan: \"Le hemos devorado\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Sean avergonzados y deshonrados los que de mi mal se alegran; *"),
-						b: String::from("vístanse de vergüenza e infamia los que se jactan de mí.")
+						b: String::from("vístanse de vergüenza e infamia los que se jactan de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Canten y alégrense los que están a favor de mi causa; *"),
-						b: String::from("digan siempre: \"Grande es el Señor,\nque ama el bienestar de su siervo\".")
+						b: String::from("digan siempre: \"Grande es el Señor,\nque ama el bienestar de su siervo\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("Y mi lengua hablará de tu justicia, *"),
-						b: String::from("y de tu alabanza todo el día.")
+						b: String::from("y de tu alabanza todo el día."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,115 +15,138 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Señor, no me reprendas en tu furor, *"),
-						b: String::from("m me castigues en tu ira;")
+						b: String::from("m me castigues en tu ira;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Porque tus saetas me han herido, *"),
-						b: String::from("y sobre mí ha descendido tu mano.")
+						b: String::from("y sobre mí ha descendido tu mano."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("No hay salud en mi carne a causa de tu ira; *"),
-						b: String::from("no hay integridad en mi cuerpo a causa de mi pecado;")
+						b: String::from("no hay integridad en mi cuerpo a causa de mi pecado;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Porque mis iniquidades me abruman; *"),
-						b: String::from("como carga pesada exceden mis fuerzas.")
+						b: String::from("como carga pesada exceden mis fuerzas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Hieden y supuran mis llagas, *"),
-						b: String::from("a causa de mi locura.")
+						b: String::from("a causa de mi locura."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Estoy encorvado, estoy humillado en gran manera; *"),
-						b: String::from("ando enlutado todo el día.")
+						b: String::from("ando enlutado todo el día."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Mis lomos están llenos de ardor, *"),
-						b: String::from("y no hay salud en mi carne.")
+						b: String::from("y no hay salud en mi carne."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Estoy completamente abatido y sin poder; *"),
-						b: String::from("gimo a causa del lamento de mi corazón.")
+						b: String::from("gimo a causa del lamento de mi corazón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Oh Soberano, delante de ti están todos mis deseos, *"),
-						b: String::from("y mi suspiro no te es oculto.")
+						b: String::from("y mi suspiro no te es oculto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Mi corazón palpita, me ha abandonado el vigor, *"),
-						b: String::from("y aun la luz de mis ojos me falta.")
+						b: String::from("y aun la luz de mis ojos me falta."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Mis amigos y mis compañeros se apartan de mi aflicción, *"),
-						b: String::from("y mis vecinos se alelan.")
+						b: String::from("y mis vecinos se alelan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Los que buscan mi vida me ponen trampas; *"),
-						b: String::from("los que procuran mi daño hablan de mi ruina,\ny conspiran todo el día contra mí.")
+						b: String::from("los que procuran mi daño hablan de mi ruina,\ny conspiran todo el día contra mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Mas yo, como si fuera sordo, no oigo, *"),
-						b: String::from("y como mudo que no abre la boca.")
+						b: String::from("y como mudo que no abre la boca."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Soy, pues, como el que no oye, *"),
-						b: String::from("y en cuya boca no hay reprensiones.")
+						b: String::from("y en cuya boca no hay reprensiones."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Pero a ti, oh Señor, he esperado; *"),
-						b: String::from("tú responderás, oh Señor Dios mío;")
+						b: String::from("tú responderás, oh Señor Dios mío;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Porque dije: \"No se burlen de mí; *"),
-						b: String::from("cuando mi pie resbale, no canten triunfo\".")
+						b: String::from("cuando mi pie resbale, no canten triunfo\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Pues yo estoy a punto de caer, *"),
-						b: String::from("y mi dolor está delante de mí continuamente.")
+						b: String::from("y mi dolor está delante de mí continuamente."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Por tanto confesaré mi ofensa, *"),
-						b: String::from("y me contristaré por mi pecado.")
+						b: String::from("y me contristaré por mi pecado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Mis enemigos gratuitos están vivos y fuertes, *"),
-						b: String::from("y son muchos los que me aborrecen sin razón.")
+						b: String::from("y son muchos los que me aborrecen sin razón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Los que pagan mal por bien me infaman, *"),
-						b: String::from("porque sigo lo que es bueno.")
+						b: String::from("porque sigo lo que es bueno."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("No me desampares, oh Señor; *"),
-						b: String::from("Dios mío, no te alejes de mí.")
+						b: String::from("Dios mío, no te alejes de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Apresúrate a socorrerme, *"),
-						b: String::from("mi Soberano y Salvador.")
+						b: String::from("mi Soberano y Salvador."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,80 +15,96 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Yo dije: \"Atenderé a mis caminos, *"),
-						b: String::from("para no pecar con mi lengua.")
+						b: String::from("para no pecar con mi lengua."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Pondré bozal en mi boca, *"),
-						b: String::from("en tanto que el maligno esté delante de mí")
+						b: String::from("en tanto que el maligno esté delante de mí"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Enmudecí, guardé silencio, *"),
-						b: String::from("me refrené de palabras imprudentes,\npero se agravó mi tormento.")
+						b: String::from("me refrené de palabras imprudentes,\npero se agravó mi tormento."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Se enardeció mi corazón dentro de mí pensándolo, me requemaba; *"),
-						b: String::from("hasta que solté la lengua:")
+						b: String::from("hasta que solté la lengua:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Hazme saber, oh Señor, mi fin,\ny cuánta sea la medida de mis días,\npara que sepa yo cuán frágil soy."),
-						b: String::from("")
+						b: String::from(""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("He aquí, me diste sólo un puñado de días,\ny toda mi vida es como nada en tu presencia; *"),
-						b: String::from("ciertamente no más que un soplo es todo mortal.")
+						b: String::from("ciertamente no más que un soplo es todo mortal."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Merodeamos como una sombra, y en vano nos afanamos; *"),
-						b: String::from("amontonamos riquezas, y no sabemos quién las recogerá.")
+						b: String::from("amontonamos riquezas, y no sabemos quién las recogerá."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Y ahora, Señor, ¿qué esperaré? *"),
-						b: String::from("Mi esperanza está en ti.")
+						b: String::from("Mi esperanza está en ti."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Líbrame de todas mis transgresiones;\nno me pongas por escarnio del insensato."),
-						b: String::from("")
+						b: String::from(""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Enmudecí, no abrí la boca; *"),
-						b: String::from("porque tú eres el que actúa.")
+						b: String::from("porque tú eres el que actúa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Quítame tu aflicción; *"),
-						b: String::from("estoy consumido por los golpes de tu mano.")
+						b: String::from("estoy consumido por los golpes de tu mano."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Nos reprendes con castigos por el pecado; como polilla deshaces nuestro cuerpo; *"),
-						b: String::from("ciertamente no más que un soplo es todo mortal.")
+						b: String::from("ciertamente no más que un soplo es todo mortal."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Oye mi oración, oh Señor, y escucha mi clamor; *"),
-						b: String::from("no calles ante mis lágrimas;")
+						b: String::from("no calles ante mis lágrimas;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Porque forastero soy para ti, *"),
-						b: String::from("y advenedizo, como todos mis antepasados.")
+						b: String::from("y advenedizo, como todos mis antepasados."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Desvía de mí tu mirada, para que me consuele un poco, *"),
-						b: String::from("antes de que me vaya, y deje de existir.")
+						b: String::from("antes de que me vaya, y deje de existir."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
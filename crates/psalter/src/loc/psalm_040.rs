@@ -15,100 +15,120 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Con paciencia esperé al Señor; *"),
-						b: String::from("se inclinó a mí, y oyó mi clamor.")
+						b: String::from("se inclinó a mí, y oyó mi clamor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Me sacó del pozo de la desolación, del lodo cenagoso; *"),
-						b: String::from("puso mis pies sobre peña, y enderezó mis pasos.")
+						b: String::from("puso mis pies sobre peña, y enderezó mis pasos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Puso luego en mi boca canción nueva, un himno de alabanza a nuestro Dios. *"),
-						b: String::from("Muchos verán esto, y temerán, y así confiarán en el Señor.")
+						b: String::from("Muchos verán esto, y temerán, y así confiarán en el Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Bienaventurados los que ponen en el Señor su confianza, *"),
-						b: String::from("que no acuden a malos espíritus, ni recurren a dioses falsos.")
+						b: String::from("que no acuden a malos espíritus, ni recurren a dioses falsos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("¡Cuántas maravillas has hecho, oh Señor Dios mío, cuántos planes en favor nuestro! *"),
-						b: String::from("Nadie se te puede comparar.")
+						b: String::from("Nadie se te puede comparar."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Si yo pudiera anunciarlos y hablar de ellos, *"),
-						b: String::from("pero no pueden ser contados.")
+						b: String::from("pero no pueden ser contados."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Sacrificio y ofrenda no te agradan; *"),
-						b: String::from("(tú me has dado oídos para escucharte);")
+						b: String::from("(tú me has dado oídos para escucharte);"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Holocausto y sacrificio para expiación no has demandado, *"),
-						b: String::from("y entonces dije: \"He aquí, yo vengo.")
+						b: String::from("y entonces dije: \"He aquí, yo vengo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("En el rollo está escrito de mí: *"),
-						b: String::from("'El hacer tu voluntad, Dios mío, me ha agradado; tu ley está en lo profundo de mi corazón' \".")
+						b: String::from("'El hacer tu voluntad, Dios mío, me ha agradado; tu ley está en lo profundo de mi corazón' \"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("He anunciado justicia en la gran asamblea; *"),
-						b: String::from("he aquí, no refrené mis labios,\ny esto, oh Señor, tú lo sabes.")
+						b: String::from("he aquí, no refrené mis labios,\ny esto, oh Señor, tú lo sabes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("No escondí tu benevolencia dentro de mi corazón; he pregonado tu fidelidad y salvación; *"),
-						b: String::from("no oculté tu bondad y fidelidad en la gran asamblea.")
+						b: String::from("no oculté tu bondad y fidelidad en la gran asamblea."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Tú eres el Señor; no retengas de mí tu compasión; *"),
-						b: String::from("tu bondad y tu fidelidad me guarden siempre;")
+						b: String::from("tu bondad y tu fidelidad me guarden siempre;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Porque me han rodeado males innumerables; me han alcanzado mis maldades, y no puedo\nlevantar la vista; *"),
-						b: String::from("se han aumentado más que los cabellos de mi cabeza,\ny mi corazón me falla.")
+						b: String::from("se han aumentado más que los cabellos de mi cabeza,\ny mi corazón me falla."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Dígnate, oh Señor, librarme; *"),
-						b: String::from("Señor, apresúrate a socorrerme.")
+						b: String::from("Señor, apresúrate a socorrerme."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Sean avergonzados y confundidos a una, los que buscan mi vida para destruirla; *"),
-						b: String::from("vuelvan atrás y averguéncense, los que mi ruina desean.")
+						b: String::from("vuelvan atrás y averguéncense, los que mi ruina desean."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Sean esquivados a causa de su afrenta, *"),
-						b: String::from("los que me dicen: \"¡Ajá!\" con malicia.")
+						b: String::from("los que me dicen: \"¡Ajá!\" con malicia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Gócense y alégrense en ti todos los que te buscan; *"),
-						b: String::from("digan siempre los que aman tu salvación:\n\"Grande es el Señor\".")
+						b: String::from("digan siempre los que aman tu salvación:\n\"Grande es el Señor\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Aunque yo esté afligido y necesitado, *"),
-						b: String::from("el Señor pensará en mí.")
+						b: String::from("el Señor pensará en mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Mi ayuda y mi libertador eres tú; *"),
-						b: String::from("Dios mío, no te tardes.")
+						b: String::from("Dios mío, no te tardes."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
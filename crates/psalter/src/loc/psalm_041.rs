@@ -15,70 +15,84 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Bienaventurados los que cuidan al pobre y menesteroso; *"),
-						b: String::from("en el día malo los librará el Señor.")
+						b: String::from("en el día malo los librará el Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("El Señor los guardará y los preservará en vida, para que sean dichosos en la tierra; *"),
-						b: String::from("y no los entregará a la voluntad de sus enemigos.")
+						b: String::from("y no los entregará a la voluntad de sus enemigos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("El Señor los sostendrá en el lecho del dolor, *"),
-						b: String::from("y les ministrará en su enfermedad.")
+						b: String::from("y les ministrará en su enfermedad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Yo dije: \"Señor, ten misericordia de mí; *"),
-						b: String::from("sáname, porque contra ti he pecado\".")
+						b: String::from("sáname, porque contra ti he pecado\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Mis enemigos hablan mal de mí, preguntando: *"),
-						b: String::from("\"¿Cuándo morirá, y perecerá su nombre?\"")
+						b: String::from("\"¿Cuándo morirá, y perecerá su nombre?\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Aun cuando vienen a verme, hablan mentiras; *"),
-						b: String::from("su corazón recoge rumores falsos;\nal salir fuera los divulgan.")
+						b: String::from("su corazón recoge rumores falsos;\nal salir fuera los divulgan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Reunidos murmuran contra mí todos mis enemigos; *"),
-						b: String::from("contra mí idean daño, diciendo:")
+						b: String::from("contra mí idean daño, diciendo:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("\"Cosa pestilente se ha apoderado de él, *"),
-						b: String::from("y el que cayó en cama no volverá a levantarse\".")
+						b: String::from("y el que cayó en cama no volverá a levantarse\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Aun mi amigo íntimo, en quien yo fiaba, el que de mi pan comía, *"),
-						b: String::from("alzó contra mí el calcañar, y me abandonó.")
+						b: String::from("alzó contra mí el calcañar, y me abandonó."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Mas tú, oh Señor, ten misericordia de mí; *"),
-						b: String::from("hazme levantar, y les daré el pago.")
+						b: String::from("hazme levantar, y les daré el pago."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Por ello conoceré que te he agradado, *"),
-						b: String::from("que mi enemigo no se huelga de mí.")
+						b: String::from("que mi enemigo no se huelga de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("En cuanto a mí, en mi integridad sostenme; *"),
-						b: String::from("hazme estar delante de ti para siempre.")
+						b: String::from("hazme estar delante de ti para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Bendito sea el Señor, el Dios de Israel, *"),
-						b: String::from("por los siglos de los siglos. Amén y Amén.")
+						b: String::from("por los siglos de los siglos. Amén y Amén."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,37 +15,44 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Como anhela el ciervo las corrientes de aguas, *"),
-						b: String::from("así te anhela, oh Dios, el alma mía.")
+						b: String::from("así te anhela, oh Dios, el alma mía."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Mi alma tiene sed de Dios, del Dios vivo; *"),
-						b: String::from("¿cuándo vendré, y me presentaré delante de Dios?")
+						b: String::from("¿cuándo vendré, y me presentaré delante de Dios?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Fueron mis lágrimas mi alimento de día y de noche, *"),
-						b: String::from("mientras me dicen todos los días:\n\"¿Dónde está tu Dios?\"")
+						b: String::from("mientras me dicen todos los días:\n\"¿Dónde está tu Dios?\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Doy rienda suelta a mi dolor, cuando pienso en estas cosas: *"),
-						b: String::from("de cómo fui con la multitud,\ny la conduje hasta la casa de Dios,")
+						b: String::from("de cómo fui con la multitud,\ny la conduje hasta la casa de Dios,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Con voz de alegría y de alabanza, *"),
-						b: String::from("haciendo fiesta la multitud.")
+						b: String::from("haciendo fiesta la multitud."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("¿Por qué te abates, oh alma mía, *"),
-						b: String::from("y te turbas dentro de mí?")
+						b: String::from("y te turbas dentro de mí?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Pon tu confianza en Dios, *"),
-						b: String::from("porque aún he de alabarle,\nSalvador, Presencia y Dios mío.")
+						b: String::from("porque aún he de alabarle,\nSalvador, Presencia y Dios mío."),
+						..Default::default()
 					}
 				]
 			},
@@ -60,45 +67,54 @@ lazy_static! {
 					PsalmVerse {
 						number: 8,
 						a: String::from("Mi alma está abatida dentro de mí; *"),
-						b: String::from("me acordaré, por tanto, de ti desde la tierra del Jordán,")
+						b: String::from("me acordaré, por tanto, de ti desde la tierra del Jordán,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Un abismo clama a otro a la voz de tus cascadas; *"),
-						b: String::from("todos tus torrentes y riadas sobre mí han pasado.")
+						b: String::from("todos tus torrentes y riadas sobre mí han pasado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("De día otorga el Señor su gracia; *"),
-						b: String::from("de noche su cántico está conmigo,\noración al Dios de mi vida.")
+						b: String::from("de noche su cántico está conmigo,\noración al Dios de mi vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Diré a Dios, Roca mía:\n\"¿Por qué te has olvidado de mí? *"),
-						b: String::from("¿Por qué he de andar enlutado por la opresión de mis enemigos?\"")
+						b: String::from("¿Por qué he de andar enlutado por la opresión de mis enemigos?\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Mientras me están quebrantando los huesos, *"),
-						b: String::from("mis adversarios me afrentan.")
+						b: String::from("mis adversarios me afrentan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Todo el día se burlan de mí, diciendo: *"),
-						b: String::from("\"¿Dónde está tu Dios?\"")
+						b: String::from("\"¿Dónde está tu Dios?\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("¿Por qué te abates, oh alma mía, y te turbas dentro de mí?\""),
-						b: String::from("")
+						b: String::from(""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Pon tu confianza en Dios, *"),
-						b: String::from("porque aún he de alabarle,\nSalvador, Presencia y Dios mío.")
+						b: String::from("porque aún he de alabarle,\nSalvador, Presencia y Dios mío."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,35 +15,42 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Hazme justicia, oh Dios, y aboga mi causa contra la gente impía; *"),
-						b: String::from("líbrame de los mentirosos y los inicuos.")
+						b: String::from("líbrame de los mentirosos y los inicuos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Tú eres el Dios de mi fortaleza; ¿por qué me has desechado? *"),
-						b: String::from("¿Por qué he de andar enlutado por la opresión de mis enemigos?")
+						b: String::from("¿Por qué he de andar enlutado por la opresión de mis enemigos?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Envía tu luz y tu verdad; que éstas me guíen, *"),
-						b: String::from("y me conduzcan a tu santo monte, a tus moradas;")
+						b: String::from("y me conduzcan a tu santo monte, a tus moradas;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Para que me acerque al altar de Dios, al Dios de mi alegría y de mi gozo; *"),
-						b: String::from("y te alabe con arpa, oh Dios, Dios mío.")
+						b: String::from("y te alabe con arpa, oh Dios, Dios mío."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("¿Por qué te abates, oh alma mía, *"),
-						b: String::from("y te turbas dentro de mí?")
+						b: String::from("y te turbas dentro de mí?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Pon tu confianza en Dios, *"),
-						b: String::from("porque aún he de alabarle,\nSalvador, Presencia y Dios mío.")
+						b: String::from("porque aún he de alabarle,\nSalvador, Presencia y Dios mío."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
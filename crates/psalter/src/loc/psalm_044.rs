@@ -15,135 +15,162 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Dios, con nuestros oídos hemos oído, nuestros antepasados nos han contado, *"),
-						b: String::from("las obras que hiciste en sus días, en los tiempos antiguos.")
+						b: String::from("las obras que hiciste en sus días, en los tiempos antiguos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Tú, con tu mano echaste las naciones,\ny plantaste a nuestros antepasados en la tierra; *"),
-						b: String::from("dispersaste las gentes e hiciste prosperar a tu pueblo;")
+						b: String::from("dispersaste las gentes e hiciste prosperar a tu pueblo;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Porque no se apoderó de la tierra por su espada, ni su brazo le dio la victoria; *"),
-						b: String::from("sino tu diestra, tu brazo y la luz de tu rostro, porque te complaciste en él.")
+						b: String::from("sino tu diestra, tu brazo y la luz de tu rostro, porque te complaciste en él."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Tú, oh Dios, eres mi Rey; *"),
-						b: String::from("tú le diste victoria a Jacob.")
+						b: String::from("tú le diste victoria a Jacob."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Por medio de ti rechazamos a nuestros adversarios; *"),
-						b: String::from("en tu Nombre hollamos a nuestros agresores;")
+						b: String::from("en tu Nombre hollamos a nuestros agresores;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Porque no confié en mi arco, *"),
-						b: String::from("ni mi espada me dio la victoria;")
+						b: String::from("ni mi espada me dio la victoria;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Pero tú nos diste la victoria sobre nuestros adversarios, *"),
-						b: String::from("y avergonzaste a los que nos aborrecían.")
+						b: String::from("y avergonzaste a los que nos aborrecían."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("En Dios nos gloriaremos todo el tiempo, *"),
-						b: String::from("y para siempre alabaremos tu Nombre.")
+						b: String::from("y para siempre alabaremos tu Nombre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Sin embargo, ahora nos has rechazado, y nos has humillado, *"),
-						b: String::from("y no sales con nuestros ejércitos.")
+						b: String::from("y no sales con nuestros ejércitos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Nos hiciste retroceder delante del adversario, *"),
-						b: String::from("y nos saquearon nuestros enemigos.")
+						b: String::from("y nos saquearon nuestros enemigos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Nos entregaste como ovejas al matadero, *"),
-						b: String::from("y nos esparciste entre las naciones.")
+						b: String::from("y nos esparciste entre las naciones."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Has vendido a tu pueblo de balde; *"),
-						b: String::from("y nada has ganado con venderlo.")
+						b: String::from("y nada has ganado con venderlo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Nos hiciste el escarnio de nuestros vecinos, *"),
-						b: String::from("mofa y burla de los que nos rodean.")
+						b: String::from("mofa y burla de los que nos rodean."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Nos hiciste objeto de burla entre las naciones, *"),
-						b: String::from("el hazmerreír entre los pueblos.")
+						b: String::from("el hazmerreír entre los pueblos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Tengo siempre delante mi deshonra, *"),
-						b: String::from("y la vergüenza me cubre la cara,")
+						b: String::from("y la vergüenza me cubre la cara,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Por la voz del que me vitupera y deshonra, *"),
-						b: String::from("por culpa del enemigo y del vengador.")
+						b: String::from("por culpa del enemigo y del vengador."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Todo esto nos ha venido; *"),
-						b: String::from("mas no nos hemos olvidado de ti,\ny no hemos faltado a tu pacto.")
+						b: String::from("mas no nos hemos olvidado de ti,\ny no hemos faltado a tu pacto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("No te abandonó nuestro corazón, *"),
-						b: String::from("ni se apartaron de tus caminos nuestros pasos,")
+						b: String::from("ni se apartaron de tus caminos nuestros pasos,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Aunque nos quebrantases en lugares de miseria, *"),
-						b: String::from("y nos cubrieses con densa oscuridad.")
+						b: String::from("y nos cubrieses con densa oscuridad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Si nos hubiésemos olvidado del Nombre de nuestro Dios, *"),
-						b: String::from("o alzado nuestras manos a dios ajeno,")
+						b: String::from("o alzado nuestras manos a dios ajeno,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("¿No lo descubriría Dios? *"),
-						b: String::from("porque él conoce los secretos del corazón.")
+						b: String::from("porque él conoce los secretos del corazón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("En verdad, por tu causa nos matan cada día; *"),
-						b: String::from("somos contados como ovejas para el matadero.")
+						b: String::from("somos contados como ovejas para el matadero."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Despiértate, ¿por qué duermes, Soberano mío? *"),
-						b: String::from("Levántate, no nos rechaces para siempre.")
+						b: String::from("Levántate, no nos rechaces para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("¿ Por qué escondes tu rostro, *"),
-						b: String::from("y te olvidas de nuestra aflicción y opresión?")
+						b: String::from("y te olvidas de nuestra aflicción y opresión?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("Porque nuestro cuello se hunde hasta el polvo; *"),
-						b: String::from("nuestro vientre se pega a la tierra.")
+						b: String::from("nuestro vientre se pega a la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Levántate para ayudarnos, *"),
-						b: String::from("y redímenos por tu gran amor.")
+						b: String::from("y redímenos por tu gran amor."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
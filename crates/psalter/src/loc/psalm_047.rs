@@ -15,55 +15,66 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Aplaudan, pueblos todos; *"),
-						b: String::from("aclamen a Dios con voz de júbilo;")
+						b: String::from("aclamen a Dios con voz de júbilo;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Porque el Señor Altísimo es temible, *"),
-						b: String::from("Rey grande sobre toda la tierra.")
+						b: String::from("Rey grande sobre toda la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Somete a los pueblos a nuestro dominio, *"),
-						b: String::from("y sujeta a las naciones bajo nuestros pies.")
+						b: String::from("y sujeta a las naciones bajo nuestros pies."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Nos elige nuestra heredad, *"),
-						b: String::from("el deleite de Jacob, a quien ama.")
+						b: String::from("el deleite de Jacob, a quien ama."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Ascendió Dios entre gritos de júbilo, *"),
-						b: String::from("el Señor con sonido de trompeta.")
+						b: String::from("el Señor con sonido de trompeta."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Canten alabanzas a Dios, canten; *"),
-						b: String::from("canten alabanzas a nuestro Rey, canten;")
+						b: String::from("canten alabanzas a nuestro Rey, canten;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Porque Dios es Rey de toda la tierra; *"),
-						b: String::from("canten alabanzas con esmero.")
+						b: String::from("canten alabanzas con esmero."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Dios reina sobre las naciones; *"),
-						b: String::from("se sienta sobre su santo trono.")
+						b: String::from("se sienta sobre su santo trono."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Los nobles de los pueblos se han unido *"),
-						b: String::from("al pueblo del Dios de Abrahán.")
+						b: String::from("al pueblo del Dios de Abrahán."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Los soberanos de la tierra pertenecen a Dios, *"),
-						b: String::from("y él es excelso.")
+						b: String::from("y él es excelso."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
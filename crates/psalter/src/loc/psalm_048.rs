@@ -15,70 +15,84 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Grande es el Señor, y digno de toda alabanza; *"),
-						b: String::from("en la ciudad de nuestro Dios está su santo monte.")
+						b: String::from("en la ciudad de nuestro Dios está su santo monte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Hermoso y sublime, el gozo de toda la tierra, es el monte de Sión, *"),
-						b: String::from("corazón del mundo y ciudad del gran Rey.")
+						b: String::from("corazón del mundo y ciudad del gran Rey."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Dios está en su ciudadela; *"),
-						b: String::from("descuella como un alcázar.")
+						b: String::from("descuella como un alcázar."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("He aquí los reyes de la tierra se aliaron; *"),
-						b: String::from("y juntos avanzaron contra ella.")
+						b: String::from("y juntos avanzaron contra ella."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Al verla, se pasmaron; *"),
-						b: String::from("se turbaron y huyeron.")
+						b: String::from("se turbaron y huyeron."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Allí se estremecieron, *"),
-						b: String::from("se retorcían como mujer que pare,\ncomo naves del mar cuando el solano las quebranta.")
+						b: String::from("se retorcían como mujer que pare,\ncomo naves del mar cuando el solano las quebranta."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Lo que habíamos oído, lo hemos visto,\nen la ciudad del Señor de las huestes, en la\nciudad de nuestro Rey: *"),
-						b: String::from("Dios la ha establecido para siempre.")
+						b: String::from("Dios la ha establecido para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Hemos meditado en tu bondad, oh Dios, *"),
-						b: String::from("en medio de tu templo.")
+						b: String::from("en medio de tu templo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Tu alabanza, como tu Nombre, oh Dios, llega hasta los confines de la tierra; *"),
-						b: String::from("de justicia está llena tu diestra.")
+						b: String::from("de justicia está llena tu diestra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Alégrese el monte de Sión, gócense las ciudades de Judá, *"),
-						b: String::from("a causa de tu Providencia.")
+						b: String::from("a causa de tu Providencia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Anden alrededor de Sión, rodéenla; *"),
-						b: String::from("cuenten las torres que tiene.")
+						b: String::from("cuenten las torres que tiene."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Consideren bien su antemuro, examinen sus fuertes, *"),
-						b: String::from("para que puedan contarlo a la generación venidera;")
+						b: String::from("para que puedan contarlo a la generación venidera;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Porque este Dios es nuestro Dios, eternamente y para siempre; *"),
-						b: String::from("él nos guiará por siempre jamás.")
+						b: String::from("él nos guiará por siempre jamás."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
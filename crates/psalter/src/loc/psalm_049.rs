@@ -15,105 +15,126 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oigan esto, pueblos todos;\nescuchen, habitantes todos del mundo, *"),
-						b: String::from("así los plebeyos como los nobles, el rico y el pobre juntamente.")
+						b: String::from("así los plebeyos como los nobles, el rico y el pobre juntamente."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Mi boca hablará sabiduría, *"),
-						b: String::from("y el pensamiento de mi corazón, inteligencia.")
+						b: String::from("y el pensamiento de mi corazón, inteligencia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Inclinaré mi oído al proverbio; *"),
-						b: String::from("manifestaré mi secreto al son del arpa.")
+						b: String::from("manifestaré mi secreto al son del arpa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("¿Por qué he de temer en los días de adversidad, *"),
-						b: String::from("cuando la iniquidad de mis insidiadores me cercare,")
+						b: String::from("cuando la iniquidad de mis insidiadores me cercare,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("La iniquidad de los que confían en sus bienes, *"),
-						b: String::from("y se jactan de sus muchas riquezas?")
+						b: String::from("y se jactan de sus muchas riquezas?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Nadie puede redimirse a sí mismo, *"),
-						b: String::from("ni pagar a Dios su propio rescate;")
+						b: String::from("ni pagar a Dios su propio rescate;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Porque el precio de nuestra redención es tan grande, *"),
-						b: String::from("que nunca tendríamos suficiente para pagarlo,")
+						b: String::from("que nunca tendríamos suficiente para pagarlo,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("A fin de vivir para siempre, *"),
-						b: String::from("y nunca ver la sepultura.")
+						b: String::from("y nunca ver la sepultura."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Vemos que también los sabios mueren; perecen como el insensato y el necio, *"),
-						b: String::from("y dejan a otros sus riquezas.")
+						b: String::from("y dejan a otros sus riquezas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("El sepulcro será su habitación eterna,\nsu morada de generación en generación, *"),
-						b: String::from("aunque hayan dado su nombre a sus tierras.")
+						b: String::from("aunque hayan dado su nombre a sus tierras."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Aunque hayan recibido honra,\npueden vivir para siempre; *"),
-						b: String::from("son como las bestias que perecen.")
+						b: String::from("son como las bestias que perecen."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Tal es el camino de los que tontamente confían en sí mismos, *"),
-						b: String::from("el fin de los que se complacen en sus propias palabras.")
+						b: String::from("el fin de los que se complacen en sus propias palabras."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Como un rebaño de ovejas, son destinados a morir; la muerte es su pastor; *"),
-						b: String::from("bajan directamente a la tumba.")
+						b: String::from("bajan directamente a la tumba."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Se desvanecerá su figura, *"),
-						b: String::from("y en el Reino de los Muertos habitarán para siempre.")
+						b: String::from("y en el Reino de los Muertos habitarán para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Empero Dios rescatará mi vida; *"),
-						b: String::from("me arrebatará de las garras de la muerte.")
+						b: String::from("me arrebatará de las garras de la muerte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("No envidies al que se enriquece, *"),
-						b: String::from("y aumenta el lujo de su casa;")
+						b: String::from("y aumenta el lujo de su casa;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Porque cuando muera no se llevará nada, *"),
-						b: String::from("ni su lujo le seguirá.")
+						b: String::from("ni su lujo le seguirá."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Aunque mientras vivía, se sobreestimaba, *"),
-						b: String::from("y era loado por su éxito,")
+						b: String::from("y era loado por su éxito,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Se unirá a la generación de sus padres, *"),
-						b: String::from("y nunca más verá la luz.")
+						b: String::from("y nunca más verá la luz."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("El que recibe honra y no entiende, *"),
-						b: String::from("es como las bestias que perecen.")
+						b: String::from("es como las bestias que perecen."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,50 +15,60 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¿Por qué te jactas de maldad, oh tirano, *"),
-						b: String::from("contra el devoto todo el día?")
+						b: String::from("contra el devoto todo el día?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Urdes agravios;\ncomo navaja afilada es tu lengua, *"),
-						b: String::from("tú que obras engaño.")
+						b: String::from("tú que obras engaño."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Amas el mal más que el bien, *"),
-						b: String::from("la mentira más que la verdad.")
+						b: String::from("la mentira más que la verdad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Amas toda suerte de palabras hirientes, *"),
-						b: String::from("oh lengua engañosa.")
+						b: String::from("oh lengua engañosa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("¡Oh, si Dios te derribara totalmente, *"),
-						b: String::from("te asolara y te arrancara de tu morada,\ny te desarraigara de la tierra de los vivientes!")
+						b: String::from("te asolara y te arrancara de tu morada,\ny te desarraigara de la tierra de los vivientes!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Verán los justos, y temerán; *"),
-						b: String::from("entonces se reirán de él, diciendo:")
+						b: String::from("entonces se reirán de él, diciendo:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("\"He aquí el que no puso a Dios por fortaleza, *"),
-						b: String::from("sino que confió en sus muchas riquezas,\ny persistió en su maldad\".")
+						b: String::from("sino que confió en sus muchas riquezas,\ny persistió en su maldad\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Pero yo estoy en la casa de Dios como olivo verde; *"),
-						b: String::from("en la misericordia de Dios confío eternamente\ny para siempre.")
+						b: String::from("en la misericordia de Dios confío eternamente\ny para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Siempre te daré gracias por lo que has hecho, *"),
-						b: String::from("y proclamaré, en la presencia de tus santos,\nque tu Nombre es bueno.")
+						b: String::from("y proclamaré, en la presencia de tus santos,\nque tu Nombre es bueno."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
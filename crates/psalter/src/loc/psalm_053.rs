@@ -15,35 +15,42 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Dice el necio en su corazón: \"No hay Dios\". *"),
-						b: String::from("Se han corrompido todos, hicieron abominable maldad;\nno hay quien haga bien.")
+						b: String::from("Se han corrompido todos, hicieron abominable maldad;\nno hay quien haga bien."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Dios desde los cielos observa al género humano, *"),
-						b: String::from("para ver si hay algún entendido que busque a Dios.")
+						b: String::from("para ver si hay algún entendido que busque a Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Todos se han extraviado; todos se han pervertido; *"),
-						b: String::from("no hay quien haga bien, no hay ni siquiera uno.")
+						b: String::from("no hay quien haga bien, no hay ni siquiera uno."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("¿No tienen conocimiento, todos los que hacen iniquidad, *"),
-						b: String::from("que devoran a mi pueblo como si comiesen pan,\ny a Dios no invocan?")
+						b: String::from("que devoran a mi pueblo como si comiesen pan,\ny a Dios no invocan?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("He aquí, ahora tiemblan grandemente como nunca antes temblaron, *"),
-						b: String::from("porque Dios ha esparcido los huesos de los malvados; se avergüenzan porque Dios los ha rechazado.")
+						b: String::from("porque Dios ha esparcido los huesos de los malvados; se avergüenzan porque Dios los ha rechazado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("¡Oh, si la liberación de Israel saliese de Sión! *"),
-						b: String::from("Cuando Dios cambie la suerte de su pueblo,\nse gozará Jacob, y se alegrará Israel.")
+						b: String::from("Cuando Dios cambie la suerte de su pueblo,\nse gozará Jacob, y se alegrará Israel."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,40 +15,48 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Dios, sálvame por tu Nombre, *"),
-						b: String::from("y con tu poder defiéndeme.")
+						b: String::from("y con tu poder defiéndeme."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Escucha mi oración, oh Dios, *"),
-						b: String::from("atiende a las palabras de mi boca.")
+						b: String::from("atiende a las palabras de mi boca."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Insolentes se han levantado contra mí, *"),
-						b: String::from("y matones buscan mi vida; \nno tienen presente a Dios.")
+						b: String::from("y matones buscan mi vida; \nno tienen presente a Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("He aquí, Dios es el que me ayuda; *"),
-						b: String::from("es el Señor quien sostiene mi vida.")
+						b: String::from("es el Señor quien sostiene mi vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Devuelve el mal a mis adversarios; *"),
-						b: String::from("destrúyelos, por tu fidelidad.")
+						b: String::from("destrúyelos, por tu fidelidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Te ofreceré sacrificios voluntarios; *"),
-						b: String::from("alabaré tu Nombre, oh Señor, porque es bueno;")
+						b: String::from("alabaré tu Nombre, oh Señor, porque es bueno;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Porque me has librado de toda angustia, *"),
-						b: String::from("y mis ojos han visto la ruina de mis enemigos.")
+						b: String::from("y mis ojos han visto la ruina de mis enemigos."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,135 +15,162 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Escucha, oh Dios, mi oración, *"),
-						b: String::from("y no te escondas de mi súplica.")
+						b: String::from("y no te escondas de mi súplica."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Hazme caso y respóndeme; *"),
-						b: String::from("me agitan mis ansiedades.")
+						b: String::from("me agitan mis ansiedades."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Me turba la voz del enemigo, *"),
-						b: String::from("la mirada feroz del malvado;")
+						b: String::from("la mirada feroz del malvado;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Porque sobre mí echan maleficios, *"),
-						b: String::from("y con furor me persiguen.")
+						b: String::from("y con furor me persiguen."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Mi corazón dentro de mí trepida, *"),
-						b: String::from("y terrores de muerte sobre mí han caído.")
+						b: String::from("y terrores de muerte sobre mí han caído."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Temor y temblor vinieron sobre mí, *"),
-						b: String::from("y espanto me ha cubierto.")
+						b: String::from("y espanto me ha cubierto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Y dije: \"¡Quién me diese alas como de paloma! *"),
-						b: String::from("volaría yo, y descansaría.")
+						b: String::from("volaría yo, y descansaría."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Ciertamente huiría lejos; *"),
-						b: String::from("moraría en el desierto.")
+						b: String::from("moraría en el desierto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Me apresuraría a escapar *"),
-						b: String::from("viento borrascoso y de la tempestad\".")
+						b: String::from("viento borrascoso y de la tempestad\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Devóralos, Soberano mío; confunde su lenguaje; *"),
-						b: String::from("porque he visto violencia y lucha en la ciudad.")
+						b: String::from("porque he visto violencia y lucha en la ciudad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Día y noche los vigilantes hacen rondas sobre sus muros, *"),
-						b: String::from("pero maldad e intriga hay en medio de ella.")
+						b: String::from("pero maldad e intriga hay en medio de ella."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Corrupción hay en ella; *"),
-						b: String::from("opresión y engaño no se apartan de sus plazas.")
+						b: String::from("opresión y engaño no se apartan de sus plazas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Si un adversario me injuriase, lo habría soportado; *"),
-						b: String::from("si un enemigo se alzace contra mí, me escondería de él.")
+						b: String::from("si un enemigo se alzace contra mí, me escondería de él."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Pero eres tú, mi amigo íntimo, *"),
-						b: String::from("mi compañero y mi confidente.")
+						b: String::from("mi compañero y mi confidente."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Nos unía una gran intimidad, *"),
-						b: String::from("y juntos andábamos con la multitud por la casa de Dios.")
+						b: String::from("y juntos andábamos con la multitud por la casa de Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Que la muerte les sorprenda;\nque desciendan vivos a la tumba; *"),
-						b: String::from("s la maldad anida entre ellos.")
+						b: String::from("s la maldad anida entre ellos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Pero yo invoco a Dios, *"),
-						b: String::from("y el Señor me salva.")
+						b: String::from("y el Señor me salva."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Tarde, mañana y al mediodía, me quejo y lloro, *"),
-						b: String::from("y él oye mi voz.")
+						b: String::from("y él oye mi voz."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("El me rescata en paz de la batalla que me hacen, *"),
-						b: String::from("porque son muchos contra mí.")
+						b: String::from("porque son muchos contra mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Dios, el que reina eternamente, me escucha, y los abate, *"),
-						b: String::from("por cuanto no cambian, ni temen a Dios.")
+						b: String::from("por cuanto no cambian, ni temen a Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Mi compañero levantó la mano contra su aliado, *"),
-						b: String::from("violando su pacto.")
+						b: String::from("violando su pacto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Su hablar es más suave que la mantequilla, *"),
-						b: String::from("pero hay guerra en su corazón.")
+						b: String::from("pero hay guerra en su corazón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Sus palabras son más suaves que el aceite, *"),
-						b: String::from("mas ellas son espadas desenvainadas.")
+						b: String::from("mas ellas son espadas desenvainadas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Echa sobre el Señor tu carga, y él te sustentará; *"),
-						b: String::from("jamás permitirá que el justo tropiece.")
+						b: String::from("jamás permitirá que el justo tropiece."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("Mas tú, oh Dios, harás caer al pozo de perdición *"),
-						b: String::from("a los sanguinarios y engañadores.")
+						b: String::from("a los sanguinarios y engañadores."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("No llegarán a la mitad de sus días, *"),
-						b: String::from("pero yo en ti confiaré.")
+						b: String::from("pero yo en ti confiaré."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,65 +15,78 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Ten misericordia de mí, oh Dios, porque me hostigan mis enemigos; *"),
-						b: String::from("me atacan y me acosan todo el día.")
+						b: String::from("me atacan y me acosan todo el día."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Todo el día me hostigan; *"),
-						b: String::from("en verdad, son muchos los que pelean contra mí,\noh Altísimo.")
+						b: String::from("en verdad, son muchos los que pelean contra mí,\noh Altísimo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Cuando tengo miedo, *"),
-						b: String::from("en ti confío.")
+						b: String::from("en ti confío."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("En Dios, cuya palabra alabo, en Dios confío, y no temo; *"),
-						b: String::from("¿qué pueden hacerme los mortales?")
+						b: String::from("¿qué pueden hacerme los mortales?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Todo el día pervierten mi causa; *"),
-						b: String::from("sólo piensan en hacerme daño.")
+						b: String::from("sólo piensan en hacerme daño."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Se apandillan; me acechan; *"),
-						b: String::from("espían mis pasos, porque me quieren matar.")
+						b: String::from("espían mis pasos, porque me quieren matar."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("¿Escaparán ellos, a pesar de su iniquidad? *"),
-						b: String::from("Oh Dios, en tu furor derriba a los pueblos.")
+						b: String::from("Oh Dios, en tu furor derriba a los pueblos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Tú has notado mis gemidos;\nhas puesto mis lágrimas en tu redoma; *"),
-						b: String::from("¿no están ellos en tu libro?")
+						b: String::from("¿no están ellos en tu libro?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("En el día que te invoque, mis enemigos serán dispersos; *"),
-						b: String::from("esto sé, porque Dios está de mi parte.")
+						b: String::from("esto sé, porque Dios está de mi parte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("En Dios el Señor, cuya palabra alabo, en Dios confío, y no temo; *"),
-						b: String::from("¿qué pueden hacerme los mortales?")
+						b: String::from("¿qué pueden hacerme los mortales?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Te debo, oh Dios, los votos que hice; *"),
-						b: String::from("los cumpliré con acción de gracias;")
+						b: String::from("los cumpliré con acción de gracias;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Porque has librado mi vida de la muerte, mis pies de la caída, *"),
-						b: String::from("para que ande delante de Dios en la luz de los que viven.")
+						b: String::from("para que ande delante de Dios en la luz de los que viven."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
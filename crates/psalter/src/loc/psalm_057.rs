@@ -15,60 +15,72 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Ten misericordia de mí, oh Dios, ten misericordia, porque en ti he confiado; *"),
-						b: String::from("me refugiaré a la sombra de tus alas, hasta que pasen mis quebrantos.")
+						b: String::from("me refugiaré a la sombra de tus alas, hasta que pasen mis quebrantos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Invocaré al Dios Altísimo, *"),
-						b: String::from("al Dios que me vindica.")
+						b: String::from("al Dios que me vindica."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("El responderá desde los cielos,\ny me salvará de la infamia de los que me hostigan; *"),
-						b: String::from("enviará su amor y fidelidad.")
+						b: String::from("enviará su amor y fidelidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Estoy entre leones que devoran a los pueblos; *"),
-						b: String::from("sus dientes son lanzas y saetas,\ny su lengua espada aguda.")
+						b: String::from("sus dientes son lanzas y saetas,\ny su lengua espada aguda."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Red han tendido a mis pies; mi alma está abatida; *"),
-						b: String::from("hoyo han cavado delante de mí, pero ellos han caído en él.")
+						b: String::from("hoyo han cavado delante de mí, pero ellos han caído en él."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Exáltate sobre los cielos, oh Dios, *"),
-						b: String::from("y tu gloria sobre toda la tierra.")
+						b: String::from("y tu gloria sobre toda la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Mi corazón está firme, oh Dios, mi corazón está firme; *"),
-						b: String::from("tocaré y cantaré salmos.")
+						b: String::from("tocaré y cantaré salmos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Despierta, oh alma mía; despierten, lira y arpa; *"),
-						b: String::from("yo mismo despertaré al alba.")
+						b: String::from("yo mismo despertaré al alba."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Te confesaré entre los pueblos, oh Señor; *"),
-						b: String::from("cantaré tus alabanzas entre las naciones;")
+						b: String::from("cantaré tus alabanzas entre las naciones;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Porque tu gracia es más grande que los cielos, *"),
-						b: String::from("y tu fidelidad alcanza hasta las nubes.")
+						b: String::from("y tu fidelidad alcanza hasta las nubes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Exáltate sobre los cielos, oh Dios, *"),
-						b: String::from("y tu gloria sobre toda la tierra.")
+						b: String::from("y tu gloria sobre toda la tierra."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
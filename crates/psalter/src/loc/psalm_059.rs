@@ -15,105 +15,126 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Líbrame de mis enemigos, oh Dios; *"),
-						b: String::from("protégeme de los que contra mí se levantan.")
+						b: String::from("protégeme de los que contra mí se levantan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Líbrame de los malhechores, *"),
-						b: String::from("y sálvame de los sanguinarios.")
+						b: String::from("y sálvame de los sanguinarios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("He aquí, están acechando mi vida,\ny se han juntado contra mí los poderosos, *"),
-						b: String::from("no por falta mía, ni pecado mío, oh Señor.")
+						b: String::from("no por falta mía, ni pecado mío, oh Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("No por culpa mía corren *"),
-						b: String::from("y se aprestan a la batalla.")
+						b: String::from("y se aprestan a la batalla."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Despierta, ven a mi encuentro y mira, *"),
-						b: String::from("tú, oh Señor Dios de los ejércitos, Dios de Israel.")
+						b: String::from("tú, oh Señor Dios de los ejércitos, Dios de Israel."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Despierta y castiga a todos los impíos; *"),
-						b: String::from("no tengas misericordia de los traidores y malvados.")
+						b: String::from("no tengas misericordia de los traidores y malvados."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("En la tarde corren de un lado a otro; *"),
-						b: String::from("gruñen como perros, y vagan por la ciudad.")
+						b: String::from("gruñen como perros, y vagan por la ciudad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("He aquí, hacen alarde con su boca, puñales hay en sus labios; *"),
-						b: String::from("y dicen: \"¿Quién nos va a oír?\"")
+						b: String::from("y dicen: \"¿Quién nos va a oír?\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Mas tú, oh Señor, te reirás de ellos; *"),
-						b: String::from("te burlarás de todos los impíos.")
+						b: String::from("te burlarás de todos los impíos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Oh fortaleza mía, en ti espero, *"),
-						b: String::from("porque tú, oh Dios, eres mi baluarte.")
+						b: String::from("porque tú, oh Dios, eres mi baluarte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Mi Dios de misericordia viene a mi encuentro; *"),
-						b: String::from("Dios me hará ver la derrota de mis enemigos.")
+						b: String::from("Dios me hará ver la derrota de mis enemigos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Mátalos, oh Dios, para que mi pueblo no olvide; *"),
-						b: String::from("hazlos tambalear con tu poder,\ny abátelos, oh Señor, escudo nuestro.")
+						b: String::from("hazlos tambalear con tu poder,\ny abátelos, oh Señor, escudo nuestro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Por el pecado de su boca, por las palabras de sus labios, por la maldición y mentira que profieren, *"),
-						b: String::from("sean presos en su soberbia.")
+						b: String::from("sean presos en su soberbia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Acábalos en tu furor; *"),
-						b: String::from("acábalos para que dejen de existir;")
+						b: String::from("acábalos para que dejen de existir;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Y sépanse que Dios gobierna en Jacob, *"),
-						b: String::from("y hasta los confines de la tierra.")
+						b: String::from("y hasta los confines de la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("En la tarde corren de un lado a otro; *"),
-						b: String::from("gruñen como perros, y vagan por la ciudad.")
+						b: String::from("gruñen como perros, y vagan por la ciudad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Forrajean en busca de comida, *"),
-						b: String::from("y si no se sacian, aúllan.")
+						b: String::from("y si no se sacian, aúllan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Pero yo cantaré de tu poder; *"),
-						b: String::from("y en la mañana celebraré tu gracia;")
+						b: String::from("y en la mañana celebraré tu gracia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Porque te has hecho mi baluarte, *"),
-						b: String::from("mi refugio en el día de mi angustia.")
+						b: String::from("mi refugio en el día de mi angustia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Oh fortaleza mía, a ti cantaré, *"),
-						b: String::from("porque tú, oh Dios, eres mi alcázar,\nmi Dios de misericordia.")
+						b: String::from("porque tú, oh Dios, eres mi alcázar,\nmi Dios de misericordia."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,65 +15,78 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Dios, tú nos has desechado, nos quebrantaste; *"),
-						b: String::from("te has airado; acéptanos de nuevo.")
+						b: String::from("te has airado; acéptanos de nuevo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Hiciste temblar la tierra, abrístela; *"),
-						b: String::from("cierra sus grietas, que se desmorona.")
+						b: String::from("cierra sus grietas, que se desmorona."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Has hecho pasar a tu pueblo duras pruebas; *"),
-						b: String::from("nos hiciste beber del vino que nos aturde.")
+						b: String::from("nos hiciste beber del vino que nos aturde."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Has alzado estandarte a los que te temen, *"),
-						b: String::from("como refugio contra los arqueros.")
+						b: String::from("como refugio contra los arqueros."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Sálvanos con tu diestra, y respóndenos, *"),
-						b: String::from("para que sean librados los que amas.")
+						b: String::from("para que sean librados los que amas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Dios habló desde su santuario, y dijo: *"),
-						b: String::from("“Yo me alegraré, y repartiré a Siquén,\ndividiré el valle de Sucot.")
+						b: String::from("“Yo me alegraré, y repartiré a Siquén,\ndividiré el valle de Sucot."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Mío es Galaad, mío Manasés; *"),
-						b: String::from("Efraín es mi yelmo, y Judá mi cetro.")
+						b: String::from("Efraín es mi yelmo, y Judá mi cetro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Moab es mi jofaina;\nsobre Edom lanzaré mi sandalia; *"),
-						b: String::from("sobre Filistea cantaré victoria\".")
+						b: String::from("sobre Filistea cantaré victoria\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("¿Quién me llevará a la ciudad fortificada? *"),
-						b: String::from("¿Quién me llevará hasta Edom,")
+						b: String::from("¿Quién me llevará hasta Edom,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Si tú, oh Dios, nos has desechado, *"),
-						b: String::from("si no sales, oh Dios, con nuestros ejércitos?")
+						b: String::from("si no sales, oh Dios, con nuestros ejércitos?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Danos tu ayuda contra el enemigo, *"),
-						b: String::from("porque vana es la ayuda del hombre.")
+						b: String::from("porque vana es la ayuda del hombre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Con Dios haremos proezas, *"),
-						b: String::from("y él hollará a nuestros enemigos.")
+						b: String::from("y él hollará a nuestros enemigos."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,60 +15,72 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Dios, tú eres mi Dios; ardientemente te busco; *"),
-						b: String::from("mi alma tiene sed de ti, mi carne te anhela,\ncomo tierra seca y árida donde no hay agua.")
+						b: String::from("mi alma tiene sed de ti, mi carne te anhela,\ncomo tierra seca y árida donde no hay agua."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("¡Oh, que pudiera yo contemplarte en tu santuario! *"),
-						b: String::from("¡Que pudiera ver tu poder y tu gloria!")
+						b: String::from("¡Que pudiera ver tu poder y tu gloria!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Porque mejor es tu gracia que la vida; *"),
-						b: String::from("te alabarán mis labios.")
+						b: String::from("te alabarán mis labios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Te bendeciré mientras viva; *"),
-						b: String::from("en tu Nombre alzaré mis manos.")
+						b: String::from("en tu Nombre alzaré mis manos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Mi alma será saciada como de meollo y grosura, *"),
-						b: String::from("y con labios de júbilo te alabará mi boca,")
+						b: String::from("y con labios de júbilo te alabará mi boca,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Cuando me acuerde de ti en mi lecho, *"),
-						b: String::from("cuando medite en ti en las vigilias de la noche;")
+						b: String::from("cuando medite en ti en las vigilias de la noche;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Porque tú has sido mi socorro; *"),
-						b: String::from("y a la sombra de tus alas me regocijaré.")
+						b: String::from("y a la sombra de tus alas me regocijaré."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Mi alma está apegada a ti; *"),
-						b: String::from("tu diestra me sostiene.")
+						b: String::from("tu diestra me sostiene."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Que cuantos buscan mi vida para destruirla *"),
-						b: String::from("bajen a lo profundo de la tierra.")
+						b: String::from("bajen a lo profundo de la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Caigan a filo de espada; *"),
-						b: String::from("sean pasto para los chacales.")
+						b: String::from("sean pasto para los chacales."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Pero el rey se alegrará en Dios;\ntodos los que juran por él se regocijarán, *"),
-						b: String::from("porque la boca de los que hablan mentira será cerrada.")
+						b: String::from("porque la boca de los que hablan mentira será cerrada."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
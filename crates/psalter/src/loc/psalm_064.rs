@@ -15,55 +15,66 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Escucha, oh Dios, la voz de mi lamento; *"),
-						b: String::from("guarda mi vida del temor del enemigo.")
+						b: String::from("guarda mi vida del temor del enemigo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Escóndeme de la conspiración de los malignos, *"),
-						b: String::from("del populacho de los que obran iniquidad.")
+						b: String::from("del populacho de los que obran iniquidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Afilan como espada su lengua, *"),
-						b: String::from("y lanzan cual saetas palabras amargas,")
+						b: String::from("y lanzan cual saetas palabras amargas,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Para emboscar al íntegro y matarlo; *"),
-						b: String::from("atacan por sorpresa y no temen.")
+						b: String::from("atacan por sorpresa y no temen."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Obstinados en su inicuo designio, *"),
-						b: String::from("tratan de esconder sus lazos.")
+						b: String::from("tratan de esconder sus lazos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Dicen: \"¿Quién nos ha de ver? ¿Quién descubrirá nuestro delito? *"),
-						b: String::from("Urdimos el crimen perfecto\".")
+						b: String::from("Urdimos el crimen perfecto\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("La mente y el corazón del hombre son un misterio, *"),
-						b: String::from("mas Dios les tirará una saeta, y de repente serán heridos.")
+						b: String::from("mas Dios les tirará una saeta, y de repente serán heridos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Su propia lengua los hará caer; *"),
-						b: String::from("y quienes los vean menearán la cabeza.")
+						b: String::from("y quienes los vean menearán la cabeza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Todos se asombrarán, y anunciarán las obras de Dios; *"),
-						b: String::from("reconocerán lo que él ha hecho.")
+						b: String::from("reconocerán lo que él ha hecho."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("El justo se alegrará en el Señor, y confiará en él; *"),
-						b: String::from("se gloriarán todos los de recto corazón.")
+						b: String::from("se gloriarán todos los de recto corazón."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,40 +15,48 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Dios tenga misericordia de nosotros, y nos bendiga, *"),
-						b: String::from("haga resplandecer su rostro y venga a nosotros.")
+						b: String::from("haga resplandecer su rostro y venga a nosotros."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Sean conocidos en la tierra tus caminos, *"),
-						b: String::from("en todas las naciones tu salvación.")
+						b: String::from("en todas las naciones tu salvación."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Te alaben los pueblos, oh Dios; *"),
-						b: String::from("todos los pueblos te alaben.")
+						b: String::from("todos los pueblos te alaben."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Alégrense las naciones y aclamen con júbilo, *"),
-						b: String::from("porque juzgas los pueblos con equidad,\ny diriges todas las naciones de la tierra.")
+						b: String::from("porque juzgas los pueblos con equidad,\ny diriges todas las naciones de la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Te alaben los pueblos, oh Dios; *"),
-						b: String::from("todos los pueblos te alaben.")
+						b: String::from("todos los pueblos te alaben."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("La tierra ha dado su fruto; *"),
-						b: String::from("nos bendiga Dios, el Dios nuestro.")
+						b: String::from("nos bendiga Dios, el Dios nuestro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Dios nos bendiga; *"),
-						b: String::from("témanlo todos los confines de la tierra.")
+						b: String::from("témanlo todos los confines de la tierra."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,82 +15,98 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Levántese Dios, y se dispersen sus enemigos; *"),
-						b: String::from("que huyan de su presencia los que le odian.")
+						b: String::from("que huyan de su presencia los que le odian."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Como el humo se disipa, disípense ellos; *"),
-						b: String::from("como se derrite la cera ante el fuego,\nderrítanse los malos ante Dios.")
+						b: String::from("como se derrite la cera ante el fuego,\nderrítanse los malos ante Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Empero alégrense los justos, gócense delante de Dios; *"),
-						b: String::from("regocíjense también, rebosando de júbilo.")
+						b: String::from("regocíjense también, rebosando de júbilo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Canten a Dios, canten alabanzas a su Nombre; enaltezcan al que cabalga sobre los cielos; *"),
-						b: String::from("su Nombre es YAHVÉ; regocijense delante de él.")
+						b: String::from("su Nombre es YAHVÉ; regocijense delante de él."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Padre de huérfanos, defensor de viudas, *"),
-						b: String::from("es Dios en su santa morada.")
+						b: String::from("es Dios en su santa morada."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("A los solitarios Dios da un hogar, y saca a libertad a los cautivos; *"),
-						b: String::from("mas los rebeldes habitarán en tierra seca.")
+						b: String::from("mas los rebeldes habitarán en tierra seca."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Oh Dios, cuando saliste delante de tu pueblo, *"),
-						b: String::from("cuando avanzaste por el desierto,")
+						b: String::from("cuando avanzaste por el desierto,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("La tierra tembló, el cielo derramó su lluvia, *"),
-						b: String::from("ante Dios, el Dios de Sinaí,\nante Dios, el Dios de Israel.")
+						b: String::from("ante Dios, el Dios de Sinaí,\nante Dios, el Dios de Israel."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Abundante lluvia derramaste, oh Dios, en tu heredad; *"),
-						b: String::from("refrescaste la tierra extenuada.")
+						b: String::from("refrescaste la tierra extenuada."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Tu pueblo habitó en ella; *"),
-						b: String::from("por tu bondad, oh Dios, has provisto al pobre.")
+						b: String::from("por tu bondad, oh Dios, has provisto al pobre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Mi Soberano ha dado la palabra: *"),
-						b: String::from("grande era la multitud de las que llevaban\nbuenas noticias:")
+						b: String::from("grande era la multitud de las que llevaban\nbuenas noticias:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("\"Van huyendo los reyes y sus ejércitos, van huyendo; *"),
-						b: String::from("las mujeres en casa reparten los despojos\".")
+						b: String::from("las mujeres en casa reparten los despojos\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Aunque tardaban ustedes entre los rediles, *"),
-						b: String::from("serán como palomas, sus alas cubiertas de plata,\nsus plumas, como de oro, destellaban.")
+						b: String::from("serán como palomas, sus alas cubiertas de plata,\nsus plumas, como de oro, destellaban."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Cuando el Omnipotente esparció a los reyes, *"),
-						b: String::from("fue como si hubiese nevado en el monte Salmón.")
+						b: String::from("fue como si hubiese nevado en el monte Salmón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("¡Oh monte altísimo, oh monte de Basán! *"),
-						b: String::from("¡Oh monte escarpado, oh monte de Basán!")
+						b: String::from("¡Oh monte escarpado, oh monte de Basán!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("¿Por qué miras con envidia, oh montaña escabrosa, al monte escogido por Dios para su morada? *"),
-						b: String::from("Ciertamente el Señor habitará en él para siempre.")
+						b: String::from("Ciertamente el Señor habitará en él para siempre."),
+						..Default::default()
 					}
 				]
 			},
@@ -105,105 +121,126 @@ lazy_static! {
 					PsalmVerse {
 						number: 17,
 						a: String::from("Los carros de Dios son veinte mil, y aun millares de millares; *"),
-						b: String::from("")
+						b: String::from(""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Subiste a lo alto, llevando cautiva a la cautividad; recibiste dones hasta de tus enemigos, *"),
-						b: String::from("para que habite el Señor Dios entre ellos.")
+						b: String::from("para que habite el Señor Dios entre ellos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Bendito sea Dios, día tras día, *"),
-						b: String::from("Dios, nuestro Salvador, que lleva nuestras cargas.")
+						b: String::from("Dios, nuestro Salvador, que lleva nuestras cargas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("El Dios nuestro es un Dios de salvación; *"),
-						b: String::from("Dios es el Señor, por quien escapamos de la muerte.")
+						b: String::from("Dios es el Señor, por quien escapamos de la muerte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Dios aplastará la cabeza de sus enemigos, *"),
-						b: String::from("y el cuero cabelludo de los que persisten en su maldad.")
+						b: String::from("y el cuero cabelludo de los que persisten en su maldad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Mi Soberano dijo: \"De Basán los haré volver; *"),
-						b: String::from("los haré volver de las profundidades del mar;")
+						b: String::from("los haré volver de las profundidades del mar;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Para que tu pie se enrojezca de sangre, *"),
-						b: String::from("y la lengua de tus perros laman la sangre de\ntus enemigos\".")
+						b: String::from("y la lengua de tus perros laman la sangre de\ntus enemigos\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Miran tu cortejo, oh Dios, *"),
-						b: String::from("el cortejo hacia el santuario,\nmi Dios y mi Rey.")
+						b: String::from("el cortejo hacia el santuario,\nmi Dios y mi Rey."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("Los cantores marchan al frente, los músicos detrás; *"),
-						b: String::from("en medio de las doncellas que tocan panderos.")
+						b: String::from("en medio de las doncellas que tocan panderos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Bendigan a Dios en la congregación, *"),
-						b: String::from("bendigan al Señor, ustedes de la estirpe de Israel.")
+						b: String::from("bendigan al Señor, ustedes de la estirpe de Israel."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Ahí va delante Benjamín, el menor de las tribus; después, en fila, los príncipes de Judá; *"),
-						b: String::from("los príncipes de Zebulón y los de Neftalí.")
+						b: String::from("los príncipes de Zebulón y los de Neftalí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("Envía tu poder, oh Dios; *"),
-						b: String::from("confirma, oh Dios, lo que has hecho por nosotros.")
+						b: String::from("confirma, oh Dios, lo que has hecho por nosotros."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 29,
 						a: String::from("Por amor a tu templo en Jerusalén, *"),
-						b: String::from("los reyes te traerán dones.")
+						b: String::from("los reyes te traerán dones."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 30,
 						a: String::from("Reprime a la fiera de los carrizales, *"),
-						b: String::from("a los pueblos, como toros con sus becerros.")
+						b: String::from("a los pueblos, como toros con sus becerros."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 31,
 						a: String::from("Pisotea a los que codician la plata; *"),
-						b: String::from("esparce a los pueblos que se complacen en la guerra.")
+						b: String::from("esparce a los pueblos que se complacen en la guerra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 32,
 						a: String::from("Que traigan tributo de Egipto; *"),
-						b: String::from("que Etiopía extienda sus manos a Dios.")
+						b: String::from("que Etiopía extienda sus manos a Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 33,
 						a: String::from("Reinos de la tierra, canten a Dios; *"),
-						b: String::from("canten alabanzas al Señor.")
+						b: String::from("canten alabanzas al Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 34,
 						a: String::from("Cabalga sobre los cielos, los cielos antiguos; *"),
-						b: String::from("lanza su voz, su voz poderosa.")
+						b: String::from("lanza su voz, su voz poderosa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 35,
 						a: String::from("Atribuyan poder a Dios; *"),
-						b: String::from("su majestad es sobre Israel,\ny su poder sobre los cielos.")
+						b: String::from("su majestad es sobre Israel,\ny su poder sobre los cielos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 36,
 						a: String::from("¡Cuán maravilloso es Dios en su santuario, *"),
-						b: String::from("el Dios de Israel, quien da fortaleza y poder a su pueblo! ¡Bendito sea Dios!")
+						b: String::from("el Dios de Israel, quien da fortaleza y poder a su pueblo! ¡Bendito sea Dios!"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
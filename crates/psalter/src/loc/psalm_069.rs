@@ -15,22 +15,26 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Sálvame, oh Dios, *"),
-						b: String::from("porque me llegan las aguas al cuello.")
+						b: String::from("porque me llegan las aguas al cuello."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Me estoy hundiendo en cieno profundo, *"),
-						b: String::from("donde no puedo apoyar los pies.")
+						b: String::from("donde no puedo apoyar los pies."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("He entrado en aguas profundas, *"),
-						b: String::from("y el torrente me ha anegado.")
+						b: String::from("y el torrente me ha anegado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Cansado estoy de gritar;\nmi garganta se ha enronquecido; *"),
-						b: String::from("han desfallecido mis ojos de tanto aguardar a mi Dios.")
+						b: String::from("han desfallecido mis ojos de tanto aguardar a mi Dios."),
+						..Default::default()
 					}
 				]
 			},
@@ -45,175 +49,210 @@ lazy_static! {
 					PsalmVerse {
 						number: 5,
 						a: String::from("Más que los cabellos de mi cabeza son los que me odian sin razón,"),
-						b: String::from("")
+						b: String::from(""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Oh Dios, tú conoces mi insensatez, *"),
-						b: String::from("y mis delitos no te son ocultos.")
+						b: String::from("y mis delitos no te son ocultos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("No sean avergonzados por causa mía los que en ti confían, Señor Dios de los ejércitos; *"),
-						b: String::from("no sean deshonrados por mí los que te buscan, oh Dios de Israel.")
+						b: String::from("no sean deshonrados por mí los que te buscan, oh Dios de Israel."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Ciertamente por amor a ti he sufrido afrenta; *"),
-						b: String::from("la vergüenza ha cubierto mi rostro.")
+						b: String::from("la vergüenza ha cubierto mi rostro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Extranjero he llegado a ser para mis propios hermanos, *"),
-						b: String::from("forastero para los hijos de mi madre.")
+						b: String::from("forastero para los hijos de mi madre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Me consumió el celo de tu casa; *"),
-						b: String::from("las ofensas de los que te insultaban cayeron sobre mí.")
+						b: String::from("las ofensas de los que te insultaban cayeron sobre mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Me humillé con ayunos, *"),
-						b: String::from("mas esto me ha sido por afrenta.")
+						b: String::from("mas esto me ha sido por afrenta."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Me vestí además de luto, *"),
-						b: String::from("y vine a serles como proverbio.")
+						b: String::from("y vine a serles como proverbio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Murmuran contra mí los que se sientan a la puerta, *"),
-						b: String::from("y me zahieren en sus canciones los borrachos.")
+						b: String::from("y me zahieren en sus canciones los borrachos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Pero yo oro a ti, oh Señor, *"),
-						b: String::from("en el tiempo que has fijado:")
+						b: String::from("en el tiempo que has fijado:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("\"Oh Dios, por la abundancia de tu misericordia, *"),
-						b: String::from("respóndeme con tu inagotable socorro.")
+						b: String::from("respóndeme con tu inagotable socorro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Sálvame del cieno, que no me hunda, *"),
-						b: String::from("líbrame de los que me odian y de las aguas profundas.")
+						b: String::from("líbrame de los que me odian y de las aguas profundas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Que no me anegue el torrente, y no me trague el abismo; *"),
-						b: String::from("que no se cierre el pozo sobre mí.")
+						b: String::from("que no se cierre el pozo sobre mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Respóndeme, oh Señor, porque benigna es tu misericordia; *"),
-						b: String::from("por tu gran compasión, vuélvete hacia mí\".")
+						b: String::from("por tu gran compasión, vuélvete hacia mí\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("\"No escondas de tu siervo tu rostro; *"),
-						b: String::from("apresúrate, respóndeme, porque estoy angustiado.")
+						b: String::from("apresúrate, respóndeme, porque estoy angustiado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Acércate y redímeme; *"),
-						b: String::from("a causa de mis enemigos, líbrame.")
+						b: String::from("a causa de mis enemigos, líbrame."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Tú sabes mi afrenta, mi vergüenza y mi oprobio; *"),
-						b: String::from("bajo tu mirada están todos mis adversarios\".")
+						b: String::from("bajo tu mirada están todos mis adversarios\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("La afrenta me destroza el corazón, y no puede ser sanado; *"),
-						b: String::from("busqué compasión, y no la encontré, consoladores, y ninguno hallé.")
+						b: String::from("busqué compasión, y no la encontré, consoladores, y ninguno hallé."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Me pusieron hiel por comida, *"),
-						b: String::from("y en mi sed me dieron a beber vinagre.")
+						b: String::from("y en mi sed me dieron a beber vinagre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Que su mesa les sirva de trampa, *"),
-						b: String::from("y sus festividades de lazo.")
+						b: String::from("y sus festividades de lazo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("Sean oscurecidos sus ojos para que no vean, *"),
-						b: String::from("y haz temblar continuamente sus lomos.")
+						b: String::from("y haz temblar continuamente sus lomos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Derrama sobre ellos tu ira, *"),
-						b: String::from("y el furor de tu enojo los alcance.")
+						b: String::from("y el furor de tu enojo los alcance."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Sea su campamento desolado, *"),
-						b: String::from("y en sus tiendas no haya quien more;")
+						b: String::from("y en sus tiendas no haya quien more;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("Porque persiguieron al que tú heriste, *"),
-						b: String::from("y añaden al dolor de los que tú llagaste.")
+						b: String::from("y añaden al dolor de los que tú llagaste."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 29,
 						a: String::from("Acúsalos, añádeles culpa tras culpa, *"),
-						b: String::from("y no reciban tu vindicación.")
+						b: String::from("y no reciban tu vindicación."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 30,
 						a: String::from("Sean borrados del libro de los vivos, *"),
-						b: String::from("y no sean inscritos entre los justos.")
+						b: String::from("y no sean inscritos entre los justos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 31,
 						a: String::from("Por mi parte, estoy afligido y en dolor; *"),
-						b: String::from("tu socorro, oh Dios, me pondrá en lo alto.")
+						b: String::from("tu socorro, oh Dios, me pondrá en lo alto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 32,
 						a: String::from("Alabaré el Nombre de Dios con cánticos, *"),
-						b: String::from("proclamaré su grandeza con acción de gracias.")
+						b: String::from("proclamaré su grandeza con acción de gracias."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 33,
 						a: String::from("Esto agradará al Señor más que sacrificio de bueyes, *"),
-						b: String::from("más que becerros con cuernos y pezuñas.")
+						b: String::from("más que becerros con cuernos y pezuñas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 34,
 						a: String::from("Lo verán los afligidos, y se alegrarán; *"),
-						b: String::from("ustedes que buscan a Dios, avivarán sus corazones;")
+						b: String::from("ustedes que buscan a Dios, avivarán sus corazones;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 35,
 						a: String::from("Porque el Señor escucha a los menesterosos, *"),
-						b: String::from("y no desprecia a sus prisioneros.")
+						b: String::from("y no desprecia a sus prisioneros."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 36,
 						a: String::from("Alábenle los cielos y la tierra, *"),
-						b: String::from("los mares y cuanto se mueve en ellos;")
+						b: String::from("los mares y cuanto se mueve en ellos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 37,
 						a: String::from("Porque Dios salvará a Sión,\ny reconstruirá las ciudades de Judá; *"),
-						b: String::from("los desterrados volverán allí y la poseerán.")
+						b: String::from("los desterrados volverán allí y la poseerán."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 38,
 						a: String::from("La descendencia de sus siervos la heredará, *"),
-						b: String::from("y los que aman su Nombre habitarán en ella.")
+						b: String::from("y los que aman su Nombre habitarán en ella."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,35 +15,42 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Dígnate, oh Dios, librarme; *"),
-						b: String::from("Señor, apresúrate a socorrerme.")
+						b: String::from("Señor, apresúrate a socorrerme."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Sean avergonzados y confundidos a una, los que buscan mi vida; *"),
-						b: String::from("vuelvan atrás y averguéncense, los que mi ruina desean.")
+						b: String::from("vuelvan atrás y averguéncense, los que mi ruina desean."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Vuélvanse atrás, avergonzados, *"),
-						b: String::from("los que con malicia me dicen: \"¡Ajá!\"")
+						b: String::from("los que con malicia me dicen: \"¡Ajá!\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Gócense y alégrense en ti todos los que te buscan; *"),
-						b: String::from("digan siempre los que aman tu salvación:\n\"¡Grande es el Señor!\"")
+						b: String::from("digan siempre los que aman tu salvación:\n\"¡Grande es el Señor!\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("En cuanto a mí, estoy afligido y en necesidad; *"),
-						b: String::from("apresúrate y ven a mí, oh Dios.")
+						b: String::from("apresúrate y ven a mí, oh Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Mi ayuda y mi libertador eres tú; *"),
-						b: String::from("no te tardes, oh Señor.")
+						b: String::from("no te tardes, oh Señor."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
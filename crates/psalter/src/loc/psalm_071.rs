@@ -15,125 +15,150 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("En ti, oh Señor, me he refugiado; *"),
-						b: String::from("no sea yo avergonzado lamas.")
+						b: String::from("no sea yo avergonzado lamas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("En tu justicia, líbrame y rescátame; *"),
-						b: String::from("inclina a mí tu oído, y sálvame.")
+						b: String::from("inclina a mí tu oído, y sálvame."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Sé tú mi roca de refugio,\nel alcázar donde me salve; *"),
-						b: String::from("tú eres mi risco y mi fortaleza.")
+						b: String::from("tú eres mi risco y mi fortaleza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Dios mío, líbrame de la mano del malvado, *"),
-						b: String::from("de las garras del malhechor y opresor;")
+						b: String::from("de las garras del malhechor y opresor;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Porque tú, Señor Dios, eres mi esperanza, *"),
-						b: String::from("mi confianza desde mi juventud.")
+						b: String::from("mi confianza desde mi juventud."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("En ti he sido sustentado desde el vientre;\ndesde el seno de mi madre has sido mi vigor; *"),
-						b: String::from("de ti será siempre mi alabanza.")
+						b: String::from("de ti será siempre mi alabanza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Portento he sido a muchos, *"),
-						b: String::from("mas tú eres mi refugio y fortaleza.")
+						b: String::from("mas tú eres mi refugio y fortaleza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Sea llena mi boca de tu alabanza, *"),
-						b: String::from("y de tu gloria todo el día.")
+						b: String::from("y de tu gloria todo el día."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("No me deseches en la vejez; *"),
-						b: String::from("cuando mi fuerza se acabare, no me desampares;")
+						b: String::from("cuando mi fuerza se acabare, no me desampares;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Porque mis enemigos hablan contra mí, *"),
-						b: String::from("y los que acechan mi vida conspiran.")
+						b: String::from("y los que acechan mi vida conspiran."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Dicen: \"Dios lo ha desamparado; *"),
-						b: String::from("persíganlo y agárrenlo,\nporque no hay quien lo defienda\".")
+						b: String::from("persíganlo y agárrenlo,\nporque no hay quien lo defienda\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Oh Dios, no te alejes de mí; *"),
-						b: String::from("Dios mío, apresúrate a socorrerme.")
+						b: String::from("Dios mío, apresúrate a socorrerme."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Sean avergonzados y deshonrados mis adversarios; *"),
-						b: String::from("queden cubiertos de desdén e improperio\nlos que buscan mi daño.")
+						b: String::from("queden cubiertos de desdén e improperio\nlos que buscan mi daño."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Mas yo con paciencia siempre esperaré, *"),
-						b: String::from("y te alabaré más y más.")
+						b: String::from("y te alabaré más y más."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Mi boca pregonará tus proezas\ny tus hechos salvíficos todo el día, *"),
-						b: String::from("aunque no puedo enumerarlos.")
+						b: String::from("aunque no puedo enumerarlos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Comenzaré con las proezas del Señor Dios; *"),
-						b: String::from("haré presente tu fidelidad, sólo la tuya.")
+						b: String::from("haré presente tu fidelidad, sólo la tuya."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Oh Dios, me has enseñado desde mi juventud, *"),
-						b: String::from("y aun hoy relato tus maravillas.")
+						b: String::from("y aun hoy relato tus maravillas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Ahora en la vejez y lleno de canas,\nno me desampares, oh Dios, *"),
-						b: String::from("hasta que dé a conocer tu fortaleza a esta generación, y tu poder a los que han de venir.")
+						b: String::from("hasta que dé a conocer tu fortaleza a esta generación, y tu poder a los que han de venir."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Tu justicia, oh Dios, alcanza hasta los cielos; *"),
-						b: String::from("has hecho proezas;\n¿quién como tú, oh Dios?")
+						b: String::from("has hecho proezas;\n¿quién como tú, oh Dios?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Tú me has hecho ver muchas angustias y males, *"),
-						b: String::from("pero volverás a darme vida,\ny de nuevo me levantarás de los abismos de la tierra.")
+						b: String::from("pero volverás a darme vida,\ny de nuevo me levantarás de los abismos de la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Aumentas mis fuerzas más y más; *"),
-						b: String::from("me abrazas y me consuelas.")
+						b: String::from("me abrazas y me consuelas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Por tanto, te alabaré con lira por tu lealtad, oh Dios mío; *"),
-						b: String::from("te cantaré con arpa, oh Santo de Israel.")
+						b: String::from("te cantaré con arpa, oh Santo de Israel."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Mis labios cantarán con júbilo, cuando toque para ti, *"),
-						b: String::from("y también mi alma, la cual redimiste.")
+						b: String::from("y también mi alma, la cual redimiste."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Mi lengua proclamará tu justicia todo el día, *"),
-						b: String::from("porque están avergonzados y deshonrados\nlos que buscaban mi vida.")
+						b: String::from("porque están avergonzados y deshonrados\nlos que buscaban mi vida."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,150 +15,180 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Cuán bueno es Dios para con Israel, *"),
-						b: String::from("para con los limpios de corazón!")
+						b: String::from("para con los limpios de corazón!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("En cuanto a mí, casi resbalaron mis pies; *"),
-						b: String::from("casi tropecé y caí;")
+						b: String::from("casi tropecé y caí;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Porque tuve envidia de los arrogantes, *"),
-						b: String::from("al ver la prosperidad de los malvados;")
+						b: String::from("al ver la prosperidad de los malvados;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Porque ellos no sufren ningún dolor, *"),
-						b: String::from("y sus cuerpos son sanos y hermosos.")
+						b: String::from("y sus cuerpos son sanos y hermosos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("No comparten el infortunio humano, *"),
-						b: String::from("ni sufren como los demás.")
+						b: String::from("ni sufren como los demás."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Por tanto, la soberbia les sirve de collar; *"),
-						b: String::from("y la violencia, como un manto, los envuelve.")
+						b: String::from("y la violencia, como un manto, los envuelve."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Su iniquidad proviene de mentes crasas, *"),
-						b: String::from("y sus corazones rebosan de malos pensamientos.")
+						b: String::from("y sus corazones rebosan de malos pensamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Se mofan y hablan malicia; *"),
-						b: String::from("en su altanería amenazan con la opresión.")
+						b: String::from("en su altanería amenazan con la opresión."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Con su boca retan al cielo, *"),
-						b: String::from("y su lengua recorre la tierra.")
+						b: String::from("y su lengua recorre la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Por eso el pueblo los alaba, *"),
-						b: String::from("y no encuentra ninguna falta en ellos.")
+						b: String::from("y no encuentra ninguna falta en ellos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Dicen: \"¿Cómo sabrá Dios? *"),
-						b: String::from("¿Hay conocimiento en el Altísimo?\"")
+						b: String::from("¿Hay conocimiento en el Altísimo?\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Así pues son los malvados; *"),
-						b: String::from("siempre seguros, acumulan riquezas.")
+						b: String::from("siempre seguros, acumulan riquezas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("En vano he limpiado mi corazón, *"),
-						b: String::from("y lavado mis manos en inocencia.")
+						b: String::from("y lavado mis manos en inocencia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("He sido azotado todo el día, *"),
-						b: String::from("y castigado todas las mañanas.")
+						b: String::from("y castigado todas las mañanas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Si yo continuara hablando así, *"),
-						b: String::from("habría engañado a la generación de tus hijos.")
+						b: String::from("habría engañado a la generación de tus hijos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Cuando traté de entender esto, *"),
-						b: String::from("me resultó muy difícil,")
+						b: String::from("me resultó muy difícil,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Hasta que entré en el santuario de Dios *"),
-						b: String::from("y comprendí el destino de ellos.")
+						b: String::from("y comprendí el destino de ellos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Ciertamente los has puesto en deslizaderos: *"),
-						b: String::from("los precipitas a la ruina.")
+						b: String::from("los precipitas a la ruina."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("¡Oh cuán de repente son asolados, *"),
-						b: String::from("se acaban y perecen de terror!")
+						b: String::from("se acaban y perecen de terror!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Como un sueño al despertar, oh Soberano mío, *"),
-						b: String::from("así haces desvanecer su imagen al levantarte.")
+						b: String::from("así haces desvanecer su imagen al levantarte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Cuando se amargó mi mente, *"),
-						b: String::from("mi corazón sintió punzadas.")
+						b: String::from("mi corazón sintió punzadas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Yo era necio e ignorante; *"),
-						b: String::from("era como bestia en tu presencia.")
+						b: String::from("era como bestia en tu presencia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Aún así yo siempre estoy contigo; *"),
-						b: String::from("me sostienes de la mano derecha.")
+						b: String::from("me sostienes de la mano derecha."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Me guiarás según tu consejo, *"),
-						b: String::from("y después me recibirás en gloria.")
+						b: String::from("y después me recibirás en gloria."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("¿A quién tengo yo en los cielos sino a ti? *"),
-						b: String::from("Y fuera de ti nada deseo en la tierra.")
+						b: String::from("Y fuera de ti nada deseo en la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Aunque mi carne y mi corazón desfallecieren, *"),
-						b: String::from("Dios es la fuerza de mi corazón y mi porción eterna.")
+						b: String::from("Dios es la fuerza de mi corazón y mi porción eterna."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Ciertamente perecerán los que te abandonan; *"),
-						b: String::from("tú destruyes a todos los que son infieles.")
+						b: String::from("tú destruyes a todos los que son infieles."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("Pero es bueno para mí estar junto a Dios; *"),
-						b: String::from("he puesto mi esperanza en el Señor Dios.")
+						b: String::from("he puesto mi esperanza en el Señor Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 29,
 						a: String::from("Hablaré de todas tus obras, *"),
-						b: String::from("en las puertas de la ciudad de Sión.")
+						b: String::from("en las puertas de la ciudad de Sión."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
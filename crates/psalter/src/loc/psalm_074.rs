@@ -15,115 +15,138 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¿Por qué, oh Dios, nos has desechado para siempre? *"),
-						b: String::from("¿Por qué se ha encendido tu ira\ncontra las ovejas de tu prado?")
+						b: String::from("¿Por qué se ha encendido tu ira\ncontra las ovejas de tu prado?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Acuérdate de tu congregación,\nque adquiriste desde tiempos antiguos, *"),
-						b: String::from("de la tribu que redimiste para herencia tuya, y del monte Sión donde pusiste tu morada.")
+						b: String::from("de la tribu que redimiste para herencia tuya, y del monte Sión donde pusiste tu morada."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Vuelve tus pasos hacia las ruinas interminables; *"),
-						b: String::from("el enemigo ha arrasado del todo tu santuario.")
+						b: String::from("el enemigo ha arrasado del todo tu santuario."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Rugieron tus adversarios en medio de tus asambleas; *"),
-						b: String::from("plantaron sus estandartes en señal de victoria.")
+						b: String::from("plantaron sus estandartes en señal de victoria."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Eran como los que levantan el hacha en medio del bosque; *"),
-						b: String::from("con hachuelas y martillos quebraron todas tus\nentalladuras.")
+						b: String::from("con hachuelas y martillos quebraron todas tus\nentalladuras."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Han puesto fuego a tu santuario; *"),
-						b: String::from("han profanado la morada de tu\nNombre, echándola por tierra.")
+						b: String::from("han profanado la morada de tu\nNombre, echándola por tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Dijeron en su corazón: \"Destruyámoslos por completo\". *"),
-						b: String::from("Han quemado todas las asambleas de Dios en la tierra.")
+						b: String::from("Han quemado todas las asambleas de Dios en la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Para nosotros no hay señales que podamos ver; ya no queda ningún profeta; *"),
-						b: String::from("ninguno entre nosotros sabe hasta cuándo.")
+						b: String::from("ninguno entre nosotros sabe hasta cuándo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("¿Hasta cuándo, oh Dios, nos afrentará el adversario? *"),
-						b: String::from("¿Ha de blasfemar el enemigo perpetuamente tu Nombre?")
+						b: String::from("¿Ha de blasfemar el enemigo perpetuamente tu Nombre?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("¿Por qué retraes tu mano? *"),
-						b: String::from("¿Por qué escondes tu diestra en el pecho?")
+						b: String::from("¿Por qué escondes tu diestra en el pecho?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Empero, oh Dios, tú eres mi Rey desde tiempo antiguo, *"),
-						b: String::from("victorioso en medio de la tierra.")
+						b: String::from("victorioso en medio de la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Dividiste el mar con tu poder; *"),
-						b: String::from("quebrantaste la cabeza del dragón sobre las aguas.")
+						b: String::from("quebrantaste la cabeza del dragón sobre las aguas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Tú aplastaste las cabezas de Leviatán; *"),
-						b: String::from("lo diste por comida a los moradores del desierto.")
+						b: String::from("lo diste por comida a los moradores del desierto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Tú hendiste manantiales y torrentes; *"),
-						b: String::from("secaste ríos inagotables.")
+						b: String::from("secaste ríos inagotables."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Tuyo es el día, tuya también la noche; *"),
-						b: String::from("tú estableciste la luna y el sol.")
+						b: String::from("tú estableciste la luna y el sol."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Tú fijaste todos los linderos de la tierra; *"),
-						b: String::from("el verano y el invierno tú los formaste.")
+						b: String::from("el verano y el invierno tú los formaste."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Acuérdate, oh Señor, de cómo el enemigo se ha burlado, *"),
-						b: String::from("de cómo un pueblo insensato ha despreciado tu Nombre.")
+						b: String::from("de cómo un pueblo insensato ha despreciado tu Nombre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("No entregues a las fieras la vida de tu paloma; *"),
-						b: String::from("no olvides jamás la vida de tus afligidos.")
+						b: String::from("no olvides jamás la vida de tus afligidos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Considera tu pacto; *"),
-						b: String::from("los lugares tenebrosos de la tierra\nson madrigueras de violencia.")
+						b: String::from("los lugares tenebrosos de la tierra\nson madrigueras de violencia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("No vuelva avergonzado el oprimido; *"),
-						b: String::from("que el pobre y el menesteroso alaben tu Nombre.")
+						b: String::from("que el pobre y el menesteroso alaben tu Nombre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Levántate, oh Dios; aboga tu causa; *"),
-						b: String::from("acuérdate de cómo el insensato te ultraja todo el día.")
+						b: String::from("acuérdate de cómo el insensato te ultraja todo el día."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("No olvides el clamor de tus adversarios, *"),
-						b: String::from("el tumulto inagotable de los que se rebelan contra ti.")
+						b: String::from("el tumulto inagotable de los que se rebelan contra ti."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
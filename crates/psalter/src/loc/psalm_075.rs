@@ -15,55 +15,66 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Gracias te damos, oh Dios, gracias te damos, *"),
-						b: String::from("invocando tu Nombre, y contando todas tus maravillas.")
+						b: String::from("invocando tu Nombre, y contando todas tus maravillas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Dice Dios: \"Señalaré un tiempo; *"),
-						b: String::from("juzgaré rectamente.")
+						b: String::from("juzgaré rectamente."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Aunque tiemble la tierra y todos sus habitantes, *"),
-						b: String::from("yo afianzaré sus columnas.")
+						b: String::from("yo afianzaré sus columnas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Diré a los jactanciosos: 'No se jacten más', *"),
-						b: String::from("y a los malvados: 'No alcen el testuz;")
+						b: String::from("y a los malvados: 'No alcen el testuz;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("No alcen el testuz contra los cielos, *"),
-						b: String::from("ni levanten la cerviz' \";")
+						b: String::from("ni levanten la cerviz' \";"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Porque ni de oriente ni de occidente viene el juicio, *"),
-						b: String::from("ni del desierto ni de los montes.")
+						b: String::from("ni del desierto ni de los montes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Es Dios el que juzga; *"),
-						b: String::from("a éste humilla, y a aquél enaltece;")
+						b: String::from("a éste humilla, y a aquél enaltece;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Porque en la mano del Señor hay un cáliz, lleno de vino espumante que él derrama; *"),
-						b: String::from("y todos los malvados de la tierra\nlo beberán hasta las heces.")
+						b: String::from("y todos los malvados de la tierra\nlo beberán hasta las heces."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Pero me regocijaré para siempre; *"),
-						b: String::from("cantaré alabanzas al Dios de Jacob.")
+						b: String::from("cantaré alabanzas al Dios de Jacob."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Quebrantará todo el poder de los malvados, *"),
-						b: String::from("pero el poder del justo será exaltado.")
+						b: String::from("pero el poder del justo será exaltado."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
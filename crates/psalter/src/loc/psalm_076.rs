@@ -15,65 +15,78 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Dios es conocido en Judá; *"),
-						b: String::from("en Israel es grande su Nombre.")
+						b: String::from("en Israel es grande su Nombre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("En Salem está su tabernáculo, *"),
-						b: String::from("y su morada en Sión.")
+						b: String::from("y su morada en Sión."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Allí quebró las saetas centellantes, *"),
-						b: String::from("el escudo, la espada y las armas de guerra.")
+						b: String::from("el escudo, la espada y las armas de guerra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("¡Cuán glorioso eres tú, *"),
-						b: String::from("más espléndido que los montes eternos!")
+						b: String::from("más espléndido que los montes eternos!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Los fuertes de corazón son despojados, duermen su sueño; *"),
-						b: String::from("a los guerreros no les responden sus brazos.")
+						b: String::from("a los guerreros no les responden sus brazos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("A tu reprensión, oh Dios de Jacob, *"),
-						b: String::from("el carro y los caballos fueron aturdidos.")
+						b: String::from("el carro y los caballos fueron aturdidos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("¡Cuán temible eres tú! *"),
-						b: String::from("¿Quién puede estar de pie ante ti,\ncuando se encienda tu ira?")
+						b: String::from("¿Quién puede estar de pie ante ti,\ncuando se encienda tu ira?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Desde los cielos proclamaste la sentencia; *"),
-						b: String::from("la tierra temió, y quedó inmóvil;")
+						b: String::from("la tierra temió, y quedó inmóvil;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Cuando Dios se levantó para juzgar, *"),
-						b: String::from("y para salvar a todos los mansos de la tierra.")
+						b: String::from("y para salvar a todos los mansos de la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Ciertamente, Edom el colérico te alabará, *"),
-						b: String::from("y el remanente de Hamat celebrará tus fiestas.")
+						b: String::from("y el remanente de Hamat celebrará tus fiestas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Hagan votos al Señor su Dios, y páguenlos; *"),
-						b: String::from("que cuantos estén alrededor de él,\ntraigan dones al Temible.")
+						b: String::from("que cuantos estén alrededor de él,\ntraigan dones al Temible."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("El quebranta el espíritu de los príncipes, *"),
-						b: String::from("e inspira temor a los reyes de la tierra.")
+						b: String::from("e inspira temor a los reyes de la tierra."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
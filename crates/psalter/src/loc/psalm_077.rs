@@ -15,105 +15,126 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Con mi voz clamo a Dios; *"),
-						b: String::from("a Dios clamo, y él me escuchará.")
+						b: String::from("a Dios clamo, y él me escuchará."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("A mi Soberano busqué en el día de mi angustia; *"),
-						b: String::from("alzaba a él mis manos de noche, sin descanso;\nrehusé ser consolado.")
+						b: String::from("alzaba a él mis manos de noche, sin descanso;\nrehusé ser consolado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Cuando pienso en Dios, estoy inquieto; *"),
-						b: String::from("medito, y mi espíritu desfallece.")
+						b: String::from("medito, y mi espíritu desfallece."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("No me dejas pegar los ojos; *"),
-						b: String::from("estoy turbado, y no puedo hablar.")
+						b: String::from("estoy turbado, y no puedo hablar."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Considero los días antiguos; *"),
-						b: String::from("recuerdo los años remotos.")
+						b: String::from("recuerdo los años remotos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Converso con mi corazón de noche; *"),
-						b: String::from("medito, y escudriño mi espíritu.")
+						b: String::from("medito, y escudriño mi espíritu."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("¿Es que mi Soberano me rechazará para siempre, *"),
-						b: String::from("y ya no volverá a favorecerme?")
+						b: String::from("y ya no volverá a favorecerme?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("¿Ha cesado para siempre su misericordia? *"),
-						b: String::from("¿Se ha acabado perpetuamente su promesa?")
+						b: String::from("¿Se ha acabado perpetuamente su promesa?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("¿Ha olvidado Dios tener compasión? *"),
-						b: String::from("¿Ha encerrado con ira sus piedades?")
+						b: String::from("¿Ha encerrado con ira sus piedades?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Me dije: \"¡Qué pena la mía! *"),
-						b: String::from("¡Ha perdido su poder la diestra del Altísimo!\"")
+						b: String::from("¡Ha perdido su poder la diestra del Altísimo!\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Me acordaré de las obras del Señor; *"),
-						b: String::from("haré memoria de tus maravillas antiguas.")
+						b: String::from("haré memoria de tus maravillas antiguas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Meditaré en todas tus obras, *"),
-						b: String::from("y consideraré tus hazañas.")
+						b: String::from("y consideraré tus hazañas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Oh Dios, santo es tu camino. *"),
-						b: String::from("¿Qué dios es tan grande como nuestro Dios?")
+						b: String::from("¿Qué dios es tan grande como nuestro Dios?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Tú eres el Dios que hace maravillas; *"),
-						b: String::from("hiciste conocer a los pueblos tu poder.")
+						b: String::from("hiciste conocer a los pueblos tu poder."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Con tu brazo redimiste a tu pueblo, *"),
-						b: String::from("a los hijos de Jacob y de José.")
+						b: String::from("a los hijos de Jacob y de José."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Te vieron las aguas, oh Dios;\nlas aguas te vieron, y temblaron; *"),
-						b: String::from("aun los abismos se estremecieron.")
+						b: String::from("aun los abismos se estremecieron."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Las nubes derramaron sus aguas; tronaron los cielos; *"),
-						b: String::from("tus saetas destellaron de un lado a otro.")
+						b: String::from("tus saetas destellaron de un lado a otro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("El sonido de tu trueno estaba en el torbellino; tus relámpagos alumbraron el mundo; *"),
-						b: String::from("se estremeció y tembló la tierra.")
+						b: String::from("se estremeció y tembló la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("En el mar fue tu camino,\ny tus sendas en las aguas profundas, *"),
-						b: String::from("pero tus pisadas no fueron vistas.")
+						b: String::from("pero tus pisadas no fueron vistas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Condujiste a tu pueblo como a un rebaño, *"),
-						b: String::from("por mano de Moisés y Aarón.")
+						b: String::from("por mano de Moisés y Aarón."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,85 +15,102 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Canten con gozo a Dios, fortaleza nuestra, *"),
-						b: String::from("al Dios de Jacob aclamen con júbilo.")
+						b: String::from("al Dios de Jacob aclamen con júbilo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Entonen canción, y tañan el pandero, *"),
-						b: String::from("la lira templada y el arpa.")
+						b: String::from("la lira templada y el arpa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Toquen el corno en la luna nueva, *"),
-						b: String::from("y en la luna llena, que es el día de nuestra fiesta;")
+						b: String::from("y en la luna llena, que es el día de nuestra fiesta;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Porque estatuto es de Israel, *"),
-						b: String::from("ordenanza del Dios de Jacob.")
+						b: String::from("ordenanza del Dios de Jacob."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Lo estableció como mandato solemne para José, *"),
-						b: String::from("al salir del país de Egipto.")
+						b: String::from("al salir del país de Egipto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("\"Oí la voz de un desconocido; *"),
-						b: String::from("retiré la carga de sus hombros;\nsus manos fueron libradas de los cestos\".")
+						b: String::from("retiré la carga de sus hombros;\nsus manos fueron libradas de los cestos\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("En tu angustia clamaste, y yo te salvé; *"),
-						b: String::from("te respondí desde lo secreto del trueno;\nte probé junto a las aguas de Meribá.")
+						b: String::from("te respondí desde lo secreto del trueno;\nte probé junto a las aguas de Meribá."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Escucha, pueblo mío, y te amonestaré, *"),
-						b: String::from("¡Ojalá me escuchases, oh Israel!")
+						b: String::from("¡Ojalá me escuchases, oh Israel!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("No habrá entre ustedes dios ajeno; *"),
-						b: String::from("no adorarás un dios extranjero.")
+						b: String::from("no adorarás un dios extranjero."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Yo soy el Señor tu Dios,\nque te saqué del país de Egipto; *"),
-						b: String::from("y dije: \"Abre tu boca, y yo la llenaré\";")
+						b: String::from("y dije: \"Abre tu boca, y yo la llenaré\";"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Pero mi pueblo no escuchó mi voz, *"),
-						b: String::from("e Israel no quiso obedecerme.")
+						b: String::from("e Israel no quiso obedecerme."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Lo entregué, por tanto, a la dureza de su corazón, *"),
-						b: String::from("para que anduviese según su antojo.")
+						b: String::from("para que anduviese según su antojo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Ojalá me escuchase mi pueblo, *"),
-						b: String::from("y que Israel caminase por mis caminos!")
+						b: String::from("y que Israel caminase por mis caminos!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("En un momento sometería a sus enemigos, *"),
-						b: String::from("y volvería mi mano contra sus adversarios.")
+						b: String::from("y volvería mi mano contra sus adversarios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Los que aborrecen al Señor se humillarían ante él, *"),
-						b: String::from("y su condenación quedaría sellada para siempre;")
+						b: String::from("y su condenación quedaría sellada para siempre;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Pero yo a Israel alimentaría con el mejor trigo, *"),
-						b: String::from("y con la miel de la peña le saciaría.")
+						b: String::from("y con la miel de la peña le saciaría."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,45 +15,54 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Dios preside en la asamblea divina; *"),
-						b: String::from("en medio de los dioses juzga.")
+						b: String::from("en medio de los dioses juzga."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("\"¿Hasta cuándo juzgarán injustamente, *"),
-						b: String::from("y mostrarán parcialidad a los malvados?")
+						b: String::from("y mostrarán parcialidad a los malvados?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Defiendan al desvalido y al huérfano; *"),
-						b: String::from("vindiquen al afligido y al menesteroso.")
+						b: String::from("vindiquen al afligido y al menesteroso."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Rescaten a los débiles y a los pobres; *"),
-						b: String::from("de mano de los malvados líbrenlos.")
+						b: String::from("de mano de los malvados líbrenlos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Ellos no saben, no entienden, caminan a oscuras; *"),
-						b: String::from("tiemblan todos los cimientos de la tierra.")
+						b: String::from("tiemblan todos los cimientos de la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("He dicho que ustedes son dioses, *"),
-						b: String::from("y todos hijos del Altísimo.")
+						b: String::from("y todos hijos del Altísimo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Sin embargo, morirán como mortales, *"),
-						b: String::from("y caerán como cualquier príncipe\".")
+						b: String::from("y caerán como cualquier príncipe\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Levántate, oh Dios, y reina en la tierra; *"),
-						b: String::from("entra en posesión de todas las naciones.")
+						b: String::from("entra en posesión de todas las naciones."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
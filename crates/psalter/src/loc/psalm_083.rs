@@ -15,95 +15,114 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Dios, no estés callado; *"),
-						b: String::from("no guardes silencio, ni te quedes inmóvil.")
+						b: String::from("no guardes silencio, ni te quedes inmóvil."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("He aquí, tus enemigos se alborotan, *"),
-						b: String::from("y los que te odian levantan la cabeza.")
+						b: String::from("y los que te odian levantan la cabeza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Contra tu pueblo urden intrigas, *"),
-						b: String::from("y se conjuran contra tus protegidos.")
+						b: String::from("y se conjuran contra tus protegidos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Han dicho: \"Vengan, extirpémosles de entre las naciones; *"),
-						b: String::from("que no haya más memoria del nombre de Israel\".")
+						b: String::from("que no haya más memoria del nombre de Israel\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Se confabulan a una; *"),
-						b: String::from("contra ti han hecho alianza:")
+						b: String::from("contra ti han hecho alianza:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Las tiendas de los edomitas y de los ismaelitas; *"),
-						b: String::from("los moabitas y los agarenos;")
+						b: String::from("los moabitas y los agarenos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Gebal, Amón y Amalec, *"),
-						b: String::from("los filisteos y los habitantes de Tiro.")
+						b: String::from("los filisteos y los habitantes de Tiro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("También los asirios se han juntado con ellos; *"),
-						b: String::from("y prestaron refuerzos al pueblo de Lot.")
+						b: String::from("y prestaron refuerzos al pueblo de Lot."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Trátalos como a Madián, como a Sísara, *"),
-						b: String::from("como a Jabín en el arroyo de Cisón:")
+						b: String::from("como a Jabín en el arroyo de Cisón:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Fueron aniquilados en Endor; *"),
-						b: String::from("sirvieron de estiércol para el campo.")
+						b: String::from("sirvieron de estiércol para el campo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Trata a sus príncipes como a Oreb y a Zeeb, *"),
-						b: String::from("a sus reyes como a Zeba y a Zalmuna,")
+						b: String::from("a sus reyes como a Zeba y a Zalmuna,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Que dijeron: \"Entremos en posesión *"),
-						b: String::from("de los campos de Dios\".")
+						b: String::from("de los campos de Dios\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Oh Dios mío, hazlos como torbellino, *"),
-						b: String::from("y como tamo ante el viento;")
+						b: String::from("y como tamo ante el viento;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Como fuego que quema el bosque, *"),
-						b: String::from("como llama que arrasa el monte.")
+						b: String::from("como llama que arrasa el monte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Persíguelos con tu tempestad, *"),
-						b: String::from("y atérralos con tu tormenta.")
+						b: String::from("y atérralos con tu tormenta."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Cúbreles el rostro de ignominia, *"),
-						b: String::from("para que busquen tu Nombre, oh Señor.")
+						b: String::from("para que busquen tu Nombre, oh Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Sean afrentados y turbados para siempre; *"),
-						b: String::from("que se avergüencen y mueran.")
+						b: String::from("que se avergüencen y mueran."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Reconozcan que tu Nombre es YAHVÉ, *"),
-						b: String::from("que sólo tú eres Altísimo sobre toda la tierra.")
+						b: String::from("que sólo tú eres Altísimo sobre toda la tierra."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
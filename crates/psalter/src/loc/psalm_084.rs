@@ -15,65 +15,78 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Cuán amable tu morada, Señor de los Ejércitos! *"),
-						b: String::from("Anhela mi alma y con ardor desea los atrios del Señor;\nmi corazón y mi carne se regocijan en el Dios vivo.")
+						b: String::from("Anhela mi alma y con ardor desea los atrios del Señor;\nmi corazón y mi carne se regocijan en el Dios vivo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("El gorrión ha encontrado casa,\ny la golondrina nido donde poner sus polluelos: *"),
-						b: String::from("en tus altares, oh Señor de los Ejércitos, Rey mío y Dios mío.")
+						b: String::from("en tus altares, oh Señor de los Ejércitos, Rey mío y Dios mío."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("¡Dichosos los que habitan en tu casa! *"),
-						b: String::from("Perpetuamente te alabarán.")
+						b: String::from("Perpetuamente te alabarán."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("¡Dichosos los que en ti encuentran su fuerza, *"),
-						b: String::from("cuyos corazones están resueltos a peregrinar!")
+						b: String::from("cuyos corazones están resueltos a peregrinar!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Los que atraviesan el valle desolado lo hallan un lugar de fuentes, *"),
-						b: String::from("porque la lluvia temprana lo ha cubierto de charcos.")
+						b: String::from("porque la lluvia temprana lo ha cubierto de charcos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Treparán de baluarte en baluarte, *"),
-						b: String::from("y se revelará el Dios de los dioses en Sión.")
+						b: String::from("y se revelará el Dios de los dioses en Sión."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Señor Dios de los Ejércitos, escucha mi oración; *"),
-						b: String::from("atiéndeme, oh Dios de Jacob.")
+						b: String::from("atiéndeme, oh Dios de Jacob."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Mira, oh Dios, a nuestro Escudo; *"),
-						b: String::from("pon los ojos en el rostro de tu Ungido.")
+						b: String::from("pon los ojos en el rostro de tu Ungido."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Mejor es pasar un día en tus atrios que mil en mi propia casa; *"),
-						b: String::from("vale más estar en el umbral de la casa de mi Dios, que vivir en las tiendas de los malvados;")
+						b: String::from("vale más estar en el umbral de la casa de mi Dios, que vivir en las tiendas de los malvados;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Porque sol y escudo es el Señor Dios; *"),
-						b: String::from("él dará la gracia y la gloria.")
+						b: String::from("él dará la gracia y la gloria."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("No quitará el Señor ningún bien *"),
-						b: String::from("a los que andan en integridad.")
+						b: String::from("a los que andan en integridad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("¡Oh Señor de los Ejércitos, *"),
-						b: String::from("dichosos los que en ti confían!")
+						b: String::from("dichosos los que en ti confían!"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,70 +15,84 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Fuiste propicio a tu tierra, oh Señor; *"),
-						b: String::from("restauraste la suerte de Jacob.")
+						b: String::from("restauraste la suerte de Jacob."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Perdonaste la iniquidad de tu pueblo; *"),
-						b: String::from("todos sus pecados cubriste.")
+						b: String::from("todos sus pecados cubriste."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Reprimiste todo tu enojo; *"),
-						b: String::from("te apartaste del ardor de tu ira.")
+						b: String::from("te apartaste del ardor de tu ira."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Restáuranos, oh Dios nuestro Salvador, *"),
-						b: String::from("y haz cesar tu cólera contra nosotros.")
+						b: String::from("y haz cesar tu cólera contra nosotros."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("¿Estarás siempre enojado contra nosotros? *"),
-						b: String::from("¿Prolongarás tu ira de edad en edad?")
+						b: String::from("¿Prolongarás tu ira de edad en edad?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("¿No volverás a darnos vida, *"),
-						b: String::from("para que tu pueblo se regocije en ti?")
+						b: String::from("para que tu pueblo se regocije en ti?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Señor, muéstranos tu misericordia, *"),
-						b: String::from("y concédenos tu salvación.")
+						b: String::from("y concédenos tu salvación."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Escucharé lo que dice el Señor Dios; *"),
-						b: String::from("porque anuncia paz a su pueblo fiel,\na los que se convierten de corazón.")
+						b: String::from("porque anuncia paz a su pueblo fiel,\na los que se convierten de corazón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Ciertamente cercana está su salvación a cuantos le temen,*"),
-						b: String::from("para que habite su gloria en nuestra tierra.")
+						b: String::from("para que habite su gloria en nuestra tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("La misericordia y la verdad se encontraron; *"),
-						b: String::from("la justicia y la paz se besaron.")
+						b: String::from("la justicia y la paz se besaron."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("La verdad brotará de la tierra, *"),
-						b: String::from("y la justicia mirará desde los cielos.")
+						b: String::from("y la justicia mirará desde los cielos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("En verdad el Señor dará la lluvia, *"),
-						b: String::from("y nuestra tierra dará su fruto.")
+						b: String::from("y nuestra tierra dará su fruto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("La justicia irá delante de él, *"),
-						b: String::from("y la paz será senda para sus pasos.")
+						b: String::from("y la paz será senda para sus pasos."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,35 +15,42 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("En el monte santo está la ciudad que él fundó; *"),
-						b: String::from("ama el Señor las puertas de Sión\nmás que todas las moradas de Jacob.")
+						b: String::from("ama el Señor las puertas de Sión\nmás que todas las moradas de Jacob."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("De ti se dicen cosas gloriosas, *"),
-						b: String::from("oh ciudad de nuestro Dios.")
+						b: String::from("oh ciudad de nuestro Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Cuento a Egipto y a Babilonia entre los que me conocen; *"),
-						b: String::from("he aquí, Filistea, Tiro y Etiopía:\nen Sión fueron nacidos.")
+						b: String::from("he aquí, Filistea, Tiro y Etiopía:\nen Sión fueron nacidos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("De Sión se dirá: \"Todos han nacido en ella, *"),
-						b: String::from("y el Altísimo mismo la sostendrá\".")
+						b: String::from("y el Altísimo mismo la sostendrá\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("El Señor escribirá en el registro de los pueblos: *"),
-						b: String::from("\"Estos también nacieron allí\".")
+						b: String::from("\"Estos también nacieron allí\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Los cantores y los que danzan dirán: *"),
-						b: String::from("\"Todas mis fuentes están en ti\".")
+						b: String::from("\"Todas mis fuentes están en ti\"."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
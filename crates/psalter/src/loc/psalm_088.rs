@@ -15,100 +15,120 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Señor, mi Dios, mi Salvador, *"),
-						b: String::from("día y noche clamo a ti.")
+						b: String::from("día y noche clamo a ti."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Llegue mi oración a tu presencia; *"),
-						b: String::from("inclina tu oído a mi lamento;")
+						b: String::from("inclina tu oído a mi lamento;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Porque estoy hastiado de desdichas, *"),
-						b: String::from("y mi vida está al borde de la tumba.")
+						b: String::from("y mi vida está al borde de la tumba."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Soy contado entre los que bajan a la fosa; *"),
-						b: String::from("soy como un inválido,")
+						b: String::from("soy como un inválido,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Perdido entre los muertos, *"),
-						b: String::from("como los caídos que yacen en el sepulcro,")
+						b: String::from("como los caídos que yacen en el sepulcro,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("De quienes no te acuerdas ya, *"),
-						b: String::from("porque fueron arrancados de tu mano.")
+						b: String::from("porque fueron arrancados de tu mano."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Me has colocado en lo profundo de la fosa, *"),
-						b: String::from("en las tinieblas y en el abismo.")
+						b: String::from("en las tinieblas y en el abismo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Pesa duramente sobre mí tu ira; *"),
-						b: String::from("todas tus grandes olas me hunden.")
+						b: String::from("todas tus grandes olas me hunden."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Has alejado de mí a mis amigos;\nme has puesto por abominación ante ellos; *"),
-						b: String::from("encerrado estoy, y no puedo salir.")
+						b: String::from("encerrado estoy, y no puedo salir."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Los ojos se me nublan a causa de mi aflicción; *"),
-						b: String::from("todos los días te he invocado, oh Señor;\nhe extendido a ti mis manos.")
+						b: String::from("todos los días te he invocado, oh Señor;\nhe extendido a ti mis manos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("¿Harás maravillas por los difuntos? *"),
-						b: String::from("¿Se levantarán para darte gracias los que han muerto?")
+						b: String::from("¿Se levantarán para darte gracias los que han muerto?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("¿Será anunciada en el sepulcro tu misericordia, *"),
-						b: String::from("o tu fidelidad en el reino de la muerte?")
+						b: String::from("o tu fidelidad en el reino de la muerte?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("¿Serán reconocidas en las tinieblas tus maravillas, *"),
-						b: String::from("o tu justicia en el país del olvido?")
+						b: String::from("o tu justicia en el país del olvido?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Mas yo, oh Señor, te pido auxilio; *"),
-						b: String::from("de mañana mi oración se presentará delante de ti.")
+						b: String::from("de mañana mi oración se presentará delante de ti."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("¿Por qué, oh Señor, me has rechazado? *"),
-						b: String::from("¿Por qué escondes de mí tu rostro?")
+						b: String::from("¿Por qué escondes de mí tu rostro?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Desde niño, he sido desgraciado\ny he estado al borde de la muerte; *"),
-						b: String::from("he soportado tus terrores con mente medrosa.")
+						b: String::from("he soportado tus terrores con mente medrosa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Sobre mí ha pasado tu ira flamante, *"),
-						b: String::from("y me han consumido tus terrores.")
+						b: String::from("y me han consumido tus terrores."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Me rodean como un diluvio todo el día; *"),
-						b: String::from("a una me han cercado.")
+						b: String::from("a una me han cercado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Has alejado de mí al amigo y al vecino, *"),
-						b: String::from("y la oscuridad es mi única compañera.")
+						b: String::from("y la oscuridad es mi única compañera."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,57 +15,68 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Tu amor, oh Señor, cantaré perpetuamente; *"),
-						b: String::from("de generación en generación\nanunciará mi boca tu fidelidad;")
+						b: String::from("de generación en generación\nanunciará mi boca tu fidelidad;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Porque seguro estoy que tu amor es para siempre; *"),
-						b: String::from("en los cielos has afirmado tu fidelidad.")
+						b: String::from("en los cielos has afirmado tu fidelidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("\"Hice pacto con mi escogido; *"),
-						b: String::from("juré a David mi siervo, diciendo:")
+						b: String::from("juré a David mi siervo, diciendo:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("'Para siempre confirmaré tu linaje, *"),
-						b: String::from("y edificaré tu trono por todas las generaciones'\".")
+						b: String::from("y edificaré tu trono por todas las generaciones'\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Proclaman los cielos tus maravillas, oh Señor, *"),
-						b: String::from("y tu fidelidad, en la asamblea de los seres celestiales;")
+						b: String::from("y tu fidelidad, en la asamblea de los seres celestiales;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Porque ¿quién en los cielos se compara al Señor? *"),
-						b: String::from("¿Quién como el Señor entre los dioses?")
+						b: String::from("¿Quién como el Señor entre los dioses?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Dios es temido en el consejo de los seres celestiales, *"),
-						b: String::from("grande y terrible para cuantos le rodean.")
+						b: String::from("grande y terrible para cuantos le rodean."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Oh Señor Dios de los Ejércitos, ¿quién como tú? *"),
-						b: String::from("Dios poderoso, tu fidelidad te rodea.")
+						b: String::from("Dios poderoso, tu fidelidad te rodea."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Dominas la braveza del mar, *"),
-						b: String::from("y sosiegas el furor de las olas.")
+						b: String::from("y sosiegas el furor de las olas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Tú quebrantaste a Rahab con herida de muerte;\ncon tu brazo poderoso esparciste a tus enemigos."),
-						b: String::from("")
+						b: String::from(""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Tuyos son los cielos, tuya también la tierra; *"),
-						b: String::from("el mundo y su plenitud, tú lo fundaste.")
+						b: String::from("el mundo y su plenitud, tú lo fundaste."),
+						..Default::default()
 					}
 				]
 			},
@@ -80,32 +91,38 @@ lazy_static! {
 					PsalmVerse {
 						number: 12,
 						a: String::from("El norte y el sur, tú los creaste; *"),
-						b: String::from("")
+						b: String::from(""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Tienes un brazo poderoso; *"),
-						b: String::from("fuerte es tu izquierda y alta tu diestra.")
+						b: String::from("fuerte es tu izquierda y alta tu diestra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Rectitud y justicia son el cimiento de tu trono; *"),
-						b: String::from("amor y fidelidad van delante de tu rostro.")
+						b: String::from("amor y fidelidad van delante de tu rostro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("¡Dichoso el pueblo que sabe la aclamación festiva! *"),
-						b: String::from("Camina, oh Señor, a la luz de tu rostro.")
+						b: String::from("Camina, oh Señor, a la luz de tu rostro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("En tu Nombre se regocija todo el día, *"),
-						b: String::from("y en tu justicia es jubiloso;")
+						b: String::from("y en tu justicia es jubiloso;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Porque tú eres la gloria de su fortaleza, *"),
-						b: String::from("y con tu favor se acrecienta nuestro poder.")
+						b: String::from("y con tu favor se acrecienta nuestro poder."),
+						..Default::default()
 					}
 				]
 			},
@@ -120,180 +137,216 @@ lazy_static! {
 					PsalmVerse {
 						number: 18,
 						a: String::from("Ciertamente, el Señor es nuestro Soberano, *"),
-						b: String::from("y nuestro Rey es el Santo de Israel.")
+						b: String::from("y nuestro Rey es el Santo de Israel."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Hablaste una vez a tus fieles en una visión, y dijiste: *"),
-						b: String::from("\"He puesto la corona sobre un héroe;\nhe levantado a un escogido del pueblo.")
+						b: String::from("\"He puesto la corona sobre un héroe;\nhe levantado a un escogido del pueblo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Hallé a David mi siervo; *"),
-						b: String::from("lo ungí con mi óleo sagrado.")
+						b: String::from("lo ungí con mi óleo sagrado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Mi mano estará siempre con él; *"),
-						b: String::from("mi brazo también lo fortalecerá.")
+						b: String::from("mi brazo también lo fortalecerá."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("No lo engañará ningún enemigo, *"),
-						b: String::from("ni cualquier malvado lo humillará.")
+						b: String::from("ni cualquier malvado lo humillará."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Aplastaré delante de él a sus enemigos *"),
-						b: String::from("y heriré a los que le aborrecen.")
+						b: String::from("y heriré a los que le aborrecen."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Mi amor y fidelidad lo acompañarán, *"),
-						b: String::from("y por mi Nombre será victorioso.")
+						b: String::from("y por mi Nombre será victorioso."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("Pondré su izquierda sobre el mar, *"),
-						b: String::from("y su diestra sobre el río.")
+						b: String::from("y su diestra sobre el río."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("El me invocará: 'Tú eres mi Padre, *"),
-						b: String::from("mi Dios, y la roca de mi salvación'.")
+						b: String::from("mi Dios, y la roca de mi salvación'."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Yo le pondré por primogénito, *"),
-						b: String::from("el más excelso de los reyes de la tierra.")
+						b: String::from("el más excelso de los reyes de la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("Sostendré mi amor por él para siempre, *"),
-						b: String::from("y mi pacto continuará firme con él.")
+						b: String::from("y mi pacto continuará firme con él."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 29,
 						a: String::from("Afirmaré su descendencia para siempre, *"),
-						b: String::from("y su trono como los días de los cielos\".")
+						b: String::from("y su trono como los días de los cielos\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 30,
 						a: String::from("\"Si abandonaren sus hijos mi ley, *"),
-						b: String::from("y no anduvieren de acuerdo con mis juicios;")
+						b: String::from("y no anduvieren de acuerdo con mis juicios;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 31,
 						a: String::from("Si profanaren mis estatutos, *"),
-						b: String::from("y no guardaren mis mandamientos;")
+						b: String::from("y no guardaren mis mandamientos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 32,
 						a: String::from("Entonces castigaré con vara su rebelión, *"),
-						b: String::from("y con azotes sus iniquidades;")
+						b: String::from("y con azotes sus iniquidades;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 33,
 						a: String::from("Mas no retiraré de él mi amor, *"),
-						b: String::from("ni falsearé mi fidelidad.")
+						b: String::from("ni falsearé mi fidelidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 34,
 						a: String::from("No violaré mi pacto, *"),
-						b: String::from("ni cambiaré lo que ha salido de mis labios.")
+						b: String::from("ni cambiaré lo que ha salido de mis labios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 35,
 						a: String::from("De una vez por todas he jurado por mi santidad: *"),
-						b: String::from("'No mentiré a David.")
+						b: String::from("'No mentiré a David."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 36,
 						a: String::from("Su linaje perdurará para siempre, *"),
-						b: String::from("y su trono como el sol delante de mí.")
+						b: String::from("y su trono como el sol delante de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 37,
 						a: String::from("Será firme para siempre como la luna, *"),
-						b: String::from("testigo fiel en los cielos'\".")
+						b: String::from("testigo fiel en los cielos'\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 38,
 						a: String::from("Pero te has airado con tu ungido; *"),
-						b: String::from("lo has rechazado y desechado.")
+						b: String::from("lo has rechazado y desechado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 39,
 						a: String::from("Has roto el pacto con tu siervo; *"),
-						b: String::from("has profanado su corona, arrojándola al suelo.")
+						b: String::from("has profanado su corona, arrojándola al suelo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 40,
 						a: String::from("Has abierto brecha en sus murallas, *"),
-						b: String::from("y has derribado sus fortalezas.")
+						b: String::from("y has derribado sus fortalezas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 41,
 						a: String::from("Lo saquean todos los que pasan por el camino; *"),
-						b: String::from("es escarnio de sus vecinos.")
+						b: String::from("es escarnio de sus vecinos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 42,
 						a: String::from("Has exaltado la diestra de sus enemigos; *"),
-						b: String::from("has alegrado a sus adversarios.")
+						b: String::from("has alegrado a sus adversarios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 43,
 						a: String::from("Has desviado el filo de su espada, *"),
-						b: String::from("y no lo has sostenido en la batalla.")
+						b: String::from("y no lo has sostenido en la batalla."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 44,
 						a: String::from("Has acabado con su esplendor, *"),
-						b: String::from("y has derribado su trono por tierra.")
+						b: String::from("y has derribado su trono por tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 45,
 						a: String::from("Has acortado los días de su juventud, *"),
-						b: String::from("y lo has cubierto de ignominia.")
+						b: String::from("y lo has cubierto de ignominia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 46,
 						a: String::from("¿Hasta cuándo, oh Señor, te esconderás? ¿Te esconderás para siempre? *"),
-						b: String::from("¿Hasta cuándo arderá tu ira como el fuego?")
+						b: String::from("¿Hasta cuándo arderá tu ira como el fuego?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 47,
 						a: String::from("Recuerda, oh Señor, cuán breve es la vida, cuán frágil has creado toda carne."),
-						b: String::from("")
+						b: String::from(""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 48,
 						a: String::from("¿Quién vivirá y no verá la muerte? *"),
-						b: String::from("¿Quién podrá salvar su vida del poder de la fosa?")
+						b: String::from("¿Quién podrá salvar su vida del poder de la fosa?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 49,
 						a: String::from("Oh Señor, ¿dónde están tus antiguas misericordias, *"),
-						b: String::from("que juraste a David en tu fidelidad?")
+						b: String::from("que juraste a David en tu fidelidad?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 50,
 						a: String::from("Acuérdate, oh Señor, del escarnio de tu siervo, *"),
-						b: String::from("de las mofas de muchos pueblos, que llevo en mi seno,")
+						b: String::from("de las mofas de muchos pueblos, que llevo en mi seno,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 51,
 						a: String::from("Las mofas que tus enemigos, oh Señor, han lanzado, *"),
-						b: String::from("las que lanzaron a las huellas de tu ungido.")
+						b: String::from("las que lanzaron a las huellas de tu ungido."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 52,
 						a: String::from("¡Bendito el Señor por siempre jamás! *"),
-						b: String::from("Amén y Amén.\nDía Decimoctavo: oración Matutina")
+						b: String::from("Amén y Amén.\nDía Decimoctavo: oración Matutina"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,90 +15,108 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Soberano mío, tú has sido nuestro refugio *"),
-						b: String::from("de generación en generación.")
+						b: String::from("de generación en generación."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Antes que naciesen los montes,\no fueran engendrados la tierra y el mundo, *"),
-						b: String::from("desde el siglo y hasta el siglo, tú eres Dios.")
+						b: String::from("desde el siglo y hasta el siglo, tú eres Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Devuelves el hombre al polvo, diciendo: *"),
-						b: String::from("\"Retorna, hijo de Adán\".")
+						b: String::from("\"Retorna, hijo de Adán\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Porque mil años delante de tus ojos\nson como el ayer, que pasó, *"),
-						b: String::from("y como una vigilia en la noche.")
+						b: String::from("y como una vigilia en la noche."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Nos arrebatas como en un sueño, *"),
-						b: String::from("como la hierba que pronto se marchita:")
+						b: String::from("como la hierba que pronto se marchita:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Por la mañana florece y crece; *"),
-						b: String::from("por la tarde es cortada y se seca;")
+						b: String::from("por la tarde es cortada y se seca;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Porque en tu furor somos consumidos, *"),
-						b: String::from("y por tu indignación somos conturbados.")
+						b: String::from("y por tu indignación somos conturbados."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Pusiste nuestras iniquidades ante ti, *"),
-						b: String::from("nuestros pecados secretos a la luz de tu rostro.")
+						b: String::from("nuestros pecados secretos a la luz de tu rostro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Todos nuestros días fallecen a causa de tu ira; *"),
-						b: String::from("acabamos nuestros años como un suspiro.")
+						b: String::from("acabamos nuestros años como un suspiro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Los días de nuestra vida son setenta años,\ny quizás en los más robustos hasta ochenta; *"),
-						b: String::from("con todo, la suma de ellos es sólo pesar y trabajo, porque pronto pasan, y desaparecemos.")
+						b: String::from("con todo, la suma de ellos es sólo pesar y trabajo, porque pronto pasan, y desaparecemos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("¿Quién conoce la vehemencia de tu ira? *"),
-						b: String::from("¿Quién teme debidamente tu indignación?")
+						b: String::from("¿Quién teme debidamente tu indignación?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Enséñanos de tal modo a contar nuestros días, *"),
-						b: String::from("que traigamos al corazón sabiduría.")
+						b: String::from("que traigamos al corazón sabiduría."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Vuélvete, oh Señor, ¿hasta cuándo tardarás? *"),
-						b: String::from("Ten compasión de tus siervos.")
+						b: String::from("Ten compasión de tus siervos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Por la mañana sácianos de tu misericordia, *"),
-						b: String::from("y así cantaremos y nos alegraremos todos nuestros días.")
+						b: String::from("y así cantaremos y nos alegraremos todos nuestros días."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Alégranos conforme a los días que nos afligiste, *"),
-						b: String::from("y a los años en que sufrimos desdichas.")
+						b: String::from("y a los años en que sufrimos desdichas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Que tus siervos vean tus obras, *"),
-						b: String::from("y su descendencia tu gloria.")
+						b: String::from("y su descendencia tu gloria."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Sea la bondad del Señor nuestro Dios sobre nosotros, *"),
-						b: String::from("y haga prosperar las obras de nuestras manos;\nsí, haga prosperar nuestras obras.")
+						b: String::from("y haga prosperar las obras de nuestras manos;\nsí, haga prosperar nuestras obras."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
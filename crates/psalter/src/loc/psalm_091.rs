@@ -15,85 +15,102 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("El que habita al abrigo del Altísimo, *"),
-						b: String::from("mora bajo la sombra del Omnipotente.")
+						b: String::from("mora bajo la sombra del Omnipotente."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Dirá al Señor: \"Refugio mío y castillo mío, *"),
-						b: String::from("mi Dios, en quien confío\".")
+						b: String::from("mi Dios, en quien confío\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("El te librará del lazo del cazador, *"),
-						b: String::from("de la peste destructora.")
+						b: String::from("de la peste destructora."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Con sus plumas te cubrirá, y debajo de sus alas estarás seguro; *"),
-						b: String::from("escudo y adarga será su fidelidad.")
+						b: String::from("escudo y adarga será su fidelidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("No temerás espanto nocturno, *"),
-						b: String::from("ni saeta que vuele de día;")
+						b: String::from("ni saeta que vuele de día;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Ni pestilencia que acecha en la oscuridad, *"),
-						b: String::from("ni enfermedad que a mediodía desola.")
+						b: String::from("ni enfermedad que a mediodía desola."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Caerán a tu lado mil, y diez mil a tu diestra, *"),
-						b: String::from("mas a ti no te alcanzará.")
+						b: String::from("mas a ti no te alcanzará."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Ciertamente con tus ojos mirarás, *"),
-						b: String::from("y verás la recompensa de los malvados;")
+						b: String::from("y verás la recompensa de los malvados;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Porque hiciste del Señor tu refugio, *"),
-						b: String::from("del Altísimo, tu habitación,")
+						b: String::from("del Altísimo, tu habitación,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("No te sobrevendrá mal alguno, *"),
-						b: String::from("ni plaga tocará tu morada.")
+						b: String::from("ni plaga tocará tu morada."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Pues a sus ángeles mandará cerca de ti, *"),
-						b: String::from("que te guarden en todos tus caminos.")
+						b: String::from("que te guarden en todos tus caminos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("En las manos te llevarán, *"),
-						b: String::from("para que tu pie no tropiece en piedra.")
+						b: String::from("para que tu pie no tropiece en piedra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Sobre el león y el áspid pisarás; *"),
-						b: String::from("hollarás al cachorro del león y a la serpiente.")
+						b: String::from("hollarás al cachorro del león y a la serpiente."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("\"Por cuanto ha hecho pacto de amor conmigo, yo lo libraré; *"),
-						b: String::from("lo protegeré, por cuanto ha conocido mi Nombre.")
+						b: String::from("lo protegeré, por cuanto ha conocido mi Nombre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Me invocará, y yo le responderé; *"),
-						b: String::from("con él estaré en la angustia;\nlo libraré, y le glorificaré.")
+						b: String::from("con él estaré en la angustia;\nlo libraré, y le glorificaré."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Lo saciaré de largos días, *"),
-						b: String::from("y le mostraré mi salvación\".")
+						b: String::from("y le mostraré mi salvación\"."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,35 +15,42 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("El Señor es Rey; se ha vestido de esplendor; *"),
-						b: String::from("el Señor se ha vestido y ceñido de poder.")
+						b: String::from("el Señor se ha vestido y ceñido de poder."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("De tal manera afirmó el orbe, *"),
-						b: String::from("que no se le puede mover.")
+						b: String::from("que no se le puede mover."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Firme es tu trono desde siempre; *"),
-						b: String::from("tú eres eternamente.")
+						b: String::from("tú eres eternamente."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Alzaron las aguas, oh Señor, las aguas alzaron su voz; *"),
-						b: String::from("alzaron sus ondas aplastantes.")
+						b: String::from("alzaron sus ondas aplastantes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Más potente que la voz de muchas aguas, más potente que los rompientes del mar, *"),
-						b: String::from("más potente es el Señor en las alturas.")
+						b: String::from("más potente es el Señor en las alturas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Tus testimonios son muy firmes; *"),
-						b: String::from("la santidad es el adorno de tu casa, oh Señor,\npor los siglos y para siempre.")
+						b: String::from("la santidad es el adorno de tu casa, oh Señor,\npor los siglos y para siempre."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,120 +15,144 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Señor Dios de la venganza, *"),
-						b: String::from("oh Dios de la venganza, muéstrate.")
+						b: String::from("oh Dios de la venganza, muéstrate."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Levántate, oh Juez del mundo, *"),
-						b: String::from("da su merecido a los soberbios.")
+						b: String::from("da su merecido a los soberbios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("¿Hasta cuándo los malvados, oh Señor, *"),
-						b: String::from("hasta cuándo los malvados triunfarán ?")
+						b: String::from("hasta cuándo los malvados triunfarán ?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("¿Hasta cuándo los insolentes bravearán, *"),
-						b: String::from("y se vanagloriarán los malhechores?")
+						b: String::from("y se vanagloriarán los malhechores?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("A tu pueblo, oh Señor, quebrantan, *"),
-						b: String::from("y afligen a tu heredad.")
+						b: String::from("y afligen a tu heredad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Matan a la viuda y al extranjero, *"),
-						b: String::from("y a los huérfanos quitan la vida.")
+						b: String::from("y a los huérfanos quitan la vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Sin embargo, dicen: \"El Señor no lo ve; *"),
-						b: String::from("no lo observa el Dios de Jacob\".")
+						b: String::from("no lo observa el Dios de Jacob\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Consideren bien, tontos del pueblo; *"),
-						b: String::from("ustedes, necios, ¿cuándo entenderán?")
+						b: String::from("ustedes, necios, ¿cuándo entenderán?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("El que plantó el oído, ¿no va a oír? *"),
-						b: String::from("El que formó el ojo, ¿no va a ver?")
+						b: String::from("El que formó el ojo, ¿no va a ver?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("El que corrige a las naciones, ¿no va a castigar? *"),
-						b: String::from("El que instruye a todo hombre, ¿no va a saber?")
+						b: String::from("El que instruye a todo hombre, ¿no va a saber?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("El Señor conoce los pensamientos humanos, *"),
-						b: String::from("que no son mas que un soplo.")
+						b: String::from("que no son mas que un soplo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Dichosos los que tú instruyes, oh Señor, *"),
-						b: String::from("los que tú enseñas con tu ley,")
+						b: String::from("los que tú enseñas con tu ley,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Dándoles descanso en los días malos, *"),
-						b: String::from("en tanto que para los malvados se cava el hoyo.")
+						b: String::from("en tanto que para los malvados se cava el hoyo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Ciertamente no abandonará el Señor a su pueblo, *"),
-						b: String::from("ni dejará sin amparo a su heredad;")
+						b: String::from("ni dejará sin amparo a su heredad;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Porque el juicio volverá a ser justo, *"),
-						b: String::from("y lo seguirán todos los rectos de corazón.")
+						b: String::from("y lo seguirán todos los rectos de corazón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("¿Quién por mí se levantó contra los malignos? *"),
-						b: String::from("¿Quién se colocó a mi lado frente a los malhechores?")
+						b: String::from("¿Quién se colocó a mi lado frente a los malhechores?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Si no me hubiera ayudado el Señor, *"),
-						b: String::from("pronto habría morado en la tierra de silencio.")
+						b: String::from("pronto habría morado en la tierra de silencio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Cuantas veces decía: \"Mi pie resbala *"),
-						b: String::from("tu amor, oh Señor, me sustentaba.")
+						b: String::from("tu amor, oh Señor, me sustentaba."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Cuando se multiplican mis ansiedades, *"),
-						b: String::from("tus consolaciones alegran mi alma.")
+						b: String::from("tus consolaciones alegran mi alma."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("¿Podrá aliarse contigo un tribunal inicuo, *"),
-						b: String::from("que convierte la injusticia en ley?")
+						b: String::from("que convierte la injusticia en ley?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Conspiran contra la vida del justo, *"),
-						b: String::from("y al inocente condenan a muerte;")
+						b: String::from("y al inocente condenan a muerte;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Mas el Señor se hizo mi baluarte, *"),
-						b: String::from("y mi Dios la roca de mi refugio.")
+						b: String::from("y mi Dios la roca de mi refugio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("El hará volver sobre ellos su iniquidad, y los destruirá en su propia malicia; *"),
-						b: String::from("destruirá el Señor nuestro Dios.")
+						b: String::from("destruirá el Señor nuestro Dios."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
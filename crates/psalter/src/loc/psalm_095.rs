@@ -15,60 +15,72 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Vengan, cantemos alegremente al Señor; *"),
-						b: String::from("aclamemos con júbilo a la Roca que nos salva.")
+						b: String::from("aclamemos con júbilo a la Roca que nos salva."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Lleguemos ante su presencia con alabanza, *"),
-						b: String::from("vitoreándole con cánticos;")
+						b: String::from("vitoreándole con cánticos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Porque el Señor es Dios grande, *"),
-						b: String::from("y Rey grande sobre todos los dioses.")
+						b: String::from("y Rey grande sobre todos los dioses."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("En su mano están las profundidades de la tierra, *"),
-						b: String::from("y las alturas de los montes son suyas.")
+						b: String::from("y las alturas de los montes son suyas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Suyo el mar, pues él lo hizo, *"),
-						b: String::from("y sus manos formaron la tierra seca.")
+						b: String::from("y sus manos formaron la tierra seca."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Vengan, adoremos y postrémonos; *"),
-						b: String::from("arrodillémonos delante del Señor nuestro Hacedor;")
+						b: String::from("arrodillémonos delante del Señor nuestro Hacedor;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Porque él es nuestro Dios;\nnosotros el pueblo de su dehesa, y ovejas de su mano. *"),
-						b: String::from("¡Ojalá escuchen hoy su voz!")
+						b: String::from("¡Ojalá escuchen hoy su voz!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("No endurezcan su corazón,\ncomo en Meribá, y en el día de Masá en el desierto, *"),
-						b: String::from("donde me tentaron sus antepasados.")
+						b: String::from("donde me tentaron sus antepasados."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Me pusieron a prueba, *"),
-						b: String::from("aunque habían visto mis obras.")
+						b: String::from("aunque habían visto mis obras."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Durante cuarenta años aborrecí aquella generación, y dije: *"),
-						b: String::from("\"Es un pueblo que divaga de corazón; no reconoce mis caminos\".")
+						b: String::from("\"Es un pueblo que divaga de corazón; no reconoce mis caminos\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Por tanto, juré en mi furor: *"),
-						b: String::from("\"No entrarán en mi reposo\".")
+						b: String::from("\"No entrarán en mi reposo\"."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
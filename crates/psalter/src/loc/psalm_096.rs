@@ -15,70 +15,84 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Canten al Señor cántico nuevo;*"),
-						b: String::from("canten al Señor, toda la tierra.")
+						b: String::from("canten al Señor, toda la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Canten al Señor, bendigan su Nombre; *"),
-						b: String::from("proclamen de día en día su victoria.")
+						b: String::from("proclamen de día en día su victoria."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Pregonen entre las naciones su gloria, *"),
-						b: String::from("en todos los pueblos sus maravillas;")
+						b: String::from("en todos los pueblos sus maravillas;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Porque grande es el Señor, y muy digno de alabanza; *"),
-						b: String::from("más temible es que todos los dioses;")
+						b: String::from("más temible es que todos los dioses;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Porque todos los dioses de los pueblos son ídolos; *"),
-						b: String::from("pero es el Señor que ha hecho los cielos.")
+						b: String::from("pero es el Señor que ha hecho los cielos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("¡Oh, la majestad y la magnificencia de su presencia! *"),
-						b: String::from("¡Oh, la fuerza y el esplendor de su santuario!")
+						b: String::from("¡Oh, la fuerza y el esplendor de su santuario!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Rindan al Señor, oh familias de los pueblos, *"),
-						b: String::from("rindan al Señor la honra y el poder.")
+						b: String::from("rindan al Señor la honra y el poder."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Rindan al Señor la gloria debida a su Nombre; *"),
-						b: String::from("traigan ofrendas, y entren en sus atrios.")
+						b: String::from("traigan ofrendas, y entren en sus atrios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Adoren al Señor en la hermosura de la santidad; *"),
-						b: String::from("tiemble delante de él toda la tierra.")
+						b: String::from("tiemble delante de él toda la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Pregonen entre las naciones: \"El Señor es Rey; *"),
-						b: String::from("de tal manera ha afirmado el orbe que no\nserá conmovido; juzgará a los pueblos con equidad\".")
+						b: String::from("de tal manera ha afirmado el orbe que no\nserá conmovido; juzgará a los pueblos con equidad\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Alégrense los cielos, y gócese la tierra; truene la mar y su plenitud; *"),
-						b: String::from("regocíjese el campo, y todo lo que en él está.")
+						b: String::from("regocíjese el campo, y todo lo que en él está."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Entonces aclamarán con júbilo todos los árboles del bosque,\ndelante del Señor cuando llegue, *"),
-						b: String::from("cuando llegue a juzgar la tierra.")
+						b: String::from("cuando llegue a juzgar la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Juzgará al mundo con justicia, *"),
-						b: String::from("y a los pueblos con SU verdad.")
+						b: String::from("y a los pueblos con SU verdad."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,65 +15,78 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("El Señor es Rey; regocíjese la tierra; *"),
-						b: String::from("alégrense la multitud de las islas.")
+						b: String::from("alégrense la multitud de las islas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Nubes y oscuridad alrededor de él; *"),
-						b: String::from("rectitud y justicia el cimiento de tu trono.")
+						b: String::from("rectitud y justicia el cimiento de tu trono."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Fuego va delante de él, *"),
-						b: String::from("y abrasa a sus enemigos alrededor.")
+						b: String::from("y abrasa a sus enemigos alrededor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Sus relámpagos alumbran el mundo; *"),
-						b: String::from("viéndolo, la tierra se estremece.")
+						b: String::from("viéndolo, la tierra se estremece."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Los montes se derriten como cera a la vista del Señor, *"),
-						b: String::from("a la vista del Soberano de toda la tierra.")
+						b: String::from("a la vista del Soberano de toda la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Los cielos anuncian su justicia, *"),
-						b: String::from("y todos los pueblos contemplan su gloria.")
+						b: String::from("y todos los pueblos contemplan su gloria."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Avergüéncense todos los que adoran imágenes de talla, *"),
-						b: String::from("los que se glorían en dioses falsos;\npóstrense ante él, dioses todos.")
+						b: String::from("los que se glorían en dioses falsos;\npóstrense ante él, dioses todos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Sión oye, y se alegra,\ny las ciudades de Judá se gozan, *"),
-						b: String::from("a causa de tus juicios, oh Señor;")
+						b: String::from("a causa de tus juicios, oh Señor;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Porque tú eres el Señor, altísimo sobre toda la tierra; *"),
-						b: String::from("eres muy excelso sobre todos los dioses.")
+						b: String::from("eres muy excelso sobre todos los dioses."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("El Señor ama a los que aborrecen el mal; *"),
-						b: String::from("él preserva la vida de sus santos,\ny de mano de los malvados los libra.")
+						b: String::from("él preserva la vida de sus santos,\ny de mano de los malvados los libra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Brota la luz para el justo, *"),
-						b: String::from("y alegría para los rectos de corazón.")
+						b: String::from("y alegría para los rectos de corazón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Alégrense, justos, en el Señor, *"),
-						b: String::from("dando gracias a su santo Nombre.")
+						b: String::from("dando gracias a su santo Nombre."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,55 +15,66 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Canten al Señor cántico nuevo, *"),
-						b: String::from("porque ha hecho maravillas.")
+						b: String::from("porque ha hecho maravillas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Con su diestra, y con su santo brazo, *"),
-						b: String::from("ha alcanzado la victoria.")
+						b: String::from("ha alcanzado la victoria."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("El Señor ha dado a conocer su victoria; *"),
-						b: String::from("a la vista de las naciones ha descubierto su justicia.")
+						b: String::from("a la vista de las naciones ha descubierto su justicia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Se acuerda de su misericordia y su fidelidad para con la casa de Israel; *"),
-						b: String::from("los confines de la tierra\nhan visto la victoria de nuestro Dios.")
+						b: String::from("los confines de la tierra\nhan visto la victoria de nuestro Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Aclamen con júbilo al Señor, pueblos todos; *"),
-						b: String::from("levanten la voz, gócense y canten.")
+						b: String::from("levanten la voz, gócense y canten."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Canten al Señor con el arpa, *"),
-						b: String::from("con el arpa y la voz de cántico.")
+						b: String::from("con el arpa y la voz de cántico."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Con trompetas y al son de clarines, *"),
-						b: String::from("aclamen con júbilo ante el Rey, el Señor.")
+						b: String::from("aclamen con júbilo ante el Rey, el Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Ruja el mar y cuanto contiene, *"),
-						b: String::from("el mundo y los que en él habitan.")
+						b: String::from("el mundo y los que en él habitan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Den palmadas los ríos, aclamen los montes al Señor, *"),
-						b: String::from("cuando llegue para juzgar la tierra.")
+						b: String::from("cuando llegue para juzgar la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Juzgará al mundo con justicia, *"),
-						b: String::from("y a los pueblos con equidad.")
+						b: String::from("y a los pueblos con equidad."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
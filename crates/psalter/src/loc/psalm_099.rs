@@ -15,50 +15,60 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("El Señor es Rey; tiemblen los pueblos; *"),
-						b: String::from("está entronizado sobre querubines;\nsacúdase la tierra.")
+						b: String::from("está entronizado sobre querubines;\nsacúdase la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("El Señor es grande en Sión; *"),
-						b: String::from("es excelso sobre todos los pueblos.")
+						b: String::from("es excelso sobre todos los pueblos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Alaben su Nombre, porque es grande y temible; *"),
-						b: String::from("él es el Santo.")
+						b: String::from("él es el Santo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("\"Oh Rey poderoso, amante de la justicia, has establecido la equidad; *"),
-						b: String::from("has administrado la justicia y el derecho en Jacob\".")
+						b: String::from("has administrado la justicia y el derecho en Jacob\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Proclamen la grandeza del Señor nuestro Dios, y póstrense ante el estrado de sus pies; *"),
-						b: String::from("él es el Santo.")
+						b: String::from("él es el Santo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Moisés y Aarón entre sus sacerdotes,\ny Samuel entre los que invocan su Nombre, *"),
-						b: String::from("invocaban al Señor, y él les respondía.")
+						b: String::from("invocaban al Señor, y él les respondía."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Desde la columna de nube les hablaba; *"),
-						b: String::from("guardaban sus testimonios, y el decreto que les dio.")
+						b: String::from("guardaban sus testimonios, y el decreto que les dio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("\"Oh Señor Dios nuestro, en verdad les respondías; *"),
-						b: String::from("tú eras para ellos un Dios de perdón;\ncon todo, les castigabas por sus malas obras\".")
+						b: String::from("tú eras para ellos un Dios de perdón;\ncon todo, les castigabas por sus malas obras\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Proclamen la grandeza del Señor nuestro Dios, y adórenle sobre su santo monte, *"),
-						b: String::from("porque el Señor nuestro Dios es el Santo.")
+						b: String::from("porque el Señor nuestro Dios es el Santo."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
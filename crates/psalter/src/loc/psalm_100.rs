@@ -15,25 +15,30 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Regocíjense en el Señor, pueblos todos; *"),
-						b: String::from("sirvan al Señor con alegría;\nvengan ante su presencia con cánticos.")
+						b: String::from("sirvan al Señor con alegría;\nvengan ante su presencia con cánticos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Sepan que el Señor es Dios; *"),
-						b: String::from("él nos hizo y somos suyos,\nsu pueblo y ovejas de su rebaño.")
+						b: String::from("él nos hizo y somos suyos,\nsu pueblo y ovejas de su rebaño."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Entren por sus puertas con acción de gracias, en sus atrios con alabanza; *"),
-						b: String::from("denle gracias, y bendigan su Nombre;")
+						b: String::from("denle gracias, y bendigan su Nombre;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Porque el Señor es bueno;\npara siempre es su misericordia; *"),
-						b: String::from("su fidelidad perdura de generación en generación.")
+						b: String::from("su fidelidad perdura de generación en generación."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
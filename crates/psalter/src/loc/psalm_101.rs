@@ -15,45 +15,54 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Misericordia y justicia cantaré; *"),
-						b: String::from("a ti cantaré alabanzas, oh Señor.")
+						b: String::from("a ti cantaré alabanzas, oh Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Me esforzaré por seguir un camino intachable; ¿cuándo vendrás a mi? *"),
-						b: String::from("Andaré con sencillez de corazón dentro de mi casa.")
+						b: String::from("Andaré con sencillez de corazón dentro de mi casa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("No pondré delante de mis ojos cosa indigna; *"),
-						b: String::from("aborrezco a los malhechores;\nno se quedarán conmigo.")
+						b: String::from("aborrezco a los malhechores;\nno se quedarán conmigo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Corazón perverso alejaré de mí; *"),
-						b: String::from("no conoceré el mal.")
+						b: String::from("no conoceré el mal."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("A los que en secreto difaman a su prójimo, haré callar; *"),
-						b: String::from("ojos engreídos, corazones arrogantes,\nno los puedo soportar.")
+						b: String::from("ojos engreídos, corazones arrogantes,\nno los puedo soportar."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Mis ojos pondré en los fieles de la tierra, para que vivan conmigo; *"),
-						b: String::from("sólo los que siguen un camino intachable me servirán.")
+						b: String::from("sólo los que siguen un camino intachable me servirán."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("No habitarán en mi casa los que hacen fraudes, *"),
-						b: String::from("y cuantos hablan mentiras no durarán en mi presencia.")
+						b: String::from("y cuantos hablan mentiras no durarán en mi presencia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Pronto destruiré a todos los malvados de la tierra, *"),
-						b: String::from("para extirpar de la ciudad del Señor\na todos los malhechores.")
+						b: String::from("para extirpar de la ciudad del Señor\na todos los malhechores."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,145 +15,174 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Señor, escucha mi oración, y llegue a ti mi clamor; *"),
-						b: String::from("no escondas de mí tu rostro en el día de mi angustia.")
+						b: String::from("no escondas de mí tu rostro en el día de mi angustia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Inclina a mí tu oído; *"),
-						b: String::from("apresúrate a responderme cuando te invoco;")
+						b: String::from("apresúrate a responderme cuando te invoco;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Porque mis días se desvanecen como humo, *"),
-						b: String::from("y mis huesos cual brasas queman.")
+						b: String::from("y mis huesos cual brasas queman."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Mi corazón está marchito como la hierba seca, *"),
-						b: String::from("de modo que me olvido de comer el pan.")
+						b: String::from("de modo que me olvido de comer el pan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Por la fuerza de mi gemido, *"),
-						b: String::from("se me pega la piel a los huesos.")
+						b: String::from("se me pega la piel a los huesos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Estoy como buitre en el desierto, *"),
-						b: String::from("como buho entre las ruinas.")
+						b: String::from("como buho entre las ruinas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Estoy desvelado y gimiendo, *"),
-						b: String::from("como gorrión solitario en el tejado.")
+						b: String::from("como gorrión solitario en el tejado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Mis enemigos me afrentan todo el día, *"),
-						b: String::from("y los que de mí se mofan, contra mí se han conjurado.")
+						b: String::from("y los que de mí se mofan, contra mí se han conjurado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("En vez de pan, he comido ceniza, *"),
-						b: String::from("y mi bebida mezclé con lágrimas,")
+						b: String::from("y mi bebida mezclé con lágrimas,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Pues me alzaste, y me has arrojado, *"),
-						b: String::from("a causa de tu indignación y de tu ira.")
+						b: String::from("a causa de tu indignación y de tu ira."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Mis días son como la sombra que se va, *"),
-						b: String::from("y me marchito como la hierba;")
+						b: String::from("y me marchito como la hierba;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Mas tú, oh Señor, permaneces para siempre, *"),
-						b: String::from("y tu Nombre de generación en generación.")
+						b: String::from("y tu Nombre de generación en generación."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Te levantarás y tendrás misericordia de Sión, pues es tiempo de tener piedad de ella; *"),
-						b: String::from("en verdad, la hora señalada ha llegado;")
+						b: String::from("en verdad, la hora señalada ha llegado;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Porque tus siervos aman aun sus escombros, *"),
-						b: String::from("y se compadecen de su polvo.")
+						b: String::from("y se compadecen de su polvo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Las naciones temerán tu Nombre, oh Señor, *"),
-						b: String::from("y todos los reyes del mundo, tu gloria;")
+						b: String::from("y todos los reyes del mundo, tu gloria;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Porque a Sión reconstruirá el Señor, *"),
-						b: String::from("y su gloria aparecerá.")
+						b: String::from("y su gloria aparecerá."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Mirará con favor a la oración de los desamparados; *"),
-						b: String::from("sus ruegos no despreciará.")
+						b: String::from("sus ruegos no despreciará."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Quede esto escrito para la generación venidera, *"),
-						b: String::from("para que el pueblo aún por nacer alabe al Señor;")
+						b: String::from("para que el pueblo aún por nacer alabe al Señor;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Porque el Señor miró desde su excelso santuario; *"),
-						b: String::from("desde los cielos se ha fijado en la tierra;")
+						b: String::from("desde los cielos se ha fijado en la tierra;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("A fin de oír el gemido de los cautivos, *"),
-						b: String::from("y librar a los condenados a muerte;")
+						b: String::from("y librar a los condenados a muerte;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Para que declaren en Sión el Nombre del Señor, *"),
-						b: String::from("y su alabanza en Jerusalén;")
+						b: String::from("y su alabanza en Jerusalén;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Cuando se congreguen los pueblos, *"),
-						b: String::from("y también los reinos, para servir al Señor.")
+						b: String::from("y también los reinos, para servir al Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("El agotó mis fuerzas antes de tiempo; *"),
-						b: String::from("acortó el número de mis días;")
+						b: String::from("acortó el número de mis días;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Y yo dije: \"Dios mío, no me arrebates a la mitad de mis días, *"),
-						b: String::from("ya que tus años duran por todas las generaciones.")
+						b: String::from("ya que tus años duran por todas las generaciones."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("En el principio tú fundaste la tierra, *"),
-						b: String::from("y los cielos son obra de tus manos.")
+						b: String::from("y los cielos son obra de tus manos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Ellos perecerán, mas tú permanecerás; todos ellos como vestidura se gastarán; *"),
-						b: String::from("como un vestido los mudarás, y serán mudados;")
+						b: String::from("como un vestido los mudarás, y serán mudados;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Mas tú eres siempre el mismo, *"),
-						b: String::from("y tus años nunca se acabarán.")
+						b: String::from("y tus años nunca se acabarán."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("Los hijos de tus siervos habitarán seguros, *"),
-						b: String::from("y su descendencia será establecida en tu presencia\".")
+						b: String::from("y su descendencia será establecida en tu presencia\"."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,115 +15,138 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Bendice, alma mía, al Señor, *"),
-						b: String::from("y todo mi ser bendiga su santo Nombre.")
+						b: String::from("y todo mi ser bendiga su santo Nombre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Bendice, alma mía, al Señor, *"),
-						b: String::from("y no olvides ninguno de sus beneficios.")
+						b: String::from("y no olvides ninguno de sus beneficios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("El perdona todas tus iniquidades, *"),
-						b: String::from("y sana todas tus dolencias.")
+						b: String::from("y sana todas tus dolencias."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("El rescata del sepulcro tu vida, *"),
-						b: String::from("y te corona de favor y misericordia.")
+						b: String::from("y te corona de favor y misericordia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("El sacia de bien tus anhelos, *"),
-						b: String::from("y como el águila se renueva tu juventud.")
+						b: String::from("y como el águila se renueva tu juventud."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("El Señor hace justicia, *"),
-						b: String::from("y defiende a todos los oprimidos.")
+						b: String::from("y defiende a todos los oprimidos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Dio a conocer sus caminos a Moisés, *"),
-						b: String::from("y al pueblo de Israel sus obras.")
+						b: String::from("y al pueblo de Israel sus obras."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Misericordioso y compasivo es el Señor, *"),
-						b: String::from("lento para la ira y rico en clemencia.")
+						b: String::from("lento para la ira y rico en clemencia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("No nos acusará para siempre, *"),
-						b: String::from("ni para siempre guardará su enojo.")
+						b: String::from("ni para siempre guardará su enojo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("No nos ha tratado conforme a nuestros pecados, *"),
-						b: String::from("ni nos ha pagado conforme a nuestras maldades.")
+						b: String::from("ni nos ha pagado conforme a nuestras maldades."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Así como se levantan los cielos sobre la tierra, *"),
-						b: String::from("así se levanta su misericordia sobre sus fieles.")
+						b: String::from("así se levanta su misericordia sobre sus fieles."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Como dista el oriente del occidente, *"),
-						b: String::from("así aleja de nosotros nuestras rebeliones.")
+						b: String::from("así aleja de nosotros nuestras rebeliones."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Como un padre cuida de sus hijos, *"),
-						b: String::from("así cuida el Señor a los que le veneran;")
+						b: String::from("así cuida el Señor a los que le veneran;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Porque él sabe de qué estamos hechos; *"),
-						b: String::from("se acuerda de que no somos más que barro.")
+						b: String::from("se acuerda de que no somos más que barro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Como la hierba son nuestros días; *"),
-						b: String::from("florecemos como la flor del campo,")
+						b: String::from("florecemos como la flor del campo,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Que pasa el viento por ella, y ya no existe, *"),
-						b: String::from("y su lugar no la conocerá más;")
+						b: String::from("y su lugar no la conocerá más;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Empero la misericordia del Señor perdura para siempre sobre los que le veneran, *"),
-						b: String::from("y su rectitud sobre los hijos de los hijos;")
+						b: String::from("y su rectitud sobre los hijos de los hijos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Sobre los que guardan su pacto, *"),
-						b: String::from("y se acuerdan de sus mandatos y los cumplen.")
+						b: String::from("y se acuerdan de sus mandatos y los cumplen."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("El Señor estableció en los cielos su trono, *"),
-						b: String::from("y su soberanía domina sobre todos.")
+						b: String::from("y su soberanía domina sobre todos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Bendigan al Señor, ustedes sus ángeles, potestades que ejecutan sus órdenes, *"),
-						b: String::from("obedeciendo a la voz de su palabra.")
+						b: String::from("obedeciendo a la voz de su palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Bendigan al Señor, ustedes sus huestes, *"),
-						b: String::from("ministros suyos que hacen su voluntad.")
+						b: String::from("ministros suyos que hacen su voluntad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Bendigan al Señor, ustedes sus obras, en todos los lugares de su dominio. *"),
-						b: String::from("Bendice, alma mía, al Señor.")
+						b: String::from("Bendice, alma mía, al Señor."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
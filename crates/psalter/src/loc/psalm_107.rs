@@ -15,157 +15,188 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Den gracias al Señor, porque es bueno, *"),
-						b: String::from("porque para siempre es su misericordia.")
+						b: String::from("porque para siempre es su misericordia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Proclamen los redimidos del Señor *"),
-						b: String::from("que él los redimió de la mano del enemigo.")
+						b: String::from("que él los redimió de la mano del enemigo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("El los recogió de entre todos los países, *"),
-						b: String::from("del oriente y del occidente,\ndel norte y del sur.")
+						b: String::from("del oriente y del occidente,\ndel norte y del sur."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Algunos erraban por el desierto, *"),
-						b: String::from("sin hallar camino a una ciudad en donde vivir.")
+						b: String::from("sin hallar camino a una ciudad en donde vivir."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Hambrientos y sedientos, *"),
-						b: String::from("su alma desfallecía en ellos.")
+						b: String::from("su alma desfallecía en ellos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Entonces clamaron al Señor en su angustia, *"),
-						b: String::from("y los libró de su aflicción.")
+						b: String::from("y los libró de su aflicción."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Los dirigió por senda recta, *"),
-						b: String::from("para que llegasen a una ciudad en donde vivir.")
+						b: String::from("para que llegasen a una ciudad en donde vivir."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Que den gracias al Señor por su misericordia, *"),
-						b: String::from("y las maravillas que hace por sus hijos;")
+						b: String::from("y las maravillas que hace por sus hijos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Porque satisface a los sedientos, *"),
-						b: String::from("y a los hambrientos los colma de bienes.")
+						b: String::from("y a los hambrientos los colma de bienes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Algunos moraban en oscuridad y tinieblas, *"),
-						b: String::from("aprisionados en miseria y en hierros,")
+						b: String::from("aprisionados en miseria y en hierros,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Por haberse rebelado contra los mandatos de Dios, *"),
-						b: String::from("despreciando el consejo del Altísimo.")
+						b: String::from("despreciando el consejo del Altísimo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Por eso, con trabajo arduo humilló sus corazones; *"),
-						b: String::from("tropezaban, y nadie los socorría.")
+						b: String::from("tropezaban, y nadie los socorría."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Entonces clamaron al Señor en su angustia, *"),
-						b: String::from("y los libró de su aflicción.")
+						b: String::from("y los libró de su aflicción."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Los sacó de la oscuridad y de las tinieblas, *"),
-						b: String::from("y rompió sus prisiones.")
+						b: String::from("y rompió sus prisiones."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Que den gracias al Señor por su misericordia, *"),
-						b: String::from("y las maravillas que hace por sus hijos;")
+						b: String::from("y las maravillas que hace por sus hijos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Porque derriba las puertas de bronce, *"),
-						b: String::from("y destroza los cerrojos de hierro.")
+						b: String::from("y destroza los cerrojos de hierro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Algunos fueron insensatos y se rebelaron; *"),
-						b: String::from("por sus iniquidades fueron afligidos.")
+						b: String::from("por sus iniquidades fueron afligidos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Aborrecieron todo alimento, *"),
-						b: String::from("y llegaron hasta las puertas de la muerte.")
+						b: String::from("y llegaron hasta las puertas de la muerte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Entonces clamaron al Señor en su angustia, *"),
-						b: String::from("y los libró de su aflicción.")
+						b: String::from("y los libró de su aflicción."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Envió su palabra y los sanó; *"),
-						b: String::from("los libró del sepulcro.")
+						b: String::from("los libró del sepulcro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Que den gracias al Señor por su misericordia, *"),
-						b: String::from("y las maravillas que hace por sus hijos.")
+						b: String::from("y las maravillas que hace por sus hijos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Que ofrezcan sacrificios de alabanza, *"),
-						b: String::from("y publiquen sus obras con júbilo.")
+						b: String::from("y publiquen sus obras con júbilo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Algunos navegaron por el mar, *"),
-						b: String::from("comerciando por las aguas profundas.")
+						b: String::from("comerciando por las aguas profundas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Contemplaron las obras de Dios, *"),
-						b: String::from("y sus maravillas en el piélago.")
+						b: String::from("y sus maravillas en el piélago."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("Entonces habló, y levantó un viento tempestuoso, *"),
-						b: String::from("que alzaba las olas hasta lo alto.")
+						b: String::from("que alzaba las olas hasta lo alto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Subían al cielo, bajaban al abismo; *"),
-						b: String::from("se derretían sus corazones ante el peligro.")
+						b: String::from("se derretían sus corazones ante el peligro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Titubeaban y se tambaleaban como borrachos, *"),
-						b: String::from("y su pericia de nada les valía.")
+						b: String::from("y su pericia de nada les valía."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("Entonces clamaron al Señor en su angustia, *"),
-						b: String::from("y los libró de su aflicción.")
+						b: String::from("y los libró de su aflicción."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 29,
 						a: String::from("Calmó la tempestad en susurro, *"),
-						b: String::from("y apaciguó las olas del mar.")
+						b: String::from("y apaciguó las olas del mar."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 30,
 						a: String::from("Luego se alegraron a causa de la calma, *"),
-						b: String::from("y los condujo al ansiado puerto.")
+						b: String::from("y los condujo al ansiado puerto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 31,
 						a: String::from("Que den gracias al Señor por su misericordia, *"),
-						b: String::from("y las maravillas que hace por sus hijos.")
+						b: String::from("y las maravillas que hace por sus hijos."),
+						..Default::default()
 					}
 				]
 			},
@@ -180,65 +211,78 @@ lazy_static! {
 					PsalmVerse {
 						number: 32,
 						a: String::from("Que lo exalten en la congregación del pueblo, *"),
-						b: String::from("y en el consejo de los ancianos lo alaben.")
+						b: String::from("y en el consejo de los ancianos lo alaben."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 33,
 						a: String::from("El Señor convirtió los ríos en desierto, *"),
-						b: String::from("y los manantiales de aguas en sequedales,")
+						b: String::from("y los manantiales de aguas en sequedales,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 34,
 						a: String::from("La tierra fértil en marismas, *"),
-						b: String::from("por la maldad de los que la habitan.")
+						b: String::from("por la maldad de los que la habitan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 35,
 						a: String::from("Transformó el desierto en estanques, *"),
-						b: String::from("y la tierra seca en manantiales de aguas.")
+						b: String::from("y la tierra seca en manantiales de aguas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 36,
 						a: String::from("Allí estableció a los hambrientos, *"),
-						b: String::from("y fundaron ciudad en donde vivir.")
+						b: String::from("y fundaron ciudad en donde vivir."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 37,
 						a: String::from("Sembraron campos, y plantaron viñas; *"),
-						b: String::from("recogieron abundantes cosechas.")
+						b: String::from("recogieron abundantes cosechas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 38,
 						a: String::from("Los bendijo, y se multiplicaron en gran manera; *"),
-						b: String::from("y no permitió que sus ganados disminuyesen.")
+						b: String::from("y no permitió que sus ganados disminuyesen."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 39,
 						a: String::from("Sin embargo, cuando fueron menguados y abatidos *"),
-						b: String::from("por el peso de la adversidad y la congoja,")
+						b: String::from("por el peso de la adversidad y la congoja,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 40,
 						a: String::from("(El esparce menosprecio sobre los príncipes, *"),
-						b: String::from("y les hace deambular por yermos, sin senderos)")
+						b: String::from("y les hace deambular por yermos, sin senderos)"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 41,
 						a: String::from("Levantó a los pobres de la miseria, *"),
-						b: String::from("y multiplicó sus familias como rebaños de ovejas.")
+						b: String::from("y multiplicó sus familias como rebaños de ovejas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 42,
 						a: String::from("Los rectos lo verán y se alegrarán, *"),
-						b: String::from("pero todos los malvados cerrarán la boca.")
+						b: String::from("pero todos los malvados cerrarán la boca."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 43,
 						a: String::from("El sabio meditará sobre estas cosas, *"),
-						b: String::from("y considerará bien la misericordia del Señor.")
+						b: String::from("y considerará bien la misericordia del Señor."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
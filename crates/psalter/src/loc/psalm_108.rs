@@ -15,70 +15,84 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Mi corazón está firme, oh Dios, mi corazón está firme; *"),
-						b: String::from("tocaré y cantaré salmos.")
+						b: String::from("tocaré y cantaré salmos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Despierta, oh alma mía; despierten, lira y arpa; *"),
-						b: String::from("yo mismo despertaré al alba.")
+						b: String::from("yo mismo despertaré al alba."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Te confesaré entre los pueblos, oh Señor; *"),
-						b: String::from("cantaré tus alabanzas entre las naciones;")
+						b: String::from("cantaré tus alabanzas entre las naciones;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Porque tu gracia es más grande que los cielos, *"),
-						b: String::from("y tu fidelidad alcanza hasta las nubes.")
+						b: String::from("y tu fidelidad alcanza hasta las nubes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Exáltate sobre los cielos, oh Dios, *"),
-						b: String::from("y tu gloria sobre toda la tierra.")
+						b: String::from("y tu gloria sobre toda la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Para que sean librados tus amados, *"),
-						b: String::from("salva con tu diestra y respóndeme.")
+						b: String::from("salva con tu diestra y respóndeme."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Dios habló desde su santuario, y dijo: *"),
-						b: String::from("\"Yo me alegraré, y repartiré a Siquén,\ndividiré el valle de Sucot.")
+						b: String::from("\"Yo me alegraré, y repartiré a Siquén,\ndividiré el valle de Sucot."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Mío es Galaad, mío Manasés; *"),
-						b: String::from("Efraín es mi yelmo, y Judá mi cetro.")
+						b: String::from("Efraín es mi yelmo, y Judá mi cetro."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Moab es mi jofaina;\nsobre Edom lanzaré mi sandalia; *"),
-						b: String::from("sobre Filistea cantaré victoria\".")
+						b: String::from("sobre Filistea cantaré victoria\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("¿Quién me llevará a la ciudad fortificada? *"),
-						b: String::from("¿Quién me llevará hasta Edom,")
+						b: String::from("¿Quién me llevará hasta Edom,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Si tú, oh Dios, nos has desechado, *"),
-						b: String::from("Si no sales, oh Dios, con nuestros ejercitos?")
+						b: String::from("Si no sales, oh Dios, con nuestros ejercitos?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Danos tu ayuda contra el enemigo, *"),
-						b: String::from("porque vana es la ayuda humana.")
+						b: String::from("porque vana es la ayuda humana."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Con Dios haremos proezas, *"),
-						b: String::from("y él hollará a nuestros enemigos.")
+						b: String::from("y él hollará a nuestros enemigos."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,155 +15,186 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Dios de mi alabanza, no calles; *"),
-						b: String::from("porque la boca del malvado, la boca del engañador,\nse ha abierto contra mí.")
+						b: String::from("porque la boca del malvado, la boca del engañador,\nse ha abierto contra mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Me hablan con lengua mentirosa, *"),
-						b: String::from("me rodean con palabras de odio,\nme combaten sin causa.")
+						b: String::from("me rodean con palabras de odio,\nme combaten sin causa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("A pesar de mi amor, me acusan; *"),
-						b: String::from("en cuanto a mí, yo oro por ellos.")
+						b: String::from("en cuanto a mí, yo oro por ellos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Me devuelven mal por bien, *"),
-						b: String::from("y odio por amor.")
+						b: String::from("y odio por amor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Pon contra él un malvado, *"),
-						b: String::from("y que un acusador esté a su diestra.")
+						b: String::from("y que un acusador esté a su diestra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Cuando fuere juzgado, salga culpable, *"),
-						b: String::from("y sea su apelación rehusada.")
+						b: String::from("y sea su apelación rehusada."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Sean pocos sus días, *"),
-						b: String::from("y tome otro su oficio.")
+						b: String::from("y tome otro su oficio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Sean huérfanos sus hijos, *"),
-						b: String::from("y su mujer viuda.")
+						b: String::from("y su mujer viuda."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Que sus hijos sean abandonados y mendiguen; *"),
-						b: String::from("sean echados de las ruinas de sus hogares.")
+						b: String::from("sean echados de las ruinas de sus hogares."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Que el acreedor se apodere de todo lo que tiene, *"),
-						b: String::from("y extranjeros saqueen sus ganancias.")
+						b: String::from("y extranjeros saqueen sus ganancias."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Que nadie le muestre clemencia, *"),
-						b: String::from("y ninguno se compadezca de sus huérfanos.")
+						b: String::from("y ninguno se compadezca de sus huérfanos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Que su posteridad sea exterminada, *"),
-						b: String::from("y borrado su apellido en la siguiente generación.")
+						b: String::from("y borrado su apellido en la siguiente generación."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Que la maldad de sus padres se recuerde ante el Señor, *"),
-						b: String::from("y el pecado de su madre no sea borrado;")
+						b: String::from("y el pecado de su madre no sea borrado;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Que su pecado esté siempre presente delante del Señor; *"),
-						b: String::from("mas su memoria arranque de la tierra;")
+						b: String::from("mas su memoria arranque de la tierra;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Porque no se acordó de hacer misericordia, *"),
-						b: String::from("sino persiguió al pobre y menesteroso,\ny al atribulado buscó para darle muerte.")
+						b: String::from("sino persiguió al pobre y menesteroso,\ny al atribulado buscó para darle muerte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Amó la maldición: recaiga sobre él; *"),
-						b: String::from("despreció la bendición: que se aparte de él.")
+						b: String::from("despreció la bendición: que se aparte de él."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Se vistió de maldición como de un traje; *"),
-						b: String::from("que le cale como agua hasta las entrañas,\ny como aceite hasta los huesos.")
+						b: String::from("que le cale como agua hasta las entrañas,\ny como aceite hasta los huesos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Séale como el manto con que se envuelve, *"),
-						b: String::from("como el cinturón que lo ciñe siempre.")
+						b: String::from("como el cinturón que lo ciñe siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Sea éste el pago del Señor a los que me acusan, *"),
-						b: String::from("a los que me calumnian.")
+						b: String::from("a los que me calumnian."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Pero tú, oh Señor mi Dios,\nfavoréceme por amor de tu Nombre: *"),
-						b: String::from("líbrame por la ternura de tu bondad;")
+						b: String::from("líbrame por la ternura de tu bondad;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Porque soy pobre y menesteroso, *"),
-						b: String::from("y mi corazón está herido dentro de mí.")
+						b: String::from("y mi corazón está herido dentro de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Desaparezco como la sombra cuando se alarga, *"),
-						b: String::from("me sacuden como a la langosta.")
+						b: String::from("me sacuden como a la langosta."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Mis rodillas están debilitadas por no comer, *"),
-						b: String::from("estoy flaco y descarnado.")
+						b: String::from("estoy flaco y descarnado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("He llegado a ser oprobio para ellos; *"),
-						b: String::from("cuando me ven, menean la cabeza.")
+						b: String::from("cuando me ven, menean la cabeza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("Ayúdame, oh Señor mi Dios; *"),
-						b: String::from("sálvame por tu misericordia.")
+						b: String::from("sálvame por tu misericordia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Reconozcan que ésta es tu mano, *"),
-						b: String::from("que eres tú, oh Señor, quien lo ha hecho.")
+						b: String::from("que eres tú, oh Señor, quien lo ha hecho."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Podrán maldecir, pero tú bendecirás; *"),
-						b: String::from("que sean avergonzados los que se levantan contra mí,\ny se regocijará tu siervo.")
+						b: String::from("que sean avergonzados los que se levantan contra mí,\ny se regocijará tu siervo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("Sean vestidos de infamia los que me acusan, *"),
-						b: String::from("sean envueltos de vergüenza como con un manto.")
+						b: String::from("sean envueltos de vergüenza como con un manto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 29,
 						a: String::from("Yo daré gracias al Señor con voz potente; *"),
-						b: String::from("en medio de la muchedumbre le alabaré;")
+						b: String::from("en medio de la muchedumbre le alabaré;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 30,
 						a: String::from("Porque él se pone a la diestra del pobre, *"),
-						b: String::from("para salvar la vida de los que le condenarían.")
+						b: String::from("para salvar la vida de los que le condenarían."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,55 +15,66 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Aleluya!\nDaré gracias al Señor de todo corazón, *"),
-						b: String::from("en la asamblea de los rectos, en la congregación.")
+						b: String::from("en la asamblea de los rectos, en la congregación."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("¡Grandes son las obras del Señor! *"),
-						b: String::from("Son dignas de estudio para los que las aman.")
+						b: String::from("Son dignas de estudio para los que las aman."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Su obra está llena de esplendor y majestad, *"),
-						b: String::from("y su benevolencia permanece para siempre.")
+						b: String::from("y su benevolencia permanece para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Ha hecho memorables sus maravillas; *"),
-						b: String::from("clemente y compasivo es el Señor.")
+						b: String::from("clemente y compasivo es el Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Da alimento a los que le veneran; *"),
-						b: String::from("para siempre se acuerda de su pacto.")
+						b: String::from("para siempre se acuerda de su pacto."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("El poder de sus obras manifestó a su pueblo, *"),
-						b: String::from("dándoles la heredad de las naciones.")
+						b: String::from("dándoles la heredad de las naciones."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Las obras de sus manos son verdad y juicio; *"),
-						b: String::from("fidedignos son todos sus mandamientos,")
+						b: String::from("fidedignos son todos sus mandamientos,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Afirmados eternamente y para siempre, *"),
-						b: String::from("hechos en verdad y en rectitud.")
+						b: String::from("hechos en verdad y en rectitud."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Redención envió a su pueblo; para siempre ordenó su pacto; *"),
-						b: String::from("santo y temible es su Nombre.")
+						b: String::from("santo y temible es su Nombre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Principio de la sabiduría es el temor del Señor; tienen buen juicio los que lo practican; *"),
-						b: String::from("su loor permanece para siempre.")
+						b: String::from("su loor permanece para siempre."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
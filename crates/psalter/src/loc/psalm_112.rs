@@ -15,55 +15,66 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Aleluya!\n¡Dichosos los que temen a mi Soberano, *"),
-						b: String::from("y de corazón se deleitan en sus mandamientos !")
+						b: String::from("y de corazón se deleitan en sus mandamientos !"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Su descendencia será poderosa en la tierra; la generación de los rectos será bendita."),
-						b: String::from("")
+						b: String::from(""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Bienes y riquezas habrá en su casa, *"),
-						b: String::from("y su benevolencia permanecerá para siempre.")
+						b: String::from("y su benevolencia permanecerá para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("La luz resplandece en las tinieblas para los rectos; *"),
-						b: String::from("los justos son clementes y compasivos.")
+						b: String::from("los justos son clementes y compasivos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Buenos los que son generosos y prestan, *"),
-						b: String::from("y administran sus asuntos con juicio.")
+						b: String::from("y administran sus asuntos con juicio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Por eso jamás tropezarán; *"),
-						b: String::from("en memoria eterna se tendrá a los justos.")
+						b: String::from("en memoria eterna se tendrá a los justos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("No temerán las malas noticias; *"),
-						b: String::from("su corazón está firme, confiado en mi Soberano.")
+						b: String::from("su corazón está firme, confiado en mi Soberano."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Firme está su corazón, y no temerá, *"),
-						b: String::from("hasta ver cumplido en sus enemigos su deseo.")
+						b: String::from("hasta ver cumplido en sus enemigos su deseo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Han repartido liberalmente al pobre,\ny su generosidad permanece para siempre; *"),
-						b: String::from("alzarán la frente con dignidad.")
+						b: String::from("alzarán la frente con dignidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Los malvados, al verlo, se enfurecerán; crujirán los dientes, y se consumirán; *"),
-						b: String::from("el deseo de los malvados fracasará.")
+						b: String::from("el deseo de los malvados fracasará."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,45 +15,54 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Aleluya!\nAlaben las obras del Señor; *"),
-						b: String::from("alaben el Nombre del Señor")
+						b: String::from("alaben el Nombre del Señor"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Sea bendito el Nombre del Señor, *"),
-						b: String::from("desde ahora y para siempre.")
+						b: String::from("desde ahora y para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Desde el nacimiento del sol hasta donde se pone, *"),
-						b: String::from("sea alabado el Nombre del Señor.")
+						b: String::from("sea alabado el Nombre del Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Excelso sobre todas las naciones es el Señor, *"),
-						b: String::from("sobre los cielos su gloria.")
+						b: String::from("sobre los cielos su gloria."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("¿Quién como el Señor nuestro Dios,\nque se sienta entronizado en las alturas, *"),
-						b: String::from("mas se humilla a mirar a los cielos y a la tierra?")
+						b: String::from("mas se humilla a mirar a los cielos y a la tierra?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("El levanta del polvo al desvalido, *"),
-						b: String::from("y al menesteroso alza del muladar,")
+						b: String::from("y al menesteroso alza del muladar,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Para sentarlos con los príncipes, *"),
-						b: String::from("con los príncipes de su pueblo.")
+						b: String::from("con los príncipes de su pueblo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("El hace que la mujer estéril *"),
-						b: String::from("sea madre gozosa de hijos.")
+						b: String::from("sea madre gozosa de hijos."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,45 +15,54 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Aleluya!\nCuando salió Israel de Egipto, *"),
-						b: String::from("la casa de Jacob de entre un pueblo de idio ma ajeno,")
+						b: String::from("la casa de Jacob de entre un pueblo de idio ma ajeno,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Judá vino a ser el santuario de Dios, *"),
-						b: String::from("e Israel su dominio.")
+						b: String::from("e Israel su dominio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Elmarlovio,yhuyó:*"),
-						b: String::from("el Jordán se volvió atrás.")
+						b: String::from("el Jordán se volvió atrás."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Los montes saltaron como carneros, *"),
-						b: String::from("y como corderos las colinas.")
+						b: String::from("y como corderos las colinas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("¿Qué te afligió, oh mar, que huiste, *"),
-						b: String::from("y a ti, oh Jordán, que te volviste atrás?")
+						b: String::from("y a ti, oh Jordán, que te volviste atrás?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Oh montes, ¿por qué saltaron como carneros, *"),
-						b: String::from("y como corderos, oh colinas?")
+						b: String::from("y como corderos, oh colinas?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Tiembla, oh tierra, a la presencia de mi Soberano, *"),
-						b: String::from("a la presencia del Dios de Jacob,")
+						b: String::from("a la presencia del Dios de Jacob,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Quien cambió la peña en estanque de aguas, *"),
-						b: String::from("y el pedernal en manantiales.")
+						b: String::from("y el pedernal en manantiales."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,95 +15,114 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("No a nosotros, oh Señor, no a nosotros, sino a tu Nombre da gloria, *"),
-						b: String::from("a causa de tu bondad, de tu fidelidad.")
+						b: String::from("a causa de tu bondad, de tu fidelidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("¿Por qué han de decir los paganos: *"),
-						b: String::from("\"Dónde está ahora su Dios?\"")
+						b: String::from("\"Dónde está ahora su Dios?\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Nuestro Dios está en los cielos; *"),
-						b: String::from("lo que quiere, lo hace.")
+						b: String::from("lo que quiere, lo hace."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Los ídolos de ellos son plata y oro, *"),
-						b: String::from("hechura de manos humanas.")
+						b: String::from("hechura de manos humanas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Boca tienen, mas no hablan; *"),
-						b: String::from("ojos tienen, mas no ven;")
+						b: String::from("ojos tienen, mas no ven;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Orejas tienen, mas no oyen; *"),
-						b: String::from("narices tienen, mas no huelen;")
+						b: String::from("narices tienen, mas no huelen;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Manos tienen, mas no palpan; pies tienen, mas no andan; *"),
-						b: String::from("no tiene voz su garganta.")
+						b: String::from("no tiene voz su garganta."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Semejantes a ellos son los que los hacen, *"),
-						b: String::from("y cualquiera que confía en ellos.")
+						b: String::from("y cualquiera que confía en ellos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Oh Israel, confía en el Señor; *"),
-						b: String::from("él es su ayuda y su escudo.")
+						b: String::from("él es su ayuda y su escudo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Oh casa de Aarón, confía en el Señor; *"),
-						b: String::from("él es su ayuda y su escudo.")
+						b: String::from("él es su ayuda y su escudo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Los que temen al Señor, confíen en el Señor; *"),
-						b: String::from("él es su ayuda y su escudo.")
+						b: String::from("él es su ayuda y su escudo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("El Señor se acordó de nosotros, y nos bendecirá; *"),
-						b: String::from("bendecirá a la casa de Israel;\nbendecirá a la casa de Aarón.")
+						b: String::from("bendecirá a la casa de Israel;\nbendecirá a la casa de Aarón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Bendecirá a los que temen al Señor, *"),
-						b: String::from("tanto a pequeños como a grandes.")
+						b: String::from("tanto a pequeños como a grandes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Que el Señor les aumente más y más, *"),
-						b: String::from("a ustedes y a su descendencia.")
+						b: String::from("a ustedes y a su descendencia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Sean bendecidos por el Señor, *"),
-						b: String::from("que hizo los cielos y la tierra.")
+						b: String::from("que hizo los cielos y la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Los cielos de los cielos son del Señor, *"),
-						b: String::from("mas la tierra se la ha dado a sus pueblos.")
+						b: String::from("mas la tierra se la ha dado a sus pueblos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("No alaban los muertos al Señor, *"),
-						b: String::from("ni cuantos descienden al silencio;")
+						b: String::from("ni cuantos descienden al silencio;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Pero nosotros bendeciremos al Señor, *"),
-						b: String::from("desde ahora y para siempre.\n¡Aleluya!")
+						b: String::from("desde ahora y para siempre.\n¡Aleluya!"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
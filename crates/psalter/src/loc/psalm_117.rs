@@ -15,15 +15,18 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Alaben al Señor, naciones todas; *"),
-						b: String::from("pueblos todos, aclámenlo;")
+						b: String::from("pueblos todos, aclámenlo;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Porque grande es su misericordia para con nosotros, *"),
-						b: String::from("y la fidelidad del Señor es para siempre.\n¡Aleluya!")
+						b: String::from("y la fidelidad del Señor es para siempre.\n¡Aleluya!"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
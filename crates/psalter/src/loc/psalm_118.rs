@@ -15,150 +15,180 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Den gracias al Señor, porque él es bueno; *"),
-						b: String::from("para siempre es su misericordia.")
+						b: String::from("para siempre es su misericordia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Diga ahora Israel: *"),
-						b: String::from("\"Para siempre es su misericordia\".")
+						b: String::from("\"Para siempre es su misericordia\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Diga ahora la casa de Aarón: *"),
-						b: String::from("\"Para siempre es su misericordia\".")
+						b: String::from("\"Para siempre es su misericordia\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Digan ahora los que veneran al Señor: *"),
-						b: String::from("\"Para siempre es su misericordia\".")
+						b: String::from("\"Para siempre es su misericordia\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("En mi angustia invoqué al Señor; *"),
-						b: String::from("me respondió el Señor, poniéndome a salvo.")
+						b: String::from("me respondió el Señor, poniéndome a salvo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("El Señor está a mi lado; por tanto, no temeré; *"),
-						b: String::from("¿quién podrá dañarme?")
+						b: String::from("¿quién podrá dañarme?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("El Señor está a mi lado para ayudarme; *"),
-						b: String::from("triunfaré sobre los que me odian.")
+						b: String::from("triunfaré sobre los que me odian."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Mejor es refugiarse en el Señor, *"),
-						b: String::from("que fiarse de los mortales.")
+						b: String::from("que fiarse de los mortales."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Mejor es refugiarse en el Señor, *"),
-						b: String::from("que fiarse de los príncipes.")
+						b: String::from("que fiarse de los príncipes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Todos los impíos me rodean; *"),
-						b: String::from("en el nombre del Señor les rechazaré.")
+						b: String::from("en el nombre del Señor les rechazaré."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Me rodean por todas partes; *"),
-						b: String::from("en el nombre del Señor les rechazaré.")
+						b: String::from("en el nombre del Señor les rechazaré."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Me rodean como enjambre de abejas; arden como fuego de espinas; *"),
-						b: String::from("en el nombre del Señor les rechazaré.")
+						b: String::from("en el nombre del Señor les rechazaré."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Me empujaban con violencia para que cayese, *"),
-						b: String::from("pero el Señor me ayudó.")
+						b: String::from("pero el Señor me ayudó."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Mi fuerza y mi refugio es el Señor, *"),
-						b: String::from("y él me ha sido por salvación.")
+						b: String::from("y él me ha sido por salvación."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Hay voz de júbilo y victoria *"),
-						b: String::from("en las tiendas de los justos:")
+						b: String::from("en las tiendas de los justos:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("\"¡La diestra del Señor ha triunfado! *"),
-						b: String::from("¡La diestra del Señor es excelsa!\n¡La diestra del Señor ha triunfado!\"")
+						b: String::from("¡La diestra del Señor es excelsa!\n¡La diestra del Señor ha triunfado!\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("No he de morir, sino que viviré. *"),
-						b: String::from("y contaré las hazañas del Señor.")
+						b: String::from("y contaré las hazañas del Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Me castigó gravemente el Señor, *"),
-						b: String::from("mas no me entregó a la muerte.")
+						b: String::from("mas no me entregó a la muerte."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Abranme las puertas de justicia; *"),
-						b: String::from("entraré por ellas, y daré gracias al Señor.")
+						b: String::from("entraré por ellas, y daré gracias al Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("\"Esta es la puerta del Señor; *"),
-						b: String::from("por ella entrarán los justos\".")
+						b: String::from("por ella entrarán los justos\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Daré gracias porque me respondiste, *"),
-						b: String::from("y me has sido de salvación.")
+						b: String::from("y me has sido de salvación."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("La misma piedra que desecharon los edificadores, *"),
-						b: String::from("ha venido a ser la cabeza del ángulo.")
+						b: String::from("ha venido a ser la cabeza del ángulo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Esto es lo que ha hecho el Señor, *"),
-						b: String::from("y es maravilloso a nuestros ojos.")
+						b: String::from("y es maravilloso a nuestros ojos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Este es el día en que actuó el Señor; *"),
-						b: String::from("regocijémonos y alegrémonos en él.")
+						b: String::from("regocijémonos y alegrémonos en él."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("¡Hosanna, oh Señor, hosanna! *"),
-						b: String::from("Señor, danos ahora la prosperidad.")
+						b: String::from("Señor, danos ahora la prosperidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Bendito el que viene en nombre del Señor; *"),
-						b: String::from("desde la casa del Señor le bendecimos.")
+						b: String::from("desde la casa del Señor le bendecimos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Dios es el Señor; nos ha iluminado; *"),
-						b: String::from("formen una procesión con ramos\nhasta los cuernos del altar.")
+						b: String::from("formen una procesión con ramos\nhasta los cuernos del altar."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("\"Tú eres mi Dios; te daré gracias; *"),
-						b: String::from("tú eres mi Dios; te ensalzaré\".")
+						b: String::from("tú eres mi Dios; te ensalzaré\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 29,
 						a: String::from("Den gracias al Señor porque es bueno; *"),
-						b: String::from("para siempre es su misericordia.")
+						b: String::from("para siempre es su misericordia."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,37 +15,44 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Dichosos los de camino intachable, *"),
-						b: String::from("los que andan en la ley del Señor!")
+						b: String::from("los que andan en la ley del Señor!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("¡Dichosos los que guardan sus decretos, *"),
-						b: String::from("y de todo corazón le buscan!")
+						b: String::from("y de todo corazón le buscan!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Los que nunca cometen iniquidad, *"),
-						b: String::from("mas siempre andan en sus caminos.")
+						b: String::from("mas siempre andan en sus caminos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Tú promulgaste tus decretos, *"),
-						b: String::from("para que los observemos plenamente.")
+						b: String::from("para que los observemos plenamente."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("¡Ojalá fuesen ordenados mis caminos *"),
-						b: String::from("para que guardase tus estatutos!")
+						b: String::from("para que guardase tus estatutos!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Entonces no sería yo avergonzado, *"),
-						b: String::from("cuando atendiese a todos tus mandamientos.")
+						b: String::from("cuando atendiese a todos tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Te daré gracias con sincero corazón, *"),
-						b: String::from("cuando haya aprendido tus justos juicios.")
+						b: String::from("cuando haya aprendido tus justos juicios."),
+						..Default::default()
 					}
 				]
 			},
@@ -60,42 +67,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 8,
 						a: String::from("Tus estatutos guardaré; *"),
-						b: String::from("no me abandones enteramente.")
+						b: String::from("no me abandones enteramente."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("¿Cómo limpiará el joven su camino? *"),
-						b: String::from("Guardando tu palabra.")
+						b: String::from("Guardando tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Con todo el corazón te busco; *"),
-						b: String::from("no dejes que me desvíe de tus mandamientos.")
+						b: String::from("no dejes que me desvíe de tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("En mi corazón atesoro tu promesa, *"),
-						b: String::from("a fin de no pecar contra ti.")
+						b: String::from("a fin de no pecar contra ti."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Bendito eres tú, oh Señor; *"),
-						b: String::from("enséñame tus estatutos.")
+						b: String::from("enséñame tus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Con mis labios contaré *"),
-						b: String::from("todos los juicios de tu boca.")
+						b: String::from("todos los juicios de tu boca."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Me he gozado más en el camino de tus decretos, *"),
-						b: String::from("que en toda riqueza.")
+						b: String::from("que en toda riqueza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("En tus mandamientos meditaré; *"),
-						b: String::from("me fijaré en tus caminos.")
+						b: String::from("me fijaré en tus caminos."),
+						..Default::default()
 					}
 				]
 			},
@@ -110,42 +125,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 16,
 						a: String::from("Me regocijo en tus estatutos; *"),
-						b: String::from("no me olvidaré de tus palabras.")
+						b: String::from("no me olvidaré de tus palabras."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Haz bien a este tu siervo, *"),
-						b: String::from("para que viva y guarde tu palabra.")
+						b: String::from("para que viva y guarde tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Abreme los ojos, *"),
-						b: String::from("para que mire las maravillas de tu ley.")
+						b: String::from("para que mire las maravillas de tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("Forastero soy aquí en la tierra; *"),
-						b: String::from("no encubras de mí tus mandamientos.")
+						b: String::from("no encubras de mí tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("Mi alma se consume continuamente, *"),
-						b: String::from("de tanto anhelar tus juicios.")
+						b: String::from("de tanto anhelar tus juicios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Reprendiste a los soberbios. *"),
-						b: String::from("¡Malditos los que se desvían de tus mandamientos!")
+						b: String::from("¡Malditos los que se desvían de tus mandamientos!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("Aparta de mí la vergüenza y la afrenta, *"),
-						b: String::from("porque tus decretos he observado.")
+						b: String::from("porque tus decretos he observado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Aunque los príncipes se sienten y conspiren contra mí, *"),
-						b: String::from("meditaré en tus estatutos;")
+						b: String::from("meditaré en tus estatutos;"),
+						..Default::default()
 					}
 				]
 			},
@@ -160,42 +183,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 24,
 						a: String::from("Pues tus juicios son mi delicia, *"),
-						b: String::from("y tus decretos mis consejeros.")
+						b: String::from("y tus decretos mis consejeros."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("Mi alma está pegada al polvo; *"),
-						b: String::from("vivifícame conforme a tu palabra.")
+						b: String::from("vivifícame conforme a tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Te he confesado mis caminos, y me has respondido; *"),
-						b: String::from("enséñame tus estatutos.")
+						b: String::from("enséñame tus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 27,
 						a: String::from("Hazme entender el camino de tus mandamientos, *"),
-						b: String::from("para que medite en tus maravillas.")
+						b: String::from("para que medite en tus maravillas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 28,
 						a: String::from("Se derrite mi alma de tristeza; *"),
-						b: String::from("fortaléceme conforme a tu palabra.")
+						b: String::from("fortaléceme conforme a tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 29,
 						a: String::from("Aparta de mí el camino de la mentira; *"),
-						b: String::from("que reciba yo gracia por tu ley.")
+						b: String::from("que reciba yo gracia por tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 30,
 						a: String::from("He escogido el camino de la fidelidad; *"),
-						b: String::from("he puesto tus juicios delante de mí.")
+						b: String::from("he puesto tus juicios delante de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 31,
 						a: String::from("Me he apegado a tus decretos; *"),
-						b: String::from("oh Señor, no me avergüences.")
+						b: String::from("oh Señor, no me avergüences."),
+						..Default::default()
 					}
 				]
 			},
@@ -210,42 +241,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 32,
 						a: String::from("Por el camino de tus mandamientos correré, *"),
-						b: String::from("porque me has ensanchado el corazón.")
+						b: String::from("porque me has ensanchado el corazón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 33,
 						a: String::from("Enséñame, oh Señor, el camino de tus estatutos, *"),
-						b: String::from("y lo guardaré hasta el fin.")
+						b: String::from("y lo guardaré hasta el fin."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 34,
 						a: String::from("Dame entendimiento, y guardaré tu ley; *"),
-						b: String::from("la cumpliré de todo corazón.")
+						b: String::from("la cumpliré de todo corazón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 35,
 						a: String::from("Guíame por la senda de tus mandamientos, *"),
-						b: String::from("porque ése es mi deseo.")
+						b: String::from("porque ése es mi deseo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 36,
 						a: String::from("Inclina mi corazón a tus decretos, *"),
-						b: String::from("y no a las ganancias injustas.")
+						b: String::from("y no a las ganancias injustas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 37,
 						a: String::from("Aparta mis ojos, que no miren lo que es inútil; *"),
-						b: String::from("vivifícame en tus caminos.")
+						b: String::from("vivifícame en tus caminos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 38,
 						a: String::from("Cumple tu promesa a tu siervo, *"),
-						b: String::from("la que haces a los que te temen.")
+						b: String::from("la que haces a los que te temen."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 39,
 						a: String::from("Quita de mí el oprobio que temo, *"),
-						b: String::from("porque buenos son tus juicios.")
+						b: String::from("porque buenos son tus juicios."),
+						..Default::default()
 					}
 				]
 			},
@@ -260,42 +299,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 40,
 						a: String::from("He aquí, anhelo tus mandamientos; *"),
-						b: String::from("en tu justicia, preserva mi vida.")
+						b: String::from("en tu justicia, preserva mi vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 41,
 						a: String::from("Venga a mí tu bondad, oh Señor, *"),
-						b: String::from("tu salvación, conforme a tu promesa.")
+						b: String::from("tu salvación, conforme a tu promesa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 42,
 						a: String::from("Entonces daré respuesta a los que me mofan, *"),
-						b: String::from("porque confío en tus palabras.")
+						b: String::from("porque confío en tus palabras."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 43,
 						a: String::from("No quites de mi boca la palabra de verdad, *"),
-						b: String::from("porque en tus mandamientos está mi esperanza.")
+						b: String::from("porque en tus mandamientos está mi esperanza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 44,
 						a: String::from("Guardaré tu ley continuamente, *"),
-						b: String::from("para siempre y hasta la eternidad.")
+						b: String::from("para siempre y hasta la eternidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 45,
 						a: String::from("Andaré en libertad, *"),
-						b: String::from("porque estudio tus mandamientos.")
+						b: String::from("porque estudio tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 46,
 						a: String::from("Hablaré de tus decretos ante los reyes, *"),
-						b: String::from("y no me avergonzaré.")
+						b: String::from("y no me avergonzaré."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 47,
 						a: String::from("Me gozo en tus mandamientos, *"),
-						b: String::from("los cuales he amado siempre.")
+						b: String::from("los cuales he amado siempre."),
+						..Default::default()
 					}
 				]
 			},
@@ -310,42 +357,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 48,
 						a: String::from("Alzaré mis manos a tus mandamientos, *"),
-						b: String::from("y meditaré en tus estatutos.")
+						b: String::from("y meditaré en tus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 49,
 						a: String::from("Acuérdate de tu palabra a tu siervo, *"),
-						b: String::from("porque tú me has dado esperanza.")
+						b: String::from("porque tú me has dado esperanza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 50,
 						a: String::from("Esto es mi consuelo en la aflicción, *"),
-						b: String::from("que tu promesa me da vida.")
+						b: String::from("que tu promesa me da vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 51,
 						a: String::from("Los soberbios se han burlado cruelmente de mí, *"),
-						b: String::from("mas no me he desviado de tu ley.")
+						b: String::from("mas no me he desviado de tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 52,
 						a: String::from("Cuando me acuerdo de tus juicios antiguos, *"),
-						b: String::from("oh Señor, me consuelo en gran manera.")
+						b: String::from("oh Señor, me consuelo en gran manera."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 53,
 						a: String::from("Siento gran indignación *"),
-						b: String::from("ante los malvados que abandonan tu ley.")
+						b: String::from("ante los malvados que abandonan tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 54,
 						a: String::from("Como cánticos han sido para mí tus estatutos, *"),
-						b: String::from("dondequiera que he morado como forastero.")
+						b: String::from("dondequiera que he morado como forastero."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 55,
 						a: String::from("Me acuerdo de tu Nombre en la noche, oh Señor, *"),
-						b: String::from("y medito en tu ley.")
+						b: String::from("y medito en tu ley."),
+						..Default::default()
 					}
 				]
 			},
@@ -360,42 +415,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 56,
 						a: String::from("Estoesloqueamímetoca,*"),
-						b: String::from("porque he guardado tus mandamientos.")
+						b: String::from("porque he guardado tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 57,
 						a: String::from("Sólo tú, oh Señor, eres mi porción; *"),
-						b: String::from("he prometido guardar tus palabras.")
+						b: String::from("he prometido guardar tus palabras."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 58,
 						a: String::from("De todo corazón suplico tu favor; *"),
-						b: String::from("ten misericordia de mí conforme a tu promesa.")
+						b: String::from("ten misericordia de mí conforme a tu promesa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 59,
 						a: String::from("He considerado mis caminos, *"),
-						b: String::from("y he vuelto mis pies a tus decretos.")
+						b: String::from("y he vuelto mis pies a tus decretos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 60,
 						a: String::from("Me apresuro, y no me retardo *"),
-						b: String::from("en guardar tus mandamientos.")
+						b: String::from("en guardar tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 61,
 						a: String::from("Aunque los lazos de los malvados me envuelvan, *"),
-						b: String::from("no me olvido de tu ley.")
+						b: String::from("no me olvido de tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 62,
 						a: String::from("A medianoche me levantaré para darte gracias *"),
-						b: String::from("por tus justos juicios.")
+						b: String::from("por tus justos juicios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 63,
 						a: String::from("Compañero soy de todos los que te temen, *"),
-						b: String::from("de cuantos guardan tus mandamientos.")
+						b: String::from("de cuantos guardan tus mandamientos."),
+						..Default::default()
 					}
 				]
 			},
@@ -410,42 +473,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 64,
 						a: String::from("De tu amor, oh Señor, está llena la tierra; *"),
-						b: String::from("enséñame tus estatutos.")
+						b: String::from("enséñame tus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 65,
 						a: String::from("Has hecho bien a tu siervo, oh Señor, *"),
-						b: String::from("conforme a tu palabra.")
+						b: String::from("conforme a tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 66,
 						a: String::from("Enséñame criterio y conocimiento, *"),
-						b: String::from("porque tus mandamientos he creído.")
+						b: String::from("porque tus mandamientos he creído."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 67,
 						a: String::from("Antes que fuera afligido, descarriado andaba, *"),
-						b: String::from("mas ahora guardo tu palabra.")
+						b: String::from("mas ahora guardo tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 68,
 						a: String::from("Bueno eres tú, y bienhechor; *"),
-						b: String::from("enséñame tus estatutos.")
+						b: String::from("enséñame tus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 69,
 						a: String::from("Los insolentes urden engaño contra mi, *"),
-						b: String::from("Mas yo guardaré de todo corazón tus mandamientos.")
+						b: String::from("Mas yo guardaré de todo corazón tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 70,
 						a: String::from("Su corazón se espesa como el sebo, *"),
-						b: String::from("mas yo en tu ley me regocijo.")
+						b: String::from("mas yo en tu ley me regocijo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 71,
 						a: String::from("Bueno me es haber sido afligido, *"),
-						b: String::from("para que aprenda tus estatutos.")
+						b: String::from("para que aprenda tus estatutos."),
+						..Default::default()
 					}
 				]
 			},
@@ -460,42 +531,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 72,
 						a: String::from("Más estimo yo la ley de tu boca *"),
-						b: String::from("que millares en oro y plata.")
+						b: String::from("que millares en oro y plata."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 73,
 						a: String::from("Tus manos me hicieron y me formaron; *"),
-						b: String::from("dame entendimiento para que aprenda tus mandamientos.")
+						b: String::from("dame entendimiento para que aprenda tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 74,
 						a: String::from("Los que te temen se alegrarán al verme, *"),
-						b: String::from("porque en tu palabra confío.")
+						b: String::from("porque en tu palabra confío."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 75,
 						a: String::from("Yo sé, oh Señor, que tus juicios son justos, *"),
-						b: String::from("y que conforme a tu fidelidad me afligiste.")
+						b: String::from("y que conforme a tu fidelidad me afligiste."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 76,
 						a: String::from("Sea tu bondad mi consuelo, *"),
-						b: String::from("según la promesa hecha a tu siervo.")
+						b: String::from("según la promesa hecha a tu siervo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 77,
 						a: String::from("Venga a mí tu compasión, para que viva, *"),
-						b: String::from("porque tu ley es mi delicia.")
+						b: String::from("porque tu ley es mi delicia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 78,
 						a: String::from("Que se avergüencen los arrogantes,\nporque me han calumniado; *"),
-						b: String::from("pero yo meditaré en tus mandamientos.")
+						b: String::from("pero yo meditaré en tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 79,
 						a: String::from("Vuélvanse a mí los que te temen, *"),
-						b: String::from("y cuantos conocen tus decretos.")
+						b: String::from("y cuantos conocen tus decretos."),
+						..Default::default()
 					}
 				]
 			},
@@ -510,42 +589,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 80,
 						a: String::from("Sea mi corazón íntegro en tus estatutos, *"),
-						b: String::from("para que no sea yo avergonzado.")
+						b: String::from("para que no sea yo avergonzado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 81,
 						a: String::from("Ansía mi alma tu salvación; *"),
-						b: String::from("he puesto mi esperanza en tu palabra.")
+						b: String::from("he puesto mi esperanza en tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 82,
 						a: String::from("Desfallecieron mis ojos, aguardando tu promesa, *"),
-						b: String::from("y digo: \"¿Cuándo me consolarás?\"")
+						b: String::from("y digo: \"¿Cuándo me consolarás?\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 83,
 						a: String::from("Soy como el odre al humo, *"),
-						b: String::from("pero no he olvidado tus estatutos.")
+						b: String::from("pero no he olvidado tus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 84,
 						a: String::from("¿Hasta cúando deberé aguardar? *"),
-						b: String::from("¿Cuándo harás juicio contra los que me persiguen?")
+						b: String::from("¿Cuándo harás juicio contra los que me persiguen?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 85,
 						a: String::from("Los soberbios han cavado hoyos para mí; *"),
-						b: String::from("ellos no guardan tu ley.")
+						b: String::from("ellos no guardan tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 86,
 						a: String::from("Todos tus mandamientos son ciertos; *"),
-						b: String::from("ayúdame, pues sin causa me persiguen.")
+						b: String::from("ayúdame, pues sin causa me persiguen."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 87,
 						a: String::from("Casi me han acabado de la tierra, *"),
-						b: String::from("pero no he abandonado tus mandamientos.")
+						b: String::from("pero no he abandonado tus mandamientos."),
+						..Default::default()
 					}
 				]
 			},
@@ -560,42 +647,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 88,
 						a: String::from("Vivifícame conforme a tu bondad, *"),
-						b: String::from("para que guarde los decretos de tu boca.")
+						b: String::from("para que guarde los decretos de tu boca."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 89,
 						a: String::from("Tu palabra, oh Señor, es eterna, *"),
-						b: String::from("establecida es en los cielos.")
+						b: String::from("establecida es en los cielos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 90,
 						a: String::from("Tu fidelidad perdura de generación en generación; *"),
-						b: String::from("tú afirmaste la tierra, y permanece.")
+						b: String::from("tú afirmaste la tierra, y permanece."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 91,
 						a: String::from("Por tu decreto permanecen hasta hoy, *"),
-						b: String::from("porque todo está a tu servicio.")
+						b: String::from("porque todo está a tu servicio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 92,
 						a: String::from("Si tu ley no hubiese sido mi delicia, *"),
-						b: String::from("en mi aflicción hubiera perecido.")
+						b: String::from("en mi aflicción hubiera perecido."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 93,
 						a: String::from("Jamás me olvidaré de tus mandamientos, *"),
-						b: String::from("pues por ellos me das vida.")
+						b: String::from("pues por ellos me das vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 94,
 						a: String::from("Tuyo soy; ¡ojalá me salvaras! *"),
-						b: String::from("porque estudio tus mandamientos.")
+						b: String::from("porque estudio tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 95,
 						a: String::from("Aunque los malvados me asechen para destruirme, *"),
-						b: String::from("yo consideraré tus decretos.")
+						b: String::from("yo consideraré tus decretos."),
+						..Default::default()
 					}
 				]
 			},
@@ -610,42 +705,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 96,
 						a: String::from("He visto que todas las cosas tienen fin, *"),
-						b: String::from("pero tus mandamientos son infinitos.")
+						b: String::from("pero tus mandamientos son infinitos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 97,
 						a: String::from("¡Oh, cuánto amo tu ley! *"),
-						b: String::from("Todo el día la estoy meditando.")
+						b: String::from("Todo el día la estoy meditando."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 98,
 						a: String::from("Tus mandamientos me han hecho más sabio que mis enemigos, *"),
-						b: String::from("y siempre están conmigo.")
+						b: String::from("y siempre están conmigo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 99,
 						a: String::from("Soy más docto que todos mis maestros, *"),
-						b: String::from("porque estudio tus decretos.")
+						b: String::from("porque estudio tus decretos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 100,
 						a: String::from("Soy más sabio que los ancianos, *"),
-						b: String::from("porque observo tus mandamientos.")
+						b: String::from("porque observo tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 101,
 						a: String::from("De todo mal camino contengo mis pies, *"),
-						b: String::from("para guardar tu palabra.")
+						b: String::from("para guardar tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 102,
 						a: String::from("No me aparto de tus juicios, *"),
-						b: String::from("porque tú mismo me has enseñado.")
+						b: String::from("porque tú mismo me has enseñado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 103,
 						a: String::from("¡Cuán dulces son a mi paladar tus palabras, *"),
-						b: String::from("más que la miel a mi boca!")
+						b: String::from("más que la miel a mi boca!"),
+						..Default::default()
 					}
 				]
 			},
@@ -660,42 +763,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 104,
 						a: String::from("De tus mandamientos adquiero comprensión; *"),
-						b: String::from("por tanto, aborrezco el camino de la mentira.")
+						b: String::from("por tanto, aborrezco el camino de la mentira."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 105,
 						a: String::from("Lámpara es a mis pies tu palabra, *"),
-						b: String::from("y lumbrera en mi camino.")
+						b: String::from("y lumbrera en mi camino."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 106,
 						a: String::from("He jurado y estoy resuelto *"),
-						b: String::from("a guardar tus justos juicios.")
+						b: String::from("a guardar tus justos juicios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 107,
 						a: String::from("Afligido estoy en gran manera; *"),
-						b: String::from("vivifícame, oh Señor, conforme a tu palabra.")
+						b: String::from("vivifícame, oh Señor, conforme a tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 108,
 						a: String::from("Acepta, oh Señor, la ofrenda voluntaria de mis labios, *"),
-						b: String::from("y enséñame tus juicios.")
+						b: String::from("y enséñame tus juicios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 109,
 						a: String::from("Mi vida está siempre en peligro; *"),
-						b: String::from("por tanto, no olvido tu ley.")
+						b: String::from("por tanto, no olvido tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 110,
 						a: String::from("Me tendieron lazo los malvados, *"),
-						b: String::from("pero yo no me desvié de tus mandamientos.")
+						b: String::from("pero yo no me desvié de tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 111,
 						a: String::from("Son tus decretos mi herencia eterna; *"),
-						b: String::from("en verdad, el gozo de mi corazón.")
+						b: String::from("en verdad, el gozo de mi corazón."),
+						..Default::default()
 					}
 				]
 			},
@@ -710,42 +821,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 112,
 						a: String::from("Mi corazón incliné a cumplir tus estatutos, *"),
-						b: String::from("eternamente y hasta el fin.")
+						b: String::from("eternamente y hasta el fin."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 113,
 						a: String::from("Odio a los inconstantes; *"),
-						b: String::from("por mi parte, yo amo tu ley.")
+						b: String::from("por mi parte, yo amo tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 114,
 						a: String::from("Mi refugio y mi escudo eres tú; *"),
-						b: String::from("en tu palabra yo espero.")
+						b: String::from("en tu palabra yo espero."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 115,
 						a: String::from("¡Apártense de mí, malvados! *"),
-						b: String::from("Guardaré los mandamientos de mi Dios.")
+						b: String::from("Guardaré los mandamientos de mi Dios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 116,
 						a: String::from("Susténtame conforme a tu promesa, y viviré; *"),
-						b: String::from("no quede frustrada mi esperanza.")
+						b: String::from("no quede frustrada mi esperanza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 117,
 						a: String::from("Sosténme, y seré salvo, *"),
-						b: String::from("y me deleitaré siempre en tus estatutos.")
+						b: String::from("y me deleitaré siempre en tus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 118,
 						a: String::from("Rechazas a todos los que se desvían de tus estatutos; *"),
-						b: String::from("su doblez no les sirve para nada.")
+						b: String::from("su doblez no les sirve para nada."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 119,
 						a: String::from("Tienes por escoria a todos los malvados; *"),
-						b: String::from("por tanto, yo amo tus decretos.")
+						b: String::from("por tanto, yo amo tus decretos."),
+						..Default::default()
 					}
 				]
 			},
@@ -760,42 +879,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 120,
 						a: String::from("Mi carne se estremece por temor a ti; *"),
-						b: String::from("de tus juicios tengo miedo.")
+						b: String::from("de tus juicios tengo miedo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 121,
 						a: String::from("Lo que es justo y recto he hecho; *"),
-						b: String::from("no me entregues a mis opresores.")
+						b: String::from("no me entregues a mis opresores."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 122,
 						a: String::from("Afianza a tu siervo para bien; *"),
-						b: String::from("no permitas que los soberbios me opriman.")
+						b: String::from("no permitas que los soberbios me opriman."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 123,
 						a: String::from("Mis ojos han desfallecido, aguardando tu salvación *"),
-						b: String::from("y tu promesa de justicia.")
+						b: String::from("y tu promesa de justicia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 124,
 						a: String::from("Haz con tu siervo según tu misericordia, *"),
-						b: String::from("y enséñame tus estatutos.")
+						b: String::from("y enséñame tus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 125,
 						a: String::from("Tu siervo soy; dame entendimiento *"),
-						b: String::from("para conocer tus decretos.")
+						b: String::from("para conocer tus decretos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 126,
 						a: String::from("Es hora de que actúes, oh Señor, *"),
-						b: String::from("porque han quebrantado tu ley.")
+						b: String::from("porque han quebrantado tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 127,
 						a: String::from("En verdad, yo amo tus mandamientos, *"),
-						b: String::from("más que el oro, más que las piedras preciosas.")
+						b: String::from("más que el oro, más que las piedras preciosas."),
+						..Default::default()
 					}
 				]
 			},
@@ -810,42 +937,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 128,
 						a: String::from("Sobre todo estimo rectos tus mandamientos; *"),
-						b: String::from("aborrezco el camino de la mentira.")
+						b: String::from("aborrezco el camino de la mentira."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 129,
 						a: String::from("Maravillosos son tus decretos; *"),
-						b: String::from("por tanto, los guardo de todo corazón.")
+						b: String::from("por tanto, los guardo de todo corazón."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 130,
 						a: String::from("La revelación de tu palabra ilumina; *"),
-						b: String::from("hace entender a los inocentes.")
+						b: String::from("hace entender a los inocentes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 131,
 						a: String::from("Abro la boca y jadeo; *"),
-						b: String::from("ansío tus mandamientos.")
+						b: String::from("ansío tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 132,
 						a: String::from("Vuélvete a mí, y ten misericordia, *"),
-						b: String::from("como acostumbras con los que aman tu Nombre.")
+						b: String::from("como acostumbras con los que aman tu Nombre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 133,
 						a: String::from("Afirma mis pasos con tu palabra; *"),
-						b: String::from("que ninguna iniquidad me domine.")
+						b: String::from("que ninguna iniquidad me domine."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 134,
 						a: String::from("Rescátame de los que me oprimen, *"),
-						b: String::from("y guardaré tus mandamientos.")
+						b: String::from("y guardaré tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 135,
 						a: String::from("Haz resplandecer tu rostro sobre tu siervo, *"),
-						b: String::from("y enséñame tus estatutos.")
+						b: String::from("y enséñame tus estatutos."),
+						..Default::default()
 					}
 				]
 			},
@@ -860,42 +995,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 136,
 						a: String::from("Ríos de aguas brotan de mis ojos *"),
-						b: String::from("a causa de los que no guardan tu ley.")
+						b: String::from("a causa de los que no guardan tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 137,
 						a: String::from("Justo eres tú, oh Señor, *"),
-						b: String::from("y rectos son tus juicios.")
+						b: String::from("y rectos son tus juicios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 138,
 						a: String::from("Has promulgado tus decretos *"),
-						b: String::from("con justicia y suma fidelidad.")
+						b: String::from("con justicia y suma fidelidad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 139,
 						a: String::from("La indignación me ha consumido, *"),
-						b: String::from("porque mis enemigos se olvidan de tus palabras.")
+						b: String::from("porque mis enemigos se olvidan de tus palabras."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 140,
 						a: String::from("Tu palabra ha pasado las más duras pruebas, *"),
-						b: String::from("y tu siervo la atesora.")
+						b: String::from("y tu siervo la atesora."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 141,
 						a: String::from("Pequeño soy e insignificante, *"),
-						b: String::from("pero no olvido tus mandamientos.")
+						b: String::from("pero no olvido tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 142,
 						a: String::from("Tu justicia es justicia eterna, *"),
-						b: String::from("y tu ley es la verdad.")
+						b: String::from("y tu ley es la verdad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 143,
 						a: String::from("Aflicción y angustia se han apoderado de mí, *"),
-						b: String::from("mas tus mandamientos son mi delicia.")
+						b: String::from("mas tus mandamientos son mi delicia."),
+						..Default::default()
 					}
 				]
 			},
@@ -910,42 +1053,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 144,
 						a: String::from("La rectitud de tus decretos es eterna; *"),
-						b: String::from("dame entendimiento, y viviré.")
+						b: String::from("dame entendimiento, y viviré."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 145,
 						a: String::from("Clamo con todo mi corazón; *"),
-						b: String::from("respóndeme, oh Señor, y guardaré tus estatutos.")
+						b: String::from("respóndeme, oh Señor, y guardaré tus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 146,
 						a: String::from("A ti clamo; ¡oh, que tú me salvaras! *"),
-						b: String::from("Guardaré tus decretos.")
+						b: String::from("Guardaré tus decretos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 147,
 						a: String::from("Me anticipo al alba, pidiendo socorro, *"),
-						b: String::from("porque en tu palabra espero.")
+						b: String::from("porque en tu palabra espero."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 148,
 						a: String::from("Velan mis ojos en las vigilias de la noche, *"),
-						b: String::from("para meditar en tu promesa.")
+						b: String::from("para meditar en tu promesa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 149,
 						a: String::from("Escucha mi voz, oh Señor, conforme a tu misericordia; *"),
-						b: String::from("según tus juicios, vivifícame.")
+						b: String::from("según tus juicios, vivifícame."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 150,
 						a: String::from("Se acercan los que me persiguen con malicia; *"),
-						b: String::from("están muy lejos de tu ley.")
+						b: String::from("están muy lejos de tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 151,
 						a: String::from("Cercano estás tú, oh Señor, *"),
-						b: String::from("y todos tus mandamientos son verdad.")
+						b: String::from("y todos tus mandamientos son verdad."),
+						..Default::default()
 					}
 				]
 			},
@@ -960,42 +1111,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 152,
 						a: String::from("Por tus decretos hace mucho he sabido *"),
-						b: String::from("que los has establecido para siempre.")
+						b: String::from("que los has establecido para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 153,
 						a: String::from("Mira mi humillación y líbrame, *"),
-						b: String::from("porque no olvido tu ley.")
+						b: String::from("porque no olvido tu ley."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 154,
 						a: String::from("Defiende mi causa y redímeme; *"),
-						b: String::from("dame vida conforme a tu promesa.")
+						b: String::from("dame vida conforme a tu promesa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 155,
 						a: String::from("Lejos está de los malvados la salvación, *"),
-						b: String::from("porque no estudian tus estatutos.")
+						b: String::from("porque no estudian tus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 156,
 						a: String::from("Grande es tu compasión, oh Señor; *"),
-						b: String::from("preserva mi vida conforme a tus juicios.")
+						b: String::from("preserva mi vida conforme a tus juicios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 157,
 						a: String::from("Muchos son mis perseguidores y mis enemigos, *"),
-						b: String::from("mas de tus decretos no me he apartado.")
+						b: String::from("mas de tus decretos no me he apartado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 158,
 						a: String::from("Veo a los infieles, y me disgusto, *"),
-						b: String::from("porque no han guardado tu palabra.")
+						b: String::from("porque no han guardado tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 159,
 						a: String::from("¡Mira, oh Señor, cómo amo tus mandamientos! *"),
-						b: String::from("Por tu misericordia, preserva mi vida.")
+						b: String::from("Por tu misericordia, preserva mi vida."),
+						..Default::default()
 					}
 				]
 			},
@@ -1010,42 +1169,50 @@ lazy_static! {
 					PsalmVerse {
 						number: 160,
 						a: String::from("La esencia de tu palabra es la verdad; *"),
-						b: String::from("eternos son todos tus justos juicios.")
+						b: String::from("eternos son todos tus justos juicios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 161,
 						a: String::from("Príncipes me han perseguido sin causa, *"),
-						b: String::from("pero mi corazón teme tu palabra.")
+						b: String::from("pero mi corazón teme tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 162,
 						a: String::from("Me regocijo tanto en tu promesa *"),
-						b: String::from("como el que halla muchos despojos.")
+						b: String::from("como el que halla muchos despojos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 163,
 						a: String::from("La mentira aborrezco y abomino, *"),
-						b: String::from("pero tu ley yo amo.")
+						b: String::from("pero tu ley yo amo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 164,
 						a: String::from("Siete veces al día te alabo, *"),
-						b: String::from("a causa de tus justos juicios.")
+						b: String::from("a causa de tus justos juicios."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 165,
 						a: String::from("Mucha paz tienen los que aman tu ley, *"),
-						b: String::from("y no hay para ellos tropiezo.")
+						b: String::from("y no hay para ellos tropiezo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 166,
 						a: String::from("Tu salvación he esperado, oh Señor, *"),
-						b: String::from("y he cumplido tus mandamientos.")
+						b: String::from("y he cumplido tus mandamientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 167,
 						a: String::from("He guardado tus decretos, *"),
-						b: String::from("y los he amado en gran manera.")
+						b: String::from("y los he amado en gran manera."),
+						..Default::default()
 					}
 				]
 			},
@@ -1060,50 +1227,60 @@ lazy_static! {
 					PsalmVerse {
 						number: 168,
 						a: String::from("He guardado tus mandamientos y tus decretos, *"),
-						b: String::from("porque todos mis caminos están delante de ti.")
+						b: String::from("porque todos mis caminos están delante de ti."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 169,
 						a: String::from("Llegue mi clamor delante de ti, oh Señor; *"),
-						b: String::from("dame entendimiento conforme a tu palabra.")
+						b: String::from("dame entendimiento conforme a tu palabra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 170,
 						a: String::from("Entre mi súplica en tu presencia; *"),
-						b: String::from("líbrame conforme a tu promesa.")
+						b: String::from("líbrame conforme a tu promesa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 171,
 						a: String::from("De mis labios brotará tu alabanza, *"),
-						b: String::from("cuando me enseñes tus estatutos.")
+						b: String::from("cuando me enseñes tus estatutos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 172,
 						a: String::from("Cantará mi lengua de tu promesa, *"),
-						b: String::from("porque todos tus mandamientos son justos.")
+						b: String::from("porque todos tus mandamientos son justos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 173,
 						a: String::from("Esté tu mano pronta para socorrerme, *"),
-						b: String::from("porque tus mandamientos he escogido.")
+						b: String::from("porque tus mandamientos he escogido."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 174,
 						a: String::from("Ansío tu salvación, oh Señor, *"),
-						b: String::from("y tu ley es mi delicia.")
+						b: String::from("y tu ley es mi delicia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 175,
 						a: String::from("Viva mi alma para alabarte, *"),
-						b: String::from("y tus juicios me ayuden.")
+						b: String::from("y tus juicios me ayuden."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 176,
 						a: String::from("Me extravié como oveja perdida; *"),
-						b: String::from("busca a tu siervo porque no olvido tus mandamientos.")
+						b: String::from("busca a tu siervo porque no olvido tus mandamientos."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,40 +15,48 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Al Señor clamé en mi angustia; *"),
-						b: String::from("clamé, y él me respondió.")
+						b: String::from("clamé, y él me respondió."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Líbrame, oh Señor, de los labios mentirosos, *"),
-						b: String::from("de la lengua engañosa.")
+						b: String::from("de la lengua engañosa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("¿Qué te hará, y qué te añadirá, *"),
-						b: String::from("oh lengua engañosa?")
+						b: String::from("oh lengua engañosa?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Agudas saetas de guerrero, *"),
-						b: String::from("con ascuas de retama.")
+						b: String::from("con ascuas de retama."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("¡Ay de mí, que he de morar en Mesec, *"),
-						b: String::from("y habitar entre las tiendas de Cedar!")
+						b: String::from("y habitar entre las tiendas de Cedar!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Demasiado he sufrido, *"),
-						b: String::from("viviendo con los que odian la paz.")
+						b: String::from("viviendo con los que odian la paz."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Estoy del lado de la paz, *"),
-						b: String::from("pero cuando digo: \"Paz\", ellos dicen: \"Guerra\".")
+						b: String::from("pero cuando digo: \"Paz\", ellos dicen: \"Guerra\"."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
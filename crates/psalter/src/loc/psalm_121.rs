@@ -15,45 +15,54 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Levanto mis ojos a los montes; *"),
-						b: String::from("¿de dónde vendrá mi socorro?")
+						b: String::from("¿de dónde vendrá mi socorro?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Mi socorro viene del Señor, *"),
-						b: String::from("que hizo los cielos y la tierra.")
+						b: String::from("que hizo los cielos y la tierra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("No permitirá que resbale tu pie, *"),
-						b: String::from("ni se dormirá el que te guarda.")
+						b: String::from("ni se dormirá el que te guarda."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("He aquí, el que guarda a Israel *"),
-						b: String::from("no se adormecerá ni dormirá.")
+						b: String::from("no se adormecerá ni dormirá."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("El Señor es tu guardián, *"),
-						b: String::from("el Señor es tu sombra a tu diestra.")
+						b: String::from("el Señor es tu sombra a tu diestra."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Elsolnoteharádañodedía,*"),
-						b: String::from("ni la luna de noche.")
+						b: String::from("ni la luna de noche."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("El Señor te guardará de todo mal; *"),
-						b: String::from("él guardará tu vida.")
+						b: String::from("él guardará tu vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("El Señor guardará tu salida y tu entrada, *"),
-						b: String::from("desde ahora y para siempre.")
+						b: String::from("desde ahora y para siempre."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,50 +15,60 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Me alegré cuando me dijeron: *"),
-						b: String::from("\"Vamos a la casa del Señor\".")
+						b: String::from("\"Vamos a la casa del Señor\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Ya están pisando nuestros pies *"),
-						b: String::from("tus umbrales, oh Jerusalén.")
+						b: String::from("tus umbrales, oh Jerusalén."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Jerusalén está edificada *"),
-						b: String::from("como ciudad bien unida entre sí.")
+						b: String::from("como ciudad bien unida entre sí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Allá suben las tribus, las tribus del Señor, la asamblea de Israel, *"),
-						b: String::from("para alabar el Nombre del Señor;")
+						b: String::from("para alabar el Nombre del Señor;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Porque allá están los tronos del juicio, *"),
-						b: String::from("los tronos de la casa de David.")
+						b: String::from("los tronos de la casa de David."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Oren por la paz de Jerusalén: *"),
-						b: String::from("\"Que prosperen los que te aman.")
+						b: String::from("\"Que prosperen los que te aman."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Haya paz dentro de tus muros, *"),
-						b: String::from("sosiego dentro de tus ciudadelas.")
+						b: String::from("sosiego dentro de tus ciudadelas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Por amor de mis hermanos y mis compañeros, *"),
-						b: String::from("digo de corazón: 'La paz contigo'.")
+						b: String::from("digo de corazón: 'La paz contigo'."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Por amor de la casa del Señor nuestro Dios, *"),
-						b: String::from("buscaré hacerte el bien\".")
+						b: String::from("buscaré hacerte el bien\"."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,30 +15,36 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("A ti levanto mis ojos, *"),
-						b: String::from("a ti entronizado en los cielos.")
+						b: String::from("a ti entronizado en los cielos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Así como los ojos de los siervos miran a las manos de sus señores, *"),
-						b: String::from("y los ojos de la sierva\na la mano de su señora,")
+						b: String::from("y los ojos de la sierva\na la mano de su señora,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Así nuestros ojos miran al Señor nuestro Dios, *"),
-						b: String::from("hasta que tenga misericordia de nosotros.")
+						b: String::from("hasta que tenga misericordia de nosotros."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Ten misericordia de nosotros, oh Señor, ten misericordia, *"),
-						b: String::from("porque estamos hartos de desprecio,")
+						b: String::from("porque estamos hartos de desprecio,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Hartos del escarnio de los ricos indolentes, *"),
-						b: String::from("del menosprecio de los orgullosos.")
+						b: String::from("del menosprecio de los orgullosos."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
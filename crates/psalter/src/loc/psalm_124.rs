@@ -15,45 +15,54 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Si el Señor no hubiera estado de nuestra parte, *"),
-						b: String::from("diga ahora Israel;")
+						b: String::from("diga ahora Israel;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Si el Señor no hubiera estado de nuestra parte, *"),
-						b: String::from("cuando los enemigos se levantaron contra nosotros;")
+						b: String::from("cuando los enemigos se levantaron contra nosotros;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Entonces nos habrían tragado vivos, *"),
-						b: String::from("cuando se encendió su furor contra nosotros;")
+						b: String::from("cuando se encendió su furor contra nosotros;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Entonces nos habrían sumergido las aguas, *"),
-						b: String::from("hasta el cuello habría subido el torrente;")
+						b: String::from("hasta el cuello habría subido el torrente;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Entonces hasta el cuello habrían subido *"),
-						b: String::from("las aguas furiosas.")
+						b: String::from("las aguas furiosas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("¡Bendito sea el Señor! *"),
-						b: String::from("No nos ha dado por presa a sus dientes.")
+						b: String::from("No nos ha dado por presa a sus dientes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Hemos escapado cual ave de la trampa del cazador; *"),
-						b: String::from("se rompió la trampa, y hemos escapado.")
+						b: String::from("se rompió la trampa, y hemos escapado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Nuestro auxilio está en el Nombre del Señor, *"),
-						b: String::from("que hizo los cielos y la tierra.")
+						b: String::from("que hizo los cielos y la tierra."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,30 +15,36 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Los que confían en el Señor son como el monte Sión, *"),
-						b: String::from("que no será movido, sino que permanece para siempre.")
+						b: String::from("que no será movido, sino que permanece para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Los montes rodean a Jerusalén; *"),
-						b: String::from("así el Señor rodea a su pueblo,\ndesde ahora y para siempre.")
+						b: String::from("así el Señor rodea a su pueblo,\ndesde ahora y para siempre."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("No pesará el cetro de los malvados sobre la heredad de los justos; *"),
-						b: String::from("no sea que extiendan los justos sus manos a la maldad.")
+						b: String::from("no sea que extiendan los justos sus manos a la maldad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Haz bien, oh Señor, a los buenos, *"),
-						b: String::from("y a los que son rectos de corazón;")
+						b: String::from("y a los que son rectos de corazón;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Mas a los que se desvían por sendas tortuosas, el Señor los llevará con los malhechores, *"),
-						b: String::from("pero la paz sea sobre Israel.")
+						b: String::from("pero la paz sea sobre Israel."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
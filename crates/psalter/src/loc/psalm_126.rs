@@ -15,40 +15,48 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Cuando el Señor cambió la suerte de Sión, *"),
-						b: String::from("éramos como los que sueñan.")
+						b: String::from("éramos como los que sueñan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Entonces nuestra boca se llenó de risa, *"),
-						b: String::from("y nuestra lengua de gritos de alegría.")
+						b: String::from("y nuestra lengua de gritos de alegría."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Y decían entre las naciones: *"),
-						b: String::from("\"Ha hecho el Señor proezas con ellos\".")
+						b: String::from("\"Ha hecho el Señor proezas con ellos\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Proezas ha hecho el Señor con nosotros, *"),
-						b: String::from("y estamos sumamente alegres.")
+						b: String::from("y estamos sumamente alegres."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Tú, oh Señor, has cambiado nuestra suerte, *"),
-						b: String::from("como los torrentes del Neguev.")
+						b: String::from("como los torrentes del Neguev."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Los que sembraron con lágrimas, *"),
-						b: String::from("con gritos de alegría segarán.")
+						b: String::from("con gritos de alegría segarán."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Los que van llorando, llevando la semilla, *"),
-						b: String::from("volverán entre cantares, trayendo sus gavillas.")
+						b: String::from("volverán entre cantares, trayendo sus gavillas."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,35 +15,42 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Si el Señor no edificare la casa, *"),
-						b: String::from("en vano trabajan los que la edifican.")
+						b: String::from("en vano trabajan los que la edifican."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Si el Señor no guardare la ciudad, *"),
-						b: String::from("en vano vela el vigilante.")
+						b: String::from("en vano vela el vigilante."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Vano es madrugar y acostarse tarde, vano también comer el pan del trabajo; *"),
-						b: String::from("pues a su amado le da el sueño.")
+						b: String::from("pues a su amado le da el sueño."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("He aquí, herencia del Señor son los hijos, *"),
-						b: String::from("y el fruto del vientre, un don.")
+						b: String::from("y el fruto del vientre, un don."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Como saetas en manos de un guerrero, *"),
-						b: String::from("así son los hijos de nuestra juventud.")
+						b: String::from("así son los hijos de nuestra juventud."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("¡Dichoso el que llena con ellas su aljaba! *"),
-						b: String::from("No será avergonzado cuando contienda\ncon sus adversarios en la puerta.")
+						b: String::from("No será avergonzado cuando contienda\ncon sus adversarios en la puerta."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,35 +15,42 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Dichosos todos los que temen al Señor; *"),
-						b: String::from("y andan en sus caminos!")
+						b: String::from("y andan en sus caminos!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Comerás el fruto de tu trabajo; *"),
-						b: String::from("dicha y prosperidad tendrás.")
+						b: String::from("dicha y prosperidad tendrás."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Tu mujer será como parra fecunda en medio de tu casa, *"),
-						b: String::from("tus hijos como renuevos de olivo alrededor de tu mesa.")
+						b: String::from("tus hijos como renuevos de olivo alrededor de tu mesa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Así será bendecido el hombre *"),
-						b: String::from("que teme al Señor.")
+						b: String::from("que teme al Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Bendígate el Señor desde Sion, *"),
-						b: String::from("y veas la prosperidad de Jerusalén\ntodos los días de tu vida.")
+						b: String::from("y veas la prosperidad de Jerusalén\ntodos los días de tu vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Que veas los hijos de tus hijos, *"),
-						b: String::from("y la paz sea sobre Israel.")
+						b: String::from("y la paz sea sobre Israel."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
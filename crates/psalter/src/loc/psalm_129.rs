@@ -15,45 +15,54 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("\"Mucho me han oprimido desde mi juventud\", *"),
-						b: String::from("diga ahora Israel;")
+						b: String::from("diga ahora Israel;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("\"Mucho me han oprimido desde mi juventud, *"),
-						b: String::from("mas no prevalecieron contra mí\".")
+						b: String::from("mas no prevalecieron contra mí\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Sobre mis espaldas araron los aradores, *"),
-						b: String::from("y alargaron sus surcos.")
+						b: String::from("y alargaron sus surcos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("El Señor, el Justo, *"),
-						b: String::from("ha cortado las coyundas de los malvados.")
+						b: String::from("ha cortado las coyundas de los malvados."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Sean avergonzados y vueltos atrás *"),
-						b: String::from("cuantos aborrecen a Sión.")
+						b: String::from("cuantos aborrecen a Sión."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Sean como la hierba de los tejados, *"),
-						b: String::from("que se marchita antes que se le segue;")
+						b: String::from("que se marchita antes que se le segue;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Que no llena la mano del segador, *"),
-						b: String::from("ni el pecho del que ata las gavillas;")
+						b: String::from("ni el pecho del que ata las gavillas;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("De modo que los que pasan ni siquiera dicen: \"Bendígate el Señor. *"),
-						b: String::from("Te deseamos buena suerte en el Nombre del Señor\".")
+						b: String::from("Te deseamos buena suerte en el Nombre del Señor\"."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
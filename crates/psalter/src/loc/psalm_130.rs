@@ -15,40 +15,48 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("De lo profundo, oh Señor, a ti clamo; Señor, escucha mi voz; *"),
-						b: String::from("estén atentos tus oídos a la voz de mi súplica.")
+						b: String::from("estén atentos tus oídos a la voz de mi súplica."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Si tú, oh Señor, notares los delitos, *"),
-						b: String::from("¿quién, oh Señor, podrá mantenerse?")
+						b: String::from("¿quién, oh Señor, podrá mantenerse?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Mas en ti hay perdón, *"),
-						b: String::from("por tanto serás venerado.")
+						b: String::from("por tanto serás venerado."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Aguardo al Señor; le aguarda mi alma; *"),
-						b: String::from("en su palabra está mi esperanza.")
+						b: String::from("en su palabra está mi esperanza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Mi alma aguarda al Señor,\nmás que los centinelas a la aurora, *"),
-						b: String::from("más que los centinelas a la aurora.")
+						b: String::from("más que los centinelas a la aurora."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Oh Israel, aguarda al Señor, *"),
-						b: String::from("porque en el Señor hay misericordia;")
+						b: String::from("porque en el Señor hay misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Con él hay abundante redención, *"),
-						b: String::from("y él redimirá a Israel de todos sus pecados.")
+						b: String::from("y él redimirá a Israel de todos sus pecados."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
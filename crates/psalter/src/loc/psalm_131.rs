@@ -15,25 +15,30 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Señor, mi corazón no es arrogante, *"),
-						b: String::from("ni mis ojos engreídos;")
+						b: String::from("ni mis ojos engreídos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("No me ocupo de cosas grandes, *"),
-						b: String::from("ni de las que superan mi capacidad;")
+						b: String::from("ni de las que superan mi capacidad;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Acallo mi alma y la sosiego,\ncomo un niño en brazos de su madre; *"),
-						b: String::from("mi alma está calmada dentro de mí.")
+						b: String::from("mi alma está calmada dentro de mí."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Oh Israel, aguarda al Señor, *"),
-						b: String::from("desde ahora y para siempre.")
+						b: String::from("desde ahora y para siempre."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
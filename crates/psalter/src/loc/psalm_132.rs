@@ -15,100 +15,120 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Acuérdate, oh Señor, de David, *"),
-						b: String::from("y de todas sus aflicciones;")
+						b: String::from("y de todas sus aflicciones;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("De cómo juró al Señor, *"),
-						b: String::from("e hizo voto al Poderoso de Jacob:")
+						b: String::from("e hizo voto al Poderoso de Jacob:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("\"No entraré bajo el techo de mi casa, *"),
-						b: String::from("ni subiré a mi lecho;")
+						b: String::from("ni subiré a mi lecho;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("No daré sueño a mis ojos, *"),
-						b: String::from("ni a mis párpados adormecimiento;")
+						b: String::from("ni a mis párpados adormecimiento;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Hasta que halle un lugar para el Señor, *"),
-						b: String::from("una morada para el Poderoso de Jacob\".")
+						b: String::from("una morada para el Poderoso de Jacob\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("\"¡El arca! Oímos que estaba en Efrata, *"),
-						b: String::from("la hallamos en el campo de Jaar.")
+						b: String::from("la hallamos en el campo de Jaar."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Vayamos a la habitación de Dios; *"),
-						b: String::from("postrémonos ante el estrado de sus pies\".")
+						b: String::from("postrémonos ante el estrado de sus pies\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Levántate, oh Señor, al lugar de tu reposo, *"),
-						b: String::from("tú, y el arca de tu poder.")
+						b: String::from("tú, y el arca de tu poder."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Que se vistan tus sacerdotes de justicia, *"),
-						b: String::from("que tus fieles canten de júbilo.")
+						b: String::from("que tus fieles canten de júbilo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Por amor a David tu siervo, *"),
-						b: String::from("no vuelvas el rostro de tu Ungido.")
+						b: String::from("no vuelvas el rostro de tu Ungido."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("El Señor ha jurado a David un juramento, *"),
-						b: String::from("y seguramente no se retractará:")
+						b: String::from("y seguramente no se retractará:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("\"A uno de los hijos de tu cuerpo *"),
-						b: String::from("pondré sobre tu trono.")
+						b: String::from("pondré sobre tu trono."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Si tus hijos guardaren mi pacto,\ny mis testimonios que yo les enseñaré, *"),
-						b: String::from("sus hijos también se sentarán sobre tu trono para siempre\";")
+						b: String::from("sus hijos también se sentarán sobre tu trono para siempre\";"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Porque el Señor ha elegido a Sión; *"),
-						b: String::from("la ha deseado para su habitación.")
+						b: String::from("la ha deseado para su habitación."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("\"Esta es para siempre mi lugar de reposo; *"),
-						b: String::from("aquí habitaré, porque en ella está mi deleite.")
+						b: String::from("aquí habitaré, porque en ella está mi deleite."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Bendeciré abundantemente sus provisiones; *"),
-						b: String::from("a sus pobres los saciaré de pan.")
+						b: String::from("a sus pobres los saciaré de pan."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Vestiré de salvación a sus sacerdotes, *"),
-						b: String::from("y sus fieles cantarán con júbilo.")
+						b: String::from("y sus fieles cantarán con júbilo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Allí haré florecer el poder de David; *"),
-						b: String::from("he dispuesto una lámpara para mi Ungido.")
+						b: String::from("he dispuesto una lámpara para mi Ungido."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("En cuanto a sus enemigos, los vestiré de vergüenza,*"),
-						b: String::from("mas sobre él brillará su corona\".")
+						b: String::from("mas sobre él brillará su corona\"."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,30 +15,36 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Oh cuán bueno y agradable es *"),
-						b: String::from("convivir los hermanos en unidad!")
+						b: String::from("convivir los hermanos en unidad!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Es como el buen óleo sobre la cabeza, *"),
-						b: String::from("el cual desciende sobre la barba,")
+						b: String::from("el cual desciende sobre la barba,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Sobre la barba de Aarón, *"),
-						b: String::from("y baja hasta el collar de sus vestiduras.")
+						b: String::from("y baja hasta el collar de sus vestiduras."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Es como el rocío del Hermón, *"),
-						b: String::from("que desciende sobre los montes de Sión;")
+						b: String::from("que desciende sobre los montes de Sión;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Porque allí manda el Señor la bendición: *"),
-						b: String::from("la vida por siempre jamás.")
+						b: String::from("la vida por siempre jamás."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
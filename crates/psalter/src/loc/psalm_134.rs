@@ -15,15 +15,18 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Y ahora bendigan al Señor, siervos todos del Señor, *"),
-						b: String::from("los que de noche están de pie en la casa del Señor.")
+						b: String::from("los que de noche están de pie en la casa del Señor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Eleven las manos hacia el santuario, y bendigan al Señor. *"),
-						b: String::from("El Señor que hizo los cielos y la tierra, te bendiga desde Sión.")
+						b: String::from("El Señor que hizo los cielos y la tierra, te bendiga desde Sión."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,135 +15,162 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Den gracias al Señor, porque es bueno, *"),
-						b: String::from("porque para siempre es su misericordia.")
+						b: String::from("porque para siempre es su misericordia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Den gracias al Dios de los dioses, *"),
-						b: String::from("porque para siempre es su misericordia.")
+						b: String::from("porque para siempre es su misericordia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Den gracias al Señor de los señores, *"),
-						b: String::from("porque para siempre es su misericordia.")
+						b: String::from("porque para siempre es su misericordia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Al único que hace grandes maravillas, *"),
-						b: String::from("porque para siempre es su misericordia;")
+						b: String::from("porque para siempre es su misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Al que hizo los cielos con sabiduría, *"),
-						b: String::from("porque para siempre es su misericordia;")
+						b: String::from("porque para siempre es su misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Al que extendió la tierra sobre las aguas, *"),
-						b: String::from("porque para siempre es su misericordia;")
+						b: String::from("porque para siempre es su misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Al que hizo las grandes lumbreras, *"),
-						b: String::from("porque para siempre es su misericordia:")
+						b: String::from("porque para siempre es su misericordia:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("El sol para que señorease de día, *"),
-						b: String::from("porque para siempre es su misericordia;")
+						b: String::from("porque para siempre es su misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("La luna y las estrellas para que señoreasen de noche, *"),
-						b: String::from("porque para siempre es su misericordia.")
+						b: String::from("porque para siempre es su misericordia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Al que hirió a los primogénitos de Egipto, *"),
-						b: String::from("porque para siempre es su misericordia;")
+						b: String::from("porque para siempre es su misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Al que sacó a Israel de en medio de ellos, *"),
-						b: String::from("porque para siempre es su misericordia,")
+						b: String::from("porque para siempre es su misericordia,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Con mano fuerte, y brazo extendido, *"),
-						b: String::from("porque para siempre es su misericordia;")
+						b: String::from("porque para siempre es su misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Al que dividió en dos el Mar Rojo, *"),
-						b: String::from("porque para siempre es su misericordia,")
+						b: String::from("porque para siempre es su misericordia,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("E hizo pasar a Israel por en medio de él, *"),
-						b: String::from("porque para siempre es su misericordia,")
+						b: String::from("porque para siempre es su misericordia,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Pero arrojó a Faraón y a su ejército en el Mar Rojo, *"),
-						b: String::from("porque para siempre es su misericordia;")
+						b: String::from("porque para siempre es su misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("Al que condujo a su pueblo por el desierto, *"),
-						b: String::from("porque para siempre es su misericordia.")
+						b: String::from("porque para siempre es su misericordia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 17,
 						a: String::from("Al que derribó a grandes reinos, *"),
-						b: String::from("porque para siempre es su misericordia,")
+						b: String::from("porque para siempre es su misericordia,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 18,
 						a: String::from("Y mató a reyes poderosos, *"),
-						b: String::from("porque para siempre es su misericordia:")
+						b: String::from("porque para siempre es su misericordia:"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 19,
 						a: String::from("A Sehón, rey amorreo, *"),
-						b: String::from("porque para siempre es su misericordia,")
+						b: String::from("porque para siempre es su misericordia,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 20,
 						a: String::from("YaOg,reydeBasán,*"),
-						b: String::from("porque para siempre es su misericordia;")
+						b: String::from("porque para siempre es su misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 21,
 						a: String::from("Y dio la tierra de ellos en heredad, *"),
-						b: String::from("porque para siempre es su misericordia,")
+						b: String::from("porque para siempre es su misericordia,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 22,
 						a: String::from("En heredad a Israel su siervo, *"),
-						b: String::from("porque para siempre es su misericordia.")
+						b: String::from("porque para siempre es su misericordia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 23,
 						a: String::from("Al que se acordó de nosotros en nuestro abatimiento, *"),
-						b: String::from("porque para siempre es su misericordia,")
+						b: String::from("porque para siempre es su misericordia,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 24,
 						a: String::from("Y nos libró de nuestros enemigos, *"),
-						b: String::from("porque para siempre es su misericordia;")
+						b: String::from("porque para siempre es su misericordia;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 25,
 						a: String::from("Al que da alimento a toda criatura, *"),
-						b: String::from("porque para siempre es su misericordia.")
+						b: String::from("porque para siempre es su misericordia."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 26,
 						a: String::from("Den gracias al Dios de los cielos, *"),
-						b: String::from("porque para siempre es su misericordia.")
+						b: String::from("porque para siempre es su misericordia."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,50 +15,60 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Junto a los ríos de Babilonia, allí nos sentamos a llorar, *"),
-						b: String::from("al acordarnos de ti, oh Sión.")
+						b: String::from("al acordarnos de ti, oh Sión."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Sobre los álamos, en medio de ella, *"),
-						b: String::from("colgamos nuestras arpas;")
+						b: String::from("colgamos nuestras arpas;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Porque los que nos llevaron cautivos pedían una canción; nuestros opresores pedían alegría: *"),
-						b: String::from("\"Cántennos un cántico de Sión\".")
+						b: String::from("\"Cántennos un cántico de Sión\"."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("¿Cómo cantaremos cántico del Señor *"),
-						b: String::from("en tierra extranjera?")
+						b: String::from("en tierra extranjera?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Si me olvidare de ti, oh Jerusalén, *"),
-						b: String::from("pierda mi diestra su destreza.")
+						b: String::from("pierda mi diestra su destreza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Que se me pegue la lengua al paladar, Si no me acordare de ti, *"),
-						b: String::from("Si no pusiere a Jerusalén\npor encima de mi suma alegría.")
+						b: String::from("Si no pusiere a Jerusalén\npor encima de mi suma alegría."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Acuérdate del día de Jerusalén, oh Señor, en contra de los edomitas, *"),
-						b: String::from("que decían: \"¡Arrásenla, arrásenla hasta los cimientos!\"")
+						b: String::from("que decían: \"¡Arrásenla, arrásenla hasta los cimientos!\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("¡Oh hija de Babilonia, asoladora, *"),
-						b: String::from("dichoso el que te dé el pago\nde lo que tú nos hiciste!")
+						b: String::from("dichoso el que te dé el pago\nde lo que tú nos hiciste!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("¡Dichoso el que tome tus niños *"),
-						b: String::from("y los estrelle contra la peña!")
+						b: String::from("y los estrelle contra la peña!"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,50 +15,60 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Te daré gracias, oh Señor, de todo corazón; *"),
-						b: String::from("delante de los dioses cantaré tus alabanzas.")
+						b: String::from("delante de los dioses cantaré tus alabanzas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Me postraré hacia tu santo templo, y alabaré tu Nombre, *"),
-						b: String::from("por tu amor y tu fidelidad;")
+						b: String::from("por tu amor y tu fidelidad;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Porque has glorificado tu Nombre, *"),
-						b: String::from("y tu palabra por encima de todo.")
+						b: String::from("y tu palabra por encima de todo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Cuando te invoqué, me respondiste, *"),
-						b: String::from("fortaleciste mi alma con vigor.")
+						b: String::from("fortaleciste mi alma con vigor."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Te alabarán, oh Señor, todos los reyes de la tierra, *"),
-						b: String::from("al escuchar las palabras de tu boca.")
+						b: String::from("al escuchar las palabras de tu boca."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Cantarán de los caminos del Señor: *"),
-						b: String::from("\"¡Cuán grande la gloria del Señor!\"")
+						b: String::from("\"¡Cuán grande la gloria del Señor!\""),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Aunque excelso es el Señor, cuida del humilde, *"),
-						b: String::from("y al altivo percibe de lejos.")
+						b: String::from("y al altivo percibe de lejos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Aunque camine entre peligros, tú me guardas seguro; *"),
-						b: String::from("contra la ira de mis enemigos extiendes tu mano,\ny tu diestra me salvará.")
+						b: String::from("contra la ira de mis enemigos extiendes tu mano,\ny tu diestra me salvará."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("El Señor cumplirá en mí su propósito; *"),
-						b: String::from("tu misericordia, oh Señor, es para siempre;\nno desampares la obra de tus manos.")
+						b: String::from("tu misericordia, oh Señor, es para siempre;\nno desampares la obra de tus manos."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,55 +15,66 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("Oh Señor, a ti clamo; apresúrate; *"),
-						b: String::from("escucha mi voz cuando te invoco.")
+						b: String::from("escucha mi voz cuando te invoco."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Ascienda mi oración como incienso ante tu presencia, *"),
-						b: String::from("el alzar de mis manos como el sacrificio vespertino.")
+						b: String::from("el alzar de mis manos como el sacrificio vespertino."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Pon centinela delante de mi boca, oh Señor, y guardia a la puerta de mis labios; *"),
-						b: String::from("no dejes que mi corazón se incline al mal.")
+						b: String::from("no dejes que mi corazón se incline al mal."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("No me dedique a la maldad con los malvados, *"),
-						b: String::from("y no coma yo de sus deleites.")
+						b: String::from("y no coma yo de sus deleites."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Que el justo me castigue con censura benévola, mas el ungüento del impío no perfume mi cabeza; *"),
-						b: String::from("mi oración es continuamente contra sus maldades.")
+						b: String::from("mi oración es continuamente contra sus maldades."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Que sus jefes sean derribados en lugares peñascosos, *"),
-						b: String::from("para que sepan que mis palabras son verdaderas.")
+						b: String::from("para que sepan que mis palabras son verdaderas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Así como la tierra es surcada por el arador, *"),
-						b: String::from("así sean esparcidos sus huesos a la boca de la tumba.")
+						b: String::from("así sean esparcidos sus huesos a la boca de la tumba."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Empero mis ojos están vueltos a ti, Señor Dios; *"),
-						b: String::from("en ti me refugio; no me despojes de la vida.")
+						b: String::from("en ti me refugio; no me despojes de la vida."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Guárdame de los lazos que me han tendido, *"),
-						b: String::from("y de las trampas de los malhechores.")
+						b: String::from("y de las trampas de los malhechores."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Caigan los malvados en sus propias redes, *"),
-						b: String::from("mientras yo escapo.")
+						b: String::from("mientras yo escapo."),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
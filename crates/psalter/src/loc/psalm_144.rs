@@ -15,85 +15,102 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Bendito el Señor, roca mía! *"),
-						b: String::from("El adiestra mis manos para el combate,\ny mis dedos para la pelea;")
+						b: String::from("El adiestra mis manos para el combate,\ny mis dedos para la pelea;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Mi auxilio y mi fortaleza,\nmi refugio y mi libertador, *"),
-						b: String::from("mi escudo en quien confío,\nque somete los pueblos a mi dominio.")
+						b: String::from("mi escudo en quien confío,\nque somete los pueblos a mi dominio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Oh Señor, ¿qué somos,\npara que de nosotros cuides? *"),
-						b: String::from("¿Que somos los mortales,\npara que en nosotros pienses?")
+						b: String::from("¿Que somos los mortales,\npara que en nosotros pienses?"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Somos igual que un soplo, *"),
-						b: String::from("y nuestros días como la sombra que pasa.")
+						b: String::from("y nuestros días como la sombra que pasa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Oh Señor, inclina tus cielos, y desciende; *"),
-						b: String::from("toca los montes, y echarán humo.")
+						b: String::from("toca los montes, y echarán humo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Lanza los relámpagos, y dispérsalos; *"),
-						b: String::from("tira tus saetas, y ponlos en fuga.")
+						b: String::from("tira tus saetas, y ponlos en fuga."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Extiende tu mano desde las alturas; *"),
-						b: String::from("rescátame, y líbrame de las grandes aguas,\nde la mano de pueblos extranjeros,")
+						b: String::from("rescátame, y líbrame de las grandes aguas,\nde la mano de pueblos extranjeros,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Cuya boca habla mentiras, *"),
-						b: String::from("y cuya diestra jura en falso.")
+						b: String::from("y cuya diestra jura en falso."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Oh Dios, a ti cantaré cántico nuevo; *"),
-						b: String::from("tañeré para ti con lira de diez cuerdas.")
+						b: String::from("tañeré para ti con lira de diez cuerdas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Tú das victoria a los reyes, *"),
-						b: String::from("y has rescatado a David tu siervo.")
+						b: String::from("y has rescatado a David tu siervo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Rescátame de la espada que hiere, *"),
-						b: String::from("y líbrame de la mano de pueblos extranjeros,")
+						b: String::from("y líbrame de la mano de pueblos extranjeros,"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Cuya boca habla mentiras, *"),
-						b: String::from("y cuya diestra jura en falso.")
+						b: String::from("y cuya diestra jura en falso."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Sean nuestros hijos como plantas bien criadas desde su juventud, *"),
-						b: String::from("y nuestras hijas como las esquinas labradas de un palacio.")
+						b: String::from("y nuestras hijas como las esquinas labradas de un palacio."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Que rebosen nuestros graneros\nde toda suerte de cosechas; *"),
-						b: String::from("se acrecienten por millares\nlos ganados en nuestras praderas;\nestén nuestros bueyes bien nutridos.")
+						b: String::from("se acrecienten por millares\nlos ganados en nuestras praderas;\nestén nuestros bueyes bien nutridos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 15,
 						a: String::from("Que no haya brechas en las murallas, ni deportación, *"),
-						b: String::from("ni lamento en nuestras plazas.")
+						b: String::from("ni lamento en nuestras plazas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 16,
 						a: String::from("¡Dichoso el pueblo que goza de todo esto! *"),
-						b: String::from("¡Dichoso el pueblo cuyo Dios es el Señor")
+						b: String::from("¡Dichoso el pueblo cuyo Dios es el Señor"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,50 +15,60 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Aleluya!\nAlaba, alma mía, al Señor; *"),
-						b: String::from("alabaré al Señor mientras viva;\ncantaré alabanzas a mi Dios mientras exista.")
+						b: String::from("alabaré al Señor mientras viva;\ncantaré alabanzas a mi Dios mientras exista."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("No confíes en los príncipes, ni en ningún hijo de Adán, *"),
-						b: String::from("porque no hay en ellos seguridad.")
+						b: String::from("porque no hay en ellos seguridad."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Al exhalar el espíritu, vuelven al polvo, *"),
-						b: String::from("y en ese día perecen todos sus planes.")
+						b: String::from("y en ese día perecen todos sus planes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("¡Dichosos aquéllos cuya ayuda es el Dios de Jacob, *"),
-						b: String::from("cuya esperanza está en el Señor su Dios!")
+						b: String::from("cuya esperanza está en el Señor su Dios!"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("El cual hizo los cielos y la tierra, el mar, y cuanto en ellos hay, *"),
-						b: String::from("que guarda su promesa para siempre;")
+						b: String::from("que guarda su promesa para siempre;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Que hace justicia a los oprimidos, *"),
-						b: String::from("y da pan a los hambrientos.")
+						b: String::from("y da pan a los hambrientos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("El Señor liberta a los cautivos;\nel Señor abre los ojos a los ciegos; *"),
-						b: String::from("el Señor levanta a los caídos;")
+						b: String::from("el Señor levanta a los caídos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("El Señor ama a los justos;\nel Señor protege a los forasteros; *"),
-						b: String::from("sostiene al huérfano y a la viuda,\npero trastorna el camino de los malvados.")
+						b: String::from("sostiene al huérfano y a la viuda,\npero trastorna el camino de los malvados."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Reinará el Señor para siempre, *"),
-						b: String::from("tu Dios, oh Sión, de generación en generación.\n¡Aleluya!")
+						b: String::from("tu Dios, oh Sión, de generación en generación.\n¡Aleluya!"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
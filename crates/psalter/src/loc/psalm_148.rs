@@ -15,75 +15,90 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Aleluya!\nAlaben al Señor desde los cielos; *"),
-						b: String::from("alábenle en las alturas.")
+						b: String::from("alábenle en las alturas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Alábenle, todos sus ángeles; *"),
-						b: String::from("alábenle, toda su hueste.")
+						b: String::from("alábenle, toda su hueste."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Alábenle, sol y luna; *"),
-						b: String::from("alábenle, todas las estrellas lucientes.")
+						b: String::from("alábenle, todas las estrellas lucientes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Alábenle, cielos de los cielos; *"),
-						b: String::from("alábenle, aguas que están sobre los cielos.")
+						b: String::from("alábenle, aguas que están sobre los cielos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Alaben el Nombre del Señor, *"),
-						b: String::from("porque él mandó, y fueron creados.")
+						b: String::from("porque él mandó, y fueron creados."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Los afirmó eternamente y para siempre; *"),
-						b: String::from("les dio una ley que no pasará.")
+						b: String::from("les dio una ley que no pasará."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Alaben al Señor desde la tierra, *"),
-						b: String::from("monstruos marinos y todos los abismos;")
+						b: String::from("monstruos marinos y todos los abismos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("Fuego y granizo, nieve y bruma, *"),
-						b: String::from("viento tempestuoso que ejecuta su voluntad;")
+						b: String::from("viento tempestuoso que ejecuta su voluntad;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Montes y todas las colinas, *"),
-						b: String::from("árboles frutales y todos los cedros;")
+						b: String::from("árboles frutales y todos los cedros;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 10,
 						a: String::from("Bestias silvestres y todo ganado, *"),
-						b: String::from("reptiles y aves aladas;")
+						b: String::from("reptiles y aves aladas;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 11,
 						a: String::from("Reyes de la tierra y todos los pueblos, *"),
-						b: String::from("príncipes y jefes del mundo;")
+						b: String::from("príncipes y jefes del mundo;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 12,
 						a: String::from("Mozos y doncellas, *"),
-						b: String::from("viejos y jóvenes juntos.")
+						b: String::from("viejos y jóvenes juntos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 13,
 						a: String::from("Alaben el Nombre del Señor, *"),
-						b: String::from("porque sólo su Nombre es excelso,\nsu gloria sobre la tierra y los cielos.")
+						b: String::from("porque sólo su Nombre es excelso,\nsu gloria sobre la tierra y los cielos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 14,
 						a: String::from("Ha alzado el cuerno de su pueblo, y alabanza para todos sus fieles, *"),
-						b: String::from("los hijos de Israel, el pueblo cercano a él. ¡Aleluya!")
+						b: String::from("los hijos de Israel, el pueblo cercano a él. ¡Aleluya!"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
@@ -15,50 +15,60 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Aleluya!\nCanten al Señor cántico nuevo, *"),
-						b: String::from("su alabanza en la congregación de los fieles")
+						b: String::from("su alabanza en la congregación de los fieles"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Alégrese Israel en su Hacedor; *"),
-						b: String::from("gócense los hijos de Sión en su Rey.")
+						b: String::from("gócense los hijos de Sión en su Rey."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Alaben su Nombre con danzas, *"),
-						b: String::from("con tambor y arpa cántenle alabanza;")
+						b: String::from("con tambor y arpa cántenle alabanza;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Porque el Señor se complace en su pueblo, *"),
-						b: String::from("y adorna con victoria a los humildes.")
+						b: String::from("y adorna con victoria a los humildes."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Regocíjense los fieles en su triunfo, *"),
-						b: String::from("y alégrense sobre sus camas.")
+						b: String::from("y alégrense sobre sus camas."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Estén las alabanzas de Dios en sus labios, *"),
-						b: String::from("y la espada de dos filos en su mano;")
+						b: String::from("y la espada de dos filos en su mano;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 7,
 						a: String::from("Para tomar venganza de las naciones *"),
-						b: String::from("y castigar a los pueblos;")
+						b: String::from("y castigar a los pueblos;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 8,
 						a: String::from("O Para atar a sus reyes con grillos, *"),
-						b: String::from("y sus nobles con eslabones de hierro;")
+						b: String::from("y sus nobles con eslabones de hierro;"),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 9,
 						a: String::from("Para ejecutar en ellos la sentencia decretada; *"),
-						b: String::from("esto es gloria para todos tus fieles.\n¡Aleluya!")
+						b: String::from("esto es gloria para todos tus fieles.\n¡Aleluya!"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file
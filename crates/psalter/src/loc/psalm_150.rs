@@ -15,35 +15,42 @@ lazy_static! {
 					PsalmVerse {
 						number: 1,
 						a: String::from("¡Aleluya!\nAlaben a Dios en su santo templo; *"),
-						b: String::from("alábenle en la bóveda de su poder.")
+						b: String::from("alábenle en la bóveda de su poder."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 2,
 						a: String::from("Alábenle por sus proezas; *"),
-						b: String::from("alábenle por su inmensa grandeza.")
+						b: String::from("alábenle por su inmensa grandeza."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 3,
 						a: String::from("Alábenle con el bramido del corno; *"),
-						b: String::from("alábenle con lira y arpa.")
+						b: String::from("alábenle con lira y arpa."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 4,
 						a: String::from("Alábenle con tambores y danzas; *"),
-						b: String::from("alábenle con cuerdas y caramillo.")
+						b: String::from("alábenle con cuerdas y caramillo."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 5,
 						a: String::from("Alábenle con címbalos resonantes; *"),
-						b: String::from("alábenle con címbalos clamorosos.")
+						b: String::from("alábenle con címbalos clamorosos."),
+						..Default::default()
 					},
 					PsalmVerse {
 						number: 6,
 						a: String::from("Todo lo que respira, *"),
-						b: String::from("alabe al Señor.\n¡Aleluya!")
+						b: String::from("alabe al Señor.\n¡Aleluya!"),
+						..Default::default()
 					}
 				]
 			}
-		]
+		],
+		..Default::default()
 	};
 }
\ No newline at end of file